@@ -56,6 +56,7 @@ export const FloatingTimer = () => {
   const [isEditDialogOpen, setIsEditDialogOpen] = React.useState(false);
   const [isMinimized, setIsMinimized] = React.useState(false);
   const [userNote, setUserNote] = React.useState("");
+  const [workItemId, setWorkItemId] = React.useState("");
   const [isHistoryOpen, setIsHistoryOpen] = React.useState(false);
   const [pendingSaveConfirmation, setPendingSaveConfirmation] =
     React.useState<PendingSaveConfirmation | null>(null);
@@ -149,6 +150,7 @@ export const FloatingTimer = () => {
       saveTimer(
         {
           userNote: note,
+          workItemId: workItemId.trim() || undefined,
           restartTimer: shouldAutoRestart
             ? {
                 userNote: CONTINUING_MY_WORK_NOTE,
@@ -159,6 +161,7 @@ export const FloatingTimer = () => {
         {
           onSuccess: () => {
             toast.success("Timer successfully saved");
+            setWorkItemId("");
             if (!shouldAutoRestart) {
               removeSegment("timer");
             }
@@ -173,6 +176,7 @@ export const FloatingTimer = () => {
       restartTimerParams,
       saveTimer,
       timer,
+      workItemId,
     ],
   );
 
@@ -433,6 +437,15 @@ export const FloatingTimer = () => {
                   </Popover>
                 </div>
               </div>
+              <Input
+                type="text"
+                inputMode="numeric"
+                placeholder="Work item, e.g. #1234"
+                aria-label="Work item to link the entry to"
+                value={workItemId}
+                onChange={(e) => setWorkItemId(e.target.value)}
+                className="h-8 w-full rounded-md border border-border bg-background px-4 text-xs text-foreground"
+              />
             </div>
             <TimeSummary
               className="pt-2"
@@ -4,6 +4,9 @@ import {
   MessagesSquare,
   CheckSquare,
   AtSign,
+  GitPullRequest,
+  ThumbsUp,
+  GitMerge,
 } from "lucide-react";
 import { match } from "ts-pattern";
 import { cn } from "@/lib/utils";
@@ -25,5 +28,14 @@ export function NotificationIcon(props: {
     .with(NotificationType.CommentMentioned, () => (
       <AtSign className={cn(props.className)} />
     ))
+    .with(NotificationType.PrCreated, () => (
+      <GitPullRequest className={cn(props.className)} />
+    ))
+    .with(NotificationType.VoteChanged, () => (
+      <ThumbsUp className={cn(props.className)} />
+    ))
+    .with(NotificationType.MergeConflicts, () => (
+      <GitMerge className={cn(props.className)} />
+    ))
     .exhaustive();
 }
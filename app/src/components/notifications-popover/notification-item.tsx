@@ -89,6 +89,9 @@ function ColoredNotificationIconWithTooltip(props: { type: NotificationType }) {
     [NotificationType.ThreadUpdated]: "text-yellow-500",
     [NotificationType.PrClosed]: "text-red-500",
     [NotificationType.CommentMentioned]: "text-purple-500",
+    [NotificationType.PrCreated]: "text-green-500",
+    [NotificationType.VoteChanged]: "text-teal-500",
+    [NotificationType.MergeConflicts]: "text-orange-500",
   };
 
   return (
@@ -105,6 +108,9 @@ function ColoredNotificationIconWithTooltip(props: { type: NotificationType }) {
           .with(NotificationType.ThreadUpdated, () => "Thread updated")
           .with(NotificationType.PrClosed, () => "Pull request closed")
           .with(NotificationType.CommentMentioned, () => "You were mentioned")
+          .with(NotificationType.PrCreated, () => "Review requested")
+          .with(NotificationType.VoteChanged, () => "Vote changed")
+          .with(NotificationType.MergeConflicts, () => "Merge conflicts")
           .exhaustive()}
       </TooltipContent>
     </Tooltip>
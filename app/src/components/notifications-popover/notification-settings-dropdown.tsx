@@ -1,5 +1,9 @@
 import { notificationsMutations } from "@/lib/api/mutations/notifications";
-import { notificationsQueries } from "@/lib/api/queries/notifications";
+import {
+  ChatWebhookEvent,
+  NotificationWebhook,
+  notificationsQueries,
+} from "@/lib/api/queries/notifications";
 import {
   hasPushPermission,
   requestNotificationPermission,
@@ -13,21 +17,35 @@ import {
   CheckCircle2,
   Bell,
   Trash2,
+  MessageSquarePlus,
 } from "lucide-react";
 import { useState, useEffect } from "react";
 import { toast } from "sonner";
 import { Tooltip, TooltipContent, TooltipTrigger } from "../ui/tooltip";
 import {
   DropdownMenu,
+  DropdownMenuCheckboxItem,
   DropdownMenuContent,
   DropdownMenuItem,
   DropdownMenuLabel,
   DropdownMenuSeparator,
+  DropdownMenuSub,
+  DropdownMenuSubContent,
+  DropdownMenuSubTrigger,
   DropdownMenuTrigger,
 } from "../ui/dropdown-menu";
 import { atomWithStorage } from "jotai/utils";
 import { useAtom } from "jotai/react";
 
+const CHAT_WEBHOOK_EVENTS: Array<{ event: ChatWebhookEvent; label: string }> =
+  [
+    { event: "pull_request.blocked", label: "My pull request is blocked" },
+    { event: "timer.long_running", label: "Timer is left running" },
+    { event: "day.unlogged", label: "A workday has nothing registered" },
+    { event: "week.unreported", label: "Week not reported by Friday" },
+    { event: "entries.anomalies", label: "Time entry anomalies" },
+  ];
+
 const deviceNamePersistedAtom = atomWithStorage<string | undefined>(
   "deviceName",
   undefined,
@@ -68,6 +86,53 @@ export function NotificationSettingsDropdown() {
       },
     });
 
+  const { data: webhooks = [] } = useQuery(notificationsQueries.webhooks());
+
+  const { mutate: addWebhook } = notificationsMutations.useAddWebhook({
+    onSuccess: () => {
+      toast.success("Chat webhook added.");
+    },
+    onError: () => {
+      toast.error(
+        "Failed to add webhook. Only Slack and Teams webhook URLs are supported.",
+      );
+    },
+  });
+
+  const { mutate: deleteWebhook } = notificationsMutations.useDeleteWebhook({
+    onSuccess: () => {
+      toast.success("Chat webhook deleted.");
+    },
+    onError: () => {
+      toast.error("Failed to delete chat webhook.");
+    },
+  });
+
+  const { mutate: updateWebhookEvents } =
+    notificationsMutations.useUpdateWebhookEvents({
+      onError: () => {
+        toast.error("Failed to update chat webhook events.");
+      },
+    });
+
+  const handleToggleWebhookEvent = (
+    webhook: NotificationWebhook,
+    event: ChatWebhookEvent,
+    enabled: boolean,
+  ) => {
+    const events = enabled
+      ? [...webhook.events, event]
+      : webhook.events.filter((e) => e !== event);
+    updateWebhookEvents({ id: webhook.id, events });
+  };
+
+  const handleAddWebhook = () => {
+    const url = prompt("Paste a Slack or Microsoft Teams incoming webhook URL");
+    if (url?.trim()) {
+      addWebhook(url.trim());
+    }
+  };
+
   const handleRequestPermission = () => {
     requestNotificationPermission({
       onGranted: () => {
@@ -223,6 +288,53 @@ export function NotificationSettingsDropdown() {
             ))}
           </>
         )}
+
+        <DropdownMenuSeparator />
+        <DropdownMenuItem onClick={handleAddWebhook} className="gap-2">
+          <MessageSquarePlus className="size-4" />
+          <span className="text-xs">Add Slack/Teams webhook</span>
+        </DropdownMenuItem>
+        {!!webhooks.length && (
+          <>
+            <DropdownMenuLabel>Chat webhooks</DropdownMenuLabel>
+            {webhooks.map((webhook) => (
+              <DropdownMenuSub key={webhook.id}>
+                <DropdownMenuSubTrigger className="text-xs">
+                  <span className="truncate">
+                    {webhook.kind === "slack" ? "Slack" : "Teams"} ·{" "}
+                    {webhook.host}
+                  </span>
+                </DropdownMenuSubTrigger>
+                <DropdownMenuSubContent className="w-64">
+                  <DropdownMenuLabel className="text-xs">
+                    Also post when
+                  </DropdownMenuLabel>
+                  {CHAT_WEBHOOK_EVENTS.map(({ event, label }) => (
+                    <DropdownMenuCheckboxItem
+                      key={event}
+                      className="text-xs"
+                      checked={webhook.events.includes(event)}
+                      onSelect={(e) => e.preventDefault()}
+                      onCheckedChange={(checked) =>
+                        handleToggleWebhookEvent(webhook, event, checked)
+                      }
+                    >
+                      {label}
+                    </DropdownMenuCheckboxItem>
+                  ))}
+                  <DropdownMenuSeparator />
+                  <DropdownMenuItem
+                    className="gap-2 text-xs text-destructive"
+                    onClick={() => deleteWebhook(webhook.id)}
+                  >
+                    <Trash2 className="size-4" />
+                    Delete webhook
+                  </DropdownMenuItem>
+                </DropdownMenuSubContent>
+              </DropdownMenuSub>
+            ))}
+          </>
+        )}
       </DropdownMenuContent>
     </DropdownMenu>
   );
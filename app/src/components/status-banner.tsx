@@ -0,0 +1,45 @@
+import { useQuery } from "@tanstack/react-query";
+import { AlertTriangleIcon, InfoIcon, WrenchIcon } from "lucide-react";
+import { statusQueries } from "@/lib/api/queries/status";
+import { cn } from "@/lib/utils";
+
+const SEVERITY_CLASSES = {
+  info: "border-sky-500/30 bg-sky-500/10 text-sky-700 dark:text-sky-300",
+  warning:
+    "border-amber-500/30 bg-amber-500/10 text-amber-700 dark:text-amber-300",
+  critical: "border-destructive/30 bg-destructive/10 text-destructive",
+} as const;
+
+/** Announcement set by an admin, shown above every page. */
+export function StatusBanner() {
+  const { data: status } = useQuery(statusQueries.status());
+  const banner = status?.banner;
+
+  if (!banner) {
+    return null;
+  }
+
+  const Icon = banner.maintenance
+    ? WrenchIcon
+    : banner.severity === "info"
+      ? InfoIcon
+      : AlertTriangleIcon;
+
+  return (
+    <div
+      role="status"
+      className={cn(
+        "flex items-center gap-2 border-b px-4 py-2 text-sm",
+        SEVERITY_CLASSES[banner.severity],
+      )}
+    >
+      <Icon className="size-4 shrink-0" />
+      <p>
+        {banner.maintenance && (
+          <span className="font-medium">Maintenance: </span>
+        )}
+        {banner.message}
+      </p>
+    </div>
+  );
+}
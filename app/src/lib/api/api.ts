@@ -23,6 +23,8 @@ export const api = ky.create({
   credentials: "include",
   retry: 0,
   timeout: 45_000,
+  // Shown in other clients (e.g. the TUI) while this app owns the timer.
+  headers: { "X-Toki-Device": "Web" },
   hooks: {
     afterResponse: [
       (_, __, response) => {
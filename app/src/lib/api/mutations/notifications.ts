@@ -6,7 +6,11 @@ import {
 } from "@tanstack/react-query";
 import { api } from "../api";
 import { DefaultMutationOptions } from "./mutations";
-import { Notification, notificationsQueries } from "../queries/notifications";
+import {
+  ChatWebhookEvent,
+  Notification,
+  notificationsQueries,
+} from "../queries/notifications";
 import { subscribeUser } from "@/lib/notifications/web_push";
 
 export enum NotificationType {
@@ -14,6 +18,9 @@ export enum NotificationType {
   ThreadAdded = "ThreadAdded",
   ThreadUpdated = "ThreadUpdated",
   CommentMentioned = "CommentMentioned",
+  PrCreated = "PrCreated",
+  VoteChanged = "VoteChanged",
+  MergeConflicts = "MergeConflicts",
 }
 
 export const notificationsMutations = {
@@ -25,6 +32,9 @@ export const notificationsMutations = {
   useRemovePrException,
   useSubscribeToPush,
   useDeletePushSubscription,
+  useAddWebhook,
+  useUpdateWebhookEvents,
+  useDeleteWebhook,
 };
 
 const notificationsListQueryKey = ["notifications", "list"] as const;
@@ -239,6 +249,8 @@ type PreferencePayload = {
     notificationType: NotificationType;
     enabled: boolean;
     pushEnabled: boolean;
+    emailEnabled: boolean;
+    webhookEnabled: boolean;
   };
 };
 
@@ -377,3 +389,63 @@ function useDeletePushSubscription(options?: DefaultMutationOptions<number>) {
     },
   });
 }
+
+function useAddWebhook(options?: DefaultMutationOptions<string>) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationKey: ["notifications", "webhooks", "add"],
+    mutationFn: (url: string) =>
+      api.post("notifications/webhooks", { json: { url } }),
+    ...options,
+    onSuccess: (data, vars, ctx) => {
+      queryClient.invalidateQueries({
+        queryKey: notificationsQueries.webhooks().queryKey,
+      });
+      options?.onSuccess?.(data, vars, ctx);
+    },
+  });
+}
+
+function useUpdateWebhookEvents(
+  options?: DefaultMutationOptions<{
+    id: number;
+    events: Array<ChatWebhookEvent>;
+  }>,
+) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationKey: ["notifications", "webhooks", "events"],
+    mutationFn: ({
+      id,
+      events,
+    }: {
+      id: number;
+      events: Array<ChatWebhookEvent>;
+    }) => api.put(`notifications/webhooks/${id}/events`, { json: { events } }),
+    ...options,
+    onSuccess: (data, vars, ctx) => {
+      queryClient.invalidateQueries({
+        queryKey: notificationsQueries.webhooks().queryKey,
+      });
+      options?.onSuccess?.(data, vars, ctx);
+    },
+  });
+}
+
+function useDeleteWebhook(options?: DefaultMutationOptions<number>) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationKey: ["notifications", "webhooks", "delete"],
+    mutationFn: (id: number) => api.delete(`notifications/webhooks/${id}`),
+    ...options,
+    onSuccess: (data, vars, ctx) => {
+      queryClient.invalidateQueries({
+        queryKey: notificationsQueries.webhooks().queryKey,
+      });
+      options?.onSuccess?.(data, vars, ctx);
+    },
+  });
+}
@@ -32,6 +32,7 @@ export const timeTrackingMutations = {
   useEditProjectRegistration,
   useDeleteProjectRegistration,
   useCreateProjectRegistration,
+  useImproveNote,
   useImportKleerUsers,
   useLinkKleerUsersByEmail,
   useUpsertKleerUserLink,
@@ -451,6 +452,19 @@ function useCreateProjectRegistration(
   });
 }
 
+function useImproveNote(
+  options?: DefaultMutationOptions<ImproveNotePayload, ImproveNoteResponse>,
+) {
+  return useMutation({
+    mutationKey: ["time-tracking", "improveNote"],
+    mutationFn: (body: ImproveNotePayload) =>
+      api
+        .post("time-tracking/improve-note", { json: body })
+        .json<ImproveNoteResponse>(),
+    ...options,
+  });
+}
+
 function useImportKleerUsers(options?: DefaultMutationOptions<void>) {
   const queryClient = useQueryClient();
 
@@ -541,6 +555,7 @@ export type StartTimerMutationAsync = MutationFnAsync<typeof useStartTimer>;
 
 export type SaveTimerPayload = {
   userNote?: string;
+  workItemId?: string;
   restartTimer?: {
     userNote: string;
     projectId?: string;
@@ -599,6 +614,19 @@ export type CreateProjectRegistrationPayload = {
   userNote: string;
 };
 
+export type ImproveNotePayload = {
+  note: string;
+  workItem?: {
+    organization: string;
+    project: string;
+    workItemId: string;
+  };
+};
+
+export type ImproveNoteResponse = {
+  suggestion: string;
+};
+
 export type UpsertKleerUserLinkPayload = {
   userId: number;
   providerUserId: string;
@@ -9,6 +9,8 @@ import {
   type BoardColumn,
   type BoardResponse,
   type BoardWorkItem,
+  type WipLimitEnforcement,
+  type WipLimitViolation,
   workItemsQueries,
 } from "../queries/workItems";
 import type { DefaultMutationOptions } from "./mutations";
@@ -22,8 +24,24 @@ export type MoveBoardItemPayload = {
   team?: string;
 };
 
+export type MoveBoardItemResult = {
+  /** Set when the move succeeded but exceeded a warn-only WIP limit. */
+  wipLimitWarning?: WipLimitViolation;
+};
+
+export type SetWipLimitPayload = {
+  organization: string;
+  project: string;
+  team?: string;
+  columnName: string;
+  /** `null` removes the column's limit. */
+  limit: number | null;
+  enforcement: WipLimitEnforcement;
+};
+
 export const workItemsMutations = {
   useMoveBoardItem,
+  useSetWipLimit,
 };
 
 type MoveBoardItemMutationContext = {
@@ -102,7 +120,7 @@ function sortItemsByColumnAndPriority(
 function useMoveBoardItem(
   options?: DefaultMutationOptions<
     MoveBoardItemPayload,
-    Response,
+    MoveBoardItemResult,
     unknown,
     MoveBoardItemMutationContext
   >,
@@ -111,10 +129,15 @@ function useMoveBoardItem(
 
   return useMutation({
     mutationKey: ["work-items", "move"],
-    mutationFn: (body: MoveBoardItemPayload) =>
-      api.post("work-items/move", {
+    mutationFn: async (body: MoveBoardItemPayload) => {
+      const response = await api.post("work-items/move", {
         json: body,
-      }),
+      });
+      if (response.status === 204) {
+        return {};
+      }
+      return response.json<MoveBoardItemResult>();
+    },
     ...options,
     onMutate: async (vars) => {
       const boardQueryKey = workItemsQueries
@@ -191,3 +214,22 @@ function useMoveBoardItem(
     },
   });
 }
+
+function useSetWipLimit(options?: DefaultMutationOptions<SetWipLimitPayload>) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationKey: ["work-items", "wip-limits"],
+    mutationFn: (body: SetWipLimitPayload) =>
+      api.put("work-items/wip-limits", {
+        json: body,
+      }),
+    ...options,
+    onSuccess: (data, vars, ctx) => {
+      queryClient.invalidateQueries({
+        queryKey: [...workItemsQueries.baseKey, "board"],
+      });
+      options?.onSuccess?.(data, vars, ctx);
+    },
+  });
+}
@@ -57,6 +57,12 @@ export const notificationsQueries = {
           .get("notifications/push-subscriptions")
           .json<Array<PushSubscriptionInfo>>(),
     }),
+  webhooks: () =>
+    queryOptions({
+      queryKey: ["notifications", "webhooks"],
+      queryFn: async () =>
+        api.get("notifications/webhooks").json<Array<NotificationWebhook>>(),
+    }),
 };
 
 export type Notification = {
@@ -80,6 +86,8 @@ export type NotificationRule = {
   notificationType: NotificationType;
   enabled: boolean;
   pushEnabled: boolean;
+  emailEnabled: boolean;
+  webhookEnabled: boolean;
 };
 
 export type PrNotificationException = {
@@ -96,3 +104,18 @@ export type PushSubscriptionInfo = {
   device: string;
   createdAt: string;
 };
+
+export type ChatWebhookEvent =
+  | "pull_request.blocked"
+  | "timer.long_running"
+  | "day.unlogged"
+  | "week.unreported"
+  | "entries.anomalies";
+
+export type NotificationWebhook = {
+  id: number;
+  kind: "slack" | "teams";
+  host: string;
+  events: Array<ChatWebhookEvent>;
+  createdAt: string;
+};
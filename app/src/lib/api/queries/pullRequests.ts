@@ -32,6 +32,8 @@ export type ListPullRequest = {
   mergeStatus: MergeStatus | null;
   threads: Thread[];
   workItems: WorkItem[];
+  ciStatus: CiStatus | null;
+  checks: PullRequestCheck[];
   reviewers: Reviewer[];
   blockedBy: Reviewer[];
   approvedBy: Reviewer[];
@@ -64,6 +66,7 @@ export type PullRequest = {
   threads: Thread[];
   commits: Commit[];
   workItems: WorkItem[];
+  checks: PullRequestCheck[];
   blockedBy: Reviewer[];
 };
 
@@ -103,6 +106,16 @@ export type MergeStatus =
   | "rejectedByPolicy"
   | "failure";
 
+export type CiStatus = "pending" | "succeeded" | "failed";
+
+export type PullRequestCheck = {
+  name: string;
+  state: "pending" | "succeeded" | "failed" | "notApplicable";
+  description: string | null;
+  targetUrl: string | null;
+  updatedAt: string | null;
+};
+
 export type MergeFailureType =
   | "none"
   | "unknown"
@@ -1,6 +1,7 @@
 import { differsQueries } from "./differs";
 import { pullRequestsQueries } from "./pullRequests";
 import { commitsQueries } from "./commits";
+import { statusQueries } from "./status";
 import { timeTrackingQueries } from "./time-tracking";
 import { userQueries } from "./user";
 import { workItemsQueries } from "./workItems";
@@ -10,6 +11,7 @@ export const queries = {
   ...differsQueries,
   ...pullRequestsQueries,
   ...commitsQueries,
+  ...statusQueries,
   ...timeTrackingQueries,
   ...workItemsQueries,
 };
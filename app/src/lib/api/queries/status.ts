@@ -0,0 +1,27 @@
+import { queryOptions } from "@tanstack/react-query";
+import { api } from "../api";
+
+export const statusQueries = {
+  status: () =>
+    queryOptions({
+      queryKey: ["status"],
+      queryFn: () => api.get("status").json<StatusResponse>(),
+      refetchInterval: 60 * 1000,
+    }),
+};
+
+export type BannerSeverity = "info" | "warning" | "critical";
+
+export type StatusBanner = {
+  message: string;
+  severity: BannerSeverity;
+  startsAt: string | null;
+  endsAt: string | null;
+  /** Time tracking is paused while the banner is shown. */
+  maintenance: boolean;
+};
+
+export type StatusResponse = {
+  banner: StatusBanner | null;
+  maintenance: boolean;
+};
@@ -203,6 +203,8 @@ export type TimerResponse = {
   hours: number;
   minutes: number;
   seconds: number;
+  /** Device running the timer, if the client that started it sent a name. */
+  device?: string;
 };
 
 /** Timer history entry from the database. */
@@ -252,6 +254,7 @@ export type TimeEntry = {
   endTime: string | null;
   weekNumber: number;
   status: TimeEntryStatus;
+  workItemId?: string | null;
 };
 
 export type TimeEntryStatus = "open" | "approved" | "certified";
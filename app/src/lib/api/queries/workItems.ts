@@ -73,9 +73,25 @@ export type BoardColumn = {
   order: number;
 };
 
+export type WipLimitEnforcement = "warn" | "block";
+
+export type BoardColumnWipLimit = {
+  columnName: string;
+  limit: number;
+  enforcement: WipLimitEnforcement;
+};
+
+export type WipLimitViolation = {
+  columnName: string;
+  limit: number;
+  itemCount: number;
+  enforcement: WipLimitEnforcement;
+};
+
 export type BoardResponse = {
   columns: BoardColumn[];
   items: BoardWorkItem[];
+  wipLimits: BoardColumnWipLimit[];
 };
 
 export type Iteration = {
@@ -87,6 +103,15 @@ export type Iteration = {
   isCurrent: boolean;
 };
 
+export type SprintCapacity = {
+  iterationName: string;
+  iterationPath: string;
+  startDate: string;
+  finishDate: string;
+  capacityHours: number | null;
+  loggedHours: number;
+};
+
 export type FormatForLlmResponse = {
   markdown: string;
   hasImages: boolean;
@@ -131,6 +156,22 @@ export const workItemsQueries = {
           })
           .json<BoardResponse>(),
     }),
+  sprintCapacity: (params: {
+    organization: string;
+    project: string;
+    team?: string;
+  }) =>
+    queryOptions({
+      queryKey: [...workItemsQueries.baseKey, "sprintCapacity", params],
+      queryFn: async () =>
+        api
+          .get("work-items/sprint-capacity", {
+            searchParams: Object.fromEntries(
+              Object.entries(params).filter(([, v]) => v !== undefined),
+            ),
+          })
+          .json<Array<SprintCapacity>>(),
+    }),
   formatForLlm: (params: {
     organization: string;
     project: string;
@@ -4,8 +4,9 @@ export async function subscribeUser(deviceName?: string) {
   if ("serviceWorker" in navigator) {
     try {
       const registration = await navigator.serviceWorker.ready;
-      const publicVapidKey =
-        "BBxWgyzTUn1tmVdmImHR67ZWFUuvqY_l8UErBpkHRcaakYqV4TjUM1_el0P0M3rQYN-gzO2tsykLuOLMFXG8y50";
+      const publicVapidKey = await api
+        .get("notifications/vapid-public-key")
+        .json<string>();
 
       const subscription = await registration.pushManager.subscribe({
         userVisibleOnly: true,
@@ -1,13 +1,20 @@
 import { ScrollArea } from "@/components/ui/scroll-area";
-import type { BoardWorkItem } from "@/lib/api/queries/workItems";
+import type {
+  BoardColumnWipLimit,
+  BoardWorkItem,
+  WipLimitEnforcement,
+} from "@/lib/api/queries/workItems";
 import type { TimeReportMode } from "@/lib/time-report";
 import { cn } from "@/lib/utils";
 import { BoardCard } from "./board-card";
+import { WipLimitPopover } from "./wip-limit-popover";
 
 export function BoardColumn({
   columnId,
   title,
   items,
+  itemCount,
+  wipLimit,
   allColumns,
   organization,
   project,
@@ -20,10 +27,14 @@ export function BoardColumn({
   onColumnDrop,
   onMoveItem,
   onTimerAction,
+  onSetWipLimit,
 }: {
   columnId: string;
   title: string;
   items: BoardWorkItem[];
+  /** Unfiltered number of items in the column, used for the WIP limit. */
+  itemCount: number;
+  wipLimit?: BoardColumnWipLimit;
   allColumns: { id: string; name: string }[];
   organization: string;
   project: string;
@@ -40,8 +51,15 @@ export function BoardColumn({
     targetColumnId: string,
   ) => void;
   onTimerAction: (item: BoardWorkItem, mode: TimeReportMode) => Promise<void>;
+  onSetWipLimit: (
+    columnName: string,
+    limit: number | null,
+    enforcement: WipLimitEnforcement,
+  ) => void;
 }) {
   const isDragging = draggingItemId !== null;
+  const isOverWipLimit = wipLimit !== undefined && itemCount > wipLimit.limit;
+  const isAtWipLimit = wipLimit !== undefined && itemCount === wipLimit.limit;
 
   return (
     <div
@@ -74,9 +92,27 @@ export function BoardColumn({
       {/* Column header */}
       <div className="flex items-center gap-2 border-b border-border/30 px-4 py-3">
         <h3 className="text-sm font-semibold">{title}</h3>
-        <span className="flex h-5 min-w-5 items-center justify-center rounded-full bg-muted px-1.5 text-xs font-medium text-muted-foreground">
-          {items.length}
+        <span
+          className={cn(
+            "flex h-5 min-w-5 items-center justify-center rounded-full bg-muted px-1.5 text-xs font-medium text-muted-foreground",
+            isAtWipLimit && "bg-amber-500/15 text-amber-600",
+            isOverWipLimit && "bg-destructive/15 text-destructive",
+          )}
+          title={
+            wipLimit
+              ? `WIP limit ${wipLimit.limit} (${wipLimit.enforcement === "block" ? "blocking" : "warning"})`
+              : undefined
+          }
+        >
+          {wipLimit ? `${itemCount}/${wipLimit.limit}` : items.length}
         </span>
+        <WipLimitPopover
+          columnName={title}
+          wipLimit={wipLimit}
+          onSave={(limit, enforcement) =>
+            onSetWipLimit(title, limit, enforcement)
+          }
+        />
       </div>
 
       {/* Card list */}
@@ -7,7 +7,11 @@ import { queries } from "@/lib/api/queries/queries";
 import { mutations } from "@/lib/api/mutations/mutations";
 import { timeTrackingMutations } from "@/lib/api/mutations/time-tracking";
 import { timeTrackingQueries } from "@/lib/api/queries/time-tracking";
-import type { BoardWorkItem } from "@/lib/api/queries/workItems";
+import type {
+  BoardWorkItem,
+  WipLimitEnforcement,
+} from "@/lib/api/queries/workItems";
+import { apiErrorMessage, showApiErrorToast } from "@/lib/api/errors";
 import {
   normalizeColumnName,
   resolveColumnIdForItem,
@@ -19,6 +23,7 @@ import {
 import { copyAndSyncTimeReport } from "@/lib/time-report-actions";
 import { BoardColumn } from "./board-column";
 import { BoardFilters } from "./board-filters";
+import { SprintCapacity } from "./sprint-capacity";
 import {
   useCallback,
   useMemo,
@@ -79,6 +84,7 @@ export function BoardView({
   });
   const { data: user } = useSuspenseQuery(queries.me());
   const { mutateAsync: moveBoardItem } = mutations.useMoveBoardItem();
+  const { mutate: setWipLimit } = mutations.useSetWipLimit();
   const { data: timerResponse, isSuccess: timerQuerySuccess } = useQuery({
     ...timeTrackingQueries.getTimer(),
     retry: false,
@@ -150,6 +156,7 @@ export function BoardView({
   );
   const boardItems = useMemo(() => board?.items ?? [], [board]);
   const boardColumns = useMemo(() => board?.columns ?? [], [board]);
+  const wipLimits = useMemo(() => board?.wipLimits ?? [], [board]);
 
   // Extract unique assignees for the member multi-select
   const members = useMemo(() => {
@@ -221,11 +228,33 @@ export function BoardView({
       itemsByColumn.get(columnId)?.push(item);
     }
 
+    // WIP limits apply to the whole column, not just the filtered view.
+    const itemCountByColumn = new Map<string, number>();
+    for (const item of boardItems) {
+      const columnId = resolveColumnIdForItem(
+        item,
+        knownColumnIds,
+        columnIdsByName,
+      );
+      if (columnId) {
+        itemCountByColumn.set(
+          columnId,
+          (itemCountByColumn.get(columnId) ?? 0) + 1,
+        );
+      }
+    }
+
     return columns.map((column) => ({
       ...column,
       items: itemsByColumn.get(column.id) ?? [],
+      itemCount: itemCountByColumn.get(column.id) ?? 0,
+      wipLimit: wipLimits.find(
+        (limit) =>
+          normalizeColumnName(limit.columnName) ===
+          normalizeColumnName(column.name),
+      ),
     }));
-  }, [boardColumns, filteredItems]);
+  }, [boardColumns, boardItems, filteredItems, wipLimits]);
   const visibleColumns = useMemo(
     () =>
       columnsWithItems.filter((column) => !hiddenColumnIdSet.has(column.id)),
@@ -294,7 +323,7 @@ export function BoardView({
       setMovingItemVersion((version) => version + 1);
 
       try {
-        await moveBoardItem({
+        const { wipLimitWarning } = await moveBoardItem({
           organization,
           project,
           workItemId: itemId,
@@ -302,8 +331,13 @@ export function BoardView({
           iterationPath,
           team,
         });
-      } catch {
-        toast.error("Failed to move work item.");
+        if (wipLimitWarning) {
+          toast.warning(
+            `${wipLimitWarning.columnName} is over its WIP limit (${wipLimitWarning.itemCount}/${wipLimitWarning.limit}).`,
+          );
+        }
+      } catch (error) {
+        toast.error(await apiErrorMessage(error, "Failed to move work item."));
       } finally {
         if (movingItemIdsRef.current.delete(itemId)) {
           setMovingItemVersion((version) => version + 1);
@@ -352,6 +386,22 @@ export function BoardView({
     },
     [moveItem],
   );
+  const handleSetWipLimit = useCallback(
+    (
+      columnName: string,
+      limit: number | null,
+      enforcement: WipLimitEnforcement,
+    ) => {
+      setWipLimit(
+        { organization, project, team, columnName, limit, enforcement },
+        {
+          onError: (error) =>
+            void showApiErrorToast(error, "Failed to save WIP limit."),
+        },
+      );
+    },
+    [organization, project, setWipLimit, team],
+  );
   const handleTimerAction = useCallback(
     async (item: BoardWorkItem, mode: TimeReportMode) => {
       const text = buildWorkItemTimeReportText({
@@ -400,19 +450,27 @@ export function BoardView({
 
   return (
     <div className="flex min-w-0 w-full flex-col gap-3">
-      <BoardFilters
-        memberFilter={memberFilter}
-        setMemberFilter={setMemberFilter}
-        members={members}
-        columns={columnsWithItems.map((column) => ({
-          id: column.id,
-          name: column.name,
-          count: column.items.length,
-        }))}
-        hiddenColumnIds={hiddenColumnIds}
-        onToggleColumn={toggleColumnVisibility}
-        onShowAllColumns={showAllColumns}
-      />
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <BoardFilters
+          memberFilter={memberFilter}
+          setMemberFilter={setMemberFilter}
+          members={members}
+          columns={columnsWithItems.map((column) => ({
+            id: column.id,
+            name: column.name,
+            count: column.items.length,
+          }))}
+          hiddenColumnIds={hiddenColumnIds}
+          onToggleColumn={toggleColumnVisibility}
+          onShowAllColumns={showAllColumns}
+        />
+        <SprintCapacity
+          organization={organization}
+          project={project}
+          iterationPath={iterationPath}
+          team={team}
+        />
+      </div>
       <div className="h-[calc(100vh-15rem)] min-w-0 w-full">
         {visibleColumns.length === 0 ? (
           <div className="flex h-full flex-col items-center justify-center gap-3 rounded-xl border border-border/40 bg-muted/20">
@@ -434,6 +492,8 @@ export function BoardView({
                   columnId={column.id}
                   title={column.name}
                   items={column.items}
+                  itemCount={column.itemCount}
+                  wipLimit={column.wipLimit}
                   allColumns={allColumns}
                   organization={organization}
                   project={project}
@@ -448,6 +508,7 @@ export function BoardView({
                   onColumnDrop={handleColumnDrop}
                   onMoveItem={handleMoveItem}
                   onTimerAction={handleTimerAction}
+                  onSetWipLimit={handleSetWipLimit}
                 />
               </div>
             ))}
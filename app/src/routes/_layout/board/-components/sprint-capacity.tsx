@@ -0,0 +1,91 @@
+import { useQuery } from "@tanstack/react-query";
+import { Gauge } from "lucide-react";
+import { queries } from "@/lib/api/queries/queries";
+import {
+  HoverCard,
+  HoverCardContent,
+  HoverCardTrigger,
+} from "@/components/ui/hover-card";
+import { Progress } from "@/components/ui/progress";
+import { cn } from "@/lib/utils";
+
+function formatHours(hours: number) {
+  return `${Math.round(hours * 10) / 10}h`;
+}
+
+/**
+ * The user's logged hours against their Azure DevOps capacity in the shown
+ * sprint, with earlier sprints on hover. Hidden when the user has no
+ * capacity set in the sprint.
+ */
+export function SprintCapacity({
+  organization,
+  project,
+  iterationPath,
+  team,
+}: {
+  organization: string;
+  project: string;
+  iterationPath?: string;
+  team?: string;
+}) {
+  const { data: sprints } = useQuery({
+    ...queries.sprintCapacity({ organization, project, team }),
+    retry: false,
+  });
+
+  const sprint = iterationPath
+    ? sprints?.find((s) => s.iterationPath === iterationPath)
+    : sprints?.[0];
+  if (!sprint || !sprint.capacityHours) {
+    return null;
+  }
+
+  const usage = sprint.loggedHours / sprint.capacityHours;
+
+  return (
+    <HoverCard openDelay={200}>
+      <HoverCardTrigger asChild>
+        <div className="flex items-center gap-2 text-xs text-muted-foreground">
+          <Gauge className="h-4 w-4" />
+          <span>
+            {formatHours(sprint.loggedHours)} of{" "}
+            {formatHours(sprint.capacityHours)} capacity logged
+          </span>
+          <Progress
+            value={Math.min(usage, 1) * 100}
+            className={cn("h-1.5 w-24", usage > 1 && "bg-amber-500/30")}
+          />
+        </div>
+      </HoverCardTrigger>
+      <HoverCardContent className="w-72" align="start">
+        <p className="mb-2 text-sm font-medium">Sprint capacity</p>
+        <div className="flex flex-col gap-1.5">
+          {sprints
+            ?.filter((s) => s.capacityHours)
+            .map((s) => (
+              <div
+                key={s.iterationPath}
+                className="flex items-center justify-between gap-2 text-xs"
+              >
+                <span className="truncate">{s.iterationName}</span>
+                <span
+                  className={cn(
+                    "shrink-0 tabular-nums text-muted-foreground",
+                    s.loggedHours > (s.capacityHours ?? 0) &&
+                      "text-amber-600 dark:text-amber-400",
+                  )}
+                >
+                  {formatHours(s.loggedHours)} /{" "}
+                  {formatHours(s.capacityHours ?? 0)}
+                </span>
+              </div>
+            ))}
+        </div>
+        <p className="mt-2 text-xs text-muted-foreground">
+          Capacity leaves out weekends and days off planned in Azure DevOps.
+        </p>
+      </HoverCardContent>
+    </HoverCard>
+  );
+}
@@ -0,0 +1,97 @@
+import { useState } from "react";
+import { Gauge } from "lucide-react";
+import {
+  Popover,
+  PopoverContent,
+  PopoverTrigger,
+} from "@/components/ui/popover";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Switch } from "@/components/ui/switch";
+import type {
+  BoardColumnWipLimit,
+  WipLimitEnforcement,
+} from "@/lib/api/queries/workItems";
+
+export function WipLimitPopover({
+  columnName,
+  wipLimit,
+  onSave,
+}: {
+  columnName: string;
+  wipLimit?: BoardColumnWipLimit;
+  onSave: (limit: number | null, enforcement: WipLimitEnforcement) => void;
+}) {
+  const [open, setOpen] = useState(false);
+  const [limit, setLimit] = useState("");
+  const [block, setBlock] = useState(false);
+
+  const parsedLimit = Number.parseInt(limit, 10);
+  const isValid = limit.trim() === "" || parsedLimit >= 1;
+
+  return (
+    <Popover
+      open={open}
+      onOpenChange={(next) => {
+        if (next) {
+          setLimit(wipLimit ? String(wipLimit.limit) : "");
+          setBlock(wipLimit?.enforcement === "block");
+        }
+        setOpen(next);
+      }}
+    >
+      <PopoverTrigger asChild>
+        <Button
+          variant="ghost"
+          size="icon"
+          className="ml-auto size-6 text-muted-foreground"
+          aria-label={`WIP limit for ${columnName}`}
+        >
+          <Gauge className="size-3.5" />
+        </Button>
+      </PopoverTrigger>
+      <PopoverContent align="end" className="w-64">
+        <form
+          className="flex flex-col gap-3"
+          onSubmit={(event) => {
+            event.preventDefault();
+            if (!isValid) {
+              return;
+            }
+            onSave(
+              limit.trim() === "" ? null : parsedLimit,
+              block ? "block" : "warn",
+            );
+            setOpen(false);
+          }}
+        >
+          <div className="flex flex-col gap-1.5">
+            <Label htmlFor={`wip-limit-${columnName}`}>WIP limit</Label>
+            <Input
+              id={`wip-limit-${columnName}`}
+              type="number"
+              min={1}
+              placeholder="No limit"
+              value={limit}
+              onChange={(event) => setLimit(event.target.value)}
+            />
+          </div>
+          <div className="flex items-center justify-between gap-2">
+            <Label htmlFor={`wip-limit-block-${columnName}`}>
+              Block moves over the limit
+            </Label>
+            <Switch
+              id={`wip-limit-block-${columnName}`}
+              checked={block}
+              onCheckedChange={setBlock}
+            />
+          </div>
+          <Button type="submit" size="sm" disabled={!isValid}>
+            Save
+          </Button>
+        </form>
+      </PopoverContent>
+    </Popover>
+  );
+}
@@ -22,6 +22,8 @@ import { useQuery } from "@tanstack/react-query";
 import { userQueries } from "@/lib/api/queries/user";
 import { NotificationIcon } from "@/components/notification-icon";
 
+type NotificationChannel = "site" | "push" | "email" | "webhook";
+
 export const Route = createFileRoute(
   "/_layout/repositories/notifications/$repoId",
 )({
@@ -51,7 +53,7 @@ function NotificationsDialog() {
   function handleToggle(
     type: NotificationType,
     enabled: boolean,
-    isPush: boolean = false,
+    channel: NotificationChannel = "site",
   ) {
     const preference = preferences?.find((p) => p.notificationType === type);
     updatePreference({
@@ -61,8 +63,16 @@ function NotificationsDialog() {
         userId: me?.id ?? 0,
         repositoryId: Number(repoId),
         notificationType: type,
-        enabled: isPush ? (preference?.enabled ?? false) : enabled,
-        pushEnabled: isPush ? enabled : (preference?.pushEnabled ?? false),
+        enabled:
+          channel === "site" ? enabled : (preference?.enabled ?? false),
+        pushEnabled:
+          channel === "push" ? enabled : (preference?.pushEnabled ?? false),
+        emailEnabled:
+          channel === "email" ? enabled : (preference?.emailEnabled ?? false),
+        webhookEnabled:
+          channel === "webhook"
+            ? enabled
+            : (preference?.webhookEnabled ?? false),
       },
     });
   }
@@ -81,7 +91,9 @@ function NotificationsDialog() {
           <DialogTitle>Notifications</DialogTitle>
           <DialogDescription className="text-balance text-sm">
             Choose which notifications you want to receive for this repository.
-            You can enable site notifications, push notifications, or both.
+            You can enable site, push, email and chat notifications
+            independently. Chat notifications are posted to the Slack or Teams
+            webhooks set up under notification settings.
           </DialogDescription>
         </DialogHeader>
 
@@ -91,7 +103,7 @@ function NotificationsDialog() {
           </div>
         ) : (
           <div className="space-y-4 pt-2">
-            <div className="grid grid-cols-[1fr,auto,auto] items-center gap-4 border-b pb-2">
+            <div className="grid grid-cols-[1fr,auto,auto,auto,auto] items-center gap-4 border-b pb-2">
               <div /> {/* Empty space for alignment */}
               <Label className="px-2 text-sm font-medium text-muted-foreground">
                 Site
@@ -99,6 +111,12 @@ function NotificationsDialog() {
               <Label className="px-2 text-sm font-medium text-muted-foreground">
                 Push
               </Label>
+              <Label className="px-2 text-sm font-medium text-muted-foreground">
+                Email
+              </Label>
+              <Label className="px-2 text-sm font-medium text-muted-foreground">
+                Chat
+              </Label>
             </div>
 
             <div className="space-y-4">
@@ -133,6 +151,30 @@ function NotificationsDialog() {
                 preferences={preferences}
                 onToggle={handleToggle}
               />
+
+              <NotificationRow
+                type={NotificationType.PrCreated}
+                title="Review Requested"
+                description="Get notified when a new pull request lists you as a reviewer."
+                preferences={preferences}
+                onToggle={handleToggle}
+              />
+
+              <NotificationRow
+                type={NotificationType.VoteChanged}
+                title="Vote Changed"
+                description="Get notified when a reviewer votes on one of your pull requests."
+                preferences={preferences}
+                onToggle={handleToggle}
+              />
+
+              <NotificationRow
+                type={NotificationType.MergeConflicts}
+                title="Merge Conflicts"
+                description="Get notified when one of your pull requests starts having merge conflicts."
+                preferences={preferences}
+                onToggle={handleToggle}
+              />
             </div>
           </div>
         )}
@@ -149,7 +191,7 @@ function NotificationRow(props: {
   onToggle: (
     type: NotificationType,
     enabled: boolean,
-    isPush?: boolean,
+    channel?: NotificationChannel,
   ) => void;
 }) {
   const preference = props.preferences?.find(
@@ -157,7 +199,7 @@ function NotificationRow(props: {
   );
 
   return (
-    <div className="grid grid-cols-[1fr,auto,auto] items-center gap-4">
+    <div className="grid grid-cols-[1fr,auto,auto,auto,auto] items-center gap-4">
       <div className="flex gap-3">
         <NotificationIcon
           type={props.type}
@@ -176,7 +218,23 @@ function NotificationRow(props: {
       <Switch
         id={`${props.type}-push`}
         checked={preference?.pushEnabled ?? false}
-        onCheckedChange={(checked) => props.onToggle(props.type, checked, true)}
+        onCheckedChange={(checked) =>
+          props.onToggle(props.type, checked, "push")
+        }
+      />
+      <Switch
+        id={`${props.type}-email`}
+        checked={preference?.emailEnabled ?? false}
+        onCheckedChange={(checked) =>
+          props.onToggle(props.type, checked, "email")
+        }
+      />
+      <Switch
+        id={`${props.type}-webhook`}
+        checked={preference?.webhookEnabled ?? false}
+        onCheckedChange={(checked) =>
+          props.onToggle(props.type, checked, "webhook")
+        }
       />
     </div>
   );
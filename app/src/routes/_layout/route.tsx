@@ -2,6 +2,7 @@ import { CmdK } from "@/components/cmd-k";
 import { LoadingSpinner } from "@/components/loading-spinner";
 import { FloatingTimer } from "@/components/floating-timer";
 import { SideNavWrapper } from "@/components/side-nav";
+import { StatusBanner } from "@/components/status-banner";
 import { Toaster } from "@/components/ui/sonner";
 import { TooltipProvider } from "@/components/ui/tooltip";
 import { Outlet, createFileRoute } from "@tanstack/react-router";
@@ -19,6 +20,7 @@ function LayoutComponent() {
   return (
     <TooltipProvider delayDuration={0}>
       <SideNavWrapper>
+        <StatusBanner />
         <Suspense fallback={<FullscreenLoading />}>
           <Outlet />
         </Suspense>
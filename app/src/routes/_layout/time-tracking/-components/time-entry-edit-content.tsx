@@ -1,6 +1,6 @@
 import { useEffect, useState } from "react";
 import dayjs from "dayjs";
-import { CalendarIcon, SaveIcon, TrashIcon } from "lucide-react";
+import { CalendarIcon, SaveIcon, SparklesIcon, TrashIcon } from "lucide-react";
 import { toast } from "sonner";
 import {
   TimeEntry,
@@ -115,6 +115,26 @@ export function TimeEntryEditContent(props: TimeEntryEditContentProps) {
       onError: apiErrorToast("Failed to delete time entry"),
     });
 
+  const { mutate: improveNote, isPending: isImprovingNote } =
+    timeTrackingMutations.useImproveNote({
+      onError: apiErrorToast("Failed to improve note"),
+    });
+
+  const handleImproveNote = () => {
+    const original = note ?? "";
+    improveNote(
+      { note: original },
+      {
+        onSuccess: ({ suggestion }) => {
+          setNote(suggestion);
+          toast.success("Note improved", {
+            action: { label: "Undo", onClick: () => setNote(original) },
+          });
+        },
+      },
+    );
+  };
+
   const handleSave = () => {
     const startDateTime = dayjs(`${selectedDate}T${startTime}`);
     const computedEndTime = endTime
@@ -263,12 +283,27 @@ export function TimeEntryEditContent(props: TimeEntryEditContentProps) {
 
         <div className="space-y-2">
           <label className="text-sm font-medium">Note</label>
-          <Input
-            value={note ?? ""}
-            onChange={(event) => setNote(event.target.value)}
-            className="rounded-xl border-border/50 bg-muted/30"
-            placeholder="What did you work on?"
-          />
+          <div className="flex gap-2">
+            <Input
+              value={note ?? ""}
+              onChange={(event) => setNote(event.target.value)}
+              className="rounded-xl border-border/50 bg-muted/30"
+              placeholder="What did you work on?"
+            />
+            <Button
+              type="button"
+              variant="outline"
+              className="shrink-0 rounded-xl border-border/50"
+              disabled={!note?.trim() || isImprovingNote}
+              onClick={handleImproveNote}
+              title="Suggest a clearer note"
+            >
+              <SparklesIcon
+                className={cn("mr-2 h-4 w-4", isImprovingNote && "animate-pulse")}
+              />
+              Improve
+            </Button>
+          </div>
         </div>
 
         <div className="flex flex-wrap items-end gap-6">
@@ -10,3 +10,5 @@ pub use repo_client::RepoClientError;
 pub use repo_client::TaskboardColumnDefinition;
 pub use repo_client::TaskboardWorkItemColumnAssignment;
 pub use repo_client::TeamIteration;
+pub use repo_client::TeamIterationCapacity;
+pub use repo_client::TeamMemberCapacity;
@@ -0,0 +1,163 @@
+use azure_devops_rust_api::wit::models::{json_patch_operation::Op, JsonPatchOperation};
+use serde_json::Value;
+
+const PARENT_RELATION: &str = "System.LinkTypes.Hierarchy-Reverse";
+
+/// A change to one field of a work item, applied with
+/// [`RepoClient::update_work_item`](crate::RepoClient::update_work_item).
+///
+/// Fields are keyed by reference name, e.g. `System.State` or
+/// `Custom.Team`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldPatch {
+    /// Set the field, replacing any current value.
+    Set { field: String, value: Value },
+    /// Clear the field.
+    Remove { field: String },
+}
+
+impl FieldPatch {
+    pub fn set(field: impl Into<String>, value: impl Into<Value>) -> Self {
+        Self::Set {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn remove(field: impl Into<String>) -> Self {
+        Self::Remove {
+            field: field.into(),
+        }
+    }
+
+    pub fn title(title: impl Into<String>) -> Self {
+        Self::set("System.Title", title.into())
+    }
+
+    pub fn state(state: impl Into<String>) -> Self {
+        Self::set("System.State", state.into())
+    }
+
+    /// Assign the work item to a user, by unique name (usually their email),
+    /// or unassign it with `None`.
+    pub fn assigned_to(unique_name: Option<&str>) -> Self {
+        Self::set("System.AssignedTo", unique_name.unwrap_or_default())
+    }
+
+    /// Replace the work item's tags. Azure DevOps stores them as one
+    /// `; `-separated string.
+    pub fn tags<I, S>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let tags = tags
+            .into_iter()
+            .filter_map(|tag| {
+                let tag = tag.as_ref().trim();
+                (!tag.is_empty()).then(|| tag.to_string())
+            })
+            .collect::<Vec<_>>();
+        Self::set("System.Tags", tags.join("; "))
+    }
+
+    pub fn field(&self) -> &str {
+        match self {
+            Self::Set { field, .. } | Self::Remove { field } => field,
+        }
+    }
+
+    pub(crate) fn into_operation(self) -> JsonPatchOperation {
+        let path = Some(format!("/fields/{}", self.field()));
+        let (op, value) = match self {
+            // `add` on an existing field replaces it, and unlike `replace`
+            // also works on fields that have never been set.
+            Self::Set { value, .. } => (Op::Add, Some(value)),
+            Self::Remove { .. } => (Op::Remove, None),
+        };
+        JsonPatchOperation {
+            op: Some(op),
+            path,
+            value,
+            from: None,
+        }
+    }
+}
+
+/// Operation linking a new work item to its parent, by the parent's API URL.
+pub(crate) fn parent_link_operation(organization: &str, parent_id: i32) -> JsonPatchOperation {
+    JsonPatchOperation {
+        op: Some(Op::Add),
+        path: Some("/relations/-".to_string()),
+        value: Some(serde_json::json!({
+            "rel": PARENT_RELATION,
+            "url": format!("https://dev.azure.com/{organization}/_apis/wit/workItems/{parent_id}"),
+        })),
+        from: None,
+    }
+}
+
+/// Operation failing the whole update unless the work item is still at
+/// `revision`.
+pub(crate) fn revision_test_operation(revision: i32) -> JsonPatchOperation {
+    JsonPatchOperation {
+        op: Some(Op::Test),
+        path: Some("/rev".to_string()),
+        value: Some(revision.into()),
+        from: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn patches_become_field_operations() {
+        let operation = FieldPatch::set("Custom.Effort", 3.5).into_operation();
+        assert_eq!(operation.op, Some(Op::Add));
+        assert_eq!(operation.path.as_deref(), Some("/fields/Custom.Effort"));
+        assert_eq!(operation.value, Some(json!(3.5)));
+
+        let operation = FieldPatch::remove("Custom.Effort").into_operation();
+        assert_eq!(operation.op, Some(Op::Remove));
+        assert_eq!(operation.value, None);
+    }
+
+    #[test]
+    fn parent_links_point_at_the_parent_work_item() {
+        let operation = parent_link_operation("my-org", 4711);
+
+        assert_eq!(operation.path.as_deref(), Some("/relations/-"));
+        assert_eq!(
+            operation.value,
+            Some(json!({
+                "rel": "System.LinkTypes.Hierarchy-Reverse",
+                "url": "https://dev.azure.com/my-org/_apis/wit/workItems/4711",
+            }))
+        );
+    }
+
+    #[test]
+    fn revision_tests_check_the_rev_path() {
+        let operation = revision_test_operation(7);
+
+        assert_eq!(operation.op, Some(Op::Test));
+        assert_eq!(operation.path.as_deref(), Some("/rev"));
+        assert_eq!(operation.value, Some(json!(7)));
+    }
+
+    #[test]
+    fn tags_are_joined_and_trimmed() {
+        assert_eq!(
+            FieldPatch::tags([" backend", "", "urgent "]),
+            FieldPatch::set("System.Tags", "backend; urgent")
+        );
+        assert_eq!(
+            FieldPatch::assigned_to(None),
+            FieldPatch::set("System.AssignedTo", "")
+        );
+    }
+}
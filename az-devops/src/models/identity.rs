@@ -33,6 +33,18 @@ impl From<i64> for Vote {
     }
 }
 
+impl From<Vote> for i64 {
+    fn from(vote: Vote) -> Self {
+        match vote {
+            Vote::Approved => 10,
+            Vote::ApprovedWithSuggestions => 5,
+            Vote::NoResponse => 0,
+            Vote::WaitingForAuthor => -5,
+            Vote::Rejected => -10,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct IdentityWithVote {
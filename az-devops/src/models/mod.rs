@@ -1,14 +1,23 @@
 mod comment;
+mod field_patch;
 mod identity;
 mod iteration;
 mod pull_request;
+mod pull_request_change;
+mod pull_request_check;
+mod rate_limit;
 mod thread;
 mod work_item;
 
 pub use azure_devops_rust_api::git::models::GitCommitRef;
 pub use comment::Comment;
+pub use field_patch::FieldPatch;
+pub(crate) use field_patch::{parent_link_operation, revision_test_operation};
 pub use identity::*;
 pub use iteration::*;
 pub use pull_request::PullRequest;
-pub use thread::Thread;
+pub use pull_request_change::PullRequestChange;
+pub use pull_request_check::{CheckState, CiStatus, PullRequestCheck};
+pub use rate_limit::RateLimitStatus;
+pub use thread::{Thread, ThreadFilter};
 pub use work_item::*;
@@ -30,6 +30,10 @@ pub struct PullRequest {
     pub merge_job_id: Option<String>,
     pub merge_failure_type: Option<MergeFailureType>,
     pub merge_failure_message: Option<String>,
+    /// Head commit of the source branch as of the last merge attempt.
+    /// Changes whenever new commits are pushed to the PR.
+    #[serde(default)]
+    pub last_merge_source_commit_id: Option<String>,
     pub reviewers: Vec<IdentityWithVote>,
     pub url: String,
 }
@@ -43,6 +47,16 @@ impl PullRequest {
         client.get_threads_in_pull_request(self.id).await
     }
 
+    pub async fn threads_filtered(
+        &self,
+        client: &RepoClient,
+        filter: &crate::ThreadFilter,
+    ) -> Result<Vec<crate::Thread>, RepoClientError> {
+        client
+            .get_threads_in_pull_request_filtered(self.id, filter)
+            .await
+    }
+
     pub async fn commits(&self, client: &RepoClient) -> Result<Vec<GitCommitRef>, RepoClientError> {
         client.get_commits_in_pull_request(self.id).await
     }
@@ -79,6 +93,9 @@ impl From<GitPullRequest> for PullRequest {
             merge_job_id: pr.merge_id,
             merge_failure_type: pr.merge_failure_type,
             merge_failure_message: pr.merge_failure_message,
+            last_merge_source_commit_id: pr
+                .last_merge_source_commit
+                .and_then(|commit| commit.commit_id),
             reviewers: pr
                 .reviewers
                 .into_iter()
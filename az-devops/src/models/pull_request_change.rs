@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// A file changed by a pull request, compared against the merge base.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestChange {
+    pub path: String,
+    /// Azure DevOps change type, e.g. `add`, `edit`, `delete` or `edit, rename`.
+    pub change_type: String,
+    pub original_path: Option<String>,
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use azure_devops_rust_api::{
+    build::models::{
+        build::{Result as BuildResult, Status as BuildStatus},
+        Build,
+    },
+    git::models::{git_status::State, GitStatus},
+};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckState {
+    Pending,
+    Succeeded,
+    Failed,
+    /// The check does not apply to this pull request, e.g. a skipped build.
+    NotApplicable,
+}
+
+/// A CI check on a pull request: a status posted to it or a build of its
+/// merge commit.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestCheck {
+    /// Status context as `genre/name`, or the pipeline name for builds.
+    pub name: String,
+    pub state: CheckState,
+    pub description: Option<String>,
+    pub target_url: Option<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+impl PullRequestCheck {
+    /// Keep only the most recent check of each name. Statuses are posted
+    /// again for every push, so older ones describe outdated commits.
+    pub fn latest_per_name(checks: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut latest: HashMap<String, Self> = HashMap::new();
+        for check in checks {
+            match latest.get(&check.name) {
+                Some(existing) if existing.updated_at >= check.updated_at => {}
+                _ => {
+                    latest.insert(check.name.clone(), check);
+                }
+            }
+        }
+
+        let mut checks: Vec<_> = latest.into_values().collect();
+        checks.sort_by(|a, b| a.name.cmp(&b.name));
+        checks
+    }
+}
+
+impl From<GitStatus> for PullRequestCheck {
+    fn from(status: GitStatus) -> Self {
+        let context = status.context.unwrap_or_default();
+        let name = context.name.unwrap_or_default();
+        let name = match context.genre.filter(|genre| !genre.is_empty()) {
+            Some(genre) => format!("{genre}/{name}"),
+            None => name,
+        };
+        let state = match status.state {
+            Some(State::Succeeded) => CheckState::Succeeded,
+            Some(State::Failed | State::Error) => CheckState::Failed,
+            Some(State::NotApplicable) => CheckState::NotApplicable,
+            Some(State::Pending | State::NotSet) | None => CheckState::Pending,
+        };
+
+        Self {
+            name,
+            state,
+            description: status.description,
+            target_url: status.target_url,
+            updated_at: status.updated_date.or(status.creation_date),
+        }
+    }
+}
+
+impl From<Build> for PullRequestCheck {
+    fn from(build: Build) -> Self {
+        let state = match (build.status, build.result) {
+            (Some(BuildStatus::Completed), Some(BuildResult::Succeeded)) => CheckState::Succeeded,
+            (
+                Some(BuildStatus::Completed),
+                Some(BuildResult::Failed | BuildResult::PartiallySucceeded | BuildResult::Canceled),
+            ) => CheckState::Failed,
+            (Some(BuildStatus::Completed), _) => CheckState::NotApplicable,
+            _ => CheckState::Pending,
+        };
+        let target_url = build
+            .links
+            .and_then(|links| links.web)
+            .map(|link| link.href);
+
+        Self {
+            name: build
+                .definition
+                .name
+                .unwrap_or_else(|| format!("Pipeline {}", build.definition.id)),
+            state,
+            description: build.build_number,
+            target_url,
+            updated_at: build.finish_time.or(build.start_time).or(build.queue_time),
+        }
+    }
+}
+
+/// Overall CI state of a pull request, summarizing its checks.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CiStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl CiStatus {
+    /// Failed if any check failed, else pending if any is still running.
+    /// `None` when no check applies.
+    pub fn from_checks(checks: &[PullRequestCheck]) -> Option<Self> {
+        let has = |state| checks.iter().any(|check| check.state == state);
+        if has(CheckState::Failed) {
+            Some(Self::Failed)
+        } else if has(CheckState::Pending) {
+            Some(Self::Pending)
+        } else if has(CheckState::Succeeded) {
+            Some(Self::Succeeded)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, state: CheckState, minute: i64) -> PullRequestCheck {
+        PullRequestCheck {
+            name: name.to_string(),
+            state,
+            description: None,
+            target_url: None,
+            updated_at: Some(OffsetDateTime::UNIX_EPOCH + time::Duration::minutes(minute)),
+        }
+    }
+
+    #[test]
+    fn only_the_latest_status_per_check_counts() {
+        let checks = PullRequestCheck::latest_per_name([
+            check("ci/build", CheckState::Failed, 1),
+            check("ci/build", CheckState::Succeeded, 5),
+            check("ci/lint", CheckState::Succeeded, 2),
+        ]);
+
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].state, CheckState::Succeeded);
+        assert_eq!(CiStatus::from_checks(&checks), Some(CiStatus::Succeeded));
+    }
+
+    #[test]
+    fn failures_outrank_running_checks() {
+        let checks = [
+            check("ci/build", CheckState::Pending, 1),
+            check("ci/tests", CheckState::Failed, 1),
+        ];
+        assert_eq!(CiStatus::from_checks(&checks), Some(CiStatus::Failed));
+        assert_eq!(
+            CiStatus::from_checks(&[check("ci/build", CheckState::NotApplicable, 1)]),
+            None
+        );
+    }
+}
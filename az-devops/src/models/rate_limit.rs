@@ -0,0 +1,96 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+/// Azure DevOps rate limiting state, read from the `X-RateLimit-*` and
+/// `Retry-After` headers of a response.
+///
+/// Azure DevOps only sends these headers once a client is close to or over
+/// its limit, so a response without them means there is headroom left.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitStatus {
+    /// Resource being throttled, e.g. `Core` or `ATCommandTriggers`.
+    pub resource: Option<String>,
+    /// Usage allowed in the current window, in TSTUs.
+    pub limit: Option<f64>,
+    /// Usage left before requests are delayed, in TSTUs.
+    pub remaining: Option<f64>,
+    /// Seconds the request was delayed by.
+    pub delay_secs: Option<f64>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub reset_at: Option<OffsetDateTime>,
+    pub retry_after_secs: Option<u64>,
+}
+
+impl RateLimitStatus {
+    pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut status = Self::default();
+        for (name, value) in headers {
+            let value = value.trim();
+            match name.to_ascii_lowercase().as_str() {
+                "x-ratelimit-resource" => status.resource = Some(value.to_string()),
+                "x-ratelimit-limit" => status.limit = value.parse().ok(),
+                "x-ratelimit-remaining" => status.remaining = value.parse().ok(),
+                "x-ratelimit-delay" => status.delay_secs = value.parse().ok(),
+                "x-ratelimit-reset" => {
+                    status.reset_at = value
+                        .parse()
+                        .ok()
+                        .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok());
+                }
+                "retry-after" => status.retry_after_secs = value.parse().ok(),
+                _ => {}
+            }
+        }
+        status
+    }
+
+    /// Azure DevOps reported any throttling at all.
+    pub fn is_throttled(&self) -> bool {
+        self.limit.is_some() || self.delay_secs.is_some() || self.retry_after_secs.is_some()
+    }
+
+    /// Share of the limit still available, from 0.0 to 1.0, when known.
+    pub fn headroom(&self) -> Option<f64> {
+        match (self.remaining, self.limit) {
+            (Some(remaining), Some(limit)) if limit > 0.0 => {
+                Some((remaining / limit).clamp(0.0, 1.0))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_throttling_headers() {
+        let status = RateLimitStatus::from_headers([
+            ("X-RateLimit-Resource", "Core"),
+            ("X-RateLimit-Limit", "200"),
+            ("X-RateLimit-Remaining", "50.5"),
+            ("X-RateLimit-Reset", "1700000000"),
+            ("Retry-After", "30"),
+            ("Content-Type", "application/json"),
+        ]);
+
+        assert!(status.is_throttled());
+        assert_eq!(status.resource.as_deref(), Some("Core"));
+        assert_eq!(status.headroom(), Some(50.5 / 200.0));
+        assert_eq!(
+            status.reset_at,
+            Some(OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap())
+        );
+        assert_eq!(status.retry_after_secs, Some(30));
+    }
+
+    #[test]
+    fn no_headers_means_no_throttling() {
+        let status = RateLimitStatus::from_headers([("Content-Type", "application/json")]);
+
+        assert!(!status.is_throttled());
+        assert_eq!(status.headroom(), None);
+    }
+}
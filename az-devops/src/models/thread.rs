@@ -34,7 +34,78 @@ impl From<CommentThread> for Thread {
     }
 }
 
+/// Narrows down which threads of a pull request are returned.
+///
+/// The threads endpoint has no status or date parameters, so the filter is
+/// applied to the raw response before threads are converted and returned.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadFilter {
+    /// Keep only threads with one of these statuses. Empty keeps all.
+    pub statuses: Vec<Status>,
+    /// Keep only threads updated after this point in time.
+    pub updated_since: Option<OffsetDateTime>,
+    /// Keep deleted threads as well.
+    pub include_deleted: bool,
+}
+
+impl ThreadFilter {
+    /// Threads that still need attention: active or pending.
+    pub fn unresolved() -> Self {
+        Self {
+            statuses: vec![Status::Active, Status::Pending],
+            ..Self::default()
+        }
+    }
+
+    /// Only keep threads updated after `since`.
+    pub fn updated_since(mut self, since: OffsetDateTime) -> Self {
+        self.updated_since = Some(since);
+        self
+    }
+
+    /// Also keep deleted threads, e.g. to drop them from a previous result
+    /// when fetching incrementally.
+    pub fn include_deleted(mut self) -> Self {
+        self.include_deleted = true;
+        self
+    }
+
+    pub(crate) fn matches(&self, thread: &CommentThread) -> bool {
+        if !self.include_deleted && thread.is_deleted == Some(true) {
+            return false;
+        }
+        if !self.statuses.is_empty()
+            && !thread
+                .status
+                .as_ref()
+                .is_some_and(|status| self.statuses.contains(status))
+        {
+            return false;
+        }
+        match (self.updated_since, thread.last_updated_date) {
+            (Some(since), Some(updated)) => updated > since,
+            _ => true,
+        }
+    }
+}
+
 impl Thread {
+    /// Applies an incremental fetch (see [`ThreadFilter::updated_since`]) to
+    /// a previous set of threads: updated threads replace their old version,
+    /// new threads are appended and deleted threads are removed.
+    pub fn merge_updates(previous: Vec<Thread>, updates: Vec<Thread>) -> Vec<Thread> {
+        let mut updates: HashMap<i32, Thread> = updates.into_iter().map(|t| (t.id, t)).collect();
+        let mut merged: Vec<Thread> = previous
+            .into_iter()
+            .map(|thread| updates.remove(&thread.id).unwrap_or(thread))
+            .collect();
+        let mut added: Vec<Thread> = updates.into_values().collect();
+        added.sort_by_key(|thread| thread.id);
+        merged.extend(added);
+        merged.retain(|thread| thread.is_deleted != Some(true));
+        merged
+    }
+
     /// Checks if the first comment in the thread is a system comment.
     pub fn is_system_thread(&self) -> bool {
         self.comments.first().is_some_and(|c| c.is_system_comment())
@@ -70,3 +141,53 @@ impl Thread {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Duration;
+
+    fn comment_thread(id: i32, status: Status, updated: OffsetDateTime) -> CommentThread {
+        CommentThread {
+            id: Some(id),
+            status: Some(status),
+            last_updated_date: Some(updated),
+            published_date: Some(updated),
+            ..CommentThread::default()
+        }
+    }
+
+    #[test]
+    fn unresolved_filter_skips_resolved_and_old_threads() {
+        let now = OffsetDateTime::now_utc();
+        let filter = ThreadFilter::unresolved().updated_since(now - Duration::hours(1));
+
+        assert!(filter.matches(&comment_thread(1, Status::Active, now)));
+        assert!(filter.matches(&comment_thread(2, Status::Pending, now)));
+        assert!(!filter.matches(&comment_thread(3, Status::Fixed, now)));
+        assert!(!filter.matches(&comment_thread(4, Status::Active, now - Duration::hours(2))));
+
+        let mut deleted = comment_thread(5, Status::Active, now);
+        deleted.is_deleted = Some(true);
+        assert!(!filter.matches(&deleted));
+        assert!(filter.include_deleted().matches(&deleted));
+    }
+
+    #[test]
+    fn merge_updates_replaces_appends_and_drops_deleted() {
+        let now = OffsetDateTime::now_utc();
+        let thread = |id: i32, status: Status| Thread::from(comment_thread(id, status, now));
+        let previous = vec![thread(1, Status::Active), thread(2, Status::Active)];
+
+        let mut deleted = thread(2, Status::Active);
+        deleted.is_deleted = Some(true);
+        let merged = Thread::merge_updates(
+            previous,
+            vec![thread(3, Status::Active), thread(1, Status::Fixed), deleted],
+        );
+
+        let ids: Vec<i32> = merged.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(merged[0].status, Some(Status::Fixed));
+    }
+}
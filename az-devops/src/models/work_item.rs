@@ -7,6 +7,11 @@ use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 use crate::Identity;
 
+/// Reference name of the field holding hours spent on a work item.
+pub const COMPLETED_WORK_FIELD: &str = "Microsoft.VSTS.Scheduling.CompletedWork";
+/// Reference name of the field holding hours left on a work item.
+pub const REMAINING_WORK_FIELD: &str = "Microsoft.VSTS.Scheduling.RemainingWork";
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkItem {
@@ -30,6 +35,12 @@ pub struct WorkItem {
     pub iteration_path: Option<String>,
     pub area_path: Option<String>,
     pub tags: Option<String>,
+    /// `Microsoft.VSTS.Scheduling.CompletedWork`, in hours.
+    pub completed_work: Option<f64>,
+    /// `Microsoft.VSTS.Scheduling.RemainingWork`, in hours.
+    pub remaining_work: Option<f64>,
+    /// Revision number, bumped on every change to the work item.
+    pub revision: Option<i32>,
 }
 
 impl From<AzureWorkItem> for WorkItem {
@@ -122,6 +133,36 @@ impl From<AzureWorkItem> for WorkItem {
                 .get("System.Tags")
                 .and_then(|value| value.as_str())
                 .map(|s| s.to_owned()),
+            completed_work: work_item
+                .fields
+                .get(COMPLETED_WORK_FIELD)
+                .and_then(|value| value.as_f64()),
+            remaining_work: work_item
+                .fields
+                .get(REMAINING_WORK_FIELD)
+                .and_then(|value| value.as_f64()),
+            revision: work_item.rev,
+        }
+    }
+}
+
+/// Work item type to create. Processes can define their own types, which
+/// go in `Other` by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkItemKind {
+    Task,
+    Bug,
+    ProductBacklogItem,
+    Other(String),
+}
+
+impl WorkItemKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Task => "Task",
+            Self::Bug => "Bug",
+            Self::ProductBacklogItem => "Product Backlog Item",
+            Self::Other(name) => name,
         }
     }
 }
@@ -1,10 +1,16 @@
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use azure_devops_rust_api::{
-    core,
-    git::{self, models::GitCommitRef},
+    build, core,
+    git::{
+        self,
+        models::{
+            GitCommitRef, GitPullRequestCommentThread, GitPullRequestCreateOptions, IdentityId,
+            IdentityRef, IdentityRefWithVote, ResourceRef,
+        },
+    },
     graph::{self, models::GraphUser},
     wit::{
         self,
@@ -15,15 +21,22 @@ use azure_devops_rust_api::{
     },
     work, Credential,
 };
+use futures::{stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tokio::sync::Semaphore;
 use tracing::debug;
 
-use crate::{Identity, Iteration, PullRequest, Thread, WorkItem, WorkItemComment};
+use crate::{
+    parent_link_operation, revision_test_operation, Comment, CommentType, FieldPatch, Identity,
+    Iteration, PullRequest, PullRequestChange, PullRequestCheck, RateLimitStatus, Thread,
+    ThreadFilter, ThreadStatus, Vote, WorkItem, WorkItemComment, WorkItemKind,
+};
 
 const WIQL_QUERY_TIMEOUT: Duration = Duration::from_secs(8);
 const WIQL_API_VERSION: &str = "7.1-preview";
+const GIT_API_VERSION: &str = "7.1";
+const MAX_PULL_REQUEST_CHANGES: usize = 2000;
 
 #[derive(Debug, thiserror::Error)]
 pub enum RepoClientError {
@@ -35,6 +48,20 @@ pub enum RepoClientError {
     HttpStatus { status: u16, body: String },
     #[error("Repository not found: {0}")]
     RepoNotFound(String),
+    #[error("Work item not found: {0}")]
+    WorkItemNotFound(i32),
+    #[error("Work item {0} changed since it was read")]
+    WorkItemChanged(i32),
+    #[error(
+        "{work_item_type} {work_item_id} cannot move to state '{target_state}' (valid states: {})",
+        valid_states.join(", ")
+    )]
+    InvalidStateTransition {
+        work_item_id: i32,
+        work_item_type: String,
+        target_state: String,
+        valid_states: Vec<String>,
+    },
     #[error("Response payload exceeds {max_bytes} bytes (actual: {actual_bytes} bytes)")]
     PayloadTooLarge { actual_bytes: u64, max_bytes: usize },
 }
@@ -56,8 +83,53 @@ struct ProjectScopeWiqlWorkItemRef {
     id: Option<i32>,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConnectionData {
+    authenticated_user: ConnectionDataUser,
+}
+
+#[derive(Deserialize)]
+struct ConnectionDataUser {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct PullRequestIterationList {
+    #[serde(default)]
+    value: Vec<PullRequestIterationRef>,
+}
+
+#[derive(Deserialize)]
+struct PullRequestIterationRef {
+    id: Option<i32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestIterationChanges {
+    #[serde(default)]
+    change_entries: Vec<PullRequestChangeEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestChangeEntry {
+    change_type: Option<String>,
+    item: Option<PullRequestChangeItem>,
+    original_path: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestChangeItem {
+    path: Option<String>,
+    is_folder: Option<bool>,
+}
+
 #[derive(Clone)]
 pub struct RepoClient {
+    build_client: build::Client,
     core_client: core::Client,
     git_client: git::Client,
     work_item_client: wit::Client,
@@ -69,6 +141,8 @@ pub struct RepoClient {
     repo_name: String,
     repo_id: String,
     pat: String,
+    /// Rate limit state from the latest pull request listing, shared by clones.
+    rate_limit: Arc<Mutex<Option<(OffsetDateTime, RateLimitStatus)>>>,
 }
 
 impl RepoClient {
@@ -80,6 +154,7 @@ impl RepoClient {
     ) -> Result<Self, RepoClientError> {
         // might need to disable retries or set a timeout (https://docs.rs/azure_devops_rust_api/latest/azure_devops_rust_api/git/struct.ClientBuilder.html, https://docs.rs/azure_core/0.20.0/azure_core/struct.TimeoutPolicy.html)
         let credential = Credential::from_pat(pat.to_owned());
+        let build_client = build::ClientBuilder::new(credential.clone()).build();
         let core_client = core::ClientBuilder::new(credential.clone()).build();
         let git_client = git::ClientBuilder::new(credential.clone()).build();
         let work_item_client = wit::ClientBuilder::new(credential.clone()).build();
@@ -98,6 +173,7 @@ impl RepoClient {
             .ok_or_else(|| RepoClientError::RepoNotFound(repo_name.to_string()))?;
 
         Ok(Self {
+            build_client,
             core_client,
             git_client,
             work_item_client,
@@ -109,6 +185,7 @@ impl RepoClient {
             repo_name: repo.name,
             repo_id: repo.id,
             pat: pat.to_owned(),
+            rate_limit: Arc::new(Mutex::new(None)),
         })
     }
 
@@ -128,13 +205,36 @@ impl RepoClient {
         &self.repo_id
     }
 
+    /// Rate limit state seen on the latest [`Self::get_open_pull_requests`]
+    /// call, and when it was seen.
+    pub fn rate_limit(&self) -> Option<(OffsetDateTime, RateLimitStatus)> {
+        self.rate_limit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
     pub async fn get_open_pull_requests(&self) -> Result<Vec<PullRequest>, RepoClientError> {
-        let pull_requests = self
+        let response = self
             .git_client
             .pull_requests_client()
             .get_pull_requests(&self.organization, &self.repo_id, &self.project)
-            .await?
-            .value;
+            .send()
+            .await?;
+        // Polled on every differ tick, which makes it a good rate limit probe.
+        let rate_limit = RateLimitStatus::from_headers(
+            response
+                .as_raw_response()
+                .headers()
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        );
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) =
+            Some((OffsetDateTime::now_utc(), rate_limit));
+        let pull_requests = response.into_raw_body().await?.value;
 
         Ok(pull_requests.into_iter().map(PullRequest::from).collect())
     }
@@ -185,6 +285,52 @@ impl RepoClient {
         Ok(pull_requests)
     }
 
+    /// Open a pull request from `source_branch` into `target_branch`.
+    ///
+    /// Branches may be given with or without the `refs/heads/` prefix.
+    /// Reviewers are identity IDs, and the work items are linked to the new
+    /// pull request.
+    pub async fn create_pull_request(
+        &self,
+        source_branch: &str,
+        target_branch: &str,
+        title: &str,
+        description: Option<&str>,
+        reviewer_ids: &[String],
+        work_item_ids: &[i32],
+    ) -> Result<PullRequest, RepoClientError> {
+        let mut options = GitPullRequestCreateOptions::new(
+            branch_ref_name(source_branch),
+            branch_ref_name(target_branch),
+            title.to_string(),
+        );
+        options.description = description.map(str::to_string);
+        options.reviewers = reviewer_ids
+            .iter()
+            .map(|id| IdentityId::new(id.clone()))
+            .collect();
+        options.work_item_refs = work_item_ids
+            .iter()
+            .map(|id| ResourceRef {
+                id: Some(id.to_string()),
+                url: None,
+            })
+            .collect();
+
+        let pull_request = self
+            .git_client
+            .pull_requests_client()
+            .create(&self.organization, &self.repo_id, &self.project, options)
+            .await?;
+
+        debug!(
+            pull_request_id = pull_request.pull_request_id,
+            source_branch, target_branch, "Created pull request"
+        );
+
+        Ok(PullRequest::from(pull_request))
+    }
+
     pub async fn get_threads_in_pull_request(
         &self,
         pull_request_id: i32,
@@ -207,6 +353,152 @@ impl RepoClient {
             .collect())
     }
 
+    /// Cast `reviewer_id`'s vote on a pull request, adding them as a reviewer
+    /// if needed. Azure DevOps only accepts votes from the identity the
+    /// client is authenticated as.
+    pub async fn set_pull_request_vote(
+        &self,
+        pull_request_id: i32,
+        reviewer_id: &str,
+        vote: Vote,
+    ) -> Result<(), RepoClientError> {
+        let mut reviewer = IdentityRefWithVote::new(IdentityRef::new(reviewer_id.to_string()));
+        reviewer.vote = Some(vote.clone().into());
+
+        self.git_client
+            .pull_request_reviewers_client()
+            .create_pull_request_reviewer(
+                &self.organization,
+                reviewer,
+                &self.repo_id,
+                pull_request_id,
+                reviewer_id,
+                &self.project,
+            )
+            .await?;
+
+        debug!(pull_request_id, reviewer_id, ?vote, "Set pull request vote");
+
+        Ok(())
+    }
+
+    /// Identity ID of the user the client's token belongs to. Votes cast
+    /// through this client are recorded for this identity.
+    pub async fn get_authenticated_user_id(&self) -> Result<String, RepoClientError> {
+        let mut url = reqwest::Url::parse("https://dev.azure.com").map_err(|error| {
+            internal_http_error(format!("Failed to build connection data URL: {error}"))
+        })?;
+        url.path_segments_mut()
+            .map_err(|_| internal_http_error("Failed to build connection data URL path"))?
+            .extend([self.organization.as_str(), "_apis", "connectionData"]);
+
+        let response = self
+            .http_client
+            .get(url)
+            .basic_auth("", Some(&self.pat))
+            .send()
+            .await
+            .map_err(|error| {
+                internal_http_error(format!("Connection data request failed: {error}"))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<failed to read response body>".to_string());
+            return Err(RepoClientError::HttpStatus {
+                status: status.as_u16(),
+                body: body.chars().take(256).collect(),
+            });
+        }
+
+        let connection = response.json::<ConnectionData>().await.map_err(|error| {
+            internal_http_error(format!("Failed to decode connection data: {error}"))
+        })?;
+
+        Ok(connection.authenticated_user.id)
+    }
+
+    /// Start a new comment thread on a pull request.
+    pub async fn create_thread(
+        &self,
+        pull_request_id: i32,
+        content: &str,
+    ) -> Result<Thread, RepoClientError> {
+        let mut thread = GitPullRequestCommentThread::new();
+        thread.comment_thread.comments = vec![text_comment(content, None)];
+        thread.comment_thread.status = Some(ThreadStatus::Active);
+
+        let thread = self
+            .git_client
+            .pull_request_threads_client()
+            .create(
+                &self.organization,
+                thread,
+                &self.repo_id,
+                pull_request_id,
+                &self.project,
+            )
+            .await?;
+
+        Ok(Thread::from(thread.comment_thread))
+    }
+
+    /// Reply to an existing comment thread on a pull request.
+    pub async fn reply_to_thread(
+        &self,
+        pull_request_id: i32,
+        thread_id: i32,
+        content: &str,
+    ) -> Result<Comment, RepoClientError> {
+        // Replies hang off the thread's first comment, like in the web UI.
+        let comment = self
+            .git_client
+            .pull_request_thread_comments_client()
+            .create(
+                &self.organization,
+                text_comment(content, Some(1)),
+                &self.repo_id,
+                pull_request_id,
+                thread_id,
+                &self.project,
+            )
+            .await?;
+
+        Ok(Comment::from(comment))
+    }
+
+    /// Like [`Self::get_threads_in_pull_request`], but only returns threads
+    /// matching `filter`. Azure DevOps has no server-side status, date or
+    /// paging parameters for threads, so the full list is still downloaded;
+    /// the rest is dropped before any comments are converted.
+    pub async fn get_threads_in_pull_request_filtered(
+        &self,
+        pull_request_id: i32,
+        filter: &ThreadFilter,
+    ) -> Result<Vec<Thread>, RepoClientError> {
+        let threads = self
+            .git_client
+            .pull_request_threads_client()
+            .list(
+                &self.organization,
+                &self.repo_id,
+                pull_request_id,
+                &self.project,
+            )
+            .await?
+            .value;
+
+        Ok(threads
+            .into_iter()
+            .map(|t| t.comment_thread)
+            .filter(|t| filter.matches(t))
+            .map(Thread::from)
+            .collect())
+    }
+
     pub async fn get_work_item_ids_in_pull_request(
         &self,
         pull_request_id: i32,
@@ -230,6 +522,135 @@ impl RepoClient {
             .collect())
     }
 
+    /// Get the files changed by a pull request.
+    ///
+    /// Uses the latest iteration compared against the merge base, i.e. the same
+    /// view as the "Files" tab in Azure DevOps. Folders are left out.
+    pub async fn get_pull_request_changes(
+        &self,
+        pull_request_id: i32,
+    ) -> Result<Vec<PullRequestChange>, RepoClientError> {
+        let pull_request_id = pull_request_id.to_string();
+        let iterations = self
+            .get_git_json::<PullRequestIterationList>(
+                &["pullRequests", &pull_request_id, "iterations"],
+                &[],
+            )
+            .await?;
+        let Some(latest_iteration) = iterations
+            .value
+            .into_iter()
+            .filter_map(|iteration| iteration.id)
+            .max()
+        else {
+            return Ok(vec![]);
+        };
+
+        let latest_iteration = latest_iteration.to_string();
+        let top = MAX_PULL_REQUEST_CHANGES.to_string();
+        let changes = self
+            .get_git_json::<PullRequestIterationChanges>(
+                &[
+                    "pullRequests",
+                    &pull_request_id,
+                    "iterations",
+                    &latest_iteration,
+                    "changes",
+                ],
+                &[("$top", &top), ("$compareTo", "0")],
+            )
+            .await?;
+
+        let changes: Vec<PullRequestChange> = changes
+            .change_entries
+            .into_iter()
+            .filter_map(|entry| {
+                let item = entry.item?;
+                if item.is_folder.unwrap_or(false) {
+                    return None;
+                }
+                Some(PullRequestChange {
+                    path: item.path?,
+                    change_type: entry.change_type.unwrap_or_else(|| "edit".to_string()),
+                    original_path: entry.original_path,
+                })
+            })
+            .collect();
+
+        debug!(
+            "Found {} changed files in pull request {} ({}/{})",
+            changes.len(),
+            pull_request_id,
+            self.project,
+            self.repo_name
+        );
+
+        Ok(changes)
+    }
+
+    /// Latest status of each check posted to a pull request, e.g. by an
+    /// external CI service or a status policy.
+    pub async fn get_pull_request_statuses(
+        &self,
+        pull_request_id: i32,
+    ) -> Result<Vec<PullRequestCheck>, RepoClientError> {
+        let statuses = self
+            .git_client
+            .pull_request_statuses_client()
+            .list(
+                &self.organization,
+                &self.repo_id,
+                pull_request_id,
+                &self.project,
+            )
+            .await?
+            .value;
+
+        Ok(PullRequestCheck::latest_per_name(
+            statuses
+                .into_iter()
+                .map(|status| PullRequestCheck::from(status.git_status)),
+        ))
+    }
+
+    /// Latest pipeline run of each pipeline that built the pull request's
+    /// merge commit, e.g. for build validation policies.
+    pub async fn get_pull_request_builds(
+        &self,
+        pull_request_id: i32,
+    ) -> Result<Vec<PullRequestCheck>, RepoClientError> {
+        let builds = self
+            .build_client
+            .builds_client()
+            .list(&self.organization, &self.project)
+            .branch_name(format!("refs/pull/{pull_request_id}/merge"))
+            .repository_id(&self.repo_id)
+            .repository_type("TfsGit")
+            .max_builds_per_definition(1)
+            .query_order("queueTimeDescending")
+            .await?
+            .value;
+
+        Ok(PullRequestCheck::latest_per_name(
+            builds.into_iter().map(PullRequestCheck::from),
+        ))
+    }
+
+    /// All CI checks on a pull request: posted statuses and pipeline runs.
+    pub async fn get_pull_request_checks(
+        &self,
+        pull_request_id: i32,
+    ) -> Result<Vec<PullRequestCheck>, RepoClientError> {
+        let (statuses, builds) = futures::try_join!(
+            self.get_pull_request_statuses(pull_request_id),
+            self.get_pull_request_builds(pull_request_id)
+        )?;
+
+        Ok(PullRequestCheck::latest_per_name(
+            statuses.into_iter().chain(builds),
+        ))
+    }
+
     pub async fn get_commits_in_pull_request(
         &self,
         pull_request_id: i32,
@@ -287,29 +708,185 @@ impl RepoClient {
 
     pub async fn get_work_items(&self, ids: Vec<i32>) -> Result<Vec<WorkItem>, RepoClientError> {
         const BATCH_SIZE: usize = 200;
+        const CONCURRENCY: usize = 4;
 
         if ids.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut all_work_items = Vec::with_capacity(ids.len());
+        // `buffered` keeps batch order, so items come back in the order the
+        // ids were given even though batches are fetched concurrently.
+        let batches: Vec<Vec<_>> = stream::iter(ids.chunks(BATCH_SIZE))
+            .map(|chunk| {
+                let mut batch_request = WorkItemBatchGetRequest::new();
+                batch_request.expand = Some(Expand::Relations);
+                batch_request.ids = chunk.to_vec();
+
+                async move {
+                    self.work_item_client
+                        .work_items_client()
+                        .get_work_items_batch(&self.organization, batch_request, &self.project)
+                        .await
+                        .map(|batch| batch.value)
+                }
+            })
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await?;
 
-        for chunk in ids.chunks(BATCH_SIZE) {
-            let mut batch_request = WorkItemBatchGetRequest::new();
-            batch_request.expand = Some(Expand::Relations);
-            batch_request.ids = chunk.to_vec();
+        let all_work_items = batches
+            .into_iter()
+            .flatten()
+            .map(WorkItem::from)
+            .collect::<Vec<_>>();
 
-            let work_items = self
-                .work_item_client
-                .work_items_client()
-                .get_work_items_batch(&self.organization, batch_request, &self.project)
-                .await?
-                .value;
+        Ok(all_work_items)
+    }
+
+    /// Apply field changes to a work item, e.g. to move it to another state,
+    /// reassign it or set custom fields. Returns the updated work item.
+    pub async fn update_work_item(
+        &self,
+        work_item_id: i32,
+        patches: Vec<FieldPatch>,
+    ) -> Result<WorkItem, RepoClientError> {
+        let operations = patches
+            .into_iter()
+            .map(FieldPatch::into_operation)
+            .collect();
 
-            all_work_items.extend(work_items.into_iter().map(WorkItem::from));
+        let work_item = self
+            .work_item_client
+            .work_items_client()
+            .update(&self.organization, operations, work_item_id, &self.project)
+            .await?;
+
+        debug!(work_item_id, "Updated work item");
+
+        Ok(WorkItem::from(work_item))
+    }
+
+    /// Like [`update_work_item`](Self::update_work_item), but only if the
+    /// work item is still at `revision`. Fails with
+    /// [`RepoClientError::WorkItemChanged`] if someone changed it since.
+    pub async fn update_work_item_at_revision(
+        &self,
+        work_item_id: i32,
+        revision: i32,
+        patches: Vec<FieldPatch>,
+    ) -> Result<WorkItem, RepoClientError> {
+        let operations = std::iter::once(revision_test_operation(revision))
+            .chain(patches.into_iter().map(FieldPatch::into_operation))
+            .collect();
+
+        let work_item = self
+            .work_item_client
+            .work_items_client()
+            .update(&self.organization, operations, work_item_id, &self.project)
+            .await
+            .map_err(|error| match error.kind() {
+                // A failed test operation is rejected as a precondition
+                typespec::error::ErrorKind::HttpResponse {
+                    status:
+                        typespec::http::StatusCode::PreconditionFailed
+                        | typespec::http::StatusCode::Conflict,
+                    ..
+                } => RepoClientError::WorkItemChanged(work_item_id),
+                _ => RepoClientError::from(error),
+            })?;
+
+        debug!(work_item_id, revision, "Updated work item at revision");
+
+        Ok(WorkItem::from(work_item))
+    }
+
+    /// Create a work item of `kind`, optionally as a child of `parent_id`.
+    ///
+    /// `fields` are set on the new work item along with its title, e.g. an
+    /// area path, iteration or assignee. Returns the created work item.
+    pub async fn create_work_item(
+        &self,
+        kind: &WorkItemKind,
+        title: &str,
+        fields: Vec<FieldPatch>,
+        parent_id: Option<i32>,
+    ) -> Result<WorkItem, RepoClientError> {
+        let mut operations: Vec<_> = std::iter::once(FieldPatch::title(title))
+            .chain(fields)
+            .map(FieldPatch::into_operation)
+            .collect();
+        if let Some(parent_id) = parent_id {
+            operations.push(parent_link_operation(&self.organization, parent_id));
         }
 
-        Ok(all_work_items)
+        let work_item = self
+            .work_item_client
+            .work_items_client()
+            .create(&self.organization, operations, &self.project, kind.as_str())
+            .await?;
+
+        debug!(
+            work_item_id = work_item.id,
+            kind = kind.as_str(),
+            parent_id,
+            "Created work item"
+        );
+
+        Ok(WorkItem::from(work_item))
+    }
+
+    /// Move a work item to `target_state`, e.g. `Active` or `Done`.
+    ///
+    /// The target must be one of the states defined for the work item's type,
+    /// matched case-insensitively; otherwise
+    /// [`RepoClientError::InvalidStateTransition`] lists the valid ones. A
+    /// work item already in the target state is returned unchanged.
+    pub async fn transition_work_item(
+        &self,
+        work_item_id: i32,
+        target_state: &str,
+    ) -> Result<WorkItem, RepoClientError> {
+        let work_item = self
+            .get_work_items(vec![work_item_id])
+            .await?
+            .into_iter()
+            .find(|item| item.id == work_item_id)
+            .ok_or(RepoClientError::WorkItemNotFound(work_item_id))?;
+
+        let valid_states = self.get_work_item_type_states(&work_item.item_type).await?;
+        let Some(state) = resolve_state(&valid_states, target_state) else {
+            return Err(RepoClientError::InvalidStateTransition {
+                work_item_id,
+                work_item_type: work_item.item_type,
+                target_state: target_state.to_string(),
+                valid_states,
+            });
+        };
+        if state == work_item.state {
+            return Ok(work_item);
+        }
+
+        let state = state.to_string();
+        self.update_work_item(work_item_id, vec![FieldPatch::state(state)])
+            .await
+    }
+
+    /// List the states defined for a work item type, in workflow order.
+    pub async fn get_work_item_type_states(
+        &self,
+        work_item_type: &str,
+    ) -> Result<Vec<String>, RepoClientError> {
+        let states = self
+            .work_item_client
+            .work_item_type_states_client()
+            .list(&self.organization, &self.project, work_item_type)
+            .await?;
+
+        Ok(states
+            .value
+            .into_iter()
+            .filter_map(|state| state.name)
+            .collect())
     }
 
     /// Download a work item attachment by ID.
@@ -470,6 +1047,59 @@ impl RepoClient {
         Ok(ids)
     }
 
+    /// GET a git REST resource under this repository and decode the JSON body.
+    async fn get_git_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<T, RepoClientError> {
+        let mut url = reqwest::Url::parse("https://dev.azure.com")
+            .map_err(|error| internal_http_error(format!("Failed to build git URL: {error}")))?;
+        url.path_segments_mut()
+            .map_err(|_| internal_http_error("Failed to build git URL path"))?
+            .extend([
+                self.organization.as_str(),
+                self.project.as_str(),
+                "_apis",
+                "git",
+                "repositories",
+                self.repo_id.as_str(),
+            ])
+            .extend(path);
+        {
+            let mut pairs = url.query_pairs_mut();
+            for (key, value) in query {
+                pairs.append_pair(key, value);
+            }
+            pairs.append_pair("api-version", GIT_API_VERSION);
+        }
+
+        let response = self
+            .http_client
+            .get(url)
+            .basic_auth("", Some(&self.pat))
+            .send()
+            .await
+            .map_err(|error| internal_http_error(format!("Git request failed: {error}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "<failed to read response body>".to_string());
+            return Err(RepoClientError::HttpStatus {
+                status: status.as_u16(),
+                body: body.chars().take(256).collect(),
+            });
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|error| internal_http_error(format!("Failed to decode git response: {error}")))
+    }
+
     /// Get all iterations for the project, flattened from the classification node tree.
     ///
     /// `depth` controls how deep to traverse the tree (defaults to 10).
@@ -574,15 +1204,70 @@ impl RepoClient {
             .value
             .into_iter()
             .filter_map(|it| {
+                let attributes = it.attributes.unwrap_or_default();
                 Some(TeamIteration {
                     id: it.id?,
                     name: it.name.unwrap_or_default(),
                     path: it.path.unwrap_or_default(),
+                    start_date: attributes.start_date,
+                    finish_date: attributes.finish_date,
                 })
             })
             .collect())
     }
 
+    /// Get the team's capacity in an iteration: each member's hours per day
+    /// and days off, and the days off of the whole team.
+    ///
+    /// `iteration_id` is the GUID of a team iteration.
+    pub async fn get_team_iteration_capacity(
+        &self,
+        team: &str,
+        iteration_id: &str,
+    ) -> Result<TeamIterationCapacity, RepoClientError> {
+        let capacity = self
+            .work_client
+            .capacities_client()
+            .get_capacities_with_identity_ref_and_totals(
+                &self.organization,
+                &self.project,
+                iteration_id,
+                team,
+            )
+            .await?;
+        let team_days_off = self
+            .work_client
+            .teamdaysoff_client()
+            .get(&self.organization, &self.project, iteration_id, team)
+            .await?;
+
+        let members = capacity
+            .team_members
+            .into_iter()
+            .filter_map(|member| {
+                let identity = member.team_member?;
+                Some(TeamMemberCapacity {
+                    id: identity.id,
+                    display_name: identity.graph_subject_base.display_name.unwrap_or_default(),
+                    unique_name: identity.unique_name,
+                    capacity_per_day: member
+                        .capacity_contract_base
+                        .activities
+                        .iter()
+                        .filter_map(|activity| activity.capacity_per_day)
+                        .map(f64::from)
+                        .sum(),
+                    days_off: to_day_ranges(member.capacity_contract_base.days_off),
+                })
+            })
+            .collect();
+
+        Ok(TeamIterationCapacity {
+            members,
+            team_days_off: to_day_ranges(team_days_off.days_off),
+        })
+    }
+
     /// Get current team iterations using the Work API timeframe filter.
     ///
     /// This relies on Azure DevOps team's sprint settings (`$timeframe=current`)
@@ -745,6 +1430,36 @@ pub struct TeamIteration {
     pub id: String,
     pub name: String,
     pub path: String,
+    pub start_date: Option<OffsetDateTime>,
+    pub finish_date: Option<OffsetDateTime>,
+}
+
+/// A team's capacity in one iteration.
+#[derive(Clone, Debug)]
+pub struct TeamIterationCapacity {
+    pub members: Vec<TeamMemberCapacity>,
+    /// Days off for the whole team, as inclusive (first, last) day ranges.
+    pub team_days_off: Vec<(OffsetDateTime, OffsetDateTime)>,
+}
+
+/// A team member's capacity in one iteration.
+#[derive(Clone, Debug)]
+pub struct TeamMemberCapacity {
+    pub id: Option<String>,
+    pub display_name: String,
+    pub unique_name: Option<String>,
+    /// Hours per working day, summed over the member's activities.
+    pub capacity_per_day: f64,
+    /// The member's own days off, as inclusive (first, last) day ranges.
+    pub days_off: Vec<(OffsetDateTime, OffsetDateTime)>,
+}
+
+/// Days-off ranges with both ends set.
+fn to_day_ranges(ranges: Vec<work::models::DateRange>) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+    ranges
+        .into_iter()
+        .filter_map(|range| Some((range.start?, range.end?)))
+        .collect()
 }
 
 /// A taskboard column definition.
@@ -825,6 +1540,34 @@ fn parse_date_string(s: &str) -> Option<OffsetDateTime> {
     OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339).ok()
 }
 
+fn text_comment(content: &str, parent_comment_id: Option<i64>) -> git::models::Comment {
+    let mut comment = git::models::Comment::new();
+    comment.content = Some(content.to_string());
+    comment.comment_type = Some(CommentType::Text);
+    comment.parent_comment_id = parent_comment_id;
+    comment
+}
+
+/// Full ref name of a branch, e.g. `refs/heads/main` for `main`.
+fn branch_ref_name(branch: &str) -> String {
+    let branch = branch.trim();
+    if branch.starts_with("refs/") {
+        branch.to_string()
+    } else {
+        format!("refs/heads/{branch}")
+    }
+}
+
+/// The state in `states` named `target`, ignoring case and surrounding
+/// whitespace.
+fn resolve_state<'a>(states: &'a [String], target: &str) -> Option<&'a str> {
+    let target = target.trim();
+    states
+        .iter()
+        .find(|state| state.eq_ignore_ascii_case(target))
+        .map(String::as_str)
+}
+
 fn internal_http_error(body: impl Into<String>) -> RepoClientError {
     RepoClientError::HttpStatus {
         status: 500,
@@ -837,6 +1580,27 @@ mod tests {
     use super::*;
     use time::{Duration, Time};
 
+    #[test]
+    fn branches_are_expanded_to_ref_names() {
+        assert_eq!(
+            branch_ref_name("feature/4711-login"),
+            "refs/heads/feature/4711-login"
+        );
+        assert_eq!(branch_ref_name("refs/heads/main"), "refs/heads/main");
+    }
+
+    #[test]
+    fn target_states_match_the_state_model_ignoring_case() {
+        let states = vec![
+            "New".to_string(),
+            "Active".to_string(),
+            "Closed".to_string(),
+        ];
+
+        assert_eq!(resolve_state(&states, " active "), Some("Active"));
+        assert_eq!(resolve_state(&states, "Done"), None);
+    }
+
     async fn get_repo_client() -> RepoClient {
         dotenvy::from_filename(".env.local")
             .or_else(|_| dotenvy::from_filename("az-devops/.env.local"))
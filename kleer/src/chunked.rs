@@ -0,0 +1,269 @@
+//! Fetching long date ranges in chunks.
+//!
+//! Kleer answers slowly for ranges spanning several months, so year-long
+//! fetches (statistics, exports) are split into month-sized chunks that are
+//! requested a few at a time and merged back in date order.
+
+use std::future::Future;
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+use time::{Date, Duration};
+
+use crate::client::{KleerClient, KleerError};
+use crate::types::{
+    KleerEventList, KleerEventRestrictionList, KleerPayrollEventList, KleerScheduleMetadataList,
+};
+
+/// How long ranges are split up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeChunking {
+    /// Days per chunk, at least 1.
+    pub chunk_days: i64,
+    /// Chunks requested at the same time, at least 1.
+    pub max_concurrent: usize,
+}
+
+impl Default for RangeChunking {
+    fn default() -> Self {
+        Self {
+            chunk_days: 31,
+            max_concurrent: 4,
+        }
+    }
+}
+
+/// Split the inclusive range `start..=end` into consecutive inclusive chunks
+/// of at most `chunk_days` days. Empty when `start` is after `end`.
+pub fn split_date_range(start: Date, end: Date, chunk_days: i64) -> Vec<(Date, Date)> {
+    let chunk_days = chunk_days.max(1);
+    let mut chunks = Vec::new();
+    let mut chunk_start = start;
+    while chunk_start <= end {
+        let chunk_end = (chunk_start + Duration::days(chunk_days - 1)).min(end);
+        chunks.push((chunk_start, chunk_end));
+        match chunk_end.next_day() {
+            Some(next) => chunk_start = next,
+            None => break,
+        }
+    }
+    chunks
+}
+
+impl KleerClient {
+    /// [`KleerClient::list_events`] for long ranges.
+    pub async fn list_events_chunked(
+        &self,
+        user_id: i64,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<KleerEventList, KleerError> {
+        let event_readables = self
+            .fetch_chunked(start_date, end_date, |from, to| async move {
+                Ok(self.list_events(user_id, from, to).await?.event_readables)
+            })
+            .await?;
+        Ok(KleerEventList { event_readables })
+    }
+
+    /// [`KleerClient::list_event_statuses`] for long ranges.
+    pub async fn list_event_statuses_chunked(
+        &self,
+        user_id: i64,
+        from_date: Date,
+        to_date: Date,
+    ) -> Result<KleerEventRestrictionList, KleerError> {
+        let event_restriction_readables = self
+            .fetch_chunked(from_date, to_date, |from, to| async move {
+                Ok(self
+                    .list_event_statuses(user_id, from, to)
+                    .await?
+                    .event_restriction_readables)
+            })
+            .await?;
+        Ok(KleerEventRestrictionList {
+            event_restriction_readables,
+        })
+    }
+
+    /// [`KleerClient::list_schedule_summary`] for long ranges.
+    pub async fn list_schedule_summary_chunked(
+        &self,
+        user_id: i64,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<KleerScheduleMetadataList, KleerError> {
+        let payroll_user_schedule_metadatas = self
+            .fetch_chunked(start_date, end_date, |from, to| async move {
+                Ok(self
+                    .list_schedule_summary(user_id, from, to)
+                    .await?
+                    .payroll_user_schedule_metadatas)
+            })
+            .await?;
+        Ok(KleerScheduleMetadataList {
+            payroll_user_schedule_metadatas,
+        })
+    }
+
+    /// [`KleerClient::list_payroll_events`] for long ranges.
+    pub async fn list_payroll_events_chunked(
+        &self,
+        user_id: i64,
+        from_date: Date,
+        to_date: Date,
+    ) -> Result<KleerPayrollEventList, KleerError> {
+        let payroll_events = self
+            .fetch_chunked(from_date, to_date, |from, to| async move {
+                Ok(self
+                    .list_payroll_events(user_id, from, to)
+                    .await?
+                    .payroll_events)
+            })
+            .await?;
+        Ok(KleerPayrollEventList { payroll_events })
+    }
+
+    /// Run `fetch` for every chunk of `start..=end`, at most
+    /// `max_concurrent` at a time, and concatenate the results in chunk
+    /// order. Fails with the first error.
+    async fn fetch_chunked<T, F, Fut>(
+        &self,
+        start: Date,
+        end: Date,
+        fetch: F,
+    ) -> Result<Vec<T>, KleerError>
+    where
+        F: Fn(Date, Date) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, KleerError>>,
+    {
+        fetch_chunks(self.range_chunking(), start, end, fetch).await
+    }
+}
+
+async fn fetch_chunks<T, F, Fut>(
+    chunking: RangeChunking,
+    start: Date,
+    end: Date,
+    fetch: F,
+) -> Result<Vec<T>, KleerError>
+where
+    F: Fn(Date, Date) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, KleerError>>,
+{
+    let chunks = split_date_range(start, end, chunking.chunk_days);
+    if chunks.len() == 1 {
+        return fetch(start, end).await;
+    }
+
+    let parts: Vec<Vec<T>> = stream::iter(chunks)
+        .map(|(from, to)| fetch(from, to))
+        .buffered(chunking.max_concurrent.max(1))
+        .try_collect()
+        .await?;
+    Ok(parts.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use time::Month;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn splits_ranges_into_inclusive_chunks() {
+        assert_eq!(
+            split_date_range(
+                date(2026, Month::January, 1),
+                date(2026, Month::March, 5),
+                31
+            ),
+            vec![
+                (
+                    date(2026, Month::January, 1),
+                    date(2026, Month::January, 31)
+                ),
+                (date(2026, Month::February, 1), date(2026, Month::March, 3)),
+                (date(2026, Month::March, 4), date(2026, Month::March, 5)),
+            ]
+        );
+        assert_eq!(
+            split_date_range(
+                date(2026, Month::January, 1),
+                date(2026, Month::January, 1),
+                31
+            ),
+            vec![(date(2026, Month::January, 1), date(2026, Month::January, 1))]
+        );
+        assert!(split_date_range(
+            date(2026, Month::January, 2),
+            date(2026, Month::January, 1),
+            31
+        )
+        .is_empty());
+    }
+
+    #[tokio::test]
+    async fn merges_chunks_in_order_with_bounded_concurrency() {
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let chunking = RangeChunking {
+            chunk_days: 7,
+            max_concurrent: 2,
+        };
+
+        let days = fetch_chunks(
+            chunking,
+            date(2026, Month::January, 1),
+            date(2026, Month::January, 31),
+            |from, to| {
+                let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(now, Ordering::SeqCst);
+                    // Let later chunks finish first.
+                    tokio::time::sleep(std::time::Duration::from_millis(
+                        40 - u64::from(from.day()),
+                    ))
+                    .await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    let mut days = Vec::new();
+                    let mut day = from;
+                    while day <= to {
+                        days.push(day.day());
+                        day = day.next_day().unwrap();
+                    }
+                    Ok(days)
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(days, (1..=31).collect::<Vec<u8>>());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fails_with_the_first_error() {
+        let result: Result<Vec<u8>, _> = fetch_chunks(
+            RangeChunking::default(),
+            date(2026, Month::January, 1),
+            date(2026, Month::December, 31),
+            |from, _| async move {
+                if from.month() == Month::March {
+                    Err(KleerError::NotFound)
+                } else {
+                    Ok(vec![])
+                }
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(KleerError::NotFound)));
+    }
+}
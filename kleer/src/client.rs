@@ -1,8 +1,11 @@
 use reqwest::{Client, Method, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
 use std::fmt;
+use std::time::Duration;
 use time::Date;
 
+use crate::chunked::RangeChunking;
+use crate::retry::RetryPolicy;
 use crate::types::{
     KleerActivityList, KleerClientProjectList, KleerEventList, KleerEventReadable,
     KleerEventRestrictionList, KleerEventWritable, KleerPayrollEventList, KleerSavedId,
@@ -53,6 +56,9 @@ pub enum KleerError {
     Forbidden,
     #[error("Kleer resource not found")]
     NotFound,
+    /// Kleer could not be reached, so the request was never sent.
+    #[error("Kleer is unavailable: {0}")]
+    Unavailable(String),
     #[error("Kleer request failed: {0}")]
     Request(String),
     #[error("Kleer returned {status}: {body}")]
@@ -61,10 +67,33 @@ pub enum KleerError {
     Deserialize { message: String, body: String },
 }
 
+impl KleerError {
+    /// Whether the same request might succeed if retried shortly: Kleer
+    /// could not be reached, the connection failed midway, or Kleer answered
+    /// with a 5xx or 429. Authentication errors are never transient.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Unavailable(_) | Self::Request(_) => true,
+            Self::Response { status, .. } => RetryPolicy::should_retry_status(*status),
+            _ => false,
+        }
+    }
+
+    fn from_reqwest(error: reqwest::Error) -> Self {
+        if error.is_connect() {
+            Self::Unavailable(error.to_string())
+        } else {
+            Self::Request(error.to_string())
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct KleerClient {
     http: Client,
     credentials: KleerCredentials,
+    range_chunking: RangeChunking,
+    retry: RetryPolicy,
 }
 
 impl KleerClient {
@@ -81,13 +110,32 @@ impl KleerClient {
                 .build()
                 .map_err(|e| KleerError::Request(e.to_string()))?,
             credentials,
+            range_chunking: RangeChunking::default(),
+            retry: RetryPolicy::default(),
         })
     }
 
+    /// Change how failed requests are retried. Use [`RetryPolicy::none`] to
+    /// fail on the first error.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Change how the `*_chunked` methods split long date ranges.
+    pub fn with_range_chunking(mut self, range_chunking: RangeChunking) -> Self {
+        self.range_chunking = range_chunking;
+        self
+    }
+
     pub fn credentials(&self) -> &KleerCredentials {
         &self.credentials
     }
 
+    pub(crate) fn range_chunking(&self) -> RangeChunking {
+        self.range_chunking
+    }
+
     pub async fn validate_credentials(&self) -> Result<KleerUserMe, KleerError> {
         self.user_me().await
     }
@@ -228,20 +276,63 @@ impl KleerClient {
         self.send(request).await
     }
 
+    /// Send `request`, retrying transient failures according to the retry
+    /// policy. Writes are only retried when Kleer could not be reached.
     async fn send<T>(&self, request: reqwest::RequestBuilder) -> Result<T, KleerError>
     where
         T: DeserializeOwned,
     {
-        let response = request
-            .send()
+        let request = request.build().map_err(KleerError::from_reqwest)?;
+        let is_read = request.method() == Method::GET;
+        let mut retry = 1;
+        let mut waited = Duration::ZERO;
+        loop {
+            let attempt = request
+                .try_clone()
+                .expect("Kleer requests have no streaming bodies");
+            let error = match self.execute(attempt).await {
+                Ok(body) => {
+                    return serde_json::from_str(&body).map_err(|e| KleerError::Deserialize {
+                        message: e.to_string(),
+                        body,
+                    })
+                }
+                Err(error) => error,
+            };
+
+            let retryable = if is_read {
+                error.is_transient()
+            } else {
+                matches!(error, KleerError::Unavailable(_))
+            };
+            let delay = retryable
+                .then(|| self.retry.next_delay(retry, waited))
+                .flatten();
+            let Some(delay) = delay else {
+                return Err(error);
+            };
+            tracing::debug!(
+                "Kleer {} {} failed ({error}), retrying in {delay:?}",
+                request.method(),
+                request.url().path()
+            );
+            tokio::time::sleep(delay).await;
+            waited += delay;
+            retry += 1;
+        }
+    }
+
+    /// Send one attempt and return the response body of a successful
+    /// response.
+    async fn execute(&self, request: reqwest::Request) -> Result<String, KleerError> {
+        let response = self
+            .http
+            .execute(request)
             .await
-            .map_err(|e| KleerError::Request(e.to_string()))?;
+            .map_err(KleerError::from_reqwest)?;
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| KleerError::Request(e.to_string()))?;
+        let body = response.text().await.map_err(KleerError::from_reqwest)?;
 
         if !status.is_success() {
             return Err(match status {
@@ -252,10 +343,7 @@ impl KleerClient {
             });
         }
 
-        serde_json::from_str(&body).map_err(|e| KleerError::Deserialize {
-            message: e.to_string(),
-            body,
-        })
+        Ok(body)
     }
 
     fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
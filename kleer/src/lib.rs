@@ -1,5 +1,9 @@
+pub mod chunked;
 pub mod client;
+pub mod retry;
 pub mod types;
 
+pub use chunked::{split_date_range, RangeChunking};
 pub use client::{KleerClient, KleerCredentials, KleerError, DEFAULT_BASE_URL};
+pub use retry::RetryPolicy;
 pub use types::*;
@@ -0,0 +1,128 @@
+//! Retrying requests through brief Kleer outages.
+
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+/// Controls how failed Kleer requests are retried.
+///
+/// Reads are retried on connection failures, 5xx and 429 responses. Writes
+/// are only retried when Kleer could not be reached at all, since a write
+/// that got a response may already have been applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every subsequent retry.
+    pub base_delay: Duration,
+    /// Upper bound for a single delay.
+    pub max_delay: Duration,
+    /// Total time one call may spend waiting between attempts. A retry that
+    /// would exceed it is not made.
+    pub budget: Duration,
+    /// Randomize each delay to between half and all of it, so clients
+    /// retrying the same outage spread out.
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// A single attempt, no retries.
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            budget: Duration::ZERO,
+            jitter: false,
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based), without jitter.
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay)
+    }
+
+    /// Delay before retry number `retry` when `waited` has already been spent
+    /// on earlier retries, or `None` when no more retries should be made.
+    pub(crate) fn next_delay(&self, retry: u32, waited: Duration) -> Option<Duration> {
+        if retry >= self.max_attempts {
+            return None;
+        }
+        let delay = self.delay_for(retry);
+        let delay = if self.jitter && !delay.is_zero() {
+            rand::thread_rng().gen_range(delay / 2..=delay)
+        } else {
+            delay
+        };
+        (waited + delay <= self.budget).then_some(delay)
+    }
+
+    pub(crate) fn should_retry_status(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(2),
+            budget: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 6,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            budget: Duration::from_secs(1),
+            jitter: false,
+        }
+    }
+
+    #[test]
+    fn delay_doubles_up_to_the_max() {
+        let policy = policy();
+
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(4), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn stops_at_max_attempts_or_when_the_budget_is_spent() {
+        let policy = policy();
+
+        assert_eq!(
+            policy.next_delay(3, Duration::from_millis(300)),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.next_delay(4, Duration::from_millis(700)), None);
+        assert_eq!(policy.next_delay(6, Duration::ZERO), None);
+        assert_eq!(RetryPolicy::none().next_delay(1, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn jitter_stays_within_half_and_the_full_delay() {
+        let policy = RetryPolicy {
+            jitter: true,
+            ..policy()
+        };
+
+        for _ in 0..20 {
+            let delay = policy.next_delay(2, Duration::ZERO).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+}
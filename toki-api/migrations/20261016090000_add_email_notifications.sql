@@ -0,0 +1,3 @@
+-- Add email_enabled column to notification_rules table
+ALTER TABLE notification_rules
+ADD COLUMN email_enabled BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,23 @@
+-- Chat webhooks (Slack / Microsoft Teams) for notification delivery
+CREATE TYPE notification_webhook_kind AS ENUM
+(
+    'slack',
+    'teams'
+);
+
+CREATE TABLE notification_webhooks
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    kind notification_webhook_kind NOT NULL,
+    url TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE (user_id, url)
+);
+
+CREATE INDEX idx_notification_webhooks_user
+ON notification_webhooks(user_id);
+
+ALTER TABLE notification_rules
+ADD COLUMN webhook_enabled BOOLEAN NOT NULL DEFAULT false;
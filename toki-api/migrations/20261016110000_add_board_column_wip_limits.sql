@@ -0,0 +1,20 @@
+-- Per-column WIP limits for work item boards
+CREATE TYPE wip_limit_enforcement AS ENUM
+(
+    'warn',
+    'block'
+);
+
+CREATE TABLE board_column_wip_limits
+(
+    id SERIAL PRIMARY KEY,
+    organization TEXT NOT NULL,
+    project TEXT NOT NULL,
+    -- Empty string means the project's default team
+    team TEXT NOT NULL DEFAULT '',
+    column_name TEXT NOT NULL,
+    wip_limit INT NOT NULL CHECK (wip_limit > 0),
+    enforcement wip_limit_enforcement NOT NULL DEFAULT 'warn',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (organization, project, team, column_name)
+);
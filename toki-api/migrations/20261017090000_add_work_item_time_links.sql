@@ -0,0 +1,19 @@
+-- Backlinks from work items to time entries whose notes mention them
+CREATE TABLE work_item_time_links
+(
+    work_item_id TEXT NOT NULL,
+    registration_id TEXT NOT NULL,
+    user_id INT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    entry_date DATE NOT NULL,
+    hours DOUBLE PRECISION NOT NULL,
+    project_name TEXT NOT NULL,
+    activity_name TEXT NOT NULL,
+    note TEXT NOT NULL,
+    PRIMARY KEY (work_item_id, registration_id)
+);
+
+CREATE INDEX idx_work_item_time_links_user_date
+    ON work_item_time_links (user_id, entry_date);
+
+CREATE INDEX idx_work_item_time_links_registration_id
+    ON work_item_time_links (registration_id);
@@ -0,0 +1,11 @@
+-- Per-user rules deducting lunch from timers left running over lunch
+CREATE TABLE user_lunch_rules
+(
+    user_id INT PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    window_start TIME NOT NULL DEFAULT '11:00',
+    window_end TIME NOT NULL DEFAULT '13:00',
+    deduction_minutes INT NOT NULL DEFAULT 30 CHECK (deduction_minutes > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (window_start < window_end)
+);
@@ -0,0 +1,32 @@
+-- Signed webhooks letting external consumers subscribe to toki events
+CREATE TABLE event_webhooks
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    events TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_event_webhooks_user
+ON event_webhooks(user_id);
+
+-- One row per delivery attempt
+CREATE TABLE event_webhook_deliveries
+(
+    id BIGSERIAL PRIMARY KEY,
+    webhook_id INT NOT NULL,
+    event_id TEXT NOT NULL,
+    event TEXT NOT NULL,
+    attempt INT NOT NULL,
+    status_code INT,
+    error TEXT,
+    success BOOLEAN NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (webhook_id) REFERENCES event_webhooks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_event_webhook_deliveries_webhook
+ON event_webhook_deliveries(webhook_id, created_at DESC);
@@ -0,0 +1,8 @@
+-- Which client (TUI on a given machine, web, ...) owns each user's running timer
+CREATE TABLE timer_devices
+(
+    user_id INT PRIMARY KEY,
+    device_name TEXT NOT NULL,
+    claimed_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
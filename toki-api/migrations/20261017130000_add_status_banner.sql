@@ -0,0 +1,21 @@
+-- Announcement shown in every client, optionally pausing time tracking
+CREATE TYPE status_banner_severity AS ENUM
+(
+    'info',
+    'warning',
+    'critical'
+);
+
+-- At most one banner at a time
+CREATE TABLE status_banner
+(
+    id INT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    message TEXT NOT NULL,
+    severity status_banner_severity NOT NULL,
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    maintenance BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_by INT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (updated_by) REFERENCES users(id) ON DELETE SET NULL
+);
@@ -0,0 +1,10 @@
+-- Board team chosen by hand for projects where auto-detection picks the wrong one.
+-- Organization and project are stored lowercase.
+CREATE TABLE project_team_overrides
+(
+    organization TEXT NOT NULL,
+    project TEXT NOT NULL,
+    team TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (organization, project)
+);
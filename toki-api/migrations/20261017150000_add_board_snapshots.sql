@@ -0,0 +1,15 @@
+-- Periodic snapshots of sprint boards, used for "what changed" diffs.
+-- Organization and project are stored lowercase.
+CREATE TABLE board_snapshots
+(
+    id SERIAL PRIMARY KEY,
+    organization TEXT NOT NULL,
+    project TEXT NOT NULL,
+    team TEXT NOT NULL,
+    iteration_path TEXT NOT NULL,
+    items JSONB NOT NULL,
+    taken_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX board_snapshots_board_taken_at_idx
+    ON board_snapshots (organization, project, team, iteration_path, taken_at);
@@ -0,0 +1,19 @@
+CREATE TYPE budget_period AS ENUM
+(
+    'week',
+    'month'
+);
+
+-- Target hours a user sets for a project, per week or per month
+CREATE TABLE project_budgets
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    project_id TEXT NOT NULL,
+    project_name TEXT NOT NULL,
+    period budget_period NOT NULL,
+    target_hours DOUBLE PRECISION NOT NULL CHECK (target_hours > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, project_id, period),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
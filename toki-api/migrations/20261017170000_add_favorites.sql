@@ -0,0 +1,13 @@
+-- Project and activity combinations a user pinned for quick selection
+CREATE TABLE favorites
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    project_id TEXT NOT NULL,
+    project_name TEXT NOT NULL,
+    activity_id TEXT NOT NULL,
+    activity_name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, project_id, activity_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,16 @@
+-- Named presets for time entries a user logs often, like the daily standup
+CREATE TABLE entry_templates
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    name TEXT NOT NULL,
+    project_id TEXT NOT NULL,
+    project_name TEXT NOT NULL,
+    activity_id TEXT NOT NULL,
+    activity_name TEXT NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+    duration_minutes INT CHECK (duration_minutes > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, name),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,19 @@
+-- ICS feed each user's meetings are suggested as time entries from
+CREATE TABLE calendar_feeds
+(
+    user_id INT PRIMARY KEY,
+    url TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Meeting occurrences the user chose not to log
+CREATE TABLE calendar_dismissals
+(
+    user_id INT NOT NULL,
+    event_uid TEXT NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, event_uid, starts_at),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- Work item a saved timer entry was explicitly linked to
+ALTER TABLE timer_history
+ADD COLUMN work_item_id TEXT;
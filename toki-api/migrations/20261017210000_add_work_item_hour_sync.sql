@@ -0,0 +1,3 @@
+-- Opt-in: add hours of saved timers to the linked work item's Completed/Remaining Work
+ALTER TABLE users
+    ADD COLUMN sync_work_item_hours BOOLEAN NOT NULL DEFAULT FALSE;
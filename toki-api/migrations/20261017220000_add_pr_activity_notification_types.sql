@@ -0,0 +1,4 @@
+-- Notify about new pull requests, vote changes and merge conflicts
+ALTER TYPE notification_type ADD VALUE 'pr_created';
+ALTER TYPE notification_type ADD VALUE 'vote_changed';
+ALTER TYPE notification_type ADD VALUE 'merge_conflicts';
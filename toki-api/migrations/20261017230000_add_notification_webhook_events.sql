@@ -0,0 +1,9 @@
+-- Events outside pull request activity that each chat webhook is posted for
+ALTER TABLE notification_webhooks
+ADD COLUMN events TEXT[] NOT NULL DEFAULT ARRAY[
+    'pull_request.blocked',
+    'timer.long_running',
+    'day.unlogged',
+    'week.unreported',
+    'entries.anomalies'
+];
@@ -0,0 +1,3 @@
+-- Opt-in summary email: 'off', 'daily' or 'weekly'
+ALTER TABLE users
+    ADD COLUMN digest_frequency TEXT NOT NULL DEFAULT 'off';
@@ -0,0 +1,10 @@
+-- Daily flex balance per user, so the balance can be charted over time
+CREATE TABLE flex_snapshots
+(
+    user_id INT NOT NULL,
+    date DATE NOT NULL,
+    flex_hours DOUBLE PRECISION NOT NULL,
+    taken_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, date),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,23 @@
+-- Per-user rules stopping timers still running at the end of the day
+CREATE TABLE user_timer_auto_stops
+(
+    user_id INT PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    stop_at TIME NOT NULL DEFAULT '23:59',
+    action TEXT NOT NULL DEFAULT 'save' CHECK (action IN ('save', 'discard')),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- The last timer each user had stopped by their rule, until a client has shown it
+CREATE TABLE timer_auto_stops
+(
+    user_id INT PRIMARY KEY REFERENCES users (id) ON DELETE CASCADE,
+    action TEXT NOT NULL CHECK (action IN ('save', 'discard')),
+    started_at TIMESTAMPTZ NOT NULL,
+    stopped_at TIMESTAMPTZ NOT NULL,
+    project_name TEXT,
+    activity_name TEXT,
+    note TEXT NOT NULL DEFAULT '',
+    registration_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
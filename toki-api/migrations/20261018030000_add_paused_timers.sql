@@ -0,0 +1,20 @@
+-- Timers paused to track an interruption, a stack per user
+CREATE TABLE paused_timers
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    project_id TEXT,
+    project_name TEXT,
+    activity_id TEXT,
+    activity_name TEXT,
+    note TEXT NOT NULL DEFAULT '',
+    segment_starts TIMESTAMPTZ[] NOT NULL,
+    segment_ends TIMESTAMPTZ[] NOT NULL,
+    -- Set while the timer runs again, keeping its segments until it is saved
+    resumed BOOLEAN NOT NULL DEFAULT FALSE,
+    paused_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (cardinality(segment_starts) = cardinality(segment_ends))
+);
+
+CREATE INDEX idx_paused_timers_user_id ON paused_timers (user_id, paused_at DESC);
+CREATE UNIQUE INDEX idx_paused_timers_resumed ON paused_timers (user_id) WHERE resumed;
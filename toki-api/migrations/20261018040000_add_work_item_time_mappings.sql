@@ -0,0 +1,16 @@
+-- Project and activity a user tracks time on for an Azure DevOps project's
+-- work items
+CREATE TABLE work_item_time_mappings
+(
+    id SERIAL PRIMARY KEY,
+    user_id INT NOT NULL,
+    organization TEXT NOT NULL,
+    project TEXT NOT NULL,
+    project_id TEXT NOT NULL,
+    project_name TEXT NOT NULL,
+    activity_id TEXT NOT NULL,
+    activity_name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, organization, project),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
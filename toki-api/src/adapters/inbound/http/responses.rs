@@ -1,55 +1,38 @@
 //! HTTP response types for time tracking and work item endpoints.
 //!
-//! These types serialize to the JSON format expected by the frontend.
+//! These types serialize to the JSON format expected by the frontend. Time
+//! tracking types, and the work item types the TUI reads, are shared with it
+//! through the `toki-types` crate.
 
 use serde::Serialize;
-use time::OffsetDateTime;
 
 use crate::domain::models::{
-    ActiveTimer, Activity, BoardColumn, BoardData, BoardState, Iteration, Project, PullRequestRef,
-    TimeEntry, TimeEntryDayStatus, TimeEntryStatus, TimerHistoryEntry, WeeklyStats, WorkItem,
-    WorkItemCategory, WorkItemPerson, WorkItemProject, WorkItemRef,
+    ActiveTimer, Activity, AutoStoppedTimer, BoardColumn, BoardColumnWipLimit, BoardData,
+    BoardDiff, BoardItemMove, BoardSnapshotItem, BoardState, CalendarSuggestion, EntryTemplate,
+    Favorite, FlexSnapshot, InvoiceActivity, InvoiceDay, InvoiceProject, InvoiceReport, Iteration,
+    MissingWorkday, PausedTimer, Project, ProjectBudget, ProjectBudgetStatus, ProjectTeams,
+    PullRequestRef, SprintCapacity, TimeEntry, TimeEntryAnomaly, TimeEntryDayStatus,
+    TimeEntryStatus, TimerHistoryEntry, TimerSegment, WeeklyStats, WipLimitEnforcement,
+    WipLimitViolation, WorkItem, WorkItemCategory, WorkItemPerson, WorkItemProject, WorkItemRef,
+    WorkItemTime, WorkItemTimeEntry, WorkItemTimeMapping,
 };
 
-/// Response for the get timer endpoint.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GetTimerResponse {
-    pub timer: Option<TimerResponse>,
-}
-
-/// Response for saving the active timer.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SaveTimerResponse {
-    pub entry: TimeEntryResponse,
-    pub timer: Option<TimerResponse>,
-}
+pub use toki_types::time_tracking::{
+    ActivityResponse, AutoStoppedTimerResponse, BatchTimeEntriesResponse,
+    CalendarSuggestionResponse, EntryTemplateResponse, FavoriteResponse, FlexSnapshotResponse,
+    GetTimerAutoStopResponse, GetTimerResponse, InvoiceActivityResponse, InvoiceDayResponse,
+    InvoiceProjectResponse, InvoiceReportResponse, MissingWorkdayResponse, PauseTimerResponse,
+    PausedTimerResponse, ProjectBudgetResponse, ProjectBudgetStatusResponse, ProjectResponse,
+    SaveTimerResponse, SplitTimeEntryResponse, TimeEntryAnomalyResponse,
+    TimeEntryDayStatusResponse, TimeEntryImportResponse, TimeEntryImportRowResponse,
+    TimeEntryResponse, TimeEntryStatus as TimeEntryStatusResponse, TimerHistoryEntryResponse,
+    TimerResponse, TimerSegmentResponse, WeeklyStatsResponse,
+};
+pub use toki_types::work_items::{SprintCapacityResponse, WorkItemTimeMappingResponse};
 
-/// Active timer response - all timers are standalone now.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimerResponse {
-    /// When the timer was started (ISO 8601).
-    #[serde(with = "time::serde::rfc3339")]
-    pub start_time: OffsetDateTime,
-    /// Project ID (if set).
-    pub project_id: Option<String>,
-    /// Project name (if set).
-    pub project_name: Option<String>,
-    /// Activity ID/code (if set).
-    pub activity_id: Option<String>,
-    /// Activity name (if set).
-    pub activity_name: Option<String>,
-    /// User note.
-    pub note: String,
-    /// Elapsed hours.
-    pub hours: i64,
-    /// Elapsed minutes (within current hour).
-    pub minutes: i64,
-    /// Elapsed seconds (within current minute).
-    pub seconds: i64,
-}
+// ---------------------------------------------------------------------------
+// Time tracking conversions (wire types live in `toki-types`)
+// ---------------------------------------------------------------------------
 
 impl From<ActiveTimer> for TimerResponse {
     fn from(timer: ActiveTimer) -> Self {
@@ -64,16 +47,53 @@ impl From<ActiveTimer> for TimerResponse {
             hours,
             minutes,
             seconds,
+            device: None,
         }
     }
 }
 
-/// Project response - simplified for frontend use.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ProjectResponse {
-    pub project_id: String,
-    pub project_name: String,
+impl From<AutoStoppedTimer> for AutoStoppedTimerResponse {
+    fn from(stopped: AutoStoppedTimer) -> Self {
+        Self {
+            action: stopped.action.as_str().to_string(),
+            start_time: stopped.started_at,
+            stop_time: stopped.stopped_at,
+            project_name: stopped.project_name,
+            activity_name: stopped.activity_name,
+            note: stopped.note,
+            registration_id: stopped.registration_id,
+        }
+    }
+}
+
+impl From<PausedTimer> for PausedTimerResponse {
+    fn from(paused: PausedTimer) -> Self {
+        let tracked_minutes = paused.tracked().whole_minutes();
+        Self {
+            id: paused.id,
+            project_id: paused.project_id.map(|id| id.to_string()),
+            project_name: paused.project_name,
+            activity_id: paused.activity_id.map(|id| id.to_string()),
+            activity_name: paused.activity_name,
+            note: paused.note,
+            segments: paused
+                .segments
+                .into_iter()
+                .map(TimerSegmentResponse::from)
+                .collect(),
+            paused_at: paused.paused_at,
+            tracked_minutes,
+        }
+    }
+}
+
+impl From<TimerSegment> for TimerSegmentResponse {
+    fn from(segment: TimerSegment) -> Self {
+        Self {
+            start_time: segment.start,
+            end_time: segment.end,
+        }
+    }
 }
 
 impl From<Project> for ProjectResponse {
@@ -81,20 +101,13 @@ impl From<Project> for ProjectResponse {
         Self {
             project_id: project.id.to_string(),
             project_name: project.name,
+            // Kleer does not expose project budgets.
+            budget_hours: None,
+            used_hours: None,
         }
     }
 }
 
-/// Activity response - simplified for frontend use.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ActivityResponse {
-    /// Activity code (used in API calls).
-    pub activity: String,
-    /// Activity display name.
-    pub activity_name: String,
-}
-
 impl From<Activity> for ActivityResponse {
     fn from(activity: Activity) -> Self {
         Self {
@@ -104,25 +117,14 @@ impl From<Activity> for ActivityResponse {
     }
 }
 
-/// Time entry response - completed time registration.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimeEntryResponse {
-    pub registration_id: String,
-    pub project_id: String,
-    pub project_name: String,
-    pub activity_id: String,
-    pub activity_name: String,
-    /// Date in YYYY-MM-DD format.
-    pub date: String,
-    pub hours: f64,
-    pub note: Option<String>,
-    #[serde(with = "time::serde::rfc3339::option")]
-    pub start_time: Option<OffsetDateTime>,
-    #[serde(with = "time::serde::rfc3339::option")]
-    pub end_time: Option<OffsetDateTime>,
-    pub week_number: u8,
-    pub status: TimeEntryStatus,
+impl From<TimeEntryStatus> for TimeEntryStatusResponse {
+    fn from(status: TimeEntryStatus) -> Self {
+        match status {
+            TimeEntryStatus::Open => Self::Open,
+            TimeEntryStatus::Approved => Self::Approved,
+            TimeEntryStatus::Certified => Self::Certified,
+        }
+    }
 }
 
 impl From<TimeEntry> for TimeEntryResponse {
@@ -139,49 +141,21 @@ impl From<TimeEntry> for TimeEntryResponse {
             start_time: entry.start_time,
             end_time: entry.end_time,
             week_number: entry.week_number,
-            status: entry.status,
+            status: entry.status.into(),
+            work_item_id: entry.work_item_id,
         }
     }
 }
 
-/// Date-level time entry status response.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimeEntryDayStatusResponse {
-    /// Date in YYYY-MM-DD format.
-    pub date: String,
-    pub status: TimeEntryStatus,
-}
-
 impl From<TimeEntryDayStatus> for TimeEntryDayStatusResponse {
     fn from(day_status: TimeEntryDayStatus) -> Self {
         Self {
             date: day_status.date.to_string(),
-            status: day_status.status,
+            status: day_status.status.into(),
         }
     }
 }
 
-/// Timer history entry response.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimerHistoryEntryResponse {
-    pub id: i32,
-    pub registration_id: Option<String>,
-    pub user_id: i32,
-    #[serde(with = "time::serde::rfc3339")]
-    pub start_time: OffsetDateTime,
-    #[serde(with = "time::serde::rfc3339::option")]
-    pub end_time: Option<OffsetDateTime>,
-    pub project_id: Option<String>,
-    pub project_name: Option<String>,
-    pub activity_id: Option<String>,
-    pub activity_name: Option<String>,
-    pub note: Option<String>,
-    #[serde(with = "time::serde::rfc3339")]
-    pub created_at: OffsetDateTime,
-}
-
 impl From<TimerHistoryEntry> for TimerHistoryEntryResponse {
     fn from(entry: TimerHistoryEntry) -> Self {
         Self {
@@ -195,23 +169,12 @@ impl From<TimerHistoryEntry> for TimerHistoryEntryResponse {
             activity_id: entry.activity_id.map(|a| a.to_string()),
             activity_name: entry.activity_name,
             note: entry.note,
+            work_item_id: entry.work_item_id,
             created_at: entry.created_at,
         }
     }
 }
 
-/// Weekly stats response.
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct WeeklyStatsResponse {
-    pub worked_hours: f64,
-    pub scheduled_hours: f64,
-    pub remaining_hours: f64,
-    pub absence_hours: f64,
-    pub covered_hours: f64,
-    pub period_flex_hours: f64,
-}
-
 impl From<WeeklyStats> for WeeklyStatsResponse {
     fn from(info: WeeklyStats) -> Self {
         Self {
@@ -225,6 +188,162 @@ impl From<WeeklyStats> for WeeklyStatsResponse {
     }
 }
 
+impl From<InvoiceReport> for InvoiceReportResponse {
+    fn from(report: InvoiceReport) -> Self {
+        Self {
+            from: report.from.to_string(),
+            to: report.to.to_string(),
+            projects: report.projects.into_iter().map(Into::into).collect(),
+            total_hours: report.total_hours,
+        }
+    }
+}
+
+impl From<InvoiceProject> for InvoiceProjectResponse {
+    fn from(project: InvoiceProject) -> Self {
+        Self {
+            project_id: project.project_id.to_string(),
+            project_name: project.project_name,
+            activities: project.activities.into_iter().map(Into::into).collect(),
+            total_hours: project.total_hours,
+        }
+    }
+}
+
+impl From<InvoiceActivity> for InvoiceActivityResponse {
+    fn from(activity: InvoiceActivity) -> Self {
+        Self {
+            activity_id: activity.activity_id.to_string(),
+            activity_name: activity.activity_name,
+            days: activity.days.into_iter().map(Into::into).collect(),
+            total_hours: activity.total_hours,
+        }
+    }
+}
+
+impl From<InvoiceDay> for InvoiceDayResponse {
+    fn from(day: InvoiceDay) -> Self {
+        Self {
+            date: day.date.to_string(),
+            hours: day.hours,
+            registered_hours: day.registered_hours,
+            notes: day.notes,
+        }
+    }
+}
+
+impl From<TimeEntryAnomaly> for TimeEntryAnomalyResponse {
+    fn from(anomaly: TimeEntryAnomaly) -> Self {
+        Self {
+            kind: anomaly.kind.as_str().to_string(),
+            date: anomaly.date.to_string(),
+            registration_ids: anomaly.registration_ids,
+            message: anomaly.message,
+        }
+    }
+}
+
+impl From<ProjectBudget> for ProjectBudgetResponse {
+    fn from(budget: ProjectBudget) -> Self {
+        Self {
+            id: budget.id,
+            project_id: budget.project_id.to_string(),
+            project_name: budget.project_name,
+            period: budget.period.as_str().to_string(),
+            target_hours: budget.target_hours,
+        }
+    }
+}
+
+impl From<Favorite> for FavoriteResponse {
+    fn from(favorite: Favorite) -> Self {
+        Self {
+            id: favorite.id,
+            project_id: favorite.project_id.to_string(),
+            project_name: favorite.project_name,
+            activity_id: favorite.activity_id.to_string(),
+            activity_name: favorite.activity_name,
+        }
+    }
+}
+
+impl From<WorkItemTimeMapping> for WorkItemTimeMappingResponse {
+    fn from(mapping: WorkItemTimeMapping) -> Self {
+        Self {
+            id: mapping.id,
+            organization: mapping.organization,
+            project: mapping.project,
+            project_id: mapping.project_id.to_string(),
+            project_name: mapping.project_name,
+            activity_id: mapping.activity_id.to_string(),
+            activity_name: mapping.activity_name,
+        }
+    }
+}
+
+impl From<EntryTemplate> for EntryTemplateResponse {
+    fn from(template: EntryTemplate) -> Self {
+        Self {
+            id: template.id,
+            name: template.name,
+            project_id: template.project_id.to_string(),
+            project_name: template.project_name,
+            activity_id: template.activity_id.to_string(),
+            activity_name: template.activity_name,
+            note: template.note,
+            duration_minutes: template
+                .duration_minutes
+                .and_then(|minutes| u32::try_from(minutes).ok()),
+        }
+    }
+}
+
+impl From<CalendarSuggestion> for CalendarSuggestionResponse {
+    fn from(suggestion: CalendarSuggestion) -> Self {
+        Self {
+            uid: suggestion.uid,
+            title: suggestion.title,
+            start_time: suggestion.start,
+            end_time: suggestion.end,
+            project_id: suggestion.project_id.map(|id| id.to_string()),
+            project_name: suggestion.project_name,
+            activity_id: suggestion.activity_id.map(|id| id.to_string()),
+            activity_name: suggestion.activity_name,
+        }
+    }
+}
+
+impl From<ProjectBudgetStatus> for ProjectBudgetStatusResponse {
+    fn from(status: ProjectBudgetStatus) -> Self {
+        let nearly_used = status.is_nearly_used();
+        Self {
+            budget: status.budget.into(),
+            from: status.from.to_string(),
+            to: status.to.to_string(),
+            used_hours: status.used_hours,
+            nearly_used,
+        }
+    }
+}
+
+impl From<FlexSnapshot> for FlexSnapshotResponse {
+    fn from(snapshot: FlexSnapshot) -> Self {
+        Self {
+            date: snapshot.date.to_string(),
+            flex_hours: snapshot.flex_hours,
+        }
+    }
+}
+
+impl From<MissingWorkday> for MissingWorkdayResponse {
+    fn from(day: MissingWorkday) -> Self {
+        Self {
+            date: day.date.to_string(),
+            hours: day.hours,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Work Item response types
 // ---------------------------------------------------------------------------
@@ -310,12 +429,13 @@ impl From<BoardColumn> for BoardColumnResponse {
     }
 }
 
-/// Board response payload (columns + items).
+/// Board response payload (columns + items + WIP limits).
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BoardResponse {
     pub columns: Vec<BoardColumnResponse>,
     pub items: Vec<WorkItemResponse>,
+    pub wip_limits: Vec<BoardColumnWipLimitResponse>,
 }
 
 impl From<BoardData> for BoardResponse {
@@ -323,10 +443,149 @@ impl From<BoardData> for BoardResponse {
         Self {
             columns: board_data.columns.into_iter().map(Into::into).collect(),
             items: board_data.items.into_iter().map(Into::into).collect(),
+            wip_limits: board_data.wip_limits.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// What changed on a board between two snapshots.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardDiffResponse {
+    /// When the snapshot the diff starts from was taken.
+    #[serde(with = "time::serde::rfc3339")]
+    pub from: time::OffsetDateTime,
+    /// When the snapshot the diff ends at was taken.
+    #[serde(with = "time::serde::rfc3339")]
+    pub to: time::OffsetDateTime,
+    pub added: Vec<BoardDiffItemResponse>,
+    pub removed: Vec<BoardDiffItemResponse>,
+    pub moved: Vec<BoardItemMoveResponse>,
+    pub completed: Vec<BoardDiffItemResponse>,
+}
+
+impl From<BoardDiff> for BoardDiffResponse {
+    fn from(diff: BoardDiff) -> Self {
+        Self {
+            from: diff.from,
+            to: diff.to,
+            added: diff.added.into_iter().map(Into::into).collect(),
+            removed: diff.removed.into_iter().map(Into::into).collect(),
+            moved: diff.moved.into_iter().map(Into::into).collect(),
+            completed: diff.completed.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardDiffItemResponse {
+    pub id: String,
+    pub title: String,
+    pub column_name: Option<String>,
+    pub board_state: BoardState,
+}
+
+impl From<BoardSnapshotItem> for BoardDiffItemResponse {
+    fn from(item: BoardSnapshotItem) -> Self {
+        Self {
+            id: item.id,
+            title: item.title,
+            column_name: item.column_name,
+            board_state: item.board_state,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardItemMoveResponse {
+    pub id: String,
+    pub title: String,
+    pub from_column: Option<String>,
+    pub to_column: Option<String>,
+}
+
+impl From<BoardItemMove> for BoardItemMoveResponse {
+    fn from(item: BoardItemMove) -> Self {
+        Self {
+            id: item.id,
+            title: item.title,
+            from_column: item.from_column,
+            to_column: item.to_column,
+        }
+    }
+}
+
+/// WIP limit configured for a board column.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardColumnWipLimitResponse {
+    pub column_name: String,
+    pub limit: i32,
+    pub enforcement: WipLimitEnforcement,
+}
+
+impl From<BoardColumnWipLimit> for BoardColumnWipLimitResponse {
+    fn from(limit: BoardColumnWipLimit) -> Self {
+        Self {
+            column_name: limit.column_name,
+            limit: limit.limit,
+            enforcement: limit.enforcement,
         }
     }
 }
 
+/// The teams of a project and the one its boards use by default.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTeamsResponse {
+    /// Team used when a board request does not name one.
+    pub team: String,
+    pub detected_team: String,
+    pub override_team: Option<String>,
+    pub teams: Vec<String>,
+}
+
+impl From<ProjectTeams> for ProjectTeamsResponse {
+    fn from(teams: ProjectTeams) -> Self {
+        Self {
+            team: teams.default_team().to_string(),
+            detected_team: teams.detected_team,
+            override_team: teams.override_team,
+            teams: teams.teams,
+        }
+    }
+}
+
+/// A move that exceeded (or would exceed) a column's WIP limit.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WipLimitViolationResponse {
+    pub column_name: String,
+    pub limit: i32,
+    pub item_count: i32,
+    pub enforcement: WipLimitEnforcement,
+}
+
+impl From<WipLimitViolation> for WipLimitViolationResponse {
+    fn from(violation: WipLimitViolation) -> Self {
+        Self {
+            column_name: violation.column_name,
+            limit: violation.limit,
+            item_count: violation.item_count,
+            enforcement: violation.enforcement,
+        }
+    }
+}
+
+/// Response for a move that succeeded despite exceeding a warn-only WIP limit.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveWorkItemResponse {
+    pub wip_limit_warning: WipLimitViolationResponse,
+}
+
 /// A person associated with a work item.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -442,6 +701,19 @@ impl From<Iteration> for IterationResponse {
     }
 }
 
+impl From<SprintCapacity> for SprintCapacityResponse {
+    fn from(capacity: SprintCapacity) -> Self {
+        Self {
+            iteration_name: capacity.iteration_name,
+            iteration_path: capacity.iteration_path,
+            start_date: capacity.start_date.to_string(),
+            finish_date: capacity.finish_date.to_string(),
+            capacity_hours: capacity.capacity_hours,
+            logged_hours: capacity.logged_hours,
+        }
+    }
+}
+
 /// A project that has work items.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -458,3 +730,49 @@ impl From<WorkItemProject> for WorkItemProjectResponse {
         }
     }
 }
+
+/// Time tracked against a work item, from entries whose notes mention it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkItemTimeResponse {
+    pub work_item_id: String,
+    pub total_hours: f64,
+    pub entries: Vec<WorkItemTimeEntryResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkItemTimeEntryResponse {
+    pub registration_id: String,
+    pub user_name: String,
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    pub hours: f64,
+    pub project_name: String,
+    pub activity_name: String,
+    pub note: String,
+}
+
+impl From<WorkItemTime> for WorkItemTimeResponse {
+    fn from(time: WorkItemTime) -> Self {
+        Self {
+            work_item_id: time.work_item_id,
+            total_hours: time.total_hours,
+            entries: time.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<WorkItemTimeEntry> for WorkItemTimeEntryResponse {
+    fn from(entry: WorkItemTimeEntry) -> Self {
+        Self {
+            registration_id: entry.registration_id,
+            user_name: entry.user_name,
+            date: entry.date.to_string(),
+            hours: entry.hours,
+            project_name: entry.project_name,
+            activity_name: entry.activity_name,
+            note: entry.note,
+        }
+    }
+}
@@ -51,4 +51,8 @@ pub trait TimeTrackingServiceFactory: Send + Sync + 'static {
         &self,
         user_id: UserId,
     ) -> Result<Box<dyn TimeTrackingService>, TimeTrackingServiceError>;
+
+    /// Drop any per-user state the factory caches between requests (e.g. the
+    /// provider user mapping). Call after a user's provider link changes.
+    fn invalidate_user(&self, _user_id: UserId) {}
 }
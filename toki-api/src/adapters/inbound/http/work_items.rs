@@ -41,4 +41,7 @@ pub trait WorkItemServiceFactory: Send + Sync + 'static {
         &self,
         user_id: UserId,
     ) -> Result<Vec<WorkItemProject>, WorkItemServiceError>;
+
+    /// Drop cached per-project settings, e.g. after its team override changed.
+    fn invalidate_project(&self, organization: &str, project: &str);
 }
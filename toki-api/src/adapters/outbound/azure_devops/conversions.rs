@@ -5,8 +5,8 @@ use time::{Duration, OffsetDateTime, Time};
 use url::Url;
 
 use crate::domain::models::{
-    BoardState, Iteration, PullRequestRef, WorkItem, WorkItemCategory, WorkItemComment,
-    WorkItemPerson, WorkItemRef,
+    BoardState, Iteration, PullRequestRef, SprintCapacityPlan, SprintMemberCapacity, WorkItem,
+    WorkItemCategory, WorkItemComment, WorkItemPerson, WorkItemRef,
 };
 
 use super::{is_allowed_ado_attachment_url, normalize_iteration_path, urls::AzureDevOpsUrl};
@@ -306,6 +306,40 @@ fn to_domain_iteration_at(ado: az_devops::Iteration, now: OffsetDateTime) -> Ite
     }
 }
 
+/// Convert a team iteration and its capacity to a sprint capacity plan.
+///
+/// Returns `None` for iterations without dates. ADO stores sprint dates and
+/// days off as midnight UTC, so their UTC date is the day meant.
+pub fn to_domain_sprint_capacity_plan(
+    iteration: az_devops::TeamIteration,
+    capacity: az_devops::TeamIterationCapacity,
+) -> Option<SprintCapacityPlan> {
+    let to_days = |ranges: Vec<(OffsetDateTime, OffsetDateTime)>| {
+        ranges
+            .into_iter()
+            .map(|(first, last)| (first.date(), last.date()))
+            .collect()
+    };
+
+    Some(SprintCapacityPlan {
+        iteration_name: iteration.name,
+        iteration_path: normalize_iteration_path(&iteration.path),
+        start_date: iteration.start_date?.date(),
+        finish_date: iteration.finish_date?.date(),
+        members: capacity
+            .members
+            .into_iter()
+            .map(|member| SprintMemberCapacity {
+                display_name: member.display_name,
+                unique_name: member.unique_name,
+                capacity_per_day: member.capacity_per_day,
+                days_off: to_days(member.days_off),
+            })
+            .collect(),
+        team_days_off: to_days(capacity.team_days_off),
+    })
+}
+
 fn effective_finish(finish: OffsetDateTime) -> OffsetDateTime {
     if finish.time() == Time::MIDNIGHT {
         // ADO finish dates are often stored as midnight; treat those as inclusive end-of-day.
@@ -568,6 +602,9 @@ mod tests {
                 iteration_path: None,
                 area_path: None,
                 tags: None,
+                completed_work: None,
+                remaining_work: None,
+                revision: None,
             },
             "my-org",
             "my-project",
@@ -1,19 +1,24 @@
 mod conversions;
+mod team_cache;
 mod urls;
+pub use team_cache::TeamResolutionCache;
 pub(crate) use urls::AzureDevOpsUrl;
 
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use az_devops::RepoClientError;
+use futures_util::future::try_join_all;
+use time::OffsetDateTime;
 use tokio::sync::OnceCell;
 use url::Url;
 
 use crate::domain::{
     models::{
-        synthetic_column_id_from_name, BoardColumn, BoardColumnAssignment, Iteration, WorkItem,
-        WorkItemComment, WorkItemImage,
+        synthetic_column_id_from_name, BoardColumn, BoardColumnAssignment, Iteration, ProjectTeams,
+        SprintCapacityPlan, WorkItem, WorkItemComment, WorkItemEffort, WorkItemImage,
     },
     ports::outbound::WorkItemProvider,
     WorkItemError,
@@ -21,13 +26,18 @@ use crate::domain::{
 
 use self::conversions::{
     html_contains_images, html_to_markdown, to_domain_comment, to_domain_iteration,
-    to_domain_work_item,
+    to_domain_sprint_capacity_plan, to_domain_work_item,
 };
 
 /// Adapter that wraps an Azure DevOps `RepoClient` to implement the `WorkItemProvider` port.
+///
+/// When a request does not name a team, the project's team override is used,
+/// or else a default team detected from the project's team names.
 pub struct AzureDevOpsWorkItemAdapter {
     client: az_devops::RepoClient,
     api_base_url: Url,
+    team_cache: TeamResolutionCache,
+    team_override: Option<String>,
     resolved_default_team: OnceCell<String>,
 }
 
@@ -35,14 +45,26 @@ const MAX_WORK_ITEM_IMAGE_BYTES: usize = 5 * 1024 * 1024;
 
 impl AzureDevOpsWorkItemAdapter {
     /// Create a new adapter wrapping the given `RepoClient`.
-    pub fn new(client: az_devops::RepoClient, api_base_url: Url) -> Self {
+    pub fn new(
+        client: az_devops::RepoClient,
+        api_base_url: Url,
+        team_cache: TeamResolutionCache,
+    ) -> Self {
         Self {
             client,
             api_base_url,
+            team_cache,
+            team_override: None,
             resolved_default_team: OnceCell::new(),
         }
     }
 
+    /// Use `team` instead of the detected default team.
+    pub fn with_team_override(mut self, team: Option<String>) -> Self {
+        self.team_override = team;
+        self
+    }
+
     async fn current_iteration_paths_for_default_team(&self) -> HashSet<String> {
         let default_team = match self.resolve_default_team(None).await {
             Ok(team) => team,
@@ -55,15 +77,8 @@ impl AzureDevOpsWorkItemAdapter {
             }
         };
 
-        match self
-            .client
-            .get_current_team_iteration_paths(&default_team)
-            .await
-        {
-            Ok(paths) => paths
-                .into_iter()
-                .map(|path| normalize_iteration_path(&path))
-                .collect(),
+        match self.current_iteration_paths(&default_team).await {
+            Ok(paths) => paths.as_ref().clone(),
             Err(e) => {
                 tracing::warn!(
                     error = %e,
@@ -158,6 +173,28 @@ impl WorkItemProvider for AzureDevOpsWorkItemAdapter {
         Ok(format_work_item_ids(ids))
     }
 
+    async fn query_assigned_work_item_ids(
+        &self,
+        assignee: &str,
+    ) -> Result<Vec<String>, WorkItemError> {
+        let query = format!(
+            "SELECT [System.Id] FROM WorkItems \
+             WHERE [System.TeamProject] = @project \
+             AND [System.AssignedTo] = '{}' \
+             AND [System.State] NOT IN ('Closed', 'Done', 'Removed', 'Completed') \
+             ORDER BY [System.ChangedDate] desc",
+            assignee.replace('\'', "''")
+        );
+
+        tracing::debug!(wiql_query = %query, "Executing assigned work items WIQL query");
+        let ids = self
+            .client
+            .query_work_item_ids_wiql_project_scope(&query)
+            .await
+            .map_err(to_provider_error)?;
+        Ok(format_work_item_ids(ids))
+    }
+
     async fn get_work_items(&self, ids: &[String]) -> Result<Vec<WorkItem>, WorkItemError> {
         let int_ids: Vec<i32> = ids
             .iter()
@@ -354,6 +391,51 @@ impl WorkItemProvider for AzureDevOpsWorkItemAdapter {
         Ok((markdown, has_images))
     }
 
+    async fn get_sprint_capacity_plans(
+        &self,
+        team: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<SprintCapacityPlan>, WorkItemError> {
+        let resolved_team = self.resolve_default_team(team).await?;
+        let today = OffsetDateTime::now_utc().date();
+
+        let mut iterations: Vec<az_devops::TeamIteration> = self
+            .client
+            .get_team_iterations(&resolved_team)
+            .await
+            .map_err(to_provider_error)?
+            .into_iter()
+            .filter(|it| it.finish_date.is_some())
+            .filter(|it| it.start_date.is_some_and(|start| start.date() <= today))
+            .collect();
+        iterations.sort_by_key(|it| std::cmp::Reverse(it.start_date));
+        iterations.truncate(count);
+
+        let capacities = try_join_all(iterations.iter().map(|it| {
+            self.client
+                .get_team_iteration_capacity(&resolved_team, &it.id)
+        }))
+        .await
+        .map_err(to_provider_error)?;
+
+        Ok(iterations
+            .into_iter()
+            .zip(capacities)
+            .filter_map(|(iteration, capacity)| to_domain_sprint_capacity_plan(iteration, capacity))
+            .collect())
+    }
+
+    async fn get_project_teams(&self) -> Result<ProjectTeams, WorkItemError> {
+        let teams = self.project_team_names().await?;
+        let detected_team = self.detect_default_team(&teams)?;
+
+        Ok(ProjectTeams {
+            teams: teams.to_vec(),
+            detected_team,
+            override_team: self.team_override.clone(),
+        })
+    }
+
     async fn fetch_image(&self, image_url: &str) -> Result<WorkItemImage, WorkItemError> {
         let ParsedAttachmentUrl {
             attachment_id,
@@ -441,6 +523,66 @@ impl WorkItemProvider for AzureDevOpsWorkItemAdapter {
             .await
             .map_err(to_provider_error)
     }
+
+    async fn get_work_item_effort(
+        &self,
+        work_item_id: &str,
+    ) -> Result<Option<WorkItemEffort>, WorkItemError> {
+        let work_item_id = parse_work_item_id(work_item_id)?;
+        let items = self
+            .client
+            .get_work_items(vec![work_item_id])
+            .await
+            .map_err(to_provider_error)?;
+
+        Ok(items
+            .into_iter()
+            .find(|item| item.id == work_item_id)
+            .map(|item| WorkItemEffort {
+                completed_work: item.completed_work,
+                remaining_work: item.remaining_work,
+                revision: item.revision,
+            }))
+    }
+
+    async fn set_work_item_effort(
+        &self,
+        work_item_id: &str,
+        effort: WorkItemEffort,
+    ) -> Result<bool, WorkItemError> {
+        let work_item_id = parse_work_item_id(work_item_id)?;
+        let patches: Vec<_> = [
+            (az_devops::COMPLETED_WORK_FIELD, effort.completed_work),
+            (az_devops::REMAINING_WORK_FIELD, effort.remaining_work),
+        ]
+        .into_iter()
+        .filter_map(|(field, hours)| Some(az_devops::FieldPatch::set(field, hours?)))
+        .collect();
+        if patches.is_empty() {
+            return Ok(true);
+        }
+
+        let updated = match effort.revision {
+            Some(revision) => {
+                self.client
+                    .update_work_item_at_revision(work_item_id, revision, patches)
+                    .await
+            }
+            None => self.client.update_work_item(work_item_id, patches).await,
+        };
+        match updated {
+            Ok(_) => Ok(true),
+            Err(RepoClientError::WorkItemChanged(_)) => Ok(false),
+            Err(error) => Err(to_provider_error(error)),
+        }
+    }
+}
+
+fn parse_work_item_id(work_item_id: &str) -> Result<i32, WorkItemError> {
+    work_item_id
+        .trim()
+        .parse()
+        .map_err(|_| WorkItemError::InvalidInput(format!("Invalid work item ID: {work_item_id}")))
 }
 
 fn normalize_iteration_path(path: &str) -> String {
@@ -808,25 +950,18 @@ impl AzureDevOpsWorkItemAdapter {
             return Ok(explicit_team.to_string());
         }
 
+        if let Some(override_team) = &self.team_override {
+            return Ok(override_team.clone());
+        }
+
         let selected_team = self
             .resolved_default_team
             .get_or_try_init(|| async {
-                let project = self.client.project();
-                let project_teams = self
-                    .client
-                    .get_project_team_names()
-                    .await
-                    .map_err(to_provider_error)?;
-                let selected_team =
-                    select_default_project_team(project, self.client.repo_name(), &project_teams)
-                        .ok_or_else(|| {
-                        WorkItemError::ProviderError(format!(
-                            "No teams were found for project '{project}'"
-                        ))
-                    })?;
+                let project_teams = self.project_team_names().await?;
+                let selected_team = self.detect_default_team(&project_teams)?;
 
                 tracing::debug!(
-                    project = %project,
+                    project = %self.client.project(),
                     team = %selected_team,
                     team_count = project_teams.len(),
                     "Resolved default taskboard team"
@@ -839,6 +974,59 @@ impl AzureDevOpsWorkItemAdapter {
         Ok(selected_team.clone())
     }
 
+    fn detect_default_team(&self, project_teams: &[String]) -> Result<String, WorkItemError> {
+        let project = self.client.project();
+        select_default_project_team(project, self.client.repo_name(), project_teams).ok_or_else(
+            || WorkItemError::ProviderError(format!("No teams were found for project '{project}'")),
+        )
+    }
+
+    async fn project_team_names(&self) -> Result<Arc<Vec<String>>, WorkItemError> {
+        let organization = self.client.organization();
+        let project = self.client.project();
+        if let Some(teams) = self.team_cache.project_teams(organization, project) {
+            return Ok(teams);
+        }
+
+        let teams = Arc::new(
+            self.client
+                .get_project_team_names()
+                .await
+                .map_err(to_provider_error)?,
+        );
+        self.team_cache
+            .insert_project_teams(organization, project, teams.clone());
+        Ok(teams)
+    }
+
+    /// Normalized paths of the team's current iteration(s).
+    async fn current_iteration_paths(
+        &self,
+        team: &str,
+    ) -> Result<Arc<HashSet<String>>, WorkItemError> {
+        let organization = self.client.organization();
+        let project = self.client.project();
+        if let Some(paths) = self
+            .team_cache
+            .current_iteration_paths(organization, project, team)
+        {
+            return Ok(paths);
+        }
+
+        let paths: Arc<HashSet<String>> = Arc::new(
+            self.client
+                .get_current_team_iteration_paths(team)
+                .await
+                .map_err(to_provider_error)?
+                .into_iter()
+                .map(|path| normalize_iteration_path(&path))
+                .collect(),
+        );
+        self.team_cache
+            .insert_current_iteration_paths(organization, project, team, paths.clone());
+        Ok(paths)
+    }
+
     async fn resolve_taskboard_iteration(
         &self,
         iteration_path: Option<&str>,
@@ -860,15 +1048,7 @@ impl AzureDevOpsWorkItemAdapter {
                     .find(|it| normalize_iteration_path(&it.path) == normalized)
             }
             None => {
-                let current_paths: HashSet<String> = self
-                    .client
-                    .get_current_team_iteration_paths(team)
-                    .await
-                    .map_err(to_provider_error)?
-                    .into_iter()
-                    .map(|path| normalize_iteration_path(&path))
-                    .collect();
-
+                let current_paths = self.current_iteration_paths(team).await?;
                 iterations.into_iter().find(|it| {
                     let normalized = normalize_iteration_path(&it.path);
                     current_paths.contains(&normalized)
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use moka::sync::Cache;
+
+/// Teams are rarely added or renamed, so the list can be kept for a while.
+const PROJECT_TEAMS_TTL: Duration = Duration::from_secs(60 * 60);
+/// Short enough that a new sprint is picked up soon after it starts.
+const CURRENT_ITERATION_TTL: Duration = Duration::from_secs(10 * 60);
+const MAX_ENTRIES: u64 = 512;
+
+type ProjectKey = (String, String);
+
+/// Team and current iteration lookups shared by all adapters.
+///
+/// Adapters are created per request, so without this every board load
+/// would list the project's teams and fetch the team's current iteration
+/// from Azure DevOps again. Keys are case-insensitive.
+#[derive(Clone)]
+pub struct TeamResolutionCache {
+    project_teams: Cache<ProjectKey, Arc<Vec<String>>>,
+    current_iteration_paths: Cache<(ProjectKey, String), Arc<HashSet<String>>>,
+}
+
+impl TeamResolutionCache {
+    pub fn new() -> Self {
+        Self {
+            project_teams: Cache::builder()
+                .time_to_live(PROJECT_TEAMS_TTL)
+                .max_capacity(MAX_ENTRIES)
+                .build(),
+            current_iteration_paths: Cache::builder()
+                .time_to_live(CURRENT_ITERATION_TTL)
+                .max_capacity(MAX_ENTRIES)
+                .build(),
+        }
+    }
+
+    pub(super) fn project_teams(
+        &self,
+        organization: &str,
+        project: &str,
+    ) -> Option<Arc<Vec<String>>> {
+        self.project_teams.get(&project_key(organization, project))
+    }
+
+    pub(super) fn insert_project_teams(
+        &self,
+        organization: &str,
+        project: &str,
+        teams: Arc<Vec<String>>,
+    ) {
+        self.project_teams
+            .insert(project_key(organization, project), teams);
+    }
+
+    pub(super) fn current_iteration_paths(
+        &self,
+        organization: &str,
+        project: &str,
+        team: &str,
+    ) -> Option<Arc<HashSet<String>>> {
+        self.current_iteration_paths
+            .get(&(project_key(organization, project), team.to_lowercase()))
+    }
+
+    pub(super) fn insert_current_iteration_paths(
+        &self,
+        organization: &str,
+        project: &str,
+        team: &str,
+        paths: Arc<HashSet<String>>,
+    ) {
+        self.current_iteration_paths.insert(
+            (project_key(organization, project), team.to_lowercase()),
+            paths,
+        );
+    }
+}
+
+impl Default for TeamResolutionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn project_key(organization: &str, project: &str) -> ProjectKey {
+    (organization.to_lowercase(), project.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_teams_are_keyed_case_insensitively() {
+        let cache = TeamResolutionCache::new();
+        cache.insert_project_teams("Org", "Space Ninjas", Arc::new(vec!["Ops".to_string()]));
+
+        let teams = cache.project_teams("org", "space ninjas").unwrap();
+        assert_eq!(teams.as_slice(), ["Ops".to_string()]);
+        assert!(cache.project_teams("org", "other").is_none());
+    }
+
+    #[test]
+    fn current_iteration_paths_are_scoped_to_the_team() {
+        let cache = TeamResolutionCache::new();
+        let paths = Arc::new(HashSet::from(["Project\\Sprint 1".to_string()]));
+        cache.insert_current_iteration_paths("org", "project", "Ops", paths);
+
+        assert!(cache
+            .current_iteration_paths("ORG", "project", "ops")
+            .is_some());
+        assert!(cache
+            .current_iteration_paths("org", "project", "Platform")
+            .is_none());
+    }
+}
@@ -4,7 +4,7 @@ use kleer::{
 };
 
 use crate::domain::{
-    models::{Activity, Project, ProjectId, TimeEntry, TimeEntryStatus},
+    models::{Activity, Project, ProjectId, ScheduledDay, TimeEntry, TimeEntryStatus},
     TimeTrackingError,
 };
 
@@ -79,16 +79,37 @@ pub fn to_domain_scheduled_hours(schedule: &[KleerScheduleMetadata]) -> f64 {
 pub fn to_domain_absence_hours(payroll_events: &[KleerPayrollEvent]) -> f64 {
     payroll_events
         .iter()
-        .filter(|event| {
-            !matches!(
-                event.event_type,
-                KleerPayrollEventType::WorkHour | KleerPayrollEventType::Unknown
-            )
-        })
+        .filter(|event| is_absence(event))
         .map(|event| event.hours)
         .sum()
 }
 
+/// Each scheduled day with the absence registered on it.
+pub fn to_domain_scheduled_days(
+    schedule: &[KleerScheduleMetadata],
+    payroll_events: &[KleerPayrollEvent],
+) -> Vec<ScheduledDay> {
+    schedule
+        .iter()
+        .map(|day| ScheduledDay {
+            date: day.date,
+            scheduled_hours: day.actual_hours,
+            absence_hours: payroll_events
+                .iter()
+                .filter(|event| event.date == day.date && is_absence(event))
+                .map(|event| event.hours)
+                .sum(),
+        })
+        .collect()
+}
+
+fn is_absence(event: &KleerPayrollEvent) -> bool {
+    !matches!(
+        event.event_type,
+        KleerPayrollEventType::WorkHour | KleerPayrollEventType::Unknown
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +198,51 @@ mod tests {
 
         assert_eq!(to_domain_absence_hours(&events), 12.0);
     }
+
+    #[test]
+    fn scheduled_days_carry_that_days_absence() {
+        let date = |day| time::Date::from_calendar_date(2026, time::Month::April, day).unwrap();
+        let schedule = vec![
+            KleerScheduleMetadata {
+                date: date(20),
+                level_of_employment: 1.0,
+                gross_hours: 8.0,
+                net_hours: 8.0,
+                actual_hours: 8.0,
+            },
+            KleerScheduleMetadata {
+                date: date(21),
+                level_of_employment: 1.0,
+                gross_hours: 8.0,
+                net_hours: 8.0,
+                actual_hours: 8.0,
+            },
+        ];
+        let events = vec![
+            KleerPayrollEvent {
+                id: Some(1),
+                date: date(21),
+                hours: 3.0,
+                event_type: KleerPayrollEventType::Sick,
+                child: None,
+                comment: None,
+            },
+            KleerPayrollEvent {
+                id: Some(2),
+                date: date(21),
+                hours: 2.0,
+                event_type: KleerPayrollEventType::WorkHour,
+                child: None,
+                comment: None,
+            },
+        ];
+
+        let days = to_domain_scheduled_days(&schedule, &events);
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].absence_hours, 0.0);
+        assert_eq!(days[1].date, date(21));
+        assert_eq!(days[1].scheduled_hours, 8.0);
+        assert_eq!(days[1].absence_hours, 3.0);
+    }
 }
@@ -4,24 +4,23 @@ use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
 use kleer::{
-    KleerActivityList, KleerClient, KleerClientProjectList, KleerClientProjectReadable,
-    KleerCredentials, KleerError, KleerEventReadable, KleerEventRestrictionList,
-    KleerEventWritable, KleerIdRef,
+    KleerActivityList, KleerClient, KleerClientProjectList, KleerClientProjectReadable, KleerError,
+    KleerEventReadable, KleerEventRestrictionList, KleerEventWritable, KleerIdRef,
 };
 use time::Date;
 
 use crate::domain::{
     models::{
-        Activity, ActivityId, CreateTimeEntryRequest, EditTimeEntryRequest, Project, ProjectId,
-        TimeEntry, TimeEntryDayStatus, TimerId, WeeklyStats,
+        first_locked_day, Activity, ActivityId, CreateTimeEntryRequest, EditTimeEntryRequest,
+        Project, ProjectId, ScheduledDay, TimeEntry, TimeEntryDayStatus, TimerId, WeeklyStats,
     },
     ports::outbound::TimeTrackingClient,
     TimeTrackingError,
 };
 
 use self::conversions::{
-    to_domain_absence_hours, to_domain_activity, to_domain_project, to_domain_scheduled_hours,
-    to_domain_status, to_domain_time_entry,
+    to_domain_absence_hours, to_domain_activity, to_domain_project, to_domain_scheduled_days,
+    to_domain_scheduled_hours, to_domain_status, to_domain_time_entry,
 };
 
 pub struct KleerAdapter {
@@ -37,15 +36,12 @@ struct VerifiedKleerEventTarget {
 impl KleerAdapter {
     const MISSING_NOTE_COMMENT: &'static str = "missing note";
 
-    pub fn new(
-        credentials: KleerCredentials,
-        target_user_id: i64,
-    ) -> Result<Self, TimeTrackingError> {
-        let client = KleerClient::new(credentials).map_err(map_kleer_error)?;
-        Ok(Self {
+    /// Build an adapter around a shared client, reusing its connection pool.
+    pub fn new(client: KleerClient, target_user_id: i64) -> Self {
+        Self {
             client,
             target_user_id,
-        })
+        }
     }
 
     fn project_visible_to_user(project: &KleerClientProjectReadable, user_id: i64) -> bool {
@@ -182,14 +178,37 @@ impl KleerAdapter {
         }
     }
 
-    async fn ensure_event_owned(&self, event_id: i64) -> Result<(), TimeTrackingError> {
+    /// The event, if it belongs to the target user.
+    async fn ensure_event_owned(
+        &self,
+        event_id: i64,
+    ) -> Result<KleerEventReadable, TimeTrackingError> {
         let event = self
             .client
             .get_event(event_id)
             .await
             .map_err(map_kleer_error)?;
 
-        self.ensure_event_owned_by_target_user(&event)
+        self.ensure_event_owned_by_target_user(&event)?;
+        Ok(event)
+    }
+
+    /// Kleer keeps attested days read-only but answers writes to them with a
+    /// generic error, so check first to give a clear one.
+    async fn ensure_days_open(&self, dates: &[Date]) -> Result<(), TimeTrackingError> {
+        let (Some(from), Some(to)) = (dates.iter().min(), dates.iter().max()) else {
+            return Ok(());
+        };
+        let statuses = self
+            .client
+            .list_event_statuses(self.target_user_id, *from, *to)
+            .await
+            .map_err(map_kleer_error)?;
+
+        match first_locked_day(&Self::to_domain_day_statuses(statuses), dates) {
+            Some(date) => Err(TimeTrackingError::DayLocked(date)),
+            None => Ok(()),
+        }
     }
 
     fn build_event_writable(
@@ -313,21 +332,26 @@ impl TimeTrackingClient for KleerAdapter {
         &self,
         date_range: (Date, Date),
     ) -> Result<WeeklyStats, TimeTrackingError> {
-        let events = self
-            .client
-            .list_events(self.target_user_id, date_range.0, date_range.1)
-            .await
-            .map_err(map_kleer_error)?;
-        let schedule = self
-            .client
-            .list_schedule_summary(self.target_user_id, date_range.0, date_range.1)
-            .await
-            .or_else(empty_schedule_for_missing_payroll_user)?;
-        let payroll_events = self
-            .client
-            .list_payroll_events(self.target_user_id, date_range.0, date_range.1)
-            .await
-            .or_else(empty_payroll_events_for_missing_payroll_user)?;
+        let (events, schedule, payroll_events) = tokio::try_join!(
+            async {
+                self.client
+                    .list_events_chunked(self.target_user_id, date_range.0, date_range.1)
+                    .await
+                    .map_err(map_kleer_error)
+            },
+            async {
+                self.client
+                    .list_schedule_summary_chunked(self.target_user_id, date_range.0, date_range.1)
+                    .await
+                    .or_else(empty_schedule_for_missing_payroll_user)
+            },
+            async {
+                self.client
+                    .list_payroll_events_chunked(self.target_user_id, date_range.0, date_range.1)
+                    .await
+                    .or_else(empty_payroll_events_for_missing_payroll_user)
+            },
+        )?;
 
         let worked_hours: f64 = events
             .event_readables
@@ -345,30 +369,47 @@ impl TimeTrackingClient for KleerAdapter {
         ))
     }
 
+    async fn get_scheduled_days(
+        &self,
+        date_range: (Date, Date),
+    ) -> Result<Vec<ScheduledDay>, TimeTrackingError> {
+        let (schedule, payroll_events) = tokio::try_join!(
+            async {
+                self.client
+                    .list_schedule_summary_chunked(self.target_user_id, date_range.0, date_range.1)
+                    .await
+                    .or_else(empty_schedule_for_missing_payroll_user)
+            },
+            async {
+                self.client
+                    .list_payroll_events_chunked(self.target_user_id, date_range.0, date_range.1)
+                    .await
+                    .or_else(empty_payroll_events_for_missing_payroll_user)
+            },
+        )?;
+
+        Ok(to_domain_scheduled_days(
+            &schedule.payroll_user_schedule_metadatas,
+            &payroll_events.payroll_events,
+        ))
+    }
+
     async fn get_time_entries(
         &self,
         date_range: (Date, Date),
     ) -> Result<Vec<TimeEntry>, TimeTrackingError> {
-        let projects = self
-            .client
-            .list_client_projects()
-            .await
-            .map_err(map_kleer_error)?;
-        let activities = self
-            .client
-            .list_activities()
-            .await
-            .map_err(map_kleer_error)?;
-        let events = self
-            .client
-            .list_events(self.target_user_id, date_range.0, date_range.1)
-            .await
-            .map_err(map_kleer_error)?;
-        let statuses = self
-            .client
-            .list_event_statuses(self.target_user_id, date_range.0, date_range.1)
-            .await
-            .map_err(map_kleer_error)?;
+        let (projects, activities, events, statuses) = tokio::try_join!(
+            self.client.list_client_projects(),
+            self.client.list_activities(),
+            self.client
+                .list_events_chunked(self.target_user_id, date_range.0, date_range.1),
+            self.client.list_event_statuses_chunked(
+                self.target_user_id,
+                date_range.0,
+                date_range.1
+            ),
+        )
+        .map_err(map_kleer_error)?;
 
         let project_names: HashMap<_, _> = projects
             .client_project_readables
@@ -434,7 +475,7 @@ impl TimeTrackingClient for KleerAdapter {
     ) -> Result<Vec<TimeEntryDayStatus>, TimeTrackingError> {
         let statuses = self
             .client
-            .list_event_statuses(self.target_user_id, date_range.0, date_range.1)
+            .list_event_statuses_chunked(self.target_user_id, date_range.0, date_range.1)
             .await
             .map_err(map_kleer_error)?;
 
@@ -445,6 +486,7 @@ impl TimeTrackingClient for KleerAdapter {
         &self,
         request: &CreateTimeEntryRequest,
     ) -> Result<TimerId, TimeTrackingError> {
+        self.ensure_days_open(&[request.start_time.date()]).await?;
         let target = self
             .ensure_project_activity_allowed(&request.project_id, &request.activity_id)
             .await?;
@@ -470,7 +512,9 @@ impl TimeTrackingClient for KleerAdapter {
         request: &EditTimeEntryRequest,
     ) -> Result<TimerId, TimeTrackingError> {
         let event_id = Self::parse_kleer_id(&request.registration_id, "event id")?;
-        self.ensure_event_owned(event_id).await?;
+        let event = self.ensure_event_owned(event_id).await?;
+        self.ensure_days_open(&[event.date, request.start_time.date()])
+            .await?;
         let target = self
             .ensure_project_activity_allowed(&request.project_id, &request.activity_id)
             .await?;
@@ -493,7 +537,8 @@ impl TimeTrackingClient for KleerAdapter {
 
     async fn delete_time_entry(&self, registration_id: &str) -> Result<(), TimeTrackingError> {
         let event_id = Self::parse_kleer_id(registration_id, "event id")?;
-        self.ensure_event_owned(event_id).await?;
+        let event = self.ensure_event_owned(event_id).await?;
+        self.ensure_days_open(&[event.date]).await?;
 
         self.client
             .delete_event(event_id)
@@ -510,7 +555,12 @@ fn map_kleer_error(error: KleerError) -> TimeTrackingError {
             TimeTrackingError::unknown("Kleer integration token is invalid or expired")
         }
         KleerError::Forbidden => TimeTrackingError::unknown("Kleer access forbidden"),
+        error if error.is_transient() => {
+            tracing::warn!("Kleer request failed after retries: {error}");
+            TimeTrackingError::Unavailable(error.to_string())
+        }
         KleerError::InvalidConfig(message)
+        | KleerError::Unavailable(message)
         | KleerError::Request(message)
         | KleerError::Deserialize { message, .. } => TimeTrackingError::unknown(message),
         KleerError::Response { status, body } => {
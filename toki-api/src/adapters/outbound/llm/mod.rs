@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    config::{LlmProvider, LlmSettings},
+    domain::{ports::outbound::TextGenerator, NoteImprovementError},
+};
+
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const ANTHROPIC_BASE_URL: &str = "https://api.anthropic.com/v1";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Text generation over the OpenAI chat completions or Anthropic messages API.
+pub struct HttpTextGenerator {
+    client: reqwest::Client,
+    provider: LlmProvider,
+    api_key: String,
+    model: String,
+    base_url: String,
+    max_tokens: u32,
+}
+
+impl HttpTextGenerator {
+    pub fn new(settings: &LlmSettings) -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(settings.timeout_secs))
+            .build()?;
+        let base_url = settings
+            .base_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .unwrap_or(match settings.provider {
+                LlmProvider::OpenAi => OPENAI_BASE_URL,
+                LlmProvider::Anthropic => ANTHROPIC_BASE_URL,
+            })
+            .trim_end_matches('/')
+            .to_string();
+
+        Ok(Self {
+            client,
+            provider: settings.provider,
+            api_key: settings.api_key.clone(),
+            model: settings.model.clone(),
+            base_url,
+            max_tokens: settings.max_tokens,
+        })
+    }
+
+    async fn openai(&self, instructions: &str, prompt: &str) -> Result<String, reqwest::Error> {
+        #[derive(Deserialize)]
+        struct Response {
+            choices: Vec<Choice>,
+        }
+        #[derive(Deserialize)]
+        struct Choice {
+            message: Message,
+        }
+        #[derive(Deserialize)]
+        struct Message {
+            content: Option<String>,
+        }
+
+        let response: Response = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&json!({
+                "model": self.model,
+                "max_tokens": self.max_tokens,
+                "messages": [
+                    { "role": "system", "content": instructions },
+                    { "role": "user", "content": prompt },
+                ],
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(response
+            .choices
+            .into_iter()
+            .find_map(|choice| choice.message.content)
+            .unwrap_or_default())
+    }
+
+    async fn anthropic(&self, instructions: &str, prompt: &str) -> Result<String, reqwest::Error> {
+        #[derive(Deserialize)]
+        struct Response {
+            content: Vec<ContentBlock>,
+        }
+        #[derive(Deserialize)]
+        struct ContentBlock {
+            #[serde(default)]
+            text: Option<String>,
+        }
+
+        let response: Response = self
+            .client
+            .post(format!("{}/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&json!({
+                "model": self.model,
+                "max_tokens": self.max_tokens,
+                "system": instructions,
+                "messages": [{ "role": "user", "content": prompt }],
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(response
+            .content
+            .into_iter()
+            .filter_map(|block| block.text)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl TextGenerator for HttpTextGenerator {
+    async fn generate(
+        &self,
+        instructions: &str,
+        prompt: &str,
+    ) -> Result<String, NoteImprovementError> {
+        let result = match self.provider {
+            LlmProvider::OpenAi => self.openai(instructions, prompt).await,
+            LlmProvider::Anthropic => self.anthropic(instructions, prompt).await,
+        };
+
+        result.map_err(|e| NoteImprovementError::Provider(e.to_string()))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod azure_devops;
 pub mod kleer;
+pub mod llm;
 pub mod media;
 pub mod postgres;
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::{
+    models::{BoardSnapshot, BoardSnapshotItem},
+    ports::outbound::{BoardSnapshotRepository, BoardSnapshotScope},
+    WorkItemError,
+};
+
+pub struct PostgresBoardSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PostgresBoardSnapshotRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct BoardSnapshotRow {
+    items: serde_json::Value,
+    taken_at: OffsetDateTime,
+}
+
+impl TryFrom<BoardSnapshotRow> for BoardSnapshot {
+    type Error = WorkItemError;
+
+    fn try_from(row: BoardSnapshotRow) -> Result<Self, Self::Error> {
+        let items: Vec<BoardSnapshotItem> = serde_json::from_value(row.items)
+            .map_err(|err| WorkItemError::Storage(format!("invalid board snapshot: {err}")))?;
+
+        Ok(BoardSnapshot {
+            taken_at: row.taken_at,
+            items,
+        })
+    }
+}
+
+#[async_trait]
+impl BoardSnapshotRepository for PostgresBoardSnapshotRepository {
+    async fn save_snapshot(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        snapshot: &BoardSnapshot,
+    ) -> Result<(), WorkItemError> {
+        let items = serde_json::to_value(&snapshot.items)
+            .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO board_snapshots (organization, project, team, iteration_path, items, taken_at)
+            VALUES (lower($1), lower($2), $3, $4, $5, $6)
+            "#,
+            scope.organization,
+            scope.project,
+            scope.team,
+            scope.iteration_path,
+            items,
+            snapshot.taken_at,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn snapshot_at_or_before(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        at: OffsetDateTime,
+    ) -> Result<Option<BoardSnapshot>, WorkItemError> {
+        let row = sqlx::query_as!(
+            BoardSnapshotRow,
+            r#"
+            SELECT items, taken_at
+            FROM board_snapshots
+            WHERE organization = lower($1) AND project = lower($2)
+              AND team = $3 AND iteration_path = $4
+              AND taken_at <= $5
+            ORDER BY taken_at DESC
+            LIMIT 1
+            "#,
+            scope.organization,
+            scope.project,
+            scope.team,
+            scope.iteration_path,
+            at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        row.map(BoardSnapshot::try_from).transpose()
+    }
+
+    async fn snapshot_at_or_after(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        at: OffsetDateTime,
+    ) -> Result<Option<BoardSnapshot>, WorkItemError> {
+        let row = sqlx::query_as!(
+            BoardSnapshotRow,
+            r#"
+            SELECT items, taken_at
+            FROM board_snapshots
+            WHERE organization = lower($1) AND project = lower($2)
+              AND team = $3 AND iteration_path = $4
+              AND taken_at >= $5
+            ORDER BY taken_at ASC
+            LIMIT 1
+            "#,
+            scope.organization,
+            scope.project,
+            scope.team,
+            scope.iteration_path,
+            at,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        row.map(BoardSnapshot::try_from).transpose()
+    }
+}
@@ -1,5 +1,13 @@
 mod avatar;
+mod board_snapshots;
+mod team_overrides;
 mod timer_history;
+mod wip_limits;
+mod work_item_time_links;
 
 pub use avatar::PostgresAvatarRepository;
+pub use board_snapshots::PostgresBoardSnapshotRepository;
+pub use team_overrides::PostgresTeamOverrideRepository;
 pub use timer_history::PostgresTimerHistoryAdapter;
+pub use wip_limits::PostgresWipLimitRepository;
+pub use work_item_time_links::PostgresWorkItemTimeLinkRepository;
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{ports::outbound::TeamOverrideRepository, WorkItemError};
+
+pub struct PostgresTeamOverrideRepository {
+    pool: PgPool,
+}
+
+impl PostgresTeamOverrideRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TeamOverrideRepository for PostgresTeamOverrideRepository {
+    async fn get_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+    ) -> Result<Option<String>, WorkItemError> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT team
+            FROM project_team_overrides
+            WHERE organization = lower($1) AND project = lower($2)
+            "#,
+            organization,
+            project,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))
+    }
+
+    async fn set_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+        team: &str,
+    ) -> Result<(), WorkItemError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO project_team_overrides (organization, project, team, updated_at)
+            VALUES (lower($1), lower($2), $3, now())
+            ON CONFLICT (organization, project) DO UPDATE
+            SET team = EXCLUDED.team,
+                updated_at = now()
+            "#,
+            organization,
+            project,
+            team,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn clear_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+    ) -> Result<(), WorkItemError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM project_team_overrides
+            WHERE organization = lower($1) AND project = lower($2)
+            "#,
+            organization,
+            project,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(())
+    }
+}
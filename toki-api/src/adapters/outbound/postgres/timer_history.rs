@@ -7,14 +7,15 @@ use time::OffsetDateTime;
 
 use crate::domain::{
     models::{
-        ActiveTimer, ActivityId, NewTimerHistoryEntry, ProjectId, TimerHistoryEntry,
-        TimerHistoryId, UserId,
+        ActiveTimer, ActivityId, NewTimerHistoryEntry, PausedTimer, ProjectId, TimerHistoryEntry,
+        TimerHistoryId, TimerSegment, UserId,
     },
     ports::outbound::TimerHistoryRepository,
     TimeTrackingError,
 };
 use crate::repositories::{
-    DatabaseTimer, FinishedDatabaseTimer, NewDatabaseTimer, TimerRepository, TimerRepositoryImpl,
+    DatabasePausedTimer, DatabaseTimer, FinishedDatabaseTimer, NewDatabasePausedTimer,
+    NewDatabaseTimer, ResumedPausedTimer, TimerRepository, TimerRepositoryImpl,
     UpdateDatabaseTimer,
 };
 
@@ -100,11 +101,114 @@ impl<R: TimerRepository + Send + Sync + 'static> TimerHistoryRepository
     async fn save_timer_finished(
         &self,
         user_id: &UserId,
+        start_time: &OffsetDateTime,
         end_time: &OffsetDateTime,
         registration_id: &str,
+        work_item_id: Option<&str>,
+    ) -> Result<(), TimeTrackingError> {
+        self.repo
+            .save_active_timer(
+                &user_id.as_i32(),
+                start_time,
+                end_time,
+                registration_id,
+                work_item_id,
+            )
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))
+    }
+
+    async fn get_paused_timers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PausedTimer>, TimeTrackingError> {
+        let timers = self
+            .repo
+            .paused_timers(&user_id.as_i32())
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))?;
+
+        Ok(timers.into_iter().map(db_paused_timer_to_domain).collect())
+    }
+
+    async fn push_paused_timer(
+        &self,
+        user_id: &UserId,
+        timer: &ActiveTimer,
+        segments: &[TimerSegment],
+    ) -> Result<PausedTimer, TimeTrackingError> {
+        let new_timer = NewDatabasePausedTimer {
+            user_id: user_id.as_i32(),
+            project_id: timer.project_id.as_ref().map(|p| p.to_string()),
+            project_name: timer.project_name.clone(),
+            activity_id: timer.activity_id.as_ref().map(|a| a.to_string()),
+            activity_name: timer.activity_name.clone(),
+            note: timer.note.clone(),
+            segment_starts: segments.iter().map(|segment| segment.start).collect(),
+            segment_ends: segments.iter().map(|segment| segment.end).collect(),
+        };
+
+        let timer = self
+            .repo
+            .create_paused_timer(&new_timer)
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))?;
+
+        Ok(db_paused_timer_to_domain(timer))
+    }
+
+    async fn resume_paused_timer(
+        &self,
+        user_id: &UserId,
+        id: Option<i32>,
+        resumed_at: OffsetDateTime,
+    ) -> Result<ActiveTimer, TimeTrackingError> {
+        let resumed = self
+            .repo
+            .resume_paused_timer(&user_id.as_i32(), id, &resumed_at)
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))?;
+
+        match resumed {
+            ResumedPausedTimer::Resumed(timer) => {
+                Ok(db_paused_timer_to_domain(timer).resume(resumed_at))
+            }
+            ResumedPausedTimer::NotFound => Err(TimeTrackingError::TimerNotFound),
+            ResumedPausedTimer::TimerRunning => Err(TimeTrackingError::TimerAlreadyRunning),
+        }
+    }
+
+    async fn get_resumed_segments(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<TimerSegment>, TimeTrackingError> {
+        let timer = self
+            .repo
+            .resumed_timer(&user_id.as_i32())
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))?;
+
+        Ok(timer
+            .map(|timer| db_paused_timer_to_domain(timer).segments)
+            .unwrap_or_default())
+    }
+
+    async fn set_resumed_segments(
+        &self,
+        user_id: &UserId,
+        segments: &[TimerSegment],
     ) -> Result<(), TimeTrackingError> {
+        let segment_starts: Vec<_> = segments.iter().map(|segment| segment.start).collect();
+        let segment_ends: Vec<_> = segments.iter().map(|segment| segment.end).collect();
+        self.repo
+            .update_resumed_timer_segments(&user_id.as_i32(), &segment_starts, &segment_ends)
+            .await
+            .map_err(|e| TimeTrackingError::unknown(e.to_string()))
+    }
+
+    async fn clear_resumed_segments(&self, user_id: &UserId) -> Result<(), TimeTrackingError> {
         self.repo
-            .save_active_timer(&user_id.as_i32(), end_time, registration_id)
+            .delete_resumed_timer(&user_id.as_i32())
             .await
             .map_err(|e| TimeTrackingError::unknown(e.to_string()))
     }
@@ -149,6 +253,7 @@ impl<R: TimerRepository + Send + Sync + 'static> TimerHistoryRepository
             activity_name: entry.activity_name.clone(),
             note: entry.note.clone(),
             registration_id: entry.registration_id.clone(),
+            work_item_id: entry.work_item_id.clone(),
         };
 
         let id = self
@@ -235,5 +340,30 @@ fn db_timer_to_domain(timer: DatabaseTimer) -> TimerHistoryEntry {
         entry = entry.with_note(note);
     }
 
+    if let Some(work_item_id) = timer.work_item_id {
+        entry = entry.with_work_item(work_item_id);
+    }
+
     entry
 }
+
+/// Convert a database paused timer to a domain PausedTimer.
+fn db_paused_timer_to_domain(timer: DatabasePausedTimer) -> PausedTimer {
+    let segments = timer
+        .segment_starts
+        .into_iter()
+        .zip(timer.segment_ends)
+        .map(|(start, end)| TimerSegment::new(start, end))
+        .collect();
+
+    PausedTimer {
+        id: timer.id,
+        project_id: timer.project_id.map(ProjectId::new),
+        project_name: timer.project_name,
+        activity_id: timer.activity_id.map(ActivityId::new),
+        activity_name: timer.activity_name,
+        note: timer.note,
+        segments,
+        paused_at: timer.paused_at,
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    models::{BoardColumnWipLimit, WipLimitEnforcement},
+    ports::outbound::WipLimitRepository,
+    WorkItemError,
+};
+
+pub struct PostgresWipLimitRepository {
+    pool: PgPool,
+}
+
+impl PostgresWipLimitRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WipLimitRepository for PostgresWipLimitRepository {
+    async fn list_limits(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+    ) -> Result<Vec<BoardColumnWipLimit>, WorkItemError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT column_name, wip_limit, enforcement::text AS "enforcement!"
+            FROM board_column_wip_limits
+            WHERE lower(organization) = lower($1)
+              AND lower(project) = lower($2)
+              AND team = $3
+            ORDER BY column_name
+            "#,
+            organization,
+            project,
+            team.unwrap_or_default(),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BoardColumnWipLimit {
+                column_name: row.column_name,
+                limit: row.wip_limit,
+                enforcement: WipLimitEnforcement::parse(&row.enforcement).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn upsert_limit(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+        limit: &BoardColumnWipLimit,
+    ) -> Result<(), WorkItemError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO board_column_wip_limits
+                (organization, project, team, column_name, wip_limit, enforcement, updated_at)
+            VALUES ($1, $2, $3, $4, $5, ($6::text)::wip_limit_enforcement, now())
+            ON CONFLICT (organization, project, team, column_name) DO UPDATE
+            SET wip_limit = EXCLUDED.wip_limit,
+                enforcement = EXCLUDED.enforcement,
+                updated_at = now()
+            "#,
+            organization,
+            project,
+            team.unwrap_or_default(),
+            &limit.column_name,
+            limit.limit,
+            limit.enforcement.as_str(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_limit(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+        column_name: &str,
+    ) -> Result<(), WorkItemError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM board_column_wip_limits
+            WHERE lower(organization) = lower($1)
+              AND lower(project) = lower($2)
+              AND team = $3
+              AND lower(column_name) = lower($4)
+            "#,
+            organization,
+            project,
+            team.unwrap_or_default(),
+            column_name,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|err| WorkItemError::Storage(err.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+use time::Date;
+
+use crate::domain::{
+    models::{UserId, WorkItemTime, WorkItemTimeEntry, WorkItemTimeLink},
+    ports::outbound::WorkItemTimeLinkRepository,
+    TimeTrackingError,
+};
+
+pub struct PostgresWorkItemTimeLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkItemTimeLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_sqlx_error(error: sqlx::Error) -> TimeTrackingError {
+    TimeTrackingError::unknown(error.to_string())
+}
+
+async fn insert_links(
+    tx: &mut Transaction<'_, Postgres>,
+    links: &[WorkItemTimeLink],
+) -> Result<(), TimeTrackingError> {
+    for link in links {
+        sqlx::query!(
+            r#"
+            INSERT INTO work_item_time_links (
+                work_item_id, registration_id, user_id, entry_date, hours,
+                project_name, activity_name, note
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (work_item_id, registration_id) DO UPDATE
+            SET user_id = EXCLUDED.user_id,
+                entry_date = EXCLUDED.entry_date,
+                hours = EXCLUDED.hours,
+                project_name = EXCLUDED.project_name,
+                activity_name = EXCLUDED.activity_name,
+                note = EXCLUDED.note
+            "#,
+            link.work_item_id,
+            link.registration_id,
+            link.user_id.as_i32(),
+            link.date,
+            link.hours,
+            link.project_name,
+            link.activity_name,
+            link.note,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(map_sqlx_error)?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl WorkItemTimeLinkRepository for PostgresWorkItemTimeLinkRepository {
+    async fn sync_links(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        links: &[WorkItemTimeLink],
+    ) -> Result<(), TimeTrackingError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM work_item_time_links
+            WHERE user_id = $1 AND entry_date BETWEEN $2 AND $3
+            "#,
+            user_id.as_i32(),
+            date_range.0,
+            date_range.1,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        insert_links(&mut tx, links).await?;
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+
+    async fn replace_entry_links(
+        &self,
+        registration_id: &str,
+        links: &[WorkItemTimeLink],
+    ) -> Result<(), TimeTrackingError> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM work_item_time_links
+            WHERE registration_id = $1
+            "#,
+            registration_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        insert_links(&mut tx, links).await?;
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+
+    async fn get_work_item_time(
+        &self,
+        work_item_id: &str,
+    ) -> Result<WorkItemTime, TimeTrackingError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT l.registration_id, u.full_name, l.entry_date, l.hours,
+                l.project_name, l.activity_name, l.note
+            FROM work_item_time_links l
+            JOIN users u ON u.id = l.user_id
+            WHERE l.work_item_id = $1
+            ORDER BY l.entry_date DESC, u.full_name
+            "#,
+            work_item_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let entries = rows
+            .into_iter()
+            .map(|row| WorkItemTimeEntry {
+                registration_id: row.registration_id,
+                user_name: row.full_name,
+                date: row.entry_date,
+                hours: row.hours,
+                project_name: row.project_name,
+                activity_name: row.activity_name,
+                note: row.note,
+            })
+            .collect();
+
+        Ok(WorkItemTime::new(work_item_id, entries))
+    }
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use axum::{
     http::StatusCode,
@@ -6,41 +10,67 @@ use axum::{
 };
 use az_devops::RepoClient;
 use futures_util::{stream::FuturesUnordered, StreamExt};
+use serde_json::json;
 use sqlx::PgPool;
+use time::OffsetDateTime;
 use tokio::sync::{
     mpsc::{self, Sender},
     Mutex, RwLock,
 };
 use url::Url;
-use web_push::{IsahcWebPushClient, WebPushClient, WebPushMessage};
+use web_push::IsahcWebPushClient;
 
 use crate::{
-    adapters::inbound::http::{TimeTrackingServiceFactory, WorkItemServiceFactory},
-    config::KleerSettings,
+    adapters::inbound::http::{TimeTrackingServiceFactory, TimerResponse, WorkItemServiceFactory},
+    config::{
+        AnomalySettings, DigestSettings, EmailSettings, KleerSettings, LiveSettings,
+        PollingSettings, PushSettings, ReminderSettings,
+    },
     domain::{
-        ports::inbound::AvatarService, CachedIdentities, NotificationHandler, PullRequest,
-        RepoConfig, RepoDiffer, RepoDifferMessage, RepoKey,
+        is_unlogged_day,
+        models::{flex_balance_range, week_end_days, FlexSnapshot, TimeEntryStatus, UserId},
+        ports::{
+            inbound::{AvatarService, NoteImprovementService},
+            outbound::WorkItemTimeLinkRepository,
+        },
+        previous_working_day, unreported_hours, week_start, AnomalyCheck, CachedIdentities,
+        CalendarFeedClient, ChatWebhookEvent, Digest, DigestFrequency, DigestSchedule,
+        EmailNotifier, EventKind, EventWebhookDispatcher, LiveEvents, NotificationHandler,
+        PendingReview, PollingSchedule, PullRequest, PushDispatcher, PushNotification, RepoConfig,
+        RepoDiffer, RepoDifferMessage, RepoDifferStatus, RepoHealth, RepoKey, StatusBanner,
+        TimerReminders, User, VapidKeys, WebhookEvent,
     },
     factory::AzureDevOpsWorkItemServiceFactory,
     repositories::{
-        NotificationRepositoryImpl, PushSubscriptionRepositoryImpl, RepoRepositoryImpl,
-        UserRepositoryImpl,
+        CalendarFeedRepositoryImpl, EntryTemplateRepositoryImpl, EventWebhookRepository,
+        EventWebhookRepositoryImpl, FavoriteRepositoryImpl, FlexSnapshotRepository,
+        FlexSnapshotRepositoryImpl, NotificationRepositoryImpl, NotificationWebhookRepositoryImpl,
+        ProjectBudgetRepositoryImpl, PushSubscriptionRepositoryImpl, RepoRepositoryImpl,
+        StatusBannerRepository, StatusBannerRepositoryImpl, TimerAutoStopRepository,
+        TimerAutoStopRepositoryImpl, TimerDeviceRepository, TimerDeviceRepositoryImpl,
+        UserRepository, UserRepositoryImpl, WorkItemTimeMappingRepositoryImpl,
     },
 };
 
+const BOARD_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const FLEX_SNAPSHOT_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+const TIMER_AUTO_STOP_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const WEBHOOK_DELIVERY_CLEANUP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Days event webhook delivery attempts stay in the delivery log.
+const WEBHOOK_DELIVERY_RETENTION_DAYS: i64 = 30;
+/// Weeks of flex history filled in for users without snapshots yet.
+const FLEX_BACKFILL_WEEKS: u8 = 8;
+
 #[derive(Debug, thiserror::Error)]
 pub enum AppStateError {
     #[error("Repository client not found for: {0}")]
     RepoClientNotFound(RepoKey),
-    #[error("Failed to send notification: {0}")]
-    WebPushError(#[from] web_push::WebPushError),
 }
 
 impl IntoResponse for AppStateError {
     fn into_response(self) -> Response {
         let status = match self {
             Self::RepoClientNotFound(_) => StatusCode::NOT_FOUND,
-            Self::WebPushError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
 
         (status, self.to_string()).into_response()
@@ -58,14 +88,37 @@ pub struct AppState {
     pub repository_repo: Arc<RepoRepositoryImpl>,
     pub push_subscriptions_repo: Arc<PushSubscriptionRepositoryImpl>,
     pub notification_repo: Arc<NotificationRepositoryImpl>,
+    pub notification_webhooks_repo: Arc<NotificationWebhookRepositoryImpl>,
+    pub event_webhooks_repo: Arc<EventWebhookRepositoryImpl>,
+    pub timer_devices_repo: Arc<TimerDeviceRepositoryImpl>,
+    pub timer_auto_stops_repo: Arc<TimerAutoStopRepositoryImpl>,
+    pub project_budgets_repo: Arc<ProjectBudgetRepositoryImpl>,
+    pub favorites_repo: Arc<FavoriteRepositoryImpl>,
+    pub work_item_time_mappings_repo: Arc<WorkItemTimeMappingRepositoryImpl>,
+    pub flex_snapshots_repo: Arc<FlexSnapshotRepositoryImpl>,
+    pub entry_templates_repo: Arc<EntryTemplateRepositoryImpl>,
+    pub calendar_feeds_repo: Arc<CalendarFeedRepositoryImpl>,
+    pub status_banner_repo: Arc<StatusBannerRepositoryImpl>,
     pub time_tracking_factory: Arc<dyn TimeTrackingServiceFactory>,
     pub avatar_service: Arc<dyn AvatarService>,
+    /// `None` when no language model is configured.
+    pub note_improvement_service: Option<Arc<dyn NoteImprovementService>>,
     pub work_item_factory: Arc<dyn WorkItemServiceFactory>,
+    pub work_item_time_links: Arc<dyn WorkItemTimeLinkRepository>,
+    pub event_webhooks: Arc<EventWebhookDispatcher>,
+    pub calendar_feeds: CalendarFeedClient,
+    pub live_events: LiveEvents,
     repo_clients: Arc<RwLock<HashMap<RepoKey, RepoClient>>>,
     differs: Arc<RwLock<HashMap<RepoKey, Arc<RepoDiffer>>>>,
     differ_txs: Arc<Mutex<HashMap<RepoKey, Sender<RepoDifferMessage>>>>,
-    web_push_client: IsahcWebPushClient,
+    pub push_dispatcher: Arc<PushDispatcher>,
     notification_handler: Arc<NotificationHandler>,
+    polling_schedule: Option<Arc<PollingSchedule>>,
+    pub anomaly_check: Arc<AnomalyCheck>,
+    pub timer_reminders: Arc<TimerReminders>,
+    digest_schedule: Arc<DigestSchedule>,
+    email_notifier: Option<EmailNotifier>,
+    status_banner: Arc<RwLock<Option<StatusBanner>>>,
 }
 
 impl std::fmt::Debug for AppState {
@@ -80,10 +133,19 @@ impl AppState {
         app_url: String,
         api_url: String,
         kleer_settings: KleerSettings,
+        email_settings: Option<EmailSettings>,
+        push_settings: Option<PushSettings>,
+        polling_settings: PollingSettings,
+        anomaly_settings: AnomalySettings,
+        reminder_settings: ReminderSettings,
+        digest_settings: DigestSettings,
+        live_settings: LiveSettings,
         db_pool: PgPool,
         repo_configs: Vec<RepoConfig>,
         time_tracking_factory: Arc<dyn TimeTrackingServiceFactory>,
         avatar_service: Arc<dyn AvatarService>,
+        note_improvement_service: Option<Arc<dyn NoteImprovementService>>,
+        work_item_time_links: Arc<dyn WorkItemTimeLinkRepository>,
     ) -> Self {
         let client_futures = repo_configs
             .into_iter()
@@ -109,12 +171,60 @@ impl AppState {
             .flatten()
             .collect();
 
-        let web_push_client = IsahcWebPushClient::new().expect("Could not create web push client");
+        let vapid_keys = match push_settings {
+            Some(settings) => VapidKeys::from_settings(&settings)
+                .inspect_err(|e| tracing::error!("Push notifications disabled: {e}"))
+                .ok(),
+            None => {
+                tracing::warn!("Push notifications disabled: no VAPID keys configured");
+                None
+            }
+        };
+        let push_dispatcher = Arc::new(PushDispatcher::new(
+            IsahcWebPushClient::new().expect("Could not create web push client"),
+            vapid_keys,
+            PushSubscriptionRepositoryImpl::new(db_pool.clone()),
+        ));
+        let email_notifier = email_settings.and_then(|settings| {
+            EmailNotifier::new(&settings)
+                .inspect_err(|e| tracing::error!("Email notifications disabled: {e}"))
+                .ok()
+        });
+        let live_events = LiveEvents::from_settings(&live_settings).unwrap_or_else(|e| {
+            tracing::error!("Invalid live settings, using defaults: {e}");
+            LiveEvents::default()
+        });
+        let event_webhooks = EventWebhookDispatcher::new(db_pool.clone(), live_events.clone());
         let notification_handler = Arc::new(NotificationHandler::new(
             db_pool.clone(),
-            web_push_client.clone(),
+            push_dispatcher.clone(),
+            email_notifier.clone(),
+            event_webhooks.clone(),
         ));
 
+        let polling_schedule = PollingSchedule::from_settings(&polling_settings)
+            .unwrap_or_else(|e| {
+                tracing::error!("Off-hours polling throttling disabled: {e}");
+                None
+            })
+            .map(Arc::new);
+        let anomaly_check = AnomalyCheck::from_settings(&anomaly_settings).unwrap_or_else(|e| {
+            tracing::error!("Invalid anomaly settings, using defaults: {e}");
+            AnomalyCheck::from_settings(&AnomalySettings::default())
+                .expect("default anomaly settings are valid")
+        });
+        let timer_reminders =
+            TimerReminders::from_settings(&reminder_settings).unwrap_or_else(|e| {
+                tracing::error!("Invalid reminder settings, using defaults: {e}");
+                TimerReminders::from_settings(&ReminderSettings::default())
+                    .expect("default reminder settings are valid")
+            });
+        let digest_schedule = DigestSchedule::from_settings(&digest_settings).unwrap_or_else(|e| {
+            tracing::error!("Invalid digest settings, using defaults: {e}");
+            DigestSchedule::from_settings(&DigestSettings::default())
+                .expect("default digest settings are valid")
+        });
+
         let mut differs = HashMap::new();
         let differ_txs = clients
             .iter()
@@ -123,6 +233,7 @@ impl AppState {
                     key.clone(),
                     client.clone(),
                     notification_handler.clone(),
+                    polling_schedule.clone(),
                 ));
                 differs.insert(key.clone(), differ.clone());
 
@@ -136,6 +247,12 @@ impl AppState {
             })
             .collect::<HashMap<_, _>>();
 
+        let status_banner_repo = Arc::new(StatusBannerRepositoryImpl::new(db_pool.clone()));
+        let status_banner = status_banner_repo.get_banner().await.unwrap_or_else(|e| {
+            tracing::error!("Failed to load status banner: {e}");
+            None
+        });
+
         let repo_clients = Arc::new(RwLock::new(clients));
         let user_repo = Arc::new(UserRepositoryImpl::new(db_pool.clone()));
         let parsed_api_url = Url::parse(&api_url).expect("Invalid API URL");
@@ -144,6 +261,7 @@ impl AppState {
             Arc::new(AzureDevOpsWorkItemServiceFactory::new(
                 repo_clients.clone(),
                 user_repo.clone(),
+                db_pool.clone(),
                 parsed_api_url.clone(),
             ));
 
@@ -156,14 +274,40 @@ impl AppState {
             repository_repo: Arc::new(RepoRepositoryImpl::new(db_pool.clone())),
             push_subscriptions_repo: Arc::new(PushSubscriptionRepositoryImpl::new(db_pool.clone())),
             notification_repo: Arc::new(NotificationRepositoryImpl::new(db_pool.clone())),
+            notification_webhooks_repo: Arc::new(NotificationWebhookRepositoryImpl::new(
+                db_pool.clone(),
+            )),
+            event_webhooks_repo: Arc::new(EventWebhookRepositoryImpl::new(db_pool.clone())),
+            timer_devices_repo: Arc::new(TimerDeviceRepositoryImpl::new(db_pool.clone())),
+            timer_auto_stops_repo: Arc::new(TimerAutoStopRepositoryImpl::new(db_pool.clone())),
+            project_budgets_repo: Arc::new(ProjectBudgetRepositoryImpl::new(db_pool.clone())),
+            favorites_repo: Arc::new(FavoriteRepositoryImpl::new(db_pool.clone())),
+            work_item_time_mappings_repo: Arc::new(WorkItemTimeMappingRepositoryImpl::new(
+                db_pool.clone(),
+            )),
+            flex_snapshots_repo: Arc::new(FlexSnapshotRepositoryImpl::new(db_pool.clone())),
+            entry_templates_repo: Arc::new(EntryTemplateRepositoryImpl::new(db_pool.clone())),
+            calendar_feeds_repo: Arc::new(CalendarFeedRepositoryImpl::new(db_pool.clone())),
+            status_banner_repo,
             time_tracking_factory,
             avatar_service,
+            note_improvement_service,
             work_item_factory,
+            work_item_time_links,
+            event_webhooks: Arc::new(event_webhooks),
+            calendar_feeds: CalendarFeedClient::new(),
+            live_events,
             repo_clients,
             differ_txs: Arc::new(Mutex::new(differ_txs)),
             differs: Arc::new(RwLock::new(differs)),
-            web_push_client,
+            push_dispatcher,
             notification_handler,
+            polling_schedule,
+            anomaly_check: Arc::new(anomaly_check),
+            timer_reminders: Arc::new(timer_reminders),
+            digest_schedule: Arc::new(digest_schedule),
+            email_notifier,
+            status_banner: Arc::new(RwLock::new(status_banner)),
         }
     }
 
@@ -191,6 +335,34 @@ impl AppState {
         differs.values().cloned().collect()
     }
 
+    /// The banner set by an admin, whether or not it is shown right now.
+    pub async fn status_banner(&self) -> Option<StatusBanner> {
+        self.status_banner.read().await.clone()
+    }
+
+    /// The banner if it is a maintenance banner shown right now. Requests and
+    /// jobs that call the time tracking provider are paused while it is.
+    pub async fn maintenance_banner(&self) -> Option<StatusBanner> {
+        self.status_banner()
+            .await
+            .filter(|banner| banner.is_maintenance(OffsetDateTime::now_utc()))
+    }
+
+    /// Replace the cached banner after it has been stored.
+    pub async fn set_status_banner(&self, banner: Option<StatusBanner>) {
+        *self.status_banner.write().await = banner;
+    }
+
+    /// Health of every repository's differ, for the admin dashboard.
+    pub async fn get_repo_health(&self) -> Vec<RepoHealth> {
+        let mut health = Vec::new();
+        for differ in self.get_repo_differs().await {
+            let webhooks = self.notification_handler.webhook_health(&differ.key);
+            health.push(differ.health(webhooks).await);
+        }
+        health
+    }
+
     pub async fn get_differ_sender(
         &self,
         key: impl Into<RepoKey>,
@@ -216,6 +388,709 @@ impl AppState {
         }
     }
 
+    /// Refresh all running differs shortly before every working day starts,
+    /// so pull request caches are fresh after the off-hours throttling.
+    #[allow(dead_code)]
+    pub fn spawn_cache_warm_up(&self) {
+        let Some(schedule) = self.polling_schedule.clone() else {
+            return;
+        };
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let now = OffsetDateTime::now_utc();
+                let next_warm_up = schedule.next_warm_up(now);
+                tracing::debug!("Next cache warm-up at {next_warm_up}");
+                tokio::time::sleep((next_warm_up - now).try_into().unwrap_or_default()).await;
+
+                app_state.warm_up_differs().await;
+            }
+        });
+    }
+
+    async fn warm_up_differs(&self) {
+        for differ in self.get_repo_differs().await {
+            if *differ.status.read().await != RepoDifferStatus::Running {
+                continue;
+            }
+            tracing::info!("Warming pull request cache for {}", differ.key);
+            if let Ok(sender) = self.get_differ_sender(differ.key.clone()).await {
+                let _ = sender.send(RepoDifferMessage::ForceUpdate).await;
+            }
+        }
+    }
+
+    /// Check every linked user's open time entries for anomalies once per
+    /// weekday and send each user a digest of what was found.
+    #[allow(dead_code)]
+    pub fn spawn_anomaly_checks(&self) {
+        if !self.anomaly_check.notifications_enabled {
+            return;
+        }
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let now = OffsetDateTime::now_utc();
+                let next_check = app_state.anomaly_check.next_check(now);
+                tracing::debug!("Next time entry anomaly check at {next_check}");
+                tokio::time::sleep((next_check - now).try_into().unwrap_or_default()).await;
+
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping anomaly check during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for anomaly check: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state.check_user_anomalies(&user).await;
+                }
+            }
+        });
+    }
+
+    /// Remind users about timers that have been running for too long, every
+    /// weekday morning about a previous working day with nothing registered,
+    /// and on Friday afternoons about a week that isn't fully reported.
+    #[allow(dead_code)]
+    pub fn spawn_timer_reminders(&self) {
+        if !self.timer_reminders.enabled {
+            return;
+        }
+
+        let app_state = self.clone();
+        tokio::spawn(async move {
+            // Start of the timer each user was last reminded about, so a
+            // forgotten timer is only reported once.
+            let mut reminded: HashMap<UserId, OffsetDateTime> = HashMap::new();
+            let mut interval =
+                tokio::time::interval(app_state.timer_reminders.timer_check_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for timer reminders: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state
+                        .check_long_running_timer(&user, &mut reminded)
+                        .await;
+                }
+            }
+        });
+
+        let app_state = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let now = OffsetDateTime::now_utc();
+                let next_check = app_state.timer_reminders.next_check(now);
+                tracing::debug!("Next unlogged day check at {next_check}");
+                tokio::time::sleep((next_check - now).try_into().unwrap_or_default()).await;
+
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping unlogged day check during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for unlogged day check: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state.check_unlogged_day(&user).await;
+                }
+            }
+        });
+
+        let app_state = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let now = OffsetDateTime::now_utc();
+                let next_check = app_state.timer_reminders.next_week_check(now);
+                tracing::debug!("Next unreported week check at {next_check}");
+                tokio::time::sleep((next_check - now).try_into().unwrap_or_default()).await;
+
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping unreported week check during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for unreported week check: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state.check_unreported_week(&user).await;
+                }
+            }
+        });
+    }
+
+    /// Email every user subscribed to a digest a summary of their day or
+    /// week, at the configured hour.
+    #[allow(dead_code)]
+    pub fn spawn_digests(&self) {
+        if !self.digest_schedule.enabled {
+            return;
+        }
+        let Some(email_notifier) = self.email_notifier.clone() else {
+            tracing::warn!("Digests disabled: email is not configured");
+            return;
+        };
+
+        let app_state = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let now = OffsetDateTime::now_utc();
+                let next_send = app_state.digest_schedule.next_send(now);
+                tracing::debug!("Next digest at {next_send}");
+                tokio::time::sleep((next_send - now).try_into().unwrap_or_default()).await;
+
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping digests during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for digests: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    let Some(digest) = app_state.build_digest(&user).await else {
+                        continue;
+                    };
+                    let email = digest.to_email(app_state.app_url.as_str());
+                    if let Err(e) = email_notifier.send(&user.email, email).await {
+                        tracing::warn!("Failed to send digest to user {}: {e}", user.id);
+                    }
+                }
+            }
+        });
+    }
+
+    /// The user's digest if one is due today.
+    async fn build_digest(&self, user: &User) -> Option<Digest> {
+        let frequency = match self.user_repo.get_digest_frequency(user.id).await {
+            Ok(DigestFrequency::Off) => return None,
+            Ok(frequency) => frequency,
+            Err(e) => {
+                tracing::warn!("Failed to get digest frequency for user {}: {e}", user.id);
+                return None;
+            }
+        };
+        let time_zone = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default();
+        let (start, end) = frequency.period(time_zone.today())?;
+
+        let service = self
+            .time_tracking_factory
+            .create_service(user.id)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!(
+                    "No time tracking for digest of user {}: {}",
+                    user.id,
+                    e.message
+                )
+            })
+            .ok()?;
+        let stats = service
+            .get_time_info((start, end))
+            .await
+            .inspect_err(|e| tracing::warn!("Failed to get time info for user {}: {e}", user.id))
+            .ok()?;
+        let flex_balance = service
+            .get_time_info(flex_balance_range(end))
+            .await
+            .ok()
+            .map(|stats| stats.period_flex_hours);
+
+        let mut unlogged_days = vec![];
+        let mut day = week_start(end);
+        while day <= end {
+            if day.weekday().number_days_from_monday() < 5 {
+                if let Ok(stats) = service.get_time_info((day, day)).await {
+                    if is_unlogged_day(&stats) {
+                        unlogged_days.push(day);
+                    }
+                }
+            }
+            day = day.next_day()?;
+        }
+
+        let mut pending_reviews = vec![];
+        let followed = self
+            .user_repo
+            .followed_repositories(user.id)
+            .await
+            .unwrap_or_default();
+        for key in followed {
+            let Ok(Some(prs)) = self.get_cached_pull_requests(key).await else {
+                continue;
+            };
+            pending_reviews.extend(
+                prs.iter()
+                    .filter(|pr| pr.waiting_for_user_review(&user.email).0)
+                    .map(|pr| PendingReview {
+                        id: pr.pull_request_base.id,
+                        title: pr.pull_request_base.title.clone(),
+                        repo_name: pr.repo_name.clone(),
+                        author: pr.pull_request_base.created_by.display_name.clone(),
+                    }),
+            );
+        }
+
+        Some(Digest {
+            frequency,
+            period: (start, end),
+            worked_hours: stats.worked_hours,
+            scheduled_hours: stats.scheduled_hours,
+            flex_balance,
+            unlogged_days,
+            pending_reviews,
+        })
+    }
+
+    /// Snapshot the current sprint board of every project with a repository
+    /// client once an hour, so board diffs can show what changed over time.
+    #[allow(dead_code)]
+    pub fn spawn_board_snapshots(&self) {
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(BOARD_SNAPSHOT_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                app_state.snapshot_boards().await;
+            }
+        });
+    }
+
+    /// Record every user's flex balance a few times a day, keeping the last
+    /// value of each day. Missing week ends of the last few weeks are filled
+    /// in, so a trend is available right away.
+    #[allow(dead_code)]
+    pub fn spawn_flex_snapshots(&self) {
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(FLEX_SNAPSHOT_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping flex snapshots during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for flex snapshots: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state.snapshot_flex(&user).await;
+                }
+            }
+        });
+    }
+
+    /// Delete event webhook delivery attempts older than
+    /// [`WEBHOOK_DELIVERY_RETENTION_DAYS`] once a day, so the delivery log
+    /// doesn't grow forever.
+    #[allow(dead_code)]
+    pub fn spawn_webhook_delivery_cleanup(&self) {
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(WEBHOOK_DELIVERY_CLEANUP_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let before = OffsetDateTime::now_utc()
+                    - time::Duration::days(WEBHOOK_DELIVERY_RETENTION_DAYS);
+                match app_state
+                    .event_webhooks_repo
+                    .delete_deliveries_before(before)
+                    .await
+                {
+                    Ok(deleted) => {
+                        tracing::info!("Deleted {deleted} old event webhook deliveries")
+                    }
+                    Err(e) => tracing::error!("Failed to delete old webhook deliveries: {e}"),
+                }
+            }
+        });
+    }
+
+    /// Stop timers still running past the stop time of their user's
+    /// auto-stop rule, recording what was done for clients to show.
+    #[allow(dead_code)]
+    pub fn spawn_timer_auto_stops(&self) {
+        let app_state = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(TIMER_AUTO_STOP_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                if app_state.maintenance_banner().await.is_some() {
+                    tracing::debug!("Skipping timer auto-stops during maintenance");
+                    continue;
+                }
+                let users = match app_state.user_repo.get_users().await {
+                    Ok(users) => users,
+                    Err(e) => {
+                        tracing::error!("Failed to get users for timer auto-stops: {e}");
+                        continue;
+                    }
+                };
+                for user in users {
+                    app_state.auto_stop_timer(&user).await;
+                }
+            }
+        });
+    }
+
+    async fn auto_stop_timer(&self, user: &User) {
+        let rule = match self.user_repo.get_timer_auto_stop(user.id).await {
+            Ok(rule) if rule.enabled => rule,
+            Ok(_) => return,
+            Err(e) => {
+                tracing::warn!("Failed to get timer auto-stop of user {}: {e}", user.id);
+                return;
+            }
+        };
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let timer = match service.get_active_timer(&user.id).await {
+            Ok(Some(timer)) => timer,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!("Failed to get timer of user {}: {e}", user.id);
+                return;
+            }
+        };
+        let stopped = match service.auto_stop_timer(&user.id, &rule).await {
+            Ok(Some(stopped)) => stopped,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!("Failed to auto-stop timer of user {}: {e}", user.id);
+                return;
+            }
+        };
+
+        tracing::info!(
+            "Auto-stopped timer of user {} ({}) at {}",
+            user.id,
+            stopped.action.as_str(),
+            stopped.stopped_at
+        );
+        if let Err(e) = self
+            .timer_devices_repo
+            .release_timer(user.id.as_i32())
+            .await
+        {
+            tracing::warn!("Failed to release timer device of user {}: {e}", user.id);
+        }
+        if let Err(e) = self
+            .timer_auto_stops_repo
+            .record_auto_stop(user.id.as_i32(), &stopped)
+            .await
+        {
+            tracing::warn!("Failed to record timer auto-stop of user {}: {e}", user.id);
+        }
+        self.event_webhooks
+            .dispatch(
+                user.id,
+                WebhookEvent::new(EventKind::TimerStopped, json!(TimerResponse::from(timer))),
+            )
+            .await;
+    }
+
+    async fn snapshot_flex(&self, user: &User) {
+        // Users without a linked time tracking account have no flex.
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let today = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default()
+            .today();
+
+        let backfill = week_end_days(today, FLEX_BACKFILL_WEEKS);
+        let recorded = match self
+            .flex_snapshots_repo
+            .get_snapshots(user.id.as_i32(), backfill[0], today)
+            .await
+        {
+            Ok(snapshots) => snapshots,
+            Err(e) => {
+                tracing::warn!("Failed to get flex snapshots of user {}: {e}", user.id);
+                return;
+            }
+        };
+        let missing = backfill
+            .into_iter()
+            .filter(|day| !recorded.iter().any(|snapshot| snapshot.date == *day));
+
+        for date in missing.chain([today]) {
+            let flex_hours = match service.get_time_info(flex_balance_range(date)).await {
+                Ok(stats) => stats.period_flex_hours,
+                Err(e) => {
+                    tracing::warn!("Failed to get flex of user {} on {date}: {e}", user.id);
+                    continue;
+                }
+            };
+            if let Err(e) = self
+                .flex_snapshots_repo
+                .upsert_snapshot(user.id.as_i32(), &FlexSnapshot { date, flex_hours })
+                .await
+            {
+                tracing::warn!("Failed to save flex snapshot of user {}: {e}", user.id);
+            }
+        }
+    }
+
+    async fn snapshot_boards(&self) {
+        let projects: HashSet<(String, String)> = self
+            .repo_clients
+            .read()
+            .await
+            .keys()
+            .map(|key| {
+                (
+                    key.organization.to_ascii_lowercase(),
+                    key.project.to_ascii_lowercase(),
+                )
+            })
+            .collect();
+
+        for (organization, project) in projects {
+            let result = match self
+                .work_item_factory
+                .create_service(&organization, &project)
+                .await
+            {
+                Ok(service) => service
+                    .snapshot_current_board()
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.message),
+            };
+            if let Err(e) = result {
+                tracing::warn!("Failed to snapshot board of {organization}/{project}: {e}");
+            }
+        }
+    }
+
+    async fn check_user_anomalies(&self, user: &User) {
+        // Users without a linked time tracking account have nothing to check.
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let date_range = self.anomaly_check.lookback_range(OffsetDateTime::now_utc());
+
+        // Attested entries can't be changed anymore, so only nag about open ones.
+        let mut entries = match service.get_time_entries(&user.id, date_range, false).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!("Failed to get time entries for user {}: {e}", user.id);
+                return;
+            }
+        };
+        entries.retain(|entry| entry.status == TimeEntryStatus::Open);
+
+        let time_zone = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default();
+        let anomalies = self.anomaly_check.rules.detect(&entries, &time_zone);
+        if anomalies.is_empty() {
+            return;
+        }
+
+        let body = anomalies
+            .iter()
+            .take(5)
+            .map(|anomaly| format!("{}: {}", anomaly.date, anomaly.message))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let title = match anomalies.len() {
+            1 => "1 time entry anomaly".to_string(),
+            n => format!("{n} time entry anomalies"),
+        };
+        let url = self.app_url.join("time-tracking").ok();
+        let notification =
+            PushNotification::new(&title, &body, url.as_ref().map(Url::as_str), None);
+
+        if let Err(e) = self
+            .notification_handler
+            .notify_user(user.id, ChatWebhookEvent::TimeEntryAnomalies, &notification)
+            .await
+        {
+            tracing::warn!("Failed to send anomaly digest to user {}: {e}", user.id);
+        }
+    }
+
+    async fn check_long_running_timer(
+        &self,
+        user: &User,
+        reminded: &mut HashMap<UserId, OffsetDateTime>,
+    ) {
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let timer = match service.get_active_timer(&user.id).await {
+            Ok(Some(timer)) => timer,
+            Ok(None) => {
+                reminded.remove(&user.id);
+                return;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to get active timer for user {}: {e}", user.id);
+                return;
+            }
+        };
+
+        let now = OffsetDateTime::now_utc();
+        if !self.timer_reminders.is_long_running(timer.started_at, now)
+            || reminded.get(&user.id) == Some(&timer.started_at)
+        {
+            return;
+        }
+
+        let time_zone = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default();
+        let started = time_zone.to_local(timer.started_at);
+        let hours = (now - timer.started_at).whole_hours();
+        let title = format!("Timer running for {hours} hours");
+        let body = format!(
+            "Your timer has been running since {} {:02}:{:02}. Did you forget to stop it?",
+            started.date(),
+            started.hour(),
+            started.minute()
+        );
+        if self
+            .send_reminder(user, ChatWebhookEvent::LongRunningTimer, &title, &body)
+            .await
+        {
+            reminded.insert(user.id, timer.started_at);
+        }
+    }
+
+    async fn check_unlogged_day(&self, user: &User) {
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let time_zone = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default();
+        let day = previous_working_day(time_zone.today());
+
+        let stats = match service.get_time_info((day, day)).await {
+            Ok(stats) => stats,
+            Err(e) => {
+                tracing::warn!("Failed to get time info for user {}: {e}", user.id);
+                return;
+            }
+        };
+        if !is_unlogged_day(&stats) {
+            return;
+        }
+
+        let title = format!("Nothing registered on {}", day.weekday());
+        let body = format!("You have no time registered on {day}.");
+        self.send_reminder(user, ChatWebhookEvent::UnloggedDay, &title, &body)
+            .await;
+    }
+
+    async fn check_unreported_week(&self, user: &User) {
+        let Ok(service) = self.time_tracking_factory.create_service(user.id).await else {
+            return;
+        };
+        let time_zone = self
+            .user_repo
+            .get_time_zone(user.id)
+            .await
+            .unwrap_or_default();
+        let today = time_zone.today();
+        let monday = week_start(today);
+
+        let stats = match service.get_time_info((monday, today)).await {
+            Ok(stats) => stats,
+            Err(e) => {
+                tracing::warn!("Failed to get time info for user {}: {e}", user.id);
+                return;
+            }
+        };
+        let Some(missing) = unreported_hours(&stats) else {
+            return;
+        };
+
+        let title = format!("Week {} is not fully reported", today.iso_week());
+        let body = format!(
+            "{missing:.1} of this week's {:.1} scheduled hours are not registered yet.",
+            stats.scheduled_hours
+        );
+        self.send_reminder(user, ChatWebhookEvent::WeekUnreported, &title, &body)
+            .await;
+    }
+
+    /// Send a reminder linking to time tracking. Returns whether it was sent.
+    async fn send_reminder(
+        &self,
+        user: &User,
+        event: ChatWebhookEvent,
+        title: &str,
+        body: &str,
+    ) -> bool {
+        let url = self.app_url.join("time-tracking").ok();
+        let notification = PushNotification::new(title, body, url.as_ref().map(Url::as_str), None);
+
+        match self
+            .notification_handler
+            .notify_user(user.id, event, &notification)
+            .await
+        {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("Failed to send reminder to user {}: {e}", user.id);
+                false
+            }
+        }
+    }
+
     pub async fn get_cached_pull_requests(
         &self,
         key: impl Into<RepoKey>,
@@ -260,6 +1135,7 @@ impl AppState {
             key.clone(),
             client.clone(),
             self.notification_handler.clone(),
+            self.polling_schedule.clone(),
         ));
         self.differs
             .write()
@@ -285,13 +1161,6 @@ impl AppState {
         differ_txs.remove(&key);
     }
 
-    pub async fn push_notification(&self, message: WebPushMessage) -> Result<(), AppStateError> {
-        self.web_push_client.send(message).await.map_err(|e| {
-            tracing::error!("Failed to send notification: {:?}", e);
-            AppStateError::WebPushError(e)
-        })
-    }
-
     #[allow(dead_code)]
     pub fn host_domain(&self) -> String {
         self.api_url.host_str().unwrap_or("localhost").to_string()
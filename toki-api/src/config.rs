@@ -11,6 +11,22 @@ pub struct Settings {
     pub database: DatabaseSettings,
     pub auth: AuthSettings,
     pub kleer: KleerSettings,
+    #[serde(default)]
+    pub email: Option<EmailSettings>,
+    #[serde(default)]
+    pub push: Option<PushSettings>,
+    #[serde(default)]
+    pub polling: PollingSettings,
+    #[serde(default)]
+    pub anomalies: AnomalySettings,
+    #[serde(default)]
+    pub reminders: ReminderSettings,
+    #[serde(default)]
+    pub digest: DigestSettings,
+    #[serde(default)]
+    pub live: LiveSettings,
+    #[serde(default)]
+    pub llm: Option<LlmSettings>,
 }
 
 #[serde_as]
@@ -53,6 +69,51 @@ pub struct KleerSettings {
     pub company_id: Option<String>,
     #[serde(default = "default_kleer_base_url")]
     pub base_url: String,
+    #[serde(default)]
+    pub retry: KleerRetrySettings,
+}
+
+/// How failed Kleer requests are retried, see [`kleer::RetryPolicy`].
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct KleerRetrySettings {
+    /// Total attempts per request; 1 disables retries.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub max_attempts: u32,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub base_delay_ms: u64,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub max_delay_ms: u64,
+    /// Total time one request may spend waiting between attempts.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub budget_ms: u64,
+    pub jitter: bool,
+}
+
+impl Default for KleerRetrySettings {
+    fn default() -> Self {
+        let policy = kleer::RetryPolicy::default();
+        Self {
+            max_attempts: policy.max_attempts,
+            base_delay_ms: policy.base_delay.as_millis() as u64,
+            max_delay_ms: policy.max_delay.as_millis() as u64,
+            budget_ms: policy.budget.as_millis() as u64,
+            jitter: policy.jitter,
+        }
+    }
+}
+
+impl KleerRetrySettings {
+    pub fn policy(&self) -> kleer::RetryPolicy {
+        kleer::RetryPolicy {
+            max_attempts: self.max_attempts.max(1),
+            base_delay: std::time::Duration::from_millis(self.base_delay_ms),
+            max_delay: std::time::Duration::from_millis(self.max_delay_ms),
+            budget: std::time::Duration::from_millis(self.budget_ms),
+            jitter: self.jitter,
+        }
+    }
 }
 
 impl KleerSettings {
@@ -79,6 +140,241 @@ impl KleerSettings {
     }
 }
 
+/// SMTP settings for email notifications. Email delivery is disabled when absent.
+#[serde_as]
+#[derive(Deserialize, Clone)]
+pub struct EmailSettings {
+    pub smtp_host: String,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Sender mailbox, e.g. `Toki <toki@example.com>`.
+    pub from_address: String,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// VAPID key pair used to sign web push messages. Push delivery is disabled
+/// when absent.
+#[derive(Deserialize, Clone)]
+pub struct PushSettings {
+    /// URL-safe base64 private key.
+    pub vapid_private_key: String,
+    /// URL-safe base64 public key, handed to browsers when they subscribe.
+    pub vapid_public_key: String,
+    /// Contact for push services, e.g. `mailto:toki@example.com`.
+    pub subject: Option<String>,
+}
+
+/// Working-hours calendar for pull request polling.
+///
+/// Outside working hours differs poll at most every `off_hours_interval_secs`,
+/// and running differs are refreshed `warm_up_lead_minutes` before the
+/// working day starts so the first page load is fresh.
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PollingSettings {
+    pub enabled: bool,
+    /// Fixed offset of the team's local time from UTC, in minutes.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub utc_offset_minutes: i16,
+    /// Days polled at full rate, e.g. `["mon", "tue", "wed", "thu", "fri"]`.
+    pub working_days: Vec<String>,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub work_start_hour: u8,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub work_end_hour: u8,
+    /// Dates (`YYYY-MM-DD`) treated as off-hours all day.
+    pub holidays: Vec<String>,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub off_hours_interval_secs: u64,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub warm_up_lead_minutes: u64,
+}
+
+impl Default for PollingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            utc_offset_minutes: 60,
+            working_days: ["mon", "tue", "wed", "thu", "fri"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            work_start_hour: 7,
+            work_end_hour: 18,
+            holidays: vec![],
+            off_hours_interval_secs: 30 * 60,
+            warm_up_lead_minutes: 15,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct AnomalySettings {
+    /// Send a daily digest of anomalies in unattested entries.
+    pub notifications_enabled: bool,
+    /// Offset of `check_hour` from UTC, in minutes. Working hours are
+    /// checked in each user's own time zone.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub utc_offset_minutes: i16,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub max_day_hours: f64,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub earliest_hour: u8,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub latest_hour: u8,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub repeated_note_min_days: usize,
+    /// Activity ids or names that must always have a note.
+    pub note_required_activities: Vec<String>,
+    /// Local hour (Mon–Fri) at which the daily check runs.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub check_hour: u8,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub lookback_days: u16,
+}
+
+impl Default for AnomalySettings {
+    fn default() -> Self {
+        Self {
+            notifications_enabled: true,
+            utc_offset_minutes: 60,
+            max_day_hours: 12.0,
+            earliest_hour: 6,
+            latest_hour: 22,
+            repeated_note_min_days: 5,
+            note_required_activities: vec![],
+            check_hour: 15,
+            lookback_days: 14,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ReminderSettings {
+    /// Remind users about forgotten timers and days without registrations.
+    pub enabled: bool,
+    /// A running timer older than this is probably forgotten.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub long_timer_hours: u16,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub timer_check_interval_minutes: u16,
+    /// Offset of `check_hour` from UTC, in minutes.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub utc_offset_minutes: i16,
+    /// Local hour (Mon–Fri) at which the previous working day is checked
+    /// for registrations.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub check_hour: u8,
+    /// Local hour on Fridays by which the whole week should be reported.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub week_check_hour: u8,
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            long_timer_hours: 10,
+            timer_check_interval_minutes: 30,
+            utc_offset_minutes: 60,
+            check_hour: 9,
+            week_check_hour: 16,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct DigestSettings {
+    /// Send the daily/weekly summary emails users subscribe to. Requires
+    /// email settings.
+    pub enabled: bool,
+    /// Offset of `send_hour` from UTC, in minutes.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub utc_offset_minutes: i16,
+    /// Local hour at which digests are sent; weekly ones on Fridays.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub send_hour: u8,
+}
+
+impl Default for DigestSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            utc_offset_minutes: 60,
+            send_hour: 17,
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct LiveSettings {
+    /// Live connections a user can have open at once, over all their devices.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub max_connections_per_user: u16,
+    /// Close live connections the client hasn't sent anything on, not even
+    /// a pong, for this long. Must be longer than the 30 second ping interval.
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub idle_timeout_secs: u16,
+}
+
+impl Default for LiveSettings {
+    fn default() -> Self {
+        Self {
+            max_connections_per_user: 10,
+            idle_timeout_secs: 90,
+        }
+    }
+}
+
+/// Language model used for note suggestions. The feature is disabled when absent.
+#[serde_as]
+#[derive(Deserialize, Clone)]
+pub struct LlmSettings {
+    pub provider: LlmProvider,
+    pub api_key: String,
+    pub model: String,
+    /// Override the provider's API URL, e.g. for an OpenAI-compatible proxy.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    #[serde(default = "default_llm_max_tokens")]
+    pub max_tokens: u32,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    #[serde(default = "default_llm_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LlmProvider {
+    #[serde(alias = "open_ai")]
+    OpenAi,
+    Anthropic,
+}
+
+fn default_llm_max_tokens() -> u32 {
+    200
+}
+
+fn default_llm_timeout_secs() -> u64 {
+    20
+}
+
 fn default_kleer_base_url() -> String {
     kleer::DEFAULT_BASE_URL.to_string()
 }
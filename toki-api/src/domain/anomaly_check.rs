@@ -0,0 +1,122 @@
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::config::AnomalySettings;
+
+use super::models::AnomalyRules;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnomalyCheckError {
+    #[error("Invalid UTC offset: {0} minutes")]
+    InvalidOffset(i16),
+    #[error("Invalid working hours {0}-{1}")]
+    InvalidHours(u8, u8),
+    #[error("Invalid check hour {0}")]
+    InvalidCheckHour(u8),
+}
+
+/// Anomaly rules plus the schedule of the daily background check.
+#[derive(Debug, Clone)]
+pub struct AnomalyCheck {
+    pub rules: AnomalyRules,
+    pub notifications_enabled: bool,
+    offset: UtcOffset,
+    check_time: Time,
+    lookback_days: u16,
+}
+
+impl AnomalyCheck {
+    pub fn from_settings(settings: &AnomalySettings) -> Result<Self, AnomalyCheckError> {
+        let offset = UtcOffset::from_whole_seconds(i32::from(settings.utc_offset_minutes) * 60)
+            .map_err(|_| AnomalyCheckError::InvalidOffset(settings.utc_offset_minutes))?;
+
+        let invalid_hours =
+            || AnomalyCheckError::InvalidHours(settings.earliest_hour, settings.latest_hour);
+        if settings.earliest_hour >= settings.latest_hour || settings.latest_hour > 24 {
+            return Err(invalid_hours());
+        }
+        let earliest_start =
+            Time::from_hms(settings.earliest_hour, 0, 0).map_err(|_| invalid_hours())?;
+        let latest_end = if settings.latest_hour == 24 {
+            Time::MAX
+        } else {
+            Time::from_hms(settings.latest_hour, 0, 0).map_err(|_| invalid_hours())?
+        };
+        let check_time = Time::from_hms(settings.check_hour, 0, 0)
+            .map_err(|_| AnomalyCheckError::InvalidCheckHour(settings.check_hour))?;
+
+        Ok(Self {
+            rules: AnomalyRules {
+                max_day_hours: settings.max_day_hours,
+                earliest_start,
+                latest_end,
+                repeated_note_min_days: settings.repeated_note_min_days,
+                note_required_activities: settings.note_required_activities.clone(),
+            },
+            notifications_enabled: settings.notifications_enabled,
+            offset,
+            check_time,
+            lookback_days: settings.lookback_days,
+        })
+    }
+
+    /// The next weekday at `check_hour` local time after `now`.
+    pub fn next_check(&self, now: OffsetDateTime) -> OffsetDateTime {
+        let mut date = now.to_offset(self.offset).date();
+        loop {
+            let is_weekday = !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday);
+            let check = date.with_time(self.check_time).assume_offset(self.offset);
+            if is_weekday && check > now {
+                return check;
+            }
+            match date.next_day() {
+                Some(next) => date = next,
+                None => return now + Duration::days(1),
+            }
+        }
+    }
+
+    /// Local dates covered by a check run at `now`.
+    pub fn lookback_range(&self, now: OffsetDateTime) -> (Date, Date) {
+        let today = now.to_offset(self.offset).date();
+        (today - Duration::days(i64::from(self.lookback_days)), today)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn utc(day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn next_check_runs_on_weekdays_in_local_time() {
+        let check = AnomalyCheck::from_settings(&AnomalySettings::default()).unwrap();
+
+        // Friday 10:00 UTC -> same day 15:00 local (14:00 UTC).
+        assert_eq!(check.next_check(utc(16, 10)), utc(16, 14));
+        // Friday after the check -> Monday.
+        assert_eq!(check.next_check(utc(16, 14)), utc(19, 14));
+    }
+
+    #[test]
+    fn rejects_inverted_hours() {
+        let settings = AnomalySettings {
+            earliest_hour: 22,
+            latest_hour: 6,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            AnomalyCheck::from_settings(&settings),
+            Err(AnomalyCheckError::InvalidHours(22, 6))
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{sync::Arc, time::Duration};
+
+use url::Url;
+
+use super::{validate_webhook_url, PublicHostResolver};
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+const MAX_FEED_BYTES: usize = 5 * 1024 * 1024;
+const MAX_REDIRECTS: usize = 5;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CalendarFeedError {
+    #[error("{0}")]
+    InvalidUrl(&'static str),
+    #[error("calendar feed could not be fetched: {0}")]
+    Fetch(String),
+    #[error("calendar feed is larger than {} MB", MAX_FEED_BYTES / 1024 / 1024)]
+    TooLarge,
+}
+
+/// Check a calendar subscription URL before storing or fetching it.
+///
+/// `webcal://` links, which calendar apps hand out for subscriptions, are
+/// read as `https://`. Like webhooks, feeds must be served over HTTPS from a
+/// public host.
+pub fn validate_calendar_feed_url(url: &str) -> Result<Url, CalendarFeedError> {
+    let url = url.trim();
+    let url = match url.get(..9) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("webcal://") => {
+            format!("https://{}", &url[9..])
+        }
+        _ => url.to_string(),
+    };
+    validate_webhook_url(&url)
+        .map_err(|_| CalendarFeedError::InvalidUrl("calendar feed URLs must be public https URLs"))
+}
+
+/// Downloads users' ICS calendar feeds.
+#[derive(Clone)]
+pub struct CalendarFeedClient {
+    client: reqwest::Client,
+}
+
+impl CalendarFeedClient {
+    pub fn new() -> Self {
+        // Redirects are checked like the feed URL itself, and every host is
+        // resolved through PublicHostResolver, so a public feed can't bounce
+        // requests to an internal address.
+        let redirects = reqwest::redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS
+                || validate_webhook_url(attempt.url().as_str()).is_err()
+            {
+                attempt.stop()
+            } else {
+                attempt.follow()
+            }
+        });
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .redirect(redirects)
+            .dns_resolver(Arc::new(PublicHostResolver))
+            .build()
+            .expect("Failed to build calendar feed HTTP client");
+        Self { client }
+    }
+
+    /// Fetch the ICS document at `url`.
+    pub async fn fetch(&self, url: &str) -> Result<String, CalendarFeedError> {
+        let url = validate_calendar_feed_url(url)?;
+        let mut response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| CalendarFeedError::Fetch(e.without_url().to_string()))?;
+        if response
+            .content_length()
+            .is_some_and(|length| length > MAX_FEED_BYTES as u64)
+        {
+            return Err(CalendarFeedError::TooLarge);
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| CalendarFeedError::Fetch(e.without_url().to_string()))?
+        {
+            if body.len() + chunk.len() > MAX_FEED_BYTES {
+                return Err(CalendarFeedError::TooLarge);
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+}
+
+impl Default for CalendarFeedClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webcal_links_are_fetched_over_https() {
+        assert_eq!(
+            validate_calendar_feed_url(" webcal://calendar.example.com/team.ics")
+                .unwrap()
+                .as_str(),
+            "https://calendar.example.com/team.ics"
+        );
+        assert!(validate_calendar_feed_url("http://calendar.example.com/team.ics").is_err());
+        assert!(validate_calendar_feed_url("webcal://127.0.0.1/team.ics").is_err());
+    }
+}
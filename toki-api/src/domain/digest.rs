@@ -0,0 +1,259 @@
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::config::DigestSettings;
+
+use super::EmailNotification;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DigestError {
+    #[error("Invalid UTC offset: {0} minutes")]
+    InvalidOffset(i16),
+    #[error("Invalid digest hour {0}")]
+    InvalidHour(u8),
+}
+
+/// How often a user gets the summary email.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigestFrequency {
+    #[default]
+    Off,
+    /// Every weekday, covering that day.
+    Daily,
+    /// Fridays, covering the week.
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Off, Self::Daily, Self::Weekly]
+            .into_iter()
+            .find(|frequency| frequency.as_str() == value)
+    }
+
+    /// The period a digest sent on `today` summarizes, or `None` if none is
+    /// due that day.
+    pub fn period(self, today: Date) -> Option<(Date, Date)> {
+        match (self, today.weekday()) {
+            (_, Weekday::Saturday | Weekday::Sunday) | (Self::Off, _) => None,
+            (Self::Daily, _) => Some((today, today)),
+            (Self::Weekly, Weekday::Friday) => Some((
+                today - Duration::days(i64::from(today.weekday().number_days_from_monday())),
+                today,
+            )),
+            (Self::Weekly, _) => None,
+        }
+    }
+}
+
+/// When digests are sent.
+#[derive(Debug, Clone)]
+pub struct DigestSchedule {
+    pub enabled: bool,
+    offset: UtcOffset,
+    send_time: Time,
+}
+
+impl DigestSchedule {
+    pub fn from_settings(settings: &DigestSettings) -> Result<Self, DigestError> {
+        let offset = UtcOffset::from_whole_seconds(i32::from(settings.utc_offset_minutes) * 60)
+            .map_err(|_| DigestError::InvalidOffset(settings.utc_offset_minutes))?;
+        let send_time = Time::from_hms(settings.send_hour, 0, 0)
+            .map_err(|_| DigestError::InvalidHour(settings.send_hour))?;
+
+        Ok(Self {
+            enabled: settings.enabled,
+            offset,
+            send_time,
+        })
+    }
+
+    /// The next `send_hour` local time after `now`. Weekends are skipped by
+    /// [`DigestFrequency::period`], not here.
+    pub fn next_send(&self, now: OffsetDateTime) -> OffsetDateTime {
+        let local = now.to_offset(self.offset);
+        let today = local
+            .date()
+            .with_time(self.send_time)
+            .assume_offset(self.offset);
+        if today > now {
+            today
+        } else {
+            today + Duration::days(1)
+        }
+    }
+}
+
+/// An open pull request waiting for the digest's recipient to review it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingReview {
+    pub id: i32,
+    pub title: String,
+    pub repo_name: String,
+    pub author: String,
+}
+
+/// Summary of a user's time registrations and review queue for a period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub frequency: DigestFrequency,
+    pub period: (Date, Date),
+    pub worked_hours: f64,
+    pub scheduled_hours: f64,
+    /// Flex accumulated since the start of the year.
+    pub flex_balance: Option<f64>,
+    /// Working days in the period's week with nothing registered.
+    pub unlogged_days: Vec<Date>,
+    pub pending_reviews: Vec<PendingReview>,
+}
+
+impl Digest {
+    pub fn to_email(&self, app_url: &str) -> EmailNotification {
+        let (start, end) = self.period;
+        let title = match self.frequency {
+            DigestFrequency::Weekly => format!("Your week {} in Toki", end.iso_week()),
+            _ => format!("Your day in Toki, {} {end}", end.weekday()),
+        };
+
+        let mut lines = vec![if start == end {
+            format!(
+                "Logged {:.1} of {:.1} scheduled hours.",
+                self.worked_hours, self.scheduled_hours
+            )
+        } else {
+            format!(
+                "Logged {:.1} of {:.1} scheduled hours from {start} to {end}.",
+                self.worked_hours, self.scheduled_hours
+            )
+        }];
+        if let Some(flex) = self.flex_balance {
+            lines.push(format!("Flex balance: {flex:+.1} hours."));
+        }
+        if !self.unlogged_days.is_empty() {
+            let days = self
+                .unlogged_days
+                .iter()
+                .map(|day| format!("{} {day}", day.weekday()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(format!("Nothing registered on: {days}."));
+        }
+        if self.pending_reviews.is_empty() {
+            lines.push("No pull requests are waiting for your review.".to_string());
+        } else {
+            lines.push(format!(
+                "{} pull request(s) waiting for your review:",
+                self.pending_reviews.len()
+            ));
+            lines.extend(self.pending_reviews.iter().map(|review| {
+                format!(
+                    "• !{} {} ({}, by {})",
+                    review.id, review.title, review.repo_name, review.author
+                )
+            }));
+        }
+
+        EmailNotification::compose(
+            &title,
+            &lines.join("\n"),
+            Some(("Open Toki", app_url)),
+            &format!(
+                "You are receiving this because you subscribed to the {} digest in Toki.",
+                self.frequency.as_str()
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn october(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    fn digest(frequency: DigestFrequency, period: (Date, Date)) -> Digest {
+        Digest {
+            frequency,
+            period,
+            worked_hours: 30.0,
+            scheduled_hours: 40.0,
+            flex_balance: Some(-2.5),
+            unlogged_days: vec![october(13)],
+            pending_reviews: vec![PendingReview {
+                id: 42,
+                title: "Add <digest>".to_string(),
+                repo_name: "toki2".to_string(),
+                author: "Jane".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn digests_are_due_on_weekdays_and_weekly_ones_on_fridays() {
+        // 2026-10-16 is a Friday.
+        assert_eq!(
+            DigestFrequency::Daily.period(october(15)),
+            Some((october(15), october(15)))
+        );
+        assert_eq!(DigestFrequency::Daily.period(october(17)), None);
+        assert_eq!(DigestFrequency::Weekly.period(october(15)), None);
+        assert_eq!(
+            DigestFrequency::Weekly.period(october(16)),
+            Some((october(12), october(16)))
+        );
+        assert_eq!(DigestFrequency::Off.period(october(16)), None);
+    }
+
+    #[test]
+    fn sends_at_the_configured_local_hour() {
+        let schedule = DigestSchedule::from_settings(&DigestSettings::default()).unwrap();
+        let utc = |day: u8, hour: u8| october(day).with_hms(hour, 0, 0).unwrap().assume_utc();
+
+        // 17:00 local is 16:00 UTC with the default +01:00 offset.
+        assert_eq!(schedule.next_send(utc(16, 10)), utc(16, 16));
+        assert_eq!(schedule.next_send(utc(16, 16)), utc(17, 16));
+    }
+
+    #[test]
+    fn weekly_email_lists_hours_gaps_and_reviews() {
+        let email =
+            digest(DigestFrequency::Weekly, (october(12), october(16))).to_email("https://toki");
+
+        assert_eq!(email.subject, "Your week 42 in Toki");
+        assert!(email
+            .text_body
+            .contains("Logged 30.0 of 40.0 scheduled hours from 2026-10-12 to 2026-10-16."));
+        assert!(email.text_body.contains("Flex balance: -2.5 hours."));
+        assert!(email
+            .text_body
+            .contains("Nothing registered on: Tuesday 2026-10-13."));
+        assert!(email
+            .text_body
+            .contains("• !42 Add <digest> (toki2, by Jane)"));
+        assert!(email.html_body.contains("Add &lt;digest&gt;"));
+        assert!(email.html_body.contains("weekly digest"));
+    }
+
+    #[test]
+    fn frequencies_round_trip() {
+        for frequency in [
+            DigestFrequency::Off,
+            DigestFrequency::Daily,
+            DigestFrequency::Weekly,
+        ] {
+            assert_eq!(DigestFrequency::parse(frequency.as_str()), Some(frequency));
+        }
+        assert_eq!(DigestFrequency::parse("monthly"), None);
+    }
+}
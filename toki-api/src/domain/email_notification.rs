@@ -0,0 +1,204 @@
+use lettre::{
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::config::EmailSettings;
+
+use super::PushNotification;
+
+const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+  <body style="margin:0;padding:24px;background:#f4f4f5;font-family:-apple-system,Segoe UI,Roboto,sans-serif;color:#18181b;">
+    <table role="presentation" width="100%" style="max-width:560px;margin:0 auto;background:#ffffff;border-radius:8px;padding:24px;">
+      <tr><td>
+        <h2 style="margin:0 0 12px;font-size:18px;">{{title}}</h2>
+        <p style="margin:0 0 20px;font-size:14px;line-height:1.5;white-space:pre-line;">{{body}}</p>
+        {{action}}
+        <p style="margin:24px 0 0;font-size:12px;color:#71717a;">
+          {{footer}}
+        </p>
+      </td></tr>
+    </table>
+  </body>
+</html>
+"#;
+
+const REPOSITORY_FOOTER: &str =
+    "You are receiving this because email notifications are enabled for this repository in Toki.";
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmailNotificationError {
+    #[error("Invalid email address '{0}'")]
+    InvalidAddress(String),
+    #[error("Failed to set up SMTP transport: {0}")]
+    Transport(String),
+    #[error("Failed to build email: {0}")]
+    Build(String),
+    #[error("Failed to send email: {0}")]
+    Send(String),
+}
+
+/// A rendered notification email with plain text and HTML bodies.
+#[derive(Debug, Clone)]
+pub struct EmailNotification {
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+impl EmailNotification {
+    /// A repository notification, linking to Azure DevOps.
+    pub fn new(title: &str, body: &str, url: Option<&str>) -> Self {
+        Self::compose(
+            title,
+            body,
+            url.map(|url| ("Open in Azure DevOps", url)),
+            REPOSITORY_FOOTER,
+        )
+    }
+
+    /// An email with a custom button, given as label and URL, and footer
+    /// explaining why it was sent. Line breaks in `body` are kept.
+    pub fn compose(title: &str, body: &str, action: Option<(&str, &str)>, footer: &str) -> Self {
+        let text_body = match action {
+            Some((_, url)) => format!("{body}\n\n{url}"),
+            None => body.to_string(),
+        };
+        let action = action
+            .map(|(label, url)| {
+                format!(
+                    r#"<a href="{}" style="display:inline-block;padding:8px 16px;background:#18181b;color:#ffffff;border-radius:6px;text-decoration:none;font-size:14px;">{}</a>"#,
+                    escape_html(url),
+                    escape_html(label)
+                )
+            })
+            .unwrap_or_default();
+        let html_body = HTML_TEMPLATE
+            .replace("{{title}}", &escape_html(title))
+            .replace("{{body}}", &escape_html(body))
+            .replace("{{action}}", &action)
+            .replace("{{footer}}", &escape_html(footer));
+
+        Self {
+            subject: title.to_string(),
+            text_body,
+            html_body,
+        }
+    }
+}
+
+impl From<&PushNotification> for EmailNotification {
+    fn from(notification: &PushNotification) -> Self {
+        Self::new(
+            &notification.title,
+            &notification.body,
+            notification.url.as_deref(),
+        )
+    }
+}
+
+/// Sends notification emails over SMTP (STARTTLS).
+#[derive(Clone)]
+pub struct EmailNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl EmailNotifier {
+    pub fn new(settings: &EmailSettings) -> Result<Self, EmailNotificationError> {
+        let from = settings
+            .from_address
+            .parse::<Mailbox>()
+            .map_err(|_| EmailNotificationError::InvalidAddress(settings.from_address.clone()))?;
+
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&settings.smtp_host)
+            .map_err(|e| EmailNotificationError::Transport(e.to_string()))?
+            .port(settings.smtp_port);
+        if let (Some(username), Some(password)) = (&settings.username, &settings.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+
+    pub async fn send(
+        &self,
+        to: &str,
+        notification: EmailNotification,
+    ) -> Result<(), EmailNotificationError> {
+        let to = to
+            .parse::<Mailbox>()
+            .map_err(|_| EmailNotificationError::InvalidAddress(to.to_string()))?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(notification.subject)
+            .multipart(MultiPart::alternative_plain_html(
+                notification.text_body,
+                notification.html_body,
+            ))
+            .map_err(|e| EmailNotificationError::Build(e.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| EmailNotificationError::Send(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_user_content_in_html_body() {
+        let email = EmailNotification::new(
+            "Fix <script> tags: You were mentioned",
+            "Jane & John mentioned you in a comment.",
+            Some("https://dev.azure.com/org/project/_git/repo/pullrequest/1?a=1&b=2"),
+        );
+
+        assert!(email.html_body.contains("Fix &lt;script&gt; tags"));
+        assert!(email.html_body.contains("Jane &amp; John"));
+        assert!(email.html_body.contains("pullrequest/1?a=1&amp;b=2"));
+        assert!(!email.html_body.contains("<script>"));
+    }
+
+    #[test]
+    fn plain_text_body_includes_link() {
+        let email = EmailNotification::new("Title", "Body", Some("https://example.com"));
+
+        assert_eq!(email.subject, "Title");
+        assert_eq!(email.text_body, "Body\n\nhttps://example.com");
+    }
+
+    #[test]
+    fn omits_action_button_without_link() {
+        let email = EmailNotification::new("Title", "Body", None);
+
+        assert!(!email.html_body.contains("<a href"));
+        assert!(!email.html_body.contains("{{action}}"));
+    }
+}
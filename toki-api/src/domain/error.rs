@@ -1,4 +1,7 @@
 use thiserror::Error;
+use time::Date;
+
+use super::models::{TimeEntryBatchError, TimeEntryMergeError};
 
 /// Errors that can occur during time tracking operations.
 #[derive(Debug, Error)]
@@ -15,6 +18,22 @@ pub enum TimeTrackingError {
     #[allow(dead_code)]
     #[error("activity not found: {0}")]
     ActivityNotFound(String),
+    #[error("time entry not found: {0}")]
+    EntryNotFound(String),
+    #[error("time entry {0} has no start and end time")]
+    EntryWithoutTimes(String),
+    /// The day is attested, so its entries can't be added to or changed.
+    #[error("{0} is attested and locked for changes")]
+    DayLocked(Date),
+    #[error("split time must be between the entry's start and end")]
+    InvalidSplitTime,
+    #[error(transparent)]
+    InvalidMerge(#[from] TimeEntryMergeError),
+    #[error(transparent)]
+    InvalidBatch(#[from] TimeEntryBatchError),
+    /// The provider is down or overloaded; retrying later may succeed.
+    #[error("time tracking provider unavailable: {0}")]
+    Unavailable(String),
     #[error("{0}")]
     Unknown(String),
 }
@@ -25,6 +44,17 @@ impl TimeTrackingError {
     }
 }
 
+/// Errors that can occur while suggesting an improved time entry note.
+#[derive(Debug, Error)]
+pub enum NoteImprovementError {
+    #[error("{0}")]
+    InvalidInput(String),
+    #[error("language model request failed: {0}")]
+    Provider(String),
+    #[error("language model returned no usable suggestion")]
+    EmptySuggestion,
+}
+
 /// Errors that can occur during avatar operations.
 #[derive(Debug, Error)]
 pub enum AvatarError {
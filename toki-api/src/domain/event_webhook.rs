@@ -0,0 +1,457 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use url::{Host, Url};
+
+use crate::repositories::{
+    EventWebhookRepository, EventWebhookRepositoryImpl, NewEventWebhookDelivery,
+};
+
+use super::{models::UserId, LiveEvents};
+
+/// Delays before each retry of a failed delivery.
+const RETRY_DELAYS: [Duration; 3] = [
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+];
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub const EVENT_HEADER: &str = "X-Toki-Event";
+pub const DELIVERY_HEADER: &str = "X-Toki-Delivery";
+pub const SIGNATURE_HEADER: &str = "X-Toki-Signature";
+
+/// Toki events external consumers can subscribe to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    #[serde(rename = "timer.started")]
+    TimerStarted,
+    #[serde(rename = "timer.updated")]
+    TimerUpdated,
+    #[serde(rename = "timer.stopped")]
+    TimerStopped,
+    #[serde(rename = "entry.saved")]
+    EntrySaved,
+    #[serde(rename = "pull_request.blocked")]
+    PullRequestBlocked,
+}
+
+impl EventKind {
+    pub const ALL: [Self; 5] = [
+        Self::TimerStarted,
+        Self::TimerUpdated,
+        Self::TimerStopped,
+        Self::EntrySaved,
+        Self::PullRequestBlocked,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TimerStarted => "timer.started",
+            Self::TimerUpdated => "timer.updated",
+            Self::TimerStopped => "timer.stopped",
+            Self::EntrySaved => "entry.saved",
+            Self::PullRequestBlocked => "pull_request.blocked",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+}
+
+/// An event as posted to subscribed webhooks.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    /// Unique per event, so consumers can drop retried duplicates.
+    pub id: String,
+    pub event: EventKind,
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurred_at: OffsetDateTime,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(event: EventKind, data: serde_json::Value) -> Self {
+        Self {
+            id: random_hex(16),
+            event,
+            occurred_at: OffsetDateTime::now_utc(),
+            data,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventWebhook {
+    pub id: i32,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<EventKind>,
+    pub created_at: OffsetDateTime,
+}
+
+/// Webhook as shown to its owner. The signing secret is only returned once,
+/// when the webhook is created.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EventWebhookInfo {
+    pub id: i32,
+    pub url: String,
+    pub events: Vec<EventKind>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl From<EventWebhook> for EventWebhookInfo {
+    fn from(webhook: EventWebhook) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+/// One attempt at delivering an event to a webhook.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EventWebhookDelivery {
+    pub id: i64,
+    pub event_id: String,
+    pub event: String,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub success: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// A new random signing secret.
+pub fn generate_secret() -> String {
+    format!("whsec_{}", random_hex(32))
+}
+
+fn random_hex(bytes: usize) -> String {
+    let mut buf = vec![0u8; bytes];
+    rand::thread_rng().fill_bytes(&mut buf);
+    hex::encode(buf)
+}
+
+/// Value of the signature header: `t=<unix time>,v1=<hex HMAC-SHA256>`.
+///
+/// The MAC covers `"<unix time>.<body>"`, so consumers can reject replays of
+/// old deliveries by checking the timestamp.
+pub fn signature_header(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!(
+        "t={timestamp},v1={}",
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Check a subscriber URL before storing it.
+///
+/// Only HTTPS URLs are accepted, and loopback and private addresses are
+/// rejected so webhooks can't be used to probe the server's own network.
+/// Host names are only checked by name here; [`PublicHostResolver`] checks
+/// what they resolve to when a request is sent.
+pub fn validate_webhook_url(url: &str) -> Result<Url, &'static str> {
+    let url = Url::parse(url).map_err(|_| "invalid URL")?;
+    if url.scheme() != "https" {
+        return Err("webhook URLs must use https");
+    }
+
+    let internal = match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_internal_ip(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_internal_ip(IpAddr::V6(ip)),
+        None => true,
+    };
+    if internal {
+        return Err("webhook URLs must point to a public host");
+    }
+
+    Ok(url)
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                // 0.0.0.0/8, "this network"
+                || first == 0
+                // 100.64.0.0/10, carrier-grade NAT
+                || (first == 100 && second & 0xc0 == 64)
+        }
+        IpAddr::V6(ip) => {
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || ip.is_multicast()
+                || ip
+                    .to_ipv4_mapped()
+                    .or_else(|| nat64_ipv4(ip))
+                    .is_some_and(|v4| is_internal_ip(IpAddr::V4(v4)))
+        }
+    }
+}
+
+/// The IPv4 address embedded in a NAT64 address (64:ff9b::/96), which a
+/// NAT64 gateway forwards to.
+fn nat64_ipv4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    match ip.segments() {
+        [0x64, 0xff9b, 0, 0, 0, 0, high, low] => {
+            Some(Ipv4Addr::from((u32::from(high) << 16) | u32::from(low)))
+        }
+        _ => None,
+    }
+}
+
+/// Resolves hosts for requests to user-supplied URLs, failing for any host
+/// with an internal address.
+///
+/// A public name can resolve to a private address, or be changed to one
+/// after its URL was checked, so the addresses are checked on every
+/// connection.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PublicHostResolver;
+
+impl Resolve for PublicHostResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(resolve_public_host(name.as_str().to_string()))
+    }
+}
+
+async fn resolve_public_host(
+    host: String,
+) -> Result<Addrs, Box<dyn std::error::Error + Send + Sync>> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+    if let Some(addr) = addrs.iter().find(|addr| is_internal_ip(addr.ip())) {
+        return Err(format!("{host} resolves to internal address {}", addr.ip()).into());
+    }
+    Ok(Box::new(addrs.into_iter()))
+}
+
+/// Posts toki events to the webhooks users subscribed to them, and to the
+/// user's connected clients through [`LiveEvents`].
+///
+/// Deliveries run in the background. Failed attempts are retried with
+/// backoff, and every attempt is recorded in the delivery log. Redirects are
+/// not followed, so a subscriber can't bounce deliveries to another host.
+#[derive(Clone)]
+pub struct EventWebhookDispatcher {
+    repo: Arc<EventWebhookRepositoryImpl>,
+    client: reqwest::Client,
+    live: LiveEvents,
+}
+
+impl EventWebhookDispatcher {
+    pub fn new(db_pool: PgPool, live: LiveEvents) -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicHostResolver))
+            .build()
+            .expect("Failed to build webhook HTTP client");
+        Self {
+            repo: Arc::new(EventWebhookRepositoryImpl::new(db_pool)),
+            client,
+            live,
+        }
+    }
+
+    /// Deliver `event` to each of the user's webhooks subscribed to it.
+    pub async fn dispatch(&self, user_id: UserId, event: WebhookEvent) {
+        self.live.publish(user_id, &event);
+
+        let webhooks = match self
+            .repo
+            .get_subscribed_webhooks(user_id.as_i32(), event.event)
+            .await
+        {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::error!("Failed to get event webhooks for user {user_id}: {e}");
+                return;
+            }
+        };
+        if webhooks.is_empty() {
+            return;
+        }
+
+        let body = match serde_json::to_vec(&event) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("Failed to serialize {} event: {e}", event.event.as_str());
+                return;
+            }
+        };
+        for webhook in webhooks {
+            let dispatcher = self.clone();
+            let event = event.clone();
+            let body = body.clone();
+            tokio::spawn(async move { dispatcher.deliver(&webhook, &event, &body).await });
+        }
+    }
+
+    async fn deliver(&self, webhook: &EventWebhook, event: &WebhookEvent, body: &[u8]) {
+        for attempt in 0..=RETRY_DELAYS.len() {
+            if let Some(delay) = attempt.checked_sub(1).map(|i| RETRY_DELAYS[i]) {
+                tokio::time::sleep(delay).await;
+            }
+
+            let (status_code, error) = match self.post(webhook, event, body).await {
+                Ok(status) if status.is_success() => (Some(status.as_u16()), None),
+                Ok(status) => (
+                    Some(status.as_u16()),
+                    Some(format!("webhook returned {status}")),
+                ),
+                Err(e) => (None, Some(format!("request failed: {e}"))),
+            };
+            let success = error.is_none();
+
+            if let Err(e) = self
+                .repo
+                .insert_delivery(NewEventWebhookDelivery {
+                    webhook_id: webhook.id,
+                    event_id: event.id.clone(),
+                    event: event.event.as_str().to_string(),
+                    attempt: attempt as i32 + 1,
+                    status_code: status_code.map(i32::from),
+                    error,
+                    success,
+                })
+                .await
+            {
+                tracing::warn!("Failed to log delivery to webhook {}: {e}", webhook.id);
+            }
+            if success {
+                return;
+            }
+        }
+
+        tracing::warn!(
+            "Giving up on delivering {} event {} to webhook {}",
+            event.event.as_str(),
+            event.id,
+            webhook.id
+        );
+    }
+
+    async fn post(
+        &self,
+        webhook: &EventWebhook,
+        event: &WebhookEvent,
+        body: &[u8],
+    ) -> Result<reqwest::StatusCode, reqwest::Error> {
+        let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+        let response = self
+            .client
+            .post(&webhook.url)
+            .timeout(DELIVERY_TIMEOUT)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, event.event.as_str())
+            .header(DELIVERY_HEADER, &event.id)
+            .header(
+                SIGNATURE_HEADER,
+                signature_header(&webhook.secret, timestamp, body),
+            )
+            .body(body.to_vec())
+            .send()
+            .await?;
+
+        Ok(response.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_kinds_round_trip_through_their_names() {
+        for kind in EventKind::ALL {
+            assert_eq!(EventKind::parse(kind.as_str()), Some(kind));
+            assert_eq!(
+                serde_json::to_value(kind).unwrap(),
+                serde_json::json!(kind.as_str())
+            );
+        }
+        assert_eq!(EventKind::parse("timer.paused"), None);
+    }
+
+    #[test]
+    fn signs_timestamp_and_body() {
+        // printf '1700000000.{}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            signature_header("secret", 1_700_000_000, b"{}"),
+            "t=1700000000,v1=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+
+    #[test]
+    fn rejects_internal_and_plain_http_urls() {
+        assert!(validate_webhook_url("https://hooks.example.com/toki").is_ok());
+        assert!(validate_webhook_url("http://hooks.example.com/toki").is_err());
+        assert!(validate_webhook_url("https://localhost:8080/hook").is_err());
+        assert!(validate_webhook_url("https://127.0.0.1/hook").is_err());
+        assert!(validate_webhook_url("https://10.0.0.5/hook").is_err());
+        assert!(validate_webhook_url("https://[::1]/hook").is_err());
+        assert!(validate_webhook_url("https://[::ffff:192.168.1.1]/hook").is_err());
+    }
+
+    #[test]
+    fn treats_shared_multicast_and_nat64_addresses_as_internal() {
+        for ip in [
+            "0.1.2.3",
+            "100.64.0.1",
+            "100.127.255.254",
+            "224.0.0.251",
+            "ff02::1",
+            "64:ff9b::a9fe:a9fe",
+            "64:ff9b::7f00:1",
+        ] {
+            assert!(is_internal_ip(ip.parse().unwrap()), "{ip} is internal");
+        }
+        for ip in ["100.63.255.255", "100.128.0.1", "64:ff9b::808:808"] {
+            assert!(!is_internal_ip(ip.parse().unwrap()), "{ip} is public");
+        }
+    }
+
+    #[tokio::test]
+    async fn resolver_rejects_hosts_resolving_to_internal_addresses() {
+        let resolved = PublicHostResolver
+            .resolve("localhost".parse().unwrap())
+            .await;
+        let Err(error) = resolved else {
+            panic!("localhost should not resolve");
+        };
+        assert!(error.to_string().contains("internal address"));
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::config::LiveSettings;
+
+use super::{models::UserId, EventKind, WebhookEvent};
+
+/// Events buffered per subscriber before a slow one starts missing them.
+const CAPACITY: usize = 256;
+/// How often idle connections are pinged, so proxies keep them open and
+/// live clients answer with a pong.
+pub const LIVE_PING_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum LiveEventsError {
+    #[error("Invalid live connection limit: {0}")]
+    InvalidConnectionLimit(u16),
+    #[error("Live idle timeout of {0} seconds is shorter than the ping interval")]
+    InvalidIdleTimeout(u16),
+}
+
+/// Fans toki events out to connected clients as they happen, so every open
+/// client of a user sees timer changes made on another device right away.
+///
+/// Each user with a connection open gets their own channel, so a burst of
+/// events for one user can't push another user's subscribers behind.
+/// Subscriptions are counted per user and capped at the configured limit,
+/// so one user can't hold an unbounded number of connections open.
+#[derive(Debug, Clone)]
+pub struct LiveEvents {
+    channels: Arc<Mutex<HashMap<UserId, UserChannel>>>,
+    counters: Arc<LiveCounters>,
+    max_connections_per_user: usize,
+    idle_timeout: Duration,
+}
+
+#[derive(Debug)]
+struct UserChannel {
+    sender: broadcast::Sender<WebhookEvent>,
+    connections: usize,
+}
+
+/// Connection counts since startup.
+#[derive(Debug, Default)]
+struct LiveCounters {
+    refused: AtomicU64,
+    idle_disconnects: AtomicU64,
+    lagged_disconnects: AtomicU64,
+}
+
+/// Live connections right now, and how many were refused or closed early
+/// since startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveEventStats {
+    pub connected_users: usize,
+    pub open_connections: usize,
+    pub refused_connections: u64,
+    pub idle_disconnects: u64,
+    pub lagged_disconnects: u64,
+}
+
+impl LiveEvents {
+    pub fn from_settings(settings: &LiveSettings) -> Result<Self, LiveEventsError> {
+        if settings.max_connections_per_user == 0 {
+            return Err(LiveEventsError::InvalidConnectionLimit(
+                settings.max_connections_per_user,
+            ));
+        }
+        let idle_timeout = Duration::from_secs(u64::from(settings.idle_timeout_secs));
+        if idle_timeout <= LIVE_PING_INTERVAL {
+            return Err(LiveEventsError::InvalidIdleTimeout(
+                settings.idle_timeout_secs,
+            ));
+        }
+
+        Ok(Self {
+            channels: Arc::default(),
+            counters: Arc::default(),
+            max_connections_per_user: usize::from(settings.max_connections_per_user),
+            idle_timeout,
+        })
+    }
+
+    /// Live connections a user can have open at once, over all their devices.
+    pub fn max_connections_per_user(&self) -> usize {
+        self.max_connections_per_user
+    }
+
+    /// How long a connection may go without hearing from its client.
+    pub fn idle_timeout(&self) -> Duration {
+        self.idle_timeout
+    }
+
+    /// Publish `event` to the user's current subscribers. Only timer and
+    /// entry events are published; nobody listening is fine.
+    pub fn publish(&self, user_id: UserId, event: &WebhookEvent) {
+        if !is_live(event.event) {
+            return;
+        }
+        let channels = self
+            .channels
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(channel) = channels.get(&user_id) {
+            let _ = channel.sender.send(event.clone());
+        }
+    }
+
+    /// Subscribe to the user's events, or `None` if they already have
+    /// [`max_connections_per_user`](Self::max_connections_per_user)
+    /// subscriptions open.
+    pub fn subscribe(&self, user_id: UserId) -> Option<LiveSubscription> {
+        let mut channels = self
+            .channels
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let channel = channels.entry(user_id).or_insert_with(|| UserChannel {
+            sender: broadcast::channel(CAPACITY).0,
+            connections: 0,
+        });
+        if channel.connections >= self.max_connections_per_user {
+            self.counters.refused.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        channel.connections += 1;
+
+        Some(LiveSubscription {
+            user_id,
+            events: channel.sender.subscribe(),
+            channels: self.channels.clone(),
+            counters: self.counters.clone(),
+        })
+    }
+
+    pub fn stats(&self) -> LiveEventStats {
+        let channels = self
+            .channels
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        LiveEventStats {
+            connected_users: channels.len(),
+            open_connections: channels.values().map(|channel| channel.connections).sum(),
+            refused_connections: self.counters.refused.load(Ordering::Relaxed),
+            idle_disconnects: self.counters.idle_disconnects.load(Ordering::Relaxed),
+            lagged_disconnects: self.counters.lagged_disconnects.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// One user's live events. Counts against the user's connection limit
+/// until dropped.
+#[derive(Debug)]
+pub struct LiveSubscription {
+    user_id: UserId,
+    events: broadcast::Receiver<WebhookEvent>,
+    channels: Arc<Mutex<HashMap<UserId, UserChannel>>>,
+    counters: Arc<LiveCounters>,
+}
+
+impl LiveSubscription {
+    /// The user's next event.
+    pub async fn recv(&mut self) -> Result<WebhookEvent, RecvError> {
+        self.events.recv().await
+    }
+
+    /// Count the connection as closed for going quiet.
+    pub fn record_idle_disconnect(&self) {
+        self.counters
+            .idle_disconnects
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count the connection as closed for falling behind.
+    pub fn record_lagged_disconnect(&self) {
+        self.counters
+            .lagged_disconnects
+            .fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Drop for LiveSubscription {
+    fn drop(&mut self) {
+        let mut channels = self
+            .channels
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(channel) = channels.get_mut(&self.user_id) {
+            channel.connections -= 1;
+            if channel.connections == 0 {
+                channels.remove(&self.user_id);
+            }
+        }
+    }
+}
+
+impl Default for LiveEvents {
+    fn default() -> Self {
+        Self::from_settings(&LiveSettings::default()).expect("default live settings are valid")
+    }
+}
+
+fn is_live(kind: EventKind) -> bool {
+    match kind {
+        EventKind::TimerStarted
+        | EventKind::TimerUpdated
+        | EventKind::TimerStopped
+        | EventKind::EntrySaved => true,
+        EventKind::PullRequestBlocked => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn publishes_the_users_timer_events_only() {
+        let live = LiveEvents::default();
+        let mut events = live.subscribe(UserId::new(2)).unwrap();
+
+        live.publish(
+            UserId::new(2),
+            &WebhookEvent::new(EventKind::PullRequestBlocked, json!({})),
+        );
+        live.publish(
+            UserId::new(1),
+            &WebhookEvent::new(EventKind::TimerStarted, json!(null)),
+        );
+        live.publish(
+            UserId::new(2),
+            &WebhookEvent::new(EventKind::TimerStopped, json!(null)),
+        );
+
+        assert_eq!(events.recv().await.unwrap().event, EventKind::TimerStopped);
+        assert!(events.events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn other_users_bursts_dont_lag_a_subscriber() {
+        let live = LiveEvents::default();
+        let mut events = live.subscribe(UserId::new(2)).unwrap();
+        let _busy = live.subscribe(UserId::new(1)).unwrap();
+
+        for _ in 0..CAPACITY * 2 {
+            live.publish(
+                UserId::new(1),
+                &WebhookEvent::new(EventKind::TimerStarted, json!(null)),
+            );
+        }
+        live.publish(
+            UserId::new(2),
+            &WebhookEvent::new(EventKind::TimerStopped, json!(null)),
+        );
+
+        assert_eq!(events.recv().await.unwrap().event, EventKind::TimerStopped);
+    }
+
+    #[test]
+    fn caps_open_subscriptions_per_user() {
+        let live = LiveEvents::default();
+        let user_id = UserId::new(1);
+        let mut open: Vec<_> = (0..live.max_connections_per_user())
+            .map(|_| live.subscribe(user_id).unwrap())
+            .collect();
+
+        assert!(live.subscribe(user_id).is_none());
+        let other = live.subscribe(UserId::new(2)).unwrap();
+        assert_eq!(
+            live.stats(),
+            LiveEventStats {
+                connected_users: 2,
+                open_connections: live.max_connections_per_user() + 1,
+                refused_connections: 1,
+                idle_disconnects: 0,
+                lagged_disconnects: 0,
+            }
+        );
+
+        // Closing a connection frees its slot
+        open.pop();
+        assert!(live.subscribe(user_id).is_some());
+        drop(other);
+        assert_eq!(live.stats().connected_users, 1);
+    }
+
+    #[test]
+    fn rejects_idle_timeouts_within_the_ping_interval() {
+        let settings = LiveSettings {
+            idle_timeout_secs: LIVE_PING_INTERVAL.as_secs() as u16,
+            ..LiveSettings::default()
+        };
+
+        assert!(matches!(
+            LiveEvents::from_settings(&settings),
+            Err(LiveEventsError::InvalidIdleTimeout(_))
+        ));
+    }
+}
@@ -1,32 +1,56 @@
+mod anomaly_check;
+mod calendar_feed;
+mod digest;
 mod email;
+mod email_notification;
 mod error;
+mod event_webhook;
+mod live_events;
 pub mod models;
 mod notification_handler;
 mod notification_preference;
+mod notification_webhook;
+mod polling_schedule;
 pub mod ports;
 mod pr_change_event;
 mod pull_request;
+mod push_dispatcher;
 mod push_notification;
 mod push_subscription;
 mod repo_config;
 mod repo_differ;
+mod repo_health;
 mod repo_key;
 mod repository;
 pub mod services;
+mod status_banner;
+mod timer_reminder;
 mod user;
 mod work_item_error;
 
+pub use anomaly_check::*;
+pub use calendar_feed::*;
+pub use digest::*;
 pub use email::*;
+pub use email_notification::*;
 pub use error::*;
+pub use event_webhook::*;
+pub use live_events::*;
 pub use notification_handler::*;
 pub use notification_preference::*;
+pub use notification_webhook::*;
+pub use polling_schedule::*;
 pub use pr_change_event::*;
 pub use pull_request::*;
+pub use push_dispatcher::*;
 pub use push_notification::*;
 pub use push_subscription::*;
 pub use repo_config::*;
 pub use repo_differ::*;
+pub use repo_health::*;
 pub use repo_key::*;
 pub use repository::*;
+pub use status_banner::*;
+pub use timer_reminder::*;
 pub use user::*;
 pub use work_item_error::*;
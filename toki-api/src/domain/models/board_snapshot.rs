@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::{BoardData, BoardState};
+
+/// Where a single work item was on the board when a snapshot was taken.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardSnapshotItem {
+    pub id: String,
+    pub title: String,
+    pub column_name: Option<String>,
+    pub board_state: BoardState,
+}
+
+/// The state of one team's board for one iteration at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardSnapshot {
+    pub taken_at: OffsetDateTime,
+    pub items: Vec<BoardSnapshotItem>,
+}
+
+impl BoardSnapshot {
+    pub fn from_board(board: &BoardData, taken_at: OffsetDateTime) -> Self {
+        let items = board
+            .items
+            .iter()
+            .map(|item| BoardSnapshotItem {
+                id: item.id.clone(),
+                title: item.title.clone(),
+                column_name: item.board_column_name.clone(),
+                board_state: item.board_state,
+            })
+            .collect();
+
+        Self { taken_at, items }
+    }
+
+    /// Whether both snapshots have the same items in the same columns.
+    pub fn same_board_as(&self, other: &BoardSnapshot) -> bool {
+        self.items == other.items
+    }
+}
+
+/// A work item that changed column between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardItemMove {
+    pub id: String,
+    pub title: String,
+    pub from_column: Option<String>,
+    pub to_column: Option<String>,
+}
+
+/// What changed on a board between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardDiff {
+    /// When the snapshot the diff starts from was taken.
+    pub from: OffsetDateTime,
+    /// When the snapshot the diff ends at was taken.
+    pub to: OffsetDateTime,
+    /// Items pulled into the iteration.
+    pub added: Vec<BoardSnapshotItem>,
+    /// Items moved out of the iteration or deleted.
+    pub removed: Vec<BoardSnapshotItem>,
+    /// Items that changed column without being completed.
+    pub moved: Vec<BoardItemMove>,
+    /// Items that reached a done column, including ones added already done.
+    pub completed: Vec<BoardSnapshotItem>,
+}
+
+impl BoardDiff {
+    pub fn between(from: &BoardSnapshot, to: &BoardSnapshot) -> Self {
+        let before: HashMap<&str, &BoardSnapshotItem> = from
+            .items
+            .iter()
+            .map(|item| (item.id.as_str(), item))
+            .collect();
+        let after: HashMap<&str, &BoardSnapshotItem> = to
+            .items
+            .iter()
+            .map(|item| (item.id.as_str(), item))
+            .collect();
+
+        let mut diff = Self {
+            from: from.taken_at,
+            to: to.taken_at,
+            added: vec![],
+            removed: vec![],
+            moved: vec![],
+            completed: vec![],
+        };
+
+        for item in &to.items {
+            let previous = before.get(item.id.as_str());
+            let was_done = previous.is_some_and(|prev| prev.board_state == BoardState::Done);
+            if item.board_state == BoardState::Done && !was_done {
+                diff.completed.push(item.clone());
+            }
+
+            match previous {
+                None => diff.added.push(item.clone()),
+                Some(prev) if prev.column_name != item.column_name => {
+                    if item.board_state != BoardState::Done || was_done {
+                        diff.moved.push(BoardItemMove {
+                            id: item.id.clone(),
+                            title: item.title.clone(),
+                            from_column: prev.column_name.clone(),
+                            to_column: item.column_name.clone(),
+                        });
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = from
+            .items
+            .iter()
+            .filter(|item| !after.contains_key(item.id.as_str()))
+            .cloned()
+            .collect();
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn utc(day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn item(id: &str, column: &str, state: BoardState) -> BoardSnapshotItem {
+        BoardSnapshotItem {
+            id: id.to_string(),
+            title: format!("Item {id}"),
+            column_name: Some(column.to_string()),
+            board_state: state,
+        }
+    }
+
+    #[test]
+    fn diff_lists_added_removed_moved_and_completed_items() {
+        let from = BoardSnapshot {
+            taken_at: utc(5, 8),
+            items: vec![
+                item("1", "New", BoardState::Todo),
+                item("2", "Active", BoardState::InProgress),
+                item("3", "Active", BoardState::InProgress),
+                item("4", "Done", BoardState::Done),
+            ],
+        };
+        let to = BoardSnapshot {
+            taken_at: utc(16, 16),
+            items: vec![
+                item("1", "Active", BoardState::InProgress),
+                item("2", "Done", BoardState::Done),
+                item("4", "Done", BoardState::Done),
+                item("5", "New", BoardState::Todo),
+            ],
+        };
+
+        let diff = BoardDiff::between(&from, &to);
+
+        assert_eq!(diff.from, from.taken_at);
+        assert_eq!(diff.to, to.taken_at);
+        assert_eq!(diff.added, vec![item("5", "New", BoardState::Todo)]);
+        assert_eq!(
+            diff.removed,
+            vec![item("3", "Active", BoardState::InProgress)]
+        );
+        assert_eq!(
+            diff.moved,
+            vec![BoardItemMove {
+                id: "1".to_string(),
+                title: "Item 1".to_string(),
+                from_column: Some("New".to_string()),
+                to_column: Some("Active".to_string()),
+            }]
+        );
+        assert_eq!(diff.completed, vec![item("2", "Done", BoardState::Done)]);
+    }
+
+    #[test]
+    fn items_added_already_done_count_as_completed() {
+        let from = BoardSnapshot {
+            taken_at: utc(5, 8),
+            items: vec![],
+        };
+        let to = BoardSnapshot {
+            taken_at: utc(6, 8),
+            items: vec![item("1", "Closed", BoardState::Done)],
+        };
+
+        let diff = BoardDiff::between(&from, &to);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.completed.len(), 1);
+        assert!(diff.moved.is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::HashSet;
+
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, Weekday};
+
+use super::UserTimeZone;
+
+/// Upper bound on occurrences expanded from one recurring event.
+const MAX_OCCURRENCES: usize = 2000;
+
+/// One occurrence of a meeting from a calendar feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub title: String,
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+/// Timed events read from an iCalendar (ICS) feed.
+///
+/// All-day and cancelled events are left out. Daily and weekly recurrences
+/// are expanded (`INTERVAL`, `BYDAY`, `COUNT`, `UNTIL` and `EXDATE`), as are
+/// moved or renamed occurrences (`RECURRENCE-ID`). Other recurrences only
+/// yield their first occurrence.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarFeed {
+    events: Vec<FeedEvent>,
+}
+
+#[derive(Debug, Clone)]
+struct FeedEvent {
+    uid: String,
+    title: String,
+    /// Wall-clock start in `time_zone`.
+    start: PrimitiveDateTime,
+    time_zone: UserTimeZone,
+    length: Duration,
+    rule: Option<Recurrence>,
+    exdates: Vec<OffsetDateTime>,
+    /// Set on an event replacing one occurrence of a recurring event.
+    recurrence_id: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Recurrence {
+    frequency: Frequency,
+    interval: u32,
+    by_day: Vec<Weekday>,
+    count: Option<usize>,
+    until: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Other,
+}
+
+impl CalendarFeed {
+    /// Parse an ICS document. Times without a zone, and times in zones that
+    /// are not IANA names (Outlook uses Windows names), are read in
+    /// `time_zone`.
+    pub fn parse(ics: &str, time_zone: &UserTimeZone) -> Self {
+        let mut events = Vec::new();
+        let mut current: Option<Vec<Property>> = None;
+        for line in unfold(ics) {
+            let Some(property) = Property::parse(&line) else {
+                continue;
+            };
+            match (property.name.as_str(), property.value.as_str()) {
+                ("BEGIN", "VEVENT") => current = Some(Vec::new()),
+                ("END", "VEVENT") => {
+                    if let Some(event) = current
+                        .take()
+                        .and_then(|props| FeedEvent::from_properties(&props, time_zone))
+                    {
+                        events.push(event);
+                    }
+                }
+                _ => {
+                    if let Some(props) = &mut current {
+                        props.push(property);
+                    }
+                }
+            }
+        }
+        Self { events }
+    }
+
+    /// Occurrences overlapping `from..to`, ordered by start.
+    pub fn events_between(&self, from: OffsetDateTime, to: OffsetDateTime) -> Vec<CalendarEvent> {
+        let replaced: HashSet<(&str, OffsetDateTime)> = self
+            .events
+            .iter()
+            .filter_map(|event| Some((event.uid.as_str(), event.recurrence_id?)))
+            .collect();
+
+        let mut occurrences: Vec<CalendarEvent> = self
+            .events
+            .iter()
+            .flat_map(|event| {
+                event
+                    .starts(to)
+                    .into_iter()
+                    .filter(|start| {
+                        event.recurrence_id.is_some()
+                            || !replaced.contains(&(event.uid.as_str(), *start))
+                    })
+                    .map(|start| CalendarEvent {
+                        uid: event.uid.clone(),
+                        title: event.title.clone(),
+                        start,
+                        end: start + event.length,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|occurrence| occurrence.end > from && occurrence.start < to)
+            .collect();
+        occurrences.sort_by_key(|occurrence| occurrence.start);
+        occurrences
+    }
+}
+
+impl FeedEvent {
+    fn from_properties(props: &[Property], default_zone: &UserTimeZone) -> Option<Self> {
+        let find = |name: &str| props.iter().find(|p| p.name == name);
+        if find("STATUS").is_some_and(|p| p.value.eq_ignore_ascii_case("CANCELLED")) {
+            return None;
+        }
+
+        let (start, time_zone) = find("DTSTART")?.local_time(default_zone)?;
+        let length =
+            if let Some((end, end_zone)) = find("DTEND").and_then(|p| p.local_time(default_zone)) {
+                end_zone.at(end) - time_zone.at(start)
+            } else {
+                find("DURATION")
+                    .and_then(|p| parse_duration(&p.value))
+                    .unwrap_or(Duration::ZERO)
+            };
+        if length <= Duration::ZERO {
+            return None;
+        }
+
+        let instant = |p: &Property| p.local_time(default_zone).map(|(t, zone)| zone.at(t));
+        Some(Self {
+            uid: find("UID").map(|p| p.value.clone()).unwrap_or_default(),
+            title: find("SUMMARY")
+                .map(|p| unescape(&p.value))
+                .unwrap_or_default(),
+            start,
+            time_zone,
+            length,
+            rule: find("RRULE").map(|p| Recurrence::parse(&p.value, default_zone)),
+            exdates: props
+                .iter()
+                .filter(|p| p.name == "EXDATE")
+                .flat_map(|p| p.split_values())
+                .filter_map(|p| instant(&p))
+                .collect(),
+            recurrence_id: find("RECURRENCE-ID").and_then(instant),
+        })
+    }
+
+    /// Start of every occurrence beginning before `until`.
+    fn starts(&self, until: OffsetDateTime) -> Vec<OffsetDateTime> {
+        let first = self.time_zone.at(self.start);
+        let Some(rule) = &self.rule else {
+            return vec![first];
+        };
+        let interval = i64::from(rule.interval.max(1));
+        let limit = rule.until.map_or(until, |rule_until| rule_until.min(until));
+        let count = rule.count.unwrap_or(MAX_OCCURRENCES).min(MAX_OCCURRENCES);
+
+        let mut dates: Box<dyn Iterator<Item = Date>> = match rule.frequency {
+            Frequency::Daily => Box::new(
+                (0..)
+                    .map(move |i| self.start.date() + Duration::days(i * interval))
+                    .take(MAX_OCCURRENCES),
+            ),
+            Frequency::Weekly => {
+                let mut days = if rule.by_day.is_empty() {
+                    vec![self.start.weekday()]
+                } else {
+                    rule.by_day.clone()
+                };
+                days.sort_by_key(|day| day.number_days_from_monday());
+                let monday = self.start.date()
+                    - Duration::days(i64::from(self.start.weekday().number_days_from_monday()));
+                let first_date = self.start.date();
+                Box::new(
+                    (0..)
+                        .flat_map(move |week| {
+                            let week_start = monday + Duration::weeks(week * interval);
+                            days.clone().into_iter().map(move |day| {
+                                week_start
+                                    + Duration::days(i64::from(day.number_days_from_monday()))
+                            })
+                        })
+                        .filter(move |date| *date >= first_date)
+                        .take(MAX_OCCURRENCES),
+                )
+            }
+            Frequency::Other => Box::new(std::iter::once(self.start.date())),
+        };
+
+        let mut starts = Vec::new();
+        for date in dates.by_ref().take(count) {
+            let start = self
+                .time_zone
+                .at(PrimitiveDateTime::new(date, self.start.time()));
+            if start > limit || (start == limit && rule.until.is_none()) {
+                break;
+            }
+            if !self.exdates.contains(&start) {
+                starts.push(start);
+            }
+        }
+        starts
+    }
+}
+
+impl Recurrence {
+    fn parse(value: &str, default_zone: &UserTimeZone) -> Self {
+        let mut rule = Self {
+            frequency: Frequency::Other,
+            interval: 1,
+            by_day: Vec::new(),
+            count: None,
+            until: None,
+        };
+        for part in value.split(';') {
+            let Some((key, value)) = part.split_once('=') else {
+                continue;
+            };
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    rule.frequency = match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        _ => Frequency::Other,
+                    }
+                }
+                "INTERVAL" => rule.interval = value.parse().unwrap_or(1),
+                "COUNT" => rule.count = value.parse().ok(),
+                "UNTIL" => {
+                    rule.until = parse_date_time(value)
+                        .map(|(time, utc)| match utc {
+                            true => time.assume_utc(),
+                            false => default_zone.at(time),
+                        })
+                        .or_else(|| {
+                            // A date-only UNTIL includes that whole day.
+                            parse_date(value).map(|date| {
+                                default_zone.at(PrimitiveDateTime::new(date, Time::MIDNIGHT))
+                                    + Duration::days(1)
+                            })
+                        })
+                }
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .filter_map(|day| parse_weekday(day.trim()))
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+        rule
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    /// Parse a content line like `DTSTART;TZID=Europe/Stockholm:20261016T090000`.
+    fn parse(line: &str) -> Option<Self> {
+        let mut in_quotes = false;
+        let split = line.char_indices().find_map(|(i, c)| match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                None
+            }
+            ':' if !in_quotes => Some(i),
+            _ => None,
+        })?;
+        let (head, value) = (&line[..split], &line[split + 1..]);
+        let mut parts = head.split(';');
+        let name = parts.next()?.trim().to_ascii_uppercase();
+        let params = parts
+            .filter_map(|param| {
+                let (key, value) = param.split_once('=')?;
+                Some((
+                    key.to_ascii_uppercase(),
+                    value.trim_matches('"').to_string(),
+                ))
+            })
+            .collect();
+        Some(Self {
+            name,
+            params,
+            value: value.to_string(),
+        })
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Wall-clock time and zone of a date-time value. `None` for dates
+    /// without a time, which mark all-day events.
+    fn local_time(&self, default_zone: &UserTimeZone) -> Option<(PrimitiveDateTime, UserTimeZone)> {
+        let (time, utc) = parse_date_time(&self.value)?;
+        if utc {
+            let local = default_zone.to_local(time.assume_utc());
+            return Some((
+                PrimitiveDateTime::new(local.date(), local.time()),
+                *default_zone,
+            ));
+        }
+        let zone = self
+            .param("TZID")
+            .and_then(UserTimeZone::parse)
+            .unwrap_or(*default_zone);
+        Some((time, zone))
+    }
+
+    /// One property per comma-separated value, as in `EXDATE:...,...`.
+    fn split_values(&self) -> Vec<Property> {
+        self.value
+            .split(',')
+            .map(|value| Property {
+                value: value.to_string(),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+/// Join folded lines: a line starting with a space or tab continues the
+/// previous one.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push(' '),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let value = value.get(..8)?;
+    let year = value.get(..4)?.parse().ok()?;
+    let month = value.get(4..6)?.parse::<u8>().ok()?.try_into().ok()?;
+    let day = value.get(6..8)?.parse().ok()?;
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// `YYYYMMDDTHHMMSS`, with whether it ends in `Z` (UTC).
+fn parse_date_time(value: &str) -> Option<(PrimitiveDateTime, bool)> {
+    let (date, time) = value.split_once('T')?;
+    let date = parse_date(date)?;
+    let utc = time.ends_with('Z');
+    let time = time.trim_end_matches('Z');
+    let hour = time.get(..2)?.parse().ok()?;
+    let minute = time.get(2..4)?.parse().ok()?;
+    let second = time.get(4..6).and_then(|s| s.parse().ok()).unwrap_or(0);
+    Some((
+        PrimitiveDateTime::new(date, Time::from_hms(hour, minute, second).ok()?),
+        utc,
+    ))
+}
+
+/// `PT15M`, `PT1H30M`, `P1D` and the like.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.strip_prefix('P')?;
+    let mut total = Duration::ZERO;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    'S' => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(total)
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    // Ordinals like `1MO` only apply to monthly rules.
+    match value.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-' || c == '+') {
+        "MO" => Some(Weekday::Monday),
+        "TU" => Some(Weekday::Tuesday),
+        "WE" => Some(Weekday::Wednesday),
+        "TH" => Some(Weekday::Thursday),
+        "FR" => Some(Weekday::Friday),
+        "SA" => Some(Weekday::Saturday),
+        "SU" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    const FEED: &str = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:standup\r
+SUMMARY:Daily standup\r
+DTSTART;TZID=Europe/Stockholm:20261012T091500\r
+DTEND;TZID=Europe/Stockholm:20261012T093000\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR\r
+EXDATE;TZID=Europe/Stockholm:20261014T091500\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:standup\r
+RECURRENCE-ID;TZID=Europe/Stockholm:20261015T091500\r
+SUMMARY:Daily standup (moved)\r
+DTSTART;TZID=Europe/Stockholm:20261015T100000\r
+DTEND;TZID=Europe/Stockholm:20261015T101500\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:planning\r
+SUMMARY:Sprint planning\\, team\r
+  Toki\r
+DTSTART:20261013T120000Z\r
+DURATION:PT1H30M\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:offsite\r
+SUMMARY:Offsite\r
+DTSTART;VALUE=DATE:20261016\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:cancelled\r
+SUMMARY:Cancelled\r
+STATUS:CANCELLED\r
+DTSTART:20261013T080000Z\r
+DTEND:20261013T090000Z\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn expands_weekly_meetings_with_exceptions() {
+        let feed = CalendarFeed::parse(FEED, &UserTimeZone::default());
+
+        let events = feed.events_between(utc(12, 0, 0), utc(17, 0, 0));
+        let summary: Vec<_> = events
+            .iter()
+            .map(|event| (event.title.as_str(), event.start, event.end))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("Daily standup", utc(12, 7, 15), utc(12, 7, 30)),
+                ("Daily standup", utc(13, 7, 15), utc(13, 7, 30)),
+                (
+                    "Sprint planning, team Toki",
+                    utc(13, 12, 0),
+                    utc(13, 13, 30)
+                ),
+                ("Daily standup (moved)", utc(15, 8, 0), utc(15, 8, 15)),
+                ("Daily standup", utc(16, 7, 15), utc(16, 7, 30)),
+            ]
+        );
+    }
+
+    #[test]
+    fn recurrences_stop_at_count_and_until() {
+        let feed = |rule: &str| {
+            CalendarFeed::parse(
+                &format!(
+                    "BEGIN:VEVENT\nUID:x\nSUMMARY:Sync\nDTSTART:20261012T080000Z\n\
+                     DTEND:20261012T083000Z\nRRULE:{rule}\nEND:VEVENT\n"
+                ),
+                &UserTimeZone::default(),
+            )
+            .events_between(utc(1, 0, 0), utc(31, 0, 0))
+            .len()
+        };
+
+        assert_eq!(feed("FREQ=DAILY;COUNT=3"), 3);
+        assert_eq!(feed("FREQ=DAILY;INTERVAL=2;UNTIL=20261016T080000Z"), 3);
+        assert_eq!(feed("FREQ=WEEKLY;UNTIL=20261026"), 3);
+        assert_eq!(feed("FREQ=MONTHLY"), 1);
+    }
+}
@@ -0,0 +1,148 @@
+use time::OffsetDateTime;
+
+use super::{ActivityId, CalendarEvent, ProjectId, TimeEntry};
+
+/// A meeting from the user's calendar that could be logged as a time entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarSuggestion {
+    pub uid: String,
+    pub title: String,
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+    /// Project and activity of the last entry noted with the meeting's title.
+    pub project_id: Option<ProjectId>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<ActivityId>,
+    pub activity_name: Option<String>,
+}
+
+impl CalendarSuggestion {
+    /// Suggest entries for the meetings in `events` that have ended by `now`,
+    /// aren't covered by any of `entries` and haven't been dismissed. A
+    /// dismissal is the event's uid and start.
+    pub fn suggest(
+        events: &[CalendarEvent],
+        entries: &[TimeEntry],
+        dismissed: &[(String, OffsetDateTime)],
+        now: OffsetDateTime,
+    ) -> Vec<Self> {
+        events
+            .iter()
+            .filter(|event| event.end <= now)
+            .filter(|event| {
+                !dismissed
+                    .iter()
+                    .any(|(uid, start)| *uid == event.uid && *start == event.start)
+            })
+            .filter(|event| {
+                !entries
+                    .iter()
+                    .any(|entry| match (entry.start_time, entry.end_time) {
+                        (Some(start), Some(end)) => start < event.end && end > event.start,
+                        _ => false,
+                    })
+            })
+            .map(|event| {
+                let previous = last_entry_titled(entries, &event.title);
+                Self {
+                    uid: event.uid.clone(),
+                    title: event.title.clone(),
+                    start: event.start,
+                    end: event.end,
+                    project_id: previous.map(|entry| entry.project_id.clone()),
+                    project_name: previous.map(|entry| entry.project_name.clone()),
+                    activity_id: previous.map(|entry| entry.activity_id.clone()),
+                    activity_name: previous.map(|entry| entry.activity_name.clone()),
+                }
+            })
+            .collect()
+    }
+}
+
+fn last_entry_titled<'a>(entries: &'a [TimeEntry], title: &str) -> Option<&'a TimeEntry> {
+    let title = title.trim();
+    if title.is_empty() {
+        return None;
+    }
+    entries
+        .iter()
+        .filter(|entry| {
+            entry
+                .note
+                .as_deref()
+                .is_some_and(|note| note.trim().eq_ignore_ascii_case(title))
+        })
+        .max_by_key(|entry| (entry.date, entry.start_time))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{at, timed_time_entry};
+
+    use super::*;
+
+    fn event(uid: &str, title: &str, start: OffsetDateTime, minutes: i64) -> CalendarEvent {
+        CalendarEvent {
+            uid: uid.to_string(),
+            title: title.to_string(),
+            start,
+            end: start + time::Duration::minutes(minutes),
+        }
+    }
+
+    fn entry(
+        id: &str,
+        activity: &str,
+        start: OffsetDateTime,
+        minutes: i64,
+        note: &str,
+    ) -> TimeEntry {
+        timed_time_entry(
+            id,
+            activity,
+            start,
+            start + time::Duration::minutes(minutes),
+        )
+        .with_note(note)
+    }
+
+    #[test]
+    fn suggests_unlogged_meetings_that_have_ended() {
+        let events = vec![
+            event("standup", "Standup", at(15, 7, 15), 15),
+            event("standup", "Standup", at(16, 7, 15), 15),
+            event("review", "Review", at(16, 9, 0), 60),
+            event("retro", "Retro", at(16, 13, 0), 60),
+            event("planning", "Planning", at(16, 15, 0), 60),
+        ];
+        let entries = vec![
+            entry("1", "meetings", at(14, 7, 15), 15, "standup"),
+            entry("2", "meetings", at(15, 7, 10), 20, "Standup"),
+            entry("3", "dev", at(16, 9, 30), 120, "Coding"),
+        ];
+        let dismissed = vec![("retro".to_string(), at(16, 13, 0))];
+
+        let suggestions = CalendarSuggestion::suggest(&events, &entries, &dismissed, at(16, 14, 0));
+
+        assert_eq!(suggestions.len(), 1);
+        let standup = &suggestions[0];
+        assert_eq!(
+            (standup.uid.as_str(), standup.start),
+            ("standup", at(16, 7, 15))
+        );
+        assert_eq!(
+            standup.activity_id.as_ref().map(|id| id.as_str()),
+            Some("meetings")
+        );
+    }
+
+    #[test]
+    fn leaves_project_empty_for_new_meetings() {
+        let events = vec![event("demo", "Demo", at(16, 7, 0), 30)];
+
+        let suggestions = CalendarSuggestion::suggest(&events, &[], &[], at(16, 14, 0));
+
+        assert_eq!(suggestions[0].project_id, None);
+        assert_eq!(suggestions[0].activity_name, None);
+    }
+}
@@ -0,0 +1,16 @@
+use super::{ActivityId, ProjectId};
+
+/// A named preset for a time entry a user logs often, like the daily
+/// standup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryTemplate {
+    pub id: i32,
+    pub name: String,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+    pub note: String,
+    /// Length of the entry when the template is logged directly.
+    pub duration_minutes: Option<i32>,
+}
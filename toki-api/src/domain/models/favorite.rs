@@ -0,0 +1,11 @@
+use super::{ActivityId, ProjectId};
+
+/// A project and activity a user pinned for quick selection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Favorite {
+    pub id: i32,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+}
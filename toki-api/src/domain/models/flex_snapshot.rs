@@ -0,0 +1,61 @@
+use time::{Date, Duration};
+
+/// A user's flex balance as recorded at the end of a day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlexSnapshot {
+    pub date: Date,
+    /// Flex accumulated since the start of the year, see [`flex_balance_range`].
+    pub flex_hours: f64,
+}
+
+/// The range whose period flex is the balance on `date`: the calendar year
+/// up to and including `date`.
+pub fn flex_balance_range(date: Date) -> (Date, Date) {
+    (date.replace_ordinal(1).unwrap_or(date), date)
+}
+
+/// The Sundays ending each of the `weeks` weeks before the week of `today`,
+/// oldest first. Snapshots on these days are enough to chart a trend.
+pub fn week_end_days(today: Date, weeks: u8) -> Vec<Date> {
+    let last_sunday = today - Duration::days(i64::from(today.weekday().number_days_from_sunday()));
+    (0..i64::from(weeks))
+        .rev()
+        .map(|week| last_sunday - Duration::weeks(week))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn date(month: Month, day: u8) -> Date {
+        Date::from_calendar_date(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn balance_covers_the_year_so_far() {
+        assert_eq!(
+            flex_balance_range(date(Month::October, 16)),
+            (date(Month::January, 1), date(Month::October, 16))
+        );
+    }
+
+    #[test]
+    fn week_ends_are_the_previous_sundays() {
+        // 2026-10-16 is a Friday.
+        assert_eq!(
+            week_end_days(date(Month::October, 16), 3),
+            vec![
+                date(Month::September, 27),
+                date(Month::October, 4),
+                date(Month::October, 11)
+            ]
+        );
+        assert_eq!(
+            week_end_days(date(Month::October, 11), 1),
+            vec![date(Month::October, 11)]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+use time::Date;
+
+use super::{ActivityId, ProjectId, TimeEntry};
+
+/// How day totals are rounded in an invoice report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvoiceRoundingMode {
+    /// Report hours exactly as registered.
+    None,
+    /// Round to the nearest increment.
+    #[default]
+    Nearest,
+    /// Always round up to the next increment.
+    Up,
+}
+
+impl InvoiceRoundingMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(Self::None),
+            "nearest" => Some(Self::Nearest),
+            "up" => Some(Self::Up),
+            _ => None,
+        }
+    }
+}
+
+/// Rules for turning raw time entries into invoice lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceReportRules {
+    pub rounding: InvoiceRoundingMode,
+    /// Rounding increment in minutes, applied to each activity's day total.
+    pub increment_minutes: u32,
+    /// Joins the notes of a day's entries into one line.
+    pub note_separator: String,
+    /// Drop repeated notes within a day.
+    pub dedupe_notes: bool,
+}
+
+impl Default for InvoiceReportRules {
+    fn default() -> Self {
+        Self {
+            rounding: InvoiceRoundingMode::Nearest,
+            increment_minutes: 15,
+            note_separator: "; ".to_string(),
+            dedupe_notes: true,
+        }
+    }
+}
+
+impl InvoiceReportRules {
+    pub fn round_hours(&self, hours: f64) -> f64 {
+        if self.rounding == InvoiceRoundingMode::None || self.increment_minutes == 0 {
+            return hours;
+        }
+
+        let increment = f64::from(self.increment_minutes);
+        // Entries are stored as fractional hours, so 0.25h can come back as
+        // 0.2500000001. Don't let float noise push a value up an increment.
+        let increments = hours * 60.0 / increment;
+        let increments = match self.rounding {
+            InvoiceRoundingMode::Up => (increments - 1e-9).ceil(),
+            _ => increments.round(),
+        };
+        increments.max(0.0) * increment / 60.0
+    }
+
+    fn join_notes<'a>(&self, notes: impl Iterator<Item = &'a str>) -> String {
+        let mut joined: Vec<&str> = Vec::new();
+        for note in notes.map(str::trim).filter(|note| !note.is_empty()) {
+            if self.dedupe_notes && joined.contains(&note) {
+                continue;
+            }
+            joined.push(note);
+        }
+        joined.join(&self.note_separator)
+    }
+}
+
+/// Time entries grouped project → activity → day, ready to attach to an invoice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceReport {
+    pub from: Date,
+    pub to: Date,
+    pub projects: Vec<InvoiceProject>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceProject {
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activities: Vec<InvoiceActivity>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceActivity {
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+    pub days: Vec<InvoiceDay>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceDay {
+    pub date: Date,
+    /// Rounded hours for the day.
+    pub hours: f64,
+    /// Hours as registered, before rounding.
+    pub registered_hours: f64,
+    pub notes: String,
+}
+
+impl InvoiceReport {
+    /// Group `entries` by project, activity and day. Totals are sums of the
+    /// rounded day lines, so the appendix always adds up.
+    pub fn build(
+        date_range: (Date, Date),
+        entries: &[TimeEntry],
+        rules: &InvoiceReportRules,
+    ) -> Self {
+        // Keyed by (name, id) so lines sort by name without merging
+        // different projects or activities that happen to share one.
+        type Key<'a> = (&'a str, &'a str);
+        type DayEntries<'a> = BTreeMap<Date, Vec<&'a TimeEntry>>;
+        type ActivityEntries<'a> = BTreeMap<Key<'a>, (&'a TimeEntry, DayEntries<'a>)>;
+
+        let mut grouped: BTreeMap<Key<'_>, (&TimeEntry, ActivityEntries<'_>)> = BTreeMap::new();
+        for entry in entries {
+            let (_, activities) = grouped
+                .entry((entry.project_name.as_str(), entry.project_id.as_str()))
+                .or_insert_with(|| (entry, BTreeMap::new()));
+            let (_, days) = activities
+                .entry((entry.activity_name.as_str(), entry.activity_id.as_str()))
+                .or_insert_with(|| (entry, BTreeMap::new()));
+            days.entry(entry.date).or_default().push(entry);
+        }
+
+        let projects: Vec<InvoiceProject> = grouped
+            .into_values()
+            .map(|(first, activities)| {
+                let activities: Vec<InvoiceActivity> = activities
+                    .into_values()
+                    .map(|(first, days)| {
+                        let days: Vec<InvoiceDay> = days
+                            .into_iter()
+                            .map(|(date, day_entries)| {
+                                let registered_hours: f64 =
+                                    day_entries.iter().map(|entry| entry.hours).sum();
+                                InvoiceDay {
+                                    date,
+                                    hours: rules.round_hours(registered_hours),
+                                    registered_hours,
+                                    notes: rules.join_notes(
+                                        day_entries
+                                            .iter()
+                                            .filter_map(|entry| entry.note.as_deref()),
+                                    ),
+                                }
+                            })
+                            .collect();
+                        InvoiceActivity {
+                            activity_id: first.activity_id.clone(),
+                            activity_name: first.activity_name.clone(),
+                            total_hours: days.iter().map(|day| day.hours).sum(),
+                            days,
+                        }
+                    })
+                    .collect();
+                InvoiceProject {
+                    project_id: first.project_id.clone(),
+                    project_name: first.project_name.clone(),
+                    total_hours: activities.iter().map(|activity| activity.total_hours).sum(),
+                    activities,
+                }
+            })
+            .collect();
+
+        Self {
+            from: date_range.0,
+            to: date_range.1,
+            total_hours: projects.iter().map(|project| project.total_hours).sum(),
+            projects,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{october, time_entry};
+
+    use super::*;
+
+    fn entry(project: &str, activity: &str, day: u8, hours: f64, note: Option<&str>) -> TimeEntry {
+        let id = format!("{project}-{activity}-{day}-{hours}");
+        let entry = time_entry(&id, project, activity, october(day), hours);
+        match note {
+            Some(note) => entry.with_note(note),
+            None => entry,
+        }
+    }
+
+    #[test]
+    fn rounds_to_increment() {
+        let nearest = InvoiceReportRules::default();
+        assert_eq!(nearest.round_hours(1.1), 1.0);
+        assert_eq!(nearest.round_hours(1.2), 1.25);
+        assert_eq!(nearest.round_hours(0.25000000001), 0.25);
+
+        let up = InvoiceReportRules {
+            rounding: InvoiceRoundingMode::Up,
+            ..Default::default()
+        };
+        assert_eq!(up.round_hours(1.01), 1.25);
+        assert_eq!(up.round_hours(0.25000000001), 0.25);
+
+        let none = InvoiceReportRules {
+            rounding: InvoiceRoundingMode::None,
+            ..Default::default()
+        };
+        assert_eq!(none.round_hours(1.1), 1.1);
+    }
+
+    #[test]
+    fn groups_by_project_activity_and_day() {
+        let entries = vec![
+            entry("Beta", "Dev", 2, 1.0, Some("Review")),
+            entry("Alpha", "Dev", 2, 2.1, Some("Login page")),
+            entry("Alpha", "Dev", 1, 3.0, None),
+            entry("Alpha", "Dev", 2, 1.0, Some("Login page")),
+            entry("Alpha", "Meetings", 2, 0.5, Some("Standup")),
+        ];
+
+        let report = InvoiceReport::build(
+            (october(1), october(31)),
+            &entries,
+            &InvoiceReportRules::default(),
+        );
+
+        let names: Vec<_> = report
+            .projects
+            .iter()
+            .map(|project| project.project_name.as_str())
+            .collect();
+        assert_eq!(names, ["Alpha", "Beta"]);
+
+        let alpha = &report.projects[0];
+        assert_eq!(alpha.activities.len(), 2);
+        let dev = &alpha.activities[0];
+        assert_eq!(dev.activity_name, "Dev");
+        assert_eq!(dev.days.len(), 2);
+        assert_eq!(dev.days[0].date, october(1));
+        assert_eq!(dev.days[1].registered_hours, 3.1);
+        assert_eq!(dev.days[1].hours, 3.0);
+        assert_eq!(dev.days[1].notes, "Login page");
+        assert_eq!(dev.total_hours, 6.0);
+        assert_eq!(alpha.total_hours, 6.5);
+        assert_eq!(report.total_hours, 7.5);
+    }
+
+    #[test]
+    fn joins_notes_with_configured_separator() {
+        let entries = vec![
+            entry("Alpha", "Dev", 1, 1.0, Some("Login page")),
+            entry("Alpha", "Dev", 1, 1.0, Some("  ")),
+            entry("Alpha", "Dev", 1, 1.0, Some("Login page")),
+            entry("Alpha", "Dev", 1, 1.0, Some("Logout")),
+        ];
+        let rules = InvoiceReportRules {
+            note_separator: " / ".to_string(),
+            dedupe_notes: false,
+            ..Default::default()
+        };
+
+        let report = InvoiceReport::build((october(1), october(1)), &entries, &rules);
+
+        assert_eq!(
+            report.projects[0].activities[0].days[0].notes,
+            "Login page / Login page / Logout"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use time::{Duration, OffsetDateTime, Time};
+
+use super::{TimeEntry, UserTimeZone};
+
+/// Note tag that keeps a saved timer from having lunch deducted.
+pub const NO_LUNCH_TAG: &str = "#nolunch";
+
+#[derive(Debug, thiserror::Error)]
+pub enum LunchRuleError {
+    #[error("lunch window must start before it ends")]
+    InvertedWindow,
+    #[error("deduction must be between 1 minute and the length of the lunch window")]
+    InvalidDeduction,
+}
+
+/// Per-user rule deducting lunch from timers left running over lunch.
+///
+/// A saved timer that covers the whole lunch window (in the user's time
+/// zone) has `deduction_minutes` taken off its end, unless its note contains
+/// [`NO_LUNCH_TAG`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LunchRule {
+    pub enabled: bool,
+    pub window_start: Time,
+    pub window_end: Time,
+    pub deduction_minutes: u16,
+}
+
+impl LunchRule {
+    pub fn new(
+        enabled: bool,
+        window_start: Time,
+        window_end: Time,
+        deduction_minutes: u16,
+    ) -> Result<Self, LunchRuleError> {
+        if window_start >= window_end {
+            return Err(LunchRuleError::InvertedWindow);
+        }
+        let window = window_end - window_start;
+        if deduction_minutes == 0 || Duration::minutes(i64::from(deduction_minutes)) > window {
+            return Err(LunchRuleError::InvalidDeduction);
+        }
+
+        Ok(Self {
+            enabled,
+            window_start,
+            window_end,
+            deduction_minutes,
+        })
+    }
+
+    /// Time to deduct from an entry running from `start` to `end`.
+    pub fn deduction(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        note: &str,
+        time_zone: &UserTimeZone,
+    ) -> Option<Duration> {
+        if !self.enabled || note.to_lowercase().contains(NO_LUNCH_TAG) {
+            return None;
+        }
+
+        let local_start = time_zone.to_local(start);
+        let date = local_start.date();
+        let window_start = date
+            .with_time(self.window_start)
+            .assume_offset(local_start.offset());
+        let window_end = date
+            .with_time(self.window_end)
+            .assume_offset(local_start.offset());
+
+        (start <= window_start && end >= window_end)
+            .then(|| Duration::minutes(i64::from(self.deduction_minutes)))
+    }
+}
+
+impl Default for LunchRule {
+    /// Disabled until the user opts in, deducting 30 minutes over 11:00–13:00.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_start: Time::from_hms(11, 0, 0).expect("valid time"),
+            window_end: Time::from_hms(13, 0, 0).expect("valid time"),
+            deduction_minutes: 30,
+        }
+    }
+}
+
+/// A timer saved as a time entry, with any lunch the user's rule deducted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedTimer {
+    pub entry: TimeEntry,
+    pub lunch_deduction: Option<Duration>,
+    /// Entries saved for the segments the timer ran in before it was paused,
+    /// oldest first. Empty unless the timer was resumed after a pause.
+    pub segment_entries: Vec<TimeEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn enabled_rule() -> LunchRule {
+        LunchRule {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// October 16th 2026 at `hour:minute` UTC (Stockholm is UTC+2).
+    fn utc(hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, 16)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn deducts_from_entries_spanning_the_local_lunch_window() {
+        let stockholm = UserTimeZone::default();
+
+        // 08:00-16:00 local
+        assert_eq!(
+            enabled_rule().deduction(utc(6, 0), utc(14, 0), "dev", &stockholm),
+            Some(Duration::minutes(30))
+        );
+        // 11:30-16:00 local starts after the window opens
+        assert_eq!(
+            enabled_rule().deduction(utc(9, 30), utc(14, 0), "dev", &stockholm),
+            None
+        );
+        // 10:30-13:30 UTC spans 11:00-13:00 in UTC, but not in Stockholm
+        assert_eq!(
+            enabled_rule().deduction(utc(10, 30), utc(13, 30), "dev", &stockholm),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_disabled_rules_and_tagged_entries() {
+        let stockholm = UserTimeZone::default();
+
+        assert_eq!(
+            LunchRule::default().deduction(utc(6, 0), utc(14, 0), "dev", &stockholm),
+            None
+        );
+        assert_eq!(
+            enabled_rule().deduction(utc(6, 0), utc(14, 0), "Workshop #NoLunch", &stockholm),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_deductions_longer_than_the_window() {
+        let eleven = Time::from_hms(11, 0, 0).unwrap();
+        let noon = Time::from_hms(12, 0, 0).unwrap();
+
+        assert!(matches!(
+            LunchRule::new(true, eleven, noon, 90),
+            Err(LunchRuleError::InvalidDeduction)
+        ));
+        assert!(matches!(
+            LunchRule::new(true, noon, eleven, 30),
+            Err(LunchRuleError::InvertedWindow)
+        ));
+        assert!(LunchRule::new(true, eleven, noon, 60).is_ok());
+    }
+}
@@ -0,0 +1,112 @@
+use time::Date;
+
+use super::{TimeEntry, TimeEntryDayStatus};
+
+/// A day in the user's work calendar at the time tracking provider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduledDay {
+    pub date: Date,
+    pub scheduled_hours: f64,
+    /// Vacation, sick leave and other absence registered that day.
+    pub absence_hours: f64,
+}
+
+impl ScheduledDay {
+    /// Scheduled hours not covered by absence.
+    pub fn working_hours(&self) -> f64 {
+        (self.scheduled_hours - self.absence_hours).max(0.0)
+    }
+}
+
+/// A workday with nothing logged on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissingWorkday {
+    pub date: Date,
+    /// Hours the day needs to be filled with.
+    pub hours: f64,
+}
+
+/// Days in `schedule` with working hours left after absence and no time
+/// logged in `entries`, oldest first. Weekends and holidays have no
+/// scheduled hours, so they are never missing, and days `statuses` has as
+/// attested can't be filled anymore.
+pub fn missing_workdays(
+    schedule: &[ScheduledDay],
+    entries: &[TimeEntry],
+    statuses: &[TimeEntryDayStatus],
+) -> Vec<MissingWorkday> {
+    let mut missing: Vec<MissingWorkday> = schedule
+        .iter()
+        .filter(|day| day.working_hours() > 0.0)
+        .filter(|day| {
+            !statuses
+                .iter()
+                .any(|status| status.date == day.date && status.status.is_locked())
+        })
+        .filter(|day| {
+            !entries
+                .iter()
+                .any(|entry| entry.date == day.date && entry.hours > 0.0)
+        })
+        .map(|day| MissingWorkday {
+            date: day.date,
+            hours: day.working_hours(),
+        })
+        .collect();
+    missing.sort_by_key(|day| day.date);
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+    use crate::domain::models::TimeEntryStatus;
+
+    fn date(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    fn scheduled(day: u8, scheduled_hours: f64, absence_hours: f64) -> ScheduledDay {
+        ScheduledDay {
+            date: date(day),
+            scheduled_hours,
+            absence_hours,
+        }
+    }
+
+    #[test]
+    fn lists_workdays_without_logged_time() {
+        let schedule = [
+            scheduled(14, 8.0, 0.0),
+            scheduled(12, 8.0, 0.0),
+            scheduled(13, 8.0, 8.0),
+            scheduled(15, 8.0, 4.0),
+            scheduled(17, 0.0, 0.0),
+        ];
+        let entries = [TimeEntry::new(
+            "1",
+            "p1",
+            "Toki",
+            "a1",
+            "Development",
+            date(14),
+            7.5,
+        )];
+
+        assert_eq!(
+            missing_workdays(&schedule, &entries),
+            vec![
+                MissingWorkday {
+                    date: date(12),
+                    hours: 8.0
+                },
+                MissingWorkday {
+                    date: date(15),
+                    hours: 4.0
+                },
+            ]
+        );
+    }
+}
@@ -1,13 +1,63 @@
 mod avatar;
+mod board_snapshot;
+mod calendar_event;
+mod calendar_suggestion;
+mod entry_template;
+mod favorite;
+mod flex_snapshot;
 mod ids;
+mod invoice_report;
+mod lunch_rule;
+mod missing_workday;
+mod note_improvement;
+mod paused_timer;
 mod project;
+mod project_budget;
+mod project_teams;
+mod sprint_capacity;
+mod time_entry_anomaly;
+mod time_entry_batch;
+mod time_entry_export;
+mod time_entry_import;
+mod time_entry_merge;
+mod time_rounding;
 mod time_tracking_user;
 mod timer;
+mod timer_auto_stop;
+mod user_time_zone;
+mod wip_limit;
 mod work_item;
+mod work_item_time;
+mod work_item_time_mapping;
 
 pub use avatar::*;
+pub use board_snapshot::*;
+pub use calendar_event::*;
+pub use calendar_suggestion::*;
+pub use entry_template::*;
+pub use favorite::*;
+pub use flex_snapshot::*;
 pub use ids::*;
+pub use invoice_report::*;
+pub use lunch_rule::*;
+pub use missing_workday::*;
+pub use note_improvement::*;
+pub use paused_timer::*;
 pub use project::*;
+pub use project_budget::*;
+pub use project_teams::*;
+pub use sprint_capacity::*;
+pub use time_entry_anomaly::*;
+pub use time_entry_batch::*;
+pub use time_entry_export::*;
+pub use time_entry_import::*;
+pub use time_entry_merge::*;
+pub use time_rounding::*;
 pub use time_tracking_user::*;
 pub use timer::*;
+pub use timer_auto_stop::*;
+pub use user_time_zone::*;
+pub use wip_limit::*;
 pub use work_item::*;
+pub use work_item_time::*;
+pub use work_item_time_mapping::*;
@@ -0,0 +1,104 @@
+/// Longest note accepted for improvement, in characters.
+pub const MAX_NOTE_CHARS: usize = 500;
+/// Work item context beyond this many characters is cut off before prompting.
+pub const MAX_CONTEXT_CHARS: usize = 6000;
+
+/// Instructions given to the language model for every note.
+pub const NOTE_IMPROVEMENT_INSTRUCTIONS: &str = "You rewrite terse time entry notes written by \
+software consultants into clear notes suitable for a client invoice. Keep the meaning and every \
+work item reference (such as #1234 or AB#1234), do not invent work that is not mentioned, write \
+in the same language as the note, and keep it to one sentence of at most 200 characters. Reply \
+with the note only.";
+
+/// A note to improve, with optional work item details as Markdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteImprovementRequest {
+    pub note: String,
+    pub work_item_context: Option<String>,
+}
+
+impl NoteImprovementRequest {
+    pub fn new(note: impl Into<String>) -> Self {
+        Self {
+            note: note.into(),
+            work_item_context: None,
+        }
+    }
+
+    pub fn with_work_item_context(mut self, context: impl Into<String>) -> Self {
+        self.work_item_context = Some(context.into());
+        self
+    }
+
+    /// The user prompt sent along with [`NOTE_IMPROVEMENT_INSTRUCTIONS`].
+    pub fn prompt(&self) -> String {
+        let note = self.note.trim();
+        match self
+            .work_item_context
+            .as_deref()
+            .map(str::trim)
+            .filter(|context| !context.is_empty())
+        {
+            Some(context) => {
+                let context: String = context.chars().take(MAX_CONTEXT_CHARS).collect();
+                format!("Work item the note refers to:\n\n{context}\n\n---\n\nNote: {note}")
+            }
+            None => format!("Note: {note}"),
+        }
+    }
+}
+
+/// Turn a raw model reply into a single-line note, or `None` if nothing is left.
+///
+/// Models tend to wrap replies in quotes or echo the `Note:` label back, so
+/// both are stripped.
+pub fn clean_note_suggestion(reply: &str) -> Option<String> {
+    let line = reply.lines().map(str::trim).find(|line| !line.is_empty())?;
+    let line = line
+        .strip_prefix("Note:")
+        .or_else(|| line.strip_prefix("note:"))
+        .unwrap_or(line)
+        .trim();
+    let line = line
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .unwrap_or(line)
+        .trim();
+
+    (!line.is_empty()).then(|| line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_includes_trimmed_work_item_context() {
+        let request = NoteImprovementRequest::new(" fix login #12 ")
+            .with_work_item_context("# Login fails on Safari");
+
+        assert_eq!(
+            request.prompt(),
+            "Work item the note refers to:\n\n# Login fails on Safari\n\n---\n\nNote: fix login #12"
+        );
+        assert_eq!(
+            NoteImprovementRequest::new("fix")
+                .with_work_item_context("  ")
+                .prompt(),
+            "Note: fix"
+        );
+    }
+
+    #[test]
+    fn cleans_quoted_and_labelled_replies() {
+        assert_eq!(
+            clean_note_suggestion("\n\"Fixed login redirect on Safari (#12)\"\n"),
+            Some("Fixed login redirect on Safari (#12)".to_string())
+        );
+        assert_eq!(
+            clean_note_suggestion("Note: Reviewed PR for billing export"),
+            Some("Reviewed PR for billing export".to_string())
+        );
+        assert_eq!(clean_note_suggestion("  \n \"\" "), None);
+    }
+}
@@ -0,0 +1,136 @@
+use time::{Duration, OffsetDateTime};
+
+use super::{ActiveTimer, ActivityId, ProjectId};
+
+/// Segments this close together are saved as one registration.
+pub const SEGMENT_STITCH_GAP: Duration = Duration::minutes(1);
+
+/// A stretch of time a timer ran, from being started or resumed until it
+/// was paused or saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerSegment {
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+impl TimerSegment {
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        Self { start, end }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// A timer put aside to track an interruption.
+///
+/// Paused timers form a stack per user. Resuming one continues it as the
+/// active timer, and saving that timer stitches the segments it ran in into
+/// registrations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PausedTimer {
+    pub id: i32,
+    pub project_id: Option<ProjectId>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<ActivityId>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    /// Oldest first.
+    pub segments: Vec<TimerSegment>,
+    pub paused_at: OffsetDateTime,
+}
+
+impl PausedTimer {
+    /// Time tracked over all segments.
+    pub fn tracked(&self) -> Duration {
+        self.segments.iter().map(TimerSegment::duration).sum()
+    }
+
+    /// The timer continued as an active timer from `now`.
+    pub fn resume(&self, now: OffsetDateTime) -> ActiveTimer {
+        ActiveTimer {
+            started_at: now,
+            project_id: self.project_id.clone(),
+            project_name: self.project_name.clone(),
+            activity_id: self.activity_id.clone(),
+            activity_name: self.activity_name.clone(),
+            note: self.note.clone(),
+        }
+    }
+}
+
+/// The registrations to save for a timer's segments, oldest first.
+///
+/// Segments are sorted, and those overlapping or less than
+/// [`SEGMENT_STITCH_GAP`] apart are joined. A timer paused for a moment and
+/// resumed is saved as one registration, one paused over an interruption as
+/// one registration on each side of it.
+pub fn stitch_segments(segments: &[TimerSegment]) -> Vec<TimerSegment> {
+    let mut sorted = segments.to_vec();
+    sorted.sort_by_key(|segment| segment.start);
+
+    let mut stitched: Vec<TimerSegment> = Vec::with_capacity(sorted.len());
+    for segment in sorted {
+        match stitched.last_mut() {
+            Some(last) if segment.start - last.end < SEGMENT_STITCH_GAP => {
+                last.end = last.end.max(segment.end);
+            }
+            _ => stitched.push(segment),
+        }
+    }
+    stitched
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn at(hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, 16)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn segment(start: (u8, u8), end: (u8, u8)) -> TimerSegment {
+        TimerSegment::new(at(start.0, start.1), at(end.0, end.1))
+    }
+
+    #[test]
+    fn stitches_segments_without_an_interruption_between_them() {
+        let stitched = stitch_segments(&[
+            segment((13, 0), (14, 0)),
+            segment((8, 0), (9, 30)),
+            segment((9, 30), (10, 0)),
+        ]);
+
+        assert_eq!(
+            stitched,
+            vec![segment((8, 0), (10, 0)), segment((13, 0), (14, 0))]
+        );
+    }
+
+    #[test]
+    fn resuming_keeps_the_timer_and_starts_a_new_segment() {
+        let paused = PausedTimer {
+            id: 1,
+            project_id: Some(ProjectId::new("p1")),
+            project_name: Some("Toki".to_string()),
+            activity_id: Some(ActivityId::new("a1")),
+            activity_name: Some("Development".to_string()),
+            note: "Login".to_string(),
+            segments: vec![segment((8, 0), (9, 30)), segment((10, 0), (10, 15))],
+            paused_at: at(10, 15),
+        };
+
+        assert_eq!(paused.tracked(), Duration::minutes(105));
+        let resumed = paused.resume(at(11, 0));
+        assert_eq!(resumed.started_at, at(11, 0));
+        assert_eq!(resumed.project_name.as_deref(), Some("Toki"));
+        assert_eq!(resumed.note, "Login");
+    }
+}
@@ -0,0 +1,156 @@
+use time::{util::days_in_year_month, Date, Duration};
+
+use super::{ProjectId, TimeEntry};
+
+/// Share of a budget used before it is flagged as about to be exceeded.
+pub const BUDGET_WARNING_RATIO: f64 = 0.9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "budget_period", rename_all = "lowercase")]
+pub enum BudgetPeriod {
+    Week,
+    Month,
+}
+
+impl BudgetPeriod {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "week" => Some(Self::Week),
+            "month" => Some(Self::Month),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    /// First and last day of the period containing `date`. Weeks start on
+    /// Monday.
+    pub fn range(self, date: Date) -> (Date, Date) {
+        match self {
+            Self::Week => {
+                let start =
+                    date - Duration::days(i64::from(date.weekday().number_days_from_monday()));
+                (start, start + Duration::days(6))
+            }
+            Self::Month => {
+                let last_day = days_in_year_month(date.year(), date.month());
+                (
+                    date.replace_day(1).expect("every month has a first day"),
+                    date.replace_day(last_day)
+                        .expect("days_in_year_month is a valid day"),
+                )
+            }
+        }
+    }
+}
+
+/// Hours a user aims to spend on a project per week or month.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectBudget {
+    pub id: i32,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub period: BudgetPeriod,
+    pub target_hours: f64,
+}
+
+/// How much of a budget the current period has used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectBudgetStatus {
+    pub budget: ProjectBudget,
+    pub from: Date,
+    pub to: Date,
+    pub used_hours: f64,
+}
+
+impl ProjectBudgetStatus {
+    /// Status of `budget` for the period containing `today`, counting the
+    /// hours of `entries` on the budget's project within the period.
+    pub fn new(budget: ProjectBudget, today: Date, entries: &[TimeEntry]) -> Self {
+        let (from, to) = budget.period.range(today);
+        let used_hours = entries
+            .iter()
+            .filter(|entry| entry.project_id == budget.project_id)
+            .filter(|entry| (from..=to).contains(&entry.date))
+            .map(|entry| entry.hours)
+            .sum();
+
+        Self {
+            budget,
+            from,
+            to,
+            used_hours,
+        }
+    }
+
+    /// Used share of the budget, where 1.0 means fully used.
+    pub fn consumption(&self) -> f64 {
+        self.used_hours / self.budget.target_hours
+    }
+
+    pub fn is_nearly_used(&self) -> bool {
+        self.consumption() >= BUDGET_WARNING_RATIO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn date(month: Month, day: u8) -> Date {
+        Date::from_calendar_date(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn periods_cover_the_week_and_month_of_the_date() {
+        // 2026-10-16 is a Friday.
+        assert_eq!(
+            BudgetPeriod::Week.range(date(Month::October, 16)),
+            (date(Month::October, 12), date(Month::October, 18))
+        );
+        assert_eq!(
+            BudgetPeriod::Month.range(date(Month::February, 10)),
+            (date(Month::February, 1), date(Month::February, 28))
+        );
+    }
+
+    #[test]
+    fn counts_hours_on_the_project_within_the_period() {
+        let budget = ProjectBudget {
+            id: 1,
+            project_id: ProjectId::new("p1"),
+            project_name: "Toki".to_string(),
+            period: BudgetPeriod::Week,
+            target_hours: 10.0,
+        };
+        let entry = |project: &str, day: u8, hours: f64| {
+            TimeEntry::new(
+                "r",
+                project,
+                project,
+                "a1",
+                "Dev",
+                date(Month::October, day),
+                hours,
+            )
+        };
+        let entries = vec![
+            entry("p1", 12, 4.0),
+            entry("p1", 16, 5.0),
+            entry("p2", 16, 8.0),
+            entry("p1", 9, 3.0),
+        ];
+
+        let status = ProjectBudgetStatus::new(budget, date(Month::October, 16), &entries);
+
+        assert_eq!(status.used_hours, 9.0);
+        assert!(status.is_nearly_used());
+    }
+}
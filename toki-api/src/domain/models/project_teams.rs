@@ -0,0 +1,26 @@
+/// The teams of a work item project and which one its board uses when a
+/// request does not name a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectTeams {
+    pub teams: Vec<String>,
+    /// Team picked by auto-detection.
+    pub detected_team: String,
+    /// Team chosen by hand, which wins over the detected one.
+    pub override_team: Option<String>,
+}
+
+impl ProjectTeams {
+    /// The team boards are loaded for by default.
+    pub fn default_team(&self) -> &str {
+        self.override_team.as_deref().unwrap_or(&self.detected_team)
+    }
+
+    /// Look up a team by name, ignoring case, returning its canonical name.
+    pub fn find_team(&self, name: &str) -> Option<&str> {
+        let name = name.trim();
+        self.teams
+            .iter()
+            .find(|team| team.eq_ignore_ascii_case(name))
+            .map(String::as_str)
+    }
+}
@@ -0,0 +1,156 @@
+use time::{Date, Duration, Weekday};
+
+use super::TimeEntry;
+
+/// Planned capacity of a team member in a sprint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintMemberCapacity {
+    pub display_name: String,
+    /// Usually the member's email.
+    pub unique_name: Option<String>,
+    /// Hours per working day, over all of the member's activities.
+    pub capacity_per_day: f64,
+    /// Inclusive (first, last) days off.
+    pub days_off: Vec<(Date, Date)>,
+}
+
+/// A team sprint and the capacity planned for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintCapacityPlan {
+    pub iteration_name: String,
+    pub iteration_path: String,
+    pub start_date: Date,
+    pub finish_date: Date,
+    pub members: Vec<SprintMemberCapacity>,
+    /// Inclusive (first, last) days off for the whole team.
+    pub team_days_off: Vec<(Date, Date)>,
+}
+
+impl SprintCapacityPlan {
+    /// The member whose unique name is `email`, ignoring case.
+    pub fn member(&self, email: &str) -> Option<&SprintMemberCapacity> {
+        self.members.iter().find(|member| {
+            member
+                .unique_name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(email))
+        })
+    }
+
+    /// Hours `member` can work in the sprint: their capacity per day times
+    /// the weekdays that are neither team nor personal days off.
+    pub fn capacity_hours(&self, member: &SprintMemberCapacity) -> f64 {
+        let is_off = |date: Date| {
+            self.team_days_off
+                .iter()
+                .chain(&member.days_off)
+                .any(|(first, last)| (*first..=*last).contains(&date))
+        };
+
+        let mut working_days = 0;
+        let mut date = self.start_date;
+        while date <= self.finish_date {
+            if !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) && !is_off(date) {
+                working_days += 1;
+            }
+            date += Duration::days(1);
+        }
+        member.capacity_per_day * f64::from(working_days)
+    }
+}
+
+/// A user's planned capacity in a sprint against the hours they logged in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintCapacity {
+    pub iteration_name: String,
+    pub iteration_path: String,
+    pub start_date: Date,
+    pub finish_date: Date,
+    /// `None` when the user has no capacity set in the sprint.
+    pub capacity_hours: Option<f64>,
+    pub logged_hours: f64,
+}
+
+impl SprintCapacity {
+    /// Capacity of the member `email` in `plan`, and the hours of `entries`
+    /// within the sprint's dates.
+    pub fn new(plan: &SprintCapacityPlan, email: &str, entries: &[TimeEntry]) -> Self {
+        let capacity_hours = plan.member(email).map(|member| plan.capacity_hours(member));
+        let logged_hours = entries
+            .iter()
+            .filter(|entry| (plan.start_date..=plan.finish_date).contains(&entry.date))
+            .map(|entry| entry.hours)
+            .sum();
+
+        Self {
+            iteration_name: plan.iteration_name.clone(),
+            iteration_path: plan.iteration_path.clone(),
+            start_date: plan.start_date,
+            finish_date: plan.finish_date,
+            capacity_hours,
+            logged_hours,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn october(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    /// Two weeks from Monday the 5th to Friday the 16th.
+    fn plan() -> SprintCapacityPlan {
+        SprintCapacityPlan {
+            iteration_name: "Sprint 42".to_string(),
+            iteration_path: "Toki\\Sprint 42".to_string(),
+            start_date: october(5),
+            finish_date: october(16),
+            members: vec![SprintMemberCapacity {
+                display_name: "Dev Eloper".to_string(),
+                unique_name: Some("dev@example.com".to_string()),
+                capacity_per_day: 6.0,
+                days_off: vec![(october(8), october(9))],
+            }],
+            team_days_off: vec![(october(12), october(12))],
+        }
+    }
+
+    #[test]
+    fn capacity_skips_weekends_and_days_off() {
+        let plan = plan();
+        let member = plan.member("DEV@example.com").unwrap();
+
+        // 10 weekdays, two personal days off and one team day off.
+        assert_eq!(plan.capacity_hours(member), 42.0);
+        assert!(plan.member("someone@example.com").is_none());
+    }
+
+    #[test]
+    fn logged_hours_count_entries_within_the_sprint() {
+        let entry = |day: u8, hours: f64| {
+            TimeEntry::new(
+                "reg",
+                "p1",
+                "Toki",
+                "a1",
+                "Development",
+                october(day),
+                hours,
+            )
+        };
+        let entries = [entry(2, 8.0), entry(5, 7.5), entry(16, 4.0), entry(19, 8.0)];
+
+        let capacity = SprintCapacity::new(&plan(), "dev@example.com", &entries);
+        assert_eq!(capacity.capacity_hours, Some(42.0));
+        assert_eq!(capacity.logged_hours, 11.5);
+
+        let outsider = SprintCapacity::new(&plan(), "someone@example.com", &entries);
+        assert_eq!(outsider.capacity_hours, None);
+        assert_eq!(outsider.logged_hours, 11.5);
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use time::{Date, Time};
+
+use super::{TimeEntry, UserTimeZone};
+
+/// Thresholds for flagging suspicious time entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyRules {
+    /// Days with more hours than this are flagged.
+    pub max_day_hours: f64,
+    /// Entries starting before this local time are flagged.
+    pub earliest_start: Time,
+    /// Entries ending after this local time are flagged.
+    pub latest_end: Time,
+    /// A note used on at least this many days is flagged as copy-pasted.
+    pub repeated_note_min_days: usize,
+    /// Activity ids or names (case-insensitive) that must have a note.
+    pub note_required_activities: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeEntryAnomalyKind {
+    LongDay,
+    OutsideWorkingHours,
+    RepeatedNote,
+    MissingNote,
+}
+
+impl TimeEntryAnomalyKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::LongDay => "long_day",
+            Self::OutsideWorkingHours => "outside_working_hours",
+            Self::RepeatedNote => "repeated_note",
+            Self::MissingNote => "missing_note",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeEntryAnomaly {
+    pub kind: TimeEntryAnomalyKind,
+    pub date: Date,
+    /// Entries involved, so clients can highlight them.
+    pub registration_ids: Vec<String>,
+    pub message: String,
+}
+
+impl AnomalyRules {
+    /// Flag anomalies in `entries`, ordered by date and kind. Working hours
+    /// are checked in the user's `time_zone`.
+    pub fn detect(&self, entries: &[TimeEntry], time_zone: &UserTimeZone) -> Vec<TimeEntryAnomaly> {
+        let mut anomalies = Vec::new();
+        self.detect_long_days(entries, &mut anomalies);
+        self.detect_outside_working_hours(entries, time_zone, &mut anomalies);
+        self.detect_repeated_notes(entries, &mut anomalies);
+        self.detect_missing_notes(entries, &mut anomalies);
+
+        anomalies.sort_by(|a, b| a.date.cmp(&b.date).then(a.kind.cmp(&b.kind)));
+        anomalies
+    }
+
+    fn detect_long_days(&self, entries: &[TimeEntry], anomalies: &mut Vec<TimeEntryAnomaly>) {
+        let mut days: BTreeMap<Date, Vec<&TimeEntry>> = BTreeMap::new();
+        for entry in entries {
+            days.entry(entry.date).or_default().push(entry);
+        }
+
+        for (date, day_entries) in days {
+            let hours: f64 = day_entries.iter().map(|entry| entry.hours).sum();
+            if hours > self.max_day_hours {
+                anomalies.push(TimeEntryAnomaly {
+                    kind: TimeEntryAnomalyKind::LongDay,
+                    date,
+                    registration_ids: registration_ids(&day_entries),
+                    message: format!(
+                        "{hours:.2}h logged on {date}, more than {}h",
+                        self.max_day_hours
+                    ),
+                });
+            }
+        }
+    }
+
+    fn detect_outside_working_hours(
+        &self,
+        entries: &[TimeEntry],
+        time_zone: &UserTimeZone,
+        anomalies: &mut Vec<TimeEntryAnomaly>,
+    ) {
+        for entry in entries {
+            let (Some(start), Some(end)) = (entry.start_time, entry.end_time) else {
+                continue;
+            };
+            let start = time_zone.to_local(start);
+            let end = time_zone.to_local(end);
+
+            let outside = start.time() < self.earliest_start
+                || end.date() > start.date()
+                || end.time() > self.latest_end;
+            if outside {
+                anomalies.push(TimeEntryAnomaly {
+                    kind: TimeEntryAnomalyKind::OutsideWorkingHours,
+                    date: entry.date,
+                    registration_ids: vec![entry.registration_id.clone()],
+                    message: format!(
+                        "{} / {} runs {}–{}, outside {}–{}",
+                        entry.project_name,
+                        entry.activity_name,
+                        hh_mm(start.time()),
+                        hh_mm(end.time()),
+                        hh_mm(self.earliest_start),
+                        hh_mm(self.latest_end),
+                    ),
+                });
+            }
+        }
+    }
+
+    fn detect_repeated_notes(&self, entries: &[TimeEntry], anomalies: &mut Vec<TimeEntryAnomaly>) {
+        if self.repeated_note_min_days == 0 {
+            return;
+        }
+
+        let mut by_note: BTreeMap<&str, Vec<&TimeEntry>> = BTreeMap::new();
+        for entry in entries {
+            if let Some(note) = note_of(entry) {
+                by_note.entry(note).or_default().push(entry);
+            }
+        }
+
+        for (note, note_entries) in by_note {
+            let days: BTreeSet<Date> = note_entries.iter().map(|entry| entry.date).collect();
+            if days.len() < self.repeated_note_min_days {
+                continue;
+            }
+            let Some(last_day) = days.last() else {
+                continue;
+            };
+            anomalies.push(TimeEntryAnomaly {
+                kind: TimeEntryAnomalyKind::RepeatedNote,
+                date: *last_day,
+                registration_ids: registration_ids(&note_entries),
+                message: format!("Note \"{note}\" is used on {} days", days.len()),
+            });
+        }
+    }
+
+    fn detect_missing_notes(&self, entries: &[TimeEntry], anomalies: &mut Vec<TimeEntryAnomaly>) {
+        for entry in entries {
+            if note_of(entry).is_some() || !self.requires_note(entry) {
+                continue;
+            }
+            anomalies.push(TimeEntryAnomaly {
+                kind: TimeEntryAnomalyKind::MissingNote,
+                date: entry.date,
+                registration_ids: vec![entry.registration_id.clone()],
+                message: format!(
+                    "{} / {} requires a note",
+                    entry.project_name, entry.activity_name
+                ),
+            });
+        }
+    }
+
+    fn requires_note(&self, entry: &TimeEntry) -> bool {
+        self.note_required_activities.iter().any(|activity| {
+            activity == entry.activity_id.as_str()
+                || activity.eq_ignore_ascii_case(&entry.activity_name)
+        })
+    }
+}
+
+fn note_of(entry: &TimeEntry) -> Option<&str> {
+    entry
+        .note
+        .as_deref()
+        .map(str::trim)
+        .filter(|note| !note.is_empty())
+}
+
+fn registration_ids(entries: &[&TimeEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| entry.registration_id.clone())
+        .collect()
+}
+
+fn hh_mm(time: Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{at, october, time_entry};
+
+    use super::*;
+
+    fn rules() -> AnomalyRules {
+        AnomalyRules {
+            max_day_hours: 12.0,
+            earliest_start: Time::from_hms(6, 0, 0).unwrap(),
+            latest_end: Time::from_hms(22, 0, 0).unwrap(),
+            repeated_note_min_days: 3,
+            note_required_activities: vec!["Support".to_string()],
+        }
+    }
+
+    fn detect(entries: &[TimeEntry]) -> Vec<TimeEntryAnomaly> {
+        // UTC+1 all year, so the times below don't depend on DST.
+        rules().detect(entries, &UserTimeZone::parse("Etc/GMT-1").unwrap())
+    }
+
+    fn entry(id: &str, day: u8, hours: f64, activity: &str, note: &str) -> TimeEntry {
+        time_entry(id, "Project", activity, october(day), hours).with_note(note)
+    }
+
+    fn kinds(anomalies: &[TimeEntryAnomaly]) -> Vec<TimeEntryAnomalyKind> {
+        anomalies.iter().map(|anomaly| anomaly.kind).collect()
+    }
+
+    #[test]
+    fn flags_days_over_max_hours() {
+        let anomalies = detect(&[
+            entry("a", 12, 8.0, "Dev", "Login"),
+            entry("b", 12, 4.5, "Dev", "Logout"),
+            entry("c", 13, 12.0, "Dev", "Signup"),
+        ]);
+
+        assert_eq!(kinds(&anomalies), [TimeEntryAnomalyKind::LongDay]);
+        assert_eq!(anomalies[0].date, october(12));
+        assert_eq!(anomalies[0].registration_ids, ["a", "b"]);
+    }
+
+    #[test]
+    fn flags_entries_outside_local_working_hours() {
+        let anomalies = detect(&[
+            // 05:30–07:00 local
+            entry("early", 12, 1.5, "Dev", "Deploy")
+                .with_times(Some(at(12, 4, 30)), Some(at(12, 6, 0))),
+            // 20:00–22:00 local, ends exactly at the limit
+            entry("late", 13, 2.0, "Dev", "Hotfix")
+                .with_times(Some(at(13, 19, 0)), Some(at(13, 21, 0))),
+            // 23:00–01:00 local, crosses midnight
+            entry("night", 14, 2.0, "Dev", "Incident")
+                .with_times(Some(at(14, 22, 0)), Some(at(15, 0, 0))),
+            entry("no-times", 15, 2.0, "Dev", "Review"),
+        ]);
+
+        let flagged: Vec<_> = anomalies
+            .iter()
+            .map(|anomaly| anomaly.registration_ids[0].as_str())
+            .collect();
+        assert_eq!(flagged, ["early", "night"]);
+        assert_eq!(
+            anomalies[0].message,
+            "Project / Dev runs 05:30–07:00, outside 06:00–22:00"
+        );
+    }
+
+    #[test]
+    fn flags_notes_repeated_on_many_days() {
+        let anomalies = detect(&[
+            entry("a", 12, 1.0, "Dev", "Development"),
+            entry("b", 12, 1.0, "Dev", "Development"),
+            entry("c", 13, 1.0, "Dev", " Development "),
+            entry("d", 14, 1.0, "Dev", "Development"),
+            entry("e", 14, 1.0, "Dev", "PROJ-1"),
+            entry("f", 15, 1.0, "Dev", "PROJ-1"),
+        ]);
+
+        assert_eq!(kinds(&anomalies), [TimeEntryAnomalyKind::RepeatedNote]);
+        assert_eq!(anomalies[0].date, october(14));
+        assert_eq!(
+            anomalies[0].message,
+            "Note \"Development\" is used on 3 days"
+        );
+    }
+
+    #[test]
+    fn flags_missing_notes_on_note_required_activities() {
+        let anomalies = detect(&[
+            entry("a", 12, 1.0, "support", "  "),
+            entry("b", 12, 1.0, "Support", "Customer call"),
+            entry("c", 12, 1.0, "Dev", ""),
+        ]);
+
+        assert_eq!(kinds(&anomalies), [TimeEntryAnomalyKind::MissingNote]);
+        assert_eq!(anomalies[0].registration_ids, ["a"]);
+    }
+}
@@ -0,0 +1,158 @@
+use super::{ActivityId, EditTimeEntryRequest, ProjectId, TimeEntry, TimeEntryStatus};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TimeEntryBatchError {
+    #[error("no entries given")]
+    Empty,
+    #[error("entry {0} not found")]
+    NotFound(String),
+    #[error("entry {0} is attested and can't be changed")]
+    Locked(String),
+    #[error("entry {0} has no start and end time")]
+    MissingTimes(String),
+}
+
+/// What to do with every entry of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeEntryBatchChange {
+    Delete,
+    /// Move the entries to another project and activity, keeping their
+    /// times and notes.
+    Reassign {
+        project_id: ProjectId,
+        project_name: String,
+        activity_id: ActivityId,
+        activity_name: String,
+    },
+}
+
+/// The provider calls that apply a [`TimeEntryBatchChange`].
+#[derive(Debug, Clone)]
+pub enum TimeEntryBatch {
+    Delete(Vec<String>),
+    Edit(Vec<EditTimeEntryRequest>),
+}
+
+impl TimeEntryBatch {
+    /// Plan applying `change` to the entries `registration_ids` out of
+    /// `entries`. Every entry is checked up front, so a batch with an
+    /// attested or unknown entry changes nothing. Repeated ids are ignored.
+    pub fn plan(
+        entries: &[TimeEntry],
+        registration_ids: &[String],
+        change: &TimeEntryBatchChange,
+    ) -> Result<Self, TimeEntryBatchError> {
+        let mut selected: Vec<&TimeEntry> = Vec::with_capacity(registration_ids.len());
+        for id in registration_ids {
+            if selected.iter().any(|entry| &entry.registration_id == id) {
+                continue;
+            }
+            let entry = entries
+                .iter()
+                .find(|entry| &entry.registration_id == id)
+                .ok_or_else(|| TimeEntryBatchError::NotFound(id.clone()))?;
+            if entry.status != TimeEntryStatus::Open {
+                return Err(TimeEntryBatchError::Locked(id.clone()));
+            }
+            selected.push(entry);
+        }
+        if selected.is_empty() {
+            return Err(TimeEntryBatchError::Empty);
+        }
+
+        match change {
+            TimeEntryBatchChange::Delete => Ok(Self::Delete(
+                selected
+                    .iter()
+                    .map(|entry| entry.registration_id.clone())
+                    .collect(),
+            )),
+            TimeEntryBatchChange::Reassign {
+                project_id,
+                project_name,
+                activity_id,
+                activity_name,
+            } => selected
+                .iter()
+                .map(|entry| {
+                    let (Some(start_time), Some(end_time)) = (entry.start_time, entry.end_time)
+                    else {
+                        return Err(TimeEntryBatchError::MissingTimes(
+                            entry.registration_id.clone(),
+                        ));
+                    };
+                    Ok(EditTimeEntryRequest {
+                        registration_id: entry.registration_id.clone(),
+                        project_id: project_id.clone(),
+                        project_name: project_name.clone(),
+                        activity_id: activity_id.clone(),
+                        activity_name: activity_name.clone(),
+                        start_time,
+                        end_time,
+                        note: entry.note.clone().unwrap_or_default(),
+                    })
+                })
+                .collect::<Result<_, _>>()
+                .map(Self::Edit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{at, ids, timed_time_entry};
+
+    use super::*;
+
+    fn entry(id: &str, day: u8) -> TimeEntry {
+        timed_time_entry(id, "a1", at(day, 8, 0), at(day, 10, 0)).with_note("Login")
+    }
+
+    fn reassign() -> TimeEntryBatchChange {
+        TimeEntryBatchChange::Reassign {
+            project_id: ProjectId::new("p2"),
+            project_name: "Acme".to_string(),
+            activity_id: ActivityId::new("a2"),
+            activity_name: "Support".to_string(),
+        }
+    }
+
+    #[test]
+    fn reassigns_entries_keeping_times_and_notes() {
+        let entries = [entry("1", 14), entry("2", 15), entry("3", 16)];
+
+        let TimeEntryBatch::Edit(edits) =
+            TimeEntryBatch::plan(&entries, &ids(&["3", "1", "3"]), &reassign()).unwrap()
+        else {
+            panic!("expected edits");
+        };
+
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].registration_id, "3");
+        assert_eq!(edits[0].project_name, "Acme");
+        assert_eq!(edits[0].activity_id, ActivityId::new("a2"));
+        assert_eq!(edits[0].start_time, at(16, 8, 0));
+        assert_eq!(edits[1].note, "Login");
+    }
+
+    #[test]
+    fn rejects_the_whole_batch_on_one_bad_entry() {
+        let mut attested = entry("2", 15);
+        attested.status = TimeEntryStatus::Approved;
+        let entries = [entry("1", 14), attested];
+
+        assert_eq!(
+            TimeEntryBatch::plan(&entries, &ids(&["1", "2"]), &TimeEntryBatchChange::Delete)
+                .unwrap_err(),
+            TimeEntryBatchError::Locked("2".to_string())
+        );
+        assert_eq!(
+            TimeEntryBatch::plan(&entries, &ids(&["1", "9"]), &reassign()).unwrap_err(),
+            TimeEntryBatchError::NotFound("9".to_string())
+        );
+        assert_eq!(
+            TimeEntryBatch::plan(&entries, &[], &TimeEntryBatchChange::Delete).unwrap_err(),
+            TimeEntryBatchError::Empty
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use time::Date;
+
+use super::{TimeEntry, UserTimeZone};
+
+/// File format of a time entry export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeEntryExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl TimeEntryExportFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "csv" => Some(Self::Csv),
+            "xlsx" => Some(Self::Xlsx),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Xlsx => "xlsx",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportRowKind {
+    Header,
+    Entry,
+    /// Total of one project/activity group.
+    Subtotal,
+    Total,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportCell {
+    Text(String),
+    Hours(f64),
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportRow {
+    pub kind: ExportRowKind,
+    pub cells: Vec<ExportCell>,
+}
+
+/// Time entries laid out as a table, grouped by project and activity with a
+/// subtotal after each group and a grand total at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeEntryExport {
+    pub from: Date,
+    pub to: Date,
+    pub rows: Vec<ExportRow>,
+}
+
+const HEADER: [&str; 7] = [
+    "Project", "Activity", "Date", "Start", "End", "Hours", "Note",
+];
+
+impl TimeEntryExport {
+    /// Build the export. Start and end times are shown in the user's
+    /// `time_zone` when known.
+    pub fn new(
+        date_range: (Date, Date),
+        mut entries: Vec<TimeEntry>,
+        time_zone: &UserTimeZone,
+    ) -> Self {
+        entries.sort_by(|a, b| {
+            a.project_name
+                .cmp(&b.project_name)
+                .then_with(|| a.activity_name.cmp(&b.activity_name))
+                .then(a.date.cmp(&b.date))
+                .then(a.start_time.cmp(&b.start_time))
+        });
+
+        let mut rows = vec![ExportRow {
+            kind: ExportRowKind::Header,
+            cells: HEADER
+                .iter()
+                .map(|title| ExportCell::Text(title.to_string()))
+                .collect(),
+        }];
+        let mut total = 0.0;
+        for group in entries
+            .chunk_by(|a, b| a.project_name == b.project_name && a.activity_name == b.activity_name)
+        {
+            for entry in group {
+                let time = |instant: Option<time::OffsetDateTime>| {
+                    instant.map_or(ExportCell::Empty, |instant| {
+                        let local = time_zone.to_local(instant);
+                        ExportCell::Text(format!("{:02}:{:02}", local.hour(), local.minute()))
+                    })
+                };
+                rows.push(ExportRow {
+                    kind: ExportRowKind::Entry,
+                    cells: vec![
+                        ExportCell::Text(entry.project_name.clone()),
+                        ExportCell::Text(entry.activity_name.clone()),
+                        ExportCell::Text(entry.date.to_string()),
+                        time(entry.start_time),
+                        time(entry.end_time),
+                        ExportCell::Hours(entry.hours),
+                        entry
+                            .note
+                            .clone()
+                            .map_or(ExportCell::Empty, ExportCell::Text),
+                    ],
+                });
+            }
+
+            let hours: f64 = group.iter().map(|entry| entry.hours).sum();
+            total += hours;
+            rows.push(ExportRow {
+                kind: ExportRowKind::Subtotal,
+                cells: vec![
+                    ExportCell::Text(group[0].project_name.clone()),
+                    ExportCell::Text(group[0].activity_name.clone()),
+                    ExportCell::Empty,
+                    ExportCell::Empty,
+                    ExportCell::Empty,
+                    ExportCell::Hours(hours),
+                    ExportCell::Text("Subtotal".to_string()),
+                ],
+            });
+        }
+
+        rows.push(ExportRow {
+            kind: ExportRowKind::Total,
+            cells: vec![
+                ExportCell::Text("Total".to_string()),
+                ExportCell::Empty,
+                ExportCell::Empty,
+                ExportCell::Empty,
+                ExportCell::Empty,
+                ExportCell::Hours(total),
+                ExportCell::Empty,
+            ],
+        });
+
+        Self {
+            from: date_range.0,
+            to: date_range.1,
+            rows,
+        }
+    }
+
+    /// File name for a download of the export.
+    pub fn file_name(&self, format: TimeEntryExportFormat) -> String {
+        format!(
+            "time-entries-{}-{}.{}",
+            self.from,
+            self.to,
+            format.extension()
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in &self.rows {
+            let line = row
+                .cells
+                .iter()
+                .map(|cell| match cell {
+                    ExportCell::Text(text) => csv_field(text),
+                    ExportCell::Hours(hours) => format!("{hours:.2}"),
+                    ExportCell::Empty => String::new(),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            csv.push_str(&line);
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    // Spreadsheets run cells starting with these as formulas.
+    let text = if text.starts_with(['=', '+', '-', '@']) {
+        format!("'{text}")
+    } else {
+        text.to_string()
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{october, time_entry};
+
+    use super::*;
+
+    fn entry(project: &str, activity: &str, day: u8, hours: f64, note: &str) -> TimeEntry {
+        let id = format!("{project}-{activity}-{day}");
+        time_entry(&id, project, activity, october(day), hours).with_note(note)
+    }
+
+    #[test]
+    fn groups_entries_with_subtotals_and_a_total() {
+        let entries = vec![
+            entry("Toki", "Support", 13, 1.0, "Call"),
+            entry("Acme", "Development", 14, 2.5, "API"),
+            entry("Toki", "Development", 12, 6.0, "Login"),
+            entry("Toki", "Development", 13, 1.5, "Fix, again"),
+        ];
+
+        let export = TimeEntryExport::new(
+            (october(12), october(16)),
+            entries,
+            &UserTimeZone::default(),
+        );
+        let kinds: Vec<_> = export.rows.iter().map(|row| row.kind).collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                ExportRowKind::Header,
+                ExportRowKind::Entry,
+                ExportRowKind::Subtotal,
+                ExportRowKind::Entry,
+                ExportRowKind::Entry,
+                ExportRowKind::Subtotal,
+                ExportRowKind::Entry,
+                ExportRowKind::Subtotal,
+                ExportRowKind::Total,
+            ]
+        );
+        assert_eq!(export.rows[5].cells[5], ExportCell::Hours(7.5));
+        assert_eq!(export.rows[8].cells[5], ExportCell::Hours(11.0));
+        assert_eq!(
+            export.file_name(TimeEntryExportFormat::Xlsx),
+            "time-entries-2026-10-12-2026-10-16.xlsx"
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields_that_need_it() {
+        let export = TimeEntryExport::new(
+            (october(13), october(13)),
+            vec![entry("Toki", "Development", 13, 1.5, "Fix, \"again\"")],
+            &UserTimeZone::default(),
+        );
+
+        let csv = export.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+
+        assert_eq!(lines[0], "Project,Activity,Date,Start,End,Hours,Note");
+        assert_eq!(
+            lines[1],
+            "Toki,Development,2026-10-13,,,1.50,\"Fix, \"\"again\"\"\""
+        );
+        assert_eq!(lines[2], "Toki,Development,,,,1.50,Subtotal");
+        assert_eq!(lines[3], "Total,,,,,1.50,");
+    }
+
+    #[test]
+    fn csv_escapes_cells_that_look_like_formulas() {
+        let export = TimeEntryExport::new(
+            (october(13), october(13)),
+            vec![
+                entry("@Toki", "Development", 13, 1.0, "=HYPERLINK(\"x\")"),
+                entry("Toki", "Development", 13, 0.5, "- fixed login"),
+            ],
+            &UserTimeZone::default(),
+        );
+
+        let csv = export.to_csv();
+        let lines: Vec<_> = csv.lines().collect();
+
+        assert_eq!(
+            lines[1],
+            "'@Toki,Development,2026-10-13,,,1.00,\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(
+            lines[3],
+            "Toki,Development,2026-10-13,,,0.50,'- fixed login"
+        );
+    }
+}
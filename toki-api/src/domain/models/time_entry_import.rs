@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use time::{format_description::well_known::Iso8601, Date, Duration, OffsetDateTime, Time};
+
+use super::UserTimeZone;
+
+/// Imported entries on a day are laid out back to back from this local hour,
+/// unless something is already registered later.
+const DAY_START_HOUR: u8 = 8;
+
+/// One row of a time entry CSV: `date,project,activity,hours,note`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeEntryImportRow {
+    pub date: Date,
+    /// Project id, name or code.
+    pub project: String,
+    /// Activity id or name.
+    pub activity: String,
+    pub hours: f64,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TimeEntryImportError {
+    #[error("expected 5 columns (date, project, activity, hours, note), found {0}")]
+    ColumnCount(usize),
+    #[error("unterminated quoted field")]
+    UnterminatedQuote,
+    #[error("invalid date '{0}', expected YYYY-MM-DD")]
+    InvalidDate(String),
+    #[error("invalid hours '{0}'")]
+    InvalidHours(String),
+    #[error("missing {0}")]
+    MissingField(&'static str),
+    #[error("entries on {0} run past midnight")]
+    DayFull(Date),
+}
+
+/// A parsed CSV line, with its 1-based line number for the import report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedImportRow {
+    pub line: usize,
+    pub row: Result<TimeEntryImportRow, TimeEntryImportError>,
+}
+
+/// Parse a time entry CSV. Fields are separated by `,`, or by `;` as
+/// spreadsheets in comma-decimal locales export, and may be quoted. A leading
+/// header row and blank lines are skipped.
+pub fn parse_time_entry_csv(csv: &str) -> Vec<ParsedImportRow> {
+    let delimiter = detect_delimiter(csv);
+    csv.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(index, line)| !(*index == 0 && is_header(line, delimiter)))
+        .map(|(index, line)| ParsedImportRow {
+            line: index + 1,
+            row: parse_row(line, delimiter),
+        })
+        .collect()
+}
+
+fn detect_delimiter(csv: &str) -> char {
+    let first = csv
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("");
+    if first.matches(';').count() > first.matches(',').count() {
+        ';'
+    } else {
+        ','
+    }
+}
+
+fn is_header(line: &str, delimiter: char) -> bool {
+    split_fields(line, delimiter)
+        .ok()
+        .and_then(|fields| fields.into_iter().next())
+        .is_some_and(|field| field.eq_ignore_ascii_case("date"))
+}
+
+fn parse_row(line: &str, delimiter: char) -> Result<TimeEntryImportRow, TimeEntryImportError> {
+    let fields = split_fields(line, delimiter)?;
+    let [date, project, activity, hours, note] = <[String; 5]>::try_from(fields)
+        .map_err(|fields| TimeEntryImportError::ColumnCount(fields.len()))?;
+
+    let date =
+        Date::parse(&date, &Iso8601::DATE).map_err(|_| TimeEntryImportError::InvalidDate(date))?;
+    if project.is_empty() {
+        return Err(TimeEntryImportError::MissingField("project"));
+    }
+    if activity.is_empty() {
+        return Err(TimeEntryImportError::MissingField("activity"));
+    }
+    let hours = match hours.replace(',', ".").parse::<f64>() {
+        Ok(value) if value > 0.0 && value <= 24.0 => value,
+        _ => return Err(TimeEntryImportError::InvalidHours(hours)),
+    };
+
+    Ok(TimeEntryImportRow {
+        date,
+        project,
+        activity,
+        hours,
+        note,
+    })
+}
+
+/// Split a CSV line into trimmed fields. `""` inside a quoted field is a
+/// literal quote.
+fn split_fields(line: &str, delimiter: char) -> Result<Vec<String>, TimeEntryImportError> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            c if c == delimiter && !quoted => {
+                fields.push(field.trim().to_string());
+                field.clear();
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(TimeEntryImportError::UnterminatedQuote);
+    }
+    fields.push(field.trim().to_string());
+    Ok(fields)
+}
+
+/// Places imported entries on their days, after whatever is already there.
+#[derive(Debug, Clone)]
+pub struct ImportSchedule {
+    time_zone: UserTimeZone,
+    next_start: HashMap<Date, OffsetDateTime>,
+}
+
+impl ImportSchedule {
+    /// `existing` holds the end times of entries already registered.
+    pub fn new(
+        time_zone: UserTimeZone,
+        existing: impl IntoIterator<Item = OffsetDateTime>,
+    ) -> Self {
+        let mut schedule = Self {
+            time_zone,
+            next_start: HashMap::new(),
+        };
+        for end in existing {
+            let date = time_zone.date_of(end);
+            let next = schedule.next_start(date).max(end);
+            schedule.next_start.insert(date, next);
+        }
+        schedule
+    }
+
+    /// Start and end time for `row`, right after the previous entry that day.
+    pub fn place(
+        &mut self,
+        row: &TimeEntryImportRow,
+    ) -> Result<(OffsetDateTime, OffsetDateTime), TimeEntryImportError> {
+        let start = self.next_start(row.date);
+        let end = start + Duration::seconds_f64(row.hours * 3600.0);
+        if self.time_zone.date_of(end - Duration::SECOND) != row.date {
+            return Err(TimeEntryImportError::DayFull(row.date));
+        }
+        self.next_start.insert(row.date, end);
+        Ok((start, end))
+    }
+
+    fn next_start(&self, date: Date) -> OffsetDateTime {
+        self.next_start.get(&date).copied().unwrap_or_else(|| {
+            let day_start = Time::from_hms(DAY_START_HOUR, 0, 0).expect("valid hour");
+            let local = date.with_time(day_start);
+            local.assume_offset(self.time_zone.offset_at(local.assume_utc()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn october(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    fn row(day: u8, hours: f64) -> TimeEntryImportRow {
+        TimeEntryImportRow {
+            date: october(day),
+            project: "Toki".to_string(),
+            activity: "Development".to_string(),
+            hours,
+            note: String::new(),
+        }
+    }
+
+    #[test]
+    fn parses_rows_and_skips_header_and_blank_lines() {
+        let csv = "date,project,activity,hours,note\n\
+                   2026-10-12,Toki,Development,7.5,\"Fix login, again\"\n\
+                   \n\
+                   2026-10-13, Toki , Support ,1,\"Said \"\"hi\"\"\"\n";
+
+        let rows = parse_time_entry_csv(csv);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        let first = rows[0].row.as_ref().unwrap();
+        assert_eq!(first.date, october(12));
+        assert_eq!(first.hours, 7.5);
+        assert_eq!(first.note, "Fix login, again");
+        assert_eq!(rows[1].line, 4);
+        let second = rows[1].row.as_ref().unwrap();
+        assert_eq!(second.activity, "Support");
+        assert_eq!(second.note, "Said \"hi\"");
+    }
+
+    #[test]
+    fn reads_semicolon_separated_rows_with_decimal_commas() {
+        let rows = parse_time_entry_csv("2026-10-12;Toki;Development;7,5;Planning\n");
+
+        assert_eq!(rows[0].row.as_ref().unwrap().hours, 7.5);
+    }
+
+    #[test]
+    fn reports_invalid_rows() {
+        let csv = "2026-10-12,Toki,Development,7.5\n\
+                   12/10/2026,Toki,Development,1,\n\
+                   2026-10-12,Toki,Development,0,\n\
+                   2026-10-12,,Development,1,\n\
+                   2026-10-12,Toki,Development,1,\"open\n";
+
+        let errors: Vec<_> = parse_time_entry_csv(csv)
+            .into_iter()
+            .map(|parsed| parsed.row.unwrap_err())
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                TimeEntryImportError::ColumnCount(4),
+                TimeEntryImportError::InvalidDate("12/10/2026".to_string()),
+                TimeEntryImportError::InvalidHours("0".to_string()),
+                TimeEntryImportError::MissingField("project"),
+                TimeEntryImportError::UnterminatedQuote,
+            ]
+        );
+    }
+
+    #[test]
+    fn schedule_places_entries_back_to_back_after_existing_ones() {
+        let time_zone = UserTimeZone::default();
+        // 09:30 local (CEST) on the 12th.
+        let existing = october(12).with_hms(7, 30, 0).unwrap().assume_utc();
+        let mut schedule = ImportSchedule::new(time_zone, [existing]);
+
+        let (start, end) = schedule.place(&row(12, 2.0)).unwrap();
+        assert_eq!(start, existing);
+        assert_eq!(end, existing + Duration::hours(2));
+        assert_eq!(schedule.place(&row(12, 1.0)).unwrap().0, end);
+
+        // 08:00 local on a day with nothing registered.
+        let (start, _) = schedule.place(&row(13, 8.0)).unwrap();
+        assert_eq!(start, october(13).with_hms(6, 0, 0).unwrap().assume_utc());
+        assert_eq!(
+            schedule.place(&row(13, 10.0)),
+            Err(TimeEntryImportError::DayFull(october(13)))
+        );
+    }
+}
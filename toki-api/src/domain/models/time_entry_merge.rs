@@ -0,0 +1,165 @@
+use time::Duration;
+
+use super::{EditTimeEntryRequest, TimeEntry, TimeEntryStatus};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TimeEntryMergeError {
+    #[error("at least two entries are needed to merge")]
+    TooFew,
+    #[error("entry {0} not found")]
+    NotFound(String),
+    #[error("only entries on the same project and activity can be merged")]
+    DifferentActivities,
+    #[error("entry {0} is attested and can't be changed")]
+    Locked(String),
+    #[error("entry {0} has no start and end time")]
+    MissingTimes(String),
+    #[error("entry {0} lies between the entries to merge")]
+    NotAdjacent(String),
+}
+
+/// How to merge entries on one day into a single registration: the earliest
+/// entry is edited to cover them all and the rest are deleted.
+#[derive(Debug, Clone)]
+pub struct TimeEntryMerge {
+    pub edit: EditTimeEntryRequest,
+    pub delete: Vec<String>,
+}
+
+impl TimeEntryMerge {
+    /// Plan merging the entries `registration_ids` out of `day_entries`, all
+    /// entries of their day. The entries have to follow each other without
+    /// other entries in between. The merged entry starts with the first and
+    /// lasts as long as all of them together, so gaps between them are not
+    /// counted. Notes are joined in order, skipping repeats.
+    pub fn plan(
+        day_entries: &[TimeEntry],
+        registration_ids: &[String],
+    ) -> Result<Self, TimeEntryMergeError> {
+        if registration_ids.len() < 2 {
+            return Err(TimeEntryMergeError::TooFew);
+        }
+
+        let mut selected = Vec::with_capacity(registration_ids.len());
+        for id in registration_ids {
+            let entry = day_entries
+                .iter()
+                .find(|entry| &entry.registration_id == id)
+                .ok_or_else(|| TimeEntryMergeError::NotFound(id.clone()))?;
+            if entry.status != TimeEntryStatus::Open {
+                return Err(TimeEntryMergeError::Locked(id.clone()));
+            }
+            let (Some(start), Some(end)) = (entry.start_time, entry.end_time) else {
+                return Err(TimeEntryMergeError::MissingTimes(id.clone()));
+            };
+            selected.push((entry, start, end));
+        }
+        selected.sort_by_key(|(_, start, _)| *start);
+        selected.dedup_by(|a, b| a.0.registration_id == b.0.registration_id);
+        if selected.len() < 2 {
+            return Err(TimeEntryMergeError::TooFew);
+        }
+
+        let (first, first_start, _) = selected[0];
+        if selected.iter().any(|(entry, _, _)| {
+            entry.project_id != first.project_id || entry.activity_id != first.activity_id
+        }) {
+            return Err(TimeEntryMergeError::DifferentActivities);
+        }
+
+        let last_start = selected[selected.len() - 1].1;
+        if let Some(between) = day_entries.iter().find(|entry| {
+            !selected
+                .iter()
+                .any(|(s, _, _)| s.registration_id == entry.registration_id)
+                && entry
+                    .start_time
+                    .is_some_and(|start| start > first_start && start < last_start)
+        }) {
+            return Err(TimeEntryMergeError::NotAdjacent(
+                between.registration_id.clone(),
+            ));
+        }
+
+        let duration: Duration = selected.iter().map(|(_, start, end)| *end - *start).sum();
+        let mut notes: Vec<&str> = Vec::new();
+        for (entry, _, _) in &selected {
+            let note = entry.note.as_deref().unwrap_or_default().trim();
+            if !note.is_empty() && !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+
+        Ok(Self {
+            edit: EditTimeEntryRequest {
+                registration_id: first.registration_id.clone(),
+                project_id: first.project_id.clone(),
+                project_name: first.project_name.clone(),
+                activity_id: first.activity_id.clone(),
+                activity_name: first.activity_name.clone(),
+                start_time: first_start,
+                end_time: first_start + duration,
+                note: notes.join("; "),
+            },
+            delete: selected[1..]
+                .iter()
+                .map(|(entry, _, _)| entry.registration_id.clone())
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{at, ids, timed_time_entry};
+
+    use super::*;
+
+    fn entry(id: &str, activity: &str, start: (u8, u8), end: (u8, u8), note: &str) -> TimeEntry {
+        timed_time_entry(id, activity, at(16, start.0, start.1), at(16, end.0, end.1))
+            .with_note(note)
+    }
+
+    #[test]
+    fn merges_into_the_earliest_entry() {
+        let day = vec![
+            entry("2", "a1", (10, 0), (11, 30), "Review"),
+            entry("1", "a1", (8, 0), (9, 45), "Login"),
+            entry("3", "a1", (13, 0), (14, 0), "Login"),
+        ];
+
+        let merge = TimeEntryMerge::plan(&day, &ids(&["3", "1", "2"])).unwrap();
+
+        assert_eq!(merge.edit.registration_id, "1");
+        assert_eq!(merge.edit.start_time, at(16, 8, 0));
+        assert_eq!(merge.edit.end_time, at(16, 12, 15));
+        assert_eq!(merge.edit.note, "Login; Review");
+        assert_eq!(merge.delete, ids(&["2", "3"]));
+    }
+
+    #[test]
+    fn rejects_entries_that_cannot_be_merged() {
+        let day = vec![
+            entry("1", "a1", (8, 0), (9, 0), ""),
+            entry("2", "a2", (9, 0), (10, 0), ""),
+            entry("3", "a1", (10, 0), (11, 0), ""),
+        ];
+
+        assert_eq!(
+            TimeEntryMerge::plan(&day, &ids(&["1"])).unwrap_err(),
+            TimeEntryMergeError::TooFew
+        );
+        assert_eq!(
+            TimeEntryMerge::plan(&day, &ids(&["1", "2"])).unwrap_err(),
+            TimeEntryMergeError::DifferentActivities
+        );
+        assert_eq!(
+            TimeEntryMerge::plan(&day, &ids(&["1", "3"])).unwrap_err(),
+            TimeEntryMergeError::NotAdjacent("2".to_string())
+        );
+        assert_eq!(
+            TimeEntryMerge::plan(&day, &ids(&["1", "9"])).unwrap_err(),
+            TimeEntryMergeError::NotFound("9".to_string())
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use time::OffsetDateTime;
+use toki_types::{round_entry_times, ROUNDING_INCREMENTS};
+
+use super::UserTimeZone;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TimeRoundingError {
+    #[error("rounding must be to 5, 15 or 30 minutes")]
+    InvalidIncrement,
+}
+
+/// Rounding of saved start and end times to the nearest increment on the
+/// user's clock, so registrations match how customers are invoiced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRounding {
+    increment_minutes: u16,
+}
+
+impl TimeRounding {
+    pub const INCREMENTS: [u16; 3] = ROUNDING_INCREMENTS;
+
+    pub fn new(increment_minutes: u16) -> Result<Self, TimeRoundingError> {
+        if !Self::INCREMENTS.contains(&increment_minutes) {
+            return Err(TimeRoundingError::InvalidIncrement);
+        }
+
+        Ok(Self { increment_minutes })
+    }
+
+    pub fn increment_minutes(&self) -> u16 {
+        self.increment_minutes
+    }
+
+    /// `start` and `end` rounded to the nearest increment, halfway rounding
+    /// up. An entry that would round away to nothing lasts one increment.
+    pub fn round(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        time_zone: &UserTimeZone,
+    ) -> (OffsetDateTime, OffsetDateTime) {
+        round_entry_times(start, end, self.increment_minutes, |instant| {
+            time_zone.to_local(instant)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn at(hour: u8, minute: u8, second: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, 14)
+            .unwrap()
+            .with_hms(hour, minute, second)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn rounds_to_the_nearest_increment_on_the_users_clock() {
+        let stockholm = UserTimeZone::default();
+        let rounding = TimeRounding::new(15).unwrap();
+
+        // 08:07:30 and 16:52:29 in Stockholm (UTC+2).
+        let (start, end) = rounding.round(at(6, 7, 30), at(14, 52, 29), &stockholm);
+        assert_eq!(start, at(6, 15, 0));
+        assert_eq!(end, at(14, 45, 0));
+
+        // 08:45 in Kathmandu (UTC+5:45) rounds to 09:00 there, not to 03:00 UTC.
+        let kathmandu = UserTimeZone::parse("Asia/Kathmandu").unwrap();
+        let (start, _) = TimeRounding::new(30)
+            .unwrap()
+            .round(at(3, 0, 0), at(5, 0, 0), &kathmandu);
+        assert_eq!(start, at(3, 15, 0));
+    }
+
+    #[test]
+    fn only_known_increments_are_valid() {
+        assert!(TimeRounding::new(5).is_ok());
+        assert!(TimeRounding::new(0).is_err());
+        assert!(TimeRounding::new(10).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use time::{Date, Duration, OffsetDateTime};
 
-use super::{ActivityId, ProjectId, TimerHistoryId, UserId};
+use super::{ActivityId, ProjectId, TimerHistoryId, UserId, UserTimeZone};
 
 /// A currently running timer.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -69,6 +69,13 @@ pub enum TimeEntryStatus {
     Certified,
 }
 
+impl TimeEntryStatus {
+    /// Attested entries and days can't be changed anymore.
+    pub fn is_locked(self) -> bool {
+        self != Self::Open
+    }
+}
+
 /// Date-level attestation status for time entry creation/editing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TimeEntryDayStatus {
@@ -76,6 +83,15 @@ pub struct TimeEntryDayStatus {
     pub status: TimeEntryStatus,
 }
 
+/// The first of `dates` that `statuses` has as locked.
+pub fn first_locked_day(statuses: &[TimeEntryDayStatus], dates: &[Date]) -> Option<Date> {
+    dates.iter().copied().find(|date| {
+        statuses
+            .iter()
+            .any(|day| day.date == *date && day.status.is_locked())
+    })
+}
+
 /// A completed time entry.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TimeEntry {
@@ -91,6 +107,8 @@ pub struct TimeEntry {
     pub end_time: Option<OffsetDateTime>,
     pub week_number: u8,
     pub status: TimeEntryStatus,
+    /// Work item the entry was explicitly linked to when it was saved.
+    pub work_item_id: Option<String>,
 }
 
 impl TimeEntry {
@@ -116,6 +134,7 @@ impl TimeEntry {
             end_time: None,
             week_number: 0,
             status: TimeEntryStatus::Open,
+            work_item_id: None,
         }
     }
 
@@ -143,6 +162,27 @@ impl TimeEntry {
         self.status = status;
         self
     }
+
+    pub fn with_work_item(mut self, work_item_id: Option<String>) -> Self {
+        self.work_item_id = work_item_id;
+        self
+    }
+
+    /// A new entry like this one on `date`, at the same time of day in
+    /// `time_zone`. `None` for entries without start and end times.
+    pub fn copy_to(&self, date: Date, time_zone: &UserTimeZone) -> Option<CreateTimeEntryRequest> {
+        let (start_time, end_time) = (self.start_time?, self.end_time?);
+        let start_time = time_zone.at(date.with_time(time_zone.to_local(start_time).time()));
+        Some(CreateTimeEntryRequest {
+            project_id: self.project_id.clone(),
+            project_name: self.project_name.clone(),
+            activity_id: self.activity_id.clone(),
+            activity_name: self.activity_name.clone(),
+            start_time,
+            end_time: start_time + (end_time - self.start_time?),
+            note: self.note.clone().unwrap_or_default(),
+        })
+    }
 }
 
 /// Time tracking statistics for a period.
@@ -195,6 +235,35 @@ pub struct EditTimeEntryRequest {
     pub note: String,
 }
 
+impl EditTimeEntryRequest {
+    /// Split the entry at `at`: the entry itself is shortened to end there
+    /// and a new one with the same project, activity and note covers the
+    /// rest. `None` unless `at` falls strictly inside the entry.
+    pub fn split_at(
+        &self,
+        at: OffsetDateTime,
+    ) -> Option<(EditTimeEntryRequest, CreateTimeEntryRequest)> {
+        if at <= self.start_time || at >= self.end_time {
+            return None;
+        }
+
+        let first = EditTimeEntryRequest {
+            end_time: at,
+            ..self.clone()
+        };
+        let second = CreateTimeEntryRequest {
+            project_id: self.project_id.clone(),
+            project_name: self.project_name.clone(),
+            activity_id: self.activity_id.clone(),
+            activity_name: self.activity_name.clone(),
+            start_time: at,
+            end_time: self.end_time,
+            note: self.note.clone(),
+        };
+        Some((first, second))
+    }
+}
+
 /// A local timer history entry (stored in our database).
 ///
 /// This tracks start/end times for time entries that may also exist
@@ -212,6 +281,8 @@ pub struct TimerHistoryEntry {
     pub activity_id: Option<ActivityId>,
     pub activity_name: Option<String>,
     pub note: Option<String>,
+    /// Work item the entry was explicitly linked to when it was saved.
+    pub work_item_id: Option<String>,
     pub created_at: OffsetDateTime,
 }
 
@@ -233,6 +304,7 @@ impl TimerHistoryEntry {
             activity_id: None,
             activity_name: None,
             note: None,
+            work_item_id: None,
             created_at,
         }
     }
@@ -263,6 +335,11 @@ impl TimerHistoryEntry {
         self.note = Some(note.into());
         self
     }
+
+    pub fn with_work_item(mut self, work_item_id: impl Into<String>) -> Self {
+        self.work_item_id = Some(work_item_id.into());
+        self
+    }
 }
 
 /// Data for creating a new finished timer history entry.
@@ -277,4 +354,93 @@ pub struct NewTimerHistoryEntry {
     pub activity_id: Option<ActivityId>,
     pub activity_name: Option<String>,
     pub note: String,
+    pub work_item_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Duration, Month};
+
+    use super::*;
+
+    #[test]
+    fn split_at_divides_the_entry_in_two() {
+        let start = Date::from_calendar_date(2026, Month::October, 16)
+            .unwrap()
+            .with_hms(8, 0, 0)
+            .unwrap()
+            .assume_utc();
+        let request = EditTimeEntryRequest {
+            registration_id: "42".to_string(),
+            project_id: ProjectId::new("p1"),
+            project_name: "Toki".to_string(),
+            activity_id: ActivityId::new("a1"),
+            activity_name: "Development".to_string(),
+            start_time: start,
+            end_time: start + Duration::hours(4),
+            note: "Login".to_string(),
+        };
+
+        let (first, second) = request.split_at(start + Duration::hours(1)).unwrap();
+
+        assert_eq!(first.registration_id, "42");
+        assert_eq!(first.end_time, start + Duration::hours(1));
+        assert_eq!(second.start_time, start + Duration::hours(1));
+        assert_eq!(second.end_time, start + Duration::hours(4));
+        assert_eq!(second.note, "Login");
+        assert!(request.split_at(start).is_none());
+        assert!(request.split_at(start + Duration::hours(5)).is_none());
+    }
+
+    #[test]
+    fn copy_to_keeps_the_local_time_of_day() {
+        let stockholm = UserTimeZone::default();
+        // 07:00 UTC is 09:00 in Stockholm in October (CEST) and 08:00 in
+        // November (CET).
+        let start = Date::from_calendar_date(2026, Month::October, 16)
+            .unwrap()
+            .with_hms(7, 0, 0)
+            .unwrap()
+            .assume_utc();
+        let entry = TimeEntry::new("42", "p1", "Toki", "a1", "Standup", start.date(), 0.25)
+            .with_note("Daily")
+            .with_times(Some(start), Some(start + Duration::minutes(15)));
+        let monday = Date::from_calendar_date(2026, Month::November, 2).unwrap();
+
+        let copy = entry.copy_to(monday, &stockholm).unwrap();
+
+        assert_eq!(
+            copy.start_time,
+            monday.with_hms(8, 0, 0).unwrap().assume_utc()
+        );
+        assert_eq!(copy.end_time - copy.start_time, Duration::minutes(15));
+        assert_eq!(stockholm.date_of(copy.start_time), monday);
+        assert_eq!(copy.note, "Daily");
+        assert!(
+            TimeEntry::new("43", "p1", "Toki", "a1", "Standup", monday, 1.0)
+                .copy_to(monday, &stockholm)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn first_locked_day_skips_open_and_unknown_days() {
+        let date = |day| Date::from_calendar_date(2026, Month::October, day).unwrap();
+        let statuses = [
+            TimeEntryDayStatus {
+                date: date(12),
+                status: TimeEntryStatus::Open,
+            },
+            TimeEntryDayStatus {
+                date: date(13),
+                status: TimeEntryStatus::Approved,
+            },
+        ];
+
+        assert_eq!(first_locked_day(&statuses, &[date(12), date(14)]), None);
+        assert_eq!(
+            first_locked_day(&statuses, &[date(12), date(13)]),
+            Some(date(13))
+        );
+    }
 }
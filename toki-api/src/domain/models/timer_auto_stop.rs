@@ -0,0 +1,170 @@
+use time::{Duration, OffsetDateTime, Time};
+
+use super::UserTimeZone;
+
+/// What happens to a timer stopped by a [`TimerAutoStop`] rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimerAutoStopAction {
+    /// Save the timer as an entry ending at the stop time.
+    #[default]
+    Save,
+    /// Throw the timer away without saving anything.
+    Discard,
+}
+
+impl TimerAutoStopAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Save => "save",
+            Self::Discard => "discard",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Save, Self::Discard]
+            .into_iter()
+            .find(|action| action.as_str() == value)
+    }
+}
+
+/// Per-user rule stopping a timer still running at `stop_at` on the user's
+/// clock, so a timer forgotten in the evening doesn't run through the night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerAutoStop {
+    pub enabled: bool,
+    pub stop_at: Time,
+    pub action: TimerAutoStopAction,
+}
+
+impl TimerAutoStop {
+    /// When a timer started at `started_at` is stopped: the first `stop_at`
+    /// after it started, in the user's time zone.
+    pub fn stop_time(
+        &self,
+        started_at: OffsetDateTime,
+        time_zone: &UserTimeZone,
+    ) -> OffsetDateTime {
+        let date = time_zone.date_of(started_at);
+        let stop = time_zone.at(date.with_time(self.stop_at));
+        if stop > started_at {
+            stop
+        } else {
+            time_zone.at((date + Duration::days(1)).with_time(self.stop_at))
+        }
+    }
+
+    /// The stop time of a timer started at `started_at`, if the rule is on
+    /// and the stop time has passed at `now`.
+    pub fn due(
+        &self,
+        started_at: OffsetDateTime,
+        now: OffsetDateTime,
+        time_zone: &UserTimeZone,
+    ) -> Option<OffsetDateTime> {
+        if !self.enabled {
+            return None;
+        }
+
+        let stop = self.stop_time(started_at, time_zone);
+        (stop <= now).then_some(stop)
+    }
+}
+
+impl Default for TimerAutoStop {
+    /// Disabled until the user opts in, saving timers still running at 23:59.
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            stop_at: Time::from_hms(23, 59, 0).expect("valid time"),
+            action: TimerAutoStopAction::Save,
+        }
+    }
+}
+
+/// A timer stopped by the user's [`TimerAutoStop`] rule, kept until a client
+/// has told the user about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoStoppedTimer {
+    pub action: TimerAutoStopAction,
+    pub started_at: OffsetDateTime,
+    pub stopped_at: OffsetDateTime,
+    pub project_name: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    /// The saved entry, when the timer was saved.
+    pub registration_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn enabled_rule() -> TimerAutoStop {
+        TimerAutoStop {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// October `day` 2026 at `hour:minute` UTC (Stockholm is UTC+2).
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn stops_at_the_first_stop_time_on_the_users_clock() {
+        let stockholm = UserTimeZone::default();
+
+        // Started 08:00 local, stopped 23:59 local the same day.
+        assert_eq!(
+            enabled_rule().stop_time(utc(16, 6, 0), &stockholm),
+            utc(16, 21, 59)
+        );
+        // Started 00:30 local on the 17th, which is still the 16th in UTC.
+        assert_eq!(
+            enabled_rule().stop_time(utc(16, 22, 30), &stockholm),
+            utc(17, 21, 59)
+        );
+
+        // An early stop time passed before the timer started stops it the
+        // next day.
+        let six = TimerAutoStop {
+            stop_at: Time::from_hms(6, 0, 0).unwrap(),
+            ..enabled_rule()
+        };
+        assert_eq!(six.stop_time(utc(16, 6, 0), &stockholm), utc(17, 4, 0));
+    }
+
+    #[test]
+    fn only_enabled_rules_past_their_stop_time_are_due() {
+        let stockholm = UserTimeZone::default();
+        let started_at = utc(16, 6, 0);
+
+        assert_eq!(
+            enabled_rule().due(started_at, utc(16, 21, 0), &stockholm),
+            None
+        );
+        assert_eq!(
+            enabled_rule().due(started_at, utc(17, 5, 0), &stockholm),
+            Some(utc(16, 21, 59))
+        );
+        assert_eq!(
+            TimerAutoStop::default().due(started_at, utc(17, 5, 0), &stockholm),
+            None
+        );
+    }
+
+    #[test]
+    fn actions_round_trip_through_their_names() {
+        for action in [TimerAutoStopAction::Save, TimerAutoStopAction::Discard] {
+            assert_eq!(TimerAutoStopAction::parse(action.as_str()), Some(action));
+        }
+        assert_eq!(TimerAutoStopAction::parse("pause"), None);
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{Offset, TimeZone};
+use chrono_tz::Tz;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// The IANA time zone a user's days and weeks are counted in.
+///
+/// Entry dates, week numbers and "today" must all come from instants
+/// converted to this zone. Taking `.date()` of a UTC timestamp puts late
+/// evening and early morning entries on the wrong day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserTimeZone(Tz);
+
+impl UserTimeZone {
+    pub const DEFAULT_NAME: &'static str = "Europe/Stockholm";
+
+    /// Parse an IANA name like `Europe/Stockholm`.
+    pub fn parse(name: &str) -> Option<Self> {
+        name.trim().parse::<Tz>().ok().map(Self)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// UTC offset in effect at `instant`, accounting for daylight saving time.
+    pub fn offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        let utc = chrono::DateTime::from_timestamp(instant.unix_timestamp(), 0).unwrap_or_default();
+        let seconds = self
+            .0
+            .offset_from_utc_datetime(&utc.naive_utc())
+            .fix()
+            .local_minus_utc();
+        UtcOffset::from_whole_seconds(seconds).unwrap_or(UtcOffset::UTC)
+    }
+
+    /// The instant of the wall-clock time `local` in this zone. Times skipped
+    /// by a daylight saving change use the offset from before the change.
+    pub fn at(&self, local: PrimitiveDateTime) -> OffsetDateTime {
+        let naive = chrono::NaiveDate::from_ymd_opt(
+            local.year(),
+            u32::from(u8::from(local.month())),
+            u32::from(local.day()),
+        )
+        .and_then(|date| {
+            date.and_hms_opt(
+                u32::from(local.hour()),
+                u32::from(local.minute()),
+                u32::from(local.second()),
+            )
+        });
+        let instant = naive
+            .and_then(|naive| self.0.from_local_datetime(&naive).earliest())
+            .and_then(|dt| OffsetDateTime::from_unix_timestamp(dt.timestamp()).ok());
+        match instant {
+            Some(instant) => instant,
+            None => {
+                let offset = self.offset_at(local.assume_utc() - time::Duration::hours(3));
+                local.assume_offset(offset).to_offset(UtcOffset::UTC)
+            }
+        }
+    }
+
+    pub fn to_local(&self, instant: OffsetDateTime) -> OffsetDateTime {
+        instant.to_offset(self.offset_at(instant))
+    }
+
+    /// The user's calendar date at `instant`.
+    pub fn date_of(&self, instant: OffsetDateTime) -> Date {
+        self.to_local(instant).date()
+    }
+
+    pub fn today(&self) -> Date {
+        self.date_of(OffsetDateTime::now_utc())
+    }
+}
+
+impl Default for UserTimeZone {
+    fn default() -> Self {
+        Self(chrono_tz::Europe::Stockholm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn utc(month: Month, day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, month, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn dates_follow_the_users_midnight() {
+        let stockholm = UserTimeZone::default();
+        let new_york = UserTimeZone::parse("America/New_York").unwrap();
+        let instant = utc(Month::October, 15, 22, 30);
+
+        assert_eq!(
+            stockholm.date_of(instant),
+            Date::from_calendar_date(2026, Month::October, 16).unwrap()
+        );
+        assert_eq!(
+            new_york.date_of(instant),
+            Date::from_calendar_date(2026, Month::October, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn offsets_follow_daylight_saving_time() {
+        let stockholm = UserTimeZone::default();
+
+        assert_eq!(
+            stockholm.offset_at(utc(Month::October, 16, 12, 0)),
+            UtcOffset::from_hms(2, 0, 0).unwrap()
+        );
+        assert_eq!(
+            stockholm.offset_at(utc(Month::November, 16, 12, 0)),
+            UtcOffset::from_hms(1, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn wall_clock_times_resolve_to_instants() {
+        let stockholm = UserTimeZone::default();
+        let local = |month, day, hour, minute| {
+            Date::from_calendar_date(2026, month, day)
+                .unwrap()
+                .with_hms(hour, minute, 0)
+                .unwrap()
+        };
+
+        assert_eq!(
+            stockholm.at(local(Month::October, 16, 9, 0)),
+            utc(Month::October, 16, 7, 0)
+        );
+        assert_eq!(
+            stockholm.at(local(Month::November, 16, 9, 0)),
+            utc(Month::November, 16, 8, 0)
+        );
+        // 02:30 doesn't exist on the last Sunday of March.
+        assert_eq!(
+            stockholm.at(local(Month::March, 29, 2, 30)),
+            utc(Month::March, 29, 1, 30)
+        );
+    }
+
+    #[test]
+    fn parses_iana_names_only() {
+        assert_eq!(
+            UserTimeZone::parse(" Europe/Stockholm ").map(|tz| tz.name()),
+            Some("Europe/Stockholm")
+        );
+        assert_eq!(UserTimeZone::parse("CEST+2"), None);
+        assert_eq!(UserTimeZone::parse(""), None);
+    }
+}
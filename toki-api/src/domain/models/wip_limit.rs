@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+/// How a board column WIP limit is enforced when a move would exceed it.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WipLimitEnforcement {
+    /// Allow the move but report the violation.
+    #[default]
+    Warn,
+    /// Reject the move.
+    Block,
+}
+
+impl WipLimitEnforcement {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Block => "block",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "warn" => Some(Self::Warn),
+            "block" => Some(Self::Block),
+            _ => None,
+        }
+    }
+}
+
+/// Maximum number of work items allowed in a board column.
+///
+/// Columns are matched by name (case-insensitive), since column ids are not
+/// stable across providers and fallback boards.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardColumnWipLimit {
+    pub column_name: String,
+    pub limit: i32,
+    pub enforcement: WipLimitEnforcement,
+}
+
+impl BoardColumnWipLimit {
+    pub fn applies_to(&self, column_name: &str) -> bool {
+        self.column_name
+            .trim()
+            .eq_ignore_ascii_case(column_name.trim())
+    }
+
+    /// Check whether a column holding `item_count` items violates this limit.
+    pub fn check(&self, item_count: usize) -> Option<WipLimitViolation> {
+        let item_count = i32::try_from(item_count).unwrap_or(i32::MAX);
+        (item_count > self.limit).then(|| WipLimitViolation {
+            column_name: self.column_name.clone(),
+            limit: self.limit,
+            item_count,
+            enforcement: self.enforcement,
+        })
+    }
+}
+
+/// A column that holds (or would hold) more items than its WIP limit allows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WipLimitViolation {
+    pub column_name: String,
+    pub limit: i32,
+    pub item_count: i32,
+    pub enforcement: WipLimitEnforcement,
+}
+
+impl std::fmt::Display for WipLimitViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "column '{}' would hold {} items, exceeding its WIP limit of {}",
+            self.column_name, self.item_count, self.limit
+        )
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
+use super::BoardColumnWipLimit;
+
 /// A provider-agnostic work item.
 ///
 /// IDs are strings to support both Azure DevOps numeric IDs ("12345")
@@ -121,6 +123,7 @@ pub struct BoardColumn {
 pub struct BoardData {
     pub columns: Vec<BoardColumn>,
     pub items: Vec<WorkItem>,
+    pub wip_limits: Vec<BoardColumnWipLimit>,
 }
 
 /// Column assignment for a single work item.
@@ -0,0 +1,236 @@
+use time::Date;
+
+use super::{TimeEntry, UserId};
+
+/// Work item ids referenced in a time entry note, e.g. `#1234` or `AB#1234`.
+///
+/// Ids are returned sorted and without duplicates. Hashes inside words
+/// (`C#9`, `abc#12`) and non-numeric tags (`#nolunch`) are ignored.
+pub fn parse_work_item_mentions(note: &str) -> Vec<String> {
+    let mut ids: Vec<u32> = note
+        .match_indices('#')
+        .filter_map(|(index, _)| {
+            let before = &note[..index];
+            let prefix_ok = match before.chars().next_back() {
+                Some(c) if c.is_alphanumeric() => {
+                    before
+                        .get(before.len().saturating_sub(2)..)
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("ab"))
+                        && !is_word_char(before[..before.len() - 2].chars().next_back())
+                }
+                _ => true,
+            };
+            if !prefix_ok {
+                return None;
+            }
+
+            let rest = &note[index + 1..];
+            let digits_end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if is_word_char(rest[digits_end..].chars().next()) {
+                return None;
+            }
+            rest[..digits_end].parse::<u32>().ok().filter(|id| *id > 0)
+        })
+        .collect();
+
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter().map(|id| id.to_string()).collect()
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// A stored backlink from a work item to a time entry mentioning it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemTimeLink {
+    pub work_item_id: String,
+    pub registration_id: String,
+    pub user_id: UserId,
+    pub date: Date,
+    pub hours: f64,
+    pub project_name: String,
+    pub activity_name: String,
+    pub note: String,
+}
+
+impl WorkItemTimeLink {
+    /// One link per work item mentioned in the entry's note, plus one for the
+    /// work item the entry was explicitly linked to.
+    pub fn from_entry(user_id: UserId, entry: &TimeEntry) -> Vec<Self> {
+        let note = entry.note.clone().unwrap_or_default();
+        let mut work_item_ids = parse_work_item_mentions(&note);
+        if let Some(work_item_id) = &entry.work_item_id {
+            if !work_item_ids.contains(work_item_id) {
+                work_item_ids.push(work_item_id.clone());
+            }
+        }
+        work_item_ids
+            .into_iter()
+            .map(|work_item_id| Self {
+                work_item_id,
+                registration_id: entry.registration_id.clone(),
+                user_id,
+                date: entry.date,
+                hours: entry.hours,
+                project_name: entry.project_name.clone(),
+                activity_name: entry.activity_name.clone(),
+                note: note.clone(),
+            })
+            .collect()
+    }
+}
+
+/// A time entry contributing to a work item's tracked time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemTimeEntry {
+    pub registration_id: String,
+    pub user_name: String,
+    pub date: Date,
+    pub hours: f64,
+    pub project_name: String,
+    pub activity_name: String,
+    pub note: String,
+}
+
+/// Total time tracked against a work item across all users.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemTime {
+    pub work_item_id: String,
+    pub total_hours: f64,
+    pub entries: Vec<WorkItemTimeEntry>,
+}
+
+impl WorkItemTime {
+    pub fn new(work_item_id: impl Into<String>, entries: Vec<WorkItemTimeEntry>) -> Self {
+        Self {
+            work_item_id: work_item_id.into(),
+            total_hours: entries.iter().map(|entry| entry.hours).sum(),
+            entries,
+        }
+    }
+}
+
+/// Scheduling fields of a work item, in hours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorkItemEffort {
+    pub completed_work: Option<f64>,
+    pub remaining_work: Option<f64>,
+    /// Revision of the work item the effort was read at, if known.
+    pub revision: Option<i32>,
+}
+
+impl WorkItemEffort {
+    /// The effort after logging `hours`: completed work grows and remaining
+    /// work, if estimated, shrinks without going below zero.
+    pub fn log(self, hours: f64) -> Self {
+        Self {
+            completed_work: Some(self.completed_work.unwrap_or_default() + hours),
+            remaining_work: self
+                .remaining_work
+                .map(|remaining| (remaining - hours).max(0.0)),
+            revision: self.revision,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    #[test]
+    fn parses_plain_and_azure_boards_mentions() {
+        assert_eq!(
+            parse_work_item_mentions("Fix login (#1234), see AB#987 and ab#1234"),
+            vec!["987", "1234"]
+        );
+    }
+
+    #[test]
+    fn ignores_hashes_that_are_not_mentions() {
+        assert_eq!(
+            parse_work_item_mentions("C#9 review, abc#12, #12ab, #nolunch, # 5, #0"),
+            Vec::<String>::new()
+        );
+        assert_eq!(parse_work_item_mentions("#42"), vec!["42"]);
+    }
+
+    #[test]
+    fn links_an_entry_to_each_mentioned_work_item() {
+        let date = Date::from_calendar_date(2026, Month::October, 16).unwrap();
+        let entry = TimeEntry::new("reg-1", "p1", "Project", "a1", "Development", date, 2.5)
+            .with_note("#12 and AB#34");
+
+        let links = WorkItemTimeLink::from_entry(UserId::new(7), &entry);
+
+        assert_eq!(
+            links
+                .iter()
+                .map(|link| link.work_item_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["12", "34"]
+        );
+        assert!(links.iter().all(|link| link.hours == 2.5
+            && link.registration_id == "reg-1"
+            && link.user_id == UserId::new(7)));
+    }
+
+    #[test]
+    fn links_an_entry_to_its_chosen_work_item_once() {
+        let date = Date::from_calendar_date(2026, Month::October, 16).unwrap();
+        let entry = TimeEntry::new("reg-1", "p1", "Project", "a1", "Development", date, 1.0)
+            .with_note("#12 review")
+            .with_work_item(Some("12".to_string()));
+        assert_eq!(
+            WorkItemTimeLink::from_entry(UserId::new(7), &entry).len(),
+            1
+        );
+
+        let entry = entry.with_work_item(Some("4711".to_string()));
+        assert_eq!(
+            WorkItemTimeLink::from_entry(UserId::new(7), &entry)
+                .iter()
+                .map(|link| link.work_item_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["12", "4711"]
+        );
+    }
+
+    #[test]
+    fn logging_hours_moves_remaining_work_to_completed() {
+        let effort = WorkItemEffort {
+            completed_work: Some(2.0),
+            remaining_work: Some(3.0),
+            revision: Some(5),
+        };
+
+        assert_eq!(
+            effort.log(1.5),
+            WorkItemEffort {
+                completed_work: Some(3.5),
+                remaining_work: Some(1.5),
+                revision: Some(5),
+            }
+        );
+        assert_eq!(effort.log(5.0).remaining_work, Some(0.0));
+
+        let unestimated = WorkItemEffort {
+            completed_work: None,
+            remaining_work: None,
+            revision: None,
+        };
+        assert_eq!(
+            unestimated.log(1.0),
+            WorkItemEffort {
+                completed_work: Some(1.0),
+                remaining_work: None,
+                revision: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use super::{ActivityId, ProjectId};
+
+/// The project and activity a user tracks time on for work items of an
+/// Azure DevOps project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkItemTimeMapping {
+    pub id: i32,
+    pub organization: String,
+    pub project: String,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+}
@@ -1,33 +1,60 @@
 use crate::adapters::outbound::azure_devops::AzureDevOpsUrl;
 use crate::repositories::RepoRepository;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
 use futures::future;
 use sqlx::PgPool;
-use web_push::{IsahcWebPushClient, WebPushClient};
+use time::OffsetDateTime;
 
-use crate::domain::{DbNotificationType, Notification, PRChangeEvent};
+use crate::domain::{
+    ChatWebhookEvent, DbNotificationType, EmailNotification, EmailNotifier, Notification,
+    PRChangeEvent, WebhookNotifier,
+};
 use crate::repositories::{
-    NotificationRepository, NotificationRepositoryImpl, PushSubscriptionRepository,
-    PushSubscriptionRepositoryImpl, RepoRepositoryImpl, UserRepository, UserRepositoryImpl,
+    NotificationRepository, NotificationRepositoryImpl, NotificationWebhookRepository,
+    NotificationWebhookRepositoryImpl, PushSubscriptionRepository, PushSubscriptionRepositoryImpl,
+    RepoRepositoryImpl, UserRepository, UserRepositoryImpl,
 };
 
-use super::{PullRequestDiff, RepoKey};
+use super::{
+    models::UserId, EventKind, EventWebhookDispatcher, PullRequestDiff, PushDispatcher,
+    PushNotification, RepoKey, WebhookDeliveryHealth, WebhookEvent,
+};
 
 pub struct NotificationHandler {
     push_subscriptions_repo: PushSubscriptionRepositoryImpl,
     user_repo: UserRepositoryImpl,
     notification_repo: NotificationRepositoryImpl,
+    notification_webhooks_repo: NotificationWebhookRepositoryImpl,
     repo_repo: RepoRepositoryImpl,
-    web_push_client: IsahcWebPushClient,
+    push_dispatcher: Arc<PushDispatcher>,
+    email_notifier: Option<EmailNotifier>,
+    webhook_notifier: WebhookNotifier,
+    event_webhooks: EventWebhookDispatcher,
+    webhook_health: Mutex<HashMap<RepoKey, WebhookDeliveryHealth>>,
 }
 
 impl NotificationHandler {
-    pub fn new(db_pool: PgPool, web_push_client: IsahcWebPushClient) -> Self {
+    pub fn new(
+        db_pool: PgPool,
+        push_dispatcher: Arc<PushDispatcher>,
+        email_notifier: Option<EmailNotifier>,
+        event_webhooks: EventWebhookDispatcher,
+    ) -> Self {
         Self {
             push_subscriptions_repo: PushSubscriptionRepositoryImpl::new(db_pool.clone()),
             user_repo: UserRepositoryImpl::new(db_pool.clone()),
             notification_repo: NotificationRepositoryImpl::new(db_pool.clone()),
+            notification_webhooks_repo: NotificationWebhookRepositoryImpl::new(db_pool.clone()),
             repo_repo: RepoRepositoryImpl::new(db_pool),
-            web_push_client,
+            push_dispatcher,
+            email_notifier,
+            webhook_notifier: WebhookNotifier::default(),
+            event_webhooks,
+            webhook_health: Mutex::new(HashMap::new()),
         }
     }
 
@@ -47,6 +74,11 @@ impl NotificationHandler {
             .get_push_subscriptions()
             .await
             .map_err(|e| format!("Failed to get push subscriptions: {e}"))?;
+        let webhooks = self
+            .notification_webhooks_repo
+            .get_webhooks()
+            .await
+            .map_err(|e| format!("Failed to get notification webhooks: {e}"))?;
 
         for user in users {
             let user_id = user.id;
@@ -72,8 +104,14 @@ impl NotificationHandler {
                 .iter()
                 .filter(|sub| sub.user_id == user_id_i32)
                 .collect();
+            let webhooks_for_user: Vec<_> = webhooks
+                .iter()
+                .filter(|webhook| webhook.user_id == user_id_i32)
+                .collect();
 
             let mut push_futures = vec![];
+            let mut email_futures = vec![];
+            let mut webhook_futures = vec![];
             for diff in diffs_for_user {
                 let repo_id = repos
                     .iter()
@@ -87,6 +125,26 @@ impl NotificationHandler {
                     .id;
                 let pr_id = diff.pr.pull_request_base.id;
 
+                // Tell the author's own integrations that their PR got blocked
+                if diff.became_blocked
+                    && diff.pr.pull_request_base.created_by.unique_name == user.email
+                {
+                    self.event_webhooks
+                        .dispatch(user_id, pull_request_blocked_event(diff))
+                        .await;
+
+                    let notification = pull_request_blocked_notification(diff);
+                    for webhook in webhooks_for_user.iter().filter(|webhook| {
+                        webhook.subscribes_to(ChatWebhookEvent::PullRequestBlocked)
+                    }) {
+                        if let Err(e) = self.webhook_notifier.send(webhook, &notification).await {
+                            tracing::warn!(
+                                "Failed to post blocked pull request to webhook for user {user_id}: {e}"
+                            );
+                        }
+                    }
+                }
+
                 // Get notification rules for this repository
                 let rules = self
                     .notification_repo
@@ -169,25 +227,185 @@ impl NotificationHandler {
                         };
                         if push_enabled {
                             for sub in push_subscriptions_for_user.iter() {
-                                let message = event.to_web_push_message(
-                                    sub,
-                                    &diff.pr.pull_request_base,
-                                    &link,
+                                push_futures.push(
+                                    self.push_dispatcher.send(sub, push_notification.clone()),
                                 );
-                                push_futures.push(self.web_push_client.send(message));
+                            }
+                        }
+
+                        // Send email notification if enabled and SMTP is configured
+                        let email_enabled = match (rule, exception) {
+                            (_, Some(e)) => e.enabled,
+                            (Some(r), None) => r.email_enabled,
+                            (None, None) => false,
+                        };
+                        if let Some(email_notifier) =
+                            self.email_notifier.as_ref().filter(|_| email_enabled)
+                        {
+                            email_futures.push(
+                                email_notifier
+                                    .send(&user.email, EmailNotification::from(&push_notification)),
+                            );
+                        }
+
+                        // Post to chat webhooks if enabled
+                        let webhook_enabled = match (rule, exception) {
+                            (_, Some(e)) => e.enabled,
+                            (Some(r), None) => r.webhook_enabled,
+                            (None, None) => false,
+                        };
+                        if webhook_enabled {
+                            for webhook in webhooks_for_user.iter().copied() {
+                                let push_notification =
+                                    event.to_push_notification(&diff.pr.pull_request_base, &link);
+                                let repo_key = RepoKey::from(&diff.pr);
+                                webhook_futures.push(async move {
+                                    let result = self
+                                        .webhook_notifier
+                                        .send(webhook, &push_notification)
+                                        .await;
+                                    (repo_key, result)
+                                });
                             }
                         }
                     }
                 }
             }
 
-            future::join_all(push_futures).await;
+            let (push_results, email_results, webhook_results) = future::join3(
+                future::join_all(push_futures),
+                future::join_all(email_futures),
+                future::join_all(webhook_futures),
+            )
+            .await;
+            for error in push_results.into_iter().filter_map(Result::err) {
+                tracing::warn!("Failed to send push notification to user {user_id}: {error}");
+            }
+            for error in email_results.into_iter().filter_map(Result::err) {
+                tracing::warn!("Failed to send email notification to user {user_id}: {error}");
+            }
+            self.record_webhook_results(&webhook_results);
+            for error in webhook_results
+                .iter()
+                .filter_map(|(_, result)| result.as_ref().err())
+            {
+                tracing::warn!("Failed to post webhook notification for user {user_id}: {error}");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delivery health of the chat webhook notifications sent for a repository.
+    pub fn webhook_health(&self, key: &RepoKey) -> WebhookDeliveryHealth {
+        self.webhook_health
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn record_webhook_results(&self, results: &[(RepoKey, Result<(), String>)]) {
+        if results.is_empty() {
+            return;
+        }
+        let now = OffsetDateTime::now_utc();
+        let mut health = self
+            .webhook_health
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for (key, result) in results {
+            health.entry(key.clone()).or_default().record(result, now);
+        }
+    }
+
+    /// Send a notification that isn't tied to a pull request straight to a
+    /// user's push subscriptions, and to the chat webhooks subscribed to
+    /// `event`. Nothing is stored in the notification inbox.
+    pub async fn notify_user(
+        &self,
+        user_id: UserId,
+        event: ChatWebhookEvent,
+        notification: &PushNotification,
+    ) -> Result<(), String> {
+        let push_subscriptions = self
+            .push_subscriptions_repo
+            .get_user_push_subscriptions(&user_id.as_i32())
+            .await
+            .map_err(|e| format!("Failed to get push subscriptions: {e}"))?;
+        let webhooks = self
+            .notification_webhooks_repo
+            .get_user_webhooks(user_id.as_i32())
+            .await
+            .map_err(|e| format!("Failed to get notification webhooks: {e}"))?;
+
+        let push_futures = push_subscriptions
+            .iter()
+            .map(|sub| self.push_dispatcher.send(sub, notification.clone()));
+        let webhook_futures = webhooks
+            .iter()
+            .filter(|webhook| webhook.subscribes_to(event))
+            .map(|webhook| self.webhook_notifier.send(webhook, notification));
+
+        let (push_results, webhook_results) = future::join(
+            future::join_all(push_futures),
+            future::join_all(webhook_futures),
+        )
+        .await;
+        for error in push_results.into_iter().filter_map(Result::err) {
+            tracing::warn!("Failed to send push notification to user {user_id}: {error}");
+        }
+        for error in webhook_results.into_iter().filter_map(Result::err) {
+            tracing::warn!("Failed to post webhook notification for user {user_id}: {error}");
         }
 
         Ok(())
     }
 }
 
+fn pull_request_blocked_event(diff: &PullRequestDiff) -> WebhookEvent {
+    let pr = &diff.pr;
+    let blocked_by: Vec<_> = pr
+        .blocked_by(&pr.threads)
+        .into_iter()
+        .map(|reviewer| reviewer.identity.display_name)
+        .collect();
+
+    WebhookEvent::new(
+        EventKind::PullRequestBlocked,
+        serde_json::json!({
+            "organization": pr.organization,
+            "project": pr.project,
+            "repoName": pr.repo_name,
+            "id": pr.pull_request_base.id,
+            "title": pr.pull_request_base.title,
+            "url": pr.url,
+            "blockedBy": blocked_by,
+        }),
+    )
+}
+
+fn pull_request_blocked_notification(diff: &PullRequestDiff) -> PushNotification {
+    let pr = &diff.pr;
+    let blocked_by: Vec<_> = pr
+        .blocked_by(&pr.threads)
+        .into_iter()
+        .map(|reviewer| reviewer.identity.display_name)
+        .collect();
+
+    PushNotification::new(
+        &format!("{}: Blocked", pr.pull_request_base.title),
+        &format!(
+            "!{} is blocked by {}.",
+            pr.pull_request_base.id,
+            blocked_by.join(", ")
+        ),
+        Some(&pr.url),
+        None,
+    )
+}
+
 fn build_event_link(diff: &PullRequestDiff, event: &PRChangeEvent) -> String {
     let pr_id = diff.pr.pull_request_base.id.to_string();
     let base_pr_url = AzureDevOpsUrl::PullRequest {
@@ -198,7 +416,10 @@ fn build_event_link(diff: &PullRequestDiff, event: &PRChangeEvent) -> String {
     };
 
     match event {
-        PRChangeEvent::PullRequestClosed => base_pr_url.to_string(),
+        PRChangeEvent::PullRequestCreated { .. }
+        | PRChangeEvent::PullRequestClosed
+        | PRChangeEvent::VoteChanged(_)
+        | PRChangeEvent::MergeConflicts => base_pr_url.to_string(),
         PRChangeEvent::ThreadAdded(thread) => {
             let first_comment = thread
                 .comments
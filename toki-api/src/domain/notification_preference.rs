@@ -10,6 +10,9 @@ pub enum DbNotificationType {
     ThreadAdded,
     ThreadUpdated,
     CommentMentioned,
+    PrCreated,
+    VoteChanged,
+    MergeConflicts,
 }
 
 impl DbNotificationType {
@@ -19,6 +22,9 @@ impl DbNotificationType {
             DbNotificationType::ThreadAdded => false,
             DbNotificationType::ThreadUpdated => false,
             DbNotificationType::CommentMentioned => true,
+            DbNotificationType::PrCreated => false,
+            DbNotificationType::VoteChanged => false,
+            DbNotificationType::MergeConflicts => false,
         }
     }
 }
@@ -30,6 +36,9 @@ impl From<&PRChangeEvent> for DbNotificationType {
             PRChangeEvent::ThreadAdded(_) => DbNotificationType::ThreadAdded,
             PRChangeEvent::ThreadUpdated(_) => DbNotificationType::ThreadUpdated,
             PRChangeEvent::CommentMentioned { .. } => DbNotificationType::CommentMentioned,
+            PRChangeEvent::PullRequestCreated { .. } => DbNotificationType::PrCreated,
+            PRChangeEvent::VoteChanged(_) => DbNotificationType::VoteChanged,
+            PRChangeEvent::MergeConflicts => DbNotificationType::MergeConflicts,
         }
     }
 }
@@ -43,6 +52,10 @@ pub struct NotificationRule {
     pub notification_type: DbNotificationType,
     pub enabled: bool,
     pub push_enabled: bool,
+    #[serde(default)]
+    pub email_enabled: bool,
+    #[serde(default)]
+    pub webhook_enabled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
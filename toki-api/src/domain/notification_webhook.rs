@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use url::Url;
+
+use super::PushNotification;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "notification_webhook_kind", rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookKind {
+    Slack,
+    Teams,
+}
+
+impl WebhookKind {
+    /// Detect the chat service from an incoming webhook URL.
+    ///
+    /// Only HTTPS URLs on the known Slack and Teams webhook hosts are
+    /// accepted, so the API can't be used to make requests to arbitrary hosts.
+    pub fn detect(url: &str) -> Option<Self> {
+        let url = Url::parse(url).ok()?;
+        if url.scheme() != "https" {
+            return None;
+        }
+        let host = url.host_str()?.to_ascii_lowercase();
+
+        if host == "hooks.slack.com" {
+            Some(Self::Slack)
+        } else if host.ends_with(".webhook.office.com")
+            || host.ends_with(".logic.azure.com")
+            || host.ends_with(".powerplatform.com")
+        {
+            Some(Self::Teams)
+        } else {
+            None
+        }
+    }
+
+    /// Build the JSON payload for a notification in this service's format.
+    pub fn payload(&self, notification: &PushNotification) -> serde_json::Value {
+        match self {
+            WebhookKind::Slack => {
+                let mut text = format!(
+                    "*{}*\n{}",
+                    escape_slack(&notification.title),
+                    escape_slack(&notification.body)
+                );
+                if let Some(url) = &notification.url {
+                    text.push_str(&format!("\n<{url}|Open in Azure DevOps>"));
+                }
+                json!({ "text": text })
+            }
+            WebhookKind::Teams => {
+                let actions = notification
+                    .url
+                    .as_ref()
+                    .map(|url| {
+                        vec![json!({
+                            "type": "Action.OpenUrl",
+                            "title": "Open in Azure DevOps",
+                            "url": url,
+                        })]
+                    })
+                    .unwrap_or_default();
+                json!({
+                    "type": "message",
+                    "attachments": [{
+                        "contentType": "application/vnd.microsoft.card.adaptive",
+                        "content": {
+                            "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                            "type": "AdaptiveCard",
+                            "version": "1.4",
+                            "body": [
+                                {
+                                    "type": "TextBlock",
+                                    "text": notification.title,
+                                    "weight": "Bolder",
+                                    "wrap": true,
+                                },
+                                {
+                                    "type": "TextBlock",
+                                    "text": notification.body,
+                                    "wrap": true,
+                                },
+                            ],
+                            "actions": actions,
+                        },
+                    }],
+                })
+            }
+        }
+    }
+}
+
+/// Events outside pull request activity that a chat webhook can be posted
+/// for. Pull request activity follows the repository notification rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatWebhookEvent {
+    #[serde(rename = "pull_request.blocked")]
+    PullRequestBlocked,
+    #[serde(rename = "timer.long_running")]
+    LongRunningTimer,
+    #[serde(rename = "day.unlogged")]
+    UnloggedDay,
+    #[serde(rename = "week.unreported")]
+    WeekUnreported,
+    #[serde(rename = "entries.anomalies")]
+    TimeEntryAnomalies,
+}
+
+impl ChatWebhookEvent {
+    pub const ALL: [Self; 5] = [
+        Self::PullRequestBlocked,
+        Self::LongRunningTimer,
+        Self::UnloggedDay,
+        Self::WeekUnreported,
+        Self::TimeEntryAnomalies,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PullRequestBlocked => "pull_request.blocked",
+            Self::LongRunningTimer => "timer.long_running",
+            Self::UnloggedDay => "day.unlogged",
+            Self::WeekUnreported => "week.unreported",
+            Self::TimeEntryAnomalies => "entries.anomalies",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationWebhook {
+    pub id: i32,
+    pub user_id: i32,
+    pub kind: WebhookKind,
+    pub url: String,
+    pub events: Vec<ChatWebhookEvent>,
+    pub created_at: time::OffsetDateTime,
+}
+
+impl NotificationWebhook {
+    pub fn subscribes_to(&self, event: ChatWebhookEvent) -> bool {
+        self.events.contains(&event)
+    }
+}
+
+/// Webhook as shown to its owner. The URL doubles as a secret, so only the
+/// host is exposed.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationWebhookInfo {
+    pub id: i32,
+    pub kind: WebhookKind,
+    pub host: String,
+    pub events: Vec<ChatWebhookEvent>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+}
+
+impl From<NotificationWebhook> for NotificationWebhookInfo {
+    fn from(webhook: NotificationWebhook) -> Self {
+        let host = Url::parse(&webhook.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+
+        NotificationWebhookInfo {
+            id: webhook.id,
+            kind: webhook.kind,
+            host,
+            events: webhook.events,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+/// Posts notifications to Slack or Teams incoming webhooks.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub async fn send(
+        &self,
+        webhook: &NotificationWebhook,
+        notification: &PushNotification,
+    ) -> Result<(), String> {
+        let response = self
+            .client
+            .post(&webhook.url)
+            .json(&webhook.kind.payload(notification))
+            .send()
+            .await
+            .map_err(|e| format!("request failed: {e}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!("webhook returned {}", response.status()));
+        }
+
+        Ok(())
+    }
+}
+
+fn escape_slack(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification() -> PushNotification {
+        PushNotification::new(
+            "Fix <login>: You were mentioned",
+            "Jane mentioned you in a comment.",
+            Some("https://dev.azure.com/org/project/_git/repo/pullrequest/1"),
+            None,
+        )
+    }
+
+    #[test]
+    fn detects_known_webhook_hosts() {
+        assert_eq!(
+            WebhookKind::detect("https://hooks.slack.com/services/T000/B000/XXXX"),
+            Some(WebhookKind::Slack)
+        );
+        assert_eq!(
+            WebhookKind::detect("https://spinit.webhook.office.com/webhookb2/abc"),
+            Some(WebhookKind::Teams)
+        );
+        assert_eq!(
+            WebhookKind::detect(
+                "https://prod-01.westeurope.logic.azure.com:443/workflows/abc/triggers/manual"
+            ),
+            Some(WebhookKind::Teams)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_hosts_and_plain_http() {
+        assert_eq!(
+            WebhookKind::detect("http://hooks.slack.com/services/x"),
+            None
+        );
+        assert_eq!(WebhookKind::detect("https://example.com/hook"), None);
+        assert_eq!(
+            WebhookKind::detect("https://hooks.slack.com.evil.io/x"),
+            None
+        );
+        assert_eq!(WebhookKind::detect("not a url"), None);
+    }
+
+    #[test]
+    fn slack_payload_escapes_text_and_links_pull_request() {
+        let payload = WebhookKind::Slack.payload(&notification());
+
+        assert_eq!(
+            payload["text"],
+            "*Fix &lt;login&gt;: You were mentioned*\nJane mentioned you in a comment.\n<https://dev.azure.com/org/project/_git/repo/pullrequest/1|Open in Azure DevOps>"
+        );
+    }
+
+    #[test]
+    fn event_names_round_trip() {
+        for event in ChatWebhookEvent::ALL {
+            assert_eq!(ChatWebhookEvent::parse(event.as_str()), Some(event));
+            assert_eq!(
+                serde_json::to_value(event).unwrap(),
+                serde_json::json!(event.as_str())
+            );
+        }
+        assert_eq!(ChatWebhookEvent::parse("timer.started"), None);
+    }
+
+    #[test]
+    fn teams_payload_is_adaptive_card() {
+        let payload = WebhookKind::Teams.payload(&notification());
+        let card = &payload["attachments"][0]["content"];
+
+        assert_eq!(card["type"], "AdaptiveCard");
+        assert_eq!(card["body"][0]["text"], "Fix <login>: You were mentioned");
+        assert_eq!(card["actions"][0]["type"], "Action.OpenUrl");
+    }
+}
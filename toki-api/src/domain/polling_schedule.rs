@@ -0,0 +1,237 @@
+use std::{collections::HashSet, time::Duration};
+
+use time::{
+    format_description::well_known::Iso8601, Date, OffsetDateTime, Time, UtcOffset, Weekday,
+};
+
+use crate::config::PollingSettings;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PollingScheduleError {
+    #[error("Invalid UTC offset: {0} minutes")]
+    InvalidOffset(i16),
+    #[error("Invalid working day '{0}'")]
+    InvalidWeekday(String),
+    #[error("Invalid holiday date '{0}', expected YYYY-MM-DD")]
+    InvalidHoliday(String),
+    #[error("Invalid working hours {0}-{1}")]
+    InvalidHours(u8, u8),
+}
+
+/// Decides how often differs may poll Azure DevOps at a given moment.
+///
+/// During working hours differs tick at their own interval. Outside of them
+/// (nights, non-working days, holidays) ticks are throttled to
+/// `off_hours_interval`.
+#[derive(Debug, Clone)]
+pub struct PollingSchedule {
+    offset: UtcOffset,
+    working_days: HashSet<Weekday>,
+    work_start: Time,
+    work_end: Time,
+    holidays: HashSet<Date>,
+    off_hours_interval: Duration,
+    warm_up_lead: Duration,
+}
+
+impl PollingSchedule {
+    /// Build a schedule from settings. Returns `Ok(None)` when throttling is
+    /// disabled.
+    pub fn from_settings(settings: &PollingSettings) -> Result<Option<Self>, PollingScheduleError> {
+        if !settings.enabled {
+            return Ok(None);
+        }
+
+        let offset = UtcOffset::from_whole_seconds(i32::from(settings.utc_offset_minutes) * 60)
+            .map_err(|_| PollingScheduleError::InvalidOffset(settings.utc_offset_minutes))?;
+        let working_days = settings
+            .working_days
+            .iter()
+            .map(|day| {
+                parse_weekday(day).ok_or_else(|| PollingScheduleError::InvalidWeekday(day.clone()))
+            })
+            .collect::<Result<_, _>>()?;
+        let holidays = settings
+            .holidays
+            .iter()
+            .map(|date| {
+                Date::parse(date.trim(), &Iso8601::DATE)
+                    .map_err(|_| PollingScheduleError::InvalidHoliday(date.clone()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let invalid_hours =
+            || PollingScheduleError::InvalidHours(settings.work_start_hour, settings.work_end_hour);
+        if settings.work_start_hour >= settings.work_end_hour || settings.work_end_hour > 24 {
+            return Err(invalid_hours());
+        }
+        let work_start =
+            Time::from_hms(settings.work_start_hour, 0, 0).map_err(|_| invalid_hours())?;
+        let work_end = if settings.work_end_hour == 24 {
+            Time::MAX
+        } else {
+            Time::from_hms(settings.work_end_hour, 0, 0).map_err(|_| invalid_hours())?
+        };
+
+        Ok(Some(Self {
+            offset,
+            working_days,
+            work_start,
+            work_end,
+            holidays,
+            off_hours_interval: Duration::from_secs(settings.off_hours_interval_secs),
+            warm_up_lead: Duration::from_secs(settings.warm_up_lead_minutes * 60),
+        }))
+    }
+
+    fn is_working_day(&self, date: Date) -> bool {
+        self.working_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    pub fn is_working_time(&self, now: OffsetDateTime) -> bool {
+        let local = now.to_offset(self.offset);
+        self.is_working_day(local.date())
+            && local.time() >= self.work_start
+            && local.time() < self.work_end
+    }
+
+    /// Minimum time between ticks at `now`, or `None` when polling should run
+    /// at the differ's regular interval.
+    pub fn throttle_interval(&self, now: OffsetDateTime) -> Option<Duration> {
+        (!self.is_working_time(now)).then_some(self.off_hours_interval)
+    }
+
+    /// Whether a differ last updated at `last_updated` should skip this tick.
+    pub fn should_skip_tick(
+        &self,
+        now: OffsetDateTime,
+        last_updated: Option<OffsetDateTime>,
+    ) -> bool {
+        let (Some(interval), Some(last_updated)) = (self.throttle_interval(now), last_updated)
+        else {
+            return false;
+        };
+        now - last_updated < interval
+    }
+
+    /// The next moment caches should be warmed, i.e. `warm_up_lead` before
+    /// the start of the next working day.
+    pub fn next_warm_up(&self, now: OffsetDateTime) -> OffsetDateTime {
+        let local = now.to_offset(self.offset);
+        let mut date = local.date();
+
+        // A year of non-working days is not a useful calendar; bail out to
+        // "tomorrow" rather than loop forever.
+        for _ in 0..366 {
+            if self.is_working_day(date) {
+                let warm_up =
+                    date.with_time(self.work_start).assume_offset(self.offset) - self.warm_up_lead;
+                if warm_up > now {
+                    return warm_up;
+                }
+            }
+            match date.next_day() {
+                Some(next) => date = next,
+                None => break,
+            }
+        }
+
+        now + Duration::from_secs(24 * 60 * 60)
+    }
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    let value = value.trim().to_ascii_lowercase();
+    let day = match value.get(..3)? {
+        "mon" => Weekday::Monday,
+        "tue" => Weekday::Tuesday,
+        "wed" => Weekday::Wednesday,
+        "thu" => Weekday::Thursday,
+        "fri" => Weekday::Friday,
+        "sat" => Weekday::Saturday,
+        "sun" => Weekday::Sunday,
+        _ => return None,
+    };
+    day.to_string()
+        .to_ascii_lowercase()
+        .starts_with(&value)
+        .then_some(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn utc(month: Month, day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, month, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn schedule() -> PollingSchedule {
+        PollingSchedule::from_settings(&PollingSettings {
+            holidays: vec!["2026-12-24".to_string()],
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn working_hours_use_local_time() {
+        let schedule = schedule();
+
+        // Friday 06:30 UTC is 07:30 at UTC+1.
+        assert!(schedule.is_working_time(utc(Month::October, 16, 6, 30)));
+        // Friday 17:30 UTC is 18:30 local, after hours.
+        assert!(!schedule.is_working_time(utc(Month::October, 16, 17, 30)));
+        // Saturday
+        assert!(!schedule.is_working_time(utc(Month::October, 17, 10, 0)));
+        // Holiday on a Thursday
+        assert!(!schedule.is_working_time(utc(Month::December, 24, 10, 0)));
+    }
+
+    #[test]
+    fn skips_ticks_off_hours_until_interval_has_passed() {
+        let schedule = schedule();
+        let night = utc(Month::October, 16, 23, 0);
+
+        assert!(schedule.should_skip_tick(night, Some(night - Duration::from_secs(10 * 60))));
+        assert!(!schedule.should_skip_tick(night, Some(night - Duration::from_secs(31 * 60))));
+        assert!(!schedule.should_skip_tick(night, None));
+        assert!(!schedule.should_skip_tick(
+            utc(Month::October, 16, 9, 0),
+            Some(utc(Month::October, 16, 8, 59))
+        ));
+    }
+
+    #[test]
+    fn next_warm_up_skips_weekends() {
+        let schedule = schedule();
+
+        // Friday evening -> Monday 06:45 local (05:45 UTC).
+        assert_eq!(
+            schedule.next_warm_up(utc(Month::October, 16, 20, 0)),
+            utc(Month::October, 19, 5, 45)
+        );
+        // Early Monday -> same morning.
+        assert_eq!(
+            schedule.next_warm_up(utc(Month::October, 19, 1, 0)),
+            utc(Month::October, 19, 5, 45)
+        );
+    }
+
+    #[test]
+    fn parses_weekday_names_and_abbreviations() {
+        assert_eq!(parse_weekday("Mon"), Some(Weekday::Monday));
+        assert_eq!(parse_weekday("thursday"), Some(Weekday::Thursday));
+        assert_eq!(parse_weekday("thurs"), Some(Weekday::Thursday));
+        assert_eq!(parse_weekday("mond"), Some(Weekday::Monday));
+        assert_eq!(parse_weekday("monx"), None);
+        assert_eq!(parse_weekday("xyz"), None);
+    }
+}
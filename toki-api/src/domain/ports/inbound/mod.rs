@@ -1,7 +1,9 @@
 mod avatar;
+mod note_improvement;
 mod time_tracking;
 mod work_items;
 
 pub use avatar::*;
+pub use note_improvement::*;
 pub use time_tracking::*;
 pub use work_items::*;
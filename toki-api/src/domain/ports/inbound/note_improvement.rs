@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::{models::NoteImprovementRequest, NoteImprovementError};
+
+#[async_trait]
+pub trait NoteImprovementService: Send + Sync + 'static {
+    /// Suggest a clearer, client-presentable version of a time entry note.
+    async fn improve_note(
+        &self,
+        request: NoteImprovementRequest,
+    ) -> Result<String, NoteImprovementError>;
+}
@@ -1,10 +1,13 @@
 use async_trait::async_trait;
-use time::Date;
+use time::{Date, OffsetDateTime};
 
 use crate::domain::{
     models::{
-        ActiveTimer, Activity, CreateTimeEntryRequest, EditTimeEntryRequest, Project, ProjectId,
-        TimeEntry, TimeEntryDayStatus, TimerHistoryEntry, UserId, WeeklyStats,
+        ActiveTimer, Activity, AnomalyRules, AutoStoppedTimer, CreateTimeEntryRequest,
+        EditTimeEntryRequest, InvoiceReport, InvoiceReportRules, MissingWorkday, PausedTimer,
+        Project, ProjectId, SavedTimer, TimeEntry, TimeEntryAnomaly, TimeEntryBatchChange,
+        TimeEntryDayStatus, TimeRounding, TimerAutoStop, TimerHistoryEntry, UserId, UserTimeZone,
+        WeeklyStats,
     },
     TimeTrackingError,
 };
@@ -40,12 +43,27 @@ pub trait TimeTrackingService: Send + Sync + 'static {
 
     /// Save/register the current timer as a time entry in the provider.
     ///
-    /// Orchestrates: get active timer → compute times → create entry in provider → mark finished locally.
+    /// Orchestrates: get active timer → compute times → deduct lunch → create entry in provider → mark finished locally.
+    /// `work_item_id` links the entry to a work item besides those mentioned in its note.
+    /// `rounding` rounds the saved start and end times after lunch is deducted.
     async fn save_timer(
         &self,
         user_id: &UserId,
         note: Option<String>,
-    ) -> Result<TimeEntry, TimeTrackingError>;
+        work_item_id: Option<String>,
+        rounding: Option<TimeRounding>,
+    ) -> Result<SavedTimer, TimeTrackingError>;
+
+    /// Stop a timer still running past the stop time of the user's `rule`,
+    /// saving it as an entry ending at the stop time or discarding it.
+    ///
+    /// Returns `None` when no timer is running or it isn't due to stop yet.
+    /// A timer without a project or activity can't be saved and is discarded.
+    async fn auto_stop_timer(
+        &self,
+        user_id: &UserId,
+        rule: &TimerAutoStop,
+    ) -> Result<Option<AutoStoppedTimer>, TimeTrackingError>;
 
     /// Edit the active timer for a user.
     async fn edit_timer(
@@ -54,6 +72,31 @@ pub trait TimeTrackingService: Send + Sync + 'static {
         timer: &ActiveTimer,
     ) -> Result<(), TimeTrackingError>;
 
+    /// Paused timers for a user, the most recently paused first.
+    async fn get_paused_timers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PausedTimer>, TimeTrackingError>;
+
+    /// Put the running timer aside to track an interruption, optionally
+    /// starting `next` in its place.
+    ///
+    /// The time tracked so far is kept with the paused timer and saved with
+    /// it once it has been resumed.
+    async fn pause_timer(
+        &self,
+        user_id: &UserId,
+        next: Option<&ActiveTimer>,
+    ) -> Result<PausedTimer, TimeTrackingError>;
+
+    /// Continue the paused timer `id`, or the most recently paused one, as
+    /// the running timer. Fails if a timer is already running.
+    async fn resume_timer(
+        &self,
+        user_id: &UserId,
+        id: Option<i32>,
+    ) -> Result<ActiveTimer, TimeTrackingError>;
+
     // ========================================================================
     // Project/Activity Lookups
     // ========================================================================
@@ -78,6 +121,12 @@ pub trait TimeTrackingService: Send + Sync + 'static {
         date_range: (Date, Date),
     ) -> Result<WeeklyStats, TimeTrackingError>;
 
+    /// Workdays in a date range with scheduled hours but nothing logged.
+    async fn get_missing_workdays(
+        &self,
+        date_range: (Date, Date),
+    ) -> Result<Vec<MissingWorkday>, TimeTrackingError>;
+
     /// Get time entries for a date range.
     ///
     /// Merges provider entries with local timer history for start/end times.
@@ -96,6 +145,25 @@ pub trait TimeTrackingService: Send + Sync + 'static {
         date_range: (Date, Date),
     ) -> Result<Vec<TimeEntryDayStatus>, TimeTrackingError>;
 
+    /// Build an invoice appendix for a date range, optionally limited to one project.
+    async fn get_invoice_report(
+        &self,
+        date_range: (Date, Date),
+        project_id: Option<&ProjectId>,
+        rules: &InvoiceReportRules,
+    ) -> Result<InvoiceReport, TimeTrackingError>;
+
+    /// Flag suspicious time entries in a date range.
+    ///
+    /// Entries are merged with local timer history first, so working-hour
+    /// checks see the real start/end times.
+    async fn get_time_entry_anomalies(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        rules: &AnomalyRules,
+    ) -> Result<Vec<TimeEntryAnomaly>, TimeTrackingError>;
+
     /// Create a new time entry.
     ///
     /// Creates the entry in the provider and persists to local timer history.
@@ -113,6 +181,50 @@ pub trait TimeTrackingService: Send + Sync + 'static {
         request: &EditTimeEntryRequest,
     ) -> Result<TimeEntry, TimeTrackingError>;
 
+    /// Split a time entry in two at `split_at`.
+    ///
+    /// The entry is shortened to end at `split_at` and a new entry covers
+    /// the rest. Returns both parts in order.
+    async fn split_time_entry(
+        &self,
+        user_id: &UserId,
+        request: &EditTimeEntryRequest,
+        split_at: OffsetDateTime,
+    ) -> Result<(TimeEntry, TimeEntry), TimeTrackingError>;
+
+    /// Copy the entry `registration_id` on `date` to `to_date`, at the same
+    /// time of day. Returns the new entry.
+    async fn copy_time_entry(
+        &self,
+        user_id: &UserId,
+        date: Date,
+        registration_id: &str,
+        to_date: Date,
+    ) -> Result<TimeEntry, TimeTrackingError>;
+
+    /// Merge consecutive entries on `date` into one.
+    ///
+    /// The earliest entry is edited to cover all of them and the others are
+    /// deleted. Returns the merged entry.
+    async fn merge_time_entries(
+        &self,
+        user_id: &UserId,
+        date: Date,
+        registration_ids: &[String],
+    ) -> Result<TimeEntry, TimeTrackingError>;
+
+    /// Delete or reassign entries between `date_range` in one go.
+    ///
+    /// All entries are checked before any is changed. Returns the reassigned
+    /// entries, none when deleting.
+    async fn batch_edit_time_entries(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        registration_ids: &[String],
+        change: &TimeEntryBatchChange,
+    ) -> Result<Vec<TimeEntry>, TimeTrackingError>;
+
     /// Delete a time entry.
     async fn delete_time_entry(&self, registration_id: &str) -> Result<(), TimeTrackingError>;
 
@@ -121,4 +233,7 @@ pub trait TimeTrackingService: Send + Sync + 'static {
         &self,
         user_id: &UserId,
     ) -> Result<Vec<TimerHistoryEntry>, TimeTrackingError>;
+
+    /// The time zone entries are dated and rounded in.
+    fn time_zone(&self) -> &UserTimeZone;
 }
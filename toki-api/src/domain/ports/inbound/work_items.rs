@@ -1,7 +1,11 @@
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
 use crate::domain::{
-    models::{BoardData, Iteration, WorkItemImage},
+    models::{
+        BoardColumnWipLimit, BoardData, BoardDiff, Iteration, ProjectTeams, SprintCapacityPlan,
+        WipLimitViolation, WorkItem, WorkItemImage,
+    },
     WorkItemError,
 };
 
@@ -23,6 +27,11 @@ pub trait WorkItemService: Send + Sync + 'static {
         team: Option<&str>,
     ) -> Result<BoardData, WorkItemError>;
 
+    /// Open work items assigned to `assignee`, most recently changed first.
+    /// Only the most recent few are returned.
+    async fn get_assigned_work_items(&self, assignee: &str)
+        -> Result<Vec<WorkItem>, WorkItemError>;
+
     /// Format a work item with comments as Markdown for LLM consumption.
     ///
     /// Returns `(markdown, has_images)`.
@@ -35,11 +44,72 @@ pub trait WorkItemService: Send + Sync + 'static {
     async fn fetch_image(&self, image_url: &str) -> Result<WorkItemImage, WorkItemError>;
 
     /// Move a work item to a target board column.
+    ///
+    /// Returns the WIP limit violation when the move was allowed but pushed the
+    /// column over a warn-only limit. Moves into a column with a blocking limit
+    /// fail with `WorkItemError::WipLimitExceeded`.
     async fn move_work_item_to_column(
         &self,
         work_item_id: &str,
         target_column_name: &str,
         iteration_path: Option<&str>,
         team: Option<&str>,
+    ) -> Result<Option<WipLimitViolation>, WorkItemError>;
+
+    /// Add `hours` to a work item's completed work and take them off its
+    /// remaining work. Returns `false` if the project has no such work item.
+    async fn log_work_item_hours(
+        &self,
+        work_item_id: &str,
+        hours: f64,
+    ) -> Result<bool, WorkItemError>;
+
+    /// Get the configured WIP limits for a team's board.
+    async fn get_wip_limits(
+        &self,
+        team: Option<&str>,
+    ) -> Result<Vec<BoardColumnWipLimit>, WorkItemError>;
+
+    /// Create or replace the WIP limit of a board column.
+    async fn set_wip_limit(
+        &self,
+        team: Option<&str>,
+        limit: BoardColumnWipLimit,
     ) -> Result<(), WorkItemError>;
+
+    /// Remove the WIP limit of a board column.
+    async fn remove_wip_limit(
+        &self,
+        team: Option<&str>,
+        column_name: &str,
+    ) -> Result<(), WorkItemError>;
+
+    /// Capacity plans of the team's `count` most recent started sprints,
+    /// newest first. Defaults to the project's board team.
+    async fn get_sprint_capacity_plans(
+        &self,
+        team: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<SprintCapacityPlan>, WorkItemError>;
+
+    /// Get the project's teams and which one boards use by default.
+    async fn get_project_teams(&self) -> Result<ProjectTeams, WorkItemError>;
+
+    /// Make `team` the project's default board team, or go back to the
+    /// detected team with `None`.
+    async fn set_team_override(&self, team: Option<&str>) -> Result<ProjectTeams, WorkItemError>;
+
+    /// Store a snapshot of the default team's current iteration board,
+    /// unless nothing changed since the previous one.
+    async fn snapshot_current_board(&self) -> Result<(), WorkItemError>;
+
+    /// What changed on a board between `from` and `to`, based on stored
+    /// snapshots. Defaults to the default team's current iteration.
+    async fn get_board_diff(
+        &self,
+        iteration_path: Option<&str>,
+        team: Option<&str>,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> Result<BoardDiff, WorkItemError>;
 }
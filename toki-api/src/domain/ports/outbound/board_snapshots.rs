@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::domain::{models::BoardSnapshot, WorkItemError};
+
+/// Which board a snapshot belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardSnapshotScope<'a> {
+    pub organization: &'a str,
+    pub project: &'a str,
+    pub team: &'a str,
+    pub iteration_path: &'a str,
+}
+
+/// Storage for periodic snapshots of board state, used to show what changed
+/// on a board over a period of time.
+#[async_trait]
+pub trait BoardSnapshotRepository: Send + Sync + 'static {
+    async fn save_snapshot(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        snapshot: &BoardSnapshot,
+    ) -> Result<(), WorkItemError>;
+
+    /// The most recent snapshot taken at or before `at`.
+    async fn snapshot_at_or_before(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        at: OffsetDateTime,
+    ) -> Result<Option<BoardSnapshot>, WorkItemError>;
+
+    /// The first snapshot taken at or after `at`.
+    async fn snapshot_at_or_after(
+        &self,
+        scope: BoardSnapshotScope<'_>,
+        at: OffsetDateTime,
+    ) -> Result<Option<BoardSnapshot>, WorkItemError>;
+}
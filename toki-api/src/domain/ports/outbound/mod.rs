@@ -1,13 +1,23 @@
 mod avatar;
 mod avatar_processing;
+mod board_snapshots;
+mod team_overrides;
+mod text_generator;
 mod time_tracking;
 mod time_tracking_user_links;
 mod timer_history;
+mod wip_limits;
 mod work_item_provider;
+mod work_item_time_links;
 
 pub use avatar::*;
 pub use avatar_processing::*;
+pub use board_snapshots::*;
+pub use team_overrides::*;
+pub use text_generator::*;
 pub use time_tracking::*;
 pub use time_tracking_user_links::*;
 pub use timer_history::*;
+pub use wip_limits::*;
 pub use work_item_provider::*;
+pub use work_item_time_links::*;
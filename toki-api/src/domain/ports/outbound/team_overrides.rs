@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+
+use crate::domain::WorkItemError;
+
+/// Storage for board teams chosen by hand.
+///
+/// An override replaces the auto-detected default team of a project wherever
+/// a request does not name a team itself.
+#[async_trait]
+pub trait TeamOverrideRepository: Send + Sync + 'static {
+    async fn get_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+    ) -> Result<Option<String>, WorkItemError>;
+
+    async fn set_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+        team: &str,
+    ) -> Result<(), WorkItemError>;
+
+    async fn clear_team_override(
+        &self,
+        organization: &str,
+        project: &str,
+    ) -> Result<(), WorkItemError>;
+}
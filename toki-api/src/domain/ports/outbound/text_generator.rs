@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::NoteImprovementError;
+
+/// A language model completing a single prompt.
+#[async_trait]
+pub trait TextGenerator: Send + Sync + 'static {
+    async fn generate(
+        &self,
+        instructions: &str,
+        prompt: &str,
+    ) -> Result<String, NoteImprovementError>;
+}
@@ -3,8 +3,8 @@ use time::Date;
 
 use crate::domain::{
     models::{
-        Activity, CreateTimeEntryRequest, EditTimeEntryRequest, Project, ProjectId, TimeEntry,
-        TimeEntryDayStatus, TimerId, WeeklyStats,
+        Activity, CreateTimeEntryRequest, EditTimeEntryRequest, Project, ProjectId, ScheduledDay,
+        TimeEntry, TimeEntryDayStatus, TimerId, WeeklyStats,
     },
     TimeTrackingError,
 };
@@ -38,6 +38,12 @@ pub trait TimeTrackingClient: Send + Sync + 'static {
         date_range: (Date, Date),
     ) -> Result<WeeklyStats, TimeTrackingError>;
 
+    /// Get the user's scheduled and absence hours for each day in a date range.
+    async fn get_scheduled_days(
+        &self,
+        date_range: (Date, Date),
+    ) -> Result<Vec<ScheduledDay>, TimeTrackingError>;
+
     /// Get time entries from the provider for a date range.
     ///
     /// Returns raw entries without local database augmentation.
@@ -1,13 +1,16 @@
 //! Timer history repository port (outbound).
 //!
 //! Defines the interface for persisting local timer history records
-//! and managing active timers.
+//! and managing active and paused timers.
 
 use async_trait::async_trait;
 use time::OffsetDateTime;
 
 use crate::domain::{
-    models::{ActiveTimer, NewTimerHistoryEntry, TimerHistoryEntry, TimerHistoryId, UserId},
+    models::{
+        ActiveTimer, NewTimerHistoryEntry, PausedTimer, TimerHistoryEntry, TimerHistoryId,
+        TimerSegment, UserId,
+    },
     TimeTrackingError,
 };
 
@@ -45,14 +48,69 @@ pub trait TimerHistoryRepository: Send + Sync + 'static {
     /// Delete the active timer for a user (stop without saving).
     async fn delete_timer(&self, user_id: &UserId) -> Result<(), TimeTrackingError>;
 
-    /// Mark the active timer as finished by setting end_time and registration_id.
+    /// Mark the active timer as finished by setting its start and end time,
+    /// registration_id and the work item it is linked to. The segments it was
+    /// resumed with are dropped along with it.
     async fn save_timer_finished(
         &self,
         user_id: &UserId,
+        start_time: &OffsetDateTime,
         end_time: &OffsetDateTime,
         registration_id: &str,
+        work_item_id: Option<&str>,
     ) -> Result<(), TimeTrackingError>;
 
+    // ========================================================================
+    // Paused Timer Operations
+    // ========================================================================
+
+    /// The user's paused timers, most recently paused first. Excludes the
+    /// one the active timer was resumed from.
+    async fn get_paused_timers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PausedTimer>, TimeTrackingError>;
+
+    /// Put `timer`, which ran in `segments`, on top of the user's stack.
+    async fn push_paused_timer(
+        &self,
+        user_id: &UserId,
+        timer: &ActiveTimer,
+        segments: &[TimerSegment],
+    ) -> Result<PausedTimer, TimeTrackingError>;
+
+    /// Start a paused timer, or the top of the stack if `id` is `None`, as
+    /// the active timer at `resumed_at`, all in one transaction. Its segments
+    /// are kept until the active timer is paused, saved or stopped, and
+    /// those of an earlier resumed timer are dropped.
+    ///
+    /// Fails with `TimerAlreadyRunning` if a timer is running and
+    /// `TimerNotFound` if there is no such paused timer.
+    async fn resume_paused_timer(
+        &self,
+        user_id: &UserId,
+        id: Option<i32>,
+        resumed_at: OffsetDateTime,
+    ) -> Result<ActiveTimer, TimeTrackingError>;
+
+    /// The segments the active timer ran in before it was last resumed.
+    /// Empty unless it was resumed from a paused timer.
+    async fn get_resumed_segments(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<TimerSegment>, TimeTrackingError>;
+
+    /// Replace the segments the active timer was resumed with, once some of
+    /// them have been saved.
+    async fn set_resumed_segments(
+        &self,
+        user_id: &UserId,
+        segments: &[TimerSegment],
+    ) -> Result<(), TimeTrackingError>;
+
+    /// Drop the segments the active timer was resumed with.
+    async fn clear_resumed_segments(&self, user_id: &UserId) -> Result<(), TimeTrackingError>;
+
     // ========================================================================
     // Timer History Operations
     // ========================================================================
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::domain::{models::BoardColumnWipLimit, WorkItemError};
+
+/// Storage for per-column board WIP limits.
+///
+/// Limits are scoped to an organization, project and team; `team: None`
+/// refers to the project's default team.
+#[async_trait]
+pub trait WipLimitRepository: Send + Sync + 'static {
+    async fn list_limits(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+    ) -> Result<Vec<BoardColumnWipLimit>, WorkItemError>;
+
+    async fn upsert_limit(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+        limit: &BoardColumnWipLimit,
+    ) -> Result<(), WorkItemError>;
+
+    async fn delete_limit(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+        column_name: &str,
+    ) -> Result<(), WorkItemError>;
+}
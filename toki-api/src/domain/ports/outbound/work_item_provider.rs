@@ -4,7 +4,8 @@ use async_trait::async_trait;
 
 use crate::domain::{
     models::{
-        BoardColumn, BoardColumnAssignment, Iteration, WorkItem, WorkItemComment, WorkItemImage,
+        BoardColumn, BoardColumnAssignment, Iteration, ProjectTeams, SprintCapacityPlan, WorkItem,
+        WorkItemComment, WorkItemEffort, WorkItemImage,
     },
     WorkItemError,
 };
@@ -33,6 +34,13 @@ pub trait WorkItemProvider: Send + Sync + 'static {
         team: Option<&str>,
     ) -> Result<Vec<String>, WorkItemError>;
 
+    /// Query IDs of open work items assigned to `assignee` (an email or
+    /// unique name), most recently changed first.
+    async fn query_assigned_work_item_ids(
+        &self,
+        assignee: &str,
+    ) -> Result<Vec<String>, WorkItemError>;
+
     /// Get full work item details for a batch of IDs.
     async fn get_work_items(&self, ids: &[String]) -> Result<Vec<WorkItem>, WorkItemError>;
 
@@ -75,6 +83,17 @@ pub trait WorkItemProvider: Send + Sync + 'static {
         work_item_id: &str,
     ) -> Result<(String, bool), WorkItemError>;
 
+    /// Capacity plans of the team's `count` most recent sprints that have
+    /// started, newest first.
+    async fn get_sprint_capacity_plans(
+        &self,
+        team: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<SprintCapacityPlan>, WorkItemError>;
+
+    /// Get the project's teams and the default team the provider would use.
+    async fn get_project_teams(&self) -> Result<ProjectTeams, WorkItemError>;
+
     /// Fetch an image referenced from a work item rich-text field.
     async fn fetch_image(&self, image_url: &str) -> Result<WorkItemImage, WorkItemError>;
 
@@ -86,4 +105,20 @@ pub trait WorkItemProvider: Send + Sync + 'static {
         iteration_path: Option<&str>,
         team: Option<&str>,
     ) -> Result<(), WorkItemError>;
+
+    /// Get a work item's completed and remaining work, or `None` if the
+    /// project has no such work item.
+    async fn get_work_item_effort(
+        &self,
+        work_item_id: &str,
+    ) -> Result<Option<WorkItemEffort>, WorkItemError>;
+
+    /// Store a work item's completed and remaining work. Fields that are
+    /// `None` are left unchanged. Returns `false` without changing anything
+    /// if the work item changed since `effort` was read.
+    async fn set_work_item_effort(
+        &self,
+        work_item_id: &str,
+        effort: WorkItemEffort,
+    ) -> Result<bool, WorkItemError>;
 }
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use time::Date;
+
+use crate::domain::{
+    models::{UserId, WorkItemTime, WorkItemTimeLink},
+    TimeTrackingError,
+};
+
+/// Storage for backlinks from work items to the time entries mentioning them.
+///
+/// Links are derived from entry notes whenever entries pass through the time
+/// tracking service, so they lag the provider until the entries are listed
+/// or saved again.
+#[async_trait]
+pub trait WorkItemTimeLinkRepository: Send + Sync + 'static {
+    /// Replace all of a user's links dated within `date_range` (inclusive).
+    async fn sync_links(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        links: &[WorkItemTimeLink],
+    ) -> Result<(), TimeTrackingError>;
+
+    /// Replace the links of a single entry. An empty `links` removes them.
+    async fn replace_entry_links(
+        &self,
+        registration_id: &str,
+        links: &[WorkItemTimeLink],
+    ) -> Result<(), TimeTrackingError>;
+
+    /// All time tracked against a work item, newest entries first.
+    async fn get_work_item_time(
+        &self,
+        work_item_id: &str,
+    ) -> Result<WorkItemTime, TimeTrackingError>;
+}
@@ -2,12 +2,20 @@ use std::fmt;
 
 use crate::domain::Email;
 
-use super::{PushNotification, PushSubscription};
-use az_devops::Comment;
+use super::PushNotification;
+use az_devops::{Comment, Vote};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PRChangeEvent {
+    /// A pull request was opened, with the unique names of its reviewers.
+    PullRequestCreated {
+        reviewers: Vec<String>,
+    },
     PullRequestClosed,
+    /// A reviewer cast or changed their vote.
+    VoteChanged(az_devops::IdentityWithVote),
+    /// The pull request started having merge conflicts.
+    MergeConflicts,
     ThreadAdded(az_devops::Thread),
     ThreadUpdated(az_devops::Thread),
     CommentMentioned {
@@ -20,9 +28,18 @@ pub enum PRChangeEvent {
 impl fmt::Display for PRChangeEvent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            PRChangeEvent::PullRequestCreated { .. } => {
+                write!(f, "PullRequestCreated")
+            }
             PRChangeEvent::PullRequestClosed => {
                 write!(f, "PullRequestClosed")
             }
+            PRChangeEvent::VoteChanged(reviewer) => {
+                write!(f, "VoteChanged({})", reviewer.identity.unique_name)
+            }
+            PRChangeEvent::MergeConflicts => {
+                write!(f, "MergeConflicts")
+            }
             PRChangeEvent::ThreadAdded(thread) => {
                 write!(f, "ThreadAdded({})", thread.id)
             }
@@ -47,7 +64,14 @@ impl fmt::Display for PRChangeEvent {
 impl PRChangeEvent {
     pub fn applies_to(&self, user_email: &str, pr_author: &str) -> bool {
         match self {
+            PRChangeEvent::PullRequestCreated { reviewers } => {
+                pr_author != user_email && reviewers.iter().any(|reviewer| reviewer == user_email)
+            }
             PRChangeEvent::PullRequestClosed => true,
+            PRChangeEvent::VoteChanged(reviewer) => {
+                pr_author == user_email && reviewer.identity.unique_name != user_email
+            }
+            PRChangeEvent::MergeConflicts => pr_author == user_email,
             PRChangeEvent::ThreadAdded(thread) => {
                 thread.author().unique_name != user_email
                     && pr_author == user_email
@@ -92,19 +116,35 @@ impl PRChangeEvent {
         }
     }
 
-    pub fn to_web_push_message(
-        &self,
-        sub: &PushSubscription,
-        pr: &az_devops::PullRequest,
-        url: &str,
-    ) -> web_push::WebPushMessage {
-        self.to_push_notification(pr, url)
-            .to_web_push_message(&sub.as_subscription_info())
-            .expect("Failed to create web push message")
-    }
-
     pub fn to_push_notification(&self, pr: &az_devops::PullRequest, url: &str) -> PushNotification {
         match self {
+            PRChangeEvent::PullRequestCreated { .. } => PushNotification::new(
+                format!("{}: Review Requested", pr.title).as_str(),
+                format!(
+                    "{} opened !{} and added you as a reviewer.",
+                    pr.created_by.display_name, pr.id
+                )
+                .as_str(),
+                Some(url),
+                None,
+            ),
+            PRChangeEvent::VoteChanged(reviewer) => PushNotification::new(
+                format!("{}: Vote Changed", pr.title).as_str(),
+                format!(
+                    "{} {}.",
+                    reviewer.identity.display_name,
+                    vote_description(reviewer.vote.as_ref())
+                )
+                .as_str(),
+                Some(url),
+                None,
+            ),
+            PRChangeEvent::MergeConflicts => PushNotification::new(
+                format!("{}: Merge Conflicts", pr.title).as_str(),
+                format!("!{} has merge conflicts with its target branch.", pr.id).as_str(),
+                Some(url),
+                None,
+            ),
             PRChangeEvent::PullRequestClosed => PushNotification::new(
                 format!("{}: Pull Request Closed", pr.title).as_str(),
                 format!("!{} has been closed.", pr.id).as_str(),
@@ -145,3 +185,13 @@ impl PRChangeEvent {
         content.starts_with("/")
     }
 }
+
+fn vote_description(vote: Option<&Vote>) -> &'static str {
+    match vote {
+        Some(Vote::Approved) => "approved",
+        Some(Vote::ApprovedWithSuggestions) => "approved with suggestions",
+        Some(Vote::WaitingForAuthor) => "is waiting for the author",
+        Some(Vote::Rejected) => "rejected",
+        Some(Vote::NoResponse) | None => "reset their vote",
+    }
+}
@@ -1,4 +1,4 @@
-use az_devops::{IdentityWithVote, ThreadStatus, Vote};
+use az_devops::{IdentityWithVote, MergeStatus, ThreadStatus, Vote};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -18,6 +18,9 @@ pub struct PullRequest {
     pub threads: Vec<az_devops::Thread>,
     pub commits: Vec<az_devops::GitCommitRef>,
     pub work_items: Vec<az_devops::WorkItem>,
+    /// CI checks on the pull request, latest per check.
+    #[serde(default)]
+    pub checks: Vec<az_devops::PullRequestCheck>,
 }
 
 impl PullRequest {
@@ -28,6 +31,7 @@ impl PullRequest {
         threads: Vec<az_devops::Thread>,
         commits: Vec<az_devops::GitCommitRef>,
         work_items: Vec<az_devops::WorkItem>,
+        checks: Vec<az_devops::PullRequestCheck>,
     ) -> Self {
         Self {
             organization: key.organization.clone(),
@@ -38,9 +42,15 @@ impl PullRequest {
             threads,
             commits,
             work_items,
+            checks,
         }
     }
 
+    /// Overall CI state, or `None` if no checks apply to the pull request.
+    pub fn ci_status(&self) -> Option<az_devops::CiStatus> {
+        az_devops::CiStatus::from_checks(&self.checks)
+    }
+
     pub fn with_replaced_mentions(&self, id_to_email_map: &HashMap<String, String>) -> Self {
         let mut pr = self.clone();
         pr.threads = pr
@@ -122,11 +132,54 @@ impl PullRequest {
             })
             .collect::<Vec<_>>();
 
+        let vote_changes = new_pr
+            .pull_request_base
+            .reviewers
+            .iter()
+            .filter(|reviewer| {
+                let old_vote = self
+                    .pull_request_base
+                    .reviewers
+                    .iter()
+                    .find(|old| old.identity.id == reviewer.identity.id)
+                    .and_then(|old| old.vote.clone());
+                // A reviewer who was just added hasn't voted yet.
+                old_vote.unwrap_or(Vote::NoResponse)
+                    != reviewer.vote.clone().unwrap_or(Vote::NoResponse)
+            })
+            .map(|reviewer| PRChangeEvent::VoteChanged(reviewer.clone()));
+
+        let has_conflicts =
+            |pr: &Self| pr.pull_request_base.merge_status == Some(MergeStatus::Conflicts);
+        let merge_conflicts = (!has_conflicts(self) && has_conflicts(new_pr))
+            .then_some(PRChangeEvent::MergeConflicts);
+
         let mut change_events = Vec::new();
         change_events.extend(new_threads);
         change_events.extend(updated_threads);
         change_events.extend(mention_events);
-        (new_pr.clone(), change_events).into()
+        change_events.extend(vote_changes);
+        change_events.extend(merge_conflicts);
+
+        let mut diff = PullRequestDiff::from((new_pr.clone(), change_events));
+        diff.became_blocked = self.blocked_by(&self.threads).is_empty()
+            && !new_pr.blocked_by(&new_pr.threads).is_empty();
+        diff
+    }
+
+    /// The diff for a pull request that wasn't open on the previous refresh.
+    pub fn opened(&self) -> PullRequestDiff {
+        let reviewers = self
+            .pull_request_base
+            .reviewers
+            .iter()
+            .map(|reviewer| reviewer.identity.unique_name.clone())
+            .collect();
+        (
+            self.clone(),
+            vec![PRChangeEvent::PullRequestCreated { reviewers }],
+        )
+            .into()
     }
 
     /// Returns the identities that are blocking this PR.
@@ -204,17 +257,31 @@ impl PullRequest {
             waiting_for_user_review.is_some_and(|r| r.is_required.unwrap_or_default()),
         )
     }
+
+    /// Whether the user is listed as a reviewer on the PR.
+    pub fn has_reviewer(&self, user_email: &str) -> bool {
+        self.pull_request_base
+            .reviewers
+            .iter()
+            .any(|reviewer| reviewer.identity.unique_name == user_email)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct PullRequestDiff {
     pub pr: PullRequest,
     pub changes: Vec<PRChangeEvent>,
+    /// The PR had no blockers before this diff and has at least one now.
+    pub became_blocked: bool,
 }
 
 impl PullRequestDiff {
     pub fn new(pr: PullRequest, changes: Vec<PRChangeEvent>) -> Self {
-        Self { pr, changes }
+        Self {
+            pr,
+            changes,
+            became_blocked: false,
+        }
     }
 }
 
@@ -311,6 +378,50 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn changelog_emits_vote_changes_and_new_merge_conflicts() {
+        let mut old_pr = test_pull_request(vec![]);
+        old_pr.pull_request_base.reviewers = vec![
+            IdentityWithVote::from(test_identity("approver@example.com")),
+            IdentityWithVote::from(test_identity("idle@example.com")),
+        ];
+        let mut new_pr = old_pr.clone();
+        new_pr.pull_request_base.reviewers[0].vote = Some(Vote::Approved);
+        new_pr.pull_request_base.reviewers[1].vote = Some(Vote::NoResponse);
+        new_pr.pull_request_base.merge_status = Some(MergeStatus::Conflicts);
+
+        let diff = old_pr.changelog(Some(&new_pr), &HashMap::new());
+        let votes = diff
+            .changes
+            .iter()
+            .filter_map(|event| match event {
+                PRChangeEvent::VoteChanged(reviewer) => {
+                    Some(reviewer.identity.unique_name.as_str())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(votes, vec!["approver@example.com"]);
+        assert!(diff.changes.contains(&PRChangeEvent::MergeConflicts));
+
+        let unchanged = new_pr.changelog(Some(&new_pr), &HashMap::new());
+        assert!(unchanged.changes.is_empty());
+    }
+
+    #[test]
+    fn has_reviewer_only_matches_listed_reviewers() {
+        let mut pr = test_pull_request(vec![]);
+        pr.pull_request_base
+            .reviewers
+            .push(IdentityWithVote::from(test_identity(
+                "reviewer@example.com",
+            )));
+
+        assert!(pr.has_reviewer("reviewer@example.com"));
+        assert!(!pr.has_reviewer("author@example.com"));
+        assert!(!pr.has_reviewer("someone@example.com"));
+    }
+
     fn test_pull_request(threads: Vec<az_devops::Thread>) -> PullRequest {
         PullRequest {
             organization: "org".to_string(),
@@ -334,12 +445,14 @@ mod tests {
                 merge_job_id: None,
                 merge_failure_type: None,
                 merge_failure_message: None,
+                last_merge_source_commit_id: None,
                 reviewers: vec![],
                 url: "https://dev.azure.com/org/project/_git/repo/pullrequest/2310".to_string(),
             },
             threads,
             commits: vec![],
             work_items: vec![],
+            checks: vec![],
         }
     }
 
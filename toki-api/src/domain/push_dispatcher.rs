@@ -0,0 +1,74 @@
+use web_push::{IsahcWebPushClient, WebPushClient, WebPushError};
+
+use crate::repositories::{PushSubscriptionRepository, PushSubscriptionRepositoryImpl};
+
+use super::{PushNotification, PushSubscription, VapidKeys};
+
+/// Delivers push notifications to users' subscribed devices.
+///
+/// Subscriptions the push service reports as expired or unknown are deleted,
+/// so uninstalled browsers and revoked permissions stop being retried.
+pub struct PushDispatcher {
+    client: IsahcWebPushClient,
+    keys: Option<VapidKeys>,
+    subscriptions: PushSubscriptionRepositoryImpl,
+}
+
+impl PushDispatcher {
+    /// Without `keys` notifications are silently dropped.
+    pub fn new(
+        client: IsahcWebPushClient,
+        keys: Option<VapidKeys>,
+        subscriptions: PushSubscriptionRepositoryImpl,
+    ) -> Self {
+        Self {
+            client,
+            keys,
+            subscriptions,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.keys.is_some()
+    }
+
+    /// Public key browsers need to subscribe, if push is configured.
+    pub fn public_key(&self) -> Option<&str> {
+        self.keys.as_ref().map(VapidKeys::public_key)
+    }
+
+    pub async fn send(
+        &self,
+        subscription: &PushSubscription,
+        notification: PushNotification,
+    ) -> Result<(), WebPushError> {
+        let Some(keys) = &self.keys else {
+            return Ok(());
+        };
+
+        let message =
+            notification.to_web_push_message(&subscription.as_subscription_info(), keys)?;
+        match self.client.send(message).await {
+            Err(WebPushError::EndpointNotValid | WebPushError::EndpointNotFound) => {
+                tracing::info!(
+                    "Removing expired push subscription {} ({}) of user {}",
+                    subscription.id,
+                    subscription.device,
+                    subscription.user_id
+                );
+                if let Err(e) = self
+                    .subscriptions
+                    .delete_push_subscription(&subscription.id)
+                    .await
+                {
+                    tracing::warn!(
+                        "Failed to delete push subscription {}: {e}",
+                        subscription.id
+                    );
+                }
+                Ok(())
+            }
+            result => result,
+        }
+    }
+}
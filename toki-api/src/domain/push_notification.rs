@@ -4,7 +4,34 @@ use web_push::{
     WebPushMessageBuilder, URL_SAFE,
 };
 
-#[derive(Debug, Serialize, Default)]
+use crate::config::PushSettings;
+
+/// The server's VAPID key pair, identifying it to browser push services.
+#[derive(Clone)]
+pub struct VapidKeys {
+    private_key: String,
+    public_key: String,
+    subject: Option<String>,
+}
+
+impl VapidKeys {
+    /// Fails if the private key can't be used for signing.
+    pub fn from_settings(settings: &PushSettings) -> Result<Self, WebPushError> {
+        VapidSignatureBuilder::from_base64_no_sub(&settings.vapid_private_key, URL_SAFE)?;
+
+        Ok(Self {
+            private_key: settings.vapid_private_key.clone(),
+            public_key: settings.vapid_public_key.clone(),
+            subject: settings.subject.clone(),
+        })
+    }
+
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone)]
 pub struct PushNotification {
     pub title: String,
     pub body: String,
@@ -34,13 +61,14 @@ impl PushNotification {
     pub fn to_web_push_message(
         &self,
         sub_info: &SubscriptionInfo,
+        keys: &VapidKeys,
     ) -> Result<WebPushMessage, WebPushError> {
-        let sig_builder = VapidSignatureBuilder::from_base64(
-            "KaRfTAcDs9ztATKecCL_mBJYdO57X3NvzgWnBNTBQ4c",
-            URL_SAFE,
-            sub_info,
-        )?
-        .build()?;
+        let mut sig_builder =
+            VapidSignatureBuilder::from_base64(&keys.private_key, URL_SAFE, sub_info)?;
+        if let Some(subject) = &keys.subject {
+            sig_builder.add_claim("sub", subject.as_str());
+        }
+        let sig_builder = sig_builder.build()?;
 
         let content_as_bytes: Vec<u8> = self.into();
 
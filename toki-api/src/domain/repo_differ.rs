@@ -14,20 +14,26 @@ use tracing::instrument;
 
 use crate::domain::Email;
 
-use super::{NotificationHandler, PullRequest, PullRequestDiff, RepoKey};
+use super::{
+    repo_health_state, DifferError, NotificationHandler, PollingSchedule, PullRequest,
+    PullRequestDiff, RepoHealth, RepoKey, WebhookDeliveryHealth,
+};
+
+/// Commits and linked work items of a pull request.
+type PullRequestDetails = (Vec<az_devops::GitCommitRef>, Vec<az_devops::WorkItem>);
 
 #[derive(Debug, thiserror::Error)]
 pub enum RepoDifferError {
-    #[error("Could not fetch pull requests for repo")]
-    PullRequests,
-    #[error("Could not fetch threads for pull request")]
-    Threads,
-    #[error("Could not fetch commits for pull request")]
-    Commits,
-    #[error("Could not fetch work items for pull request")]
-    WorkItems,
-    #[error("Could not fetch identities")]
-    Identities,
+    #[error("Could not fetch pull requests for repo: {0}")]
+    PullRequests(String),
+    #[error("Could not fetch threads for pull request: {0}")]
+    Threads(String),
+    #[error("Could not fetch commits for pull request: {0}")]
+    Commits(String),
+    #[error("Could not fetch work items for pull request: {0}")]
+    WorkItems(String),
+    #[error("Could not fetch identities: {0}")]
+    Identities(String),
 }
 
 impl IntoResponse for RepoDifferError {
@@ -62,6 +68,10 @@ pub struct RepoDiffer {
     pub status: Arc<RwLock<RepoDifferStatus>>,
     pub last_updated: Arc<RwLock<Option<OffsetDateTime>>>,
     pub interval: Arc<RwLock<Option<Duration>>>,
+    last_full_refresh: Arc<RwLock<Option<OffsetDateTime>>>,
+    schedule: Option<Arc<PollingSchedule>>,
+    last_error: Arc<RwLock<Option<DifferError>>>,
+    consecutive_failures: Arc<RwLock<u32>>,
 }
 
 impl RepoDiffer {
@@ -69,6 +79,7 @@ impl RepoDiffer {
         key: RepoKey,
         az_client: RepoClient,
         notification_handler: Arc<NotificationHandler>,
+        schedule: Option<Arc<PollingSchedule>>,
     ) -> Self {
         Self {
             key,
@@ -81,6 +92,10 @@ impl RepoDiffer {
             status: Arc::new(RwLock::new(RepoDifferStatus::Stopped)),
             last_updated: Arc::new(RwLock::new(None)),
             interval: Arc::new(RwLock::new(None)),
+            last_full_refresh: Arc::new(RwLock::new(None)),
+            schedule,
+            last_error: Arc::new(RwLock::new(None)),
+            consecutive_failures: Arc::new(RwLock::new(0)),
         }
     }
 
@@ -93,6 +108,9 @@ impl RepoDiffer {
     const MAX_RETRIES: usize = 10;
     const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(30);
     const MAX_RETRY_DELAY: Duration = Duration::from_secs(3600);
+    /// How long commits and work items of an unchanged PR are reused before
+    /// they are fetched again, to pick up work item links added without a push.
+    const FULL_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
 
     #[instrument(name = "RepoDiffer::run", skip(self, receiver), fields(key = %self.key))]
     pub async fn run(&self, mut receiver: mpsc::Receiver<RepoDifferMessage>, db_pool: Arc<PgPool>) {
@@ -115,7 +133,11 @@ impl RepoDiffer {
                         RepoDifferMessage::ForceUpdate => {
                             // TODO: timeout
                             tracing::debug!("Forcing update for differ {}", self.key);
-                            let _ = self.tick().await;
+                            self.last_full_refresh.write().await.take();
+                            match self.tick().await {
+                                Ok(_) => self.record_success().await,
+                                Err(err) => self.record_failure(err.to_string()).await,
+                            }
                         }
                         RepoDifferMessage::Stop => {
                             tracing::debug!("Stopping differ {}", self.key);
@@ -126,6 +148,13 @@ impl RepoDiffer {
                     }
                 }
                 _ = interval_tick_or_sleep(&mut tick_interval) => {
+                    if let Some(schedule) = &self.schedule {
+                        let last_updated = *self.last_updated.read().await;
+                        if schedule.should_skip_tick(OffsetDateTime::now_utc(), last_updated) {
+                            tracing::debug!("Skipping off-hours tick for {}", self.key);
+                            continue;
+                        }
+                    }
                     tracing::debug!("Ticked");
                     let mut retries = 0;
                     let mut last_error: Option<Box<dyn std::error::Error + Send + Sync>> = None;
@@ -133,6 +162,7 @@ impl RepoDiffer {
                     'retry_loop: while retries < Self::MAX_RETRIES && self.is_running().await {
                         match tokio::time::timeout(Duration::from_secs(120), self.tick()).await {
                             Ok(Ok(change_events)) => {
+                                self.record_success().await;
                                 if !change_events.is_empty() {
                                     if let Err(e) = self.notification_handler.notify_affected_users(change_events).await {
                                         tracing::error!("Failed to notify affected users: {}", e);
@@ -144,10 +174,12 @@ impl RepoDiffer {
                             }
                             Ok(Err(err)) => {
                                 tracing::error!("Error ticking for {}: {:?}", self.key, err);
+                                self.record_failure(err.to_string()).await;
                                 last_error = Some(Box::new(err));
                             }
                             Err(_) => {
                                 tracing::error!("Tick operation timed out for {}", self.key);
+                                self.record_failure("Tick operation timed out".to_string()).await;
                                 last_error = Some(Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "Tick operation timed out")));
                             }
                         }
@@ -175,6 +207,57 @@ impl RepoDiffer {
         }
     }
 
+    async fn record_success(&self) {
+        *self.consecutive_failures.write().await = 0;
+    }
+
+    async fn record_failure(&self, message: String) {
+        *self.consecutive_failures.write().await += 1;
+        self.last_error.write().await.replace(DifferError {
+            message,
+            occurred_at: OffsetDateTime::now_utc(),
+        });
+    }
+
+    /// Current health of this differ. Webhook delivery is tracked by the
+    /// notification handler, so it is passed in.
+    pub async fn health(&self, webhooks: WebhookDeliveryHealth) -> RepoHealth {
+        let now = OffsetDateTime::now_utc();
+        let status = *self.status.read().await;
+        let interval = *self.interval.read().await;
+        let last_success_at = *self.last_updated.read().await;
+        let consecutive_failures = *self.consecutive_failures.read().await;
+        // Off hours the schedule stretches the interval between runs.
+        let expected_interval = interval.map(|interval| {
+            self.schedule
+                .as_ref()
+                .and_then(|schedule| schedule.throttle_interval(now))
+                .map_or(interval, |throttled| throttled.max(interval))
+        });
+        let identities_updated_at = self.identities.read().await.last_updated();
+
+        RepoHealth {
+            key: self.key.clone(),
+            state: repo_health_state(
+                status,
+                last_success_at,
+                consecutive_failures,
+                expected_interval,
+                now,
+            ),
+            differ_status: status,
+            refresh_interval_secs: interval.map(|interval| interval.as_secs()),
+            last_success_at,
+            last_error: self.last_error.read().await.clone(),
+            consecutive_failures,
+            rate_limit: self.az_client.rate_limit().map(Into::into),
+            cache_age_secs: last_success_at.map(|at| (now - at).whole_seconds()),
+            cached_pull_requests: self.prev_pull_requests.read().await.as_ref().map(Vec::len),
+            identities_age_secs: identities_updated_at.map(|at| (now - at).whole_seconds()),
+            webhooks,
+        }
+    }
+
     fn calculate_backoff_duration(retry_count: usize) -> Duration {
         let base = Self::INITIAL_RETRY_DELAY.as_secs_f64();
         let max = Self::MAX_RETRY_DELAY.as_secs_f64();
@@ -192,32 +275,79 @@ impl RepoDiffer {
             .az_client
             .get_open_pull_requests()
             .await
-            .map_err(|_| RepoDifferError::PullRequests)?;
+            .map_err(|e| RepoDifferError::PullRequests(e.to_string()))?;
+
+        let now = OffsetDateTime::now_utc();
+        let full_refresh = self
+            .last_full_refresh
+            .read()
+            .await
+            .is_none_or(|last_refresh| {
+                (now - last_refresh).unsigned_abs() > Self::FULL_REFRESH_INTERVAL
+            });
+        let reusable_details = if full_refresh {
+            HashMap::new()
+        } else {
+            self.reusable_pull_request_details().await
+        };
 
         let mut complete_pull_requests = Vec::new();
+        let mut reused_count = 0;
         for pr in base_pull_requests {
-            let commits = pr
-                .commits(&self.az_client)
-                .await
-                .map_err(|_| RepoDifferError::Commits)?;
-            let work_items = pr
-                .work_items(&self.az_client)
-                .await
-                .map_err(|_| RepoDifferError::WorkItems)?;
+            let reusable = pr
+                .last_merge_source_commit_id
+                .as_ref()
+                .and_then(|commit_id| reusable_details.get(&(pr.id, commit_id.clone())));
+            let (commits, work_items) = match reusable {
+                Some((commits, work_items)) => {
+                    reused_count += 1;
+                    (commits.clone(), work_items.clone())
+                }
+                None => {
+                    let commits = pr
+                        .commits(&self.az_client)
+                        .await
+                        .map_err(|e| RepoDifferError::Commits(e.to_string()))?;
+                    let work_items = pr
+                        .work_items(&self.az_client)
+                        .await
+                        .map_err(|e| RepoDifferError::WorkItems(e.to_string()))?;
+                    (commits, work_items)
+                }
+            };
+            // Threads have no server-side change filter, and new comments are
+            // what most notifications are about, so they are always fetched.
             let threads = pr
                 .threads(&self.az_client)
                 .await
-                .map_err(|_| RepoDifferError::Threads)?;
+                .map_err(|e| RepoDifferError::Threads(e.to_string()))?;
+
+            // Checks change while pipelines run, so they are always fetched
+            // too. A PR without its checks is still worth caching.
+            let checks = match self.az_client.get_pull_request_checks(pr.id).await {
+                Ok(checks) => checks,
+                Err(e) => {
+                    tracing::warn!(pull_request_id = pr.id, "Failed to fetch PR checks: {e}");
+                    vec![]
+                }
+            };
 
             let url = format!(
                 "https://dev.azure.com/{}/{}/_git/{}/pullrequest/{}",
                 self.key.organization, self.key.project, self.key.repo_name, pr.id
             );
             complete_pull_requests.push(PullRequest::new(
-                &self.key, url, pr, threads, commits, work_items,
+                &self.key, url, pr, threads, commits, work_items, checks,
             ));
         }
 
+        tracing::debug!(
+            full_refresh,
+            reused_count,
+            total = complete_pull_requests.len(),
+            "Fetched pull request details"
+        );
+
         let id_to_email_map = {
             let cached_identities = self.identities.read().await;
             // Update the cached identities if they are stale.
@@ -226,7 +356,7 @@ impl RepoDiffer {
                     .az_client
                     .get_git_identities()
                     .await
-                    .map_err(|_| RepoDifferError::Identities)?;
+                    .map_err(|e| RepoDifferError::Identities(e.to_string()))?;
 
                 drop(cached_identities); // Drop the read lock before acquiring write lock to avoid deadlock
                 let mut cached_identities = self.identities.write().await;
@@ -240,18 +370,29 @@ impl RepoDiffer {
         let change_events = {
             let prev_pull_requests = self.prev_pull_requests.read().await;
             match prev_pull_requests.clone() {
-                Some(prev_pull_requests) => prev_pull_requests
-                    .iter()
-                    .map(|prev_pr| {
-                        prev_pr.changelog(
-                            complete_pull_requests
+                Some(prev_pull_requests) => {
+                    let opened = complete_pull_requests
+                        .iter()
+                        .filter(|pr| {
+                            !prev_pull_requests
                                 .iter()
-                                .find(|p| p.pull_request_base.id == prev_pr.pull_request_base.id),
-                            &id_to_email_map,
-                        )
-                    })
-                    .filter(|diff| !diff.changes.is_empty())
-                    .collect::<Vec<PullRequestDiff>>(),
+                                .any(|prev| prev.pull_request_base.id == pr.pull_request_base.id)
+                        })
+                        .map(PullRequest::opened);
+                    prev_pull_requests
+                        .iter()
+                        .map(|prev_pr| {
+                            prev_pr.changelog(
+                                complete_pull_requests.iter().find(|p| {
+                                    p.pull_request_base.id == prev_pr.pull_request_base.id
+                                }),
+                                &id_to_email_map,
+                            )
+                        })
+                        .filter(|diff| !diff.changes.is_empty() || diff.became_blocked)
+                        .chain(opened)
+                        .collect::<Vec<PullRequestDiff>>()
+                }
                 None => Vec::new(),
             }
         };
@@ -264,9 +405,30 @@ impl RepoDiffer {
             .write()
             .await
             .replace(OffsetDateTime::now_utc());
+        if full_refresh {
+            self.last_full_refresh.write().await.replace(now);
+        }
 
         Ok(change_events)
     }
+
+    /// Commits and work items from the previous tick, keyed by PR id and the
+    /// source commit they were fetched for. A PR whose source commit is
+    /// unchanged can reuse them instead of hitting ADO again.
+    async fn reusable_pull_request_details(&self) -> HashMap<(i32, String), PullRequestDetails> {
+        let prev_pull_requests = self.prev_pull_requests.read().await;
+        prev_pull_requests
+            .iter()
+            .flatten()
+            .filter_map(|pr| {
+                let commit_id = pr.pull_request_base.last_merge_source_commit_id.clone()?;
+                Some((
+                    (pr.pull_request_base.id, commit_id),
+                    (pr.commits.clone(), pr.work_items.clone()),
+                ))
+            })
+            .collect()
+    }
 }
 
 impl fmt::Debug for RepoDiffer {
@@ -310,6 +472,10 @@ impl CachedIdentities {
         })
     }
 
+    pub fn last_updated(&self) -> Option<OffsetDateTime> {
+        self.last_updated
+    }
+
     /// Update the cached identities and set the last updated time to now.
     pub fn update(&mut self, identities: Vec<Identity>) {
         self.identities = identities;
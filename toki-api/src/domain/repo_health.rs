@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use az_devops::RateLimitStatus;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use super::{RepoDifferStatus, RepoKey};
+
+/// A differ is reported as stale once this many expected ticks have passed
+/// without a successful run.
+const STALE_AFTER_TICKS: u32 = 3;
+
+/// Overall verdict for a repository, for sorting and coloring a dashboard.
+/// Variants are ordered worst first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RepoHealthState {
+    /// The latest run failed, or the differ gave up after retrying.
+    Failing,
+    /// Running, but no successful run for several ticks.
+    Stale,
+    Stopped,
+    Healthy,
+}
+
+/// When and why a differ run last failed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DifferError {
+    pub message: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurred_at: OffsetDateTime,
+}
+
+/// Outcome of the chat webhook notifications sent for a repository's pull
+/// requests since the server started.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryHealth {
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_success_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_failure_at: Option<OffsetDateTime>,
+    pub last_failure: Option<String>,
+    pub consecutive_failures: u32,
+}
+
+impl WebhookDeliveryHealth {
+    pub fn record(&mut self, result: &Result<(), String>, now: OffsetDateTime) {
+        match result {
+            Ok(()) => {
+                self.last_success_at = Some(now);
+                self.consecutive_failures = 0;
+            }
+            Err(error) => {
+                self.last_failure_at = Some(now);
+                self.last_failure = Some(error.clone());
+                self.consecutive_failures += 1;
+            }
+        }
+    }
+}
+
+/// Azure DevOps rate limiting as last observed by a differ.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitHealth {
+    #[serde(with = "time::serde::rfc3339")]
+    pub observed_at: OffsetDateTime,
+    pub throttled: bool,
+    /// Share of the limit left, from 0.0 to 1.0. `None` when Azure DevOps
+    /// did not report usage, which it only does close to the limit.
+    pub headroom: Option<f64>,
+    #[serde(flatten)]
+    pub status: RateLimitStatus,
+}
+
+impl From<(OffsetDateTime, RateLimitStatus)> for RateLimitHealth {
+    fn from((observed_at, status): (OffsetDateTime, RateLimitStatus)) -> Self {
+        Self {
+            observed_at,
+            throttled: status.is_throttled(),
+            headroom: status.headroom(),
+            status,
+        }
+    }
+}
+
+/// Everything an operator needs to tell whether a repository's differ is
+/// doing its job.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoHealth {
+    #[serde(flatten)]
+    pub key: RepoKey,
+    pub state: RepoHealthState,
+    pub differ_status: RepoDifferStatus,
+    pub refresh_interval_secs: Option<u64>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_success_at: Option<OffsetDateTime>,
+    pub last_error: Option<DifferError>,
+    pub consecutive_failures: u32,
+    pub rate_limit: Option<RateLimitHealth>,
+    /// Age of the cached pull requests served to clients.
+    pub cache_age_secs: Option<i64>,
+    pub cached_pull_requests: Option<usize>,
+    pub identities_age_secs: Option<i64>,
+    pub webhooks: WebhookDeliveryHealth,
+}
+
+/// Classify a differ from its status and run history.
+///
+/// `expected_interval` is how often the differ should currently tick; a
+/// running differ without a success for [`STALE_AFTER_TICKS`] of those is
+/// considered stuck.
+pub fn repo_health_state(
+    status: RepoDifferStatus,
+    last_success_at: Option<OffsetDateTime>,
+    consecutive_failures: u32,
+    expected_interval: Option<Duration>,
+    now: OffsetDateTime,
+) -> RepoHealthState {
+    match status {
+        RepoDifferStatus::Stopped => return RepoHealthState::Stopped,
+        RepoDifferStatus::Errored => return RepoHealthState::Failing,
+        RepoDifferStatus::Running => {}
+    }
+    if consecutive_failures > 0 {
+        return RepoHealthState::Failing;
+    }
+
+    let Some(stale_after) = expected_interval.map(|interval| interval * STALE_AFTER_TICKS) else {
+        return RepoHealthState::Healthy;
+    };
+    match last_success_at {
+        Some(last_success_at) if (now - last_success_at).unsigned_abs() <= stale_after => {
+            RepoHealthState::Healthy
+        }
+        // A differ that just started has had no chance to run yet.
+        None => RepoHealthState::Healthy,
+        Some(_) => RepoHealthState::Stale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn running_differ_without_recent_success_is_stale() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let state = |minutes_ago: i64| {
+            repo_health_state(
+                RepoDifferStatus::Running,
+                Some(now - time::Duration::minutes(minutes_ago)),
+                0,
+                Some(MINUTE),
+                now,
+            )
+        };
+
+        assert_eq!(state(2), RepoHealthState::Healthy);
+        assert_eq!(state(10), RepoHealthState::Stale);
+    }
+
+    #[test]
+    fn failures_and_status_take_precedence() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+        assert_eq!(
+            repo_health_state(RepoDifferStatus::Running, Some(now), 2, Some(MINUTE), now),
+            RepoHealthState::Failing
+        );
+        assert_eq!(
+            repo_health_state(RepoDifferStatus::Errored, Some(now), 0, Some(MINUTE), now),
+            RepoHealthState::Failing
+        );
+        assert_eq!(
+            repo_health_state(RepoDifferStatus::Stopped, None, 0, None, now),
+            RepoHealthState::Stopped
+        );
+    }
+
+    #[test]
+    fn webhook_failures_reset_on_success() {
+        let now = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let mut health = WebhookDeliveryHealth::default();
+
+        health.record(&Err("webhook returned 500".to_string()), now);
+        health.record(&Err("webhook returned 500".to_string()), now);
+        assert_eq!(health.consecutive_failures, 2);
+
+        health.record(&Ok(()), now);
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(health.last_success_at, Some(now));
+        assert_eq!(health.last_failure.as_deref(), Some("webhook returned 500"));
+    }
+}
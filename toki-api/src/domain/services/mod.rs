@@ -1,7 +1,9 @@
 mod avatar;
+mod note_improvement;
 mod time_tracking;
 mod work_items;
 
 pub use avatar::AvatarServiceImpl;
+pub use note_improvement::NoteImprovementServiceImpl;
 pub use time_tracking::TimeTrackingServiceImpl;
 pub use work_items::WorkItemServiceImpl;
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    models::{
+        clean_note_suggestion, NoteImprovementRequest, MAX_NOTE_CHARS,
+        NOTE_IMPROVEMENT_INSTRUCTIONS,
+    },
+    ports::{inbound::NoteImprovementService, outbound::TextGenerator},
+    NoteImprovementError,
+};
+
+pub struct NoteImprovementServiceImpl<G> {
+    generator: Arc<G>,
+}
+
+impl<G> NoteImprovementServiceImpl<G> {
+    pub fn new(generator: Arc<G>) -> Self {
+        Self { generator }
+    }
+}
+
+#[async_trait]
+impl<G: TextGenerator> NoteImprovementService for NoteImprovementServiceImpl<G> {
+    async fn improve_note(
+        &self,
+        request: NoteImprovementRequest,
+    ) -> Result<String, NoteImprovementError> {
+        let note = request.note.trim();
+        if note.is_empty() {
+            return Err(NoteImprovementError::InvalidInput(
+                "note cannot be empty".to_string(),
+            ));
+        }
+        if note.chars().count() > MAX_NOTE_CHARS {
+            return Err(NoteImprovementError::InvalidInput(format!(
+                "note cannot be longer than {MAX_NOTE_CHARS} characters"
+            )));
+        }
+
+        let reply = self
+            .generator
+            .generate(NOTE_IMPROVEMENT_INSTRUCTIONS, &request.prompt())
+            .await?;
+
+        clean_note_suggestion(&reply).ok_or(NoteImprovementError::EmptySuggestion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct FakeGenerator {
+        reply: String,
+        prompts: Mutex<Vec<String>>,
+    }
+
+    impl FakeGenerator {
+        fn replying(reply: &str) -> Arc<Self> {
+            Arc::new(Self {
+                reply: reply.to_string(),
+                prompts: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl TextGenerator for FakeGenerator {
+        async fn generate(
+            &self,
+            _instructions: &str,
+            prompt: &str,
+        ) -> Result<String, NoteImprovementError> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
+            Ok(self.reply.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_the_cleaned_suggestion() {
+        let generator = FakeGenerator::replying("\"Fixed Safari login redirect (#12)\"");
+        let service = NoteImprovementServiceImpl::new(generator.clone());
+
+        let suggestion = service
+            .improve_note(NoteImprovementRequest::new("fix login #12"))
+            .await
+            .unwrap();
+
+        assert_eq!(suggestion, "Fixed Safari login redirect (#12)");
+        assert_eq!(
+            *generator.prompts.lock().unwrap(),
+            vec!["Note: fix login #12"]
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_notes_without_calling_the_model() {
+        let generator = FakeGenerator::replying("anything");
+        let service = NoteImprovementServiceImpl::new(generator.clone());
+
+        let result = service
+            .improve_note(NoteImprovementRequest::new("   "))
+            .await;
+
+        assert!(matches!(result, Err(NoteImprovementError::InvalidInput(_))));
+        assert!(generator.prompts.lock().unwrap().is_empty());
+    }
+}
@@ -2,17 +2,20 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use itertools::Itertools;
-use time::{Date, OffsetDateTime};
+use time::{Date, Duration, OffsetDateTime};
 
 use crate::domain::{
     models::{
-        ActiveTimer, Activity, CreateTimeEntryRequest, EditTimeEntryRequest, NewTimerHistoryEntry,
-        Project, ProjectId, TimeEntry, TimeEntryDayStatus, TimeEntryStatus, TimerHistoryEntry,
-        UserId, WeeklyStats,
+        missing_workdays, stitch_segments, ActiveTimer, Activity, AnomalyRules, AutoStoppedTimer,
+        CreateTimeEntryRequest, EditTimeEntryRequest, InvoiceReport, InvoiceReportRules, LunchRule,
+        MissingWorkday, NewTimerHistoryEntry, PausedTimer, Project, ProjectId, SavedTimer,
+        TimeEntry, TimeEntryAnomaly, TimeEntryBatch, TimeEntryBatchChange, TimeEntryDayStatus,
+        TimeEntryMerge, TimeEntryStatus, TimeRounding, TimerAutoStop, TimerAutoStopAction,
+        TimerHistoryEntry, TimerSegment, UserId, UserTimeZone, WeeklyStats, WorkItemTimeLink,
     },
     ports::{
         inbound::TimeTrackingService,
-        outbound::{TimeTrackingClient, TimerHistoryRepository},
+        outbound::{TimeTrackingClient, TimerHistoryRepository, WorkItemTimeLinkRepository},
     },
     TimeTrackingError,
 };
@@ -26,18 +29,95 @@ use crate::domain::{
 pub struct TimeTrackingServiceImpl<C, R> {
     client: Arc<C>,
     timer_repo: Arc<R>,
+    time_zone: UserTimeZone,
+    lunch_rule: LunchRule,
+    work_item_links: Option<Arc<dyn WorkItemTimeLinkRepository>>,
 }
 
 impl<C, R> TimeTrackingServiceImpl<C, R> {
     pub fn new(client: Arc<C>, timer_repo: Arc<R>) -> Self {
-        Self { client, timer_repo }
+        Self {
+            client,
+            timer_repo,
+            time_zone: UserTimeZone::default(),
+            lunch_rule: LunchRule::default(),
+            work_item_links: None,
+        }
+    }
+
+    /// Set the time zone entry dates and week numbers are counted in.
+    pub fn with_time_zone(mut self, time_zone: UserTimeZone) -> Self {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Set the rule deducting lunch from saved timers.
+    pub fn with_lunch_rule(mut self, lunch_rule: LunchRule) -> Self {
+        self.lunch_rule = lunch_rule;
+        self
+    }
+
+    /// Store work item backlinks parsed from entry notes as entries are
+    /// listed and saved.
+    pub fn with_work_item_links(mut self, links: Arc<dyn WorkItemTimeLinkRepository>) -> Self {
+        self.work_item_links = Some(links);
+        self
+    }
+
+    /// Replace the backlinks of a user's entries in `date_range`. Failures
+    /// are logged; the entries themselves are already stored in the provider.
+    async fn sync_work_item_links(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        entries: &[TimeEntry],
+    ) {
+        let Some(repo) = &self.work_item_links else {
+            return;
+        };
+        let links: Vec<_> = entries
+            .iter()
+            .flat_map(|entry| WorkItemTimeLink::from_entry(*user_id, entry))
+            .collect();
+        if let Err(e) = repo.sync_links(user_id, date_range, &links).await {
+            tracing::error!("Failed to sync work item links: {:?}", e);
+        }
+    }
+
+    async fn relink_entry(&self, registration_id: &str, links: &[WorkItemTimeLink]) {
+        let Some(repo) = &self.work_item_links else {
+            return;
+        };
+        if let Err(e) = repo.replace_entry_links(registration_id, links).await {
+            tracing::error!("Failed to update work item links: {:?}", e);
+        }
+    }
+
+    /// Providers take the entry date from `start_time`, and clients send
+    /// times in any offset (usually UTC). Shift both into the user's time
+    /// zone so an entry started at 00:30 local time lands on the right day.
+    fn localize_create_request(&self, request: &CreateTimeEntryRequest) -> CreateTimeEntryRequest {
+        CreateTimeEntryRequest {
+            start_time: self.time_zone.to_local(request.start_time),
+            end_time: self.time_zone.to_local(request.end_time),
+            ..request.clone()
+        }
+    }
+
+    fn localize_edit_request(&self, request: &EditTimeEntryRequest) -> EditTimeEntryRequest {
+        EditTimeEntryRequest {
+            start_time: self.time_zone.to_local(request.start_time),
+            end_time: self.time_zone.to_local(request.end_time),
+            ..request.clone()
+        }
     }
 
     fn time_entry_from_create_request(
+        &self,
         request: &CreateTimeEntryRequest,
         registration_id: impl Into<String>,
     ) -> TimeEntry {
-        let date = request.start_time.date();
+        let date = self.time_zone.date_of(request.start_time);
         let hours = (request.end_time - request.start_time).whole_seconds() as f64 / 3600.0;
 
         TimeEntry::new(
@@ -56,10 +136,11 @@ impl<C, R> TimeTrackingServiceImpl<C, R> {
     }
 
     fn time_entry_from_edit_request(
+        &self,
         request: &EditTimeEntryRequest,
         registration_id: impl Into<String>,
     ) -> TimeEntry {
-        let date = request.start_time.date();
+        let date = self.time_zone.date_of(request.start_time);
         let hours = (request.end_time - request.start_time).whole_seconds() as f64 / 3600.0;
 
         TimeEntry::new(
@@ -78,6 +159,170 @@ impl<C, R> TimeTrackingServiceImpl<C, R> {
     }
 }
 
+impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingServiceImpl<C, R> {
+    /// Create an entry in the provider, linked to `work_item_id` as well as
+    /// the work items its note mentions, and keep it in the local history.
+    async fn create_entry(
+        &self,
+        user_id: &UserId,
+        request: &CreateTimeEntryRequest,
+        work_item_id: Option<String>,
+    ) -> Result<TimeEntry, TimeTrackingError> {
+        // Create in provider
+        let request = &self.localize_create_request(request);
+        let registration_id = self.client.create_time_entry(request).await?;
+        let created_entry = self
+            .time_entry_from_create_request(request, registration_id.to_string())
+            .with_work_item(work_item_id);
+        self.relink_entry(
+            &created_entry.registration_id,
+            &WorkItemTimeLink::from_entry(*user_id, &created_entry),
+        )
+        .await;
+
+        // Persist to local timer history
+        let entry = NewTimerHistoryEntry {
+            user_id: *user_id,
+            registration_id: registration_id.to_string(),
+            start_time: request.start_time,
+            end_time: request.end_time,
+            project_id: Some(request.project_id.clone()),
+            project_name: Some(request.project_name.clone()),
+            activity_id: Some(request.activity_id.clone()),
+            activity_name: Some(request.activity_name.clone()),
+            note: request.note.clone(),
+            work_item_id: created_entry.work_item_id.clone(),
+        };
+
+        if let Err(e) = self.timer_repo.create_finished(&entry).await {
+            tracing::error!("Failed to persist timer to local history: {:?}", e);
+            // Don't fail the request - the provider entry was created successfully
+        }
+
+        Ok(created_entry)
+    }
+
+    /// Save `active_timer` as an entry ending at `end_time` and mark it
+    /// finished.
+    ///
+    /// A timer resumed after a pause is saved as one entry per stretch of
+    /// time it ran in, the segments before the pause saved first.
+    async fn finish_timer(
+        &self,
+        user_id: &UserId,
+        active_timer: &ActiveTimer,
+        note: Option<String>,
+        work_item_id: Option<String>,
+        end_time: OffsetDateTime,
+        rounding: Option<TimeRounding>,
+    ) -> Result<SavedTimer, TimeTrackingError> {
+        let note = note.unwrap_or_else(|| active_timer.note.clone());
+        let project_id = active_timer
+            .project_id
+            .clone()
+            .ok_or_else(|| TimeTrackingError::unknown("project id not set on timer"))?;
+        let project_name = active_timer
+            .project_name
+            .clone()
+            .ok_or_else(|| TimeTrackingError::unknown("project name not set on timer"))?;
+        let activity_id = active_timer
+            .activity_id
+            .clone()
+            .ok_or_else(|| TimeTrackingError::unknown("activity id not set on timer"))?;
+        let activity_name = active_timer
+            .activity_name
+            .clone()
+            .ok_or_else(|| TimeTrackingError::unknown("activity name not set on timer"))?;
+
+        // Join the segments from before any pause with the current one
+        let resumed_segments = self.timer_repo.get_resumed_segments(user_id).await?;
+        let mut segments = resumed_segments.clone();
+        segments.push(TimerSegment::new(active_timer.started_at, end_time));
+        let mut pieces = stitch_segments(&segments);
+        let current = pieces.pop().expect("at least the current segment");
+
+        // Compute times, taking off lunch if a piece ran through it
+        let piece_times = |piece: TimerSegment| {
+            let lunch_deduction =
+                self.lunch_rule
+                    .deduction(piece.start, piece.end, &note, &self.time_zone);
+            let end_time = piece.end - lunch_deduction.unwrap_or_default();
+            let (start_time, end_time) = match rounding {
+                Some(rounding) => rounding.round(piece.start, end_time, &self.time_zone),
+                None => (piece.start, end_time),
+            };
+            (start_time, end_time, lunch_deduction)
+        };
+        let create_request = |start_time, end_time| CreateTimeEntryRequest {
+            project_id: project_id.clone(),
+            project_name: project_name.clone(),
+            activity_id: activity_id.clone(),
+            activity_name: activity_name.clone(),
+            start_time,
+            end_time,
+            note: note.clone(),
+        };
+
+        // Save the earlier pieces as entries of their own
+        let mut segment_entries = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            if piece.duration() < Duration::minutes(1) {
+                continue;
+            }
+            let (start_time, end_time, _) = piece_times(piece);
+            let entry = self
+                .create_entry(
+                    user_id,
+                    &create_request(start_time, end_time),
+                    work_item_id.clone(),
+                )
+                .await?;
+            segment_entries.push(entry);
+
+            // Forget the segments saved so far, so that retrying after a
+            // later failure doesn't save them again
+            let unsaved: Vec<_> = resumed_segments
+                .iter()
+                .copied()
+                .filter(|segment| segment.end > piece.end)
+                .collect();
+            self.timer_repo
+                .set_resumed_segments(user_id, &unsaved)
+                .await?;
+        }
+
+        // Create time entry in the provider
+        let (start_time, end_time, lunch_deduction) = piece_times(current);
+        let req = self.localize_create_request(&create_request(start_time, end_time));
+        let timer_id = self.client.create_time_entry(&req).await?;
+        let created_entry = self
+            .time_entry_from_create_request(&req, timer_id.to_string())
+            .with_work_item(work_item_id);
+        self.relink_entry(
+            &created_entry.registration_id,
+            &WorkItemTimeLink::from_entry(*user_id, &created_entry),
+        )
+        .await;
+
+        // Mark the active timer as finished, which also drops its segments
+        self.timer_repo
+            .save_timer_finished(
+                user_id,
+                &start_time,
+                &end_time,
+                timer_id.as_str(),
+                created_entry.work_item_id.as_deref(),
+            )
+            .await?;
+
+        Ok(SavedTimer {
+            entry: created_entry,
+            lunch_deduction,
+            segment_entries,
+        })
+    }
+}
+
 #[async_trait]
 impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
     for TimeTrackingServiceImpl<C, R>
@@ -103,18 +348,24 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
             return Err(TimeTrackingError::TimerAlreadyRunning);
         }
 
+        // Segments left by a resumed timer that was never saved don't belong
+        // to the new one
+        self.timer_repo.clear_resumed_segments(user_id).await?;
         self.timer_repo.create_timer(user_id, timer).await
     }
 
     async fn stop_timer(&self, user_id: &UserId) -> Result<(), TimeTrackingError> {
-        self.timer_repo.delete_timer(user_id).await
+        self.timer_repo.delete_timer(user_id).await?;
+        self.timer_repo.clear_resumed_segments(user_id).await
     }
 
     async fn save_timer(
         &self,
         user_id: &UserId,
         note: Option<String>,
-    ) -> Result<TimeEntry, TimeTrackingError> {
+        work_item_id: Option<String>,
+        rounding: Option<TimeRounding>,
+    ) -> Result<SavedTimer, TimeTrackingError> {
         // Get the active timer
         let active_timer = self
             .timer_repo
@@ -122,42 +373,111 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
             .await?
             .ok_or(TimeTrackingError::NoTimerRunning)?;
 
-        // Compute times
-        let end_time = OffsetDateTime::now_utc();
-
-        // Build the create request
-        let req = CreateTimeEntryRequest {
-            project_id: active_timer
-                .project_id
-                .clone()
-                .ok_or_else(|| TimeTrackingError::unknown("project id not set on timer"))?,
-            project_name: active_timer
-                .project_name
-                .clone()
-                .ok_or_else(|| TimeTrackingError::unknown("project name not set on timer"))?,
-            activity_id: active_timer
-                .activity_id
-                .clone()
-                .ok_or_else(|| TimeTrackingError::unknown("activity id not set on timer"))?,
-            activity_name: active_timer
-                .activity_name
-                .clone()
-                .ok_or_else(|| TimeTrackingError::unknown("activity name not set on timer"))?,
-            start_time: active_timer.started_at,
-            end_time,
-            note: note.unwrap_or_else(|| active_timer.note.clone()),
+        self.finish_timer(
+            user_id,
+            &active_timer,
+            note,
+            work_item_id,
+            OffsetDateTime::now_utc(),
+            rounding,
+        )
+        .await
+    }
+
+    async fn auto_stop_timer(
+        &self,
+        user_id: &UserId,
+        rule: &TimerAutoStop,
+    ) -> Result<Option<AutoStoppedTimer>, TimeTrackingError> {
+        let Some(active_timer) = self.timer_repo.get_active_timer(user_id).await? else {
+            return Ok(None);
+        };
+        let Some(stopped_at) = rule.due(
+            active_timer.started_at,
+            OffsetDateTime::now_utc(),
+            &self.time_zone,
+        ) else {
+            return Ok(None);
         };
 
-        // Create time entry in the provider
-        let timer_id = self.client.create_time_entry(&req).await?;
-        let created_entry = Self::time_entry_from_create_request(&req, timer_id.to_string());
+        // A timer without a project or activity can't be saved
+        let saveable = active_timer.project_id.is_some() && active_timer.activity_id.is_some();
+        let action = if saveable {
+            rule.action
+        } else {
+            TimerAutoStopAction::Discard
+        };
+        let registration_id = match action {
+            TimerAutoStopAction::Save => {
+                let saved = self
+                    .finish_timer(user_id, &active_timer, None, None, stopped_at, None)
+                    .await?;
+                Some(saved.entry.registration_id)
+            }
+            TimerAutoStopAction::Discard => {
+                self.timer_repo.delete_timer(user_id).await?;
+                self.timer_repo.clear_resumed_segments(user_id).await?;
+                None
+            }
+        };
 
-        // Mark the active timer as finished
-        self.timer_repo
-            .save_timer_finished(user_id, &end_time, timer_id.as_str())
+        Ok(Some(AutoStoppedTimer {
+            action,
+            started_at: active_timer.started_at,
+            stopped_at,
+            project_name: active_timer.project_name,
+            activity_name: active_timer.activity_name,
+            note: active_timer.note,
+            registration_id,
+        }))
+    }
+
+    async fn get_paused_timers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PausedTimer>, TimeTrackingError> {
+        self.timer_repo.get_paused_timers(user_id).await
+    }
+
+    async fn pause_timer(
+        &self,
+        user_id: &UserId,
+        next: Option<&ActiveTimer>,
+    ) -> Result<PausedTimer, TimeTrackingError> {
+        let active_timer = self
+            .timer_repo
+            .get_active_timer(user_id)
+            .await?
+            .ok_or(TimeTrackingError::NoTimerRunning)?;
+
+        let mut segments = self.timer_repo.get_resumed_segments(user_id).await?;
+        segments.push(TimerSegment::new(
+            active_timer.started_at,
+            OffsetDateTime::now_utc(),
+        ));
+        let paused = self
+            .timer_repo
+            .push_paused_timer(user_id, &active_timer, &segments)
             .await?;
+        // The new paused timer carries the resumed segments on
+        self.timer_repo.clear_resumed_segments(user_id).await?;
+        self.timer_repo.delete_timer(user_id).await?;
 
-        Ok(created_entry)
+        if let Some(next) = next {
+            self.timer_repo.create_timer(user_id, next).await?;
+        }
+
+        Ok(paused)
+    }
+
+    async fn resume_timer(
+        &self,
+        user_id: &UserId,
+        id: Option<i32>,
+    ) -> Result<ActiveTimer, TimeTrackingError> {
+        self.timer_repo
+            .resume_paused_timer(user_id, id, OffsetDateTime::now_utc())
+            .await
     }
 
     async fn edit_timer(
@@ -195,6 +515,18 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
         self.client.get_time_info(date_range).await
     }
 
+    async fn get_missing_workdays(
+        &self,
+        date_range: (Date, Date),
+    ) -> Result<Vec<MissingWorkday>, TimeTrackingError> {
+        let (schedule, entries, statuses) = tokio::try_join!(
+            self.client.get_scheduled_days(date_range),
+            self.client.get_time_entries(date_range),
+            self.client.get_time_entry_day_statuses(date_range),
+        )?;
+        Ok(missing_workdays(&schedule, &entries, &statuses))
+    }
+
     async fn get_time_entries(
         &self,
         user_id: &UserId,
@@ -207,27 +539,34 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
         // Merge with local timer history
         let history = self.timer_repo.get_history(user_id).await?;
 
-        // Build a map of registration_id -> (start_time, end_time)
+        // Build a map of registration_id -> (start_time, end_time, work_item_id)
         let history_map: HashMap<String, _> = history
             .into_iter()
             .filter_map(|h| {
                 h.registration_id
-                    .map(|reg_id| (reg_id, (h.start_time, h.end_time)))
+                    .map(|reg_id| (reg_id, (h.start_time, h.end_time, h.work_item_id)))
             })
             .collect();
 
-        // Augment entries with local start/end times
+        // Augment entries with local start/end times and work item links
         entries = entries
             .into_iter()
             .map(|entry| {
-                if let Some((start_time, end_time)) = history_map.get(&entry.registration_id) {
-                    entry.with_times(Some(*start_time), *end_time)
+                if let Some((start_time, end_time, work_item_id)) =
+                    history_map.get(&entry.registration_id)
+                {
+                    entry
+                        .with_times(Some(*start_time), *end_time)
+                        .with_work_item(work_item_id.clone())
                 } else {
                     entry
                 }
             })
             .collect();
 
+        self.sync_work_item_links(user_id, date_range, &entries)
+            .await;
+
         // Sort by date (descending) then by start_time (descending)
         entries.sort_by(|a, b| {
             let date_cmp = b.date.cmp(&a.date);
@@ -263,35 +602,36 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
         self.client.get_time_entry_day_statuses(date_range).await
     }
 
+    async fn get_invoice_report(
+        &self,
+        date_range: (Date, Date),
+        project_id: Option<&ProjectId>,
+        rules: &InvoiceReportRules,
+    ) -> Result<InvoiceReport, TimeTrackingError> {
+        let mut entries = self.client.get_time_entries(date_range).await?;
+        if let Some(project_id) = project_id {
+            entries.retain(|entry| &entry.project_id == project_id);
+        }
+
+        Ok(InvoiceReport::build(date_range, &entries, rules))
+    }
+
+    async fn get_time_entry_anomalies(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        rules: &AnomalyRules,
+    ) -> Result<Vec<TimeEntryAnomaly>, TimeTrackingError> {
+        let entries = self.get_time_entries(user_id, date_range, false).await?;
+        Ok(rules.detect(&entries, &self.time_zone))
+    }
+
     async fn create_time_entry(
         &self,
         user_id: &UserId,
         request: &CreateTimeEntryRequest,
     ) -> Result<TimeEntry, TimeTrackingError> {
-        // Create in provider
-        let registration_id = self.client.create_time_entry(request).await?;
-        let created_entry =
-            Self::time_entry_from_create_request(request, registration_id.to_string());
-
-        // Persist to local timer history
-        let entry = NewTimerHistoryEntry {
-            user_id: *user_id,
-            registration_id: registration_id.to_string(),
-            start_time: request.start_time,
-            end_time: request.end_time,
-            project_id: Some(request.project_id.clone()),
-            project_name: Some(request.project_name.clone()),
-            activity_id: Some(request.activity_id.clone()),
-            activity_name: Some(request.activity_name.clone()),
-            note: request.note.clone(),
-        };
-
-        if let Err(e) = self.timer_repo.create_finished(&entry).await {
-            tracing::error!("Failed to persist timer to local history: {:?}", e);
-            // Don't fail the request - the provider entry was created successfully
-        }
-
-        Ok(created_entry)
+        self.create_entry(user_id, request, None).await
     }
 
     async fn edit_time_entry(
@@ -299,9 +639,13 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
         request: &EditTimeEntryRequest,
     ) -> Result<TimeEntry, TimeTrackingError> {
         // Edit in provider (may return a new registration ID if day changed)
+        let request = &self.localize_edit_request(request);
         let new_registration_id = self.client.edit_time_entry(request).await?;
         let updated_entry =
-            Self::time_entry_from_edit_request(request, new_registration_id.to_string());
+            self.time_entry_from_edit_request(request, new_registration_id.to_string());
+        // Edits don't know the user, so drop the old links and let the next
+        // listing relink the entry with its new note.
+        self.relink_entry(&request.registration_id, &[]).await;
 
         // Update local timer history
         // Check if we have a local record for this registration
@@ -345,10 +689,104 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
         Ok(updated_entry)
     }
 
+    async fn split_time_entry(
+        &self,
+        user_id: &UserId,
+        request: &EditTimeEntryRequest,
+        split_at: OffsetDateTime,
+    ) -> Result<(TimeEntry, TimeEntry), TimeTrackingError> {
+        let (first, second) = request
+            .split_at(split_at)
+            .ok_or(TimeTrackingError::InvalidSplitTime)?;
+
+        let first_entry = self.edit_time_entry(&first).await?;
+        match self.create_time_entry(user_id, &second).await {
+            Ok(second_entry) => Ok((first_entry, second_entry)),
+            Err(e) => {
+                // Put the original entry back so no time goes missing.
+                let restore = EditTimeEntryRequest {
+                    registration_id: first_entry.registration_id.clone(),
+                    ..request.clone()
+                };
+                if let Err(restore_err) = self.edit_time_entry(&restore).await {
+                    tracing::error!(
+                        "Failed to restore entry {} after a failed split: {:?}",
+                        restore.registration_id,
+                        restore_err
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn copy_time_entry(
+        &self,
+        user_id: &UserId,
+        date: Date,
+        registration_id: &str,
+        to_date: Date,
+    ) -> Result<TimeEntry, TimeTrackingError> {
+        let day_entries = self.get_time_entries(user_id, (date, date), false).await?;
+        let entry = day_entries
+            .iter()
+            .find(|entry| entry.registration_id == registration_id)
+            .ok_or_else(|| TimeTrackingError::EntryNotFound(registration_id.to_string()))?;
+        let request = entry
+            .copy_to(to_date, &self.time_zone)
+            .ok_or_else(|| TimeTrackingError::EntryWithoutTimes(registration_id.to_string()))?;
+        self.create_time_entry(user_id, &request).await
+    }
+
+    async fn merge_time_entries(
+        &self,
+        user_id: &UserId,
+        date: Date,
+        registration_ids: &[String],
+    ) -> Result<TimeEntry, TimeTrackingError> {
+        let day_entries = self.get_time_entries(user_id, (date, date), false).await?;
+        let merge = TimeEntryMerge::plan(&day_entries, registration_ids)?;
+
+        // Edit before deleting: if a delete fails, time is counted twice
+        // rather than lost.
+        let merged = self.edit_time_entry(&merge.edit).await?;
+        for registration_id in &merge.delete {
+            self.delete_time_entry(registration_id).await?;
+        }
+        Ok(merged)
+    }
+
+    async fn batch_edit_time_entries(
+        &self,
+        user_id: &UserId,
+        date_range: (Date, Date),
+        registration_ids: &[String],
+        change: &TimeEntryBatchChange,
+    ) -> Result<Vec<TimeEntry>, TimeTrackingError> {
+        let entries = self.get_time_entries(user_id, date_range, false).await?;
+        match TimeEntryBatch::plan(&entries, registration_ids, change)? {
+            TimeEntryBatch::Delete(registration_ids) => {
+                for registration_id in &registration_ids {
+                    self.delete_time_entry(registration_id).await?;
+                }
+                Ok(Vec::new())
+            }
+            TimeEntryBatch::Edit(edits) => {
+                let mut edited = Vec::with_capacity(edits.len());
+                for edit in &edits {
+                    edited.push(self.edit_time_entry(edit).await?);
+                }
+                Ok(edited)
+            }
+        }
+    }
+
     async fn delete_time_entry(&self, registration_id: &str) -> Result<(), TimeTrackingError> {
         // Delete from provider
-        self.client.delete_time_entry(registration_id).await
+        self.client.delete_time_entry(registration_id).await?;
+        self.relink_entry(registration_id, &[]).await;
         // Note: We don't delete from local timer history - it serves as an audit log
+        Ok(())
     }
 
     async fn get_timer_history(
@@ -357,18 +795,25 @@ impl<C: TimeTrackingClient, R: TimerHistoryRepository> TimeTrackingService
     ) -> Result<Vec<TimerHistoryEntry>, TimeTrackingError> {
         self.timer_repo.get_history(user_id).await
     }
+
+    fn time_zone(&self) -> &UserTimeZone {
+        &self.time_zone
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::{TimerHistoryId, TimerId};
+    use crate::domain::models::{ScheduledDay, TimerHistoryId, TimerId};
     use std::sync::Mutex;
     use time::Duration;
 
     #[derive(Default)]
     struct MockTimeTrackingClient {
         created_request: Mutex<Option<CreateTimeEntryRequest>>,
+        created_count: Mutex<usize>,
+        /// Fail to create entries once this many have been created.
+        create_limit: Option<usize>,
     }
 
     #[async_trait]
@@ -392,6 +837,13 @@ mod tests {
             unused_mock_method()
         }
 
+        async fn get_scheduled_days(
+            &self,
+            _date_range: (Date, Date),
+        ) -> Result<Vec<ScheduledDay>, TimeTrackingError> {
+            unused_mock_method()
+        }
+
         async fn get_time_entries(
             &self,
             _date_range: (Date, Date),
@@ -410,8 +862,16 @@ mod tests {
             &self,
             request: &CreateTimeEntryRequest,
         ) -> Result<TimerId, TimeTrackingError> {
+            let mut created_count = self.created_count.lock().unwrap();
+            if self
+                .create_limit
+                .is_some_and(|limit| *created_count >= limit)
+            {
+                return Err(TimeTrackingError::unknown("provider unavailable"));
+            }
+            *created_count += 1;
             *self.created_request.lock().unwrap() = Some(request.clone());
-            Ok(TimerId::new("entry-1"))
+            Ok(TimerId::new(format!("entry-{created_count}")))
         }
 
         async fn edit_time_entry(
@@ -429,6 +889,8 @@ mod tests {
     struct MockTimerHistoryRepository {
         active_timer: Mutex<Option<ActiveTimer>>,
         saved_end_time: Mutex<Option<OffsetDateTime>>,
+        resumed_segments: Mutex<Vec<TimerSegment>>,
+        history: Mutex<Vec<TimerHistoryEntry>>,
     }
 
     #[async_trait]
@@ -457,16 +919,71 @@ mod tests {
         }
 
         async fn delete_timer(&self, _user_id: &UserId) -> Result<(), TimeTrackingError> {
-            unused_mock_method()
+            *self.active_timer.lock().unwrap() = None;
+            Ok(())
         }
 
         async fn save_timer_finished(
             &self,
-            _user_id: &UserId,
+            user_id: &UserId,
+            start_time: &OffsetDateTime,
             end_time: &OffsetDateTime,
-            _registration_id: &str,
+            registration_id: &str,
+            _work_item_id: Option<&str>,
         ) -> Result<(), TimeTrackingError> {
             *self.saved_end_time.lock().unwrap() = Some(*end_time);
+            self.resumed_segments.lock().unwrap().clear();
+            self.history.lock().unwrap().push(
+                TimerHistoryEntry::new(1, *user_id, *start_time, OffsetDateTime::now_utc())
+                    .with_end_time(*end_time)
+                    .with_registration_id(registration_id),
+            );
+            Ok(())
+        }
+
+        async fn get_paused_timers(
+            &self,
+            _user_id: &UserId,
+        ) -> Result<Vec<PausedTimer>, TimeTrackingError> {
+            unused_mock_method()
+        }
+
+        async fn push_paused_timer(
+            &self,
+            _user_id: &UserId,
+            _timer: &ActiveTimer,
+            _segments: &[TimerSegment],
+        ) -> Result<PausedTimer, TimeTrackingError> {
+            unused_mock_method()
+        }
+
+        async fn resume_paused_timer(
+            &self,
+            _user_id: &UserId,
+            _id: Option<i32>,
+            _resumed_at: OffsetDateTime,
+        ) -> Result<ActiveTimer, TimeTrackingError> {
+            unused_mock_method()
+        }
+
+        async fn get_resumed_segments(
+            &self,
+            _user_id: &UserId,
+        ) -> Result<Vec<TimerSegment>, TimeTrackingError> {
+            Ok(self.resumed_segments.lock().unwrap().clone())
+        }
+
+        async fn set_resumed_segments(
+            &self,
+            _user_id: &UserId,
+            segments: &[TimerSegment],
+        ) -> Result<(), TimeTrackingError> {
+            *self.resumed_segments.lock().unwrap() = segments.to_vec();
+            Ok(())
+        }
+
+        async fn clear_resumed_segments(&self, _user_id: &UserId) -> Result<(), TimeTrackingError> {
+            self.resumed_segments.lock().unwrap().clear();
             Ok(())
         }
 
@@ -474,7 +991,7 @@ mod tests {
             &self,
             _user_id: &UserId,
         ) -> Result<Vec<TimerHistoryEntry>, TimeTrackingError> {
-            unused_mock_method()
+            Ok(self.history.lock().unwrap().clone())
         }
 
         async fn get_by_registration_id(
@@ -488,7 +1005,7 @@ mod tests {
             &self,
             _entry: &NewTimerHistoryEntry,
         ) -> Result<TimerHistoryId, TimeTrackingError> {
-            unused_mock_method()
+            Ok(TimerHistoryId::new(1))
         }
 
         async fn update_times(
@@ -511,6 +1028,42 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MockWorkItemTimeLinkRepository {
+        replaced: Mutex<Vec<(String, Vec<WorkItemTimeLink>)>>,
+    }
+
+    #[async_trait]
+    impl WorkItemTimeLinkRepository for MockWorkItemTimeLinkRepository {
+        async fn sync_links(
+            &self,
+            _user_id: &UserId,
+            _date_range: (Date, Date),
+            _links: &[WorkItemTimeLink],
+        ) -> Result<(), TimeTrackingError> {
+            unused_mock_method()
+        }
+
+        async fn replace_entry_links(
+            &self,
+            registration_id: &str,
+            links: &[WorkItemTimeLink],
+        ) -> Result<(), TimeTrackingError> {
+            self.replaced
+                .lock()
+                .unwrap()
+                .push((registration_id.to_string(), links.to_vec()));
+            Ok(())
+        }
+
+        async fn get_work_item_time(
+            &self,
+            _work_item_id: &str,
+        ) -> Result<crate::domain::models::WorkItemTime, TimeTrackingError> {
+            unused_mock_method()
+        }
+    }
+
     fn unused_mock_method<T>() -> Result<T, TimeTrackingError> {
         panic!("test called an unexpected mock method")
     }
@@ -526,12 +1079,18 @@ mod tests {
         let repo = Arc::new(MockTimerHistoryRepository {
             active_timer: Mutex::new(Some(active_timer)),
             saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
         });
         let service = TimeTrackingServiceImpl::new(client.clone(), repo.clone());
         let user_id = UserId::new(1);
 
         let before_save = OffsetDateTime::now_utc();
-        let saved_entry = service.save_timer(&user_id, None).await.unwrap();
+        let saved_entry = service
+            .save_timer(&user_id, None, None, None)
+            .await
+            .unwrap()
+            .entry;
         let after_save = OffsetDateTime::now_utc();
 
         let provider_request = client.created_request.lock().unwrap().clone().unwrap();
@@ -542,4 +1101,314 @@ mod tests {
         assert_eq!(history_end_time, provider_request.end_time);
         assert_eq!(saved_entry.end_time, Some(provider_request.end_time));
     }
+
+    #[tokio::test]
+    async fn save_timer_rounds_times_when_asked() {
+        let started_at = OffsetDateTime::now_utc() - Duration::hours(2);
+        let active_timer = ActiveTimer::new(started_at)
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo.clone());
+
+        service
+            .save_timer(
+                &UserId::new(1),
+                None,
+                None,
+                Some(TimeRounding::new(15).unwrap()),
+            )
+            .await
+            .unwrap();
+
+        let provider_request = client.created_request.lock().unwrap().clone().unwrap();
+        assert_eq!(provider_request.start_time.minute() % 15, 0);
+        assert_eq!(provider_request.start_time.second(), 0);
+        assert_eq!(provider_request.end_time.minute() % 15, 0);
+        assert_eq!(
+            repo.saved_end_time.lock().unwrap().unwrap(),
+            provider_request.end_time
+        );
+    }
+
+    #[tokio::test]
+    async fn save_timer_keeps_rounded_times_in_the_history() {
+        let started_at = (OffsetDateTime::now_utc() - Duration::hours(2))
+            .replace_minute(7)
+            .unwrap()
+            .replace_second(30)
+            .unwrap();
+        let active_timer = ActiveTimer::new(started_at)
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo);
+        let user_id = UserId::new(1);
+
+        service
+            .save_timer(&user_id, None, None, Some(TimeRounding::new(15).unwrap()))
+            .await
+            .unwrap();
+
+        let provider_request = client.created_request.lock().unwrap().clone().unwrap();
+        assert_ne!(provider_request.start_time, started_at);
+        let history = service.get_timer_history(&user_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].start_time, provider_request.start_time);
+        assert_eq!(history[0].end_time, Some(provider_request.end_time));
+    }
+
+    #[tokio::test]
+    async fn save_timer_saves_each_segment_of_a_resumed_timer() {
+        let now = OffsetDateTime::now_utc();
+        let active_timer = ActiveTimer::new(now - Duration::minutes(30))
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(vec![
+                TimerSegment::new(now - Duration::hours(3), now - Duration::hours(2)),
+                // Paused and resumed right away, so saved with the segment before
+                TimerSegment::new(now - Duration::hours(2), now - Duration::minutes(90)),
+            ]),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo.clone());
+
+        let saved = service
+            .save_timer(&UserId::new(1), None, Some("4711".to_string()), None)
+            .await
+            .unwrap();
+
+        assert_eq!(saved.segment_entries.len(), 1);
+        let segment_entry = &saved.segment_entries[0];
+        assert_eq!(segment_entry.start_time, Some(now - Duration::hours(3)));
+        assert_eq!(segment_entry.end_time, Some(now - Duration::minutes(90)));
+        assert_eq!(segment_entry.work_item_id.as_deref(), Some("4711"));
+        assert_eq!(saved.entry.start_time, Some(now - Duration::minutes(30)));
+        assert!(repo.resumed_segments.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn save_timer_retries_only_the_segments_not_yet_saved() {
+        let now = OffsetDateTime::now_utc();
+        let first = TimerSegment::new(now - Duration::hours(3), now - Duration::hours(2));
+        let second = TimerSegment::new(now - Duration::minutes(90), now - Duration::hours(1));
+        let active_timer = ActiveTimer::new(now - Duration::minutes(30))
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(vec![first, second]),
+            history: Mutex::new(Vec::new()),
+        });
+        let failing_client = Arc::new(MockTimeTrackingClient {
+            create_limit: Some(1),
+            ..Default::default()
+        });
+        let service = TimeTrackingServiceImpl::new(failing_client, repo.clone());
+
+        assert!(service
+            .save_timer(&UserId::new(1), None, None, None)
+            .await
+            .is_err());
+        assert_eq!(*repo.resumed_segments.lock().unwrap(), vec![second]);
+        assert!(repo.saved_end_time.lock().unwrap().is_none());
+
+        let service =
+            TimeTrackingServiceImpl::new(Arc::new(MockTimeTrackingClient::default()), repo.clone());
+        let saved = service
+            .save_timer(&UserId::new(1), None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(saved.segment_entries.len(), 1);
+        assert_eq!(saved.segment_entries[0].start_time, Some(second.start));
+        assert!(repo.resumed_segments.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn auto_stop_saves_timers_ending_at_the_stop_time() {
+        let started_at = OffsetDateTime::now_utc() - Duration::hours(30);
+        let active_timer = ActiveTimer::new(started_at)
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo.clone());
+        let rule = TimerAutoStop {
+            enabled: true,
+            ..Default::default()
+        };
+
+        let stopped = service
+            .auto_stop_timer(&UserId::new(1), &rule)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let stop_time = rule.stop_time(started_at, &UserTimeZone::default());
+        assert_eq!(stopped.action, TimerAutoStopAction::Save);
+        assert_eq!(stopped.stopped_at, stop_time);
+        assert!(stopped.registration_id.is_some());
+        let provider_request = client.created_request.lock().unwrap().clone().unwrap();
+        assert_eq!(provider_request.end_time, stop_time);
+        assert_eq!(repo.saved_end_time.lock().unwrap().unwrap(), stop_time);
+    }
+
+    #[tokio::test]
+    async fn auto_stop_discards_timers_that_cant_be_saved() {
+        let started_at = OffsetDateTime::now_utc() - Duration::hours(30);
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(ActiveTimer::new(started_at))),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo.clone());
+        let rule = TimerAutoStop {
+            enabled: true,
+            ..Default::default()
+        };
+
+        let stopped = service
+            .auto_stop_timer(&UserId::new(1), &rule)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stopped.action, TimerAutoStopAction::Discard);
+        assert!(client.created_request.lock().unwrap().is_none());
+        assert!(repo.active_timer.lock().unwrap().is_none());
+        assert!(service
+            .auto_stop_timer(&UserId::new(1), &rule)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn save_timer_dates_entries_in_the_users_time_zone() {
+        // 23:30 UTC on Thursday is 01:30 on Friday in Stockholm (CEST).
+        let started_at = Date::from_calendar_date(2026, time::Month::October, 15)
+            .unwrap()
+            .with_hms(23, 30, 0)
+            .unwrap()
+            .assume_utc();
+        let active_timer = ActiveTimer::new(started_at)
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity");
+        let client = Arc::new(MockTimeTrackingClient::default());
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let service = TimeTrackingServiceImpl::new(client.clone(), repo)
+            .with_time_zone(UserTimeZone::parse("Europe/Stockholm").unwrap());
+
+        let saved_entry = service
+            .save_timer(&UserId::new(1), None, None, None)
+            .await
+            .unwrap()
+            .entry;
+
+        let provider_request = client.created_request.lock().unwrap().clone().unwrap();
+        let friday = Date::from_calendar_date(2026, time::Month::October, 16).unwrap();
+        assert_eq!(provider_request.start_time.date(), friday);
+        assert_eq!(provider_request.start_time, started_at);
+        assert_eq!(saved_entry.date, friday);
+        assert_eq!(saved_entry.week_number, friday.iso_week());
+    }
+
+    #[tokio::test]
+    async fn save_timer_links_work_items_mentioned_in_the_note() {
+        let active_timer = ActiveTimer::new(OffsetDateTime::now_utc() - Duration::hours(1))
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity")
+            .with_note("AB#1234: review #77");
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let links = Arc::new(MockWorkItemTimeLinkRepository::default());
+        let service =
+            TimeTrackingServiceImpl::new(Arc::new(MockTimeTrackingClient::default()), repo)
+                .with_work_item_links(links.clone());
+
+        service
+            .save_timer(&UserId::new(1), None, None, None)
+            .await
+            .unwrap();
+
+        let replaced = links.replaced.lock().unwrap();
+        let (registration_id, links) = &replaced[0];
+        assert_eq!(registration_id, "entry-1");
+        assert_eq!(
+            links
+                .iter()
+                .map(|link| link.work_item_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["77", "1234"]
+        );
+    }
+
+    #[tokio::test]
+    async fn save_timer_links_the_chosen_work_item() {
+        let active_timer = ActiveTimer::new(OffsetDateTime::now_utc() - Duration::hours(1))
+            .with_project("project-1", "Project")
+            .with_activity("activity-1", "Activity")
+            .with_note("Review #77");
+        let repo = Arc::new(MockTimerHistoryRepository {
+            active_timer: Mutex::new(Some(active_timer)),
+            saved_end_time: Mutex::new(None),
+            resumed_segments: Mutex::new(Vec::new()),
+            history: Mutex::new(Vec::new()),
+        });
+        let links = Arc::new(MockWorkItemTimeLinkRepository::default());
+        let service =
+            TimeTrackingServiceImpl::new(Arc::new(MockTimeTrackingClient::default()), repo)
+                .with_work_item_links(links.clone());
+
+        let saved_entry = service
+            .save_timer(&UserId::new(1), None, Some("4711".to_string()), None)
+            .await
+            .unwrap()
+            .entry;
+
+        assert_eq!(saved_entry.work_item_id.as_deref(), Some("4711"));
+        let replaced = links.replaced.lock().unwrap();
+        assert_eq!(
+            replaced[0]
+                .1
+                .iter()
+                .map(|link| link.work_item_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["77", "4711"]
+        );
+    }
 }
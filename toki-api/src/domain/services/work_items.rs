@@ -2,30 +2,76 @@ use std::sync::Arc;
 use std::{cmp::Ordering, collections::HashMap};
 
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
 use crate::domain::{
     models::{
-        synthetic_column_id_from_name, BoardColumn, BoardData, BoardState, Iteration, WorkItem,
-        WorkItemImage,
+        synthetic_column_id_from_name, BoardColumn, BoardColumnWipLimit, BoardData, BoardDiff,
+        BoardSnapshot, BoardState, Iteration, ProjectTeams, SprintCapacityPlan,
+        WipLimitEnforcement, WipLimitViolation, WorkItem, WorkItemImage,
+    },
+    ports::{
+        inbound::WorkItemService,
+        outbound::{
+            BoardSnapshotRepository, BoardSnapshotScope, TeamOverrideRepository,
+            WipLimitRepository, WorkItemProvider,
+        },
     },
-    ports::{inbound::WorkItemService, outbound::WorkItemProvider},
     WorkItemError,
 };
 
+/// Most assigned work items returned for a single project.
+const MAX_ASSIGNED_WORK_ITEMS: usize = 50;
+/// How many times logging hours re-reads a work item that changed under it.
+const MAX_EFFORT_UPDATE_ATTEMPTS: usize = 3;
+
 /// Implementation of the WorkItemService inbound port.
 ///
 /// This service orchestrates work item board operations by delegating to a
-/// WorkItemProvider (outbound port) and adding business logic (sorting).
+/// WorkItemProvider (outbound port) and adding business logic (sorting, WIP
+/// limits).
 ///
-/// Single generic type param `P` — no local DB needed, unlike time tracking
-/// which has both a client and a repository.
+/// Board data lives entirely in the provider; the only local state is the
+/// per-column WIP limits, the project's team override and board snapshots,
+/// scoped to the service's organization and project.
 pub struct WorkItemServiceImpl<P: WorkItemProvider> {
     provider: Arc<P>,
+    wip_limits: Arc<dyn WipLimitRepository>,
+    team_overrides: Arc<dyn TeamOverrideRepository>,
+    board_snapshots: Arc<dyn BoardSnapshotRepository>,
+    organization: String,
+    project: String,
 }
 
 impl<P: WorkItemProvider> WorkItemServiceImpl<P> {
-    pub fn new(provider: Arc<P>) -> Self {
-        Self { provider }
+    pub fn new(
+        provider: Arc<P>,
+        wip_limits: Arc<dyn WipLimitRepository>,
+        team_overrides: Arc<dyn TeamOverrideRepository>,
+        board_snapshots: Arc<dyn BoardSnapshotRepository>,
+        organization: impl Into<String>,
+        project: impl Into<String>,
+    ) -> Self {
+        Self {
+            provider,
+            wip_limits,
+            team_overrides,
+            board_snapshots,
+            organization: organization.into(),
+            project: project.into(),
+        }
+    }
+
+    /// Limits are advisory for board rendering, so a storage failure should
+    /// not take the whole board down.
+    async fn wip_limits_or_empty(&self, team: Option<&str>) -> Vec<BoardColumnWipLimit> {
+        self.wip_limits
+            .list_limits(&self.organization, &self.project, team)
+            .await
+            .unwrap_or_else(|err| {
+                tracing::warn!("Failed to load WIP limits: {err}");
+                vec![]
+            })
     }
 }
 
@@ -40,26 +86,34 @@ impl<P: WorkItemProvider> WorkItemService for WorkItemServiceImpl<P> {
         iteration_path: Option<&str>,
         team: Option<&str>,
     ) -> Result<BoardData, WorkItemError> {
-        let ids = self
-            .provider
-            .query_work_item_ids(iteration_path, team)
-            .await?;
-
+        // Column metadata doesn't depend on the item ids, so fetch it while
+        // the WIQL query and item batches are in flight.
         let fetch_items = async {
-            if ids.is_empty() {
-                Ok(vec![])
+            let ids = self
+                .provider
+                .query_work_item_ids(iteration_path, team)
+                .await?;
+            let items = if ids.is_empty() {
+                vec![]
             } else {
-                self.provider.get_work_items(&ids).await
-            }
+                self.provider.get_work_items(&ids).await?
+            };
+            Ok::<_, WorkItemError>((ids.len(), items))
         };
         let fetch_columns = self.provider.get_board_columns(iteration_path, team);
         let fetch_assignments = self
             .provider
             .get_taskboard_column_assignments(iteration_path, team);
 
-        let (items_result, mut columns, assignments) =
-            tokio::join!(fetch_items, fetch_columns, fetch_assignments);
-        let mut items = items_result?;
+        let fetch_wip_limits = self.wip_limits_or_empty(team);
+
+        let (items_result, mut columns, assignments, wip_limits) = tokio::join!(
+            fetch_items,
+            fetch_columns,
+            fetch_assignments,
+            fetch_wip_limits
+        );
+        let (queried_id_count, mut items) = items_result?;
 
         for item in &mut items {
             if let Some(assignment) = assignments.get(&item.id) {
@@ -109,14 +163,40 @@ impl<P: WorkItemProvider> WorkItemService for WorkItemServiceImpl<P> {
         tracing::debug!(
             iteration_path = iteration_path.unwrap_or("<current>"),
             team = team.unwrap_or("<default>"),
-            queried_ids = ids.len(),
+            queried_ids = queried_id_count,
             item_count = items.len(),
             column_count = columns.len(),
             assignment_count = assignments.len(),
             "Built work item board data"
         );
 
-        Ok(BoardData { columns, items })
+        Ok(BoardData {
+            columns,
+            items,
+            wip_limits,
+        })
+    }
+
+    async fn get_assigned_work_items(
+        &self,
+        assignee: &str,
+    ) -> Result<Vec<WorkItem>, WorkItemError> {
+        let mut ids = self.provider.query_assigned_work_item_ids(assignee).await?;
+        ids.truncate(MAX_ASSIGNED_WORK_ITEMS);
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Batch fetches don't keep the query's most-recently-changed order.
+        let mut items = self.provider.get_work_items(&ids).await?;
+        let position: HashMap<&str, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (id.as_str(), index))
+            .collect();
+        items.sort_by_key(|item| position.get(item.id.as_str()).copied());
+
+        Ok(items)
     }
 
     async fn format_work_item_for_llm(
@@ -136,7 +216,7 @@ impl<P: WorkItemProvider> WorkItemService for WorkItemServiceImpl<P> {
         target_column_name: &str,
         iteration_path: Option<&str>,
         team: Option<&str>,
-    ) -> Result<(), WorkItemError> {
+    ) -> Result<Option<WipLimitViolation>, WorkItemError> {
         let work_item_id = work_item_id.trim();
         if work_item_id.is_empty() {
             return Err(WorkItemError::InvalidInput(
@@ -151,10 +231,289 @@ impl<P: WorkItemProvider> WorkItemService for WorkItemServiceImpl<P> {
             ));
         }
 
+        let violation = self
+            .check_wip_limit(work_item_id, target_column_name, iteration_path, team)
+            .await?;
+        if let Some(violation) = &violation {
+            if violation.enforcement == WipLimitEnforcement::Block {
+                return Err(WorkItemError::WipLimitExceeded(violation.clone()));
+            }
+        }
+
         self.provider
             .move_work_item_to_column(work_item_id, target_column_name, iteration_path, team)
+            .await?;
+
+        Ok(violation)
+    }
+
+    async fn log_work_item_hours(
+        &self,
+        work_item_id: &str,
+        hours: f64,
+    ) -> Result<bool, WorkItemError> {
+        let work_item_id = work_item_id.trim();
+        if work_item_id.is_empty() {
+            return Err(WorkItemError::InvalidInput(
+                "work_item_id cannot be empty".to_string(),
+            ));
+        }
+        if !hours.is_finite() || hours <= 0.0 {
+            return Err(WorkItemError::InvalidInput(format!(
+                "cannot log {hours} hours"
+            )));
+        }
+
+        for _ in 0..MAX_EFFORT_UPDATE_ATTEMPTS {
+            let Some(effort) = self.provider.get_work_item_effort(work_item_id).await? else {
+                return Ok(false);
+            };
+            if self
+                .provider
+                .set_work_item_effort(work_item_id, effort.log(hours))
+                .await?
+            {
+                return Ok(true);
+            }
+        }
+
+        Err(WorkItemError::ProviderError(format!(
+            "work item {work_item_id} kept changing while logging hours"
+        )))
+    }
+
+    async fn get_wip_limits(
+        &self,
+        team: Option<&str>,
+    ) -> Result<Vec<BoardColumnWipLimit>, WorkItemError> {
+        self.wip_limits
+            .list_limits(&self.organization, &self.project, team)
             .await
     }
+
+    async fn set_wip_limit(
+        &self,
+        team: Option<&str>,
+        limit: BoardColumnWipLimit,
+    ) -> Result<(), WorkItemError> {
+        let column_name = limit.column_name.trim();
+        if column_name.is_empty() {
+            return Err(WorkItemError::InvalidInput(
+                "column_name cannot be empty".to_string(),
+            ));
+        }
+        if limit.limit < 1 {
+            return Err(WorkItemError::InvalidInput(
+                "WIP limit must be at least 1".to_string(),
+            ));
+        }
+
+        let limit = BoardColumnWipLimit {
+            column_name: column_name.to_string(),
+            ..limit
+        };
+        self.wip_limits
+            .upsert_limit(&self.organization, &self.project, team, &limit)
+            .await
+    }
+
+    async fn remove_wip_limit(
+        &self,
+        team: Option<&str>,
+        column_name: &str,
+    ) -> Result<(), WorkItemError> {
+        self.wip_limits
+            .delete_limit(&self.organization, &self.project, team, column_name.trim())
+            .await
+    }
+
+    async fn get_sprint_capacity_plans(
+        &self,
+        team: Option<&str>,
+        count: usize,
+    ) -> Result<Vec<SprintCapacityPlan>, WorkItemError> {
+        self.provider.get_sprint_capacity_plans(team, count).await
+    }
+
+    async fn get_project_teams(&self) -> Result<ProjectTeams, WorkItemError> {
+        self.provider.get_project_teams().await
+    }
+
+    async fn set_team_override(&self, team: Option<&str>) -> Result<ProjectTeams, WorkItemError> {
+        let mut teams = self.provider.get_project_teams().await?;
+
+        match team.map(str::trim).filter(|team| !team.is_empty()) {
+            Some(team) => {
+                let Some(team) = teams.find_team(team).map(str::to_string) else {
+                    return Err(WorkItemError::InvalidInput(format!(
+                        "Unknown team '{team}' in project '{}'",
+                        self.project
+                    )));
+                };
+                self.team_overrides
+                    .set_team_override(&self.organization, &self.project, &team)
+                    .await?;
+                teams.override_team = Some(team);
+            }
+            None => {
+                self.team_overrides
+                    .clear_team_override(&self.organization, &self.project)
+                    .await?;
+                teams.override_team = None;
+            }
+        }
+
+        Ok(teams)
+    }
+
+    async fn snapshot_current_board(&self) -> Result<(), WorkItemError> {
+        let (team, iteration_path) = self.resolve_board(None, None).await?;
+        let board = self
+            .get_board_data(Some(&iteration_path), Some(&team))
+            .await?;
+        let snapshot = BoardSnapshot::from_board(&board, OffsetDateTime::now_utc());
+
+        let scope = self.snapshot_scope(&team, &iteration_path);
+        let latest = self
+            .board_snapshots
+            .snapshot_at_or_before(scope, snapshot.taken_at)
+            .await?;
+        // The latest snapshot keeps describing the board until it changes.
+        if latest.is_some_and(|latest| latest.same_board_as(&snapshot)) {
+            return Ok(());
+        }
+
+        self.board_snapshots.save_snapshot(scope, &snapshot).await
+    }
+
+    async fn get_board_diff(
+        &self,
+        iteration_path: Option<&str>,
+        team: Option<&str>,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> Result<BoardDiff, WorkItemError> {
+        if from >= to {
+            return Err(WorkItemError::InvalidInput(
+                "from must be before to".to_string(),
+            ));
+        }
+
+        let (team, iteration_path) = self.resolve_board(iteration_path, team).await?;
+        let scope = self.snapshot_scope(&team, &iteration_path);
+
+        let Some(end) = self
+            .board_snapshots
+            .snapshot_at_or_before(scope, to)
+            .await?
+        else {
+            return Err(WorkItemError::InvalidInput(format!(
+                "No board snapshots of '{iteration_path}' for team '{team}' before {to}"
+            )));
+        };
+        // Without a snapshot from before the window, start from the first
+        // one taken inside it.
+        let start = match self
+            .board_snapshots
+            .snapshot_at_or_before(scope, from)
+            .await?
+        {
+            Some(start) => start,
+            None => self
+                .board_snapshots
+                .snapshot_at_or_after(scope, from)
+                .await?
+                .filter(|start| start.taken_at <= end.taken_at)
+                .unwrap_or_else(|| end.clone()),
+        };
+
+        Ok(BoardDiff::between(&start, &end))
+    }
+}
+
+impl<P: WorkItemProvider> WorkItemServiceImpl<P> {
+    /// Canonical team name and iteration path of a board, defaulting to the
+    /// project's default team and current iteration.
+    async fn resolve_board(
+        &self,
+        iteration_path: Option<&str>,
+        team: Option<&str>,
+    ) -> Result<(String, String), WorkItemError> {
+        let teams = self.provider.get_project_teams().await?;
+        let team = match team.map(str::trim).filter(|team| !team.is_empty()) {
+            Some(team) => teams.find_team(team).unwrap_or(team).to_string(),
+            None => teams.default_team().to_string(),
+        };
+
+        let iteration_path = match iteration_path {
+            Some(path) => path.to_string(),
+            None => self
+                .provider
+                .get_iterations()
+                .await?
+                .into_iter()
+                .find(|iteration| iteration.is_current)
+                .map(|iteration| iteration.path)
+                .ok_or_else(|| {
+                    WorkItemError::InvalidInput(format!(
+                        "Project '{}' has no current iteration",
+                        self.project
+                    ))
+                })?,
+        };
+
+        Ok((team, iteration_path))
+    }
+
+    fn snapshot_scope<'a>(
+        &'a self,
+        team: &'a str,
+        iteration_path: &'a str,
+    ) -> BoardSnapshotScope<'a> {
+        BoardSnapshotScope {
+            organization: &self.organization,
+            project: &self.project,
+            team,
+            iteration_path,
+        }
+    }
+
+    /// Check whether moving `work_item_id` into `target_column_name` would
+    /// push that column over its WIP limit.
+    ///
+    /// The board is only loaded when the target column actually has a limit.
+    async fn check_wip_limit(
+        &self,
+        work_item_id: &str,
+        target_column_name: &str,
+        iteration_path: Option<&str>,
+        team: Option<&str>,
+    ) -> Result<Option<WipLimitViolation>, WorkItemError> {
+        let limits = self
+            .wip_limits
+            .list_limits(&self.organization, &self.project, team)
+            .await?;
+        let Some(limit) = limits
+            .into_iter()
+            .find(|limit| limit.applies_to(target_column_name))
+        else {
+            return Ok(None);
+        };
+
+        let board = self.get_board_data(iteration_path, team).await?;
+        let items_in_column = board
+            .items
+            .iter()
+            .filter(|item| item.id != work_item_id)
+            .filter(|item| {
+                item.board_column_name
+                    .as_deref()
+                    .is_some_and(|name| limit.applies_to(name))
+            })
+            .count();
+
+        Ok(limit.check(items_in_column + 1))
+    }
 }
 
 fn fallback_columns() -> Vec<BoardColumn> {
@@ -325,13 +684,14 @@ fn compare_work_item_ids(a: &str, b: &str) -> Ordering {
 mod tests {
     use std::cmp::Ordering;
     use std::collections::HashMap;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     use async_trait::async_trait;
     use time::OffsetDateTime;
 
     use crate::domain::models::{
-        BoardColumnAssignment, PullRequestRef, WorkItemCategory, WorkItemPerson, WorkItemRef,
+        BoardColumnAssignment, PullRequestRef, WorkItemCategory, WorkItemEffort, WorkItemPerson,
+        WorkItemRef,
     };
 
     use super::*;
@@ -354,16 +714,21 @@ mod tests {
 
     #[derive(Clone, Default)]
     struct MockProvider {
+        iterations: Vec<Iteration>,
         ids: Vec<String>,
         items: Vec<WorkItem>,
         columns: Vec<BoardColumn>,
         assignments: HashMap<String, BoardColumnAssignment>,
+        moves: Arc<Mutex<Vec<(String, String)>>>,
+        effort: Option<WorkItemEffort>,
+        stale_effort_writes: Arc<Mutex<usize>>,
+        logged_efforts: Arc<Mutex<Vec<WorkItemEffort>>>,
     }
 
     #[async_trait]
     impl WorkItemProvider for MockProvider {
         async fn get_iterations(&self) -> Result<Vec<Iteration>, WorkItemError> {
-            Ok(vec![])
+            Ok(self.iterations.clone())
         }
 
         async fn query_work_item_ids(
@@ -374,6 +739,13 @@ mod tests {
             Ok(self.ids.clone())
         }
 
+        async fn query_assigned_work_item_ids(
+            &self,
+            _assignee: &str,
+        ) -> Result<Vec<String>, WorkItemError> {
+            Ok(self.ids.clone())
+        }
+
         async fn get_work_items(&self, _ids: &[String]) -> Result<Vec<WorkItem>, WorkItemError> {
             Ok(self.items.clone())
         }
@@ -408,6 +780,22 @@ mod tests {
             Ok((String::new(), false))
         }
 
+        async fn get_sprint_capacity_plans(
+            &self,
+            _team: Option<&str>,
+            _count: usize,
+        ) -> Result<Vec<SprintCapacityPlan>, WorkItemError> {
+            Ok(vec![])
+        }
+
+        async fn get_project_teams(&self) -> Result<ProjectTeams, WorkItemError> {
+            Ok(ProjectTeams {
+                teams: vec!["Platform Team".to_string(), "Ops".to_string()],
+                detected_team: "Platform Team".to_string(),
+                override_team: None,
+            })
+        }
+
         async fn fetch_image(&self, _image_url: &str) -> Result<WorkItemImage, WorkItemError> {
             Ok(WorkItemImage {
                 bytes: vec![],
@@ -417,15 +805,214 @@ mod tests {
 
         async fn move_work_item_to_column(
             &self,
-            _work_item_id: &str,
-            _target_column_name: &str,
+            work_item_id: &str,
+            target_column_name: &str,
             _iteration_path: Option<&str>,
             _team: Option<&str>,
         ) -> Result<(), WorkItemError> {
+            self.moves
+                .lock()
+                .unwrap()
+                .push((work_item_id.to_string(), target_column_name.to_string()));
+            Ok(())
+        }
+
+        async fn get_work_item_effort(
+            &self,
+            _work_item_id: &str,
+        ) -> Result<Option<WorkItemEffort>, WorkItemError> {
+            Ok(self.effort)
+        }
+
+        async fn set_work_item_effort(
+            &self,
+            _work_item_id: &str,
+            effort: WorkItemEffort,
+        ) -> Result<bool, WorkItemError> {
+            let mut stale_writes = self.stale_effort_writes.lock().unwrap();
+            if *stale_writes > 0 {
+                *stale_writes -= 1;
+                return Ok(false);
+            }
+            self.logged_efforts.lock().unwrap().push(effort);
+            Ok(true)
+        }
+    }
+
+    struct MockWipLimits(Vec<BoardColumnWipLimit>);
+
+    #[async_trait]
+    impl WipLimitRepository for MockWipLimits {
+        async fn list_limits(
+            &self,
+            _organization: &str,
+            _project: &str,
+            _team: Option<&str>,
+        ) -> Result<Vec<BoardColumnWipLimit>, WorkItemError> {
+            Ok(self.0.clone())
+        }
+
+        async fn upsert_limit(
+            &self,
+            _organization: &str,
+            _project: &str,
+            _team: Option<&str>,
+            _limit: &BoardColumnWipLimit,
+        ) -> Result<(), WorkItemError> {
+            Ok(())
+        }
+
+        async fn delete_limit(
+            &self,
+            _organization: &str,
+            _project: &str,
+            _team: Option<&str>,
+            _column_name: &str,
+        ) -> Result<(), WorkItemError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct MockTeamOverrides(Arc<Mutex<Option<String>>>);
+
+    #[async_trait]
+    impl TeamOverrideRepository for MockTeamOverrides {
+        async fn get_team_override(
+            &self,
+            _organization: &str,
+            _project: &str,
+        ) -> Result<Option<String>, WorkItemError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        async fn set_team_override(
+            &self,
+            _organization: &str,
+            _project: &str,
+            team: &str,
+        ) -> Result<(), WorkItemError> {
+            *self.0.lock().unwrap() = Some(team.to_string());
+            Ok(())
+        }
+
+        async fn clear_team_override(
+            &self,
+            _organization: &str,
+            _project: &str,
+        ) -> Result<(), WorkItemError> {
+            *self.0.lock().unwrap() = None;
             Ok(())
         }
     }
 
+    /// Snapshots of a single board, in the order they were saved.
+    #[derive(Clone, Default)]
+    struct MockBoardSnapshots(Arc<Mutex<Vec<BoardSnapshot>>>);
+
+    #[async_trait]
+    impl BoardSnapshotRepository for MockBoardSnapshots {
+        async fn save_snapshot(
+            &self,
+            _scope: BoardSnapshotScope<'_>,
+            snapshot: &BoardSnapshot,
+        ) -> Result<(), WorkItemError> {
+            self.0.lock().unwrap().push(snapshot.clone());
+            Ok(())
+        }
+
+        async fn snapshot_at_or_before(
+            &self,
+            _scope: BoardSnapshotScope<'_>,
+            at: OffsetDateTime,
+        ) -> Result<Option<BoardSnapshot>, WorkItemError> {
+            let snapshots = self.0.lock().unwrap();
+            Ok(snapshots
+                .iter()
+                .rev()
+                .find(|snapshot| snapshot.taken_at <= at)
+                .cloned())
+        }
+
+        async fn snapshot_at_or_after(
+            &self,
+            _scope: BoardSnapshotScope<'_>,
+            at: OffsetDateTime,
+        ) -> Result<Option<BoardSnapshot>, WorkItemError> {
+            let snapshots = self.0.lock().unwrap();
+            Ok(snapshots
+                .iter()
+                .find(|snapshot| snapshot.taken_at >= at)
+                .cloned())
+        }
+    }
+
+    fn service_with_limits(
+        provider: MockProvider,
+        limits: Vec<BoardColumnWipLimit>,
+    ) -> WorkItemServiceImpl<MockProvider> {
+        service_with_team_overrides(provider, limits, MockTeamOverrides::default())
+    }
+
+    fn service_with_team_overrides(
+        provider: MockProvider,
+        limits: Vec<BoardColumnWipLimit>,
+        team_overrides: MockTeamOverrides,
+    ) -> WorkItemServiceImpl<MockProvider> {
+        WorkItemServiceImpl::new(
+            Arc::new(provider),
+            Arc::new(MockWipLimits(limits)),
+            Arc::new(team_overrides),
+            Arc::new(MockBoardSnapshots::default()),
+            "org",
+            "project",
+        )
+    }
+
+    fn service_with_snapshots(
+        provider: MockProvider,
+        snapshots: MockBoardSnapshots,
+    ) -> WorkItemServiceImpl<MockProvider> {
+        WorkItemServiceImpl::new(
+            Arc::new(provider),
+            Arc::new(MockWipLimits(vec![])),
+            Arc::new(MockTeamOverrides::default()),
+            Arc::new(snapshots),
+            "org",
+            "project",
+        )
+    }
+
+    fn current_iteration() -> Iteration {
+        Iteration {
+            id: "1".to_string(),
+            name: "Sprint 1".to_string(),
+            path: "Project\\Sprint 1".to_string(),
+            start_date: None,
+            finish_date: None,
+            is_current: true,
+        }
+    }
+
+    fn provider_with_items_in_progress(ids: &[&str]) -> MockProvider {
+        MockProvider {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            items: ids
+                .iter()
+                .map(|id| make_item(id, BoardState::InProgress, None))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn in_progress_limit(limit: i32, enforcement: WipLimitEnforcement) -> BoardColumnWipLimit {
+        BoardColumnWipLimit {
+            column_name: "In Progress".to_string(),
+            limit,
+            enforcement,
+        }
+    }
+
     fn make_item(id: &str, board_state: BoardState, priority: Option<i32>) -> WorkItem {
         WorkItem {
             id: id.to_string(),
@@ -467,6 +1054,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn assigned_work_items_keep_the_query_order() {
+        let provider = MockProvider {
+            ids: vec!["3".to_string(), "1".to_string(), "2".to_string()],
+            items: vec![
+                make_item("1", BoardState::InProgress, None),
+                make_item("2", BoardState::Todo, None),
+                make_item("3", BoardState::InProgress, None),
+            ],
+            ..Default::default()
+        };
+        let service = service_with_limits(provider, vec![]);
+
+        let items = service
+            .get_assigned_work_items("user@example.com")
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["3", "1", "2"]);
+    }
+
     #[tokio::test]
     async fn uses_fallback_columns_when_board_columns_are_unavailable() {
         let provider = MockProvider {
@@ -474,7 +1083,7 @@ mod tests {
             items: vec![make_item("1", BoardState::InProgress, Some(2))],
             ..Default::default()
         };
-        let service = WorkItemServiceImpl::new(Arc::new(provider));
+        let service = service_with_limits(provider, vec![]);
 
         let board = service.get_board_data(None, None).await.unwrap();
 
@@ -505,7 +1114,7 @@ mod tests {
             items: vec![item_one, item_two],
             ..Default::default()
         };
-        let service = WorkItemServiceImpl::new(Arc::new(provider));
+        let service = service_with_limits(provider, vec![]);
 
         let board = service.get_board_data(None, None).await.unwrap();
 
@@ -568,7 +1177,7 @@ mod tests {
             }],
             assignments,
         };
-        let service = WorkItemServiceImpl::new(Arc::new(provider));
+        let service = service_with_limits(provider, vec![]);
 
         let board = service.get_board_data(None, None).await.unwrap();
 
@@ -605,7 +1214,7 @@ mod tests {
             ],
             ..Default::default()
         };
-        let service = WorkItemServiceImpl::new(Arc::new(provider));
+        let service = service_with_limits(provider, vec![]);
 
         let board = service.get_board_data(None, None).await.unwrap();
         let item_ids: Vec<_> = board.items.iter().map(|item| item.id.as_str()).collect();
@@ -615,7 +1224,7 @@ mod tests {
 
     #[tokio::test]
     async fn move_work_item_validates_input() {
-        let service = WorkItemServiceImpl::new(Arc::new(MockProvider::default()));
+        let service = service_with_limits(MockProvider::default(), vec![]);
 
         let empty_id_err = service
             .move_work_item_to_column("   ", "Done", None, None)
@@ -629,4 +1238,224 @@ mod tests {
             .unwrap_err();
         assert!(matches!(empty_column_err, WorkItemError::InvalidInput(_)));
     }
+
+    #[tokio::test]
+    async fn move_within_wip_limit_reports_no_violation() {
+        let provider = provider_with_items_in_progress(&["1"]);
+        let moves = provider.moves.clone();
+        let service = service_with_limits(
+            provider,
+            vec![in_progress_limit(2, WipLimitEnforcement::Block)],
+        );
+
+        let violation = service
+            .move_work_item_to_column("9", "in progress", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(violation, None);
+        assert_eq!(moves.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn move_over_warn_limit_moves_and_reports_violation() {
+        let provider = provider_with_items_in_progress(&["1", "2"]);
+        let moves = provider.moves.clone();
+        let service = service_with_limits(
+            provider,
+            vec![in_progress_limit(2, WipLimitEnforcement::Warn)],
+        );
+
+        let violation = service
+            .move_work_item_to_column("9", "In Progress", None, None)
+            .await
+            .unwrap()
+            .expect("violation");
+
+        assert_eq!(violation.item_count, 3);
+        assert_eq!(violation.limit, 2);
+        assert_eq!(violation.enforcement, WipLimitEnforcement::Warn);
+        assert_eq!(moves.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn move_over_block_limit_is_rejected() {
+        let provider = provider_with_items_in_progress(&["1", "2"]);
+        let moves = provider.moves.clone();
+        let service = service_with_limits(
+            provider,
+            vec![in_progress_limit(2, WipLimitEnforcement::Block)],
+        );
+
+        let err = service
+            .move_work_item_to_column("9", "In Progress", None, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            WorkItemError::WipLimitExceeded(WipLimitViolation { item_count: 3, .. })
+        ));
+        assert!(moves.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reordering_within_a_full_column_is_allowed() {
+        let provider = provider_with_items_in_progress(&["1", "2"]);
+        let service = service_with_limits(
+            provider,
+            vec![in_progress_limit(2, WipLimitEnforcement::Block)],
+        );
+
+        let violation = service
+            .move_work_item_to_column("2", "In Progress", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(violation, None);
+    }
+
+    #[tokio::test]
+    async fn set_wip_limit_rejects_non_positive_limits() {
+        let service = service_with_limits(MockProvider::default(), vec![]);
+
+        let err = service
+            .set_wip_limit(None, in_progress_limit(0, WipLimitEnforcement::Warn))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, WorkItemError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn set_team_override_stores_the_canonical_team_name() {
+        let overrides = MockTeamOverrides::default();
+        let service =
+            service_with_team_overrides(MockProvider::default(), vec![], overrides.clone());
+
+        let teams = service.set_team_override(Some(" ops ")).await.unwrap();
+
+        assert_eq!(teams.default_team(), "Ops");
+        assert_eq!(overrides.0.lock().unwrap().as_deref(), Some("Ops"));
+
+        let teams = service.set_team_override(None).await.unwrap();
+
+        assert_eq!(teams.default_team(), "Platform Team");
+        assert_eq!(*overrides.0.lock().unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn set_team_override_rejects_unknown_teams() {
+        let overrides = MockTeamOverrides::default();
+        let service =
+            service_with_team_overrides(MockProvider::default(), vec![], overrides.clone());
+
+        let err = service
+            .set_team_override(Some("Space Ninjas"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, WorkItemError::InvalidInput(_)));
+        assert_eq!(*overrides.0.lock().unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn snapshot_current_board_skips_unchanged_boards() {
+        let snapshots = MockBoardSnapshots::default();
+        let provider = MockProvider {
+            iterations: vec![current_iteration()],
+            ..provider_with_items_in_progress(&["1", "2"])
+        };
+        let service = service_with_snapshots(provider, snapshots.clone());
+
+        service.snapshot_current_board().await.unwrap();
+        service.snapshot_current_board().await.unwrap();
+
+        let saved = snapshots.0.lock().unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_board_diff_compares_snapshots_around_the_window() {
+        let now = OffsetDateTime::now_utc();
+        let snapshot = |hours_ago: i64, ids: &[&str]| {
+            let board = BoardData {
+                columns: vec![],
+                items: ids
+                    .iter()
+                    .map(|id| make_item(id, BoardState::InProgress, None))
+                    .collect(),
+                wip_limits: vec![],
+            };
+            BoardSnapshot::from_board(&board, now - time::Duration::hours(hours_ago))
+        };
+        let snapshots = MockBoardSnapshots(Arc::new(Mutex::new(vec![
+            snapshot(48, &["1"]),
+            snapshot(24, &["1", "2"]),
+            snapshot(1, &["2", "3"]),
+        ])));
+        let provider = MockProvider {
+            iterations: vec![current_iteration()],
+            ..Default::default()
+        };
+        let service = service_with_snapshots(provider, snapshots);
+
+        let diff = service
+            .get_board_diff(None, None, now - time::Duration::hours(30), now)
+            .await
+            .unwrap();
+
+        assert_eq!(diff.from, now - time::Duration::hours(48));
+        let added: Vec<&str> = diff.added.iter().map(|item| item.id.as_str()).collect();
+        let removed: Vec<&str> = diff.removed.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(added, vec!["2", "3"]);
+        assert_eq!(removed, vec!["1"]);
+    }
+
+    #[tokio::test]
+    async fn get_board_diff_requires_snapshots() {
+        let provider = MockProvider {
+            iterations: vec![current_iteration()],
+            ..Default::default()
+        };
+        let service = service_with_snapshots(provider, MockBoardSnapshots::default());
+        let now = OffsetDateTime::now_utc();
+
+        let err = service
+            .get_board_diff(None, None, now - time::Duration::days(7), now)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, WorkItemError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn log_work_item_hours_retries_when_the_work_item_changed() {
+        let provider = MockProvider {
+            effort: Some(WorkItemEffort {
+                completed_work: Some(1.0),
+                remaining_work: Some(4.0),
+                revision: Some(3),
+            }),
+            stale_effort_writes: Arc::new(Mutex::new(1)),
+            ..Default::default()
+        };
+        let logged_efforts = provider.logged_efforts.clone();
+        let service = service_with_limits(provider.clone(), vec![]);
+
+        assert!(service.log_work_item_hours("42", 1.5).await.unwrap());
+        assert_eq!(
+            *logged_efforts.lock().unwrap(),
+            vec![WorkItemEffort {
+                completed_work: Some(2.5),
+                remaining_work: Some(2.5),
+                revision: Some(3),
+            }]
+        );
+
+        *provider.stale_effort_writes.lock().unwrap() = MAX_EFFORT_UPDATE_ATTEMPTS;
+        let err = service.log_work_item_hours("42", 1.5).await.unwrap_err();
+        assert!(matches!(err, WorkItemError::ProviderError(_)));
+    }
 }
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+pub const MAX_BANNER_MESSAGE_CHARS: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "status_banner_severity", rename_all = "snake_case")]
+#[serde(rename_all = "lowercase")]
+pub enum BannerSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// Announcement set by an admin and shown at the top of every client.
+///
+/// A maintenance banner also pauses time tracking while it is shown, so
+/// clients get a clear message instead of errors from the provider.
+#[derive(Debug, Clone)]
+pub struct StatusBanner {
+    pub message: String,
+    pub severity: BannerSeverity,
+    pub starts_at: Option<OffsetDateTime>,
+    pub ends_at: Option<OffsetDateTime>,
+    pub maintenance: bool,
+}
+
+impl StatusBanner {
+    pub fn is_shown(&self, now: OffsetDateTime) -> bool {
+        self.starts_at.is_none_or(|starts_at| starts_at <= now)
+            && self.ends_at.is_none_or(|ends_at| now < ends_at)
+    }
+
+    pub fn is_maintenance(&self, now: OffsetDateTime) -> bool {
+        self.maintenance && self.is_shown(now)
+    }
+
+    /// Check a banner before storing it.
+    pub fn validate(&self, now: OffsetDateTime) -> Result<(), &'static str> {
+        if self.message.trim().is_empty() {
+            return Err("message must not be empty");
+        }
+        if self.message.chars().count() > MAX_BANNER_MESSAGE_CHARS {
+            return Err("message is too long");
+        }
+        if let (Some(starts_at), Some(ends_at)) = (self.starts_at, self.ends_at) {
+            if ends_at <= starts_at {
+                return Err("the banner must end after it starts");
+            }
+        }
+        if self.ends_at.is_some_and(|ends_at| ends_at <= now) {
+            return Err("the banner must end in the future");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::Duration;
+
+    fn banner(starts_in: Option<i64>, ends_in: Option<i64>) -> (StatusBanner, OffsetDateTime) {
+        let now = OffsetDateTime::now_utc();
+        let banner = StatusBanner {
+            message: "Kleer is down for an upgrade".to_string(),
+            severity: BannerSeverity::Warning,
+            starts_at: starts_in.map(|h| now + Duration::hours(h)),
+            ends_at: ends_in.map(|h| now + Duration::hours(h)),
+            maintenance: true,
+        };
+        (banner, now)
+    }
+
+    #[test]
+    fn shown_only_inside_its_window() {
+        let (open_ended, now) = banner(None, None);
+        assert!(open_ended.is_maintenance(now));
+
+        let (upcoming, now) = banner(Some(1), Some(2));
+        assert!(!upcoming.is_shown(now));
+        assert!(upcoming.is_shown(now + Duration::minutes(90)));
+        assert!(!upcoming.is_shown(now + Duration::hours(2)));
+    }
+
+    #[test]
+    fn rejects_empty_or_backwards_banners() {
+        let (mut banner, now) = banner(Some(2), Some(1));
+        assert!(banner.validate(now).is_err());
+
+        banner.starts_at = None;
+        assert!(banner.validate(now).is_ok());
+
+        banner.message = "  ".to_string();
+        assert!(banner.validate(now).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::config::ReminderSettings;
+
+use super::models::WeeklyStats;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TimerReminderError {
+    #[error("Invalid UTC offset: {0} minutes")]
+    InvalidOffset(i16),
+    #[error("Invalid check hour {0}")]
+    InvalidCheckHour(u8),
+    #[error("Invalid timer check interval: {0} minutes")]
+    InvalidInterval(u16),
+}
+
+/// When to remind users about forgotten timers and unlogged days.
+#[derive(Debug, Clone)]
+pub struct TimerReminders {
+    pub enabled: bool,
+    pub timer_check_interval: std::time::Duration,
+    long_timer: Duration,
+    offset: UtcOffset,
+    check_time: Time,
+    week_check_time: Time,
+}
+
+impl TimerReminders {
+    pub fn from_settings(settings: &ReminderSettings) -> Result<Self, TimerReminderError> {
+        let offset = UtcOffset::from_whole_seconds(i32::from(settings.utc_offset_minutes) * 60)
+            .map_err(|_| TimerReminderError::InvalidOffset(settings.utc_offset_minutes))?;
+        let check_time = Time::from_hms(settings.check_hour, 0, 0)
+            .map_err(|_| TimerReminderError::InvalidCheckHour(settings.check_hour))?;
+        let week_check_time = Time::from_hms(settings.week_check_hour, 0, 0)
+            .map_err(|_| TimerReminderError::InvalidCheckHour(settings.week_check_hour))?;
+        if settings.timer_check_interval_minutes == 0 {
+            return Err(TimerReminderError::InvalidInterval(
+                settings.timer_check_interval_minutes,
+            ));
+        }
+
+        Ok(Self {
+            enabled: settings.enabled,
+            timer_check_interval: std::time::Duration::from_secs(
+                u64::from(settings.timer_check_interval_minutes) * 60,
+            ),
+            long_timer: Duration::hours(i64::from(settings.long_timer_hours)),
+            offset,
+            check_time,
+            week_check_time,
+        })
+    }
+
+    /// Whether a timer started at `started_at` has run long enough at `now`
+    /// to probably be forgotten.
+    pub fn is_long_running(&self, started_at: OffsetDateTime, now: OffsetDateTime) -> bool {
+        now - started_at >= self.long_timer
+    }
+
+    /// The next weekday at `check_hour` local time after `now`.
+    pub fn next_check(&self, now: OffsetDateTime) -> OffsetDateTime {
+        let mut date = now.to_offset(self.offset).date();
+        loop {
+            let check = date.with_time(self.check_time).assume_offset(self.offset);
+            if is_weekday(date) && check > now {
+                return check;
+            }
+            match date.next_day() {
+                Some(next) => date = next,
+                None => return now + Duration::days(1),
+            }
+        }
+    }
+
+    /// The next Friday at `week_check_hour` local time after `now`.
+    pub fn next_week_check(&self, now: OffsetDateTime) -> OffsetDateTime {
+        let mut date = now.to_offset(self.offset).date();
+        loop {
+            let check = date
+                .with_time(self.week_check_time)
+                .assume_offset(self.offset);
+            if date.weekday() == Weekday::Friday && check > now {
+                return check;
+            }
+            match date.next_day() {
+                Some(next) => date = next,
+                None => return now + Duration::weeks(1),
+            }
+        }
+    }
+}
+
+/// A day counts as unlogged when work was scheduled but nothing, neither
+/// work nor absence, was registered. Holidays have no scheduled hours.
+pub fn is_unlogged_day(stats: &WeeklyStats) -> bool {
+    stats.scheduled_hours > 0.0 && stats.covered_hours <= 0.0
+}
+
+/// Hours of the week's schedule that are covered by neither work nor absence.
+/// Less than a minute is treated as fully reported.
+pub fn unreported_hours(stats: &WeeklyStats) -> Option<f64> {
+    (stats.remaining_hours >= 1.0 / 60.0).then_some(stats.remaining_hours)
+}
+
+/// The Monday of the week containing `date`.
+pub fn week_start(date: Date) -> Date {
+    date - Duration::days(i64::from(date.weekday().number_days_from_monday()))
+}
+
+/// The working day before `date`, skipping weekends.
+pub fn previous_working_day(date: Date) -> Date {
+    let mut date = date;
+    while let Some(previous) = date.previous_day() {
+        date = previous;
+        if is_weekday(date) {
+            break;
+        }
+    }
+    date
+}
+
+fn is_weekday(date: Date) -> bool {
+    !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn october(day: u8) -> Date {
+        Date::from_calendar_date(2026, Month::October, day).unwrap()
+    }
+
+    fn utc(day: u8, hour: u8) -> OffsetDateTime {
+        october(day).with_hms(hour, 0, 0).unwrap().assume_utc()
+    }
+
+    fn reminders() -> TimerReminders {
+        TimerReminders::from_settings(&ReminderSettings::default()).unwrap()
+    }
+
+    #[test]
+    fn timers_are_long_running_after_the_configured_hours() {
+        let reminders = reminders();
+
+        assert!(!reminders.is_long_running(utc(16, 8), utc(16, 17)));
+        assert!(reminders.is_long_running(utc(15, 8), utc(16, 7)));
+    }
+
+    #[test]
+    fn checks_the_previous_working_day_on_weekday_mornings() {
+        // Friday 10:00 UTC -> Monday 09:00 local (08:00 UTC).
+        assert_eq!(reminders().next_check(utc(16, 10)), utc(19, 8));
+        // Monday looks back at Friday, Tuesday at Monday.
+        assert_eq!(previous_working_day(october(19)), october(16));
+        assert_eq!(previous_working_day(october(20)), october(19));
+    }
+
+    #[test]
+    fn only_scheduled_days_without_registrations_are_unlogged() {
+        assert!(is_unlogged_day(&WeeklyStats::new(0.0, 8.0, 0.0)));
+        assert!(!is_unlogged_day(&WeeklyStats::new(0.0, 8.0, 8.0)));
+        assert!(!is_unlogged_day(&WeeklyStats::new(2.5, 8.0, 0.0)));
+        assert!(!is_unlogged_day(&WeeklyStats::new(0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn checks_the_week_on_friday_afternoons() {
+        // Wednesday -> Friday 16:00 local (15:00 UTC), and a week later once
+        // the check has passed.
+        assert_eq!(reminders().next_week_check(utc(14, 10)), utc(16, 15));
+        assert_eq!(reminders().next_week_check(utc(16, 15)), utc(23, 15));
+        assert_eq!(week_start(october(16)), october(12));
+        assert_eq!(week_start(october(12)), october(12));
+    }
+
+    #[test]
+    fn weeks_missing_scheduled_hours_are_unreported() {
+        assert_eq!(
+            unreported_hours(&WeeklyStats::new(30.0, 40.0, 2.0)),
+            Some(8.0)
+        );
+        assert_eq!(unreported_hours(&WeeklyStats::new(32.0, 40.0, 8.0)), None);
+        assert_eq!(unreported_hours(&WeeklyStats::new(0.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn rejects_a_zero_check_interval() {
+        let settings = ReminderSettings {
+            timer_check_interval_minutes: 0,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            TimerReminders::from_settings(&settings),
+            Err(TimerReminderError::InvalidInterval(0))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::domain::models::WipLimitViolation;
+
 /// Errors that can occur during work item operations.
 #[derive(Debug, Error)]
 pub enum WorkItemError {
@@ -7,4 +9,8 @@ pub enum WorkItemError {
     InvalidInput(String),
     #[error("Provider error: {0}")]
     ProviderError(String),
+    #[error("WIP limit exceeded: {0}")]
+    WipLimitExceeded(WipLimitViolation),
+    #[error("Storage error: {0}")]
+    Storage(String),
 }
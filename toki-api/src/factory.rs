@@ -4,11 +4,14 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use axum::http::StatusCode;
 use az_devops::RepoClient;
-use kleer::KleerCredentials;
+use kleer::{KleerClient, KleerCredentials};
+use moka::sync::Cache;
+use sqlx::PgPool;
 use tokio::sync::RwLock;
 use url::Url;
 
@@ -19,8 +22,12 @@ use crate::{
             WorkItemServiceFactory,
         },
         outbound::{
-            azure_devops::AzureDevOpsWorkItemAdapter, kleer::KleerAdapter,
-            postgres::PostgresTimerHistoryAdapter,
+            azure_devops::{AzureDevOpsWorkItemAdapter, TeamResolutionCache},
+            kleer::KleerAdapter,
+            postgres::{
+                PostgresBoardSnapshotRepository, PostgresTeamOverrideRepository,
+                PostgresTimerHistoryAdapter, PostgresWipLimitRepository,
+            },
         },
     },
     config::KleerSettings,
@@ -28,7 +35,10 @@ use crate::{
         models::{UserId, WorkItemProject, KLEER_TIME_TRACKING_PROVIDER},
         ports::{
             inbound::{TimeTrackingService, WorkItemService},
-            outbound::TimeTrackingUserLinkRepository,
+            outbound::{
+                BoardSnapshotRepository, TeamOverrideRepository, TimeTrackingUserLinkRepository,
+                WipLimitRepository, WorkItemTimeLinkRepository,
+            },
         },
         services::{TimeTrackingServiceImpl, WorkItemServiceImpl},
         RepoKey,
@@ -36,33 +46,77 @@ use crate::{
     repositories::{TimerRepositoryImpl, UserRepository, UserRepositoryImpl},
 };
 
+const KLEER_USER_ID_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const KLEER_USER_ID_CACHE_MAX_ENTRIES: u64 = 2_048;
+const TEAM_OVERRIDE_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const TEAM_OVERRIDE_CACHE_MAX_ENTRIES: u64 = 512;
+
 /// Concrete factory that creates Kleer-backed TimeTrackingService instances.
+///
+/// A single `KleerClient` is shared by all services so requests reuse its
+/// connection pool, and resolved Toki → Kleer user mappings are cached for a
+/// few minutes to skip the link lookup on every request. Each service counts
+/// entry dates in its user's time zone and records work item mentions.
 pub struct KleerServiceFactory {
     timer_repo: Arc<TimerRepositoryImpl>,
     user_link_repo: Arc<dyn TimeTrackingUserLinkRepository>,
-    credentials: Result<KleerCredentials, String>,
+    user_repo: Arc<UserRepositoryImpl>,
+    work_item_links: Arc<dyn WorkItemTimeLinkRepository>,
+    client: Result<KleerClient, String>,
+    kleer_user_ids: Cache<UserId, i64>,
 }
 
 impl KleerServiceFactory {
     pub fn new(
         timer_repo: Arc<TimerRepositoryImpl>,
         user_link_repo: Arc<dyn TimeTrackingUserLinkRepository>,
+        user_repo: Arc<UserRepositoryImpl>,
+        work_item_links: Arc<dyn WorkItemTimeLinkRepository>,
         settings: KleerSettings,
     ) -> Self {
+        let client = settings.credentials().and_then(|credentials| {
+            KleerClient::new(credentials)
+                .map(|client| client.with_retry_policy(settings.retry.policy()))
+                .map_err(|error| format!("failed to create Kleer client: {error}"))
+        });
+
         Self {
             timer_repo,
             user_link_repo,
-            credentials: settings.credentials(),
+            user_repo,
+            work_item_links,
+            client,
+            kleer_user_ids: Cache::builder()
+                .time_to_live(KLEER_USER_ID_CACHE_TTL)
+                .max_capacity(KLEER_USER_ID_CACHE_MAX_ENTRIES)
+                .build(),
         }
     }
 
-    fn credentials(&self) -> Result<KleerCredentials, TimeTrackingServiceError> {
-        self.credentials
-            .clone()
-            .map_err(TimeTrackingServiceError::configuration)
+    fn client(&self) -> Result<&KleerClient, TimeTrackingServiceError> {
+        self.client
+            .as_ref()
+            .map_err(|error| TimeTrackingServiceError::configuration(error.clone()))
     }
 
     async fn mapped_kleer_user_id(
+        &self,
+        user_id: UserId,
+        credentials: &KleerCredentials,
+    ) -> Result<i64, TimeTrackingServiceError> {
+        if let Some(kleer_user_id) = self.kleer_user_ids.get(&user_id) {
+            return Ok(kleer_user_id);
+        }
+
+        let kleer_user_id = self
+            .lookup_kleer_user_id(user_id, &credentials.company_id)
+            .await?;
+        self.kleer_user_ids.insert(user_id, kleer_user_id);
+
+        Ok(kleer_user_id)
+    }
+
+    async fn lookup_kleer_user_id(
         &self,
         user_id: UserId,
         provider_company_id: &str,
@@ -93,20 +147,28 @@ impl TimeTrackingServiceFactory for KleerServiceFactory {
         &self,
         user_id: UserId,
     ) -> Result<Box<dyn TimeTrackingService>, TimeTrackingServiceError> {
-        let credentials = self.credentials()?;
+        let client = self.client()?;
         let kleer_user_id = self
-            .mapped_kleer_user_id(user_id, &credentials.company_id)
+            .mapped_kleer_user_id(user_id, client.credentials())
             .await?;
-        let adapter = KleerAdapter::new(credentials, kleer_user_id).map_err(|error| {
-            TimeTrackingServiceError::configuration(format!(
-                "failed to create Kleer service: {error}"
-            ))
-        })?;
+        let (time_zone, lunch_rule) = self
+            .user_repo
+            .get_time_tracking_settings(user_id)
+            .await
+            .map_err(|error| TimeTrackingServiceError::internal(error.to_string()))?;
+        let adapter = KleerAdapter::new(client.clone(), kleer_user_id);
         let history_adapter = PostgresTimerHistoryAdapter::new(self.timer_repo.clone());
-        let service = TimeTrackingServiceImpl::new(Arc::new(adapter), Arc::new(history_adapter));
+        let service = TimeTrackingServiceImpl::new(Arc::new(adapter), Arc::new(history_adapter))
+            .with_time_zone(time_zone)
+            .with_lunch_rule(lunch_rule)
+            .with_work_item_links(self.work_item_links.clone());
 
         Ok(Box::new(service))
     }
+
+    fn invalidate_user(&self, user_id: UserId) {
+        self.kleer_user_ids.invalidate(&user_id);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -117,9 +179,18 @@ impl TimeTrackingServiceFactory for KleerServiceFactory {
 ///
 /// Finds a `RepoClient` matching the requested organization and project,
 /// wraps it in an `AzureDevOpsWorkItemAdapter`, and returns a `WorkItemServiceImpl`.
+///
+/// Default team detection and current iteration lookups are shared across
+/// requests through a `TeamResolutionCache`, and each project's team override
+/// is cached for a few minutes.
 pub struct AzureDevOpsWorkItemServiceFactory {
     repo_clients: Arc<RwLock<HashMap<RepoKey, RepoClient>>>,
     user_repo: Arc<UserRepositoryImpl>,
+    wip_limits: Arc<dyn WipLimitRepository>,
+    team_overrides: Arc<dyn TeamOverrideRepository>,
+    board_snapshots: Arc<dyn BoardSnapshotRepository>,
+    team_cache: TeamResolutionCache,
+    team_override_cache: Cache<(String, String), Option<String>>,
     api_base_url: Url,
 }
 
@@ -127,14 +198,51 @@ impl AzureDevOpsWorkItemServiceFactory {
     pub fn new(
         repo_clients: Arc<RwLock<HashMap<RepoKey, RepoClient>>>,
         user_repo: Arc<UserRepositoryImpl>,
+        db_pool: PgPool,
         api_base_url: Url,
     ) -> Self {
         Self {
             repo_clients,
             user_repo,
+            wip_limits: Arc::new(PostgresWipLimitRepository::new(db_pool.clone())),
+            team_overrides: Arc::new(PostgresTeamOverrideRepository::new(db_pool.clone())),
+            board_snapshots: Arc::new(PostgresBoardSnapshotRepository::new(db_pool)),
+            team_cache: TeamResolutionCache::new(),
+            team_override_cache: Cache::builder()
+                .time_to_live(TEAM_OVERRIDE_CACHE_TTL)
+                .max_capacity(TEAM_OVERRIDE_CACHE_MAX_ENTRIES)
+                .build(),
             api_base_url,
         }
     }
+
+    /// The project's team override. A storage failure falls back to team
+    /// detection rather than failing the request.
+    async fn team_override(&self, organization: &str, project: &str) -> Option<String> {
+        let key = project_cache_key(organization, project);
+        if let Some(team) = self.team_override_cache.get(&key) {
+            return team;
+        }
+
+        match self
+            .team_overrides
+            .get_team_override(organization, project)
+            .await
+        {
+            Ok(team) => {
+                self.team_override_cache.insert(key, team.clone());
+                team
+            }
+            Err(err) => {
+                tracing::warn!("Failed to load team override for {organization}/{project}: {err}");
+                None
+            }
+        }
+    }
+}
+
+fn project_cache_key(organization: &str, project: &str) -> (String, String) {
+    (organization.to_lowercase(), project.to_lowercase())
 }
 
 #[async_trait]
@@ -144,23 +252,40 @@ impl WorkItemServiceFactory for AzureDevOpsWorkItemServiceFactory {
         organization: &str,
         project: &str,
     ) -> Result<Box<dyn WorkItemService>, WorkItemServiceError> {
-        // 1. Find any RepoClient matching the requested org+project
+        // 1. Find a RepoClient matching the requested org+project. Team detection
+        //    looks at the repo name, so always pick the same one.
         let clients = self.repo_clients.read().await;
         let client = clients
             .iter()
-            .find(|(key, _)| {
+            .filter(|(key, _)| {
                 key.organization.eq_ignore_ascii_case(organization)
                     && key.project.eq_ignore_ascii_case(project)
             })
+            .min_by(|(a, _), (b, _)| a.repo_name.cmp(&b.repo_name))
             .map(|(_, client)| client.clone())
             .ok_or_else(|| WorkItemServiceError {
                 status: StatusCode::NOT_FOUND,
                 message: format!("No client found for {}/{}", organization, project),
             })?;
 
+        drop(clients);
+
         // 2. Create adapter and service
-        let adapter = AzureDevOpsWorkItemAdapter::new(client, self.api_base_url.clone());
-        let service = WorkItemServiceImpl::new(Arc::new(adapter));
+        let team_override = self.team_override(organization, project).await;
+        let adapter = AzureDevOpsWorkItemAdapter::new(
+            client,
+            self.api_base_url.clone(),
+            self.team_cache.clone(),
+        )
+        .with_team_override(team_override);
+        let service = WorkItemServiceImpl::new(
+            Arc::new(adapter),
+            self.wip_limits.clone(),
+            self.team_overrides.clone(),
+            self.board_snapshots.clone(),
+            organization,
+            project,
+        );
         Ok(Box::new(service))
     }
 
@@ -208,4 +333,9 @@ impl WorkItemServiceFactory for AzureDevOpsWorkItemServiceFactory {
 
         Ok(projects)
     }
+
+    fn invalidate_project(&self, organization: &str, project: &str) {
+        self.team_override_cache
+            .invalidate(&project_cache_key(organization, project));
+    }
 }
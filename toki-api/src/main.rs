@@ -20,6 +20,8 @@ mod factory;
 mod repositories;
 mod router;
 mod routes;
+#[cfg(test)]
+mod test_support;
 mod utils;
 
 #[tokio::main]
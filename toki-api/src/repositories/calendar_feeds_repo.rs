@@ -0,0 +1,118 @@
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use super::repo_error::RepositoryError;
+
+/// Users' calendar feeds and the meetings they chose not to log.
+pub trait CalendarFeedRepository {
+    async fn get_feed_url(&self, user_id: i32) -> Result<Option<String>, RepositoryError>;
+    async fn set_feed_url(&self, user_id: i32, url: &str) -> Result<(), RepositoryError>;
+    /// Returns false if the user had no feed.
+    async fn delete_feed(&self, user_id: i32) -> Result<bool, RepositoryError>;
+    /// Uid and start of the dismissed meetings starting from `since`.
+    async fn get_dismissals(
+        &self,
+        user_id: i32,
+        since: OffsetDateTime,
+    ) -> Result<Vec<(String, OffsetDateTime)>, RepositoryError>;
+    async fn dismiss(
+        &self,
+        user_id: i32,
+        event_uid: &str,
+        starts_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError>;
+}
+
+pub struct CalendarFeedRepositoryImpl {
+    pool: PgPool,
+}
+
+impl CalendarFeedRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl CalendarFeedRepository for CalendarFeedRepositoryImpl {
+    async fn get_feed_url(&self, user_id: i32) -> Result<Option<String>, RepositoryError> {
+        let url = sqlx::query_scalar!(
+            r#"SELECT url FROM calendar_feeds WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(url)
+    }
+
+    async fn set_feed_url(&self, user_id: i32, url: &str) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO calendar_feeds (user_id, url)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET url = EXCLUDED.url,
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            url
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_feed(&self, user_id: i32) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(r#"DELETE FROM calendar_feeds WHERE user_id = $1"#, user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_dismissals(
+        &self,
+        user_id: i32,
+        since: OffsetDateTime,
+    ) -> Result<Vec<(String, OffsetDateTime)>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT event_uid, starts_at
+            FROM calendar_dismissals
+            WHERE user_id = $1 AND starts_at >= $2
+            "#,
+            user_id,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.event_uid, row.starts_at))
+            .collect())
+    }
+
+    async fn dismiss(
+        &self,
+        user_id: i32,
+        event_uid: &str,
+        starts_at: OffsetDateTime,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO calendar_dismissals (user_id, event_uid, starts_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            "#,
+            user_id,
+            event_uid,
+            starts_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
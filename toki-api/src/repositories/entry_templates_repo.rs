@@ -0,0 +1,126 @@
+use sqlx::PgPool;
+
+use crate::domain::models::{ActivityId, EntryTemplate, ProjectId};
+
+use super::repo_error::RepositoryError;
+
+/// Time entry templates users saved.
+pub trait EntryTemplateRepository {
+    async fn get_templates(&self, user_id: i32) -> Result<Vec<EntryTemplate>, RepositoryError>;
+    /// Create the template, or replace the user's existing template with the
+    /// same name.
+    async fn upsert_template(
+        &self,
+        user_id: i32,
+        template: &NewEntryTemplate,
+    ) -> Result<EntryTemplate, RepositoryError>;
+    /// Returns false if the user has no template with that id.
+    async fn delete_template(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
+
+pub struct EntryTemplateRepositoryImpl {
+    pool: PgPool,
+}
+
+impl EntryTemplateRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewEntryTemplate {
+    pub name: String,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+    pub note: String,
+    pub duration_minutes: Option<i32>,
+}
+
+impl EntryTemplateRepository for EntryTemplateRepositoryImpl {
+    async fn get_templates(&self, user_id: i32) -> Result<Vec<EntryTemplate>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, name, project_id, project_name, activity_id, activity_name, note,
+                duration_minutes
+            FROM entry_templates
+            WHERE user_id = $1
+            ORDER BY name
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| EntryTemplate {
+                id: row.id,
+                name: row.name,
+                project_id: ProjectId::new(row.project_id),
+                project_name: row.project_name,
+                activity_id: ActivityId::new(row.activity_id),
+                activity_name: row.activity_name,
+                note: row.note,
+                duration_minutes: row.duration_minutes,
+            })
+            .collect())
+    }
+
+    async fn upsert_template(
+        &self,
+        user_id: i32,
+        template: &NewEntryTemplate,
+    ) -> Result<EntryTemplate, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO entry_templates (user_id, name, project_id, project_name, activity_id,
+                activity_name, note, duration_minutes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id, name) DO UPDATE
+            SET project_id = EXCLUDED.project_id,
+                project_name = EXCLUDED.project_name,
+                activity_id = EXCLUDED.activity_id,
+                activity_name = EXCLUDED.activity_name,
+                note = EXCLUDED.note,
+                duration_minutes = EXCLUDED.duration_minutes
+            RETURNING id
+            "#,
+            user_id,
+            template.name,
+            template.project_id.as_str(),
+            template.project_name,
+            template.activity_id.as_str(),
+            template.activity_name,
+            template.note,
+            template.duration_minutes
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(EntryTemplate {
+            id: row.id,
+            name: template.name.clone(),
+            project_id: template.project_id.clone(),
+            project_name: template.project_name.clone(),
+            activity_id: template.activity_id.clone(),
+            activity_name: template.activity_name.clone(),
+            note: template.note.clone(),
+            duration_minutes: template.duration_minutes,
+        })
+    }
+
+    async fn delete_template(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM entry_templates WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
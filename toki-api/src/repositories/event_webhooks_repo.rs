@@ -0,0 +1,238 @@
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::{EventKind, EventWebhook, EventWebhookDelivery};
+
+use super::repo_error::RepositoryError;
+
+pub trait EventWebhookRepository {
+    async fn get_user_webhooks(&self, user_id: i32) -> Result<Vec<EventWebhook>, RepositoryError>;
+    async fn get_subscribed_webhooks(
+        &self,
+        user_id: i32,
+        event: EventKind,
+    ) -> Result<Vec<EventWebhook>, RepositoryError>;
+    async fn insert_webhook(
+        &self,
+        webhook: NewEventWebhook,
+    ) -> Result<EventWebhook, RepositoryError>;
+    async fn delete_webhook(&self, id: i32, user_id: i32) -> Result<(), RepositoryError>;
+    async fn insert_delivery(
+        &self,
+        delivery: NewEventWebhookDelivery,
+    ) -> Result<(), RepositoryError>;
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        user_id: i32,
+        limit: i64,
+    ) -> Result<Vec<EventWebhookDelivery>, RepositoryError>;
+    /// Delete logged delivery attempts made before `before`, returning how
+    /// many were deleted.
+    async fn delete_deliveries_before(
+        &self,
+        before: OffsetDateTime,
+    ) -> Result<u64, RepositoryError>;
+}
+
+pub struct EventWebhookRepositoryImpl {
+    pool: PgPool,
+}
+
+impl EventWebhookRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct EventWebhookRow {
+    id: i32,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+    created_at: OffsetDateTime,
+}
+
+impl From<EventWebhookRow> for EventWebhook {
+    fn from(row: EventWebhookRow) -> Self {
+        Self {
+            id: row.id,
+            url: row.url,
+            secret: row.secret,
+            // Unknown names are skipped rather than failing the whole webhook.
+            events: row
+                .events
+                .iter()
+                .filter_map(|event| EventKind::parse(event))
+                .collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl EventWebhookRepository for EventWebhookRepositoryImpl {
+    async fn get_user_webhooks(&self, user_id: i32) -> Result<Vec<EventWebhook>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            EventWebhookRow,
+            r#"
+            SELECT id, url, secret, events, created_at
+            FROM event_webhooks
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(EventWebhook::from).collect())
+    }
+
+    async fn get_subscribed_webhooks(
+        &self,
+        user_id: i32,
+        event: EventKind,
+    ) -> Result<Vec<EventWebhook>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            EventWebhookRow,
+            r#"
+            SELECT id, url, secret, events, created_at
+            FROM event_webhooks
+            WHERE user_id = $1 AND $2 = ANY(events)
+            "#,
+            user_id,
+            event.as_str()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(EventWebhook::from).collect())
+    }
+
+    async fn insert_webhook(
+        &self,
+        webhook: NewEventWebhook,
+    ) -> Result<EventWebhook, RepositoryError> {
+        let events: Vec<String> = webhook
+            .events
+            .iter()
+            .map(|event| event.as_str().to_string())
+            .collect();
+
+        let row = sqlx::query_as!(
+            EventWebhookRow,
+            r#"
+            INSERT INTO event_webhooks (user_id, url, secret, events)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, url, secret, events, created_at
+            "#,
+            webhook.user_id,
+            webhook.url,
+            webhook.secret,
+            &events
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_webhook(&self, id: i32, user_id: i32) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM event_webhooks WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("webhook {id}")));
+        }
+
+        Ok(())
+    }
+
+    async fn insert_delivery(
+        &self,
+        delivery: NewEventWebhookDelivery,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO event_webhook_deliveries (
+                webhook_id, event_id, event, attempt, status_code, error, success
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            delivery.webhook_id,
+            delivery.event_id,
+            delivery.event,
+            delivery.attempt,
+            delivery.status_code,
+            delivery.error,
+            delivery.success
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_deliveries(
+        &self,
+        webhook_id: i32,
+        user_id: i32,
+        limit: i64,
+    ) -> Result<Vec<EventWebhookDelivery>, RepositoryError> {
+        let deliveries = sqlx::query_as!(
+            EventWebhookDelivery,
+            r#"
+            SELECT d.id, d.event_id, d.event, d.attempt, d.status_code, d.error,
+                d.success, d.created_at
+            FROM event_webhook_deliveries d
+            JOIN event_webhooks w ON w.id = d.webhook_id
+            WHERE d.webhook_id = $1 AND w.user_id = $2
+            ORDER BY d.created_at DESC, d.id DESC
+            LIMIT $3
+            "#,
+            webhook_id,
+            user_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    async fn delete_deliveries_before(
+        &self,
+        before: OffsetDateTime,
+    ) -> Result<u64, RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM event_webhook_deliveries WHERE created_at < $1"#,
+            before
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+pub struct NewEventWebhook {
+    pub user_id: i32,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<EventKind>,
+}
+
+pub struct NewEventWebhookDelivery {
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub event: String,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub success: bool,
+}
@@ -0,0 +1,109 @@
+use sqlx::PgPool;
+
+use crate::domain::models::{ActivityId, Favorite, ProjectId};
+
+use super::repo_error::RepositoryError;
+
+/// Project and activity combinations users pinned.
+pub trait FavoriteRepository {
+    /// The user's favorites in the order they were pinned.
+    async fn get_favorites(&self, user_id: i32) -> Result<Vec<Favorite>, RepositoryError>;
+    /// Pin the combination, or refresh the names of the user's existing
+    /// favorite for the same project and activity.
+    async fn upsert_favorite(
+        &self,
+        user_id: i32,
+        favorite: &NewFavorite,
+    ) -> Result<Favorite, RepositoryError>;
+    /// Returns false if the user has no favorite with that id.
+    async fn delete_favorite(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
+
+pub struct FavoriteRepositoryImpl {
+    pool: PgPool,
+}
+
+impl FavoriteRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewFavorite {
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+}
+
+impl FavoriteRepository for FavoriteRepositoryImpl {
+    async fn get_favorites(&self, user_id: i32) -> Result<Vec<Favorite>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, project_id, project_name, activity_id, activity_name
+            FROM favorites
+            WHERE user_id = $1
+            ORDER BY created_at, id
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Favorite {
+                id: row.id,
+                project_id: ProjectId::new(row.project_id),
+                project_name: row.project_name,
+                activity_id: ActivityId::new(row.activity_id),
+                activity_name: row.activity_name,
+            })
+            .collect())
+    }
+
+    async fn upsert_favorite(
+        &self,
+        user_id: i32,
+        favorite: &NewFavorite,
+    ) -> Result<Favorite, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO favorites (user_id, project_id, project_name, activity_id, activity_name)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, project_id, activity_id) DO UPDATE
+            SET project_name = EXCLUDED.project_name,
+                activity_name = EXCLUDED.activity_name
+            RETURNING id
+            "#,
+            user_id,
+            favorite.project_id.as_str(),
+            favorite.project_name,
+            favorite.activity_id.as_str(),
+            favorite.activity_name
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(Favorite {
+            id: row.id,
+            project_id: favorite.project_id.clone(),
+            project_name: favorite.project_name.clone(),
+            activity_id: favorite.activity_id.clone(),
+            activity_name: favorite.activity_name.clone(),
+        })
+    }
+
+    async fn delete_favorite(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM favorites WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,86 @@
+use sqlx::PgPool;
+use time::Date;
+
+use crate::domain::models::FlexSnapshot;
+
+use super::repo_error::RepositoryError;
+
+/// Daily flex balances recorded by the snapshot job.
+pub trait FlexSnapshotRepository {
+    /// Snapshots between `from` and `to`, inclusive, oldest first.
+    async fn get_snapshots(
+        &self,
+        user_id: i32,
+        from: Date,
+        to: Date,
+    ) -> Result<Vec<FlexSnapshot>, RepositoryError>;
+    /// Record the snapshot, replacing any earlier one of the same day.
+    async fn upsert_snapshot(
+        &self,
+        user_id: i32,
+        snapshot: &FlexSnapshot,
+    ) -> Result<(), RepositoryError>;
+}
+
+pub struct FlexSnapshotRepositoryImpl {
+    pool: PgPool,
+}
+
+impl FlexSnapshotRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl FlexSnapshotRepository for FlexSnapshotRepositoryImpl {
+    async fn get_snapshots(
+        &self,
+        user_id: i32,
+        from: Date,
+        to: Date,
+    ) -> Result<Vec<FlexSnapshot>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT date, flex_hours
+            FROM flex_snapshots
+            WHERE user_id = $1 AND date BETWEEN $2 AND $3
+            ORDER BY date
+            "#,
+            user_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| FlexSnapshot {
+                date: row.date,
+                flex_hours: row.flex_hours,
+            })
+            .collect())
+    }
+
+    async fn upsert_snapshot(
+        &self,
+        user_id: i32,
+        snapshot: &FlexSnapshot,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO flex_snapshots (user_id, date, flex_hours)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, date) DO UPDATE
+            SET flex_hours = EXCLUDED.flex_hours, taken_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            snapshot.date,
+            snapshot.flex_hours
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
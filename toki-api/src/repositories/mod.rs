@@ -1,16 +1,38 @@
+mod calendar_feeds_repo;
+mod entry_templates_repo;
+mod event_webhooks_repo;
+mod favorites_repo;
+mod flex_snapshots_repo;
 mod notification_repo;
+mod notification_webhooks_repo;
+mod project_budgets_repo;
 mod push_subscriptions_repo;
 mod repo_error;
 mod repository_repo;
+mod status_banner_repo;
 mod time_tracking_user_link_repo;
+mod timer_auto_stops_repo;
+mod timer_devices_repo;
 mod timer_repo;
 mod user_repo;
+mod work_item_time_mappings_repo;
 
+pub use calendar_feeds_repo::*;
+pub use entry_templates_repo::*;
+pub use event_webhooks_repo::*;
+pub use favorites_repo::*;
+pub use flex_snapshots_repo::*;
 pub use notification_repo::*;
+pub use notification_webhooks_repo::*;
+pub use project_budgets_repo::*;
 pub use push_subscriptions_repo::*;
 pub use repo_error::RepositoryError;
 pub use repository_repo::*;
+pub use status_banner_repo::*;
 #[allow(unused_imports)]
 pub use time_tracking_user_link_repo::*;
+pub use timer_auto_stops_repo::*;
+pub use timer_devices_repo::*;
 pub use timer_repo::*;
 pub use user_repo::*;
+pub use work_item_time_mappings_repo::*;
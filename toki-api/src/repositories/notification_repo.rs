@@ -181,7 +181,7 @@ impl NotificationRepository for NotificationRepositoryImpl {
             SELECT 
                 id, user_id, repository_id,
                 notification_type as "notification_type: DbNotificationType",
-                enabled, push_enabled
+                enabled, push_enabled, email_enabled, webhook_enabled
             FROM notification_rules
             WHERE user_id = $1 AND repository_id = $2
             "#,
@@ -200,24 +200,29 @@ impl NotificationRepository for NotificationRepositoryImpl {
             NotificationRule,
             r#"
             INSERT INTO notification_rules (
-                user_id, repository_id, notification_type, enabled, push_enabled
+                user_id, repository_id, notification_type,
+                enabled, push_enabled, email_enabled, webhook_enabled
             )
-            VALUES ($1, $2, $3, $4, $5)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ON CONFLICT (user_id, repository_id, notification_type)
             DO UPDATE SET 
                 enabled = EXCLUDED.enabled, 
                 push_enabled = EXCLUDED.push_enabled,
+                email_enabled = EXCLUDED.email_enabled,
+                webhook_enabled = EXCLUDED.webhook_enabled,
                 updated_at = CURRENT_TIMESTAMP
             RETURNING 
                 id, user_id, repository_id,
                 notification_type as "notification_type: DbNotificationType",
-                enabled, push_enabled
+                enabled, push_enabled, email_enabled, webhook_enabled
             "#,
             rule.user_id,
             rule.repository_id,
             rule.notification_type.clone() as DbNotificationType,
             rule.enabled,
-            rule.push_enabled
+            rule.push_enabled,
+            rule.email_enabled,
+            rule.webhook_enabled
         )
         .fetch_one(&self.pool)
         .await?)
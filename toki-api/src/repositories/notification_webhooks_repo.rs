@@ -0,0 +1,177 @@
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::domain::{ChatWebhookEvent, NotificationWebhook, WebhookKind};
+
+use super::repo_error::RepositoryError;
+
+pub trait NotificationWebhookRepository {
+    async fn get_webhooks(&self) -> Result<Vec<NotificationWebhook>, RepositoryError>;
+    async fn get_user_webhooks(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<NotificationWebhook>, RepositoryError>;
+    async fn upsert_webhook(
+        &self,
+        webhook: NewNotificationWebhook,
+    ) -> Result<NotificationWebhook, RepositoryError>;
+    async fn update_webhook_events(
+        &self,
+        id: i32,
+        user_id: i32,
+        events: &[ChatWebhookEvent],
+    ) -> Result<NotificationWebhook, RepositoryError>;
+    async fn delete_webhook(&self, id: i32, user_id: i32) -> Result<(), RepositoryError>;
+}
+
+pub struct NotificationWebhookRepositoryImpl {
+    pool: PgPool,
+}
+
+impl NotificationWebhookRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct NotificationWebhookRow {
+    id: i32,
+    user_id: i32,
+    kind: WebhookKind,
+    url: String,
+    events: Vec<String>,
+    created_at: OffsetDateTime,
+}
+
+impl From<NotificationWebhookRow> for NotificationWebhook {
+    fn from(row: NotificationWebhookRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: row.user_id,
+            kind: row.kind,
+            url: row.url,
+            // Unknown names are skipped rather than failing the whole webhook.
+            events: row
+                .events
+                .iter()
+                .filter_map(|event| ChatWebhookEvent::parse(event))
+                .collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn event_names(events: &[ChatWebhookEvent]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| event.as_str().to_string())
+        .collect()
+}
+
+impl NotificationWebhookRepository for NotificationWebhookRepositoryImpl {
+    async fn get_webhooks(&self) -> Result<Vec<NotificationWebhook>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            NotificationWebhookRow,
+            r#"
+            SELECT id, user_id, kind as "kind: WebhookKind", url, events, created_at
+            FROM notification_webhooks
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(NotificationWebhook::from).collect())
+    }
+
+    async fn get_user_webhooks(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<NotificationWebhook>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            NotificationWebhookRow,
+            r#"
+            SELECT id, user_id, kind as "kind: WebhookKind", url, events, created_at
+            FROM notification_webhooks
+            WHERE user_id = $1
+            ORDER BY created_at
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(NotificationWebhook::from).collect())
+    }
+
+    async fn upsert_webhook(
+        &self,
+        webhook: NewNotificationWebhook,
+    ) -> Result<NotificationWebhook, RepositoryError> {
+        let row = sqlx::query_as!(
+            NotificationWebhookRow,
+            r#"
+            INSERT INTO notification_webhooks (user_id, kind, url, events)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, url) DO UPDATE
+            SET kind = EXCLUDED.kind, events = EXCLUDED.events
+            RETURNING id, user_id, kind as "kind: WebhookKind", url, events, created_at
+            "#,
+            webhook.user_id,
+            webhook.kind as WebhookKind,
+            webhook.url,
+            &event_names(&webhook.events)
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update_webhook_events(
+        &self,
+        id: i32,
+        user_id: i32,
+        events: &[ChatWebhookEvent],
+    ) -> Result<NotificationWebhook, RepositoryError> {
+        let row = sqlx::query_as!(
+            NotificationWebhookRow,
+            r#"
+            UPDATE notification_webhooks
+            SET events = $3
+            WHERE id = $1 AND user_id = $2
+            RETURNING id, user_id, kind as "kind: WebhookKind", url, events, created_at
+            "#,
+            id,
+            user_id,
+            &event_names(events)
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| RepositoryError::NotFound(format!("webhook {id}")))?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_webhook(&self, id: i32, user_id: i32) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM notification_webhooks WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("webhook {id}")));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct NewNotificationWebhook {
+    pub user_id: i32,
+    pub kind: WebhookKind,
+    pub url: String,
+    pub events: Vec<ChatWebhookEvent>,
+}
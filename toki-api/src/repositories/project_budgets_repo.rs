@@ -0,0 +1,108 @@
+use sqlx::PgPool;
+
+use crate::domain::models::{BudgetPeriod, ProjectBudget, ProjectId};
+
+use super::repo_error::RepositoryError;
+
+/// Target hours users set per project.
+pub trait ProjectBudgetRepository {
+    async fn get_budgets(&self, user_id: i32) -> Result<Vec<ProjectBudget>, RepositoryError>;
+    /// Create the budget, or replace the target of the user's existing budget
+    /// for the same project and period.
+    async fn upsert_budget(
+        &self,
+        user_id: i32,
+        budget: &NewProjectBudget,
+    ) -> Result<ProjectBudget, RepositoryError>;
+    /// Returns false if the user has no budget with that id.
+    async fn delete_budget(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError>;
+}
+
+pub struct ProjectBudgetRepositoryImpl {
+    pool: PgPool,
+}
+
+impl ProjectBudgetRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewProjectBudget {
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub period: BudgetPeriod,
+    pub target_hours: f64,
+}
+
+impl ProjectBudgetRepository for ProjectBudgetRepositoryImpl {
+    async fn get_budgets(&self, user_id: i32) -> Result<Vec<ProjectBudget>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, project_id, project_name, period as "period: BudgetPeriod", target_hours
+            FROM project_budgets
+            WHERE user_id = $1
+            ORDER BY project_name, period
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ProjectBudget {
+                id: row.id,
+                project_id: ProjectId::new(row.project_id),
+                project_name: row.project_name,
+                period: row.period,
+                target_hours: row.target_hours,
+            })
+            .collect())
+    }
+
+    async fn upsert_budget(
+        &self,
+        user_id: i32,
+        budget: &NewProjectBudget,
+    ) -> Result<ProjectBudget, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO project_budgets (user_id, project_id, project_name, period, target_hours)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, project_id, period) DO UPDATE
+            SET project_name = EXCLUDED.project_name,
+                target_hours = EXCLUDED.target_hours
+            RETURNING id
+            "#,
+            user_id,
+            budget.project_id.as_str(),
+            budget.project_name,
+            budget.period as BudgetPeriod,
+            budget.target_hours
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(ProjectBudget {
+            id: row.id,
+            project_id: budget.project_id.clone(),
+            project_name: budget.project_name.clone(),
+            period: budget.period,
+            target_hours: budget.target_hours,
+        })
+    }
+
+    async fn delete_budget(&self, user_id: i32, id: i32) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM project_budgets WHERE id = $1 AND user_id = $2"#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
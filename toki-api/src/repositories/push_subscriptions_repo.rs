@@ -15,6 +15,12 @@ pub trait PushSubscriptionRepository {
         push_subscription: NewPushSubscription,
     ) -> Result<(), RepositoryError>;
     async fn delete_push_subscription(&self, id: &i32) -> Result<(), RepositoryError>;
+    /// Returns whether the user had a subscription with the endpoint.
+    async fn delete_user_push_subscription_by_endpoint(
+        &self,
+        user_id: &i32,
+        endpoint: &str,
+    ) -> Result<bool, RepositoryError>;
 }
 
 pub struct PushSubscriptionRepositoryImpl {
@@ -93,6 +99,22 @@ impl PushSubscriptionRepository for PushSubscriptionRepositoryImpl {
 
         Ok(())
     }
+
+    async fn delete_user_push_subscription_by_endpoint(
+        &self,
+        user_id: &i32,
+        endpoint: &str,
+    ) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"DELETE FROM push_subscriptions WHERE user_id = $1 AND endpoint = $2"#,
+            user_id,
+            endpoint
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
 }
 
 pub struct NewPushSubscription {
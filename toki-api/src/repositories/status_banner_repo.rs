@@ -0,0 +1,80 @@
+use sqlx::PgPool;
+
+use crate::domain::{BannerSeverity, StatusBanner};
+
+use super::repo_error::RepositoryError;
+
+pub trait StatusBannerRepository {
+    async fn get_banner(&self) -> Result<Option<StatusBanner>, RepositoryError>;
+    async fn set_banner(
+        &self,
+        banner: &StatusBanner,
+        updated_by: i32,
+    ) -> Result<(), RepositoryError>;
+    async fn clear_banner(&self) -> Result<(), RepositoryError>;
+}
+
+pub struct StatusBannerRepositoryImpl {
+    pool: PgPool,
+}
+
+impl StatusBannerRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl StatusBannerRepository for StatusBannerRepositoryImpl {
+    async fn get_banner(&self) -> Result<Option<StatusBanner>, RepositoryError> {
+        let banner = sqlx::query_as!(
+            StatusBanner,
+            r#"
+            SELECT message, severity as "severity: BannerSeverity", starts_at, ends_at, maintenance
+            FROM status_banner
+            "#
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(banner)
+    }
+
+    async fn set_banner(
+        &self,
+        banner: &StatusBanner,
+        updated_by: i32,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO status_banner (id, message, severity, starts_at, ends_at, maintenance, updated_by)
+            VALUES (1, $1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO UPDATE
+            SET message = EXCLUDED.message,
+                severity = EXCLUDED.severity,
+                starts_at = EXCLUDED.starts_at,
+                ends_at = EXCLUDED.ends_at,
+                maintenance = EXCLUDED.maintenance,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+            banner.message,
+            banner.severity as BannerSeverity,
+            banner.starts_at,
+            banner.ends_at,
+            banner.maintenance,
+            updated_by
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn clear_banner(&self) -> Result<(), RepositoryError> {
+        sqlx::query!(r#"DELETE FROM status_banner"#)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use sqlx::PgPool;
+
+use crate::domain::models::{AutoStoppedTimer, TimerAutoStopAction};
+
+use super::repo_error::RepositoryError;
+
+/// The last timer each user had stopped by their auto-stop rule, kept until
+/// a client has shown it.
+pub trait TimerAutoStopRepository {
+    async fn get_auto_stop(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<AutoStoppedTimer>, RepositoryError>;
+    /// Record the stop, replacing any earlier one not yet shown.
+    async fn record_auto_stop(
+        &self,
+        user_id: i32,
+        stopped: &AutoStoppedTimer,
+    ) -> Result<(), RepositoryError>;
+    async fn clear_auto_stop(&self, user_id: i32) -> Result<(), RepositoryError>;
+}
+
+pub struct TimerAutoStopRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TimerAutoStopRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl TimerAutoStopRepository for TimerAutoStopRepositoryImpl {
+    async fn get_auto_stop(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<AutoStoppedTimer>, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT action, started_at, stopped_at, project_name, activity_name, note, registration_id
+            FROM timer_auto_stops
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| AutoStoppedTimer {
+            action: TimerAutoStopAction::parse(&row.action).unwrap_or_else(|| {
+                tracing::warn!(
+                    "Unknown timer auto-stop action '{}' for user {user_id}, using save",
+                    row.action
+                );
+                TimerAutoStopAction::Save
+            }),
+            started_at: row.started_at,
+            stopped_at: row.stopped_at,
+            project_name: row.project_name,
+            activity_name: row.activity_name,
+            note: row.note,
+            registration_id: row.registration_id,
+        }))
+    }
+
+    async fn record_auto_stop(
+        &self,
+        user_id: i32,
+        stopped: &AutoStoppedTimer,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO timer_auto_stops
+                (user_id, action, started_at, stopped_at, project_name, activity_name, note,
+                 registration_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id) DO UPDATE
+            SET action = EXCLUDED.action,
+                started_at = EXCLUDED.started_at,
+                stopped_at = EXCLUDED.stopped_at,
+                project_name = EXCLUDED.project_name,
+                activity_name = EXCLUDED.activity_name,
+                note = EXCLUDED.note,
+                registration_id = EXCLUDED.registration_id,
+                created_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            stopped.action.as_str(),
+            stopped.started_at,
+            stopped.stopped_at,
+            stopped.project_name,
+            stopped.activity_name,
+            stopped.note,
+            stopped.registration_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn clear_auto_stop(&self, user_id: i32) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"DELETE FROM timer_auto_stops WHERE user_id = $1"#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
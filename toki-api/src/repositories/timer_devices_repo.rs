@@ -0,0 +1,68 @@
+use sqlx::PgPool;
+
+use super::repo_error::RepositoryError;
+
+/// Tracks which device a user's running timer was started or taken over on.
+pub trait TimerDeviceRepository {
+    async fn get_timer_device(&self, user_id: i32) -> Result<Option<TimerDevice>, RepositoryError>;
+    async fn claim_timer(&self, user_id: i32, device_name: &str) -> Result<(), RepositoryError>;
+    async fn release_timer(&self, user_id: i32) -> Result<(), RepositoryError>;
+}
+
+pub struct TimerDeviceRepositoryImpl {
+    pool: PgPool,
+}
+
+impl TimerDeviceRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimerDevice {
+    pub device_name: String,
+}
+
+impl TimerDeviceRepository for TimerDeviceRepositoryImpl {
+    async fn get_timer_device(&self, user_id: i32) -> Result<Option<TimerDevice>, RepositoryError> {
+        let device = sqlx::query_as!(
+            TimerDevice,
+            r#"
+            SELECT device_name
+            FROM timer_devices
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(device)
+    }
+
+    async fn claim_timer(&self, user_id: i32, device_name: &str) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO timer_devices (user_id, device_name)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE
+            SET device_name = EXCLUDED.device_name, claimed_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            device_name
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn release_timer(&self, user_id: i32) -> Result<(), RepositoryError> {
+        sqlx::query!(r#"DELETE FROM timer_devices WHERE user_id = $1"#, user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -14,8 +14,10 @@ pub trait TimerRepository {
     async fn save_active_timer(
         &self,
         user_id: &i32,
+        start_time: &time::OffsetDateTime,
         end_time: &time::OffsetDateTime,
         registration_id: &str,
+        work_item_id: Option<&str>,
     ) -> Result<(), RepositoryError>;
     async fn delete_active_timer(&self, user_id: &i32) -> Result<(), RepositoryError>;
     async fn get_by_registration_id(
@@ -39,6 +41,31 @@ pub trait TimerRepository {
         &self,
         timer: &FinishedDatabaseTimer,
     ) -> Result<i32, RepositoryError>;
+    async fn paused_timers(
+        &self,
+        user_id: &i32,
+    ) -> Result<Vec<DatabasePausedTimer>, RepositoryError>;
+    async fn create_paused_timer(
+        &self,
+        timer: &NewDatabasePausedTimer,
+    ) -> Result<DatabasePausedTimer, RepositoryError>;
+    async fn resume_paused_timer(
+        &self,
+        user_id: &i32,
+        id: Option<i32>,
+        started_at: &time::OffsetDateTime,
+    ) -> Result<ResumedPausedTimer, RepositoryError>;
+    async fn resumed_timer(
+        &self,
+        user_id: &i32,
+    ) -> Result<Option<DatabasePausedTimer>, RepositoryError>;
+    async fn update_resumed_timer_segments(
+        &self,
+        user_id: &i32,
+        segment_starts: &[time::OffsetDateTime],
+        segment_ends: &[time::OffsetDateTime],
+    ) -> Result<(), RepositoryError>;
+    async fn delete_resumed_timer(&self, user_id: &i32) -> Result<(), RepositoryError>;
 }
 
 pub struct TimerRepositoryImpl {
@@ -68,6 +95,7 @@ pub struct DatabaseTimer {
     pub note: Option<String>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: time::OffsetDateTime,
+    pub work_item_id: Option<String>,
 }
 
 pub struct NewDatabaseTimer {
@@ -100,6 +128,38 @@ pub struct FinishedDatabaseTimer {
     pub activity_name: Option<String>,
     pub note: String,
     pub registration_id: String,
+    pub work_item_id: Option<String>,
+}
+
+pub struct DatabasePausedTimer {
+    pub id: i32,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    pub segment_starts: Vec<time::OffsetDateTime>,
+    pub segment_ends: Vec<time::OffsetDateTime>,
+    pub paused_at: time::OffsetDateTime,
+}
+
+/// Outcome of resuming a paused timer. Nothing is changed unless it was
+/// resumed.
+pub enum ResumedPausedTimer {
+    Resumed(DatabasePausedTimer),
+    NotFound,
+    TimerRunning,
+}
+
+pub struct NewDatabasePausedTimer {
+    pub user_id: i32,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    pub segment_starts: Vec<time::OffsetDateTime>,
+    pub segment_ends: Vec<time::OffsetDateTime>,
 }
 
 #[async_trait]
@@ -111,7 +171,7 @@ impl TimerRepository for TimerRepositoryImpl {
         let timers = sqlx::query_as!(
             DatabaseTimer,
             r#"
-            SELECT id, user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, created_at, registration_id
+            SELECT id, user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, created_at, registration_id, work_item_id
             FROM timer_history
             WHERE user_id = $1
             "#,
@@ -127,7 +187,7 @@ impl TimerRepository for TimerRepositoryImpl {
         let timer = sqlx::query_as!(
             DatabaseTimer,
             r#"
-            SELECT id, user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, created_at, registration_id
+            SELECT id, user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, created_at, registration_id, work_item_id
             FROM timer_history
             WHERE user_id = $1 AND end_time IS NULL
             ORDER BY created_at DESC
@@ -180,22 +240,41 @@ impl TimerRepository for TimerRepositoryImpl {
     async fn save_active_timer(
         &self,
         user_id: &i32,
+        start_time: &time::OffsetDateTime,
         end_time: &time::OffsetDateTime,
         registration_id: &str,
+        work_item_id: Option<&str>,
     ) -> Result<(), RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query!(
             r#"
             UPDATE timer_history
-            SET end_time = $1, registration_id = $2
-            WHERE user_id = $3 AND end_time IS NULL
+            SET start_time = $1, end_time = $2, registration_id = $3, work_item_id = $5
+            WHERE user_id = $4 AND end_time IS NULL
             "#,
+            start_time,
             end_time,
             registration_id,
+            user_id,
+            work_item_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The segments the timer was resumed with have been saved with it
+        sqlx::query!(
+            r#"
+            DELETE FROM paused_timers
+            WHERE user_id = $1 AND resumed
+            "#,
             user_id
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -311,9 +390,9 @@ impl TimerRepository for TimerRepositoryImpl {
         let id = sqlx::query!(
             r#"
             INSERT INTO timer_history (
-                user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, registration_id
+                user_id, start_time, end_time, project_id, project_name, activity_id, activity_name, note, registration_id, work_item_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING id
             "#,
             timer.user_id,
@@ -324,7 +403,8 @@ impl TimerRepository for TimerRepositoryImpl {
             timer.activity_id,
             timer.activity_name,
             timer.note,
-            timer.registration_id
+            timer.registration_id,
+            timer.work_item_id
         )
         .fetch_one(&self.pool)
         .await?
@@ -332,4 +412,184 @@ impl TimerRepository for TimerRepositoryImpl {
 
         Ok(id)
     }
+
+    async fn paused_timers(
+        &self,
+        user_id: &i32,
+    ) -> Result<Vec<DatabasePausedTimer>, RepositoryError> {
+        let timers = sqlx::query_as!(
+            DatabasePausedTimer,
+            r#"
+            SELECT id, project_id, project_name, activity_id, activity_name, note, segment_starts, segment_ends, paused_at
+            FROM paused_timers
+            WHERE user_id = $1 AND NOT resumed
+            ORDER BY paused_at DESC, id DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(timers)
+    }
+
+    async fn create_paused_timer(
+        &self,
+        timer: &NewDatabasePausedTimer,
+    ) -> Result<DatabasePausedTimer, RepositoryError> {
+        let timer = sqlx::query_as!(
+            DatabasePausedTimer,
+            r#"
+            INSERT INTO paused_timers (
+                user_id, project_id, project_name, activity_id, activity_name, note, segment_starts, segment_ends
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, project_id, project_name, activity_id, activity_name, note, segment_starts, segment_ends, paused_at
+            "#,
+            timer.user_id,
+            timer.project_id,
+            timer.project_name,
+            timer.activity_id,
+            timer.activity_name,
+            timer.note,
+            &timer.segment_starts,
+            &timer.segment_ends
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(timer)
+    }
+
+    async fn resume_paused_timer(
+        &self,
+        user_id: &i32,
+        id: Option<i32>,
+        started_at: &time::OffsetDateTime,
+    ) -> Result<ResumedPausedTimer, RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
+        let running = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM timer_history WHERE user_id = $1 AND end_time IS NULL
+            ) AS "running!"
+            "#,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if running {
+            return Ok(ResumedPausedTimer::TimerRunning);
+        }
+
+        // Segments of an earlier resumed timer that was never saved
+        sqlx::query!(
+            r#"
+            DELETE FROM paused_timers
+            WHERE user_id = $1 AND resumed
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let Some(timer) = sqlx::query_as!(
+            DatabasePausedTimer,
+            r#"
+            UPDATE paused_timers
+            SET resumed = TRUE
+            WHERE id = (
+                SELECT id
+                FROM paused_timers
+                WHERE user_id = $1 AND NOT resumed AND ($2::INT IS NULL OR id = $2)
+                ORDER BY paused_at DESC, id DESC
+                LIMIT 1
+            )
+            RETURNING id, project_id, project_name, activity_id, activity_name, note, segment_starts, segment_ends, paused_at
+            "#,
+            user_id,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(ResumedPausedTimer::NotFound);
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO timer_history (user_id, start_time, project_id, project_name, activity_id, activity_name, note)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            user_id,
+            started_at,
+            timer.project_id,
+            timer.project_name,
+            timer.activity_id,
+            timer.activity_name,
+            timer.note
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(ResumedPausedTimer::Resumed(timer))
+    }
+
+    async fn resumed_timer(
+        &self,
+        user_id: &i32,
+    ) -> Result<Option<DatabasePausedTimer>, RepositoryError> {
+        let timer = sqlx::query_as!(
+            DatabasePausedTimer,
+            r#"
+            SELECT id, project_id, project_name, activity_id, activity_name, note, segment_starts, segment_ends, paused_at
+            FROM paused_timers
+            WHERE user_id = $1 AND resumed
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(timer)
+    }
+
+    async fn update_resumed_timer_segments(
+        &self,
+        user_id: &i32,
+        segment_starts: &[time::OffsetDateTime],
+        segment_ends: &[time::OffsetDateTime],
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            UPDATE paused_timers
+            SET segment_starts = $2, segment_ends = $3
+            WHERE user_id = $1 AND resumed
+            "#,
+            user_id,
+            segment_starts,
+            segment_ends
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_resumed_timer(&self, user_id: &i32) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM paused_timers
+            WHERE user_id = $1 AND resumed
+            "#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use sqlx::PgPool;
 
-use crate::domain::{models::UserId, RepoKey, Role, User};
+use crate::domain::{
+    models::{LunchRule, TimerAutoStop, TimerAutoStopAction, UserId, UserTimeZone},
+    DigestFrequency, RepoKey, Role, User,
+};
 
 use super::repo_error::RepositoryError;
 
@@ -15,6 +18,38 @@ pub trait UserRepository {
         repo: &RepoKey,
         follow: bool,
     ) -> Result<(), RepositoryError>;
+    async fn get_time_zone(&self, id: UserId) -> Result<UserTimeZone, RepositoryError>;
+    async fn set_time_zone(
+        &self,
+        id: UserId,
+        time_zone: UserTimeZone,
+    ) -> Result<(), RepositoryError>;
+    async fn get_lunch_rule(&self, id: UserId) -> Result<LunchRule, RepositoryError>;
+    async fn set_lunch_rule(&self, id: UserId, rule: &LunchRule) -> Result<(), RepositoryError>;
+    /// Time zone and lunch rule in one query, for building a time tracking
+    /// service on every request.
+    async fn get_time_tracking_settings(
+        &self,
+        id: UserId,
+    ) -> Result<(UserTimeZone, LunchRule), RepositoryError>;
+    async fn get_timer_auto_stop(&self, id: UserId) -> Result<TimerAutoStop, RepositoryError>;
+    async fn set_timer_auto_stop(
+        &self,
+        id: UserId,
+        rule: &TimerAutoStop,
+    ) -> Result<(), RepositoryError>;
+    async fn get_work_item_hour_sync(&self, id: UserId) -> Result<bool, RepositoryError>;
+    async fn set_work_item_hour_sync(
+        &self,
+        id: UserId,
+        enabled: bool,
+    ) -> Result<(), RepositoryError>;
+    async fn get_digest_frequency(&self, id: UserId) -> Result<DigestFrequency, RepositoryError>;
+    async fn set_digest_frequency(
+        &self,
+        id: UserId,
+        frequency: DigestFrequency,
+    ) -> Result<(), RepositoryError>;
 }
 
 pub struct UserRepositoryImpl {
@@ -183,6 +218,263 @@ impl UserRepository for UserRepositoryImpl {
 
         Ok(())
     }
+
+    async fn get_time_zone(&self, id: UserId) -> Result<UserTimeZone, RepositoryError> {
+        let id = id.as_i32();
+        let time_zone = sqlx::query_scalar!(
+            r#"
+            SELECT time_zone
+            FROM users
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(parse_time_zone(id, &time_zone))
+    }
+
+    async fn set_time_zone(
+        &self,
+        id: UserId,
+        time_zone: UserTimeZone,
+    ) -> Result<(), RepositoryError> {
+        let id = id.as_i32();
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET time_zone = $2
+            WHERE id = $1
+            "#,
+            id,
+            time_zone.name()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_lunch_rule(&self, id: UserId) -> Result<LunchRule, RepositoryError> {
+        let id = id.as_i32();
+        let row = sqlx::query!(
+            r#"
+            SELECT enabled, window_start, window_end, deduction_minutes
+            FROM user_lunch_rules
+            WHERE user_id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map_or_else(LunchRule::default, |row| {
+            lunch_rule(
+                id,
+                row.enabled,
+                row.window_start,
+                row.window_end,
+                row.deduction_minutes,
+            )
+        }))
+    }
+
+    async fn get_time_tracking_settings(
+        &self,
+        id: UserId,
+    ) -> Result<(UserTimeZone, LunchRule), RepositoryError> {
+        let id = id.as_i32();
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                u.time_zone,
+                l.enabled as "lunch_enabled?",
+                l.window_start as "lunch_window_start?",
+                l.window_end as "lunch_window_end?",
+                l.deduction_minutes as "lunch_deduction_minutes?"
+            FROM users u
+            LEFT JOIN user_lunch_rules l ON l.user_id = u.id
+            WHERE u.id = $1
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let lunch_rule = match (
+            row.lunch_enabled,
+            row.lunch_window_start,
+            row.lunch_window_end,
+            row.lunch_deduction_minutes,
+        ) {
+            (Some(enabled), Some(window_start), Some(window_end), Some(deduction_minutes)) => {
+                lunch_rule(id, enabled, window_start, window_end, deduction_minutes)
+            }
+            _ => LunchRule::default(),
+        };
+        Ok((parse_time_zone(id, &row.time_zone), lunch_rule))
+    }
+
+    async fn set_lunch_rule(&self, id: UserId, rule: &LunchRule) -> Result<(), RepositoryError> {
+        let id = id.as_i32();
+        sqlx::query!(
+            r#"
+            INSERT INTO user_lunch_rules
+                (user_id, enabled, window_start, window_end, deduction_minutes, updated_at)
+            VALUES ($1, $2, $3, $4, $5, now())
+            ON CONFLICT (user_id) DO UPDATE
+            SET enabled = EXCLUDED.enabled,
+                window_start = EXCLUDED.window_start,
+                window_end = EXCLUDED.window_end,
+                deduction_minutes = EXCLUDED.deduction_minutes,
+                updated_at = now()
+            "#,
+            id,
+            rule.enabled,
+            rule.window_start,
+            rule.window_end,
+            i32::from(rule.deduction_minutes)
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_timer_auto_stop(&self, id: UserId) -> Result<TimerAutoStop, RepositoryError> {
+        let id = id.as_i32();
+        let row = sqlx::query!(
+            r#"
+            SELECT enabled, stop_at, action
+            FROM user_timer_auto_stops
+            WHERE user_id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(TimerAutoStop::default());
+        };
+        let action = TimerAutoStopAction::parse(&row.action).unwrap_or_else(|| {
+            tracing::warn!(
+                "Unknown timer auto-stop action '{}' for user {id}, using save",
+                row.action
+            );
+            TimerAutoStopAction::Save
+        });
+        Ok(TimerAutoStop {
+            enabled: row.enabled,
+            stop_at: row.stop_at,
+            action,
+        })
+    }
+
+    async fn set_timer_auto_stop(
+        &self,
+        id: UserId,
+        rule: &TimerAutoStop,
+    ) -> Result<(), RepositoryError> {
+        let id = id.as_i32();
+        sqlx::query!(
+            r#"
+            INSERT INTO user_timer_auto_stops (user_id, enabled, stop_at, action, updated_at)
+            VALUES ($1, $2, $3, $4, now())
+            ON CONFLICT (user_id) DO UPDATE
+            SET enabled = EXCLUDED.enabled,
+                stop_at = EXCLUDED.stop_at,
+                action = EXCLUDED.action,
+                updated_at = now()
+            "#,
+            id,
+            rule.enabled,
+            rule.stop_at,
+            rule.action.as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_work_item_hour_sync(&self, id: UserId) -> Result<bool, RepositoryError> {
+        let id = id.as_i32();
+        let enabled = sqlx::query_scalar!(
+            r#"
+            SELECT sync_work_item_hours
+            FROM users
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(enabled)
+    }
+
+    async fn set_work_item_hour_sync(
+        &self,
+        id: UserId,
+        enabled: bool,
+    ) -> Result<(), RepositoryError> {
+        let id = id.as_i32();
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET sync_work_item_hours = $2
+            WHERE id = $1
+            "#,
+            id,
+            enabled
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_digest_frequency(&self, id: UserId) -> Result<DigestFrequency, RepositoryError> {
+        let id = id.as_i32();
+        let frequency = sqlx::query_scalar!(
+            r#"
+            SELECT digest_frequency
+            FROM users
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(DigestFrequency::parse(&frequency).unwrap_or_else(|| {
+            tracing::warn!("Unknown digest frequency '{frequency}' for user {id}, using off");
+            DigestFrequency::Off
+        }))
+    }
+
+    async fn set_digest_frequency(
+        &self,
+        id: UserId,
+        frequency: DigestFrequency,
+    ) -> Result<(), RepositoryError> {
+        let id = id.as_i32();
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET digest_frequency = $2
+            WHERE id = $1
+            "#,
+            id,
+            frequency.as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
 
 pub struct NewUser {
@@ -214,3 +506,31 @@ struct DbUser {
     roles: Vec<String>,
     session_auth_hash: String,
 }
+
+// Names are validated on write, but tzdata can drop zones between releases.
+// Fall back rather than fail every time tracking request.
+fn parse_time_zone(id: i32, name: &str) -> UserTimeZone {
+    UserTimeZone::parse(name).unwrap_or_else(|| {
+        tracing::warn!("Unknown time zone '{name}' for user {id}, using default");
+        UserTimeZone::default()
+    })
+}
+
+fn lunch_rule(
+    id: i32,
+    enabled: bool,
+    window_start: time::Time,
+    window_end: time::Time,
+    deduction_minutes: i32,
+) -> LunchRule {
+    LunchRule::new(
+        enabled,
+        window_start,
+        window_end,
+        u16::try_from(deduction_minutes).unwrap_or(u16::MAX),
+    )
+    .unwrap_or_else(|e| {
+        tracing::warn!("Invalid lunch rule for user {id} ({e}), using default");
+        LunchRule::default()
+    })
+}
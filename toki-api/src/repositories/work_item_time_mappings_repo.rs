@@ -0,0 +1,138 @@
+use sqlx::PgPool;
+
+use crate::domain::models::{ActivityId, ProjectId, WorkItemTimeMapping};
+
+use super::repo_error::RepositoryError;
+
+/// Projects and activities users track time on for Azure DevOps projects.
+pub trait WorkItemTimeMappingRepository {
+    /// The user's mappings, ordered by organization and project.
+    async fn get_mappings(&self, user_id: i32)
+        -> Result<Vec<WorkItemTimeMapping>, RepositoryError>;
+    /// Map the Azure DevOps project, replacing the user's existing mapping
+    /// for it.
+    async fn upsert_mapping(
+        &self,
+        user_id: i32,
+        mapping: &NewWorkItemTimeMapping,
+    ) -> Result<WorkItemTimeMapping, RepositoryError>;
+    /// Returns false if the user has no mapping for the project.
+    async fn delete_mapping(
+        &self,
+        user_id: i32,
+        organization: &str,
+        project: &str,
+    ) -> Result<bool, RepositoryError>;
+}
+
+pub struct WorkItemTimeMappingRepositoryImpl {
+    pool: PgPool,
+}
+
+impl WorkItemTimeMappingRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWorkItemTimeMapping {
+    pub organization: String,
+    pub project: String,
+    pub project_id: ProjectId,
+    pub project_name: String,
+    pub activity_id: ActivityId,
+    pub activity_name: String,
+}
+
+impl WorkItemTimeMappingRepository for WorkItemTimeMappingRepositoryImpl {
+    async fn get_mappings(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<WorkItemTimeMapping>, RepositoryError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, organization, project, project_id, project_name, activity_id, activity_name
+            FROM work_item_time_mappings
+            WHERE user_id = $1
+            ORDER BY organization, project
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| WorkItemTimeMapping {
+                id: row.id,
+                organization: row.organization,
+                project: row.project,
+                project_id: ProjectId::new(row.project_id),
+                project_name: row.project_name,
+                activity_id: ActivityId::new(row.activity_id),
+                activity_name: row.activity_name,
+            })
+            .collect())
+    }
+
+    async fn upsert_mapping(
+        &self,
+        user_id: i32,
+        mapping: &NewWorkItemTimeMapping,
+    ) -> Result<WorkItemTimeMapping, RepositoryError> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO work_item_time_mappings
+                (user_id, organization, project, project_id, project_name, activity_id, activity_name)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, organization, project) DO UPDATE
+            SET project_id = EXCLUDED.project_id,
+                project_name = EXCLUDED.project_name,
+                activity_id = EXCLUDED.activity_id,
+                activity_name = EXCLUDED.activity_name
+            RETURNING id
+            "#,
+            user_id,
+            mapping.organization,
+            mapping.project,
+            mapping.project_id.as_str(),
+            mapping.project_name,
+            mapping.activity_id.as_str(),
+            mapping.activity_name
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(WorkItemTimeMapping {
+            id: row.id,
+            organization: mapping.organization.clone(),
+            project: mapping.project.clone(),
+            project_id: mapping.project_id.clone(),
+            project_name: mapping.project_name.clone(),
+            activity_id: mapping.activity_id.clone(),
+            activity_name: mapping.activity_name.clone(),
+        })
+    }
+
+    async fn delete_mapping(
+        &self,
+        user_id: i32,
+        organization: &str,
+        project: &str,
+    ) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM work_item_time_mappings
+            WHERE user_id = $1 AND organization = $2 AND project = $3
+            "#,
+            user_id,
+            organization,
+            project
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
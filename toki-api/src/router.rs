@@ -21,11 +21,19 @@ type SessionStore = CachingSessionStore<MokaStore, PostgresStore>;
 const SESSION_COOKIE_NAME: &str = "toki.sid";
 
 use crate::{
-    adapters::outbound::{media::WebpAvatarProcessor, postgres::PostgresAvatarRepository},
+    adapters::outbound::{
+        llm::HttpTextGenerator,
+        media::WebpAvatarProcessor,
+        postgres::{PostgresAvatarRepository, PostgresWorkItemTimeLinkRepository},
+    },
     app_state::AppState,
     auth::{self, AuthBackend},
     config::Settings,
-    domain::{ports::inbound::AvatarService, services::AvatarServiceImpl, RepoConfig},
+    domain::{
+        ports::inbound::{AvatarService, NoteImprovementService},
+        services::{AvatarServiceImpl, NoteImprovementServiceImpl},
+        RepoConfig,
+    },
     factory::KleerServiceFactory,
     routes,
 };
@@ -37,12 +45,17 @@ pub async fn create(
 ) -> Router<()> {
     let base_app = Router::new()
         .route("/", get(|| async { "Hello, little World!" }))
+        .route("/ws", get(routes::live::live_events))
+        .nest("/ws/stats", routes::live::admin_router())
         .nest("/pull-requests", routes::pull_requests::router())
         .nest("/differs", routes::differs::router())
+        .nest("/my-work", routes::my_work::router())
         .nest("/repositories", routes::repositories::router())
+        .nest("/status/banner", routes::status::admin_router())
         .nest("/notifications", routes::notifications::router())
         .nest("/time-tracking", routes::time_tracking::router())
         .nest("/users", routes::users::router())
+        .nest("/webhooks", routes::webhooks::router())
         .nest("/work-items", routes::work_items::router());
 
     // If authentication is enabled, wrap the app with the auth middleware
@@ -63,9 +76,17 @@ pub async fn create(
     let time_tracking_user_link_repo = Arc::new(
         crate::repositories::TimeTrackingUserLinkRepositoryImpl::new(connection_pool.clone()),
     );
+    let user_repo = Arc::new(crate::repositories::UserRepositoryImpl::new(
+        connection_pool.clone(),
+    ));
+    let work_item_time_links = Arc::new(PostgresWorkItemTimeLinkRepository::new(
+        connection_pool.clone(),
+    ));
     let time_tracking_factory = Arc::new(KleerServiceFactory::new(
         timer_repo,
         time_tracking_user_link_repo,
+        user_repo,
+        work_item_time_links.clone(),
         config.kleer.clone(),
     ));
     let avatar_repository = Arc::new(PostgresAvatarRepository::new(connection_pool.clone()));
@@ -75,29 +96,65 @@ pub async fn create(
         avatar_processor,
         config.application.api_url.clone(),
     ));
+    let note_improvement_service =
+        config
+            .llm
+            .as_ref()
+            .and_then(|settings| match HttpTextGenerator::new(settings) {
+                Ok(generator) => Some(
+                    Arc::new(NoteImprovementServiceImpl::new(Arc::new(generator)))
+                        as Arc<dyn NoteImprovementService>,
+                ),
+                Err(e) => {
+                    tracing::error!("Failed to create language model client: {e}");
+                    None
+                }
+            });
 
     // Create app state
     let app_state = AppState::new(
         config.application.app_url.clone(),
         config.application.api_url.clone(),
         config.kleer.clone(),
+        config.email.clone(),
+        config.push.clone(),
+        config.polling.clone(),
+        config.anomalies.clone(),
+        config.reminders.clone(),
+        config.digest.clone(),
+        config.live.clone(),
         connection_pool.clone(),
         repo_configs,
         time_tracking_factory,
         avatar_service,
+        note_improvement_service,
+        work_item_time_links,
     )
     .await;
 
     // Start all the differ threads (if in production)
     #[cfg(not(debug_assertions))]
-    app_state.start_all_differs().await;
+    {
+        app_state.start_all_differs().await;
+        app_state.spawn_cache_warm_up();
+        app_state.spawn_anomaly_checks();
+        app_state.spawn_timer_reminders();
+        app_state.spawn_digests();
+        app_state.spawn_board_snapshots();
+        app_state.spawn_flex_snapshots();
+        app_state.spawn_timer_auto_stops();
+        app_state.spawn_webhook_delivery_cleanup();
+    }
 
     // Finally, wrap the app with tracing layer, state and CORS
     let app_url = config.application.app_url.clone();
     let allowed_suffix = config.application.cors_allowed_origin_suffix.clone();
     let cors = CorsLayer::new()
         .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-        .allow_headers(["content-type".parse().unwrap()])
+        .allow_headers([
+            "content-type".parse().unwrap(),
+            toki_types::DEVICE_HEADER.parse().unwrap(),
+        ])
         .allow_credentials(true)
         .allow_origin(AllowOrigin::predicate(move |origin, _| {
             let origin_str = origin.to_str().unwrap_or_default();
@@ -110,6 +167,7 @@ pub async fn create(
             false
         }));
     app_with_auth
+        .merge(routes::status::router())
         .with_state(app_state)
         .layer(cors)
         .layer(TraceLayer::new_for_http().make_span_with(DefaultMakeSpan::default()))
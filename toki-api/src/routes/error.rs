@@ -9,18 +9,25 @@ use std::fmt;
 #[derive(Serialize)]
 struct ErrorBody {
     error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<serde_json::Value>,
 }
 
 use crate::{
-    adapters::inbound::http::{TimeTrackingServiceError, WorkItemServiceError},
+    adapters::inbound::http::{
+        TimeTrackingServiceError, WipLimitViolationResponse, WorkItemServiceError,
+    },
     app_state::AppStateError,
-    domain::{AvatarError, TimeTrackingError, WorkItemError},
+    domain::{
+        AvatarError, CalendarFeedError, NoteImprovementError, TimeTrackingError, WorkItemError,
+    },
     repositories::RepositoryError,
 };
 
 pub struct ApiError {
     status: StatusCode,
     message: String,
+    details: Option<serde_json::Value>,
 }
 
 impl ApiError {
@@ -28,9 +35,16 @@ impl ApiError {
         Self {
             status,
             message: message.into(),
+            details: None,
         }
     }
 
+    /// Attach a machine-readable payload for clients, serialized as `details`.
+    pub fn with_details(mut self, details: impl Serialize) -> Self {
+        self.details = serde_json::to_value(details).ok();
+        self
+    }
+
     pub fn internal(message: impl Into<String>) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, message)
     }
@@ -66,6 +80,7 @@ impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let body = ErrorBody {
             error: self.message,
+            details: self.details,
         };
         (self.status, Json(body)).into_response()
     }
@@ -101,8 +116,18 @@ impl From<TimeTrackingError> for ApiError {
             TimeTrackingError::TimerNotFound
             | TimeTrackingError::NoTimerRunning
             | TimeTrackingError::ProjectNotFound(_)
-            | TimeTrackingError::ActivityNotFound(_) => Self::not_found(err.to_string()),
-            TimeTrackingError::TimerAlreadyRunning => Self::conflict(err.to_string()),
+            | TimeTrackingError::ActivityNotFound(_)
+            | TimeTrackingError::EntryNotFound(_) => Self::not_found(err.to_string()),
+            TimeTrackingError::TimerAlreadyRunning | TimeTrackingError::DayLocked(_) => {
+                Self::conflict(err.to_string())
+            }
+            TimeTrackingError::InvalidSplitTime
+            | TimeTrackingError::EntryWithoutTimes(_)
+            | TimeTrackingError::InvalidMerge(_)
+            | TimeTrackingError::InvalidBatch(_) => Self::bad_request(err.to_string()),
+            TimeTrackingError::Unavailable(_) => {
+                Self::new(StatusCode::SERVICE_UNAVAILABLE, err.to_string())
+            }
             _ => Self::internal(err.to_string()),
         }
     }
@@ -134,6 +159,32 @@ impl From<AvatarError> for ApiError {
     }
 }
 
+impl From<NoteImprovementError> for ApiError {
+    fn from(err: NoteImprovementError) -> Self {
+        match err {
+            NoteImprovementError::InvalidInput(message) => Self::bad_request(message),
+            NoteImprovementError::Provider(message) => {
+                tracing::error!("Note improvement failed: {}", message);
+                Self::new(StatusCode::BAD_GATEWAY, "language model request failed")
+            }
+            NoteImprovementError::EmptySuggestion => {
+                Self::new(StatusCode::BAD_GATEWAY, err.to_string())
+            }
+        }
+    }
+}
+
+impl From<CalendarFeedError> for ApiError {
+    fn from(err: CalendarFeedError) -> Self {
+        match err {
+            CalendarFeedError::InvalidUrl(message) => Self::bad_request(message),
+            CalendarFeedError::Fetch(_) | CalendarFeedError::TooLarge => {
+                Self::new(StatusCode::BAD_GATEWAY, err.to_string())
+            }
+        }
+    }
+}
+
 impl From<WorkItemError> for ApiError {
     fn from(err: WorkItemError) -> Self {
         match err {
@@ -142,6 +193,12 @@ impl From<WorkItemError> for ApiError {
                 tracing::error!("Work item provider operation failed: {}", message);
                 Self::internal("work item provider operation failed")
             }
+            WorkItemError::WipLimitExceeded(ref violation) => Self::conflict(err.to_string())
+                .with_details(WipLimitViolationResponse::from(violation.clone())),
+            WorkItemError::Storage(message) => {
+                tracing::error!("Work item storage operation failed: {}", message);
+                Self::internal("work item storage operation failed")
+            }
         }
     }
 }
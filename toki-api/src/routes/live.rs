@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    http::StatusCode,
+    response::Response,
+    routing::get,
+    Json, Router,
+};
+use axum_login::permission_required;
+use serde::Serialize;
+use tokio::{sync::broadcast::error::RecvError, time::Instant};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    auth::{AuthBackend, AuthUser},
+    domain::{models::UserId, LiveEventStats, LiveSubscription, Role, LIVE_PING_INTERVAL},
+};
+
+use super::ApiError;
+
+pub fn admin_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_live_stats))
+        .route_layer(permission_required!(AuthBackend, Role::Admin))
+}
+
+/// Push the user's timer and entry events over a WebSocket as they happen.
+///
+/// Messages have the same JSON shape as event webhook deliveries. A client
+/// that falls too far behind, or sends nothing for the configured idle
+/// timeout, not even a pong, is disconnected and should refetch its state
+/// when it reconnects. Users with too many connections open are refused
+/// with 429 Too Many Requests.
+#[instrument(name = "live_events", skip(app_state, ws))]
+pub async fn live_events(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    let live = &app_state.live_events;
+    let events = live.subscribe(user.id).ok_or_else(|| {
+        ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            format!(
+                "At most {} live connections can be open at once",
+                live.max_connections_per_user()
+            ),
+        )
+    })?;
+    let idle_timeout = live.idle_timeout();
+    Ok(ws.on_upgrade(move |socket| stream_events(socket, user.id, events, idle_timeout)))
+}
+
+async fn stream_events(
+    mut socket: WebSocket,
+    user_id: UserId,
+    mut events: LiveSubscription,
+    idle_timeout: Duration,
+) {
+    let mut ping = tokio::time::interval(LIVE_PING_INTERVAL);
+    let idle = tokio::time::sleep(idle_timeout);
+    tokio::pin!(idle);
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(missed)) => {
+                        tracing::warn!("Live events for user {user_id} lagged by {missed}, closing");
+                        events.record_lagged_disconnect();
+                        break;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let text = match serde_json::to_string(&event) {
+                    Ok(text) => text,
+                    Err(e) => {
+                        tracing::error!("Failed to serialize {} event: {e}", event.event.as_str());
+                        continue;
+                    }
+                };
+                if socket.send(Message::Text(text)).await.is_err() {
+                    break;
+                }
+            }
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Clients only answer pings; anything they send keeps the
+                // connection alive.
+                Some(Ok(_)) => idle.as_mut().reset(Instant::now() + idle_timeout),
+            },
+            _ = ping.tick() => {
+                if socket.send(Message::Ping(Vec::new())).await.is_err() {
+                    break;
+                }
+            }
+            _ = &mut idle => {
+                tracing::debug!("Live connection of user {user_id} went idle, closing");
+                events.record_idle_disconnect();
+                break;
+            }
+        }
+    }
+    let _ = socket.close().await;
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveStatsResponse {
+    connected_users: usize,
+    open_connections: usize,
+    refused_connections: u64,
+    idle_disconnects: u64,
+    lagged_disconnects: u64,
+}
+
+impl From<LiveEventStats> for LiveStatsResponse {
+    fn from(stats: LiveEventStats) -> Self {
+        Self {
+            connected_users: stats.connected_users,
+            open_connections: stats.open_connections,
+            refused_connections: stats.refused_connections,
+            idle_disconnects: stats.idle_disconnects,
+            lagged_disconnects: stats.lagged_disconnects,
+        }
+    }
+}
+
+/// Live connection counts, for admins keeping an eye on the server.
+#[instrument(name = "GET /ws/stats", skip(app_state))]
+async fn get_live_stats(State(app_state): State<AppState>) -> Json<LiveStatsResponse> {
+    Json(app_state.live_events.stats().into())
+}
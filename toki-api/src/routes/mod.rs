@@ -1,10 +1,14 @@
 pub(crate) mod differs;
 pub(crate) mod error;
+pub(crate) mod live;
+pub(crate) mod my_work;
 pub(crate) mod notifications;
 pub(crate) mod pull_requests;
 pub(crate) mod repositories;
+pub(crate) mod status;
 pub(crate) mod time_tracking;
 pub(crate) mod users;
+pub(crate) mod webhooks;
 pub(crate) mod work_items;
 
 pub(crate) use error::ApiError;
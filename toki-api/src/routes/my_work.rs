@@ -0,0 +1,255 @@
+use std::cmp;
+
+use axum::{extract::State, routing::get, Json, Router};
+use futures_util::future::join_all;
+use serde::Serialize;
+use time::OffsetDateTime;
+use toki_types::{TimeEntryResponse, TimerResponse};
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::WorkItemResponse,
+    auth::AuthUser,
+    domain::models::WorkItemProject,
+    routes::{pull_requests, time_tracking::timer, work_items},
+    AppState,
+};
+
+use super::ApiError;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(my_work))
+        .route("/work-items", get(my_work_items))
+}
+
+/// Everything currently on the user's plate, gathered from every provider
+/// they're connected to.
+///
+/// Each section is fetched independently. A section that fails is left
+/// empty and named in `unavailable`, so one slow or broken provider doesn't
+/// hide the rest.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MyWorkResponse {
+    work_items: Vec<MyWorkItem>,
+    reviews: Vec<MyWorkReview>,
+    time_entries_today: Vec<TimeEntryResponse>,
+    timer: Option<TimerResponse>,
+    unavailable: Vec<MyWorkSection>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum MyWorkSection {
+    WorkItems,
+    Reviews,
+    TimeEntries,
+    Timer,
+}
+
+/// An open work item assigned to the user.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MyWorkItem {
+    organization: String,
+    project: String,
+    #[serde(flatten)]
+    item: WorkItemResponse,
+}
+
+/// A pull request in a followed repository that is waiting for the user's
+/// review.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MyWorkReview {
+    organization: String,
+    project: String,
+    repo_name: String,
+    url: String,
+    id: i32,
+    title: String,
+    created_by: az_devops::Identity,
+    #[serde(with = "time::serde::rfc3339")]
+    created_at: OffsetDateTime,
+    is_draft: bool,
+    review_required: bool,
+}
+
+#[instrument(name = "GET /my-work", skip(app_state))]
+async fn my_work(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<MyWorkResponse>, ApiError> {
+    let (work_items, reviews, time_tracking) = tokio::join!(
+        assigned_work_items(&app_state, &user),
+        reviews(&app_state, &user),
+        time_tracking(&app_state, &user),
+    );
+
+    let mut unavailable = vec![];
+    let work_items = or_unavailable(work_items, MyWorkSection::WorkItems, &mut unavailable);
+    let reviews = or_unavailable(reviews, MyWorkSection::Reviews, &mut unavailable);
+    let (time_entries_today, timer) = match time_tracking {
+        Ok((entries, timer)) => (
+            or_unavailable(entries, MyWorkSection::TimeEntries, &mut unavailable),
+            or_unavailable(timer, MyWorkSection::Timer, &mut unavailable),
+        ),
+        Err(err) => {
+            tracing::warn!("Failed to connect to time tracking for my work: {err}");
+            unavailable.extend([MyWorkSection::TimeEntries, MyWorkSection::Timer]);
+            (vec![], None)
+        }
+    };
+
+    Ok(Json(MyWorkResponse {
+        work_items,
+        reviews,
+        time_entries_today,
+        timer,
+        unavailable,
+    }))
+}
+
+/// Only the open work items assigned to the user, for pickers that don't
+/// need the rest of `/my-work`.
+#[instrument(name = "GET /my-work/work-items", skip(app_state))]
+async fn my_work_items(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<MyWorkItem>>, ApiError> {
+    Ok(Json(assigned_work_items(&app_state, &user).await?))
+}
+
+/// The section's value, or an empty one if it failed to load.
+fn or_unavailable<T: Default>(
+    result: Result<T, ApiError>,
+    section: MyWorkSection,
+    unavailable: &mut Vec<MyWorkSection>,
+) -> T {
+    result.unwrap_or_else(|err| {
+        tracing::warn!("Failed to load {section:?} for my work: {err}");
+        unavailable.push(section);
+        T::default()
+    })
+}
+
+/// Assigned work items across every project the user has access to. Projects
+/// that fail are skipped, the section only fails if all of them do.
+async fn assigned_work_items(
+    app_state: &AppState,
+    user: &AuthUser,
+) -> Result<Vec<MyWorkItem>, ApiError> {
+    let projects = work_items::get_available_projects_cached(app_state, user).await?;
+
+    let results = join_all(
+        projects
+            .iter()
+            .map(|project| project_work_items(app_state, project, &user.email)),
+    )
+    .await;
+
+    let mut items = vec![];
+    let mut failed = 0;
+    let mut last_error = None;
+    for (project, result) in projects.iter().zip(results) {
+        match result {
+            Ok(project_items) => items.extend(project_items),
+            Err(err) => {
+                tracing::warn!(
+                    organization = %project.organization,
+                    project = %project.project,
+                    "Failed to load assigned work items: {err}"
+                );
+                failed += 1;
+                last_error = Some(err);
+            }
+        }
+    }
+
+    match last_error {
+        Some(err) if failed == projects.len() => Err(err),
+        _ => Ok(items),
+    }
+}
+
+async fn project_work_items(
+    app_state: &AppState,
+    project: &WorkItemProject,
+    assignee: &str,
+) -> Result<Vec<MyWorkItem>, ApiError> {
+    let service = app_state
+        .work_item_factory
+        .create_service(&project.organization, &project.project)
+        .await?;
+    let items = service.get_assigned_work_items(assignee).await?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| MyWorkItem {
+            organization: project.organization.clone(),
+            project: project.project.clone(),
+            item: item.into(),
+        })
+        .collect())
+}
+
+async fn reviews(app_state: &AppState, user: &AuthUser) -> Result<Vec<MyWorkReview>, ApiError> {
+    let mut prs = pull_requests::get_followed_pull_requests(app_state, user).await?;
+    prs.retain(|pr| pr.waiting_for_user_review(&user.email).0);
+    pull_requests::apply_avatar_overrides_to_pull_requests(app_state, &mut prs).await?;
+    prs.sort_by_key(|pr| cmp::Reverse(pr.pull_request_base.created_at));
+
+    Ok(prs
+        .into_iter()
+        .map(|pr| {
+            let (_, review_required) = pr.waiting_for_user_review(&user.email);
+            MyWorkReview {
+                organization: pr.organization,
+                project: pr.project,
+                repo_name: pr.repo_name,
+                url: pr.url,
+                id: pr.pull_request_base.id,
+                title: pr.pull_request_base.title,
+                created_by: pr.pull_request_base.created_by,
+                created_at: pr.pull_request_base.created_at,
+                is_draft: pr.pull_request_base.is_draft,
+                review_required,
+            }
+        })
+        .collect())
+}
+
+/// Today's time entries and the running timer. They share one time
+/// tracking connection, but fail independently once it's established.
+async fn time_tracking(
+    app_state: &AppState,
+    user: &AuthUser,
+) -> Result<
+    (
+        Result<Vec<TimeEntryResponse>, ApiError>,
+        Result<Option<TimerResponse>, ApiError>,
+    ),
+    ApiError,
+> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let entries = async {
+        let today = app_state.user_repo.get_time_zone(user.id).await?.today();
+        let entries = service
+            .get_time_entries(&user.id, (today, today), false)
+            .await?;
+        Ok::<Vec<TimeEntryResponse>, ApiError>(entries.into_iter().map(Into::into).collect())
+    };
+    let timer = async {
+        Ok::<_, ApiError>(match service.get_active_timer(&user.id).await? {
+            Some(active) => Some(timer::with_device(app_state, user.id, active).await),
+            None => None,
+        })
+    };
+
+    Ok(tokio::join!(entries, timer))
+}
@@ -1,13 +1,14 @@
 use crate::domain::DbNotificationType;
 use crate::domain::PushSubscriptionInfo;
 use crate::repositories::NotificationRepository;
+use crate::repositories::NotificationWebhookRepository;
 use crate::repositories::PushSubscriptionRepository;
 use crate::utils::client_hints::ClientHints;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use serde::Deserialize;
@@ -17,8 +18,11 @@ use tracing::instrument;
 use crate::{
     app_state::AppState,
     auth::AuthUser,
-    domain::{Notification, NotificationRule, PrNotificationException, PushNotification},
-    repositories::NewPushSubscription,
+    domain::{
+        ChatWebhookEvent, Notification, NotificationRule, NotificationWebhookInfo,
+        PrNotificationException, PushNotification, WebhookKind,
+    },
+    repositories::{NewNotificationWebhook, NewPushSubscription},
 };
 use strum::IntoEnumIterator;
 
@@ -26,11 +30,17 @@ use super::ApiError;
 
 pub fn router() -> Router<AppState> {
     Router::new()
+        .route("/vapid-public-key", get(get_vapid_public_key))
         .route("/subscribe", post(subscribe))
+        .route("/unsubscribe", post(unsubscribe))
         .route("/is-subscribed", post(is_subscribed))
         .route("/push-subscriptions", get(get_push_subscriptions))
         .route("/push-subscriptions/:id", delete(delete_push_subscription))
         .route("/test-push", post(test_push))
+        .route("/webhooks", get(get_webhooks))
+        .route("/webhooks", post(add_webhook))
+        .route("/webhooks/:id", delete(delete_webhook))
+        .route("/webhooks/:id/events", put(update_webhook_events))
         .route("/", get(get_notifications))
         .route("/:id/view", post(mark_notification_viewed))
         .route("/view-all", post(mark_all_notifications_viewed))
@@ -51,6 +61,15 @@ pub fn router() -> Router<AppState> {
         )
 }
 
+#[instrument(name = "get_vapid_public_key")]
+async fn get_vapid_public_key(State(app_state): State<AppState>) -> Result<Json<String>, ApiError> {
+    app_state
+        .push_dispatcher
+        .public_key()
+        .map(|key| Json(key.to_string()))
+        .ok_or_else(|| ApiError::not_found("Push notifications are not configured"))
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubscribePayload {
@@ -84,6 +103,32 @@ async fn subscribe(
     Ok(StatusCode::OK)
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsubscribePayload {
+    endpoint: String,
+}
+
+/// Remove the subscription of the calling device, identified by the push
+/// endpoint the browser hands out.
+#[instrument(name = "unsubscribe", skip(body))]
+async fn unsubscribe(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<UnsubscribePayload>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .push_subscriptions_repo
+        .delete_user_push_subscription_by_endpoint(user.id.as_ref(), &body.endpoint)
+        .await?;
+
+    if !deleted {
+        return Err(ApiError::not_found("Push subscription not found"));
+    }
+
+    Ok(StatusCode::OK)
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct IsSubscribedPayload {
@@ -165,15 +210,118 @@ async fn test_push(State(app_state): State<AppState>) -> Result<StatusCode, ApiE
         None,
     );
     for subscriber in subscribers {
-        let push_message = content
-            .to_web_push_message(&subscriber.as_subscription_info())
+        app_state
+            .push_dispatcher
+            .send(&subscriber, content.clone())
+            .await
             .map_err(|e| {
-                tracing::error!("Failed to create push message: {:?}", e);
-                ApiError::internal("Failed to create push message")
+                tracing::error!("Failed to send push notification: {:?}", e);
+                ApiError::internal("Failed to send push notification")
             })?;
+    }
+
+    Ok(StatusCode::OK)
+}
+
+#[instrument(name = "get_webhooks")]
+async fn get_webhooks(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<NotificationWebhookInfo>>, ApiError> {
+    let webhooks = app_state
+        .notification_webhooks_repo
+        .get_user_webhooks(user.id.as_i32())
+        .await?;
+
+    Ok(Json(
+        webhooks
+            .into_iter()
+            .map(NotificationWebhookInfo::from)
+            .collect(),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddWebhookPayload {
+    url: String,
+    /// Defaults to every event.
+    events: Option<Vec<String>>,
+}
 
-        app_state.push_notification(push_message).await?;
+fn parse_chat_webhook_events(names: &[String]) -> Result<Vec<ChatWebhookEvent>, ApiError> {
+    let mut events = Vec::new();
+    for name in names {
+        let event = ChatWebhookEvent::parse(name)
+            .ok_or_else(|| ApiError::bad_request(format!("Unknown event: {name}")))?;
+        if !events.contains(&event) {
+            events.push(event);
+        }
     }
+    Ok(events)
+}
+
+#[instrument(name = "add_webhook", skip(body))]
+async fn add_webhook(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<AddWebhookPayload>,
+) -> Result<Json<NotificationWebhookInfo>, ApiError> {
+    let url = body.url.trim().to_string();
+    let kind = WebhookKind::detect(&url).ok_or_else(|| {
+        ApiError::bad_request("Only Slack and Microsoft Teams incoming webhook URLs are supported")
+    })?;
+    let events = match body.events {
+        Some(names) => parse_chat_webhook_events(&names)?,
+        None => ChatWebhookEvent::ALL.to_vec(),
+    };
+
+    let webhook = app_state
+        .notification_webhooks_repo
+        .upsert_webhook(NewNotificationWebhook {
+            user_id: user.id.as_i32(),
+            kind,
+            url,
+            events,
+        })
+        .await?;
+
+    Ok(Json(webhook.into()))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhookEventsPayload {
+    events: Vec<String>,
+}
+
+#[instrument(name = "update_webhook_events")]
+async fn update_webhook_events(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+    Json(body): Json<UpdateWebhookEventsPayload>,
+) -> Result<Json<NotificationWebhookInfo>, ApiError> {
+    let events = parse_chat_webhook_events(&body.events)?;
+
+    let webhook = app_state
+        .notification_webhooks_repo
+        .update_webhook_events(id, user.id.as_i32(), &events)
+        .await?;
+
+    Ok(Json(webhook.into()))
+}
+
+#[instrument(name = "delete_webhook")]
+async fn delete_webhook(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    app_state
+        .notification_webhooks_repo
+        .delete_webhook(id, user.id.as_i32())
+        .await?;
 
     Ok(StatusCode::OK)
 }
@@ -272,6 +420,8 @@ async fn get_preferences(
                     notification_type,
                     enabled: notification_type.default_enabled(),
                     push_enabled: false,
+                    email_enabled: false,
+                    webhook_enabled: false,
                 })
         })
         .collect();
@@ -28,6 +28,7 @@ pub fn router() -> Router<AppState> {
         .route("/open", get(open_pull_requests))
         .route("/cached", get(cached_pull_requests))
         .route("/list", get(list_pull_requests))
+        .route("/changes", get(pull_request_changes))
         .route("/most-recent-commits", get(most_recent_commits))
 }
 
@@ -123,6 +124,7 @@ struct ListPullRequest {
     url: String,
     id: i32,
     title: String,
+    description: Option<String>,
     created_by: az_devops::Identity,
     #[serde(with = "time::serde::rfc3339")]
     created_at: OffsetDateTime,
@@ -132,6 +134,8 @@ struct ListPullRequest {
     merge_status: Option<az_devops::MergeStatus>,
     threads: Vec<az_devops::Thread>,
     work_items: Vec<az_devops::WorkItem>,
+    ci_status: Option<az_devops::CiStatus>,
+    checks: Vec<az_devops::PullRequestCheck>,
     reviewers: Vec<az_devops::IdentityWithVote>,
     blocked_by: Vec<az_devops::IdentityWithVote>,
     approved_by: Vec<az_devops::IdentityWithVote>,
@@ -144,6 +148,7 @@ impl ListPullRequest {
         let blocked_by = pr.blocked_by(&pr.threads);
         let approved_by = pr.approved_by();
         let (waiting_for_user_review, review_required) = pr.waiting_for_user_review(user_email);
+        let ci_status = pr.ci_status();
         Self {
             organization: pr.organization,
             project: pr.project,
@@ -151,6 +156,7 @@ impl ListPullRequest {
             url: pr.url,
             id: pr.pull_request_base.id,
             title: pr.pull_request_base.title,
+            description: pr.pull_request_base.description,
             created_by: pr.pull_request_base.created_by,
             created_at: pr.pull_request_base.created_at,
             source_branch: pr.pull_request_base.source_branch,
@@ -159,6 +165,8 @@ impl ListPullRequest {
             merge_status: pr.pull_request_base.merge_status,
             threads: pr.threads,
             work_items: pr.work_items,
+            ci_status,
+            checks: pr.checks,
             reviewers: pr.pull_request_base.reviewers,
             blocked_by,
             approved_by,
@@ -185,10 +193,46 @@ async fn list_pull_requests(
     Ok(Json(list_prs))
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestChangesQuery {
+    organization: String,
+    project: String,
+    repo_name: String,
+    id: i32,
+}
+
+impl From<&PullRequestChangesQuery> for RepoKey {
+    fn from(query: &PullRequestChangesQuery) -> Self {
+        Self::new(&query.organization, &query.project, &query.repo_name)
+    }
+}
+
+#[instrument(name = "GET /pull-requests/changes")]
+async fn pull_request_changes(
+    _user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<PullRequestChangesQuery>,
+) -> Result<Json<Vec<az_devops::PullRequestChange>>, ApiError> {
+    let client = app_state.get_repo_client(&query).await?;
+
+    let changes = client
+        .get_pull_request_changes(query.id)
+        .await
+        .map_err(|err| match err {
+            az_devops::RepoClientError::HttpStatus { status: 404, .. } => {
+                ApiError::not_found(format!("pull request {} not found", query.id))
+            }
+            err => ApiError::internal(format!("failed to fetch pull request changes: {err}")),
+        })?;
+
+    Ok(Json(changes))
+}
+
 /// Get the followed pull requests from the cache.
 ///
 /// This function will fetch the cached pull requests from the cache and replace the mentions in the threads with names instead of ids.
-async fn get_followed_pull_requests(
+pub(crate) async fn get_followed_pull_requests(
     app_state: &AppState,
     user: &AuthUser,
 ) -> Result<Vec<PullRequest>, ApiError> {
@@ -218,7 +262,7 @@ async fn get_followed_pull_requests(
     Ok(followed_prs)
 }
 
-async fn apply_avatar_overrides_to_pull_requests(
+pub(crate) async fn apply_avatar_overrides_to_pull_requests(
     app_state: &AppState,
     prs: &mut [PullRequest],
 ) -> Result<(), ApiError> {
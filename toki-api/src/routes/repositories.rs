@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
     routing::{delete, get, post},
     Json, Router,
@@ -13,7 +13,7 @@ use tracing::instrument;
 
 use crate::{
     auth::{AuthBackend, AuthUser},
-    domain::{RepoDifferMessage, RepoKey, Repository, Role},
+    domain::{RepoDifferMessage, RepoHealth, RepoKey, Repository, Role},
     repositories::{NewRepository, RepoRepository, UserRepository},
     AppState,
 };
@@ -23,10 +23,19 @@ use super::ApiError;
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", delete(delete_repository))
+        .route("/health", get(get_repository_health))
         .route_layer(permission_required!(AuthBackend, Role::Admin))
         .route("/", get(get_repositories))
         .route("/", post(add_repository))
         .route("/follow", post(follow_repository))
+        .route(
+            "/:repository_id/pull-requests/:pull_request_id/vote",
+            post(vote_on_pull_request),
+        )
+        .route(
+            "/:repository_id/pull-requests/:pull_request_id/comment",
+            post(comment_on_pull_request),
+        )
 }
 
 #[instrument(name = "GET /repositories")]
@@ -40,6 +49,15 @@ async fn get_repositories(State(app_state): State<AppState>) -> Json<Vec<Reposit
     Json(repos)
 }
 
+#[instrument(name = "GET /repositories/health")]
+async fn get_repository_health(State(app_state): State<AppState>) -> Json<Vec<RepoHealth>> {
+    let mut health = app_state.get_repo_health().await;
+    // Worst first, so stuck pollers are at the top of the dashboard.
+    health.sort_by_key(|repo| (repo.state, repo.key.to_string()));
+
+    Json(health)
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct FollowRepositoryBody {
@@ -154,3 +172,170 @@ async fn delete_repository(
 
     Ok(StatusCode::OK)
 }
+
+#[derive(Debug, Deserialize)]
+struct PullRequestPath {
+    repository_id: i32,
+    pull_request_id: i32,
+}
+
+/// Client for reviewing a pull request in a repository the user follows.
+///
+/// Review actions go through the repository's shared token, so they are
+/// recorded as the token's owner. Until they can run as the caller's own
+/// Azure DevOps identity, only users listed as reviewers on the pull request
+/// may take them.
+async fn reviewer_repo_client(
+    app_state: &AppState,
+    user: &AuthUser,
+    path: &PullRequestPath,
+) -> Result<(RepoKey, RepoClient), ApiError> {
+    let repository = app_state
+        .repository_repo
+        .get_repositories()
+        .await?
+        .into_iter()
+        .find(|repo| repo.id == path.repository_id)
+        .ok_or_else(|| {
+            ApiError::not_found(format!("repository {} not found", path.repository_id))
+        })?;
+    let repo_key = RepoKey::from(&repository);
+
+    let followed = app_state.user_repo.followed_repositories(user.id).await?;
+    if !followed.contains(&repo_key) {
+        return Err(ApiError::forbidden(
+            "follow the repository to review its pull requests",
+        ));
+    }
+
+    let pull_request = app_state
+        .get_cached_pull_requests(repo_key.clone())
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|pr| pr.pull_request_base.id == path.pull_request_id)
+        .ok_or_else(|| {
+            ApiError::not_found(format!("pull request {} not found", path.pull_request_id))
+        })?;
+    if !pull_request.has_reviewer(&user.email) {
+        return Err(ApiError::forbidden(
+            "only reviewers of the pull request can vote or comment on it",
+        ));
+    }
+
+    let client = app_state.get_repo_client(repo_key.clone()).await?;
+    Ok((repo_key, client))
+}
+
+fn review_action_error(pull_request_id: i32, err: az_devops::RepoClientError) -> ApiError {
+    match err {
+        az_devops::RepoClientError::HttpStatus { status: 404, .. } => {
+            ApiError::not_found(format!("pull request {pull_request_id} not found"))
+        }
+        err => ApiError::internal(format!("failed to update pull request: {err}")),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct VoteBody {
+    vote: az_devops::Vote,
+}
+
+#[instrument(name = "POST /repositories/:repository_id/pull-requests/:pull_request_id/vote")]
+async fn vote_on_pull_request(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(path): Path<PullRequestPath>,
+    Json(body): Json<VoteBody>,
+) -> Result<StatusCode, ApiError> {
+    let (repo_key, client) = reviewer_repo_client(&app_state, &user, &path).await?;
+
+    let reviewer_id = client
+        .get_authenticated_user_id()
+        .await
+        .map_err(|err| ApiError::internal(format!("failed to resolve reviewer: {err}")))?;
+    client
+        .set_pull_request_vote(path.pull_request_id, &reviewer_id, body.vote.clone())
+        .await
+        .map_err(|err| review_action_error(path.pull_request_id, err))?;
+
+    tracing::info!(
+        target: "audit",
+        user_id = user.id.as_i32(),
+        user_email = %user.email,
+        repository = %repo_key,
+        pull_request_id = path.pull_request_id,
+        reviewer_id = %reviewer_id,
+        vote = ?body.vote,
+        "Voted on pull request"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentBody {
+    content: String,
+    /// Reply to this thread instead of starting a new one.
+    thread_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentResponse {
+    thread_id: i32,
+    comment: az_devops::Comment,
+}
+
+#[instrument(
+    name = "POST /repositories/:repository_id/pull-requests/:pull_request_id/comment",
+    skip(body)
+)]
+async fn comment_on_pull_request(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(path): Path<PullRequestPath>,
+    Json(body): Json<CommentBody>,
+) -> Result<Json<CommentResponse>, ApiError> {
+    let content = body.content.trim();
+    if content.is_empty() {
+        return Err(ApiError::bad_request("comment cannot be empty"));
+    }
+    let (repo_key, client) = reviewer_repo_client(&app_state, &user, &path).await?;
+
+    let (thread_id, comment) = match body.thread_id {
+        Some(thread_id) => {
+            let comment = client
+                .reply_to_thread(path.pull_request_id, thread_id, content)
+                .await
+                .map_err(|err| review_action_error(path.pull_request_id, err))?;
+            (thread_id, comment)
+        }
+        None => {
+            let thread = client
+                .create_thread(path.pull_request_id, content)
+                .await
+                .map_err(|err| review_action_error(path.pull_request_id, err))?;
+            let comment = thread
+                .comments
+                .into_iter()
+                .next()
+                .ok_or_else(|| ApiError::internal("created thread has no comment"))?;
+            (thread.id, comment)
+        }
+    };
+
+    tracing::info!(
+        target: "audit",
+        user_id = user.id.as_i32(),
+        user_email = %user.email,
+        repository = %repo_key,
+        pull_request_id = path.pull_request_id,
+        thread_id,
+        comment_id = comment.id,
+        "Commented on pull request"
+    );
+
+    Ok(Json(CommentResponse { thread_id, comment }))
+}
@@ -0,0 +1,137 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, State},
+    http::{request::Parts, StatusCode},
+    routing::get,
+    Json, Router,
+};
+use axum_login::permission_required;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    auth::{AuthBackend, AuthUser},
+    domain::{BannerSeverity, Role, StatusBanner},
+    repositories::StatusBannerRepository,
+};
+
+use super::ApiError;
+
+/// Public routes, reachable without logging in.
+pub fn router() -> Router<AppState> {
+    Router::new().route("/status", get(get_status))
+}
+
+pub fn admin_router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_banner).put(set_banner).delete(clear_banner))
+        .route_layer(permission_required!(AuthBackend, Role::Admin))
+}
+
+#[instrument(name = "GET /status", skip(app_state))]
+async fn get_status(State(app_state): State<AppState>) -> Json<toki_types::StatusResponse> {
+    let now = OffsetDateTime::now_utc();
+    let banner = app_state
+        .status_banner()
+        .await
+        .filter(|banner| banner.is_shown(now));
+
+    Json(toki_types::StatusResponse {
+        maintenance: banner.as_ref().is_some_and(|b| b.is_maintenance(now)),
+        banner: banner.map(Into::into),
+    })
+}
+
+#[instrument(name = "GET /status/banner", skip(app_state))]
+async fn get_banner(State(app_state): State<AppState>) -> Json<Option<toki_types::StatusBanner>> {
+    Json(app_state.status_banner().await.map(Into::into))
+}
+
+#[instrument(name = "PUT /status/banner", skip(app_state))]
+async fn set_banner(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<toki_types::StatusBanner>,
+) -> Result<Json<toki_types::StatusBanner>, ApiError> {
+    let banner = StatusBanner::from(body);
+    banner
+        .validate(OffsetDateTime::now_utc())
+        .map_err(ApiError::bad_request)?;
+
+    app_state
+        .status_banner_repo
+        .set_banner(&banner, user.id.as_i32())
+        .await?;
+    app_state.set_status_banner(Some(banner.clone())).await;
+
+    Ok(Json(banner.into()))
+}
+
+#[instrument(name = "DELETE /status/banner", skip(app_state))]
+async fn clear_banner(State(app_state): State<AppState>) -> Result<StatusCode, ApiError> {
+    app_state.status_banner_repo.clear_banner().await?;
+    app_state.set_status_banner(None).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Rejects the request with 503 and the banner while a maintenance banner
+/// is shown, rather than passing provider errors on to clients. Handlers
+/// that call the time tracking provider take it as their first extractor.
+#[derive(Debug)]
+pub struct ProviderAvailable;
+
+#[async_trait]
+impl FromRequestParts<AppState> for ProviderAvailable {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        _parts: &mut Parts,
+        app_state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        match app_state.maintenance_banner().await {
+            Some(banner) => {
+                let message = format!(
+                    "Time tracking is paused for maintenance: {}",
+                    banner.message
+                );
+                Err(ApiError::new(StatusCode::SERVICE_UNAVAILABLE, message)
+                    .with_details(toki_types::StatusBanner::from(banner)))
+            }
+            None => Ok(Self),
+        }
+    }
+}
+
+impl From<toki_types::StatusBanner> for StatusBanner {
+    fn from(banner: toki_types::StatusBanner) -> Self {
+        Self {
+            message: banner.message.trim().to_string(),
+            severity: match banner.severity {
+                toki_types::BannerSeverity::Info => BannerSeverity::Info,
+                toki_types::BannerSeverity::Warning => BannerSeverity::Warning,
+                toki_types::BannerSeverity::Critical => BannerSeverity::Critical,
+            },
+            starts_at: banner.starts_at,
+            ends_at: banner.ends_at,
+            maintenance: banner.maintenance,
+        }
+    }
+}
+
+impl From<StatusBanner> for toki_types::StatusBanner {
+    fn from(banner: StatusBanner) -> Self {
+        Self {
+            message: banner.message,
+            severity: match banner.severity {
+                BannerSeverity::Info => toki_types::BannerSeverity::Info,
+                BannerSeverity::Warning => toki_types::BannerSeverity::Warning,
+                BannerSeverity::Critical => toki_types::BannerSeverity::Critical,
+            },
+            starts_at: banner.starts_at,
+            ends_at: banner.ends_at,
+            maintenance: banner.maintenance,
+        }
+    }
+}
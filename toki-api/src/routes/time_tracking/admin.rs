@@ -199,9 +199,10 @@ async fn upsert_user_link(
         return Err(ApiError::bad_request("Cannot map an inactive Kleer user"));
     }
 
+    let user_id = UserId::from(payload.user_id);
     let link = repo
         .upsert_active_link(&NewTimeTrackingUserLink {
-            user_id: UserId::from(payload.user_id),
+            user_id,
             provider: KLEER_TIME_TRACKING_PROVIDER.to_string(),
             provider_company_id: credentials.company_id,
             provider_user_id: provider_user.provider_user_id,
@@ -209,6 +210,7 @@ async fn upsert_user_link(
             provider_user_name: Some(provider_user.name),
         })
         .await?;
+    app_state.time_tracking_factory.invalidate_user(user_id);
 
     Ok(Json(link.into()))
 }
@@ -217,9 +219,11 @@ async fn deactivate_user_link(
     Path(user_id): Path<i32>,
     State(app_state): State<AppState>,
 ) -> Result<StatusCode, ApiError> {
+    let user_id = UserId::from(user_id);
     let repo = mapping_repo(&app_state);
-    repo.deactivate_active_link(&UserId::from(user_id), KLEER_TIME_TRACKING_PROVIDER)
+    repo.deactivate_active_link(&user_id, KLEER_TIME_TRACKING_PROVIDER)
         .await?;
+    app_state.time_tracking_factory.invalidate_user(user_id);
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -414,6 +418,9 @@ fn kleer_request_error(error: KleerError) -> ApiError {
             ApiError::new(StatusCode::SERVICE_UNAVAILABLE, error.to_string())
         }
         KleerError::NotFound => ApiError::not_found(error.to_string()),
+        error if error.is_transient() => {
+            ApiError::new(StatusCode::SERVICE_UNAVAILABLE, error.to_string())
+        }
         KleerError::Response { status, body: _ } => {
             tracing::warn!("Kleer admin request failed: status={status}");
             ApiError::internal(format!("Kleer returned {status}"))
@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use toki_types::SetProjectBudgetPayload;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::{ProjectBudgetResponse, ProjectBudgetStatusResponse},
+    app_state::AppState,
+    auth::AuthUser,
+    domain::models::{BudgetPeriod, ProjectBudgetStatus, ProjectId},
+    repositories::{NewProjectBudget, ProjectBudgetRepository, UserRepository},
+    routes::{status::ProviderAvailable, ApiError},
+};
+
+#[instrument(name = "list_budgets", skip(app_state))]
+pub async fn list_budgets(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<ProjectBudgetResponse>>, ApiError> {
+    let budgets = app_state
+        .project_budgets_repo
+        .get_budgets(user.id.as_i32())
+        .await?;
+
+    Ok(Json(budgets.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(name = "set_budget", skip(app_state))]
+pub async fn set_budget(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<SetProjectBudgetPayload>,
+) -> Result<Json<ProjectBudgetResponse>, ApiError> {
+    let period = BudgetPeriod::parse(&payload.period).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "invalid period '{}', expected week or month",
+            payload.period
+        ))
+    })?;
+    if !payload.target_hours.is_finite() || payload.target_hours <= 0.0 {
+        return Err(ApiError::bad_request("targetHours must be greater than 0"));
+    }
+    if payload.project_id.trim().is_empty() {
+        return Err(ApiError::bad_request("projectId is required"));
+    }
+
+    let budget = app_state
+        .project_budgets_repo
+        .upsert_budget(
+            user.id.as_i32(),
+            &NewProjectBudget {
+                project_id: ProjectId::new(payload.project_id),
+                project_name: payload.project_name,
+                period,
+                target_hours: payload.target_hours,
+            },
+        )
+        .await?;
+
+    Ok(Json(budget.into()))
+}
+
+#[instrument(name = "delete_budget", skip(app_state))]
+pub async fn delete_budget(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .project_budgets_repo
+        .delete_budget(user.id.as_i32(), id)
+        .await?;
+    if !deleted {
+        return Err(ApiError::not_found(format!("budget {id} not found")));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Hours used of each budget in its current week or month.
+#[instrument(name = "get_budget_statuses", skip(app_state))]
+pub async fn get_budget_statuses(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<ProjectBudgetStatusResponse>>, ApiError> {
+    let budgets = app_state
+        .project_budgets_repo
+        .get_budgets(user.id.as_i32())
+        .await?;
+    if budgets.is_empty() {
+        return Ok(Json(Vec::new()));
+    }
+
+    let today = app_state.user_repo.get_time_zone(user.id).await?.today();
+    // One fetch covering every period; each status filters out its own days.
+    let (from, to) = budgets
+        .iter()
+        .map(|budget| budget.period.range(today))
+        .reduce(|(from, to), (start, end)| (from.min(start), to.max(end)))
+        .expect("budgets is not empty");
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+    let entries = service
+        .get_time_entries(&user.id, (from, to), false)
+        .await?;
+
+    Ok(Json(
+        budgets
+            .into_iter()
+            .map(|budget| ProjectBudgetStatus::new(budget, today, &entries).into())
+            .collect(),
+    ))
+}
@@ -3,15 +3,32 @@ use axum::{
     http::StatusCode,
     Json,
 };
+use itertools::Itertools;
 use serde::Deserialize;
+use serde_json::json;
+use toki_types::{
+    BatchProjectRegistrationsPayload, BatchRegistrationAction, CopyProjectRegistrationPayload,
+    CreateProjectRegistrationPayload, DeleteProjectRegistrationPayload,
+    EditProjectRegistrationPayload, MergeProjectRegistrationsPayload,
+    SplitProjectRegistrationPayload,
+};
 use tracing::instrument;
 
 use crate::{
-    adapters::inbound::http::{TimeEntryDayStatusResponse, TimeEntryResponse, WeeklyStatsResponse},
+    adapters::inbound::http::{
+        BatchTimeEntriesResponse, MissingWorkdayResponse, SplitTimeEntryResponse,
+        TimeEntryDayStatusResponse, TimeEntryResponse, WeeklyStatsResponse,
+    },
     app_state::AppState,
     auth::AuthUser,
-    domain::models::{ActivityId, CreateTimeEntryRequest, EditTimeEntryRequest, ProjectId},
-    routes::ApiError,
+    domain::{
+        models::{
+            ActivityId, CreateTimeEntryRequest, EditTimeEntryRequest, ProjectId,
+            TimeEntryBatchChange, TimeRounding, UserTimeZone,
+        },
+        EventKind, WebhookEvent,
+    },
+    routes::{status::ProviderAvailable, ApiError},
 };
 
 #[derive(Debug, Deserialize)]
@@ -20,7 +37,7 @@ pub struct DateFilterQuery {
     to: String,
 }
 
-fn parse_date(s: &str) -> Result<time::Date, ApiError> {
+pub(super) fn parse_date(s: &str) -> Result<time::Date, ApiError> {
     let format = time::format_description::parse("[year]-[month]-[day]").unwrap();
     time::Date::parse(s, &format)
         .map_err(|_| ApiError::bad_request(format!("could not parse date: {}", s)))
@@ -31,8 +48,30 @@ fn parse_rfc3339(s: &str, field: &str) -> Result<time::OffsetDateTime, ApiError>
         .map_err(|_| ApiError::bad_request(format!("Invalid {} format", field)))
 }
 
+pub(super) fn parse_rounding(minutes: Option<u16>) -> Result<Option<TimeRounding>, ApiError> {
+    minutes
+        .map(TimeRounding::new)
+        .transpose()
+        .map_err(|e| ApiError::bad_request(e.to_string()))
+}
+
+/// `start` and `end` rounded to `round_to_minutes` on the user's clock, or
+/// as given when no rounding is asked for.
+fn rounded_times(
+    time_zone: &UserTimeZone,
+    round_to_minutes: Option<u16>,
+    start: time::OffsetDateTime,
+    end: time::OffsetDateTime,
+) -> Result<(time::OffsetDateTime, time::OffsetDateTime), ApiError> {
+    let Some(rounding) = parse_rounding(round_to_minutes)? else {
+        return Ok((start, end));
+    };
+    Ok(rounding.round(start, end, time_zone))
+}
+
 #[instrument(name = "get_time_info", skip(app_state))]
 pub async fn get_time_info(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Query(date_filter): Query<DateFilterQuery>,
@@ -59,6 +98,7 @@ pub struct TimeEntriesQuery {
 
 #[instrument(name = "get_time_entries", skip(app_state))]
 pub async fn get_time_entries(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Query(query): Query<TimeEntriesQuery>,
@@ -80,6 +120,7 @@ pub async fn get_time_entries(
 
 #[instrument(name = "get_time_entry_day_statuses", skip(app_state))]
 pub async fn get_time_entry_day_statuses(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Query(date_filter): Query<DateFilterQuery>,
@@ -97,25 +138,50 @@ pub async fn get_time_entry_day_statuses(
     Ok(Json(statuses.into_iter().map(Into::into).collect()))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MissingWorkdaysQuery {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+/// Workdays with scheduled hours and nothing logged. Defaults to the current
+/// month up to today.
+#[instrument(name = "get_missing_workdays", skip(app_state))]
+pub async fn get_missing_workdays(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<MissingWorkdaysQuery>,
+) -> Result<Json<Vec<MissingWorkdayResponse>>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let to = match &query.to {
+        Some(to) => parse_date(to)?,
+        None => service.time_zone().today(),
+    };
+    let from = match &query.from {
+        Some(from) => parse_date(from)?,
+        None => to.replace_day(1).unwrap_or(to),
+    };
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+
+    let missing = service.get_missing_workdays((from, to)).await?;
+
+    Ok(Json(missing.into_iter().map(Into::into).collect()))
+}
+
 // ============================================================================
 // Time Entry Mutations (Create, Edit, Delete)
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EditProjectRegistrationPayload {
-    project_registration_id: String,
-    project_id: String,
-    project_name: String,
-    activity_id: String,
-    activity_name: String,
-    start_time: String,
-    end_time: String,
-    user_note: String,
-}
-
 #[instrument(name = "edit_project_registration", skip(app_state))]
 pub async fn edit_project_registration(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Json(payload): Json<EditProjectRegistrationPayload>,
@@ -125,14 +191,20 @@ pub async fn edit_project_registration(
         .create_service(user.id)
         .await?;
 
+    let (start_time, end_time) = rounded_times(
+        service.time_zone(),
+        payload.round_to_minutes,
+        parse_rfc3339(&payload.start_time, "start time")?,
+        parse_rfc3339(&payload.end_time, "end time")?,
+    )?;
     let request = EditTimeEntryRequest {
         registration_id: payload.project_registration_id,
         project_id: ProjectId::new(payload.project_id),
         project_name: payload.project_name,
         activity_id: ActivityId::new(payload.activity_id),
         activity_name: payload.activity_name,
-        start_time: parse_rfc3339(&payload.start_time, "start time")?,
-        end_time: parse_rfc3339(&payload.end_time, "end time")?,
+        start_time,
+        end_time,
         note: payload.user_note,
     };
 
@@ -141,14 +213,170 @@ pub async fn edit_project_registration(
     Ok(Json(entry.into()))
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DeleteProjectRegistrationPayload {
-    project_registration_id: String,
+#[instrument(name = "split_project_registration", skip(app_state))]
+pub async fn split_project_registration(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<SplitProjectRegistrationPayload>,
+) -> Result<Json<SplitTimeEntryResponse>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let request = EditTimeEntryRequest {
+        registration_id: payload.project_registration_id,
+        project_id: ProjectId::new(payload.project_id),
+        project_name: payload.project_name,
+        activity_id: ActivityId::new(payload.activity_id),
+        activity_name: payload.activity_name,
+        start_time: parse_rfc3339(&payload.start_time, "start time")?,
+        end_time: parse_rfc3339(&payload.end_time, "end time")?,
+        note: payload.user_note,
+    };
+    let split_at = parse_rfc3339(&payload.split_time, "split time")?;
+
+    let (first, second) = service
+        .split_time_entry(&user.id, &request, split_at)
+        .await?;
+    let second = TimeEntryResponse::from(second);
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::EntrySaved, json!(second)),
+        )
+        .await;
+
+    Ok(Json(SplitTimeEntryResponse {
+        first: first.into(),
+        second,
+    }))
+}
+
+#[instrument(name = "copy_project_registration", skip(app_state))]
+pub async fn copy_project_registration(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<CopyProjectRegistrationPayload>,
+) -> Result<(StatusCode, Json<TimeEntryResponse>), ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let date = parse_date(&payload.date)?;
+    let to_date = parse_date(&payload.to_date)?;
+    let entry = TimeEntryResponse::from(
+        service
+            .copy_time_entry(&user.id, date, &payload.project_registration_id, to_date)
+            .await?,
+    );
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+        )
+        .await;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+#[instrument(name = "merge_project_registrations", skip(app_state))]
+pub async fn merge_project_registrations(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<MergeProjectRegistrationsPayload>,
+) -> Result<Json<TimeEntryResponse>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let date = parse_date(&payload.date)?;
+    let entry = TimeEntryResponse::from(
+        service
+            .merge_time_entries(&user.id, date, &payload.project_registration_ids)
+            .await?,
+    );
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+        )
+        .await;
+
+    Ok(Json(entry))
+}
+
+#[instrument(name = "batch_project_registrations", skip(app_state))]
+pub async fn batch_project_registrations(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<BatchProjectRegistrationsPayload>,
+) -> Result<Json<BatchTimeEntriesResponse>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let date_range = (parse_date(&payload.from)?, parse_date(&payload.to)?);
+    let change = match payload.action {
+        BatchRegistrationAction::Delete => TimeEntryBatchChange::Delete,
+        BatchRegistrationAction::Reassign {
+            project_id,
+            project_name,
+            activity_id,
+            activity_name,
+        } => TimeEntryBatchChange::Reassign {
+            project_id: ProjectId::new(project_id),
+            project_name,
+            activity_id: ActivityId::new(activity_id),
+            activity_name,
+        },
+    };
+
+    let updated: Vec<TimeEntryResponse> = service
+        .batch_edit_time_entries(
+            &user.id,
+            date_range,
+            &payload.project_registration_ids,
+            &change,
+        )
+        .await?
+        .into_iter()
+        .map(TimeEntryResponse::from)
+        .collect();
+    for entry in &updated {
+        app_state
+            .event_webhooks
+            .dispatch(
+                user.id,
+                WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+            )
+            .await;
+    }
+
+    let deleted = match change {
+        TimeEntryBatchChange::Delete => payload
+            .project_registration_ids
+            .into_iter()
+            .unique()
+            .collect(),
+        TimeEntryBatchChange::Reassign { .. } => Vec::new(),
+    };
+    Ok(Json(BatchTimeEntriesResponse { updated, deleted }))
 }
 
 #[instrument(name = "delete_project_registration", skip(app_state))]
 pub async fn delete_project_registration(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Json(payload): Json<DeleteProjectRegistrationPayload>,
@@ -165,20 +393,9 @@ pub async fn delete_project_registration(
     Ok(StatusCode::OK)
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CreateProjectRegistrationPayload {
-    project_id: String,
-    project_name: String,
-    activity_id: String,
-    activity_name: String,
-    start_time: String,
-    end_time: String,
-    user_note: String,
-}
-
 #[instrument(name = "create_project_registration", skip(app_state))]
 pub async fn create_project_registration(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
     Json(payload): Json<CreateProjectRegistrationPayload>,
@@ -188,17 +405,30 @@ pub async fn create_project_registration(
         .create_service(user.id)
         .await?;
 
+    let (start_time, end_time) = rounded_times(
+        service.time_zone(),
+        payload.round_to_minutes,
+        parse_rfc3339(&payload.start_time, "start time")?,
+        parse_rfc3339(&payload.end_time, "end time")?,
+    )?;
     let request = CreateTimeEntryRequest {
         project_id: ProjectId::new(payload.project_id),
         project_name: payload.project_name,
         activity_id: ActivityId::new(payload.activity_id),
         activity_name: payload.activity_name,
-        start_time: parse_rfc3339(&payload.start_time, "start time")?,
-        end_time: parse_rfc3339(&payload.end_time, "end time")?,
+        start_time,
+        end_time,
         note: payload.user_note,
     };
 
-    let entry = service.create_time_entry(&user.id, &request).await?;
+    let entry = TimeEntryResponse::from(service.create_time_entry(&user.id, &request).await?);
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+        )
+        .await;
 
-    Ok((StatusCode::CREATED, Json(entry.into())))
+    Ok((StatusCode::CREATED, Json(entry)))
 }
@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use time::{Duration, PrimitiveDateTime, Time};
+use toki_types::{CalendarFeedResponse, DismissCalendarSuggestionPayload, SetCalendarFeedPayload};
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::CalendarSuggestionResponse,
+    app_state::AppState,
+    auth::AuthUser,
+    domain::{
+        models::{CalendarFeed, CalendarSuggestion},
+        validate_calendar_feed_url,
+    },
+    repositories::{CalendarFeedRepository, UserRepository},
+    routes::{status::ProviderAvailable, ApiError},
+};
+
+use super::calendar::parse_date;
+
+/// How far back entries are searched for the project and activity of a
+/// meeting logged before.
+const PREFILL_LOOKBACK_DAYS: i64 = 30;
+const MAX_SUGGESTION_DAYS: i64 = 31;
+
+#[instrument(name = "get_calendar_feed", skip(app_state))]
+pub async fn get_calendar_feed(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<CalendarFeedResponse>, ApiError> {
+    let url = app_state
+        .calendar_feeds_repo
+        .get_feed_url(user.id.as_i32())
+        .await?
+        .ok_or_else(|| ApiError::not_found("no calendar feed set"))?;
+
+    Ok(Json(CalendarFeedResponse { url }))
+}
+
+#[instrument(name = "set_calendar_feed", skip(app_state, payload))]
+pub async fn set_calendar_feed(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<SetCalendarFeedPayload>,
+) -> Result<Json<CalendarFeedResponse>, ApiError> {
+    let url = validate_calendar_feed_url(&payload.url)?.to_string();
+    // Fail now rather than on every later suggestion request.
+    app_state.calendar_feeds.fetch(&url).await?;
+
+    app_state
+        .calendar_feeds_repo
+        .set_feed_url(user.id.as_i32(), &url)
+        .await?;
+
+    Ok(Json(CalendarFeedResponse { url }))
+}
+
+#[instrument(name = "delete_calendar_feed", skip(app_state))]
+pub async fn delete_calendar_feed(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .calendar_feeds_repo
+        .delete_feed(user.id.as_i32())
+        .await?;
+    if !deleted {
+        return Err(ApiError::not_found("no calendar feed set"));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarSuggestionsQuery {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+/// Meetings from the user's calendar feed between `from` and `to`
+/// (inclusive dates, this week up to today by default) that have ended and
+/// have no time entry overlapping them. Empty when no feed is set.
+#[instrument(name = "get_calendar_suggestions", skip(app_state))]
+pub async fn get_calendar_suggestions(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<CalendarSuggestionsQuery>,
+) -> Result<Json<Vec<CalendarSuggestionResponse>>, ApiError> {
+    let Some(url) = app_state
+        .calendar_feeds_repo
+        .get_feed_url(user.id.as_i32())
+        .await?
+    else {
+        return Ok(Json(Vec::new()));
+    };
+
+    let time_zone = app_state.user_repo.get_time_zone(user.id).await?;
+    let today = time_zone.today();
+    let from = match &query.from {
+        Some(from) => parse_date(from)?,
+        None => today - Duration::days(i64::from(today.weekday().number_days_from_monday())),
+    };
+    let to = match &query.to {
+        Some(to) => parse_date(to)?,
+        None => today,
+    };
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+    if to - from >= Duration::days(MAX_SUGGESTION_DAYS) {
+        return Err(ApiError::bad_request(format!(
+            "at most {MAX_SUGGESTION_DAYS} days can be suggested at once"
+        )));
+    }
+
+    let ics = app_state.calendar_feeds.fetch(&url).await?;
+    let start = time_zone.at(PrimitiveDateTime::new(from, Time::MIDNIGHT));
+    let end = time_zone.at(PrimitiveDateTime::new(
+        to + Duration::days(1),
+        Time::MIDNIGHT,
+    ));
+    let events = CalendarFeed::parse(&ics, &time_zone).events_between(start, end);
+    if events.is_empty() {
+        return Ok(Json(Vec::new()));
+    }
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+    let entries = service
+        .get_time_entries(
+            &user.id,
+            (from - Duration::days(PREFILL_LOOKBACK_DAYS), to),
+            false,
+        )
+        .await?;
+    let dismissed = app_state
+        .calendar_feeds_repo
+        .get_dismissals(user.id.as_i32(), start)
+        .await?;
+
+    let suggestions = CalendarSuggestion::suggest(
+        &events,
+        &entries,
+        &dismissed,
+        time::OffsetDateTime::now_utc(),
+    );
+
+    Ok(Json(suggestions.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(name = "dismiss_calendar_suggestion", skip(app_state))]
+pub async fn dismiss_calendar_suggestion(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<DismissCalendarSuggestionPayload>,
+) -> Result<StatusCode, ApiError> {
+    if payload.uid.trim().is_empty() {
+        return Err(ApiError::bad_request("uid is required"));
+    }
+
+    app_state
+        .calendar_feeds_repo
+        .dismiss(user.id.as_i32(), &payload.uid, payload.start_time)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,75 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use toki_types::AddFavoritePayload;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::FavoriteResponse,
+    app_state::AppState,
+    auth::AuthUser,
+    domain::models::{ActivityId, ProjectId},
+    repositories::{FavoriteRepository, NewFavorite},
+    routes::ApiError,
+};
+
+#[instrument(name = "list_favorites", skip(app_state))]
+pub async fn list_favorites(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<FavoriteResponse>>, ApiError> {
+    let favorites = app_state
+        .favorites_repo
+        .get_favorites(user.id.as_i32())
+        .await?;
+
+    Ok(Json(favorites.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(name = "add_favorite", skip(app_state))]
+pub async fn add_favorite(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<AddFavoritePayload>,
+) -> Result<Json<FavoriteResponse>, ApiError> {
+    if payload.project_id.trim().is_empty() {
+        return Err(ApiError::bad_request("projectId is required"));
+    }
+    if payload.activity_id.trim().is_empty() {
+        return Err(ApiError::bad_request("activityId is required"));
+    }
+
+    let favorite = app_state
+        .favorites_repo
+        .upsert_favorite(
+            user.id.as_i32(),
+            &NewFavorite {
+                project_id: ProjectId::new(payload.project_id),
+                project_name: payload.project_name,
+                activity_id: ActivityId::new(payload.activity_id),
+                activity_name: payload.activity_name,
+            },
+        )
+        .await?;
+
+    Ok(Json(favorite.into()))
+}
+
+#[instrument(name = "delete_favorite", skip(app_state))]
+pub async fn delete_favorite(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .favorites_repo
+        .delete_favorite(user.id.as_i32(), id)
+        .await?;
+    if !deleted {
+        return Err(ApiError::not_found(format!("favorite {id} not found")));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,54 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+use time::Duration;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::FlexSnapshotResponse,
+    app_state::AppState,
+    auth::AuthUser,
+    repositories::{FlexSnapshotRepository, UserRepository},
+    routes::ApiError,
+};
+
+use super::calendar::parse_date;
+
+/// Weeks of history returned when no `from` is given.
+const DEFAULT_HISTORY_WEEKS: i64 = 8;
+
+#[derive(Debug, Deserialize)]
+pub struct FlexHistoryQuery {
+    from: Option<String>,
+    to: Option<String>,
+}
+
+/// Recorded flex balances between `from` and `to`, oldest first. Defaults to
+/// the last eight weeks.
+#[instrument(name = "get_flex_history", skip(app_state))]
+pub async fn get_flex_history(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<FlexHistoryQuery>,
+) -> Result<Json<Vec<FlexSnapshotResponse>>, ApiError> {
+    let to = match &query.to {
+        Some(to) => parse_date(to)?,
+        None => app_state.user_repo.get_time_zone(user.id).await?.today(),
+    };
+    let from = match &query.from {
+        Some(from) => parse_date(from)?,
+        None => to - Duration::weeks(DEFAULT_HISTORY_WEEKS),
+    };
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+
+    let snapshots = app_state
+        .flex_snapshots_repo
+        .get_snapshots(user.id.as_i32(), from, to)
+        .await?;
+
+    Ok(Json(snapshots.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use axum::extract::State;
+use axum::Json;
+use serde_json::json;
+use time::Date;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::{
+        TimeEntryImportResponse, TimeEntryImportRowResponse, TimeEntryResponse,
+    },
+    app_state::AppState,
+    auth::AuthUser,
+    domain::{
+        models::{
+            parse_time_entry_csv, Activity, CreateTimeEntryRequest, ImportSchedule, Project,
+            ProjectId, TimeEntryImportRow,
+        },
+        ports::inbound::TimeTrackingService,
+        EventKind, WebhookEvent,
+    },
+    routes::{status::ProviderAvailable, ApiError},
+};
+
+const MAX_IMPORT_ROWS: usize = 500;
+
+/// Create time entries from a CSV body with the columns
+/// `date,project,activity,hours,note`. Projects match on id, name or code and
+/// activities on id or name. Each row is created on its own, so one bad row
+/// doesn't stop the rest; the response reports the outcome per row.
+#[instrument(name = "import_time_entries", skip(app_state, csv))]
+pub async fn import_time_entries(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    csv: String,
+) -> Result<Json<TimeEntryImportResponse>, ApiError> {
+    let parsed = parse_time_entry_csv(&csv);
+    if parsed.is_empty() {
+        return Err(ApiError::bad_request("CSV contains no rows"));
+    }
+    if parsed.len() > MAX_IMPORT_ROWS {
+        return Err(ApiError::bad_request(format!(
+            "CSV has {} rows, at most {MAX_IMPORT_ROWS} can be imported at once",
+            parsed.len()
+        )));
+    }
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+    let time_zone = *service.time_zone();
+    let projects = service.get_projects().await?;
+
+    // Lay entries out after whatever is already registered on their days.
+    let dates = parsed.iter().filter_map(|parsed| parsed.row.as_ref().ok());
+    let existing_ends = match (
+        dates.clone().map(|row| row.date).min(),
+        dates.map(|row| row.date).max(),
+    ) {
+        (Some(from), Some(to)) => service
+            .get_time_entries(&user.id, (from, to), false)
+            .await?
+            .into_iter()
+            .filter_map(|entry| entry.end_time)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let mut schedule = ImportSchedule::new(time_zone, existing_ends);
+
+    let mut activities: HashMap<(ProjectId, Date), Vec<Activity>> = HashMap::new();
+    let mut rows = Vec::with_capacity(parsed.len());
+    for parsed in parsed {
+        let result = match parsed.row {
+            Ok(row) => {
+                import_row(
+                    &app_state,
+                    &user,
+                    service.as_ref(),
+                    &projects,
+                    &mut activities,
+                    &mut schedule,
+                    &row,
+                )
+                .await
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        rows.push(match result {
+            Ok(entry) => TimeEntryImportRowResponse {
+                line: parsed.line,
+                entry: Some(entry),
+                error: None,
+            },
+            Err(error) => TimeEntryImportRowResponse {
+                line: parsed.line,
+                entry: None,
+                error: Some(error),
+            },
+        });
+    }
+
+    let created = rows.iter().filter(|row| row.entry.is_some()).count();
+    Ok(Json(TimeEntryImportResponse {
+        created,
+        failed: rows.len() - created,
+        rows,
+    }))
+}
+
+async fn import_row(
+    app_state: &AppState,
+    user: &AuthUser,
+    service: &dyn TimeTrackingService,
+    projects: &[Project],
+    activities: &mut HashMap<(ProjectId, Date), Vec<Activity>>,
+    schedule: &mut ImportSchedule,
+    row: &TimeEntryImportRow,
+) -> Result<TimeEntryResponse, String> {
+    let project = projects
+        .iter()
+        .find(|project| {
+            project.id.as_str() == row.project
+                || project.name.eq_ignore_ascii_case(&row.project)
+                || project
+                    .code
+                    .as_deref()
+                    .is_some_and(|code| code.eq_ignore_ascii_case(&row.project))
+        })
+        .ok_or_else(|| format!("unknown project '{}'", row.project))?;
+
+    let key = (project.id.clone(), row.date);
+    if !activities.contains_key(&key) {
+        let fetched = service
+            .get_activities(&project.id, (row.date, row.date))
+            .await
+            .map_err(|e| e.to_string())?;
+        activities.insert(key.clone(), fetched);
+    }
+    let activity = activities[&key]
+        .iter()
+        .find(|activity| {
+            activity.id.as_str() == row.activity
+                || activity.name.eq_ignore_ascii_case(&row.activity)
+        })
+        .ok_or_else(|| {
+            format!(
+                "unknown activity '{}' on {} for {}",
+                row.activity, row.date, project.name
+            )
+        })?;
+
+    let (start_time, end_time) = schedule.place(row).map_err(|e| e.to_string())?;
+    let request = CreateTimeEntryRequest {
+        project_id: project.id.clone(),
+        project_name: project.name.clone(),
+        activity_id: activity.id.clone(),
+        activity_name: activity.name.clone(),
+        start_time,
+        end_time,
+        note: row.note.clone(),
+    };
+
+    let entry = service
+        .create_time_entry(&user.id, &request)
+        .await
+        .map_err(|e| e.to_string())?;
+    let entry = TimeEntryResponse::from(entry);
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+        )
+        .await;
+
+    Ok(entry)
+}
@@ -1,11 +1,19 @@
 mod admin;
+mod budgets;
 mod calendar;
+mod calendar_feed;
 mod connection;
+mod favorites;
+mod flex;
+mod import;
+mod notes;
 mod projects;
-mod timer;
+mod reports;
+mod templates;
+pub(super) mod timer;
 
 use axum::{
-    routing::{get, put},
+    routing::{delete, get, post, put},
     Router,
 };
 
@@ -21,10 +29,12 @@ pub fn router() -> Router<AppState> {
             get(projects::list_activities),
         )
         .route("/time-info", get(calendar::get_time_info))
+        .route("/flex-history", get(flex::get_flex_history))
         .route(
             "/time-entry-day-statuses",
             get(calendar::get_time_entry_day_statuses),
         )
+        .route("/missing-workdays", get(calendar::get_missing_workdays))
         .route(
             "/time-entries",
             get(calendar::get_time_entries)
@@ -32,6 +42,26 @@ pub fn router() -> Router<AppState> {
                 .delete(calendar::delete_project_registration)
                 .post(calendar::create_project_registration),
         )
+        .route(
+            "/time-entries/split",
+            post(calendar::split_project_registration),
+        )
+        .route(
+            "/time-entries/copy",
+            post(calendar::copy_project_registration),
+        )
+        .route(
+            "/time-entries/merge",
+            post(calendar::merge_project_registrations),
+        )
+        .route(
+            "/time-entries/batch",
+            post(calendar::batch_project_registrations),
+        )
+        .route("/time-entries/import", post(import::import_time_entries))
+        .route("/time-entries/export", get(reports::export_time_entries))
+        .route("/invoice-report", get(reports::get_invoice_report))
+        .route("/anomalies", get(reports::get_time_entry_anomalies))
         .route("/timer-history", get(timer::get_timer_history))
         .route(
             "/timer",
@@ -40,5 +70,44 @@ pub fn router() -> Router<AppState> {
                 .delete(timer::stop_timer)
                 .put(timer::save_timer),
         )
+        .route("/timer/takeover", post(timer::take_over_timer))
+        .route("/timer/paused", get(timer::get_paused_timers))
+        .route("/timer/pause", post(timer::pause_timer))
+        .route("/timer/resume", post(timer::resume_timer))
+        .route(
+            "/timer/auto-stop",
+            get(timer::get_timer_auto_stop).delete(timer::clear_timer_auto_stop),
+        )
         .route("/update-timer", put(timer::edit_timer))
+        .route("/improve-note", post(notes::improve_note))
+        .route(
+            "/budgets",
+            get(budgets::list_budgets).put(budgets::set_budget),
+        )
+        .route("/budgets/status", get(budgets::get_budget_statuses))
+        .route("/budgets/:id", delete(budgets::delete_budget))
+        .route(
+            "/favorites",
+            get(favorites::list_favorites).post(favorites::add_favorite),
+        )
+        .route("/favorites/:id", delete(favorites::delete_favorite))
+        .route(
+            "/templates",
+            get(templates::list_templates).post(templates::save_template),
+        )
+        .route("/templates/:id", delete(templates::delete_template))
+        .route(
+            "/calendar-feed",
+            get(calendar_feed::get_calendar_feed)
+                .put(calendar_feed::set_calendar_feed)
+                .delete(calendar_feed::delete_calendar_feed),
+        )
+        .route(
+            "/calendar-suggestions",
+            get(calendar_feed::get_calendar_suggestions),
+        )
+        .route(
+            "/calendar-suggestions/dismiss",
+            post(calendar_feed::dismiss_calendar_suggestion),
+        )
 }
@@ -0,0 +1,62 @@
+use axum::{extract::State, http::StatusCode, Json};
+use toki_types::{ImproveNotePayload, ImproveNoteResponse, NoteWorkItemRef};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    auth::AuthUser,
+    domain::models::NoteImprovementRequest,
+    routes::{work_items::ensure_user_has_project_access, ApiError},
+};
+
+#[instrument(name = "improve_note", skip(app_state, body))]
+pub async fn improve_note(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<ImproveNotePayload>,
+) -> Result<Json<ImproveNoteResponse>, ApiError> {
+    let service = app_state.note_improvement_service.clone().ok_or_else(|| {
+        ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Note suggestions are not configured",
+        )
+    })?;
+
+    let mut request = NoteImprovementRequest::new(body.note);
+    if let Some(work_item) = body.work_item {
+        ensure_user_has_project_access(
+            &app_state,
+            &user,
+            &work_item.organization,
+            &work_item.project,
+        )
+        .await?;
+        // A suggestion without context beats no suggestion at all.
+        match work_item_context(&app_state, &work_item).await {
+            Ok(context) => request = request.with_work_item_context(context),
+            Err(e) => tracing::warn!(
+                "Failed to load work item {} for note suggestion: {e}",
+                work_item.work_item_id
+            ),
+        }
+    }
+
+    let suggestion = service.improve_note(request).await?;
+
+    Ok(Json(ImproveNoteResponse { suggestion }))
+}
+
+async fn work_item_context(
+    app_state: &AppState,
+    work_item: &NoteWorkItemRef,
+) -> Result<String, ApiError> {
+    let service = app_state
+        .work_item_factory
+        .create_service(&work_item.organization, &work_item.project)
+        .await?;
+    let (markdown, _has_images) = service
+        .format_work_item_for_llm(&work_item.work_item_id)
+        .await?;
+
+    Ok(markdown)
+}
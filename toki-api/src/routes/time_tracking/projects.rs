@@ -8,11 +8,12 @@ use crate::{
     adapters::inbound::http::{ActivityResponse, ProjectResponse},
     app_state::AppState,
     auth::AuthUser,
-    routes::ApiError,
+    routes::{status::ProviderAvailable, ApiError},
 };
 
 #[instrument(name = "list_projects", skip(app_state))]
 pub async fn list_projects(
+    _: ProviderAvailable,
     State(app_state): State<AppState>,
     user: AuthUser,
 ) -> Result<Json<Vec<ProjectResponse>>, ApiError> {
@@ -30,6 +31,7 @@ pub async fn list_projects(
 
 #[instrument(name = "list_activities", skip(app_state))]
 pub async fn list_activities(
+    _: ProviderAvailable,
     Path(project_id): Path<String>,
     State(app_state): State<AppState>,
     user: AuthUser,
@@ -40,7 +42,7 @@ pub async fn list_activities(
         .await?;
 
     // Use current date range for activity filtering (matches old behavior)
-    let today = time::OffsetDateTime::now_utc().date();
+    let today = service.time_zone().today();
     let activities = service
         .get_activities(&project_id.into(), (today, today))
         .await?;
@@ -0,0 +1,189 @@
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::{InvoiceReportResponse, TimeEntryAnomalyResponse},
+    app_state::AppState,
+    auth::AuthUser,
+    domain::models::{
+        InvoiceReportRules, InvoiceRoundingMode, ProjectId, TimeEntryExport, TimeEntryExportFormat,
+    },
+    routes::{status::ProviderAvailable, ApiError},
+    utils::xlsx,
+};
+
+use super::calendar::parse_date;
+
+/// Largest accepted rounding increment; anything coarser than a working day
+/// is almost certainly a typo.
+const MAX_ROUNDING_MINUTES: u32 = 8 * 60;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceReportQuery {
+    from: String,
+    to: String,
+    project: Option<String>,
+    /// `none`, `nearest` (default) or `up`.
+    rounding: Option<String>,
+    round_to_minutes: Option<u32>,
+    note_separator: Option<String>,
+    dedupe_notes: Option<bool>,
+}
+
+impl InvoiceReportQuery {
+    fn rules(&self) -> Result<InvoiceReportRules, ApiError> {
+        let defaults = InvoiceReportRules::default();
+
+        let rounding = match self.rounding.as_deref() {
+            Some(value) => InvoiceRoundingMode::parse(value).ok_or_else(|| {
+                ApiError::bad_request(format!(
+                    "invalid rounding '{value}', expected none, nearest or up"
+                ))
+            })?,
+            None => defaults.rounding,
+        };
+        let increment_minutes = self.round_to_minutes.unwrap_or(defaults.increment_minutes);
+        if rounding != InvoiceRoundingMode::None
+            && !(1..=MAX_ROUNDING_MINUTES).contains(&increment_minutes)
+        {
+            return Err(ApiError::bad_request(format!(
+                "roundToMinutes must be between 1 and {MAX_ROUNDING_MINUTES}"
+            )));
+        }
+
+        Ok(InvoiceReportRules {
+            rounding,
+            increment_minutes,
+            note_separator: self
+                .note_separator
+                .clone()
+                .unwrap_or(defaults.note_separator),
+            dedupe_notes: self.dedupe_notes.unwrap_or(defaults.dedupe_notes),
+        })
+    }
+}
+
+#[instrument(name = "get_invoice_report", skip(app_state))]
+pub async fn get_invoice_report(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<InvoiceReportQuery>,
+) -> Result<Json<InvoiceReportResponse>, ApiError> {
+    let from = parse_date(&query.from)?;
+    let to = parse_date(&query.to)?;
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+    let rules = query.rules()?;
+    let project_id = query
+        .project
+        .as_deref()
+        .filter(|project| !project.is_empty())
+        .map(ProjectId::new);
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let report = service
+        .get_invoice_report((from, to), project_id.as_ref(), &rules)
+        .await?;
+
+    Ok(Json(report.into()))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnomaliesQuery {
+    from: String,
+    to: String,
+}
+
+#[instrument(name = "get_time_entry_anomalies", skip(app_state))]
+pub async fn get_time_entry_anomalies(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<AnomaliesQuery>,
+) -> Result<Json<Vec<TimeEntryAnomalyResponse>>, ApiError> {
+    let from = parse_date(&query.from)?;
+    let to = parse_date(&query.to)?;
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let anomalies = service
+        .get_time_entry_anomalies(&user.id, (from, to), &app_state.anomaly_check.rules)
+        .await?;
+
+    Ok(Json(anomalies.into_iter().map(Into::into).collect()))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    from: String,
+    to: String,
+    /// `csv` (default) or `xlsx`.
+    format: Option<String>,
+}
+
+/// Download the time entries in a date range as a file, grouped by project
+/// and activity with subtotals.
+#[instrument(name = "export_time_entries", skip(app_state))]
+pub async fn export_time_entries(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response, ApiError> {
+    let from = parse_date(&query.from)?;
+    let to = parse_date(&query.to)?;
+    if from > to {
+        return Err(ApiError::bad_request("from must not be after to"));
+    }
+    let format = match query.format.as_deref() {
+        Some(value) => TimeEntryExportFormat::parse(value).ok_or_else(|| {
+            ApiError::bad_request(format!("invalid format '{value}', expected csv or xlsx"))
+        })?,
+        None => TimeEntryExportFormat::default(),
+    };
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let entries = service
+        .get_time_entries(&user.id, (from, to), false)
+        .await?;
+    let export = TimeEntryExport::new((from, to), entries, service.time_zone());
+
+    let body = match format {
+        // The byte order mark makes Excel read the file as UTF-8.
+        TimeEntryExportFormat::Csv => format!("\u{feff}{}", export.to_csv()).into_bytes(),
+        TimeEntryExportFormat::Xlsx => xlsx::write_workbook("Time entries", &export.rows),
+    };
+    let disposition = format!("attachment; filename=\"{}\"", export.file_name(format));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use toki_types::SaveEntryTemplatePayload;
+use tracing::instrument;
+
+use crate::{
+    adapters::inbound::http::EntryTemplateResponse,
+    app_state::AppState,
+    auth::AuthUser,
+    domain::models::{ActivityId, ProjectId},
+    repositories::{EntryTemplateRepository, NewEntryTemplate},
+    routes::ApiError,
+};
+
+#[instrument(name = "list_templates", skip(app_state))]
+pub async fn list_templates(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<EntryTemplateResponse>>, ApiError> {
+    let templates = app_state
+        .entry_templates_repo
+        .get_templates(user.id.as_i32())
+        .await?;
+
+    Ok(Json(templates.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(name = "save_template", skip(app_state))]
+pub async fn save_template(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(payload): Json<SaveEntryTemplatePayload>,
+) -> Result<Json<EntryTemplateResponse>, ApiError> {
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::bad_request("name is required"));
+    }
+    if payload.project_id.trim().is_empty() || payload.activity_id.trim().is_empty() {
+        return Err(ApiError::bad_request(
+            "projectId and activityId are required",
+        ));
+    }
+    let duration_minutes = payload
+        .duration_minutes
+        .map(|minutes| {
+            i32::try_from(minutes)
+                .ok()
+                .filter(|minutes| *minutes > 0)
+                .ok_or_else(|| ApiError::bad_request("durationMinutes must be greater than 0"))
+        })
+        .transpose()?;
+
+    let template = app_state
+        .entry_templates_repo
+        .upsert_template(
+            user.id.as_i32(),
+            &NewEntryTemplate {
+                name: name.to_string(),
+                project_id: ProjectId::new(payload.project_id),
+                project_name: payload.project_name,
+                activity_id: ActivityId::new(payload.activity_id),
+                activity_name: payload.activity_name,
+                note: payload.note,
+                duration_minutes,
+            },
+        )
+        .await?;
+
+    Ok(Json(template.into()))
+}
+
+#[instrument(name = "delete_template", skip(app_state))]
+pub async fn delete_template(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .entry_templates_repo
+        .delete_template(user.id.as_i32(), id)
+        .await?;
+    if !deleted {
+        return Err(ApiError::not_found(format!("template {id} not found")));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,17 +1,27 @@
 use crate::{
     adapters::inbound::http::{
-        GetTimerResponse, SaveTimerResponse, TimeEntryResponse, TimerHistoryEntryResponse,
+        AutoStoppedTimerResponse, GetTimerAutoStopResponse, GetTimerResponse, PauseTimerResponse,
+        PausedTimerResponse, SaveTimerResponse, TimeEntryResponse, TimerHistoryEntryResponse,
         TimerResponse,
     },
     app_state::AppState,
     auth::AuthUser,
-    domain::models::ActiveTimer,
-    routes::ApiError,
+    domain::{
+        models::{ActiveTimer, UserId},
+        EventKind, WebhookEvent,
+    },
+    repositories::{TimerAutoStopRepository, TimerDeviceRepository},
+    routes::{status::ProviderAvailable, work_items, ApiError},
+    utils::client_device::ClientDevice,
 };
 
 use axum::{extract::State, http::StatusCode, Json};
-use serde::Deserialize;
+use serde_json::json;
 use time::OffsetDateTime;
+use toki_types::{
+    EditTimerPayload, PauseTimerPayload, ResumeTimerPayload, SaveTimerPayload, StartTimerPayload,
+    DEVICE_HEADER,
+};
 use tracing::instrument;
 
 // ============================================================================
@@ -29,30 +39,62 @@ pub async fn get_timer(
         .await?;
 
     let active_timer = service.get_active_timer(&user.id).await?;
+    let timer = match active_timer {
+        Some(timer) => Some(with_device(&app_state, user.id, timer).await),
+        None => None,
+    };
 
-    Ok(Json(GetTimerResponse {
-        timer: active_timer.map(TimerResponse::from),
-    }))
+    Ok(Json(GetTimerResponse { timer }))
+}
+
+/// The active timer, along with the device that owns it.
+pub(crate) async fn with_device(
+    app_state: &AppState,
+    user_id: UserId,
+    timer: ActiveTimer,
+) -> TimerResponse {
+    let device = match app_state
+        .timer_devices_repo
+        .get_timer_device(user_id.as_i32())
+        .await
+    {
+        Ok(device) => device.map(|d| d.device_name),
+        Err(e) => {
+            tracing::warn!("Failed to get timer device for user {user_id}: {e}");
+            None
+        }
+    };
+
+    TimerResponse {
+        device,
+        ..TimerResponse::from(timer)
+    }
+}
+
+/// Record which device now owns the user's timer. `None` clears the owner,
+/// for timers started by clients that don't name themselves.
+///
+/// The timer itself has already changed, so failures are only logged.
+async fn set_timer_device(app_state: &AppState, user_id: UserId, device: Option<&str>) {
+    let repo = &app_state.timer_devices_repo;
+    let result = match device {
+        Some(device) => repo.claim_timer(user_id.as_i32(), device).await,
+        None => repo.release_timer(user_id.as_i32()).await,
+    };
+    if let Err(e) = result {
+        tracing::warn!("Failed to update timer device for user {user_id}: {e}");
+    }
 }
 
 // ============================================================================
 // Start Timer
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct StartTimerPayload {
-    user_note: Option<String>,
-    project_id: Option<String>,
-    project_name: Option<String>,
-    activity_id: Option<String>,
-    activity_name: Option<String>,
-}
-
 #[instrument(name = "start_timer", skip(app_state))]
 pub async fn start_timer(
     user: AuthUser,
     State(app_state): State<AppState>,
+    ClientDevice(device): ClientDevice,
     Json(body): Json<StartTimerPayload>,
 ) -> Result<StatusCode, ApiError> {
     let service = app_state
@@ -60,6 +102,26 @@ pub async fn start_timer(
         .create_service(user.id)
         .await?;
 
+    let timer = timer_from_payload(body);
+    service.start_timer(&user.id, &timer).await?;
+    set_timer_device(&app_state, user.id, device.as_deref()).await;
+    let timer = TimerResponse {
+        device,
+        ..TimerResponse::from(timer)
+    };
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::TimerStarted, json!(timer)),
+        )
+        .await;
+
+    Ok(StatusCode::OK)
+}
+
+/// A timer starting now, as described by `body`.
+fn timer_from_payload(body: StartTimerPayload) -> ActiveTimer {
     let mut timer = ActiveTimer::new(OffsetDateTime::now_utc());
 
     if let (Some(pid), Some(pname)) = (body.project_id, body.project_name) {
@@ -71,10 +133,7 @@ pub async fn start_timer(
     if let Some(note) = body.user_note {
         timer = timer.with_note(note);
     }
-
-    service.start_timer(&user.id, &timer).await?;
-
-    Ok(StatusCode::OK)
+    timer
 }
 
 // ============================================================================
@@ -91,7 +150,19 @@ pub async fn stop_timer(
         .create_service(user.id)
         .await?;
 
+    let timer = service.get_active_timer(&user.id).await?;
     service.stop_timer(&user.id).await?;
+    set_timer_device(&app_state, user.id, None).await;
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(
+                EventKind::TimerStopped,
+                json!(timer.map(TimerResponse::from)),
+            ),
+        )
+        .await;
 
     Ok(StatusCode::OK)
 }
@@ -100,27 +171,12 @@ pub async fn stop_timer(
 // Save Timer (pushes to provider via service layer)
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SaveTimerPayload {
-    user_note: Option<String>,
-    restart_timer: Option<RestartTimerPayload>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct RestartTimerPayload {
-    user_note: String,
-    project_id: Option<String>,
-    project_name: Option<String>,
-    activity_id: Option<String>,
-    activity_name: Option<String>,
-}
-
 #[instrument(name = "save_timer", skip(app_state))]
 pub async fn save_timer(
+    _: ProviderAvailable,
     user: AuthUser,
     State(app_state): State<AppState>,
+    ClientDevice(device): ClientDevice,
     Json(body): Json<SaveTimerPayload>,
 ) -> Result<Json<SaveTimerResponse>, ApiError> {
     let service = app_state
@@ -129,8 +185,27 @@ pub async fn save_timer(
         .await?;
 
     let user_note = body.user_note;
-
-    let entry = service.save_timer(&user.id, user_note).await?;
+    let rounding = super::calendar::parse_rounding(body.round_to_minutes)?;
+    let work_item_id = body
+        .work_item_id
+        .as_deref()
+        .map(|id| id.trim().trim_start_matches('#').trim())
+        .filter(|id| !id.is_empty())
+        .map(str::to_string);
+
+    let saved = service
+        .save_timer(&user.id, user_note, work_item_id, rounding)
+        .await?;
+    if let Some(work_item_id) = saved.entry.work_item_id.clone() {
+        // A resumed timer is saved as several entries, all on the work item
+        let hours = saved
+            .segment_entries
+            .iter()
+            .chain([&saved.entry])
+            .map(|entry| entry.hours)
+            .sum();
+        work_items::spawn_work_item_hour_sync(&app_state, &user, work_item_id, hours);
+    }
 
     let timer = if let Some(restart_timer) = body.restart_timer {
         let mut timer =
@@ -148,32 +223,230 @@ pub async fn save_timer(
         }
 
         service.start_timer(&user.id, &timer).await?;
-        Some(TimerResponse::from(timer))
+        set_timer_device(&app_state, user.id, device.as_deref()).await;
+        Some(TimerResponse {
+            device,
+            ..TimerResponse::from(timer)
+        })
     } else {
+        set_timer_device(&app_state, user.id, None).await;
         None
     };
 
-    Ok(Json(SaveTimerResponse {
-        entry: TimeEntryResponse::from(entry),
+    let response = SaveTimerResponse {
+        entry: TimeEntryResponse::from(saved.entry),
+        timer,
+        lunch_deduction_minutes: saved.lunch_deduction.map(|d| d.whole_minutes()),
+        segment_entries: saved
+            .segment_entries
+            .into_iter()
+            .map(TimeEntryResponse::from)
+            .collect(),
+    };
+    for entry in response.segment_entries.iter().chain([&response.entry]) {
+        app_state
+            .event_webhooks
+            .dispatch(
+                user.id,
+                WebhookEvent::new(EventKind::EntrySaved, json!(entry)),
+            )
+            .await;
+    }
+    if let Some(timer) = &response.timer {
+        app_state
+            .event_webhooks
+            .dispatch(
+                user.id,
+                WebhookEvent::new(EventKind::TimerStarted, json!(timer)),
+            )
+            .await;
+    }
+
+    Ok(Json(response))
+}
+
+// ============================================================================
+// Paused Timers
+// ============================================================================
+
+/// The user's paused timers, the most recently paused first.
+#[instrument(name = "get_paused_timers", skip(app_state))]
+pub async fn get_paused_timers(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<PausedTimerResponse>>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let paused = service.get_paused_timers(&user.id).await?;
+    Ok(Json(paused.into_iter().map(Into::into).collect()))
+}
+
+/// Pause the running timer to track an interruption, optionally starting
+/// another timer in its place.
+#[instrument(name = "pause_timer", skip(app_state))]
+pub async fn pause_timer(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    ClientDevice(device): ClientDevice,
+    Json(body): Json<PauseTimerPayload>,
+) -> Result<Json<PauseTimerResponse>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let paused_timer = service.get_active_timer(&user.id).await?;
+    let next = body.start_timer.map(timer_from_payload);
+    let paused = service.pause_timer(&user.id, next.as_ref()).await?;
+    set_timer_device(&app_state, user.id, next.as_ref().and(device.as_deref())).await;
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(
+                EventKind::TimerStopped,
+                json!(paused_timer.map(TimerResponse::from)),
+            ),
+        )
+        .await;
+
+    let timer = next.map(|timer| TimerResponse {
+        device,
+        ..TimerResponse::from(timer)
+    });
+    if let Some(timer) = &timer {
+        app_state
+            .event_webhooks
+            .dispatch(
+                user.id,
+                WebhookEvent::new(EventKind::TimerStarted, json!(timer)),
+            )
+            .await;
+    }
+
+    Ok(Json(PauseTimerResponse {
+        paused: PausedTimerResponse::from(paused),
         timer,
     }))
 }
 
+/// Continue a paused timer as the running timer.
+#[instrument(name = "resume_timer", skip(app_state))]
+pub async fn resume_timer(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    ClientDevice(device): ClientDevice,
+    Json(body): Json<ResumeTimerPayload>,
+) -> Result<Json<GetTimerResponse>, ApiError> {
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let timer = service.resume_timer(&user.id, body.id).await?;
+    set_timer_device(&app_state, user.id, device.as_deref()).await;
+    let timer = TimerResponse {
+        device,
+        ..TimerResponse::from(timer)
+    };
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::TimerStarted, json!(timer)),
+        )
+        .await;
+
+    Ok(Json(GetTimerResponse { timer: Some(timer) }))
+}
+
 // ============================================================================
-// Edit Timer
+// Take Over Timer
 // ============================================================================
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct EditTimerPayload {
-    user_note: Option<String>,
-    project_id: Option<String>,
-    project_name: Option<String>,
-    activity_id: Option<String>,
-    activity_name: Option<String>,
-    start_time: Option<String>,
+/// Make the calling device the owner of the running timer.
+#[instrument(name = "take_over_timer", skip(app_state))]
+pub async fn take_over_timer(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    ClientDevice(device): ClientDevice,
+) -> Result<Json<GetTimerResponse>, ApiError> {
+    let Some(device) = device else {
+        return Err(ApiError::bad_request(format!(
+            "the {DEVICE_HEADER} header is required to take over the timer"
+        )));
+    };
+
+    let service = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?;
+
+    let timer = service
+        .get_active_timer(&user.id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("no active timer found"))?;
+
+    app_state
+        .timer_devices_repo
+        .claim_timer(user.id.as_i32(), &device)
+        .await?;
+    let timer = TimerResponse {
+        device: Some(device),
+        ..TimerResponse::from(timer)
+    };
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::TimerUpdated, json!(timer)),
+        )
+        .await;
+
+    Ok(Json(GetTimerResponse { timer: Some(timer) }))
 }
 
+// ============================================================================
+// Timer Auto-Stop
+// ============================================================================
+
+/// The last timer the user's auto-stop rule stopped, if not yet acknowledged.
+#[instrument(name = "get_timer_auto_stop", skip(app_state))]
+pub async fn get_timer_auto_stop(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<GetTimerAutoStopResponse>, ApiError> {
+    let auto_stop = app_state
+        .timer_auto_stops_repo
+        .get_auto_stop(user.id.as_i32())
+        .await?;
+
+    Ok(Json(GetTimerAutoStopResponse {
+        auto_stop: auto_stop.map(AutoStoppedTimerResponse::from),
+    }))
+}
+
+/// Acknowledge the last auto-stop once it has been shown to the user.
+#[instrument(name = "clear_timer_auto_stop", skip(app_state))]
+pub async fn clear_timer_auto_stop(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ApiError> {
+    app_state
+        .timer_auto_stops_repo
+        .clear_auto_stop(user.id.as_i32())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Edit Timer
+// ============================================================================
+
 #[instrument(name = "edit_timer", skip(app_state))]
 pub async fn edit_timer(
     user: AuthUser,
@@ -233,6 +506,14 @@ pub async fn edit_timer(
     updated_timer = updated_timer.with_note(note);
 
     service.edit_timer(&user.id, &updated_timer).await?;
+    let timer = with_device(&app_state, user.id, updated_timer).await;
+    app_state
+        .event_webhooks
+        .dispatch(
+            user.id,
+            WebhookEvent::new(EventKind::TimerUpdated, json!(timer)),
+        )
+        .await;
 
     Ok(StatusCode::OK)
 }
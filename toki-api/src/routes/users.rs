@@ -4,13 +4,22 @@ use axum::{
     http::{header, HeaderValue, StatusCode},
     response::Response,
     routing::get,
-    Router,
+    Json, Router,
+};
+use time::Time;
+use toki_types::{
+    DigestPreference, LunchRulePreference, TimeZonePreference, TimerAutoStopPreference,
+    WorkItemHourSyncPreference,
 };
 
 use crate::{
     app_state::AppState,
     auth::AuthUser,
-    domain::{models::UserId, AvatarError},
+    domain::{
+        models::{LunchRule, TimerAutoStop, TimerAutoStopAction, UserId, UserTimeZone},
+        AvatarError, DigestFrequency,
+    },
+    repositories::UserRepository,
     routes::ApiError,
 };
 
@@ -29,6 +38,194 @@ pub fn router() -> Router<AppState> {
         )
         .route_layer(DefaultBodyLimit::max(AVATAR_UPLOAD_BODY_LIMIT))
         .route("/:user_id/avatar", get(user_avatar))
+        .route("/me/time-zone", get(my_time_zone).put(update_my_time_zone))
+        .route(
+            "/me/lunch-rule",
+            get(my_lunch_rule).put(update_my_lunch_rule),
+        )
+        .route(
+            "/me/timer-auto-stop",
+            get(my_timer_auto_stop).put(update_my_timer_auto_stop),
+        )
+        .route(
+            "/me/work-item-hour-sync",
+            get(my_work_item_hour_sync).put(update_my_work_item_hour_sync),
+        )
+        .route("/me/digest", get(my_digest).put(update_my_digest))
+}
+
+async fn my_time_zone(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<TimeZonePreference>, ApiError> {
+    let time_zone = app_state.user_repo.get_time_zone(user.id).await?;
+
+    Ok(Json(TimeZonePreference {
+        time_zone: time_zone.name().to_string(),
+    }))
+}
+
+async fn update_my_time_zone(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<TimeZonePreference>,
+) -> Result<Json<TimeZonePreference>, ApiError> {
+    let time_zone = UserTimeZone::parse(&body.time_zone).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "unknown time zone '{}', expected an IANA name like Europe/Stockholm",
+            body.time_zone
+        ))
+    })?;
+    app_state
+        .user_repo
+        .set_time_zone(user.id, time_zone)
+        .await?;
+
+    Ok(Json(TimeZonePreference {
+        time_zone: time_zone.name().to_string(),
+    }))
+}
+
+async fn my_lunch_rule(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<LunchRulePreference>, ApiError> {
+    let rule = app_state.user_repo.get_lunch_rule(user.id).await?;
+
+    Ok(Json(lunch_rule_preference(&rule)))
+}
+
+async fn update_my_lunch_rule(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<LunchRulePreference>,
+) -> Result<Json<LunchRulePreference>, ApiError> {
+    let window_start = parse_clock_time(&body.window_start)?;
+    let window_end = parse_clock_time(&body.window_end)?;
+    let rule = LunchRule::new(
+        body.enabled,
+        window_start,
+        window_end,
+        body.deduction_minutes,
+    )
+    .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    app_state.user_repo.set_lunch_rule(user.id, &rule).await?;
+
+    Ok(Json(lunch_rule_preference(&rule)))
+}
+
+async fn my_timer_auto_stop(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<TimerAutoStopPreference>, ApiError> {
+    let rule = app_state.user_repo.get_timer_auto_stop(user.id).await?;
+
+    Ok(Json(timer_auto_stop_preference(&rule)))
+}
+
+async fn update_my_timer_auto_stop(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<TimerAutoStopPreference>,
+) -> Result<Json<TimerAutoStopPreference>, ApiError> {
+    let action = TimerAutoStopAction::parse(&body.action).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "unknown auto-stop action '{}', expected save or discard",
+            body.action
+        ))
+    })?;
+    let rule = TimerAutoStop {
+        enabled: body.enabled,
+        stop_at: parse_clock_time(&body.stop_at)?,
+        action,
+    };
+    app_state
+        .user_repo
+        .set_timer_auto_stop(user.id, &rule)
+        .await?;
+
+    Ok(Json(timer_auto_stop_preference(&rule)))
+}
+
+async fn my_work_item_hour_sync(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<WorkItemHourSyncPreference>, ApiError> {
+    let enabled = app_state.user_repo.get_work_item_hour_sync(user.id).await?;
+
+    Ok(Json(WorkItemHourSyncPreference { enabled }))
+}
+
+async fn update_my_work_item_hour_sync(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<WorkItemHourSyncPreference>,
+) -> Result<Json<WorkItemHourSyncPreference>, ApiError> {
+    app_state
+        .user_repo
+        .set_work_item_hour_sync(user.id, body.enabled)
+        .await?;
+
+    Ok(Json(body))
+}
+
+async fn my_digest(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<DigestPreference>, ApiError> {
+    let frequency = app_state.user_repo.get_digest_frequency(user.id).await?;
+
+    Ok(Json(DigestPreference {
+        frequency: frequency.as_str().to_string(),
+    }))
+}
+
+async fn update_my_digest(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<DigestPreference>,
+) -> Result<Json<DigestPreference>, ApiError> {
+    let frequency = DigestFrequency::parse(&body.frequency).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "unknown digest frequency '{}', expected off, daily or weekly",
+            body.frequency
+        ))
+    })?;
+    app_state
+        .user_repo
+        .set_digest_frequency(user.id, frequency)
+        .await?;
+
+    Ok(Json(DigestPreference {
+        frequency: frequency.as_str().to_string(),
+    }))
+}
+
+fn lunch_rule_preference(rule: &LunchRule) -> LunchRulePreference {
+    let format = |time: Time| format!("{:02}:{:02}", time.hour(), time.minute());
+    LunchRulePreference {
+        enabled: rule.enabled,
+        window_start: format(rule.window_start),
+        window_end: format(rule.window_end),
+        deduction_minutes: rule.deduction_minutes,
+    }
+}
+
+fn timer_auto_stop_preference(rule: &TimerAutoStop) -> TimerAutoStopPreference {
+    TimerAutoStopPreference {
+        enabled: rule.enabled,
+        stop_at: format!("{:02}:{:02}", rule.stop_at.hour(), rule.stop_at.minute()),
+        action: rule.action.as_str().to_string(),
+    }
+}
+
+/// Parse an `HH:MM` time of day.
+fn parse_clock_time(value: &str) -> Result<Time, ApiError> {
+    value
+        .split_once(':')
+        .and_then(|(hour, minute)| Some((hour.parse().ok()?, minute.parse().ok()?)))
+        .and_then(|(hour, minute)| Time::from_hms(hour, minute, 0).ok())
+        .ok_or_else(|| ApiError::bad_request(format!("invalid time '{value}', expected HH:MM")))
 }
 
 async fn my_avatar(
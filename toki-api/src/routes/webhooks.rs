@@ -0,0 +1,150 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    auth::AuthUser,
+    domain::{
+        generate_secret, validate_webhook_url, EventKind, EventWebhookDelivery, EventWebhookInfo,
+    },
+    repositories::{EventWebhookRepository, NewEventWebhook},
+};
+
+use super::ApiError;
+
+const MAX_WEBHOOKS_PER_USER: usize = 10;
+const DEFAULT_DELIVERY_LIMIT: i64 = 50;
+const MAX_DELIVERY_LIMIT: i64 = 200;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_webhooks).post(create_webhook))
+        .route("/:id", delete(delete_webhook))
+        .route("/:id/deliveries", get(get_deliveries))
+}
+
+#[instrument(name = "get_event_webhooks")]
+async fn get_webhooks(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<EventWebhookInfo>>, ApiError> {
+    let webhooks = app_state
+        .event_webhooks_repo
+        .get_user_webhooks(user.id.as_i32())
+        .await?;
+
+    Ok(Json(
+        webhooks.into_iter().map(EventWebhookInfo::from).collect(),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookPayload {
+    url: String,
+    events: Vec<String>,
+}
+
+/// A newly created webhook, including the secret used to sign its deliveries.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedWebhookResponse {
+    #[serde(flatten)]
+    webhook: EventWebhookInfo,
+    secret: String,
+}
+
+#[instrument(name = "create_event_webhook", skip(body))]
+async fn create_webhook(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<CreateWebhookPayload>,
+) -> Result<(StatusCode, Json<CreatedWebhookResponse>), ApiError> {
+    let url = validate_webhook_url(body.url.trim()).map_err(ApiError::bad_request)?;
+
+    let mut events = Vec::new();
+    for name in &body.events {
+        let event = EventKind::parse(name)
+            .ok_or_else(|| ApiError::bad_request(format!("Unknown event: {name}")))?;
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+    if events.is_empty() {
+        return Err(ApiError::bad_request("Subscribe to at least one event"));
+    }
+
+    let existing = app_state
+        .event_webhooks_repo
+        .get_user_webhooks(user.id.as_i32())
+        .await?;
+    if existing.len() >= MAX_WEBHOOKS_PER_USER {
+        return Err(ApiError::bad_request(format!(
+            "At most {MAX_WEBHOOKS_PER_USER} webhooks are allowed"
+        )));
+    }
+
+    let webhook = app_state
+        .event_webhooks_repo
+        .insert_webhook(NewEventWebhook {
+            user_id: user.id.as_i32(),
+            url: url.to_string(),
+            secret: generate_secret(),
+            events,
+        })
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedWebhookResponse {
+            secret: webhook.secret.clone(),
+            webhook: webhook.into(),
+        }),
+    ))
+}
+
+#[instrument(name = "delete_event_webhook")]
+async fn delete_webhook(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    app_state
+        .event_webhooks_repo
+        .delete_webhook(id, user.id.as_i32())
+        .await?;
+
+    Ok(StatusCode::OK)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeliveriesQuery {
+    limit: Option<i64>,
+}
+
+#[instrument(name = "get_event_webhook_deliveries")]
+async fn get_deliveries(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<i32>,
+    Query(query): Query<DeliveriesQuery>,
+) -> Result<Json<Vec<EventWebhookDelivery>>, ApiError> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+        .clamp(1, MAX_DELIVERY_LIMIT);
+
+    let deliveries = app_state
+        .event_webhooks_repo
+        .get_deliveries(id, user.id.as_i32(), limit)
+        .await?;
+
+    Ok(Json(deliveries))
+}
@@ -6,32 +6,39 @@ use std::{
 
 use axum::{
     body::Body,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::{header, HeaderValue, StatusCode},
-    response::Response,
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
 use futures_util::future::join_all;
 use moka::sync::Cache;
 use serde::Deserialize;
+use toki_types::SetWorkItemTimeMappingPayload;
 use tracing::instrument;
 
 use crate::{
     adapters::inbound::http::{
-        BoardResponse, FormatForLlmResponse, IterationResponse, PullRequestApprovalStatusResponse,
-        PullRequestRefResponse, PullRequestReviewerResponse, WorkItemProjectResponse,
-        WorkItemResponse,
+        BoardColumnWipLimitResponse, BoardDiffResponse, BoardResponse, FormatForLlmResponse,
+        IterationResponse, MoveWorkItemResponse, ProjectTeamsResponse,
+        PullRequestApprovalStatusResponse, PullRequestRefResponse, PullRequestReviewerResponse,
+        SprintCapacityResponse, WorkItemProjectResponse, WorkItemResponse,
+        WorkItemTimeMappingResponse, WorkItemTimeResponse,
     },
     app_state::AppState,
     auth::AuthUser,
     domain::{
-        models::{BoardData, PullRequestRef, WorkItem, WorkItemProject},
+        models::{
+            ActivityId, BoardColumnWipLimit, BoardData, ProjectId, PullRequestRef, SprintCapacity,
+            WipLimitEnforcement, WorkItem, WorkItemProject,
+        },
         Email, RepoKey, WorkItemError,
     },
+    repositories::{NewWorkItemTimeMapping, WorkItemTimeMappingRepository},
 };
 
-use super::ApiError;
+use super::{status::ProviderAvailable, ApiError};
 
 // ---------------------------------------------------------------------------
 // Query parameter types
@@ -53,6 +60,19 @@ pub struct BoardQuery {
     pub team: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardDiffQuery {
+    pub organization: String,
+    pub project: String,
+    pub iteration_path: Option<String>,
+    pub team: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub from: time::OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub to: time::OffsetDateTime,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FormatForLlmQuery {
@@ -80,6 +100,46 @@ pub struct MoveWorkItemBody {
     pub team: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WipLimitsQuery {
+    pub organization: String,
+    pub project: String,
+    pub team: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWipLimitBody {
+    pub organization: String,
+    pub project: String,
+    pub team: Option<String>,
+    pub column_name: String,
+    /// `None` removes the column's limit.
+    pub limit: Option<i32>,
+    #[serde(default)]
+    pub enforcement: WipLimitEnforcement,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SprintCapacityQuery {
+    pub organization: String,
+    pub project: String,
+    pub team: Option<String>,
+    /// How many of the most recent sprints to return.
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectTeamBody {
+    pub organization: String,
+    pub project: String,
+    /// `None` goes back to the detected default team.
+    pub team: Option<String>,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct PullRequestApprovalIndexKey {
     work_item_id: String,
@@ -107,6 +167,8 @@ struct PullRequestRefEnrichment {
 
 const DEFAULT_WORK_ITEM_IMAGE_MIME: &str = "application/octet-stream";
 const WORK_ITEM_IMAGE_CACHE_CONTROL: &str = "private, max-age=3600";
+const DEFAULT_SPRINT_CAPACITY_COUNT: usize = 6;
+const MAX_SPRINT_CAPACITY_COUNT: usize = 26;
 const AVAILABLE_PROJECTS_CACHE_TTL: Duration = Duration::from_secs(30);
 const AVAILABLE_PROJECTS_CACHE_MAX_ENTRIES: u64 = 2_048;
 
@@ -169,14 +231,50 @@ async fn get_board(
     let mut board_data = service
         .get_board_data(query.iteration_path.as_deref(), query.team.as_deref())
         .await?;
-    apply_avatar_overrides_to_work_items(&app_state, &mut board_data.items).await?;
-    let approval_index =
-        build_pull_request_approval_index(&app_state, &query, &board_data.items).await?;
+    let (avatar_by_email, approval_index) = tokio::try_join!(
+        resolve_work_item_avatar_overrides(&app_state, &board_data.items),
+        build_pull_request_approval_index(&app_state, &query, &board_data.items),
+    )?;
+    apply_avatar_overrides_to_work_items(&mut board_data.items, &avatar_by_email);
     let response = board_response_from_enriched_board(board_data, &approval_index);
 
     Ok(Json(response))
 }
 
+#[instrument(
+    name = "GET /work-items/board/diff",
+    skip(user, app_state),
+    fields(
+        user_id = %user.id,
+        organization = %query.organization,
+        project = %query.project,
+        iteration_path = ?query.iteration_path,
+        team = ?query.team,
+        from = %query.from,
+        to = %query.to,
+    )
+)]
+async fn get_board_diff(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<BoardDiffQuery>,
+) -> Result<Json<BoardDiffResponse>, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &query.organization, &query.project).await?;
+    let service = app_state
+        .work_item_factory
+        .create_service(&query.organization, &query.project)
+        .await?;
+    let diff = service
+        .get_board_diff(
+            query.iteration_path.as_deref(),
+            query.team.as_deref(),
+            query.from,
+            query.to,
+        )
+        .await?;
+    Ok(Json(diff.into()))
+}
+
 #[instrument(name = "GET /work-items/format-for-llm")]
 async fn format_for_llm(
     user: AuthUser,
@@ -246,14 +344,14 @@ async fn move_work_item(
     user: AuthUser,
     State(app_state): State<AppState>,
     Json(body): Json<MoveWorkItemBody>,
-) -> Result<StatusCode, ApiError> {
+) -> Result<Response, ApiError> {
     ensure_user_has_project_access(&app_state, &user, &body.organization, &body.project).await?;
     let service = app_state
         .work_item_factory
         .create_service(&body.organization, &body.project)
         .await?;
 
-    service
+    let wip_limit_warning = service
         .move_work_item_to_column(
             &body.work_item_id,
             &body.target_column_name,
@@ -262,10 +360,251 @@ async fn move_work_item(
         )
         .await?;
 
+    Ok(match wip_limit_warning {
+        Some(violation) => Json(MoveWorkItemResponse {
+            wip_limit_warning: violation.into(),
+        })
+        .into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
+/// Time tracked against a work item by anyone whose entry notes mention it
+/// (`#1234` or `AB#1234`).
+#[instrument(name = "GET /work-items/:id/time")]
+async fn get_work_item_time(
+    State(app_state): State<AppState>,
+    Path(work_item_id): Path<String>,
+) -> Result<Json<WorkItemTimeResponse>, ApiError> {
+    let time = app_state
+        .work_item_time_links
+        .get_work_item_time(&work_item_id)
+        .await?;
+    Ok(Json(time.into()))
+}
+
+#[instrument(name = "GET /work-items/wip-limits")]
+async fn get_wip_limits(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<WipLimitsQuery>,
+) -> Result<Json<Vec<BoardColumnWipLimitResponse>>, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &query.organization, &query.project).await?;
+    let service = app_state
+        .work_item_factory
+        .create_service(&query.organization, &query.project)
+        .await?;
+    let limits = service.get_wip_limits(query.team.as_deref()).await?;
+    Ok(Json(limits.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(
+    name = "PUT /work-items/wip-limits",
+    fields(
+        organization = %body.organization,
+        project = %body.project,
+        column_name = %body.column_name,
+        limit = ?body.limit
+    )
+)]
+async fn set_wip_limit(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<SetWipLimitBody>,
+) -> Result<StatusCode, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &body.organization, &body.project).await?;
+    let service = app_state
+        .work_item_factory
+        .create_service(&body.organization, &body.project)
+        .await?;
+
+    match body.limit {
+        Some(limit) => {
+            service
+                .set_wip_limit(
+                    body.team.as_deref(),
+                    BoardColumnWipLimit {
+                        column_name: body.column_name,
+                        limit,
+                        enforcement: body.enforcement,
+                    },
+                )
+                .await?
+        }
+        None => {
+            service
+                .remove_wip_limit(body.team.as_deref(), &body.column_name)
+                .await?
+        }
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// The user's capacity in the team's most recent sprints against the hours
+/// they logged in each, newest first. Sprints without capacity for the user
+/// are included with only their logged hours.
+#[instrument(
+    name = "GET /work-items/sprint-capacity",
+    skip(user, app_state),
+    fields(
+        user_id = %user.id,
+        organization = %query.organization,
+        project = %query.project,
+        team = ?query.team,
+    )
+)]
+async fn get_sprint_capacity(
+    _: ProviderAvailable,
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<SprintCapacityQuery>,
+) -> Result<Json<Vec<SprintCapacityResponse>>, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &query.organization, &query.project).await?;
+    let count = query
+        .count
+        .unwrap_or(DEFAULT_SPRINT_CAPACITY_COUNT)
+        .clamp(1, MAX_SPRINT_CAPACITY_COUNT);
+    let service = app_state
+        .work_item_factory
+        .create_service(&query.organization, &query.project)
+        .await?;
+    let plans = service
+        .get_sprint_capacity_plans(query.team.as_deref(), count)
+        .await?;
+    let Some((from, to)) = plans
+        .iter()
+        .map(|plan| (plan.start_date, plan.finish_date))
+        .reduce(|(from, to), (start, finish)| (from.min(start), to.max(finish)))
+    else {
+        return Ok(Json(Vec::new()));
+    };
+
+    let entries = app_state
+        .time_tracking_factory
+        .create_service(user.id)
+        .await?
+        .get_time_entries(&user.id, (from, to), false)
+        .await?;
+
+    Ok(Json(
+        plans
+            .iter()
+            .map(|plan| SprintCapacity::new(plan, &user.email, &entries).into())
+            .collect(),
+    ))
+}
+
+/// The teams of a project and the one boards use when no team is given.
+#[instrument(name = "GET /work-items/team")]
+async fn get_project_team(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<ProjectQuery>,
+) -> Result<Json<ProjectTeamsResponse>, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &query.organization, &query.project).await?;
+    let service = app_state
+        .work_item_factory
+        .create_service(&query.organization, &query.project)
+        .await?;
+    let teams = service.get_project_teams().await?;
+    Ok(Json(teams.into()))
+}
+
+#[instrument(
+    name = "PUT /work-items/team",
+    fields(
+        organization = %body.organization,
+        project = %body.project,
+        team = ?body.team
+    )
+)]
+async fn set_project_team(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<SetProjectTeamBody>,
+) -> Result<Json<ProjectTeamsResponse>, ApiError> {
+    ensure_user_has_project_access(&app_state, &user, &body.organization, &body.project).await?;
+    let service = app_state
+        .work_item_factory
+        .create_service(&body.organization, &body.project)
+        .await?;
+    let teams = service.set_team_override(body.team.as_deref()).await?;
+    app_state
+        .work_item_factory
+        .invalidate_project(&body.organization, &body.project);
+    Ok(Json(teams.into()))
+}
+
+/// The projects and activities the user tracks time on for Azure DevOps
+/// projects.
+#[instrument(name = "GET /work-items/time-mappings")]
+async fn get_time_mappings(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<Vec<WorkItemTimeMappingResponse>>, ApiError> {
+    let mappings = app_state
+        .work_item_time_mappings_repo
+        .get_mappings(user.id.as_i32())
+        .await?;
+    Ok(Json(mappings.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(
+    name = "PUT /work-items/time-mappings",
+    fields(organization = %body.organization, project = %body.project)
+)]
+async fn set_time_mapping(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Json(body): Json<SetWorkItemTimeMappingPayload>,
+) -> Result<Json<WorkItemTimeMappingResponse>, ApiError> {
+    if body.project_id.trim().is_empty() {
+        return Err(ApiError::bad_request("projectId is required"));
+    }
+    if body.activity_id.trim().is_empty() {
+        return Err(ApiError::bad_request("activityId is required"));
+    }
+    ensure_user_has_project_access(&app_state, &user, &body.organization, &body.project).await?;
+
+    let mapping = app_state
+        .work_item_time_mappings_repo
+        .upsert_mapping(
+            user.id.as_i32(),
+            &NewWorkItemTimeMapping {
+                organization: body.organization,
+                project: body.project,
+                project_id: ProjectId::new(body.project_id),
+                project_name: body.project_name,
+                activity_id: ActivityId::new(body.activity_id),
+                activity_name: body.activity_name,
+            },
+        )
+        .await?;
+    Ok(Json(mapping.into()))
+}
+
+#[instrument(name = "DELETE /work-items/time-mappings")]
+async fn delete_time_mapping(
+    user: AuthUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<ProjectQuery>,
+) -> Result<StatusCode, ApiError> {
+    let deleted = app_state
+        .work_item_time_mappings_repo
+        .delete_mapping(user.id.as_i32(), &query.organization, &query.project)
+        .await?;
+    if !deleted {
+        return Err(ApiError::not_found(format!(
+            "no time mapping for {}/{}",
+            query.organization, query.project
+        )));
+    }
+
     Ok(StatusCode::NO_CONTENT)
 }
 
-async fn get_available_projects_cached(
+pub(crate) async fn get_available_projects_cached(
     app_state: &AppState,
     user: &AuthUser,
 ) -> Result<Vec<WorkItemProject>, ApiError> {
@@ -285,7 +624,72 @@ async fn get_available_projects_cached(
     Ok(projects)
 }
 
-async fn ensure_user_has_project_access(
+/// Add the hours of a saved timer to its linked work item's Completed Work
+/// and take them off Remaining Work, if the user opted in. Runs in the
+/// background; failures are logged.
+pub(crate) fn spawn_work_item_hour_sync(
+    app_state: &AppState,
+    user: &AuthUser,
+    work_item_id: String,
+    hours: f64,
+) {
+    let (app_state, user) = (app_state.clone(), user.clone());
+    tokio::spawn(async move {
+        match app_state.user_repo.get_work_item_hour_sync(user.id).await {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(e) => {
+                tracing::warn!("Failed to read work item hour sync preference: {e}");
+                return;
+            }
+        }
+
+        let projects = match get_available_projects_cached(&app_state, &user).await {
+            Ok(projects) => projects,
+            Err(e) => {
+                tracing::warn!("Failed to list projects for work item hour sync: {e:?}");
+                return;
+            }
+        };
+
+        // Work item ids are unique per organization, so the first project
+        // that knows the item is the one it belongs to.
+        for project in &projects {
+            let result = match app_state
+                .work_item_factory
+                .create_service(&project.organization, &project.project)
+                .await
+            {
+                Ok(service) => service
+                    .log_work_item_hours(&work_item_id, hours)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.message),
+            };
+            match result {
+                Ok(true) => {
+                    tracing::info!(
+                        work_item_id = %work_item_id,
+                        hours,
+                        organization = %project.organization,
+                        project = %project.project,
+                        "Synced logged hours to work item"
+                    );
+                    return;
+                }
+                Ok(false) => {}
+                Err(e) => tracing::warn!(
+                    work_item_id = %work_item_id,
+                    organization = %project.organization,
+                    project = %project.project,
+                    "Failed to sync logged hours to work item: {e}"
+                ),
+            }
+        }
+    });
+}
+
+pub(crate) async fn ensure_user_has_project_access(
     app_state: &AppState,
     user: &AuthUser,
     organization: &str,
@@ -445,6 +849,7 @@ fn board_response_from_enriched_board(
 ) -> BoardResponse {
     BoardResponse {
         columns: board_data.columns.into_iter().map(Into::into).collect(),
+        wip_limits: board_data.wip_limits.into_iter().map(Into::into).collect(),
         items: board_data
             .items
             .into_iter()
@@ -500,25 +905,22 @@ fn map_work_item_image_error(error: WorkItemError) -> ApiError {
                 ApiError::internal("work item image operation failed")
             }
         }
+        error => error.into(),
     }
 }
 
-async fn apply_avatar_overrides_to_work_items(
+async fn resolve_work_item_avatar_overrides(
     app_state: &AppState,
-    items: &mut [WorkItem],
-) -> Result<(), ApiError> {
-    if items.is_empty() {
-        return Ok(());
-    }
-
+    items: &[WorkItem],
+) -> Result<HashMap<String, String>, ApiError> {
     let mut unique_emails = HashSet::new();
-    for item in items.iter() {
+    for item in items {
         collect_work_item_person_email(&mut unique_emails, item.assigned_to.as_ref());
         collect_work_item_person_email(&mut unique_emails, item.created_by.as_ref());
     }
 
     if unique_emails.is_empty() {
-        return Ok(());
+        return Ok(HashMap::new());
     }
 
     let email_list = unique_emails.into_iter().collect::<Vec<_>>();
@@ -528,14 +930,23 @@ async fn apply_avatar_overrides_to_work_items(
         .await?
         .into_iter()
         .map(|override_item| (override_item.email.to_lowercase(), override_item.avatar_url))
-        .collect::<HashMap<_, _>>();
+        .collect();
 
-    for item in items.iter_mut() {
-        apply_avatar_override_to_work_item_person(item.assigned_to.as_mut(), &avatar_by_email);
-        apply_avatar_override_to_work_item_person(item.created_by.as_mut(), &avatar_by_email);
+    Ok(avatar_by_email)
+}
+
+fn apply_avatar_overrides_to_work_items(
+    items: &mut [WorkItem],
+    avatar_by_email: &HashMap<String, String>,
+) {
+    if avatar_by_email.is_empty() {
+        return;
     }
 
-    Ok(())
+    for item in items.iter_mut() {
+        apply_avatar_override_to_work_item_person(item.assigned_to.as_mut(), avatar_by_email);
+        apply_avatar_override_to_work_item_person(item.created_by.as_mut(), avatar_by_email);
+    }
 }
 
 fn collect_work_item_person_email(
@@ -585,9 +996,20 @@ pub fn router() -> Router<AppState> {
         .route("/projects", get(get_projects))
         .route("/iterations", get(get_iterations))
         .route("/board", get(get_board))
+        .route("/board/diff", get(get_board_diff))
         .route("/image", get(get_image))
         .route("/format-for-llm", get(format_for_llm))
         .route("/move", post(move_work_item))
+        .route("/wip-limits", get(get_wip_limits).put(set_wip_limit))
+        .route("/team", get(get_project_team).put(set_project_team))
+        .route("/sprint-capacity", get(get_sprint_capacity))
+        .route(
+            "/time-mappings",
+            get(get_time_mappings)
+                .put(set_time_mapping)
+                .delete(delete_time_mapping),
+        )
+        .route("/:id/time", get(get_work_item_time))
 }
 
 #[cfg(test)]
@@ -708,6 +1130,7 @@ mod tests {
         let board = BoardData {
             columns: vec![],
             items: vec![sample_work_item()],
+            wip_limits: vec![],
         };
 
         let mut approval_index = HashMap::new();
@@ -754,6 +1177,7 @@ mod tests {
         let board = BoardData {
             columns: vec![],
             items: vec![sample_work_item()],
+            wip_limits: vec![],
         };
 
         let response = board_response_from_enriched_board(board, &HashMap::new());
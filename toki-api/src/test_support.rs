@@ -0,0 +1,40 @@
+//! Fixtures shared by unit tests. Days are in October 2026 and times in UTC.
+
+use time::{Date, Month, OffsetDateTime};
+
+use crate::domain::models::TimeEntry;
+
+pub fn october(day: u8) -> Date {
+    Date::from_calendar_date(2026, Month::October, day).unwrap()
+}
+
+pub fn at(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+    october(day).with_hms(hour, minute, 0).unwrap().assume_utc()
+}
+
+/// An entry of `hours` on `date`, with the project and activity names
+/// equal to their ids.
+pub fn time_entry(id: &str, project: &str, activity: &str, date: Date, hours: f64) -> TimeEntry {
+    TimeEntry::new(id, project, project, activity, activity, date, hours)
+}
+
+/// An entry on project `p1` lasting from `start` to `end`.
+pub fn timed_time_entry(
+    id: &str,
+    activity: &str,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+) -> TimeEntry {
+    time_entry(
+        id,
+        "p1",
+        activity,
+        start.date(),
+        (end - start).as_seconds_f64() / 3600.0,
+    )
+    .with_times(Some(start), Some(end))
+}
+
+pub fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
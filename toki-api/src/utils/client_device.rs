@@ -0,0 +1,28 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use toki_types::DEVICE_HEADER;
+
+const MAX_DEVICE_NAME_CHARS: usize = 64;
+
+/// Device name a client sent in the `X-Toki-Device` header.
+#[derive(Debug, Default)]
+pub struct ClientDevice(pub Option<String>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientDevice
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let name = parts
+            .headers
+            .get(DEVICE_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| name.chars().take(MAX_DEVICE_NAME_CHARS).collect());
+
+        Ok(ClientDevice(name))
+    }
+}
@@ -1 +1,3 @@
+pub(crate) mod client_device;
 pub(crate) mod client_hints;
+pub(crate) mod xlsx;
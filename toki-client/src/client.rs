@@ -0,0 +1,860 @@
+use std::sync::Arc;
+
+use reqwest::{cookie::Jar, Client, Method, RequestBuilder, StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use time::Date;
+use toki_types::{
+    ActivityResponse, AddFavoritePayload, AutoStoppedTimerResponse,
+    BatchProjectRegistrationsPayload, BatchTimeEntriesResponse, CalendarFeedResponse,
+    CalendarSuggestionResponse, CopyProjectRegistrationPayload, CreateProjectRegistrationPayload,
+    DeleteProjectRegistrationPayload, DismissCalendarSuggestionPayload,
+    EditProjectRegistrationPayload, EditTimerPayload, EntryTemplateResponse, FavoriteResponse,
+    FlexSnapshotResponse, GetTimerAutoStopResponse, GetTimerResponse, ImproveNotePayload,
+    ImproveNoteResponse, MergeProjectRegistrationsPayload, MissingWorkdayResponse,
+    PauseTimerPayload, PauseTimerResponse, PausedTimerResponse, ProjectBudgetStatusResponse,
+    ProjectResponse, ResumeTimerPayload, SaveEntryTemplatePayload, SaveTimerPayload,
+    SaveTimerResponse, SetCalendarFeedPayload, SetWorkItemTimeMappingPayload,
+    SplitProjectRegistrationPayload, SplitTimeEntryResponse, SprintCapacityResponse,
+    StartTimerPayload, StatusResponse, TimeEntryDayStatusResponse, TimeEntryResponse,
+    TimeZonePreference, TimerHistoryEntryResponse, TimerResponse, WeeklyStatsResponse,
+    WorkItemTimeMappingResponse, DEVICE_HEADER,
+};
+
+use crate::{
+    pull_request::{ListPullRequest, PullRequestChange},
+    retry::RetryPolicy,
+    work_item::{AssignedWorkItem, Board, WipLimitWarning},
+};
+
+/// Name of the session cookie set by toki-api on login.
+pub const SESSION_COOKIE: &str = "id";
+
+#[derive(Debug, thiserror::Error)]
+pub enum TokiClientError {
+    #[error("invalid API URL: {0}")]
+    InvalidUrl(String),
+    #[error("session expired or invalid")]
+    Unauthorized,
+    #[error("failed to call {call}: {message}")]
+    Request { call: String, message: String },
+    #[error("{call} returned {status}: {body}")]
+    Response {
+        call: String,
+        status: StatusCode,
+        body: String,
+    },
+    #[error("failed to parse {call} response: {message}")]
+    Deserialize { call: String, message: String },
+    /// The server is up but has paused the call, e.g. for planned maintenance.
+    #[error("{0}")]
+    Unavailable(String),
+}
+
+impl TokiClientError {
+    /// Whether the call failed because toki-api, or the time tracking
+    /// provider behind it, could not be reached, as opposed to the request
+    /// being rejected. Such calls are worth trying again later.
+    pub fn is_unreachable(&self) -> bool {
+        match self {
+            Self::Request { .. } | Self::Unavailable(_) => true,
+            Self::Response { status, .. } => status.is_server_error(),
+            _ => false,
+        }
+    }
+}
+
+/// The logged in user, as returned by `GET /me`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Me {
+    pub id: i32,
+    pub email: String,
+    pub full_name: String,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct TokiClient {
+    http: Client,
+    base_url: Url,
+    session_id: String,
+    retry: RetryPolicy,
+    device_name: Option<String>,
+}
+
+impl std::fmt::Debug for TokiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokiClient")
+            .field("base_url", &self.base_url)
+            .field("session_id", &"[redacted]")
+            .field("retry", &self.retry)
+            .field("device_name", &self.device_name)
+            .finish()
+    }
+}
+
+impl TokiClient {
+    /// Create a client authenticated with an existing session id.
+    pub fn new(base_url: &str, session_id: &str) -> Result<Self, TokiClientError> {
+        let base_url = Url::parse(base_url.trim_end_matches('/'))
+            .map_err(|e| TokiClientError::InvalidUrl(format!("{base_url}: {e}")))?;
+        let jar = Arc::new(Jar::default());
+        jar.add_cookie_str(
+            &format!("{}={}; Path=/", SESSION_COOKIE, session_id),
+            &base_url,
+        );
+
+        let http = Client::builder()
+            .cookie_provider(jar)
+            .build()
+            .map_err(|e| TokiClientError::Request {
+                call: "client builder".to_string(),
+                message: e.to_string(),
+            })?;
+
+        Ok(Self {
+            http,
+            base_url,
+            session_id: session_id.to_string(),
+            retry: RetryPolicy::none(),
+            device_name: None,
+        })
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Name this client in the `X-Toki-Device` header, so the server can tell
+    /// which device owns the running timer.
+    pub fn with_device_name(mut self, name: impl Into<String>) -> Self {
+        self.device_name = Some(name.into());
+        self
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    pub(crate) fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        self.device_name.as_deref()
+    }
+
+    // ========================================================================
+    // Auth
+    // ========================================================================
+
+    pub async fn me(&self) -> Result<Me, TokiClientError> {
+        self.get("/me", &[]).await
+    }
+
+    /// The time zone the user's entry dates and weeks are counted in.
+    pub async fn get_time_zone(&self) -> Result<TimeZonePreference, TokiClientError> {
+        self.get("/users/me/time-zone", &[]).await
+    }
+
+    /// Banner and maintenance state. Works without a valid session.
+    pub async fn get_status(&self) -> Result<StatusResponse, TokiClientError> {
+        self.get("/status", &[]).await
+    }
+
+    // ========================================================================
+    // Timer
+    // ========================================================================
+
+    pub async fn get_active_timer(&self) -> Result<Option<TimerResponse>, TokiClientError> {
+        let response: GetTimerResponse = self.get("/time-tracking/timer", &[]).await?;
+        Ok(response.timer)
+    }
+
+    pub async fn start_timer(&self, body: &StartTimerPayload) -> Result<(), TokiClientError> {
+        self.send_without_response(Method::POST, "/time-tracking/timer", Some(body))
+            .await
+    }
+
+    pub async fn save_timer(
+        &self,
+        body: &SaveTimerPayload,
+    ) -> Result<SaveTimerResponse, TokiClientError> {
+        self.send_json(Method::PUT, "/time-tracking/timer", Some(body))
+            .await
+    }
+
+    pub async fn stop_timer(&self) -> Result<(), TokiClientError> {
+        self.send_without_response::<()>(Method::DELETE, "/time-tracking/timer", None)
+            .await
+    }
+
+    /// Make this device the owner of the running timer.
+    pub async fn take_over_timer(&self) -> Result<Option<TimerResponse>, TokiClientError> {
+        let response: GetTimerResponse = self
+            .send_json::<(), _>(Method::POST, "/time-tracking/timer/takeover", None)
+            .await?;
+        Ok(response.timer)
+    }
+
+    /// Paused timers, the most recently paused first.
+    pub async fn get_paused_timers(&self) -> Result<Vec<PausedTimerResponse>, TokiClientError> {
+        self.get("/time-tracking/timer/paused", &[]).await
+    }
+
+    /// Pause the running timer, optionally starting another in its place.
+    pub async fn pause_timer(
+        &self,
+        body: &PauseTimerPayload,
+    ) -> Result<PauseTimerResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/timer/pause", Some(body))
+            .await
+    }
+
+    /// Continue a paused timer as the running timer.
+    pub async fn resume_timer(
+        &self,
+        body: &ResumeTimerPayload,
+    ) -> Result<Option<TimerResponse>, TokiClientError> {
+        let response: GetTimerResponse = self
+            .send_json(Method::POST, "/time-tracking/timer/resume", Some(body))
+            .await?;
+        Ok(response.timer)
+    }
+
+    /// The last timer the user's auto-stop rule stopped, if not yet
+    /// acknowledged with [`Self::clear_timer_auto_stop`].
+    pub async fn get_timer_auto_stop(
+        &self,
+    ) -> Result<Option<AutoStoppedTimerResponse>, TokiClientError> {
+        let response: GetTimerAutoStopResponse =
+            self.get("/time-tracking/timer/auto-stop", &[]).await?;
+        Ok(response.auto_stop)
+    }
+
+    pub async fn clear_timer_auto_stop(&self) -> Result<(), TokiClientError> {
+        self.send_without_response::<()>(Method::DELETE, "/time-tracking/timer/auto-stop", None)
+            .await
+    }
+
+    pub async fn update_timer(&self, body: &EditTimerPayload) -> Result<(), TokiClientError> {
+        self.send_without_response(Method::PUT, "/time-tracking/update-timer", Some(body))
+            .await
+    }
+
+    pub async fn get_timer_history(
+        &self,
+    ) -> Result<Vec<TimerHistoryEntryResponse>, TokiClientError> {
+        self.get("/time-tracking/timer-history", &[]).await
+    }
+
+    /// Ask the server's language model for a clearer version of a note.
+    pub async fn improve_note(
+        &self,
+        body: &ImproveNotePayload,
+    ) -> Result<ImproveNoteResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/improve-note", Some(body))
+            .await
+    }
+
+    // ========================================================================
+    // Calendar
+    // ========================================================================
+
+    pub async fn get_time_info(
+        &self,
+        from: Date,
+        to: Date,
+    ) -> Result<WeeklyStatsResponse, TokiClientError> {
+        self.get("/time-tracking/time-info", &date_range(from, to))
+            .await
+    }
+
+    /// Recorded flex balances between `from` and `to`, oldest first.
+    pub async fn get_flex_history(
+        &self,
+        from: Date,
+        to: Date,
+    ) -> Result<Vec<FlexSnapshotResponse>, TokiClientError> {
+        self.get("/time-tracking/flex-history", &date_range(from, to))
+            .await
+    }
+
+    /// Workdays between `from` and `to` with scheduled hours and nothing
+    /// logged, oldest first.
+    pub async fn get_missing_workdays(
+        &self,
+        from: Date,
+        to: Date,
+    ) -> Result<Vec<MissingWorkdayResponse>, TokiClientError> {
+        self.get("/time-tracking/missing-workdays", &date_range(from, to))
+            .await
+    }
+
+    pub async fn get_time_entries(
+        &self,
+        from: Date,
+        to: Date,
+        unique: bool,
+    ) -> Result<Vec<TimeEntryResponse>, TokiClientError> {
+        let mut query = date_range(from, to);
+        if unique {
+            query.push(("unique", "true".to_string()));
+        }
+        self.get("/time-tracking/time-entries", &query).await
+    }
+
+    pub async fn get_time_entry_day_statuses(
+        &self,
+        from: Date,
+        to: Date,
+    ) -> Result<Vec<TimeEntryDayStatusResponse>, TokiClientError> {
+        self.get(
+            "/time-tracking/time-entry-day-statuses",
+            &date_range(from, to),
+        )
+        .await
+    }
+
+    pub async fn create_time_entry(
+        &self,
+        body: &CreateProjectRegistrationPayload,
+    ) -> Result<TimeEntryResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/time-entries", Some(body))
+            .await
+    }
+
+    pub async fn edit_time_entry(
+        &self,
+        body: &EditProjectRegistrationPayload,
+    ) -> Result<TimeEntryResponse, TokiClientError> {
+        self.send_json(Method::PUT, "/time-tracking/time-entries", Some(body))
+            .await
+    }
+
+    /// Split an entry in two at `body.split_time`.
+    pub async fn split_time_entry(
+        &self,
+        body: &SplitProjectRegistrationPayload,
+    ) -> Result<SplitTimeEntryResponse, TokiClientError> {
+        self.send_json(
+            Method::POST,
+            "/time-tracking/time-entries/split",
+            Some(body),
+        )
+        .await
+    }
+
+    /// Merge consecutive entries on one day into one. Returns the merged entry.
+    pub async fn merge_time_entries(
+        &self,
+        body: &MergeProjectRegistrationsPayload,
+    ) -> Result<TimeEntryResponse, TokiClientError> {
+        self.send_json(
+            Method::POST,
+            "/time-tracking/time-entries/merge",
+            Some(body),
+        )
+        .await
+    }
+
+    /// Copy an entry to another day. Returns the new entry.
+    pub async fn copy_time_entry(
+        &self,
+        body: &CopyProjectRegistrationPayload,
+    ) -> Result<TimeEntryResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/time-entries/copy", Some(body))
+            .await
+    }
+
+    /// Delete or reassign several entries at once. Nothing is changed if any
+    /// of them can't be.
+    pub async fn batch_time_entries(
+        &self,
+        body: &BatchProjectRegistrationsPayload,
+    ) -> Result<BatchTimeEntriesResponse, TokiClientError> {
+        self.send_json(
+            Method::POST,
+            "/time-tracking/time-entries/batch",
+            Some(body),
+        )
+        .await
+    }
+
+    pub async fn delete_time_entry(&self, registration_id: &str) -> Result<(), TokiClientError> {
+        let body = DeleteProjectRegistrationPayload {
+            project_registration_id: registration_id.to_string(),
+        };
+        self.send_without_response(Method::DELETE, "/time-tracking/time-entries", Some(&body))
+            .await
+    }
+
+    // ========================================================================
+    // Projects
+    // ========================================================================
+
+    pub async fn get_projects(&self) -> Result<Vec<ProjectResponse>, TokiClientError> {
+        self.get("/time-tracking/projects", &[]).await
+    }
+
+    pub async fn get_activities(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<ActivityResponse>, TokiClientError> {
+        self.get(
+            &format!("/time-tracking/projects/{}/activities", project_id),
+            &[],
+        )
+        .await
+    }
+
+    /// Progress of the user's project budgets in their current period.
+    pub async fn get_budget_statuses(
+        &self,
+    ) -> Result<Vec<ProjectBudgetStatusResponse>, TokiClientError> {
+        self.get("/time-tracking/budgets/status", &[]).await
+    }
+
+    /// The user's pinned project and activity combinations, in pinned order.
+    pub async fn get_favorites(&self) -> Result<Vec<FavoriteResponse>, TokiClientError> {
+        self.get("/time-tracking/favorites", &[]).await
+    }
+
+    pub async fn add_favorite(
+        &self,
+        body: &AddFavoritePayload,
+    ) -> Result<FavoriteResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/favorites", Some(body))
+            .await
+    }
+
+    pub async fn delete_favorite(&self, id: i32) -> Result<(), TokiClientError> {
+        self.send_without_response::<()>(
+            Method::DELETE,
+            &format!("/time-tracking/favorites/{id}"),
+            None,
+        )
+        .await
+    }
+
+    /// The user's saved entry templates, by name.
+    pub async fn get_templates(&self) -> Result<Vec<EntryTemplateResponse>, TokiClientError> {
+        self.get("/time-tracking/templates", &[]).await
+    }
+
+    pub async fn save_template(
+        &self,
+        body: &SaveEntryTemplatePayload,
+    ) -> Result<EntryTemplateResponse, TokiClientError> {
+        self.send_json(Method::POST, "/time-tracking/templates", Some(body))
+            .await
+    }
+
+    pub async fn delete_template(&self, id: i32) -> Result<(), TokiClientError> {
+        self.send_without_response::<()>(
+            Method::DELETE,
+            &format!("/time-tracking/templates/{id}"),
+            None,
+        )
+        .await
+    }
+
+    /// Set the ICS feed meetings are suggested from. The API fetches it once
+    /// to check it before saving.
+    pub async fn set_calendar_feed(
+        &self,
+        url: &str,
+    ) -> Result<CalendarFeedResponse, TokiClientError> {
+        let body = SetCalendarFeedPayload {
+            url: url.to_string(),
+        };
+        self.send_json(Method::PUT, "/time-tracking/calendar-feed", Some(&body))
+            .await
+    }
+
+    pub async fn delete_calendar_feed(&self) -> Result<(), TokiClientError> {
+        self.send_without_response::<()>(Method::DELETE, "/time-tracking/calendar-feed", None)
+            .await
+    }
+
+    /// Meetings from this week, up to now, that have nothing logged over
+    /// them. Empty when no calendar feed is set.
+    pub async fn get_calendar_suggestions(
+        &self,
+    ) -> Result<Vec<CalendarSuggestionResponse>, TokiClientError> {
+        self.get("/time-tracking/calendar-suggestions", &[]).await
+    }
+
+    pub async fn dismiss_calendar_suggestion(
+        &self,
+        body: &DismissCalendarSuggestionPayload,
+    ) -> Result<(), TokiClientError> {
+        self.send_without_response(
+            Method::POST,
+            "/time-tracking/calendar-suggestions/dismiss",
+            Some(body),
+        )
+        .await
+    }
+
+    // ========================================================================
+    // Pull requests
+    // ========================================================================
+
+    /// Pull requests in the user's followed repositories, newest first.
+    pub async fn list_pull_requests(&self) -> Result<Vec<ListPullRequest>, TokiClientError> {
+        self.get("/pull-requests/list", &[]).await
+    }
+
+    pub async fn get_pull_request_changes(
+        &self,
+        pr: &ListPullRequest,
+    ) -> Result<Vec<PullRequestChange>, TokiClientError> {
+        self.get(
+            "/pull-requests/changes",
+            &[
+                ("organization", pr.organization.clone()),
+                ("project", pr.project.clone()),
+                ("repoName", pr.repo_name.clone()),
+                ("id", pr.id.to_string()),
+            ],
+        )
+        .await
+    }
+
+    // ========================================================================
+    // Work items
+    // ========================================================================
+
+    /// Open work items assigned to the user, across every project they can
+    /// access.
+    pub async fn list_assigned_work_items(&self) -> Result<Vec<AssignedWorkItem>, TokiClientError> {
+        self.get("/my-work/work-items", &[]).await
+    }
+
+    /// The user's capacity in a team's most recent sprints against the hours
+    /// they logged in each, newest first. `team` defaults to the project's
+    /// board team.
+    pub async fn get_sprint_capacity(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+    ) -> Result<Vec<SprintCapacityResponse>, TokiClientError> {
+        let mut query = vec![
+            ("organization", organization.to_string()),
+            ("project", project.to_string()),
+        ];
+        if let Some(team) = team {
+            query.push(("team", team.to_string()));
+        }
+        self.get("/work-items/sprint-capacity", &query).await
+    }
+
+    /// The projects and activities the user tracks time on for Azure DevOps
+    /// projects.
+    pub async fn get_work_item_time_mappings(
+        &self,
+    ) -> Result<Vec<WorkItemTimeMappingResponse>, TokiClientError> {
+        self.get("/work-items/time-mappings", &[]).await
+    }
+
+    /// Set the project and activity to track time on for an Azure DevOps
+    /// project's work items.
+    pub async fn set_work_item_time_mapping(
+        &self,
+        body: &SetWorkItemTimeMappingPayload,
+    ) -> Result<WorkItemTimeMappingResponse, TokiClientError> {
+        self.send_json(Method::PUT, "/work-items/time-mappings", Some(body))
+            .await
+    }
+
+    /// A team's taskboard for the current iteration. `team` defaults to the
+    /// project's board team.
+    pub async fn get_board(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+    ) -> Result<Board, TokiClientError> {
+        let mut query = vec![
+            ("organization", organization.to_string()),
+            ("project", project.to_string()),
+        ];
+        if let Some(team) = team {
+            query.push(("team", team.to_string()));
+        }
+        self.get("/work-items/board", &query).await
+    }
+
+    /// Move a work item to another board column. Returns the WIP limit the
+    /// move exceeded, if any.
+    pub async fn move_work_item(
+        &self,
+        organization: &str,
+        project: &str,
+        team: Option<&str>,
+        work_item_id: &str,
+        target_column_name: &str,
+    ) -> Result<Option<WipLimitWarning>, TokiClientError> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MoveWorkItemBody<'a> {
+            organization: &'a str,
+            project: &'a str,
+            work_item_id: &'a str,
+            target_column_name: &'a str,
+            team: Option<&'a str>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MoveWorkItemResponse {
+            wip_limit_warning: WipLimitWarning,
+        }
+
+        let path = "/work-items/move";
+        let call = format!("POST {path}");
+        let body = MoveWorkItemBody {
+            organization,
+            project,
+            work_item_id,
+            target_column_name,
+            team,
+        };
+        let response = self
+            .execute(self.with_body(Method::POST, path, Some(&body))?, &call)
+            .await?;
+        // 204 when no WIP limit was exceeded.
+        if response.is_empty() {
+            return Ok(None);
+        }
+        parse::<MoveWorkItemResponse>(&call, &response)
+            .map(|response| Some(response.wip_limit_warning))
+    }
+
+    // ========================================================================
+    // Transport
+    // ========================================================================
+
+    async fn get<T>(&self, path: &str, query: &[(&str, String)]) -> Result<T, TokiClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let call = format!("GET {path}");
+        let mut attempt = 1;
+        loop {
+            let request = self.request(Method::GET, path)?.query(query);
+            match self.execute(request, &call).await {
+                Ok(body) => return parse(&call, &body),
+                Err(e) if attempt < self.retry.max_attempts && is_transient(&e) => {
+                    let delay = self.retry.delay_for(attempt);
+                    tracing::debug!("{call} failed ({e}), retrying in {delay:?}");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn send_json<B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<T, TokiClientError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let call = format!("{method} {path}");
+        let response = self
+            .execute(self.with_body(method, path, body)?, &call)
+            .await?;
+        parse(&call, &response)
+    }
+
+    async fn send_without_response<B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<(), TokiClientError>
+    where
+        B: Serialize + ?Sized,
+    {
+        let call = format!("{method} {path}");
+        self.execute(self.with_body(method, path, body)?, &call)
+            .await
+            .map(|_| ())
+    }
+
+    fn with_body<B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<RequestBuilder, TokiClientError>
+    where
+        B: Serialize + ?Sized,
+    {
+        let request = self.request(method, path)?;
+        Ok(match body {
+            Some(body) => request.json(body),
+            None => request,
+        })
+    }
+
+    async fn execute(
+        &self,
+        request: RequestBuilder,
+        call: &str,
+    ) -> Result<String, TokiClientError> {
+        let response = request.send().await.map_err(|e| TokiClientError::Request {
+            call: call.to_string(),
+            message: e.to_string(),
+        })?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| TokiClientError::Request {
+                call: call.to_string(),
+                message: e.to_string(),
+            })?;
+
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            return Err(TokiClientError::Unauthorized);
+        }
+        if status == StatusCode::SERVICE_UNAVAILABLE {
+            if let Some(message) = error_message(&body) {
+                return Err(TokiClientError::Unavailable(message));
+            }
+        }
+        if !status.is_success() {
+            return Err(TokiClientError::Response {
+                call: call.to_string(),
+                status,
+                body,
+            });
+        }
+
+        Ok(body)
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, TokiClientError> {
+        let url = self
+            .base_url
+            .join(path)
+            .map_err(|e| TokiClientError::InvalidUrl(format!("{path}: {e}")))?;
+        let request = self.http.request(method, url);
+        Ok(match &self.device_name {
+            Some(name) => request.header(DEVICE_HEADER, name),
+            None => request,
+        })
+    }
+}
+
+fn parse<T: DeserializeOwned>(call: &str, body: &str) -> Result<T, TokiClientError> {
+    serde_json::from_str(body).map_err(|e| TokiClientError::Deserialize {
+        call: call.to_string(),
+        message: e.to_string(),
+    })
+}
+
+/// The `error` field of a toki-api error body.
+fn error_message(body: &str) -> Option<String> {
+    #[derive(Deserialize)]
+    struct ErrorBody {
+        error: String,
+    }
+
+    serde_json::from_str::<ErrorBody>(body)
+        .ok()
+        .map(|body| body.error)
+}
+
+fn is_transient(error: &TokiClientError) -> bool {
+    match error {
+        TokiClientError::Request { .. } => true,
+        TokiClientError::Response { status, .. } => RetryPolicy::should_retry_status(*status),
+        _ => false,
+    }
+}
+
+fn date_range(from: Date, to: Date) -> Vec<(&'static str, String)> {
+    vec![("from", from.to_string()), ("to", to.to_string())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_base_url() {
+        let error = TokiClient::new("not a url", "session").unwrap_err();
+        assert!(matches!(error, TokiClientError::InvalidUrl(_)));
+    }
+
+    #[test]
+    fn builds_endpoints_relative_to_base_url() {
+        let client = TokiClient::new("https://toki.example.com/", "session").expect("valid url");
+        let request = client
+            .request(Method::GET, "/time-tracking/timer")
+            .expect("valid path")
+            .build()
+            .expect("request to build");
+
+        assert_eq!(
+            request.url().as_str(),
+            "https://toki.example.com/time-tracking/timer"
+        );
+    }
+
+    #[test]
+    fn only_connection_and_server_errors_are_unreachable() {
+        let request = TokiClientError::Request {
+            call: "save timer".to_string(),
+            message: "connection refused".to_string(),
+        };
+        let response = |status| TokiClientError::Response {
+            call: "save timer".to_string(),
+            status,
+            body: String::new(),
+        };
+
+        assert!(request.is_unreachable());
+        assert!(response(StatusCode::BAD_GATEWAY).is_unreachable());
+        assert!(!response(StatusCode::CONFLICT).is_unreachable());
+        assert!(!TokiClientError::Unauthorized.is_unreachable());
+    }
+
+    #[test]
+    fn formats_date_range_query() {
+        let query = date_range(
+            Date::from_calendar_date(2026, time::Month::March, 2).unwrap(),
+            Date::from_calendar_date(2026, time::Month::March, 8).unwrap(),
+        );
+
+        assert_eq!(
+            query,
+            vec![
+                ("from", "2026-03-02".to_string()),
+                ("to", "2026-03-08".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_error_message_from_api_error_body() {
+        assert_eq!(
+            error_message(r#"{"error":"Time tracking is paused for maintenance: upgrade"}"#),
+            Some("Time tracking is paused for maintenance: upgrade".to_string())
+        );
+        assert_eq!(error_message("<html>Bad gateway</html>"), None);
+    }
+}
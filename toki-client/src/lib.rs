@@ -0,0 +1,16 @@
+//! Typed async client for the toki-api HTTP API.
+
+pub mod client;
+pub mod live;
+pub mod pull_request;
+pub mod retry;
+pub mod work_item;
+
+pub use client::{Me, TokiClient, TokiClientError, SESSION_COOKIE};
+pub use live::LiveEvents;
+pub use pull_request::{ListPullRequest, PullRequestChange};
+pub use retry::RetryPolicy;
+pub use toki_types;
+pub use work_item::{
+    AssignedWorkItem, Board, BoardColumn, BoardWorkItem, WipLimitWarning, WorkItemPerson,
+};
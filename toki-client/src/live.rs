@@ -0,0 +1,88 @@
+//! Events pushed by toki-api over a WebSocket.
+
+use futures_util::StreamExt;
+use reqwest::StatusCode;
+use toki_types::{LiveEvent, DEVICE_HEADER};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    tungstenite::{self, client::IntoClientRequest, http::HeaderValue, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+use crate::client::{TokiClient, TokiClientError, SESSION_COOKIE};
+
+const CALL: &str = "GET /ws";
+
+/// An open connection to `GET /ws`.
+pub struct LiveEvents {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl LiveEvents {
+    /// Wait for the next event. Returns `None` once the server closes the
+    /// connection. Events this client doesn't know are skipped.
+    pub async fn next(&mut self) -> Option<Result<LiveEvent, TokiClientError>> {
+        while let Some(message) = self.socket.next().await {
+            let text = match message {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(_)) => return None,
+                // Pings are answered by tungstenite.
+                Ok(_) => continue,
+                Err(e) => return Some(Err(request_error(e))),
+            };
+            match serde_json::from_str(&text) {
+                Ok(event) => return Some(Ok(event)),
+                Err(e) => tracing::debug!("Skipping unknown live event ({e}): {text}"),
+            }
+        }
+        None
+    }
+}
+
+impl TokiClient {
+    /// Connect to the server's live event stream, which pushes timer changes
+    /// made on any of the user's devices.
+    pub async fn connect_live_events(&self) -> Result<LiveEvents, TokiClientError> {
+        let mut url = self
+            .base_url()
+            .join("/ws")
+            .map_err(|e| TokiClientError::InvalidUrl(format!("/ws: {e}")))?;
+        let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+        url.set_scheme(scheme)
+            .map_err(|()| TokiClientError::InvalidUrl(format!("{url}: unsupported scheme")))?;
+
+        let mut request = url.as_str().into_client_request().map_err(request_error)?;
+        let headers = request.headers_mut();
+        let cookie = format!("{}={}", SESSION_COOKIE, self.session_id());
+        headers.insert(
+            "Cookie",
+            HeaderValue::from_str(&cookie)
+                .map_err(|e| TokiClientError::InvalidUrl(format!("session cookie: {e}")))?,
+        );
+        if let Some(name) = self.device_name() {
+            if let Ok(name) = HeaderValue::from_str(name) {
+                headers.insert(DEVICE_HEADER, name);
+            }
+        }
+
+        match tokio_tungstenite::connect_async(request).await {
+            Ok((socket, _)) => Ok(LiveEvents { socket }),
+            Err(tungstenite::Error::Http(response))
+                if matches!(
+                    response.status(),
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+                ) =>
+            {
+                Err(TokiClientError::Unauthorized)
+            }
+            Err(e) => Err(request_error(e)),
+        }
+    }
+}
+
+fn request_error(error: tungstenite::Error) -> TokiClientError {
+    TokiClientError::Request {
+        call: CALL.to_string(),
+        message: error.to_string(),
+    }
+}
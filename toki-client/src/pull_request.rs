@@ -0,0 +1,130 @@
+//! Pull request types returned by `GET /pull-requests/*`.
+//!
+//! These mirror the subset of the API payload the clients read; unknown fields
+//! are ignored.
+
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Identity {
+    pub display_name: String,
+    pub unique_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Vote {
+    Approved,
+    ApprovedWithSuggestions,
+    NoResponse,
+    WaitingForAuthor,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reviewer {
+    pub identity: Identity,
+    #[serde(default)]
+    pub vote: Option<Vote>,
+    #[serde(default)]
+    pub is_required: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: i64,
+    pub author: Identity,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub comment_type: Option<String>,
+    #[serde(default)]
+    pub is_deleted: Option<bool>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published_at: OffsetDateTime,
+}
+
+impl Comment {
+    pub fn is_system_comment(&self) -> bool {
+        self.comment_type.as_deref() == Some("system")
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Thread {
+    pub id: i32,
+    pub comments: Vec<Comment>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub is_deleted: Option<bool>,
+}
+
+impl Thread {
+    /// Threads created by Azure DevOps itself (votes, pushes, policy updates).
+    pub fn is_system_thread(&self) -> bool {
+        self.comments
+            .first()
+            .is_some_and(Comment::is_system_comment)
+    }
+}
+
+/// A followed pull request, as returned by `GET /pull-requests/list`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPullRequest {
+    pub organization: String,
+    pub project: String,
+    pub repo_name: String,
+    pub url: String,
+    pub id: i32,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub created_by: Identity,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub source_branch: String,
+    pub target_branch: String,
+    pub is_draft: bool,
+    #[serde(default)]
+    pub merge_status: Option<String>,
+    pub threads: Vec<Thread>,
+    pub reviewers: Vec<Reviewer>,
+    pub blocked_by: Vec<Reviewer>,
+    pub approved_by: Vec<Reviewer>,
+    pub waiting_for_user_review: bool,
+    pub review_required: bool,
+}
+
+impl ListPullRequest {
+    /// Threads with at least one human comment, oldest first.
+    pub fn discussion_threads(&self) -> impl Iterator<Item = &Thread> {
+        self.threads.iter().filter(|thread| {
+            !thread.is_deleted.unwrap_or(false)
+                && !thread.comments.is_empty()
+                && !thread.is_system_thread()
+        })
+    }
+
+    /// Number of discussion threads still marked active.
+    pub fn active_thread_count(&self) -> usize {
+        self.discussion_threads()
+            .filter(|thread| thread.status.as_deref() == Some("active"))
+            .count()
+    }
+}
+
+/// A file changed by a pull request, as returned by `GET /pull-requests/changes`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestChange {
+    pub path: String,
+    pub change_type: String,
+    #[serde(default)]
+    pub original_path: Option<String>,
+}
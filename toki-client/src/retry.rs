@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Controls how idempotent (GET) requests are retried.
+///
+/// Mutating requests are never retried since the server may already have
+/// applied them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every subsequent retry.
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// A single attempt, no retries.
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+        }
+    }
+
+    pub const fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+        }
+    }
+
+    /// Delay before retry number `retry` (1-based).
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+    }
+
+    pub(crate) fn should_retry_status(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(250))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_per_retry() {
+        let policy = RetryPolicy::new(4, Duration::from_millis(100));
+
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn retries_only_transient_statuses() {
+        assert!(RetryPolicy::should_retry_status(StatusCode::BAD_GATEWAY));
+        assert!(RetryPolicy::should_retry_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(!RetryPolicy::should_retry_status(StatusCode::NOT_FOUND));
+    }
+}
@@ -0,0 +1,84 @@
+//! Work item types returned by `GET /my-work/*` and `/work-items/*`.
+//!
+//! These mirror the subset of the API payload the clients read; unknown fields
+//! are ignored.
+
+use serde::Deserialize;
+
+/// An open work item assigned to the user.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignedWorkItem {
+    pub organization: String,
+    pub project: String,
+    pub id: String,
+    pub title: String,
+    /// `userStory`, `bug`, `task`, `feature`, `epic` or the provider's name.
+    pub category: String,
+    pub state_name: String,
+    pub url: String,
+}
+
+impl AssignedWorkItem {
+    /// `#1234 Title`, the way work items are referenced in notes.
+    pub fn reference(&self) -> String {
+        format!("#{} {}", self.id, self.title.trim())
+    }
+}
+
+/// A team's taskboard for the current iteration.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Board {
+    pub columns: Vec<BoardColumn>,
+    pub items: Vec<BoardWorkItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardColumn {
+    pub id: String,
+    pub name: String,
+    pub order: i32,
+}
+
+/// A work item on a board.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardWorkItem {
+    pub id: String,
+    pub title: String,
+    /// `todo`, `inProgress` or `done`, used to place items without a column.
+    pub board_state: String,
+    pub board_column_id: Option<String>,
+    pub board_column_name: Option<String>,
+    pub category: String,
+    pub state_name: String,
+    pub priority: Option<i32>,
+    pub assigned_to: Option<WorkItemPerson>,
+    pub url: String,
+}
+
+impl BoardWorkItem {
+    /// `#1234 Title`, the way work items are referenced in notes.
+    pub fn reference(&self) -> String {
+        format!("#{} {}", self.id, self.title.trim())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkItemPerson {
+    pub display_name: String,
+}
+
+/// A move that went through although it put a column over its WIP limit.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WipLimitWarning {
+    pub column_name: String,
+    pub limit: i32,
+    pub item_count: i32,
+    /// `warn` or `block`.
+    pub enforcement: String,
+}
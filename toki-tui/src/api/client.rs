@@ -1,121 +1,130 @@
 use anyhow::{Context, Result};
-use reqwest::{cookie::Jar, Client, RequestBuilder, Response, StatusCode, Url};
-use serde::de::DeserializeOwned;
-use std::sync::Arc;
+use std::time::Duration;
+use toki_client::{RetryPolicy, TokiClient, TokiClientError};
+use toki_types::{
+    AddFavoritePayload, BatchProjectRegistrationsPayload, BatchRegistrationAction,
+    CopyProjectRegistrationPayload, CreateProjectRegistrationPayload,
+    DismissCalendarSuggestionPayload, EditProjectRegistrationPayload, EditTimerPayload,
+    EntryTemplateResponse, ImproveNotePayload, MergeProjectRegistrationsPayload, PauseTimerPayload,
+    ResumeTimerPayload, SaveEntryTemplatePayload, SaveTimerPayload, SetWorkItemTimeMappingPayload,
+    SplitProjectRegistrationPayload, StartTimerPayload,
+};
+use tokio::sync::mpsc;
 
 use crate::api::dev_backend::DevBackend;
-use crate::api::dto::{
-    ActivityDto, DeleteEntryRequest, EditEntryRequest, ProjectDto, StartTimerRequest,
-    UpdateActiveTimerRequest,
-};
-use crate::api::SaveTimerRequest;
+use crate::app::BoardProject;
+use crate::config::TemplateConfig;
+use crate::rounding::Rounding;
 use crate::types::{
-    ActiveTimerState, Activity, GetTimerResponse, Me, Project, TimeEntry, TimeInfo,
+    ActiveTimerState, Activity, AutoStoppedTimer, Board, BudgetStatus, CalendarSuggestion,
+    Favorite, FlexSnapshot, LiveEvent, Me, MissingWorkday, PausedTimer, Project, ProjectBudget,
+    PullRequest, PullRequestChange, SprintCapacity, StatusResponse, TimeEntry, TimeInfo,
+    WipLimitWarning, WorkItem, WorkItemTimeMapping,
 };
 
-const SESSION_COOKIE: &str = "id";
+/// Delay before reconnecting to the server's live events, doubled after every
+/// failed attempt up to `LIVE_RECONNECT_MAX`.
+const LIVE_RECONNECT_MIN: Duration = Duration::from_secs(2);
+const LIVE_RECONNECT_MAX: Duration = Duration::from_secs(60);
+
 const UNAUTH_INVALID_SESSION: &str =
     "Session expired or invalid. Run `toki-tui login` to authenticate.";
 const UNAUTH_RELOGIN: &str = "Session expired. Run `toki-tui login` to re-authenticate.";
 
 #[derive(Debug, Clone)]
 pub struct ApiClient {
-    client: Client,
-    base_url: Url,
+    inner: TokiClient,
     dev_backend: Option<DevBackend>,
+    /// Minutes the server rounds saved entries to, see [`Self::with_rounding`].
+    round_to_minutes: Option<u16>,
 }
 
-impl ApiClient {
-    pub fn new(base_url: &str, session_id: &str) -> Result<Self> {
-        let base_url = Url::parse(base_url.trim_end_matches('/'))
-            .with_context(|| format!("Invalid API URL: {}", base_url))?;
-        let jar = Arc::new(Jar::default());
+/// Turn a client error into a user-facing error, pointing at `toki-tui login`
+/// when the session is no longer valid.
+fn template_from_dto(dto: EntryTemplateResponse) -> TemplateConfig {
+    TemplateConfig {
+        description: dto.name,
+        project: dto.project_name,
+        activity: dto.activity_name,
+        note: dto.note,
+        duration_minutes: dto.duration_minutes,
+        server_id: Some(dto.id),
+    }
+}
 
-        jar.add_cookie_str(
-            &format!("{}={}; Path=/", SESSION_COOKIE, session_id),
-            &base_url,
-        );
+fn map_error(error: TokiClientError, unauthorized_message: &str) -> anyhow::Error {
+    match error {
+        TokiClientError::Unauthorized => anyhow::anyhow!("{unauthorized_message}"),
+        other => other.into(),
+    }
+}
 
-        let client = Client::builder()
-            .cookie_provider(jar.clone())
-            .build()
-            .context("Failed to build HTTP client")?;
+/// Whether a failed call should be queued and retried once toki-api or the
+/// time tracking provider is reachable again.
+pub fn is_unreachable(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<TokiClientError>()
+        .is_some_and(TokiClientError::is_unreachable)
+}
 
+impl ApiClient {
+    pub fn new(base_url: &str, session_id: &str, device_name: &str) -> Result<Self> {
         Ok(Self {
-            client,
-            base_url,
+            inner: TokiClient::new(base_url, session_id)?
+                .with_retry_policy(RetryPolicy::default())
+                .with_device_name(device_name),
             dev_backend: None,
+            round_to_minutes: None,
         })
     }
 
     pub fn dev() -> Result<Self> {
-        let base_url = Url::parse("http://localhost")?;
-        let jar = Arc::new(Jar::default());
-        let client = Client::builder()
-            .cookie_provider(jar.clone())
-            .build()
-            .context("Failed to build HTTP client")?;
-
         Ok(Self {
-            client,
-            base_url,
+            inner: TokiClient::new("http://localhost", "")?,
             dev_backend: Some(DevBackend::new()),
+            round_to_minutes: None,
         })
     }
 
-    fn endpoint(&self, path: &str) -> Result<Url> {
-        self.base_url
-            .join(path)
-            .with_context(|| format!("Failed to build URL for path {}", path))
+    /// Have saved timers and created or edited entries rounded by the
+    /// server when `rounding` applies on save.
+    pub fn with_rounding(mut self, rounding: Option<Rounding>) -> Self {
+        self.round_to_minutes = rounding.and_then(Rounding::save_minutes);
+        self
     }
 
-    async fn send(
-        &mut self,
-        request: RequestBuilder,
-        call_name: &str,
-        unauthorized_message: &str,
-    ) -> Result<Response> {
-        let response = request
-            .send()
-            .await
-            .with_context(|| format!("Failed to call {}", call_name))?;
-
-        if matches!(
-            response.status(),
-            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
-        ) {
-            anyhow::bail!("{unauthorized_message}");
+    /// Keep a connection to the server's live events open in the background
+    /// and forward the events it pushes. Reconnects after dropped connections
+    /// and stops once the session is invalid or the receiver is dropped.
+    /// `None` in dev mode, which has no server.
+    pub fn live_events(&self) -> Option<mpsc::UnboundedReceiver<LiveEvent>> {
+        if self.dev_backend.is_some() {
+            return None;
         }
 
-        response
-            .error_for_status_ref()
-            .with_context(|| format!("{} returned error", call_name))?;
-
-        Ok(response)
-    }
-
-    async fn get_json<T: DeserializeOwned>(
-        &mut self,
-        request: RequestBuilder,
-        call_name: &str,
-        unauthorized_message: &str,
-    ) -> Result<T> {
-        let response = self.send(request, call_name, unauthorized_message).await?;
-        response
-            .json::<T>()
-            .await
-            .with_context(|| format!("Failed to parse {} response", call_name))
-    }
-
-    async fn send_without_body(
-        &mut self,
-        request: RequestBuilder,
-        call_name: &str,
-        unauthorized_message: &str,
-    ) -> Result<()> {
-        let response = self.send(request, call_name, unauthorized_message).await?;
-        let _ = response.bytes().await;
-        Ok(())
+        let client = self.inner.clone();
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut delay = LIVE_RECONNECT_MIN;
+            while !tx.is_closed() {
+                match client.connect_live_events().await {
+                    Ok(mut events) => {
+                        delay = LIVE_RECONNECT_MIN;
+                        while let Some(Ok(event)) = events.next().await {
+                            if tx.send(event).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(TokiClientError::Unauthorized) => return,
+                    // The 60 second refresh keeps the TUI in sync meanwhile.
+                    Err(_) => {}
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(LIVE_RECONNECT_MAX);
+            }
+        });
+        Some(rx)
     }
 
     pub async fn me(&mut self) -> Result<Me> {
@@ -124,31 +133,72 @@ impl ApiClient {
                 id: 1,
                 email: "dev@localhost".to_string(),
                 full_name: "Dev User".to_string(),
+                avatar_url: None,
             });
         }
 
-        self.get_json(
-            self.client.get(self.endpoint("/me")?),
-            "GET /me",
-            UNAUTH_INVALID_SESSION,
-        )
-        .await
+        self.inner
+            .me()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_INVALID_SESSION))
     }
 
-    pub async fn get_active_timer(&mut self) -> Result<Option<ActiveTimerState>> {
+    /// IANA name of the user's time zone preference. `None` in dev mode,
+    /// where the system time zone is used.
+    pub async fn get_time_zone(&mut self) -> Result<Option<String>> {
         if self.dev_backend.is_some() {
             return Ok(None);
         }
 
-        let response: GetTimerResponse = self
-            .get_json(
-                self.client.get(self.endpoint("/time-tracking/timer")?),
-                "GET /time-tracking/timer",
-                UNAUTH_RELOGIN,
-            )
-            .await?;
+        self.inner
+            .get_time_zone()
+            .await
+            .map(|preference| Some(preference.time_zone))
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// The last timer the server's auto-stop rule stopped, acknowledged so
+    /// it is only reported once. Nothing to report in dev mode.
+    pub async fn take_timer_auto_stop(&mut self) -> Result<Option<AutoStoppedTimer>> {
+        if self.dev_backend.is_some() {
+            return Ok(None);
+        }
 
-        Ok(response.timer)
+        let stopped = self
+            .inner
+            .get_timer_auto_stop()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
+        if stopped.is_some() {
+            self.inner
+                .clear_timer_auto_stop()
+                .await
+                .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
+        }
+        Ok(stopped)
+    }
+
+    /// Announcement banner and maintenance state. Nothing to show in dev mode.
+    pub async fn get_status(&mut self) -> Result<StatusResponse> {
+        if self.dev_backend.is_some() {
+            return Ok(StatusResponse::default());
+        }
+
+        self.inner
+            .get_status()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_active_timer(&mut self) -> Result<Option<ActiveTimerState>> {
+        if self.dev_backend.is_some() {
+            return Ok(None);
+        }
+
+        self.inner
+            .get_active_timer()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn get_time_info(&mut self, from: time::Date, to: time::Date) -> Result<TimeInfo> {
@@ -156,18 +206,10 @@ impl ApiClient {
             return Ok(dev.time_info());
         }
 
-        let format = time::format_description::parse("[year]-[month]-[day]")?;
-        let from_str = from.format(&format).context("Failed to format from date")?;
-        let to_str = to.format(&format).context("Failed to format to date")?;
-
-        self.get_json(
-            self.client
-                .get(self.endpoint("/time-tracking/time-info")?)
-                .query(&[("from", &from_str), ("to", &to_str)]),
-            "GET /time-tracking/time-info",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        self.inner
+            .get_time_info(from, to)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn get_time_entries(
@@ -190,18 +232,10 @@ impl ApiClient {
                 .collect());
         }
 
-        let format = time::format_description::parse("[year]-[month]-[day]")?;
-        let from_str = from.format(&format).context("Failed to format from date")?;
-        let to_str = to.format(&format).context("Failed to format to date")?;
-
-        self.get_json(
-            self.client
-                .get(self.endpoint("/time-tracking/time-entries")?)
-                .query(&[("from", &from_str), ("to", &to_str)]),
-            "GET /time-tracking/time-entries",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        self.inner
+            .get_time_entries(from, to, false)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn start_timer(
@@ -216,35 +250,85 @@ impl ApiClient {
             return Ok(());
         }
 
-        self.send_without_body(
-            self.client
-                .post(self.endpoint("/time-tracking/timer")?)
-                .json(&StartTimerRequest {
-                    project_id,
-                    project_name,
-                    activity_id,
-                    activity_name,
-                    user_note: note,
-                }),
-            "POST /time-tracking/timer",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        self.inner
+            .start_timer(&StartTimerPayload {
+                project_id,
+                project_name,
+                activity_id,
+                activity_name,
+                user_note: note,
+            })
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
-    pub async fn save_timer(&mut self, request: SaveTimerRequest) -> Result<()> {
+    /// Save the active timer. Returns the minutes deducted for lunch, if any.
+    pub async fn save_timer(&mut self, mut request: SaveTimerPayload) -> Result<Option<i64>> {
         if self.dev_backend.is_some() {
-            return Ok(());
+            return Ok(None);
+        }
+
+        request.round_to_minutes = request.round_to_minutes.or(self.round_to_minutes);
+        self.inner
+            .save_timer(&request)
+            .await
+            .map(|response| response.lunch_deduction_minutes)
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Make this TUI the owner of the running timer. Returns the timer as the
+    /// server now sees it, or `None` if it's no longer running.
+    pub async fn take_over_timer(&mut self) -> Result<Option<ActiveTimerState>> {
+        if self.dev_backend.is_some() {
+            anyhow::bail!("Taking over the timer is not available in dev mode");
+        }
+
+        self.inner
+            .take_over_timer()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Timers paused on the server, the most recently paused first. None in
+    /// dev mode.
+    pub async fn get_paused_timers(&mut self) -> Result<Vec<PausedTimer>> {
+        if self.dev_backend.is_some() {
+            return Ok(Vec::new());
+        }
+
+        self.inner
+            .get_paused_timers()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Pause the running timer and start an empty one in its place. Returns
+    /// the paused timer and the new one.
+    pub async fn pause_timer(&mut self) -> Result<(PausedTimer, Option<ActiveTimerState>)> {
+        if self.dev_backend.is_some() {
+            anyhow::bail!("Pausing timers is not available in dev mode");
         }
 
-        self.send_without_body(
-            self.client
-                .put(self.endpoint("/time-tracking/timer")?)
-                .json(&request),
-            "PUT /time-tracking/timer",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        let request = PauseTimerPayload {
+            start_timer: Some(StartTimerPayload::default()),
+        };
+        self.inner
+            .pause_timer(&request)
+            .await
+            .map(|response| (response.paused, response.timer))
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Continue the paused timer `id` as the running timer.
+    pub async fn resume_timer(&mut self, id: i32) -> Result<Option<ActiveTimerState>> {
+        if self.dev_backend.is_some() {
+            anyhow::bail!("Pausing timers is not available in dev mode");
+        }
+
+        self.inner
+            .resume_timer(&ResumeTimerPayload { id: Some(id) })
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn stop_timer(&mut self) -> Result<()> {
@@ -252,12 +336,10 @@ impl ApiClient {
             return Ok(());
         }
 
-        self.send_without_body(
-            self.client.delete(self.endpoint("/time-tracking/timer")?),
-            "DELETE /time-tracking/timer",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        self.inner
+            .stop_timer()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn update_active_timer(
@@ -273,21 +355,69 @@ impl ApiClient {
             return Ok(());
         }
 
-        self.send_without_body(
-            self.client
-                .put(self.endpoint("/time-tracking/update-timer")?)
-                .json(&UpdateActiveTimerRequest {
-                    project_id,
-                    project_name,
-                    activity_id,
-                    activity_name,
-                    user_note: note,
-                    start_time,
-                }),
-            "PUT /time-tracking/update-timer",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        let start_time = start_time
+            .map(|t| t.format(&time::format_description::well_known::Rfc3339))
+            .transpose()
+            .context("Failed to format start_time")?;
+
+        self.inner
+            .update_timer(&EditTimerPayload {
+                project_id,
+                project_name,
+                activity_id,
+                activity_name,
+                user_note: note,
+                start_time,
+            })
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_time_entry(
+        &mut self,
+        project_id: &str,
+        project_name: &str,
+        activity_id: &str,
+        activity_name: &str,
+        start_time: time::OffsetDateTime,
+        end_time: time::OffsetDateTime,
+        user_note: &str,
+    ) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            dev.create_entry(
+                project_id,
+                project_name,
+                activity_id,
+                activity_name,
+                start_time,
+                end_time,
+                user_note,
+            );
+            return Ok(());
+        }
+
+        let format = time::format_description::well_known::Rfc3339;
+        let body = CreateProjectRegistrationPayload {
+            project_id: project_id.to_string(),
+            project_name: project_name.to_string(),
+            activity_id: activity_id.to_string(),
+            activity_name: activity_name.to_string(),
+            start_time: start_time
+                .format(&format)
+                .context("Failed to format start_time")?,
+            end_time: end_time
+                .format(&format)
+                .context("Failed to format end_time")?,
+            user_note: user_note.to_string(),
+            round_to_minutes: self.round_to_minutes,
+        };
+
+        self.inner
+            .create_time_entry(&body)
+            .await
+            .map(|_| ())
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -300,11 +430,7 @@ impl ApiClient {
         activity_name: &str,
         start_time: time::OffsetDateTime,
         end_time: time::OffsetDateTime,
-        reg_day: &str,
-        week_number: i32,
         user_note: &str,
-        original_project_id: Option<&str>,
-        original_activity_id: Option<&str>,
     ) -> Result<()> {
         if let Some(dev) = &self.dev_backend {
             dev.edit_entry(
@@ -321,34 +447,227 @@ impl ApiClient {
         }
 
         let format = time::format_description::well_known::Rfc3339;
-        let body = EditEntryRequest {
-            project_registration_id,
-            project_id,
-            project_name,
-            activity_id,
-            activity_name,
+        let body = EditProjectRegistrationPayload {
+            project_registration_id: project_registration_id.to_string(),
+            project_id: project_id.to_string(),
+            project_name: project_name.to_string(),
+            activity_id: activity_id.to_string(),
+            activity_name: activity_name.to_string(),
             start_time: start_time
                 .format(&format)
                 .context("Failed to format start_time")?,
             end_time: end_time
                 .format(&format)
                 .context("Failed to format end_time")?,
-            reg_day,
-            week_number,
-            user_note,
-            original_reg_day: None,
-            original_project_id,
-            original_activity_id,
+            user_note: user_note.to_string(),
+            round_to_minutes: self.round_to_minutes,
+        };
+
+        self.inner
+            .edit_time_entry(&body)
+            .await
+            .map(|_| ())
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Split `entry` in two at `split_at`. The entry keeps the part before
+    /// and a new entry is created for the rest.
+    pub async fn split_time_entry(
+        &mut self,
+        entry: &TimeEntry,
+        split_at: time::OffsetDateTime,
+    ) -> Result<()> {
+        let (Some(start_time), Some(end_time)) = (entry.start_time, entry.end_time) else {
+            anyhow::bail!("Entry has no start and end time to split");
+        };
+        let note = entry.note.as_deref().unwrap_or_default();
+
+        if let Some(dev) = &self.dev_backend {
+            dev.edit_entry(
+                &entry.registration_id,
+                &entry.project_id,
+                &entry.project_name,
+                &entry.activity_id,
+                &entry.activity_name,
+                start_time,
+                split_at,
+                note,
+            );
+            dev.create_entry(
+                &entry.project_id,
+                &entry.project_name,
+                &entry.activity_id,
+                &entry.activity_name,
+                split_at,
+                end_time,
+                note,
+            );
+            return Ok(());
+        }
+
+        let format = time::format_description::well_known::Rfc3339;
+        let body = SplitProjectRegistrationPayload {
+            project_registration_id: entry.registration_id.clone(),
+            project_id: entry.project_id.clone(),
+            project_name: entry.project_name.clone(),
+            activity_id: entry.activity_id.clone(),
+            activity_name: entry.activity_name.clone(),
+            start_time: start_time
+                .format(&format)
+                .context("Failed to format start_time")?,
+            end_time: end_time
+                .format(&format)
+                .context("Failed to format end_time")?,
+            user_note: note.to_string(),
+            split_time: split_at
+                .format(&format)
+                .context("Failed to format split_time")?,
+        };
+
+        self.inner
+            .split_time_entry(&body)
+            .await
+            .map(|_| ())
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Copy `entry` to `to_date` at the same time of day. Returns the copy.
+    pub async fn copy_time_entry(
+        &mut self,
+        entry: &TimeEntry,
+        to_date: time::Date,
+    ) -> Result<TimeEntry> {
+        if let Some(dev) = &self.dev_backend {
+            let (Some(start_time), Some(end_time), Some(date)) = (
+                entry.start_time,
+                entry.end_time,
+                crate::app::parse_date_str(&entry.date),
+            ) else {
+                anyhow::bail!("Entry has no start and end time to copy");
+            };
+            let shift = to_date - date;
+            let registration_id = dev.create_entry(
+                &entry.project_id,
+                &entry.project_name,
+                &entry.activity_id,
+                &entry.activity_name,
+                start_time + shift,
+                end_time + shift,
+                entry.note.as_deref().unwrap_or_default(),
+            );
+            return dev
+                .time_entries()
+                .into_iter()
+                .find(|copy| copy.registration_id == registration_id)
+                .context("Copied entry not found");
+        }
+
+        let body = CopyProjectRegistrationPayload {
+            date: entry.date.clone(),
+            project_registration_id: entry.registration_id.clone(),
+            to_date: format!(
+                "{:04}-{:02}-{:02}",
+                to_date.year(),
+                to_date.month() as u8,
+                to_date.day()
+            ),
+        };
+
+        self.inner
+            .copy_time_entry(&body)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Merge consecutive entries on one day into one registration.
+    pub async fn merge_time_entries(&mut self, entries: &[&TimeEntry]) -> Result<()> {
+        let Some(first) = entries.first() else {
+            return Ok(());
+        };
+
+        if let Some(dev) = &self.dev_backend {
+            dev.merge_entries(entries);
+            return Ok(());
+        }
+
+        let body = MergeProjectRegistrationsPayload {
+            date: first.date.clone(),
+            project_registration_ids: entries
+                .iter()
+                .map(|entry| entry.registration_id.clone())
+                .collect(),
+        };
+
+        self.inner
+            .merge_time_entries(&body)
+            .await
+            .map(|_| ())
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Delete several entries at once. Nothing is deleted if any of them
+    /// can't be.
+    pub async fn delete_time_entries(&mut self, entries: &[&TimeEntry]) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            for entry in entries {
+                dev.delete_entry(&entry.registration_id);
+            }
+            return Ok(());
+        }
+
+        self.batch_time_entries(entries, BatchRegistrationAction::Delete)
+            .await
+    }
+
+    /// Move several entries to `project` / `activity`, keeping their times
+    /// and notes.
+    pub async fn reassign_time_entries(
+        &mut self,
+        entries: &[&TimeEntry],
+        project: &Project,
+        activity: &Activity,
+    ) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            dev.reassign_entries(entries, project, activity);
+            return Ok(());
+        }
+
+        let action = BatchRegistrationAction::Reassign {
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            activity_id: activity.id.clone(),
+            activity_name: activity.name.clone(),
+        };
+        self.batch_time_entries(entries, action).await
+    }
+
+    async fn batch_time_entries(
+        &mut self,
+        entries: &[&TimeEntry],
+        action: BatchRegistrationAction,
+    ) -> Result<()> {
+        let (Some(from), Some(to)) = (
+            entries.iter().map(|entry| &entry.date).min(),
+            entries.iter().map(|entry| &entry.date).max(),
+        ) else {
+            return Ok(());
         };
 
-        self.send_without_body(
-            self.client
-                .put(self.endpoint("/time-tracking/time-entries")?)
-                .json(&body),
-            "PUT /time-tracking/time-entries",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        let body = BatchProjectRegistrationsPayload {
+            from: from.clone(),
+            to: to.clone(),
+            project_registration_ids: entries
+                .iter()
+                .map(|entry| entry.registration_id.clone())
+                .collect(),
+            action,
+        };
+
+        self.inner
+            .batch_time_entries(&body)
+            .await
+            .map(|_| ())
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn delete_time_entry(&mut self, registration_id: &str) -> Result<()> {
@@ -357,16 +676,10 @@ impl ApiClient {
             return Ok(());
         }
 
-        self.send_without_body(
-            self.client
-                .delete(self.endpoint("/time-tracking/time-entries")?)
-                .json(&DeleteEntryRequest {
-                    project_registration_id: registration_id,
-                }),
-            "DELETE /time-tracking/time-entries",
-            UNAUTH_RELOGIN,
-        )
-        .await
+        self.inner
+            .delete_time_entry(registration_id)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
     }
 
     pub async fn get_projects(&mut self) -> Result<Vec<Project>> {
@@ -374,40 +687,350 @@ impl ApiClient {
             return Ok(dev.projects());
         }
 
-        let dtos: Vec<ProjectDto> = self
-            .get_json(
-                self.client.get(self.endpoint("/time-tracking/projects")?),
-                "GET /time-tracking/projects",
-                UNAUTH_RELOGIN,
-            )
-            .await?;
+        let dtos = self
+            .inner
+            .get_projects()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
 
         let mut projects: Vec<Project> = dtos
             .into_iter()
             .map(|dto| Project {
                 id: dto.project_id,
                 name: dto.project_name,
+                budget: dto.budget_hours.map(|budget_hours| ProjectBudget {
+                    budget_hours,
+                    used_hours: dto.used_hours.unwrap_or_default(),
+                }),
             })
             .collect();
         projects.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(projects)
     }
 
+    pub async fn get_budget_statuses(&mut self) -> Result<Vec<BudgetStatus>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.budget_statuses());
+        }
+
+        self.inner
+            .get_budget_statuses()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_sprint_capacity(
+        &mut self,
+        board: &BoardProject,
+    ) -> Result<Vec<SprintCapacity>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.sprint_capacity());
+        }
+
+        self.inner
+            .get_sprint_capacity(&board.organization, &board.project, board.team.as_deref())
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_board(&mut self, board: &BoardProject) -> Result<Board> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.board());
+        }
+
+        self.inner
+            .get_board(&board.organization, &board.project, board.team.as_deref())
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Move a work item to another column of the `[board]` team's board.
+    pub async fn move_work_item(
+        &mut self,
+        board: &BoardProject,
+        work_item_id: &str,
+        target_column_name: &str,
+    ) -> Result<Option<WipLimitWarning>> {
+        if let Some(dev) = &self.dev_backend {
+            dev.move_work_item(work_item_id, target_column_name);
+            return Ok(None);
+        }
+
+        self.inner
+            .move_work_item(
+                &board.organization,
+                &board.project,
+                board.team.as_deref(),
+                work_item_id,
+                target_column_name,
+            )
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Project and activity the user tracks time on for the `[board]`
+    /// project's work items, `None` when none is set.
+    pub async fn get_work_item_time_mapping(
+        &mut self,
+        board: &BoardProject,
+    ) -> Result<Option<WorkItemTimeMapping>> {
+        let mappings = match &self.dev_backend {
+            Some(dev) => dev.time_mappings(),
+            None => self
+                .inner
+                .get_work_item_time_mappings()
+                .await
+                .map_err(|e| map_error(e, UNAUTH_RELOGIN))?,
+        };
+
+        Ok(mappings.into_iter().find(|mapping| {
+            mapping
+                .organization
+                .eq_ignore_ascii_case(&board.organization)
+                && mapping.project.eq_ignore_ascii_case(&board.project)
+        }))
+    }
+
+    pub async fn set_work_item_time_mapping(
+        &mut self,
+        board: &BoardProject,
+        project: &Project,
+        activity: &Activity,
+    ) -> Result<WorkItemTimeMapping> {
+        let payload = SetWorkItemTimeMappingPayload {
+            organization: board.organization.clone(),
+            project: board.project.clone(),
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            activity_id: activity.id.clone(),
+            activity_name: activity.name.clone(),
+        };
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.set_time_mapping(WorkItemTimeMapping {
+                id: 0,
+                organization: payload.organization,
+                project: payload.project,
+                project_id: payload.project_id,
+                project_name: payload.project_name,
+                activity_id: payload.activity_id,
+                activity_name: payload.activity_name,
+            }));
+        }
+
+        self.inner
+            .set_work_item_time_mapping(&payload)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_flex_history(
+        &mut self,
+        from: time::Date,
+        to: time::Date,
+    ) -> Result<Vec<FlexSnapshot>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.flex_history(to));
+        }
+
+        self.inner
+            .get_flex_history(from, to)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_missing_workdays(
+        &mut self,
+        from: time::Date,
+        to: time::Date,
+    ) -> Result<Vec<MissingWorkday>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.missing_workdays(from, to));
+        }
+
+        self.inner
+            .get_missing_workdays(from, to)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_favorites(&mut self) -> Result<Vec<Favorite>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.favorites());
+        }
+
+        self.inner
+            .get_favorites()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn add_favorite(
+        &mut self,
+        project: &Project,
+        activity: &Activity,
+    ) -> Result<Favorite> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.add_favorite(project, activity));
+        }
+
+        let body = AddFavoritePayload {
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            activity_id: activity.id.clone(),
+            activity_name: activity.name.clone(),
+        };
+        self.inner
+            .add_favorite(&body)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn delete_favorite(&mut self, id: i32) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            dev.delete_favorite(id);
+            return Ok(());
+        }
+
+        self.inner
+            .delete_favorite(id)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Entry templates saved on the server, as picker templates.
+    pub async fn get_templates(&mut self) -> Result<Vec<TemplateConfig>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.templates().into_iter().map(template_from_dto).collect());
+        }
+
+        let dtos = self
+            .inner
+            .get_templates()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
+        Ok(dtos.into_iter().map(template_from_dto).collect())
+    }
+
+    /// Save `entry` as a template named `name`, lasting as long as the entry.
+    pub async fn save_template(&mut self, name: &str, entry: &TimeEntry) -> Result<TemplateConfig> {
+        let minutes = (entry.hours * 60.0).round() as u32;
+        let body = SaveEntryTemplatePayload {
+            name: name.to_string(),
+            project_id: entry.project_id.clone(),
+            project_name: entry.project_name.clone(),
+            activity_id: entry.activity_id.clone(),
+            activity_name: entry.activity_name.clone(),
+            // A linked log file belongs to the entry, not to later ones.
+            note: entry
+                .note
+                .as_deref()
+                .map(crate::log_notes::strip_tag)
+                .unwrap_or_default()
+                .to_string(),
+            duration_minutes: (minutes > 0).then_some(minutes),
+        };
+        if let Some(dev) = &self.dev_backend {
+            return Ok(template_from_dto(dev.save_template(body)));
+        }
+
+        self.inner
+            .save_template(&body)
+            .await
+            .map(template_from_dto)
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn delete_template(&mut self, id: i32) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            dev.delete_template(id);
+            return Ok(());
+        }
+
+        self.inner
+            .delete_template(id)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Set the feed meetings are suggested from, returning the URL as saved.
+    pub async fn set_calendar_feed(&mut self, url: &str) -> Result<String> {
+        if self.dev_backend.is_some() {
+            return Ok(url.to_string());
+        }
+
+        self.inner
+            .set_calendar_feed(url)
+            .await
+            .map(|feed| feed.url)
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn delete_calendar_feed(&mut self) -> Result<()> {
+        if self.dev_backend.is_some() {
+            return Ok(());
+        }
+
+        self.inner
+            .delete_calendar_feed()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Meetings from the user's calendar feed this week with nothing logged
+    /// over them.
+    pub async fn get_calendar_suggestions(&mut self) -> Result<Vec<CalendarSuggestion>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.calendar_suggestions());
+        }
+
+        self.inner
+            .get_calendar_suggestions()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Stop suggesting a meeting occurrence.
+    pub async fn dismiss_calendar_suggestion(
+        &mut self,
+        suggestion: &CalendarSuggestion,
+    ) -> Result<()> {
+        if let Some(dev) = &self.dev_backend {
+            dev.dismiss_calendar_suggestion(suggestion);
+            return Ok(());
+        }
+
+        let body = DismissCalendarSuggestionPayload {
+            uid: suggestion.uid.clone(),
+            start_time: suggestion.start_time,
+        };
+        self.inner
+            .dismiss_calendar_suggestion(&body)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    /// Open Azure DevOps work items assigned to the user.
+    pub async fn list_assigned_work_items(&mut self) -> Result<Vec<WorkItem>> {
+        if let Some(dev) = &self.dev_backend {
+            return Ok(dev.assigned_work_items());
+        }
+
+        self.inner
+            .list_assigned_work_items()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
     pub async fn get_activities(&mut self, project_id: &str) -> Result<Vec<Activity>> {
         if let Some(dev) = &self.dev_backend {
             return Ok(dev.activities(project_id));
         }
 
-        let dtos: Vec<ActivityDto> = self
-            .get_json(
-                self.client.get(self.endpoint(&format!(
-                    "/time-tracking/projects/{}/activities",
-                    project_id
-                ))?),
-                "GET /time-tracking/projects/:id/activities",
-                UNAUTH_RELOGIN,
-            )
-            .await?;
+        let dtos = self
+            .inner
+            .get_activities(project_id)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
 
         let mut activities: Vec<Activity> = dtos
             .into_iter()
@@ -421,4 +1044,46 @@ impl ApiClient {
         activities.sort_by(|a, b| a.name.cmp(&b.name));
         Ok(activities)
     }
+
+    /// A clearer version of `note`, suggested by the server's language model.
+    pub async fn improve_note(&mut self, note: &str) -> Result<String> {
+        if self.dev_backend.is_some() {
+            anyhow::bail!("Note suggestions are not available in dev mode");
+        }
+
+        let response = self
+            .inner
+            .improve_note(&ImproveNotePayload {
+                note: note.to_string(),
+                work_item: None,
+            })
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))?;
+        Ok(response.suggestion)
+    }
+
+    pub async fn list_pull_requests(&mut self) -> Result<Vec<PullRequest>> {
+        if self.dev_backend.is_some() {
+            return Ok(Vec::new());
+        }
+
+        self.inner
+            .list_pull_requests()
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
+
+    pub async fn get_pull_request_changes(
+        &mut self,
+        pr: &PullRequest,
+    ) -> Result<Vec<PullRequestChange>> {
+        if self.dev_backend.is_some() {
+            return Ok(Vec::new());
+        }
+
+        self.inner
+            .get_pull_request_changes(pr)
+            .await
+            .map_err(|e| map_error(e, UNAUTH_RELOGIN))
+    }
 }
@@ -1,11 +1,21 @@
-use crate::types::{Activity, Project, TimeEntry};
+use crate::types::{
+    Activity, Board, BoardColumn, BoardWorkItem, BudgetStatus, CalendarSuggestion, Favorite,
+    FlexSnapshot, MissingWorkday, Project, ProjectBudget, SprintCapacity, TimeEntry, WorkItem,
+    WorkItemTimeMapping,
+};
 use std::sync::{Arc, Mutex};
 use time::macros::offset;
 use time::OffsetDateTime;
+use toki_types::{EntryTemplateResponse, SaveEntryTemplatePayload};
 
 #[derive(Debug, Clone)]
 pub struct DevBackend {
     store: Arc<Mutex<Vec<DevEntry>>>,
+    favorites: Arc<Mutex<Vec<Favorite>>>,
+    templates: Arc<Mutex<Vec<EntryTemplateResponse>>>,
+    calendar_suggestions: Arc<Mutex<Vec<CalendarSuggestion>>>,
+    board: Arc<Mutex<Board>>,
+    time_mappings: Arc<Mutex<Vec<WorkItemTimeMapping>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +34,17 @@ impl DevBackend {
     pub fn new() -> Self {
         Self {
             store: Arc::new(Mutex::new(seed_dev_history())),
+            favorites: Arc::new(Mutex::new(vec![Favorite {
+                id: 1,
+                project_id: "proj_3".to_string(),
+                project_name: "TUI Development".to_string(),
+                activity_id: "act_1_1".to_string(),
+                activity_name: "Backend Development".to_string(),
+            }])),
+            templates: Arc::new(Mutex::new(Vec::new())),
+            calendar_suggestions: Arc::new(Mutex::new(seed_calendar_suggestions())),
+            board: Arc::new(Mutex::new(seed_dev_board())),
+            time_mappings: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -50,6 +71,7 @@ impl DevBackend {
                 end_time: e.end_time,
                 week_number: e.start_time.iso_week(),
                 status: Default::default(),
+                work_item_id: None,
             })
             .collect()
     }
@@ -61,6 +83,96 @@ impl DevBackend {
             .retain(|entry| entry.registration_id != registration_id);
     }
 
+    /// Move entries to another project and activity.
+    pub fn reassign_entries(&self, entries: &[&TimeEntry], project: &Project, activity: &Activity) {
+        let mut store = self.store.lock().expect("dev store lock poisoned");
+        for stored in store.iter_mut().filter(|stored| {
+            entries
+                .iter()
+                .any(|entry| entry.registration_id == stored.registration_id)
+        }) {
+            stored.project_id = Some(project.id.clone());
+            stored.project_name = Some(project.name.clone());
+            stored.activity_id = Some(activity.id.clone());
+            stored.activity_name = Some(activity.name.clone());
+        }
+    }
+
+    /// Merge entries into the earliest one, lasting as long as all of them
+    /// together, like the server does.
+    pub fn merge_entries(&self, entries: &[&TimeEntry]) {
+        let mut entries: Vec<&TimeEntry> = entries
+            .iter()
+            .copied()
+            .filter(|entry| entry.start_time.is_some() && entry.end_time.is_some())
+            .collect();
+        entries.sort_by_key(|entry| entry.start_time);
+        let Some(first) = entries.first() else {
+            return;
+        };
+
+        let duration: time::Duration = entries
+            .iter()
+            .filter_map(|entry| Some(entry.end_time? - entry.start_time?))
+            .sum();
+        let mut notes: Vec<&str> = Vec::new();
+        for entry in &entries {
+            let note = entry.note.as_deref().unwrap_or_default().trim();
+            if !note.is_empty() && !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+
+        let mut store = self.store.lock().expect("dev store lock poisoned");
+        if let Some(merged) = store
+            .iter_mut()
+            .find(|entry| entry.registration_id == first.registration_id)
+        {
+            merged.end_time = Some(merged.start_time + duration);
+            merged.note = Some(notes.join("; "));
+        }
+        store.retain(|entry| {
+            entry.registration_id == first.registration_id
+                || !entries
+                    .iter()
+                    .any(|merged| merged.registration_id == entry.registration_id)
+        });
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_entry(
+        &self,
+        project_id: &str,
+        project_name: &str,
+        activity_id: &str,
+        activity_name: &str,
+        start_time: OffsetDateTime,
+        end_time: OffsetDateTime,
+        user_note: &str,
+    ) -> String {
+        let mut store = self.store.lock().expect("dev store lock poisoned");
+        // Deleted entries leave gaps, so count on from the highest id.
+        let next_id = store
+            .iter()
+            .filter_map(|entry| entry.registration_id.strip_prefix("dev-reg-"))
+            .filter_map(|id| id.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let registration_id = format!("dev-reg-{}", next_id);
+        store.push(DevEntry {
+            registration_id: registration_id.clone(),
+            start_time,
+            end_time: Some(end_time),
+            project_id: Some(project_id.to_string()),
+            project_name: Some(project_name.to_string()),
+            activity_id: Some(activity_id.to_string()),
+            activity_name: Some(activity_name.to_string()),
+            note: Some(user_note.to_string()),
+        });
+        registration_id
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn edit_entry(
         &self,
@@ -95,14 +207,23 @@ impl DevBackend {
             Project {
                 id: "proj_1".to_string(),
                 name: "Nordic Crisis Manager".to_string(),
+                budget: Some(ProjectBudget {
+                    budget_hours: 400.0,
+                    used_hours: 372.5,
+                }),
             },
             Project {
                 id: "proj_2".to_string(),
                 name: "Azure DevOps Integration".to_string(),
+                budget: Some(ProjectBudget {
+                    budget_hours: 120.0,
+                    used_hours: 46.0,
+                }),
             },
             Project {
                 id: "proj_3".to_string(),
                 name: "TUI Development".to_string(),
+                budget: None,
             },
         ]
     }
@@ -122,9 +243,284 @@ impl DevBackend {
         ]
     }
 
+    pub fn favorites(&self) -> Vec<Favorite> {
+        self.favorites
+            .lock()
+            .expect("dev favorites lock poisoned")
+            .clone()
+    }
+
+    pub fn add_favorite(&self, project: &Project, activity: &Activity) -> Favorite {
+        let mut favorites = self.favorites.lock().expect("dev favorites lock poisoned");
+        if let Some(existing) = favorites
+            .iter()
+            .find(|f| f.project_id == project.id && f.activity_id == activity.id)
+        {
+            return existing.clone();
+        }
+        let favorite = Favorite {
+            id: favorites.iter().map(|f| f.id).max().unwrap_or(0) + 1,
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            activity_id: activity.id.clone(),
+            activity_name: activity.name.clone(),
+        };
+        favorites.push(favorite.clone());
+        favorite
+    }
+
+    pub fn delete_favorite(&self, id: i32) {
+        self.favorites
+            .lock()
+            .expect("dev favorites lock poisoned")
+            .retain(|favorite| favorite.id != id);
+    }
+
+    pub fn templates(&self) -> Vec<EntryTemplateResponse> {
+        self.templates
+            .lock()
+            .expect("dev templates lock poisoned")
+            .clone()
+    }
+
+    /// Save a template, replacing the one with the same name like the server.
+    pub fn save_template(&self, body: SaveEntryTemplatePayload) -> EntryTemplateResponse {
+        let mut templates = self.templates.lock().expect("dev templates lock poisoned");
+        let id = templates
+            .iter()
+            .find(|t| t.name == body.name)
+            .map(|t| t.id)
+            .unwrap_or_else(|| templates.iter().map(|t| t.id).max().unwrap_or(0) + 1);
+        templates.retain(|t| t.id != id);
+        let template = EntryTemplateResponse {
+            id,
+            name: body.name,
+            project_id: body.project_id,
+            project_name: body.project_name,
+            activity_id: body.activity_id,
+            activity_name: body.activity_name,
+            note: body.note,
+            duration_minutes: body.duration_minutes,
+        };
+        templates.push(template.clone());
+        template
+    }
+
+    pub fn delete_template(&self, id: i32) {
+        self.templates
+            .lock()
+            .expect("dev templates lock poisoned")
+            .retain(|template| template.id != id);
+    }
+
+    /// Seeded meetings without an entry overlapping them, like the server.
+    pub fn calendar_suggestions(&self) -> Vec<CalendarSuggestion> {
+        let entries = self.store.lock().expect("dev store lock poisoned").clone();
+        self.calendar_suggestions
+            .lock()
+            .expect("dev calendar lock poisoned")
+            .iter()
+            .filter(|suggestion| {
+                !entries.iter().any(|entry| {
+                    entry.start_time < suggestion.end_time
+                        && entry
+                            .end_time
+                            .is_some_and(|end| end > suggestion.start_time)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn dismiss_calendar_suggestion(&self, dismissed: &CalendarSuggestion) {
+        self.calendar_suggestions
+            .lock()
+            .expect("dev calendar lock poisoned")
+            .retain(|suggestion| {
+                suggestion.uid != dismissed.uid || suggestion.start_time != dismissed.start_time
+            });
+    }
+
+    pub fn assigned_work_items(&self) -> Vec<WorkItem> {
+        [
+            (
+                "4711",
+                "task",
+                "Active",
+                "Scrollable lists in the history view",
+            ),
+            ("4712", "bug", "New", "Timer drifts after sleep"),
+            ("4720", "userStory", "Active", "Log time from the board"),
+        ]
+        .into_iter()
+        .map(|(id, category, state, title)| WorkItem {
+            organization: "dev-org".to_string(),
+            project: "Toki".to_string(),
+            id: id.to_string(),
+            title: title.to_string(),
+            category: category.to_string(),
+            state_name: state.to_string(),
+            url: format!("https://dev.azure.com/dev-org/Toki/_workitems/edit/{id}"),
+        })
+        .collect()
+    }
+
+    pub fn board(&self) -> Board {
+        self.board.lock().expect("dev board lock poisoned").clone()
+    }
+
+    pub fn move_work_item(&self, work_item_id: &str, target_column_name: &str) {
+        let mut board = self.board.lock().expect("dev board lock poisoned");
+        let Some(column) = board
+            .columns
+            .iter()
+            .find(|column| column.name == target_column_name)
+            .cloned()
+        else {
+            return;
+        };
+        if let Some(item) = board.items.iter_mut().find(|item| item.id == work_item_id) {
+            item.board_column_id = Some(column.id);
+            item.board_column_name = Some(column.name);
+        }
+    }
+
+    pub fn time_mappings(&self) -> Vec<WorkItemTimeMapping> {
+        self.time_mappings
+            .lock()
+            .expect("dev time mappings lock poisoned")
+            .clone()
+    }
+
+    pub fn set_time_mapping(&self, mut mapping: WorkItemTimeMapping) -> WorkItemTimeMapping {
+        let mut mappings = self
+            .time_mappings
+            .lock()
+            .expect("dev time mappings lock poisoned");
+        mappings.retain(|existing| {
+            existing.organization != mapping.organization || existing.project != mapping.project
+        });
+        mapping.id = mappings
+            .iter()
+            .map(|existing| existing.id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        mappings.push(mapping.clone());
+        mapping
+    }
+
+    /// A weekly budget on the first project, counting this week's entries.
+    pub fn budget_statuses(&self) -> Vec<BudgetStatus> {
+        let today = crate::time_utils::local_today();
+        let from =
+            today - time::Duration::days(i64::from(today.weekday().number_days_from_monday()));
+        let to = from + time::Duration::days(6);
+        let (from, to) = (from.to_string(), to.to_string());
+        let used_hours = self
+            .time_entries()
+            .iter()
+            .filter(|entry| entry.project_id == "proj_1")
+            .filter(|entry| entry.date >= from && entry.date <= to)
+            .map(|entry| entry.hours)
+            .sum::<f64>();
+        let target_hours = 8.0;
+
+        vec![BudgetStatus {
+            budget: toki_types::ProjectBudgetResponse {
+                id: 1,
+                project_id: "proj_1".to_string(),
+                project_name: "Nordic Crisis Manager".to_string(),
+                period: "week".to_string(),
+                target_hours,
+            },
+            from,
+            to,
+            used_hours,
+            nearly_used: used_hours / target_hours >= crate::types::BUDGET_WARNING_RATIO,
+        }]
+    }
+
+    /// Three two-week sprints, the newest ending this week with its entries
+    /// logged against 6h a day.
+    pub fn sprint_capacity(&self) -> Vec<SprintCapacity> {
+        let today = crate::time_utils::local_today();
+        let friday =
+            today + time::Duration::days(4 - i64::from(today.weekday().number_days_from_monday()));
+
+        [
+            (42, Some(60.0), None),
+            (41, Some(54.0), Some(57.5)),
+            (40, None, Some(62.0)),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (number, capacity_hours, logged_hours))| {
+            let finish = friday - time::Duration::weeks(2 * i as i64);
+            let (start, finish) = (
+                (finish - time::Duration::days(11)).to_string(),
+                finish.to_string(),
+            );
+            let logged_hours = logged_hours.unwrap_or_else(|| {
+                self.time_entries()
+                    .iter()
+                    .filter(|entry| entry.date >= start && entry.date <= finish)
+                    .map(|entry| entry.hours)
+                    .sum()
+            });
+            SprintCapacity {
+                iteration_name: format!("Sprint {number}"),
+                iteration_path: format!("Toki\\Sprint {number}"),
+                start_date: start,
+                finish_date: finish,
+                capacity_hours,
+                logged_hours,
+            }
+        })
+        .collect()
+    }
+
+    /// A weekly flex balance drifting around zero over the last 8 weeks.
+    pub fn flex_history(&self, to: time::Date) -> Vec<FlexSnapshot> {
+        [1.5, 3.0, 2.0, -0.5, -2.0, 0.5, 1.0, 2.5]
+            .into_iter()
+            .enumerate()
+            .map(|(i, flex_hours)| FlexSnapshot {
+                date: (to - time::Duration::weeks(7 - i as i64)).to_string(),
+                flex_hours,
+            })
+            .collect()
+    }
+
+    /// Weekdays between `from` and `to` without entries, scheduled for 8h.
+    pub fn missing_workdays(&self, from: time::Date, to: time::Date) -> Vec<MissingWorkday> {
+        let logged: Vec<String> = self
+            .time_entries()
+            .into_iter()
+            .map(|entry| entry.date)
+            .collect();
+        std::iter::successors(Some(from), |date| date.next_day())
+            .take_while(|date| *date <= to)
+            .filter(|date| {
+                !matches!(
+                    date.weekday(),
+                    time::Weekday::Saturday | time::Weekday::Sunday
+                )
+            })
+            .map(|date| date.to_string())
+            .filter(|date| !logged.contains(date))
+            .map(|date| MissingWorkday { date, hours: 8.0 })
+            .collect()
+    }
+
     pub fn time_info(&self) -> crate::types::TimeInfo {
         crate::types::TimeInfo {
+            worked_hours: 0.0,
             scheduled_hours: 32.0,
+            remaining_hours: 32.0,
+            absence_hours: 0.0,
+            covered_hours: 0.0,
+            period_flex_hours: 0.0,
         }
     }
 }
@@ -207,3 +603,122 @@ fn seed_dev_history() -> Vec<DevEntry> {
         ),
     ]
 }
+
+/// Meetings from yesterday, one logged before and one new.
+fn seed_calendar_suggestions() -> Vec<CalendarSuggestion> {
+    let yesterday = OffsetDateTime::now_utc().to_offset(offset!(+1)).date() - time::Duration::DAY;
+    let at = |hour: u8, minute: u8| {
+        OffsetDateTime::new_in_offset(
+            yesterday,
+            time::Time::from_hms(hour, minute, 0).expect("valid time"),
+            offset!(+1),
+        )
+    };
+
+    vec![
+        CalendarSuggestion {
+            uid: "dev-standup".to_string(),
+            title: "Daily standup".to_string(),
+            start_time: at(9, 15),
+            end_time: at(9, 30),
+            project_id: Some("proj_1".to_string()),
+            project_name: Some("Nordic Crisis Manager".to_string()),
+            activity_id: Some("act_1_4".to_string()),
+            activity_name: Some("Code Review".to_string()),
+        },
+        CalendarSuggestion {
+            uid: "dev-architecture".to_string(),
+            title: "Architecture sync".to_string(),
+            start_time: at(13, 0),
+            end_time: at(13, 45),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+        },
+    ]
+}
+
+/// A small taskboard with the assigned work items and a few others.
+fn seed_dev_board() -> Board {
+    let columns = [
+        ("todo", "To Do"),
+        ("doing", "Doing"),
+        ("review", "Review"),
+        ("done", "Done"),
+    ];
+    let items = [
+        (
+            "4712",
+            "bug",
+            "New",
+            "todo",
+            "Timer drifts after sleep",
+            Some("Dev User"),
+        ),
+        ("4718", "task", "New", "todo", "Export history as CSV", None),
+        (
+            "4711",
+            "task",
+            "Active",
+            "doing",
+            "Scrollable lists in the history view",
+            Some("Dev User"),
+        ),
+        (
+            "4720",
+            "userStory",
+            "Active",
+            "doing",
+            "Log time from the board",
+            Some("Dev User"),
+        ),
+        (
+            "4705",
+            "task",
+            "Active",
+            "review",
+            "Round entry times",
+            Some("Ada Lovelace"),
+        ),
+        (
+            "4701",
+            "bug",
+            "Closed",
+            "done",
+            "Crash on empty notes",
+            Some("Ada Lovelace"),
+        ),
+    ];
+
+    Board {
+        columns: columns
+            .into_iter()
+            .enumerate()
+            .map(|(order, (id, name))| BoardColumn {
+                id: id.to_string(),
+                name: name.to_string(),
+                order: order as i32,
+            })
+            .collect(),
+        items: items
+            .into_iter()
+            .map(
+                |(id, category, state, column, title, assignee)| BoardWorkItem {
+                    id: id.to_string(),
+                    title: title.to_string(),
+                    board_state: "todo".to_string(),
+                    board_column_id: Some(column.to_string()),
+                    board_column_name: None,
+                    category: category.to_string(),
+                    state_name: state.to_string(),
+                    priority: Some(2),
+                    assigned_to: assignee.map(|name| toki_client::WorkItemPerson {
+                        display_name: name.to_string(),
+                    }),
+                    url: format!("https://dev.azure.com/dev-org/Toki/_workitems/edit/{id}"),
+                },
+            )
+            .collect(),
+    }
+}
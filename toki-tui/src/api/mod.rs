@@ -1,6 +1,4 @@
 mod client;
 mod dev_backend;
-mod dto;
 
-pub use client::ApiClient;
-pub(crate) use dto::SaveTimerRequest;
+pub use client::{is_unreachable, ApiClient};
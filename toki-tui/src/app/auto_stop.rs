@@ -0,0 +1,69 @@
+use super::*;
+use crate::types::AutoStoppedTimer;
+
+impl App {
+    /// Tell the user the server stopped a timer they left running, e.g.
+    /// "Timer left running was saved at 23:59 on 2026-10-15: Toki / Development (15h 59m)".
+    pub fn show_timer_auto_stop(&mut self, stopped: &AutoStoppedTimer) {
+        let outcome = match stopped.action.as_str() {
+            "discard" => "discarded",
+            _ => "saved",
+        };
+        let stop = to_local_time(stopped.stop_time);
+        let ran = stopped.stop_time - stopped.start_time;
+        let what = match (&stopped.project_name, &stopped.activity_name) {
+            (Some(project), Some(activity)) => format!("{} / {}", project, activity),
+            (Some(project), None) => project.clone(),
+            _ => "no project".to_string(),
+        };
+        self.set_status(format!(
+            "Timer left running was {} at {:02}:{:02} on {}: {} ({}h {:02}m)",
+            outcome,
+            stop.hour(),
+            stop.minute(),
+            stop.date(),
+            what,
+            ran.whole_hours(),
+            ran.whole_minutes() % 60
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+    use crate::time_utils::local_date_time;
+    use time::macros::{date, time};
+
+    fn stopped(action: &str, project: Option<&str>) -> AutoStoppedTimer {
+        AutoStoppedTimer {
+            action: action.to_string(),
+            start_time: local_date_time(date!(2026 - 10 - 15), time!(08:00)),
+            stop_time: local_date_time(date!(2026 - 10 - 15), time!(23:59)),
+            project_name: project.map(str::to_string),
+            activity_name: project.map(|_| "Development".to_string()),
+            note: String::new(),
+            registration_id: None,
+        }
+    }
+
+    #[test]
+    fn reports_what_happened_to_the_timer() {
+        let mut app = test_app();
+
+        app.show_timer_auto_stop(&stopped("save", Some("Toki")));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some(
+                "Timer left running was saved at 23:59 on 2026-10-15: Toki / Development (15h 59m)"
+            )
+        );
+
+        app.show_timer_auto_stop(&stopped("discard", None));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Timer left running was discarded at 23:59 on 2026-10-15: no project (15h 59m)")
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use super::*;
+use crate::types::{Board, BoardColumn, BoardWorkItem};
+
+/// A board column and the items in it, in the order the API returned them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardLane {
+    pub column: BoardColumn,
+    pub items: Vec<BoardWorkItem>,
+}
+
+/// Split a board into lanes ordered like its columns.
+///
+/// Items are placed by column id, then by column name ignoring case, then by
+/// their board state; items matching no column are left out, like on the web
+/// board.
+fn board_lanes(board: Board) -> Vec<BoardLane> {
+    let mut columns = board.columns;
+    columns.sort_by_key(|column| column.order);
+    let mut lanes: Vec<BoardLane> = columns
+        .into_iter()
+        .map(|column| BoardLane {
+            column,
+            items: Vec::new(),
+        })
+        .collect();
+
+    for item in board.items {
+        let lane = lanes
+            .iter()
+            .position(|lane| item.board_column_id.as_deref() == Some(lane.column.id.as_str()))
+            .or_else(|| {
+                let name = item.board_column_name.as_deref()?.trim();
+                lanes
+                    .iter()
+                    .position(|lane| lane.column.name.trim().eq_ignore_ascii_case(name))
+            })
+            .or_else(|| {
+                lanes
+                    .iter()
+                    .position(|lane| lane.column.id == item.board_state)
+            });
+        if let Some(lane) = lane {
+            lanes[lane].items.push(item);
+        }
+    }
+    lanes
+}
+
+impl App {
+    /// Replace the board, keeping the selection on the same item when it is
+    /// still there.
+    pub fn set_board(&mut self, board: Board) {
+        let selected_id = self.selected_board_item().map(|item| item.id.clone());
+        self.board_lanes = board_lanes(board);
+        self.board_move_target = None;
+
+        let position = selected_id.and_then(|id| {
+            self.board_lanes
+                .iter()
+                .enumerate()
+                .find_map(|(column, lane)| {
+                    let item = lane.items.iter().position(|item| item.id == id)?;
+                    Some((column, item))
+                })
+        });
+        match position {
+            Some((column, item)) => {
+                self.board_column_index = column;
+                self.board_item_index = item;
+            }
+            None => {
+                self.board_column_index = self
+                    .board_column_index
+                    .min(self.board_lanes.len().saturating_sub(1));
+                self.clamp_board_item_index();
+            }
+        }
+    }
+
+    pub fn selected_board_lane(&self) -> Option<&BoardLane> {
+        self.board_lanes.get(self.board_column_index)
+    }
+
+    pub fn selected_board_item(&self) -> Option<&BoardWorkItem> {
+        self.selected_board_lane()?.items.get(self.board_item_index)
+    }
+
+    pub fn board_focus_left(&mut self) {
+        self.board_column_index = self.board_column_index.saturating_sub(1);
+        self.clamp_board_item_index();
+    }
+
+    pub fn board_focus_right(&mut self) {
+        if self.board_column_index + 1 < self.board_lanes.len() {
+            self.board_column_index += 1;
+        }
+        self.clamp_board_item_index();
+    }
+
+    pub fn board_focus_down(&mut self) {
+        let len = self
+            .selected_board_lane()
+            .map_or(0, |lane| lane.items.len());
+        if self.board_item_index + 1 < len {
+            self.board_item_index += 1;
+        }
+    }
+
+    pub fn board_focus_up(&mut self) {
+        self.board_item_index = self.board_item_index.saturating_sub(1);
+    }
+
+    fn clamp_board_item_index(&mut self) {
+        let len = self
+            .selected_board_lane()
+            .map_or(0, |lane| lane.items.len());
+        self.board_item_index = self.board_item_index.min(len.saturating_sub(1));
+    }
+
+    /// Ask which column to move the selected item to, starting at its own.
+    pub fn open_board_move_prompt(&mut self) {
+        if self.selected_board_item().is_some() {
+            self.board_move_target = Some(self.board_column_index);
+        }
+    }
+
+    pub fn board_move_target_down(&mut self) {
+        if let Some(target) = self.board_move_target.as_mut() {
+            if *target + 1 < self.board_lanes.len() {
+                *target += 1;
+            }
+        }
+    }
+
+    pub fn board_move_target_up(&mut self) {
+        if let Some(target) = self.board_move_target.as_mut() {
+            *target = target.saturating_sub(1);
+        }
+    }
+
+    pub fn cancel_board_move(&mut self) {
+        self.board_move_target = None;
+    }
+
+    /// Close the move prompt and move the selected item to the picked column
+    /// locally, keeping it selected. Returns the item id and column name to
+    /// send to the server, `None` when the item stays where it is.
+    pub fn confirm_board_move(&mut self) -> Option<(String, String)> {
+        let target = self.board_move_target.take()?;
+        if target == self.board_column_index || target >= self.board_lanes.len() {
+            return None;
+        }
+        let lane = self.board_lanes.get_mut(self.board_column_index)?;
+        if self.board_item_index >= lane.items.len() {
+            return None;
+        }
+
+        let mut item = lane.items.remove(self.board_item_index);
+        let column = &self.board_lanes[target].column;
+        item.board_column_id = Some(column.id.clone());
+        item.board_column_name = Some(column.name.clone());
+        let moved = (item.id.clone(), column.name.clone());
+
+        self.board_lanes[target].items.push(item);
+        self.board_column_index = target;
+        self.board_item_index = self.board_lanes[target].items.len() - 1;
+        Some(moved)
+    }
+    /// Set up the timer for the selected item: `#1234 Title` as the note,
+    /// linked to the item, on the board's mapped project and activity. The
+    /// selected project is kept when nothing is mapped. Returns the note.
+    pub fn prefill_timer_from_board_item(&mut self) -> Option<String> {
+        let item = self.selected_board_item()?.clone();
+        if let Some(mapping) = &self.board_time_mapping {
+            self.selected_project = Some(crate::types::Project {
+                id: mapping.project_id.clone(),
+                name: mapping.project_name.clone(),
+                budget: None,
+            });
+            self.selected_activity = Some(crate::types::Activity {
+                id: mapping.activity_id.clone(),
+                name: mapping.activity_name.clone(),
+                project_id: mapping.project_id.clone(),
+            });
+        }
+
+        let reference = item.reference();
+        self.set_note_from_raw(&reference);
+        self.description_is_default = false;
+        self.description_work_item_id = Some(item.id);
+        Some(reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{activity, project, test_app};
+    use crate::types::WorkItemTimeMapping;
+
+    fn column(id: &str, name: &str, order: i32) -> BoardColumn {
+        BoardColumn {
+            id: id.to_string(),
+            name: name.to_string(),
+            order,
+        }
+    }
+
+    fn item(id: &str, column_id: Option<&str>, column_name: Option<&str>) -> BoardWorkItem {
+        BoardWorkItem {
+            id: id.to_string(),
+            title: format!("Item {id}"),
+            board_state: "todo".to_string(),
+            board_column_id: column_id.map(str::to_string),
+            board_column_name: column_name.map(str::to_string),
+            category: "task".to_string(),
+            state_name: "New".to_string(),
+            priority: None,
+            assigned_to: None,
+            url: format!("https://dev.azure.com/org/Toki/_workitems/edit/{id}"),
+        }
+    }
+
+    fn board() -> Board {
+        let mut archived = item("4", Some("gone"), Some("Archived"));
+        archived.board_state = "done".to_string();
+        Board {
+            columns: vec![
+                column("c2", "Doing", 1),
+                column("todo", "To Do", 0),
+                column("c3", "Done", 2),
+            ],
+            items: vec![
+                item("1", Some("c2"), None),
+                item("2", None, Some(" doing ")),
+                item("3", None, None),
+                archived,
+            ],
+        }
+    }
+
+    #[test]
+    fn lanes_follow_column_order_and_place_items_by_id_name_or_state() {
+        let lanes = board_lanes(board());
+
+        let names: Vec<&str> = lanes.iter().map(|lane| lane.column.name.as_str()).collect();
+        assert_eq!(names, ["To Do", "Doing", "Done"]);
+        let ids = |lane: &BoardLane| lane.items.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&lanes[0]), ["3"]);
+        assert_eq!(ids(&lanes[1]), ["1", "2"]);
+        assert!(lanes[2].items.is_empty());
+    }
+
+    #[test]
+    fn focus_moves_between_columns_and_stays_on_items() {
+        let mut app = test_app();
+        app.set_board(board());
+
+        app.board_focus_right();
+        app.board_focus_down();
+        assert_eq!(app.selected_board_item().map(|i| i.id.as_str()), Some("2"));
+
+        // The empty "Done" column has nothing to select.
+        app.board_focus_right();
+        assert_eq!(app.board_item_index, 0);
+        assert!(app.selected_board_item().is_none());
+        app.board_focus_right();
+        assert_eq!(app.board_column_index, 2);
+
+        app.board_focus_left();
+        app.board_focus_left();
+        app.board_focus_left();
+        assert_eq!(app.selected_board_item().map(|i| i.id.as_str()), Some("3"));
+    }
+
+    #[test]
+    fn confirming_a_move_moves_the_item_locally() {
+        let mut app = test_app();
+        app.set_board(board());
+        app.board_focus_right();
+
+        app.open_board_move_prompt();
+        app.board_move_target_down();
+        app.board_move_target_down();
+        assert_eq!(
+            app.confirm_board_move(),
+            Some(("1".to_string(), "Done".to_string()))
+        );
+        assert_eq!(app.board_move_target, None);
+        assert_eq!(app.board_column_index, 2);
+        let moved = app.selected_board_item().unwrap();
+        assert_eq!(moved.id, "1");
+        assert_eq!(moved.board_column_name.as_deref(), Some("Done"));
+
+        // Picking the item's own column sends nothing.
+        app.open_board_move_prompt();
+        assert_eq!(app.confirm_board_move(), None);
+    }
+
+    #[test]
+    fn reloading_the_board_keeps_the_selected_item() {
+        let mut app = test_app();
+        app.set_board(board());
+        app.board_focus_right();
+        app.board_focus_down();
+
+        let mut reloaded = board();
+        reloaded.items[1].board_column_name = Some("Done".to_string());
+        app.set_board(reloaded);
+
+        assert_eq!(app.board_column_index, 2);
+        assert_eq!(app.selected_board_item().map(|i| i.id.as_str()), Some("2"));
+    }
+    #[test]
+    fn timer_from_a_board_item_uses_the_mapped_project() {
+        let mut app = test_app();
+        app.set_board(board());
+        app.selected_project = Some(project("proj-1", "Project One"));
+        app.selected_activity = Some(activity("act-1", "proj-1", "Activity One"));
+
+        // Without a mapping the selected project stays.
+        assert_eq!(
+            app.prefill_timer_from_board_item().as_deref(),
+            Some("#3 Item 3")
+        );
+        assert_eq!(app.description_input.value, "#3 Item 3");
+        assert_eq!(app.description_work_item_id.as_deref(), Some("3"));
+        assert_eq!(app.selected_project.as_ref().unwrap().id, "proj-1");
+
+        app.board_time_mapping = Some(WorkItemTimeMapping {
+            id: 1,
+            organization: "org".to_string(),
+            project: "Toki".to_string(),
+            project_id: "proj-2".to_string(),
+            project_name: "Project Two".to_string(),
+            activity_id: "act-2".to_string(),
+            activity_name: "Development".to_string(),
+        });
+        app.board_focus_right();
+        app.prefill_timer_from_board_item();
+        assert_eq!(app.description_input.value, "#1 Item 1");
+        assert_eq!(app.selected_project.as_ref().unwrap().name, "Project Two");
+        assert_eq!(app.selected_activity.as_ref().unwrap().id, "act-2");
+    }
+}
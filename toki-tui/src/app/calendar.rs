@@ -0,0 +1,155 @@
+use super::week_checklist::format_date;
+use super::*;
+use time::{Date, Weekday};
+
+/// How full a day in the calendar is, used to pick its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayFill {
+    /// Weekends and days that haven't happened yet, with nothing logged.
+    Idle,
+    /// A past working day with nothing logged.
+    Missing,
+    /// Something logged, but less than a full day.
+    Partial,
+    /// At least a full day logged.
+    Full,
+}
+
+/// One day in the calendar month.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarDay {
+    pub date: Date,
+    pub hours: f64,
+}
+
+impl CalendarDay {
+    pub fn fill(&self, today: Date, full_day_hours: f64) -> DayFill {
+        let is_weekend = matches!(self.date.weekday(), Weekday::Saturday | Weekday::Sunday);
+        if self.hours <= 0.0 {
+            if is_weekend || self.date > today {
+                DayFill::Idle
+            } else {
+                DayFill::Missing
+            }
+        } else if self.hours + f64::EPSILON < full_day_hours {
+            DayFill::Partial
+        } else {
+            DayFill::Full
+        }
+    }
+}
+
+impl App {
+    /// Show `month` in the calendar, with the entries logged during it.
+    pub fn set_calendar_month(&mut self, month: Date, entries: Vec<TimeEntry>) {
+        self.calendar_month = first_of_month(month);
+        self.calendar_entries = entries;
+    }
+
+    /// Every day of the calendar month with the hours logged on it.
+    pub fn calendar_days(&self) -> Vec<CalendarDay> {
+        let first = self.calendar_month;
+        let days = first.month().length(first.year());
+        (0..days)
+            .map(|offset| {
+                let date = first + time::Duration::days(offset as i64);
+                let key = format_date(date);
+                let hours = self
+                    .calendar_entries
+                    .iter()
+                    .filter(|entry| entry.date == key)
+                    .map(|entry| entry.hours)
+                    .sum();
+                CalendarDay { date, hours }
+            })
+            .collect()
+    }
+
+    /// Hours a working day needs to count as full, based on the scheduled
+    /// hours for a five-day week.
+    pub fn full_day_hours(&self) -> f64 {
+        self.scheduled_hours_per_week / 5.0
+    }
+}
+
+pub fn first_of_month(date: Date) -> Date {
+    date.replace_day(1).expect("every month has a first day")
+}
+
+/// The first day of the month `months` months after the one `date` is in.
+pub fn shift_month(date: Date, months: i32) -> Date {
+    let index = date.year() * 12 + date.month() as i32 - 1 + months;
+    let month = time::Month::try_from((index.rem_euclid(12) + 1) as u8).expect("valid month");
+    Date::from_calendar_date(index.div_euclid(12), month, 1).expect("valid date")
+}
+
+/// The last day of the month `date` is in.
+pub fn last_of_month(date: Date) -> Date {
+    date.replace_day(date.month().length(date.year()))
+        .expect("valid last day of month")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+    use time::macros::date;
+
+    #[test]
+    fn calendar_days_sum_hours_per_day() {
+        let mut app = test_app();
+        let entry = |id: &str, date: &str, hours: f64| {
+            time_entry(
+                id,
+                "p1",
+                "Toki",
+                "a1",
+                "Development",
+                date,
+                hours,
+                None,
+                None,
+                None,
+            )
+        };
+        app.set_calendar_month(
+            date!(2026 - 02 - 14),
+            vec![
+                entry("1", "2026-02-02", 3.0),
+                entry("2", "2026-02-02", 4.5),
+                entry("3", "2026-02-27", 8.0),
+            ],
+        );
+
+        let days = app.calendar_days();
+
+        assert_eq!(days.len(), 28);
+        assert_eq!(days[0].date, date!(2026 - 02 - 01));
+        assert_eq!(days[1].hours, 7.5);
+        assert_eq!(days[26].hours, 8.0);
+        assert_eq!(days[27].hours, 0.0);
+    }
+
+    #[test]
+    fn shift_month_wraps_years() {
+        assert_eq!(
+            shift_month(date!(2026 - 01 - 31), -1),
+            date!(2025 - 12 - 01)
+        );
+        assert_eq!(shift_month(date!(2026 - 12 - 15), 1), date!(2027 - 01 - 01));
+        assert_eq!(shift_month(date!(2026 - 03 - 10), 0), date!(2026 - 03 - 01));
+        assert_eq!(last_of_month(date!(2028 - 02 - 03)), date!(2028 - 02 - 29));
+    }
+
+    #[test]
+    fn fill_flags_only_past_working_days_as_missing() {
+        let today = date!(2026 - 03 - 11);
+        let day = |date: Date, hours: f64| CalendarDay { date, hours }.fill(today, 8.0);
+
+        assert_eq!(day(date!(2026 - 03 - 10), 0.0), DayFill::Missing);
+        assert_eq!(day(date!(2026 - 03 - 08), 0.0), DayFill::Idle);
+        assert_eq!(day(date!(2026 - 03 - 12), 0.0), DayFill::Idle);
+        assert_eq!(day(date!(2026 - 03 - 09), 6.0), DayFill::Partial);
+        assert_eq!(day(date!(2026 - 03 - 11), 8.0), DayFill::Full);
+    }
+}
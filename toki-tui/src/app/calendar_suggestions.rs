@@ -0,0 +1,171 @@
+use super::week_checklist::{format_date, short_weekday};
+use super::*;
+
+/// Project and activity a calendar suggestion is logged on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuggestionTarget {
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+}
+
+impl App {
+    pub fn set_calendar_suggestions(&mut self, suggestions: Vec<CalendarSuggestion>) {
+        self.calendar_suggestions = suggestions;
+        self.clamp_suggestion_selection();
+    }
+
+    pub fn selected_calendar_suggestion(&self) -> Option<&CalendarSuggestion> {
+        self.calendar_suggestions
+            .get(self.selected_suggestion_index)
+    }
+
+    /// Drop a suggestion once it is logged or declined.
+    pub fn remove_calendar_suggestion(&mut self, suggestion: &CalendarSuggestion) {
+        self.calendar_suggestions.retain(|existing| {
+            existing.uid != suggestion.uid || existing.start_time != suggestion.start_time
+        });
+        self.clamp_suggestion_selection();
+    }
+
+    /// Where to log `suggestion`: the project and activity last used for the
+    /// meeting, or else the ones selected for the timer.
+    pub fn suggestion_target(&self, suggestion: &CalendarSuggestion) -> Option<SuggestionTarget> {
+        if let (Some(project_id), Some(activity_id)) =
+            (&suggestion.project_id, &suggestion.activity_id)
+        {
+            return Some(SuggestionTarget {
+                project_id: project_id.clone(),
+                project_name: suggestion.project_name.clone().unwrap_or_default(),
+                activity_id: activity_id.clone(),
+                activity_name: suggestion.activity_name.clone().unwrap_or_default(),
+            });
+        }
+        let (project, activity) = (
+            self.selected_project.as_ref()?,
+            self.selected_activity.as_ref()?,
+        );
+        Some(SuggestionTarget {
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            activity_id: activity.id.clone(),
+            activity_name: activity.name.clone(),
+        })
+    }
+
+    pub fn suggestion_focus_up(&mut self) {
+        if self.selected_suggestion_index == 0 {
+            self.focus_previous();
+        } else {
+            self.selected_suggestion_index -= 1;
+        }
+    }
+
+    pub fn suggestion_focus_down(&mut self) {
+        if self.selected_suggestion_index + 1 >= self.calendar_suggestions.len() {
+            self.focus_next();
+        } else {
+            self.selected_suggestion_index += 1;
+        }
+    }
+
+    fn clamp_suggestion_selection(&mut self) {
+        self.selected_suggestion_index = self
+            .selected_suggestion_index
+            .min(self.calendar_suggestions.len().saturating_sub(1));
+        if self.calendar_suggestions.is_empty() && self.focused_box == FocusedBox::Suggestions {
+            self.focused_box = FocusedBox::Today;
+        }
+    }
+}
+
+/// `Mon 2026-10-12 09:15–09:30 Daily standup`, in local time.
+pub fn suggestion_label(suggestion: &CalendarSuggestion) -> String {
+    let (start, end) = (
+        to_local_time(suggestion.start_time),
+        to_local_time(suggestion.end_time),
+    );
+    format!(
+        "{} {} {:02}:{:02}–{:02}:{:02} {}",
+        short_weekday(start.weekday()),
+        format_date(start.date()),
+        start.hour(),
+        start.minute(),
+        end.hour(),
+        end.minute(),
+        suggestion.title
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn suggestion(uid: &str, project: Option<(&str, &str)>) -> CalendarSuggestion {
+        let start = time::macros::datetime!(2026-10-16 07:15 UTC);
+        CalendarSuggestion {
+            uid: uid.to_string(),
+            title: "Standup".to_string(),
+            start_time: start,
+            end_time: start + time::Duration::minutes(15),
+            project_id: project.map(|(p, _)| p.to_string()),
+            project_name: project.map(|(p, _)| format!("Project {p}")),
+            activity_id: project.map(|(_, a)| a.to_string()),
+            activity_name: project.map(|(_, a)| format!("Activity {a}")),
+        }
+    }
+
+    #[test]
+    fn suggestions_without_a_previous_entry_use_the_timer_selection() {
+        let mut app = test_app();
+        let known = suggestion("standup", Some(("p1", "a1")));
+        let unknown = suggestion("demo", None);
+
+        assert_eq!(
+            app.suggestion_target(&known).map(|t| t.activity_id),
+            Some("a1".to_string())
+        );
+        assert_eq!(app.suggestion_target(&unknown), None);
+
+        app.selected_project = Some(Project {
+            id: "p2".to_string(),
+            name: "Project p2".to_string(),
+            budget: None,
+        });
+        app.selected_activity = Some(Activity {
+            id: "a2".to_string(),
+            name: "Activity a2".to_string(),
+            project_id: "p2".to_string(),
+        });
+        assert_eq!(
+            app.suggestion_target(&unknown).map(|t| t.project_id),
+            Some("p2".to_string())
+        );
+    }
+
+    #[test]
+    fn focus_leaves_the_suggestions_when_the_last_is_handled() {
+        let mut app = test_app();
+        app.set_calendar_suggestions(vec![suggestion("a", None), suggestion("b", None)]);
+        app.focused_box = FocusedBox::Description;
+
+        app.focus_next();
+        assert_eq!(app.focused_box, FocusedBox::Suggestions);
+        app.suggestion_focus_down();
+        assert_eq!(
+            app.selected_calendar_suggestion().map(|s| s.uid.as_str()),
+            Some("b")
+        );
+
+        app.remove_calendar_suggestion(&suggestion("b", None));
+        assert_eq!(app.selected_suggestion_index, 0);
+        assert_eq!(app.focused_box, FocusedBox::Suggestions);
+
+        app.remove_calendar_suggestion(&suggestion("a", None));
+        assert_eq!(app.focused_box, FocusedBox::Today);
+        app.focus_previous();
+        assert_eq!(app.focused_box, FocusedBox::Description);
+    }
+}
@@ -0,0 +1,173 @@
+use super::week_checklist::format_date;
+use super::*;
+use time::{Date, Weekday};
+
+/// Prompt for the day to copy a saved entry to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyPrompt {
+    pub registration_id: String,
+    /// Day as typed, `YYYY-MM-DD`.
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl App {
+    /// Open the copy prompt for the entry being edited, prefilled with the
+    /// next workday after it. Unsaved edits are not part of the copy.
+    pub fn open_copy_prompt(&mut self) {
+        let Some(registration_id) = self
+            .current_edit_state()
+            .map(|state| state.registration_id.clone())
+        else {
+            return;
+        };
+        if registration_id.is_empty() {
+            self.set_status("Only saved entries can be copied".to_string());
+            return;
+        }
+        let Some(entry) = self
+            .time_entries
+            .iter()
+            .find(|entry| entry.registration_id == registration_id)
+        else {
+            self.set_status("Error: Entry not found in history".to_string());
+            return;
+        };
+        if entry.start_time.is_none() || entry.end_time.is_none() {
+            self.set_status("Entry has no start and end time to copy".to_string());
+            return;
+        }
+
+        let input = parse_date_str(&entry.date)
+            .map(|date| format_date(next_workday(date)))
+            .unwrap_or_default();
+        self.copy_prompt = Some(CopyPrompt {
+            registration_id,
+            input,
+            error: None,
+        });
+    }
+
+    pub fn close_copy_prompt(&mut self) {
+        self.copy_prompt = None;
+    }
+
+    pub fn copy_prompt_input_char(&mut self, c: char) {
+        let Some(prompt) = &mut self.copy_prompt else {
+            return;
+        };
+        if !c.is_ascii_digit() && c != '-' {
+            return;
+        }
+        if prompt.input.len() >= 10 {
+            prompt.input.clear();
+        }
+        prompt.input.push(c);
+        prompt.error = None;
+    }
+
+    pub fn copy_prompt_backspace(&mut self) {
+        let Some(prompt) = &mut self.copy_prompt else {
+            return;
+        };
+        prompt.input.pop();
+        prompt.error = None;
+    }
+}
+
+/// The first Monday to Friday after `date`.
+pub fn next_workday(date: Date) -> Date {
+    let mut next = date.next_day().unwrap_or(date);
+    while matches!(next.weekday(), Weekday::Saturday | Weekday::Sunday) {
+        next = next.next_day().unwrap_or(next);
+    }
+    next
+}
+
+/// Parse the day typed in the copy prompt.
+pub fn parse_copy_date(input: &str) -> Result<Date, String> {
+    parse_date_str(input.trim()).ok_or_else(|| "Enter the day as YYYY-MM-DD".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+    use time::macros::{date, datetime};
+
+    fn edit_state(registration_id: &str) -> EntryEditState {
+        EntryEditState {
+            registration_id: registration_id.to_string(),
+            start_time_input: "08:00".to_string(),
+            end_time_input: "09:00".to_string(),
+            original_start_time: "08:00".to_string(),
+            original_end_time: "09:00".to_string(),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+            note: TextInput::new(),
+            focused_field: EntryEditField::StartTime,
+            validation_error: None,
+        }
+    }
+
+    #[test]
+    fn prompt_defaults_to_the_next_workday() {
+        let mut app = test_app();
+        // 2026-10-16 is a Friday.
+        app.time_entries = vec![time_entry(
+            "reg-1",
+            "p1",
+            "Toki",
+            "a1",
+            "Standup",
+            "2026-10-16",
+            0.25,
+            None,
+            Some(datetime!(2026-10-16 07:00 UTC)),
+            Some(datetime!(2026-10-16 07:15 UTC)),
+        )];
+        app.current_view = View::History;
+        app.history_edit_state = Some(edit_state("reg-1"));
+
+        app.open_copy_prompt();
+
+        assert_eq!(
+            app.copy_prompt.as_ref().map(|prompt| prompt.input.as_str()),
+            Some("2026-10-19")
+        );
+        app.copy_prompt_input_char('x');
+        app.copy_prompt_backspace();
+        app.copy_prompt_input_char('8');
+        assert_eq!(
+            parse_copy_date(&app.copy_prompt.unwrap().input),
+            Ok(date!(2026 - 10 - 18))
+        );
+    }
+
+    #[test]
+    fn entries_without_times_cant_be_copied() {
+        let mut app = test_app();
+        app.time_entries = vec![time_entry(
+            "reg-1",
+            "p1",
+            "Toki",
+            "a1",
+            "Standup",
+            "2026-10-16",
+            1.0,
+            None,
+            None,
+            None,
+        )];
+        app.current_view = View::History;
+        app.history_edit_state = Some(edit_state("reg-1"));
+
+        app.open_copy_prompt();
+
+        assert!(app.copy_prompt.is_none());
+        assert!(parse_copy_date("2026-02-30").is_err());
+        assert_eq!(next_workday(date!(2026 - 10 - 14)), date!(2026 - 10 - 15));
+    }
+}
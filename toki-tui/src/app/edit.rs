@@ -652,6 +652,14 @@ impl App {
             .unwrap_or(false)
     }
 
+    /// Whether a loaded entry on `date` ("YYYY-MM-DD") is attested. Days are
+    /// attested as a whole, so nothing can be added to such a day either.
+    pub fn is_day_locked(&self, date: &str) -> bool {
+        self.time_entries
+            .iter()
+            .any(|e| e.date == date && e.status.is_locked())
+    }
+
     /// Set the status message for a locked-entry delete attempt.
     pub fn set_locked_delete_status(&mut self) {
         self.set_status(LOCKED_DELETE_MSG.to_string());
@@ -677,6 +685,7 @@ impl App {
         self.selected_project = Some(crate::types::Project {
             id: entry.project_id.clone(),
             name: entry.project_name.clone(),
+            budget: None,
         });
         self.selected_activity = Some(crate::types::Activity {
             id: entry.activity_id.clone(),
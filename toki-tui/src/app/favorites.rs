@@ -0,0 +1,93 @@
+use super::*;
+
+/// Favorites listed in the project selection, one per number key.
+pub const FAVORITE_SHORTCUTS: usize = 9;
+
+impl App {
+    /// The favorite selected with number key `c` (`1` is the first).
+    pub fn favorite_for_key(&self, c: char) -> Option<&Favorite> {
+        let index = c.to_digit(10)?.checked_sub(1)? as usize;
+        if index >= FAVORITE_SHORTCUTS {
+            return None;
+        }
+        self.favorites.get(index)
+    }
+
+    /// The favorite for the project and activity, if pinned.
+    pub fn favorite_for(&self, project_id: &str, activity_id: &str) -> Option<&Favorite> {
+        self.favorites.iter().find(|favorite| {
+            favorite.project_id == project_id && favorite.activity_id == activity_id
+        })
+    }
+
+    /// Select the favorite's project and activity in one go, as if both had
+    /// been picked from their lists.
+    pub fn select_favorite(&mut self, favorite: &Favorite) {
+        let budget = self
+            .projects
+            .iter()
+            .find(|project| project.id == favorite.project_id)
+            .and_then(|project| project.budget);
+        self.selected_project = Some(Project {
+            id: favorite.project_id.clone(),
+            name: favorite.project_name.clone(),
+            budget,
+        });
+        self.select_activity(Activity {
+            id: favorite.activity_id.clone(),
+            name: favorite.activity_name.clone(),
+            project_id: favorite.project_id.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn favorite(id: i32, project_id: &str, activity_id: &str) -> Favorite {
+        Favorite {
+            id,
+            project_id: project_id.to_string(),
+            project_name: format!("Project {project_id}"),
+            activity_id: activity_id.to_string(),
+            activity_name: format!("Activity {activity_id}"),
+        }
+    }
+
+    #[test]
+    fn number_keys_pick_favorites_in_pinned_order() {
+        let mut app = test_app();
+        app.favorites = (1..=10)
+            .map(|i| favorite(i, "p1", &format!("a{i}")))
+            .collect();
+
+        assert_eq!(app.favorite_for_key('1').map(|f| f.id), Some(1));
+        assert_eq!(app.favorite_for_key('9').map(|f| f.id), Some(9));
+        assert_eq!(app.favorite_for_key('0'), None);
+        assert_eq!(app.favorite_for_key('x'), None);
+        assert_eq!(app.favorite_for("p1", "a10").map(|f| f.id), Some(10));
+    }
+
+    #[test]
+    fn selecting_a_favorite_sets_project_and_activity() {
+        let mut app = test_app();
+        app.description_is_default = false;
+        app.navigate_to(View::SelectProject);
+
+        app.select_favorite(&favorite(1, "p1", "a1"));
+
+        assert_eq!(
+            app.selected_project.as_ref().map(|p| p.name.as_str()),
+            Some("Project p1")
+        );
+        assert_eq!(
+            app.selected_activity
+                .as_ref()
+                .map(|a| a.project_id.as_str()),
+            Some("p1")
+        );
+        assert_eq!(app.current_view, View::Timer);
+    }
+}
@@ -0,0 +1,287 @@
+use super::week_checklist::{format_date, short_weekday};
+use super::*;
+use crate::types::MissingWorkday;
+use time::Date;
+
+/// A workday with nothing logged, as listed in the fill wizard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillDay {
+    pub date: Date,
+    /// Hours to log, the day's scheduled hours less any absence.
+    pub hours: f64,
+    pub selected: bool,
+}
+
+impl FillDay {
+    pub fn label(&self) -> String {
+        format!(
+            "{} {}  {:.1}h",
+            short_weekday(self.date.weekday()),
+            format_date(self.date),
+            self.hours
+        )
+    }
+}
+
+/// What the selected days are filled with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillSource {
+    pub label: String,
+    pub project: String,
+    pub activity: String,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillStep {
+    Days,
+    Source,
+}
+
+/// Wizard filling the workdays with nothing logged: pick the days, then the
+/// template or default project to fill them with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillWizard {
+    pub days: Vec<FillDay>,
+    pub sources: Vec<FillSource>,
+    pub step: FillStep,
+    /// Focused row in the list of the current step.
+    pub focused: usize,
+}
+
+impl FillWizard {
+    fn list_len(&self) -> usize {
+        match self.step {
+            FillStep::Days => self.days.len(),
+            FillStep::Source => self.sources.len(),
+        }
+    }
+}
+
+/// An entry the wizard logs for one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillEntry {
+    pub project: String,
+    pub activity: String,
+    pub note: String,
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+impl App {
+    /// Open the wizard on the days the server found missing, all selected.
+    pub fn open_fill_wizard(&mut self, missing: Vec<MissingWorkday>) {
+        let days: Vec<FillDay> = missing
+            .into_iter()
+            .filter_map(|day| {
+                Some(FillDay {
+                    date: parse_date_str(&day.date)?,
+                    hours: day.hours,
+                    selected: true,
+                })
+            })
+            .filter(|day| day.hours > 0.0)
+            .collect();
+        if days.is_empty() {
+            self.set_status("No workdays without time this month".to_string());
+            return;
+        }
+
+        self.fill_wizard = Some(FillWizard {
+            days,
+            sources: self.fill_sources(),
+            step: FillStep::Days,
+            focused: 0,
+        });
+        self.navigate_to(View::FillWorkdays);
+    }
+
+    /// The configured default project first, then the templates.
+    fn fill_sources(&self) -> Vec<FillSource> {
+        let default = self.fill_default.is_set().then(|| FillSource {
+            label: format!(
+                "Default: {} / {}",
+                self.fill_default.project, self.fill_default.activity
+            ),
+            project: self.fill_default.project.clone(),
+            activity: self.fill_default.activity.clone(),
+            note: self.fill_default.note.clone(),
+        });
+        default
+            .into_iter()
+            .chain(self.templates.iter().map(|template| FillSource {
+                label: format!(
+                    "{} ({} / {})",
+                    template.description, template.project, template.activity
+                ),
+                project: template.project.clone(),
+                activity: template.activity.clone(),
+                note: template.note.clone(),
+            }))
+            .collect()
+    }
+
+    pub fn close_fill_wizard(&mut self) {
+        self.fill_wizard = None;
+        self.navigate_to(View::Timer);
+    }
+
+    pub fn fill_wizard_move(&mut self, down: bool) {
+        let Some(wizard) = &mut self.fill_wizard else {
+            return;
+        };
+        let len = wizard.list_len();
+        if len == 0 {
+            return;
+        }
+        wizard.focused = if down {
+            (wizard.focused + 1) % len
+        } else {
+            (wizard.focused + len - 1) % len
+        };
+    }
+
+    /// Select or deselect the focused day.
+    pub fn toggle_fill_day(&mut self) {
+        let Some(wizard) = &mut self.fill_wizard else {
+            return;
+        };
+        if wizard.step != FillStep::Days {
+            return;
+        }
+        if let Some(day) = wizard.days.get_mut(wizard.focused) {
+            day.selected = !day.selected;
+        }
+    }
+
+    /// Go on to picking what to fill the selected days with. Returns true
+    /// when the focused source is picked and the days can be filled.
+    pub fn fill_wizard_next(&mut self) -> bool {
+        let Some(wizard) = &mut self.fill_wizard else {
+            return false;
+        };
+        match wizard.step {
+            FillStep::Days if !wizard.days.iter().any(|day| day.selected) => {
+                self.set_status("Select at least one day to fill".to_string());
+                false
+            }
+            FillStep::Days if wizard.sources.is_empty() => {
+                self.set_status(
+                    "Add a [fill] project or a template to the config to fill days".to_string(),
+                );
+                false
+            }
+            FillStep::Days => {
+                wizard.step = FillStep::Source;
+                wizard.focused = 0;
+                false
+            }
+            FillStep::Source => true,
+        }
+    }
+
+    /// Back to the days from the sources, or out of the wizard.
+    pub fn fill_wizard_back(&mut self) {
+        match &mut self.fill_wizard {
+            Some(wizard) if wizard.step == FillStep::Source => {
+                wizard.step = FillStep::Days;
+                wizard.focused = 0;
+            }
+            _ => self.close_fill_wizard(),
+        }
+    }
+
+    /// One entry per selected day with the focused source, starting at the
+    /// configured time and lasting the day's missing hours.
+    pub fn fill_wizard_entries(&self) -> Vec<FillEntry> {
+        let Some(wizard) = &self.fill_wizard else {
+            return Vec::new();
+        };
+        let Some(source) = wizard.sources.get(wizard.focused) else {
+            return Vec::new();
+        };
+        let start_time = self.fill_default.start_time();
+        wizard
+            .days
+            .iter()
+            .filter(|day| day.selected)
+            .map(|day| {
+                let start = crate::time_utils::local_date_time(day.date, start_time);
+                FillEntry {
+                    project: source.project.clone(),
+                    activity: source.activity.clone(),
+                    note: source.note.clone(),
+                    start,
+                    end: start + time::Duration::seconds_f64(day.hours * 3600.0),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FillConfig, TemplateConfig};
+    use crate::test_support::test_app;
+
+    fn missing(date: &str, hours: f64) -> MissingWorkday {
+        MissingWorkday {
+            date: date.to_string(),
+            hours,
+        }
+    }
+
+    #[test]
+    fn fills_selected_days_from_the_picked_source() {
+        let mut app = test_app();
+        app.fill_default = FillConfig {
+            project: "Toki".to_string(),
+            activity: "Development".to_string(),
+            note: "Coding".to_string(),
+            start: "09:00".to_string(),
+        };
+        app.templates = vec![TemplateConfig {
+            description: "Support".to_string(),
+            project: "Acme".to_string(),
+            activity: "Support".to_string(),
+            note: "Tickets".to_string(),
+            ..TemplateConfig::default()
+        }];
+
+        app.open_fill_wizard(vec![
+            missing("2026-10-12", 8.0),
+            missing("2026-10-13", 4.0),
+            missing("2026-10-14", 8.0),
+        ]);
+        assert_eq!(app.current_view, View::FillWorkdays);
+        app.fill_wizard_move(true);
+        app.toggle_fill_day();
+        assert!(!app.fill_wizard_next());
+        app.fill_wizard_move(true);
+        assert!(app.fill_wizard_next());
+
+        let entries = app.fill_wizard_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].project, "Acme");
+        assert_eq!(entries[0].note, "Tickets");
+        let start = crate::time_utils::to_local_time(entries[0].start);
+        assert_eq!(start.date(), time::macros::date!(2026 - 10 - 12));
+        assert_eq!(start.hour(), 9);
+        assert_eq!(entries[0].end - entries[0].start, time::Duration::hours(8));
+    }
+
+    #[test]
+    fn nothing_to_fill_keeps_the_wizard_closed() {
+        let mut app = test_app();
+        app.open_fill_wizard(Vec::new());
+        assert!(app.fill_wizard.is_none());
+        assert_eq!(app.current_view, View::Timer);
+
+        app.open_fill_wizard(vec![missing("2026-10-12", 8.0)]);
+        assert!(!app.fill_wizard_next());
+        assert_eq!(app.fill_wizard.as_ref().unwrap().step, FillStep::Days);
+        app.fill_wizard_back();
+        assert!(app.fill_wizard.is_none());
+    }
+}
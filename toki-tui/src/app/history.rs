@@ -1,23 +1,96 @@
 use super::*;
+use crate::rounding::{Rounding, RoundingMode};
 use std::collections::HashMap;
 
 impl App {
-    /// Build the history list entries (indices into time_entries)
-    pub fn rebuild_history_list(&mut self) {
-        let month_ago = (OffsetDateTime::now_utc() - time::Duration::days(30)).date();
-        let month_ago_str = format!(
-            "{:04}-{:02}-{:02}",
-            month_ago.year(),
-            month_ago.month() as u8,
-            month_ago.day()
-        );
-        self.history_list_entries = self
-            .time_entries
+    /// The entries listed in the history view.
+    pub fn history_list(&self) -> Vec<&TimeEntry> {
+        self.history_list_entries
             .iter()
-            .enumerate()
-            .filter(|(_, entry)| entry.date >= month_ago_str)
-            .map(|(idx, _)| idx)
-            .collect();
+            .filter_map(|&idx| self.time_entries.get(idx))
+            .collect()
+    }
+
+    /// Mark the focused history entry for merging, deleting or reassigning,
+    /// or unmark it.
+    pub fn toggle_entry_mark(&mut self) {
+        let Some(entry) = self
+            .focused_history_index
+            .and_then(|list_idx| self.history_list_entries.get(list_idx))
+            .and_then(|&history_idx| self.time_entries.get(history_idx))
+        else {
+            return;
+        };
+        if entry.status.is_locked() {
+            self.set_status("Attested entries can't be changed".to_string());
+            return;
+        }
+
+        let id = entry.registration_id.clone();
+        if !self.marked_entries.remove(&id) {
+            self.marked_entries.insert(id);
+        }
+    }
+
+    /// Marked entries, in history order. Includes marked entries the
+    /// history filter hides.
+    pub fn marked_history_entries(&self) -> Vec<&TimeEntry> {
+        self.time_entries
+            .iter()
+            .filter(|entry| self.marked_entries.contains(&entry.registration_id))
+            .collect()
+    }
+
+    /// Ask to delete all marked entries at once.
+    pub fn enter_marked_delete_confirm(&mut self) {
+        let entries = self.marked_history_entries();
+        let (Some(first), Some(last)) = (
+            entries.iter().map(|entry| &entry.date).min(),
+            entries.iter().map(|entry| &entry.date).max(),
+        ) else {
+            return;
+        };
+        let display_date = if first == last {
+            first.clone()
+        } else {
+            format!("{} – {}", first, last)
+        };
+        let ctx = DeleteContext {
+            registration_ids: entries
+                .iter()
+                .map(|entry| entry.registration_id.clone())
+                .collect(),
+            display_label: format!("{} marked entries", entries.len()),
+            display_date,
+            display_hours: entries.iter().map(|entry| entry.hours).sum(),
+            origin: DeleteOrigin::History,
+        };
+        self.delete_context = Some(ctx);
+        self.navigate_to(View::ConfirmDelete);
+    }
+
+    /// Pick a project and activity to move the marked entries to. The
+    /// timer's selection is kept aside until the pick is done.
+    pub fn start_reassigning_marked(&mut self) {
+        if self.marked_entries.is_empty() {
+            return;
+        }
+        self.reassigning_marked = Some((
+            self.selected_project.clone(),
+            self.selected_activity.clone(),
+        ));
+        self.navigate_to(View::SelectProject);
+    }
+
+    /// The project and activity picked for the marked entries, if they are
+    /// being reassigned. Gives the timer its selection back and returns to
+    /// the history view.
+    pub fn finish_reassigning_marked(&mut self) -> Option<(Project, Activity)> {
+        let (restore_project, restore_activity) = self.reassigning_marked.take()?;
+        let project = std::mem::replace(&mut self.selected_project, restore_project);
+        let activity = std::mem::replace(&mut self.selected_activity, restore_activity);
+        self.navigate_to(View::History);
+        project.zip(activity)
     }
 
     /// Compute overlapping time entries per day.
@@ -91,9 +164,15 @@ impl App {
         sunday.replace_time(time::Time::MIDNIGHT) + time::Duration::nanoseconds(86_399_999_999_999)
     }
 
+    /// Rounding to show entries with, when it isn't applied as they are saved.
+    pub fn display_rounding(&self) -> Option<Rounding> {
+        self.rounding
+            .filter(|rounding| rounding.mode == RoundingMode::Display)
+    }
+
     /// Get this week's history entries (Monday to Sunday)
     pub fn this_week_history(&self) -> Vec<&TimeEntry> {
-        let now = OffsetDateTime::now_utc();
+        let now = to_local_time(OffsetDateTime::now_utc());
         let week_start = Self::week_start(now).date();
         let week_end = Self::week_end(now).date();
         let ws = format!(
@@ -127,104 +206,6 @@ impl App {
 
         (self.worked_hours_this_week() / self.scheduled_hours_per_week * 100.0).clamp(0.0, 100.0)
     }
-
-    /// Per-project/activity breakdown for this week (≥ 1% of total, sorted desc)
-    pub fn weekly_project_stats(&self) -> Vec<ProjectStat> {
-        let entries = self.this_week_history();
-        let mut map: HashMap<String, f64> = HashMap::new();
-
-        for e in &entries {
-            if e.hours > 0.0 {
-                let key = format!("{}: {}", e.project_name, e.activity_name);
-                *map.entry(key).or_insert(0.0) += e.hours;
-            }
-        }
-
-        let total: f64 = map.values().sum();
-        if total == 0.0 {
-            return Vec::new();
-        }
-
-        let mut stats: Vec<ProjectStat> = map
-            .into_iter()
-            .filter_map(|(label, hours)| {
-                let percentage = hours / total * 100.0;
-                if percentage >= 1.0 {
-                    Some(ProjectStat {
-                        label,
-                        hours,
-                        percentage,
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        stats.sort_by(|a, b| {
-            b.hours
-                .partial_cmp(&a.hours)
-                .unwrap_or(std::cmp::Ordering::Equal)
-                .then_with(|| a.label.cmp(&b.label)) // stable tiebreaker: label alphabetical
-        });
-        stats
-    }
-
-    /// Per-day breakdown for this week, Mon–Sun, each day split by project/activity.
-    /// Projects are colored by their global rank (same order as weekly_project_stats).
-    pub fn weekly_daily_stats(&self) -> Vec<DayStat> {
-        // Build the global project ordering (for consistent palette indices)
-        let global_stats = self.weekly_project_stats();
-        let color_index: HashMap<String, usize> = global_stats
-            .iter()
-            .enumerate()
-            .map(|(i, s)| (s.label.clone(), i))
-            .collect();
-
-        // Build 7 slots Mon(0)…Sun(6)
-        let day_names = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-        let mut slots: Vec<HashMap<String, f64>> = vec![HashMap::new(); 7];
-
-        for entry in self.this_week_history() {
-            if entry.hours <= 0.0 {
-                continue;
-            }
-            // Parse entry date to find which weekday slot
-            let Some(date) = parse_date_str(&entry.date) else {
-                continue;
-            };
-
-            let slot = date.weekday().number_days_from_monday() as usize;
-            let key = format!("{}: {}", entry.project_name, entry.activity_name);
-            *slots[slot].entry(key).or_insert(0.0) += entry.hours;
-        }
-
-        slots
-            .into_iter()
-            .enumerate()
-            .map(|(i, map)| {
-                let total_hours: f64 = map.values().sum();
-                let mut projects: Vec<DailyProjectStat> = map
-                    .into_iter()
-                    .map(|(label, hours)| {
-                        let ci = *color_index.get(&label).unwrap_or(&0);
-                        DailyProjectStat {
-                            label,
-                            hours,
-                            color_index: ci,
-                        }
-                    })
-                    .collect();
-                // Sort by global rank (color_index) so stacking order matches pie
-                projects.sort_by_key(|p| p.color_index);
-                DayStat {
-                    day_name: day_names[i].to_string(),
-                    total_hours,
-                    projects,
-                }
-            })
-            .collect()
-    }
 }
 
 /// Parse a date string in "YYYY-MM-DD" format into a [`time::Date`].
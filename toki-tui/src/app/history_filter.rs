@@ -0,0 +1,546 @@
+use super::calendar::{first_of_month, last_of_month, shift_month};
+use super::history::parse_date_str;
+use super::week_checklist::format_date;
+use super::*;
+use time::Date;
+
+/// Days loaded at startup and listed when no other period is picked.
+pub const RECENT_DAYS: i64 = 30;
+
+/// Period the history view is narrowed to. All but `Custom` are paged back
+/// from the current one with `[` and `]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoryPeriod {
+    /// Pages of 30 days, the first one being everything loaded at startup.
+    #[default]
+    Recent,
+    Week,
+    Month,
+    /// Picked with the date range prompt.
+    Custom {
+        start: Date,
+        end: Date,
+    },
+}
+
+impl HistoryPeriod {
+    /// The period `f` switches to.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Recent => Self::Week,
+            Self::Week => Self::Month,
+            Self::Month | Self::Custom { .. } => Self::Recent,
+        }
+    }
+
+    /// First and last day listed, `page` pages back from `today`'s.
+    pub fn range(self, today: Date, page: u32) -> (Date, Date) {
+        match self {
+            Self::Recent => {
+                let end = today - time::Duration::days((RECENT_DAYS + 1) * page as i64);
+                (end - time::Duration::days(RECENT_DAYS), end)
+            }
+            Self::Week => {
+                let monday = today
+                    - time::Duration::days(today.weekday().number_days_from_monday() as i64)
+                    - time::Duration::weeks(page as i64);
+                (monday, monday + time::Duration::days(6))
+            }
+            Self::Month => {
+                let start = shift_month(first_of_month(today), -(page as i32));
+                (start, last_of_month(start))
+            }
+            Self::Custom { start, end } => (start, end),
+        }
+    }
+
+    /// "this week" for the current page, the dates otherwise.
+    pub fn label(self, today: Date, page: u32) -> String {
+        match (self, page) {
+            (Self::Recent, 0) => "last 30 days".to_string(),
+            (Self::Week, 0) => "this week".to_string(),
+            (Self::Month, 0) => "this month".to_string(),
+            _ => {
+                let (start, end) = self.range(today, page);
+                format!("{} – {}", format_date(start), format_date(end))
+            }
+        }
+    }
+}
+
+/// What narrows down the entries listed in the history view.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryFilter {
+    /// Fuzzy matched against each entry's note, project and activity.
+    pub search: TextInput,
+    /// Whether keys go to the search rather than the list.
+    pub searching: bool,
+    pub period: HistoryPeriod,
+    /// Pages back from the current period, unused for custom ranges.
+    pub page: u32,
+    /// Only list entries of this project, by name.
+    pub project: Option<String>,
+}
+
+/// Prompt for the dates to list in the history view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryRangePrompt {
+    /// `YYYY-MM-DD YYYY-MM-DD` as typed, or a single day.
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl HistoryFilter {
+    /// Whether anything beyond the default period is filtered on.
+    pub fn is_active(&self) -> bool {
+        !self.search.value.trim().is_empty()
+            || self.period != HistoryPeriod::default()
+            || self.page > 0
+            || self.project.is_some()
+    }
+
+    /// First and last day listed on `today`.
+    pub fn range(&self, today: Date) -> (Date, Date) {
+        self.period.range(today, self.page)
+    }
+
+    /// "this week · Toki · /login", for the history title.
+    pub fn describe(&self, today: Date) -> String {
+        let mut parts = vec![self.period.label(today, self.page)];
+        if let Some(project) = &self.project {
+            parts.push(project.clone());
+        }
+        let query = self.search.value.trim();
+        if !query.is_empty() {
+            parts.push(format!("/{}", query));
+        }
+        parts.join(" · ")
+    }
+
+    /// Whether `entry` is listed with this filter on `today`.
+    fn matches(&self, entry: &TimeEntry, today: Date, matcher: &SkimMatcherV2) -> bool {
+        let (start, end) = self.range(today);
+        if entry.date < format_date(start) || entry.date > format_date(end) {
+            return false;
+        }
+        if self
+            .project
+            .as_ref()
+            .is_some_and(|project| entry.project_name != *project)
+        {
+            return false;
+        }
+        let query = self.search.value.trim();
+        query.is_empty()
+            || matcher
+                .fuzzy_match(
+                    &format!(
+                        "{} {} {}",
+                        entry.note.as_deref().unwrap_or_default(),
+                        entry.project_name,
+                        entry.activity_name
+                    ),
+                    query,
+                )
+                .is_some()
+    }
+}
+
+impl App {
+    /// Build the history list entries (indices into time_entries) from the
+    /// entries the history filter lets through, newest first.
+    pub fn rebuild_history_list(&mut self) {
+        let today = crate::time_utils::local_today();
+        let matcher = SkimMatcherV2::default();
+        self.history_list_entries = self
+            .time_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| self.history_filter.matches(entry, today, &matcher))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let len = self.history_list_entries.len();
+        self.focused_history_index = match self.focused_history_index {
+            _ if len == 0 => None,
+            Some(idx) => Some(idx.min(len - 1)),
+            None => None,
+        };
+    }
+
+    /// Start typing a search in the history view.
+    pub fn start_history_search(&mut self) {
+        self.history_filter.searching = true;
+    }
+
+    pub fn history_search_char(&mut self, c: char) {
+        self.history_filter.search.insert(c);
+        self.refilter_history();
+    }
+
+    pub fn history_search_backspace(&mut self) {
+        self.history_filter.search.backspace();
+        self.refilter_history();
+    }
+
+    /// Stop typing and keep the list filtered by the search.
+    pub fn finish_history_search(&mut self) {
+        self.history_filter.searching = false;
+    }
+
+    /// Stop typing and drop the search.
+    pub fn clear_history_search(&mut self) {
+        self.history_filter.searching = false;
+        self.history_filter.search.clear();
+        self.refilter_history();
+    }
+
+    /// Switch between the last 30 days, this week and this month.
+    pub fn cycle_history_period(&mut self) {
+        self.history_filter.period = self.history_filter.period.next();
+        self.history_filter.page = 0;
+        self.refilter_history();
+    }
+
+    /// Page to the period before the listed one, or after it when `older`
+    /// is false. Custom ranges move by their own length.
+    pub fn page_history(&mut self, older: bool) {
+        let today = crate::time_utils::local_today();
+        let filter = &mut self.history_filter;
+        match filter.period {
+            HistoryPeriod::Custom { start, end } => {
+                if !older && end >= today {
+                    return;
+                }
+                let length = end - start + time::Duration::days(1);
+                let shift = if older { -length } else { length };
+                filter.period = HistoryPeriod::Custom {
+                    start: start + shift,
+                    end: end + shift,
+                };
+            }
+            _ if older => filter.page += 1,
+            _ if filter.page == 0 => return,
+            _ => filter.page -= 1,
+        }
+        self.refilter_history();
+    }
+
+    /// Days of the listed period that are older than the loaded history,
+    /// from the start of the period up to the oldest loaded day.
+    pub fn missing_history_range(&self) -> Option<(Date, Date)> {
+        let loaded_from = self.history_loaded_from?;
+        let (start, _) = self.history_filter.range(crate::time_utils::local_today());
+        (start < loaded_from).then(|| (start, loaded_from - time::Duration::days(1)))
+    }
+
+    /// Replace the loaded entries between `from` and `to` with `entries`,
+    /// keeping the rest of the loaded history.
+    pub fn merge_history(&mut self, entries: Vec<TimeEntry>, from: Date, to: Date) {
+        let (from_str, to_str) = (format_date(from), format_date(to));
+        let mut merged: Vec<TimeEntry> = self
+            .time_entries
+            .drain(..)
+            .filter(|entry| entry.date < from_str || entry.date > to_str)
+            .collect();
+        merged.extend(entries);
+        self.history_loaded_from = Some(
+            self.history_loaded_from
+                .map_or(from, |loaded_from| loaded_from.min(from)),
+        );
+        self.update_history(merged);
+        self.rebuild_history_list();
+    }
+
+    /// Ask for the dates to list, prefilled with the listed ones.
+    pub fn open_history_range_prompt(&mut self) {
+        let (start, end) = self.history_filter.range(crate::time_utils::local_today());
+        self.history_range_prompt = Some(HistoryRangePrompt {
+            input: format!("{} {}", format_date(start), format_date(end)),
+            error: None,
+        });
+    }
+
+    pub fn close_history_range_prompt(&mut self) {
+        self.history_range_prompt = None;
+    }
+
+    pub fn history_range_prompt_char(&mut self, c: char) {
+        if let Some(prompt) = &mut self.history_range_prompt {
+            if c.is_ascii_digit() || c == '-' || c == ' ' {
+                prompt.input.push(c);
+                prompt.error = None;
+            }
+        }
+    }
+
+    pub fn history_range_prompt_backspace(&mut self) {
+        if let Some(prompt) = &mut self.history_range_prompt {
+            prompt.input.pop();
+            prompt.error = None;
+        }
+    }
+
+    /// List the dates typed in the range prompt. Returns false and keeps
+    /// the prompt open with an error if they can't be parsed.
+    pub fn apply_history_range_prompt(&mut self) -> bool {
+        let Some(prompt) = &mut self.history_range_prompt else {
+            return false;
+        };
+        match parse_history_range(&prompt.input) {
+            Ok((start, end)) => {
+                self.history_range_prompt = None;
+                self.history_filter.period = HistoryPeriod::Custom { start, end };
+                self.history_filter.page = 0;
+                self.refilter_history();
+                true
+            }
+            Err(error) => {
+                prompt.error = Some(error);
+                false
+            }
+        }
+    }
+
+    /// Only list entries of the focused entry's project, or list all
+    /// projects again.
+    pub fn toggle_history_project_filter(&mut self) {
+        if self.history_filter.project.take().is_none() {
+            let Some(project) = self
+                .focused_history_index
+                .and_then(|list_idx| self.history_list_entries.get(list_idx))
+                .and_then(|&idx| self.time_entries.get(idx))
+                .map(|entry| entry.project_name.clone())
+            else {
+                return;
+            };
+            self.history_filter.project = Some(project);
+        }
+        self.refilter_history();
+    }
+
+    /// Drop the search and quick filters.
+    pub fn clear_history_filters(&mut self) {
+        self.history_filter = HistoryFilter::default();
+        self.refilter_history();
+    }
+
+    /// Rebuild the list after the filter changed, focusing its first entry.
+    fn refilter_history(&mut self) {
+        self.rebuild_history_list();
+        self.history_scroll = 0;
+        self.focused_history_index = if self.history_list_entries.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+}
+
+/// Parse `YYYY-MM-DD YYYY-MM-DD`, or a single `YYYY-MM-DD` for one day.
+pub fn parse_history_range(input: &str) -> Result<(Date, Date), String> {
+    let parse = |part: &str| {
+        parse_date_str(part).ok_or_else(|| format!("Not a date (YYYY-MM-DD): {}", part))
+    };
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    let (start, end) = match parts.as_slice() {
+        [day] => (parse(day)?, parse(day)?),
+        [start, end] => (parse(start)?, parse(end)?),
+        _ => return Err("Enter a start and an end date".to_string()),
+    };
+    if start > end {
+        return Err("The start date is after the end date".to_string());
+    }
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{day_entry, test_app};
+    use time::macros::date;
+
+    fn listed_ids(app: &App) -> Vec<&str> {
+        app.history_list()
+            .iter()
+            .map(|entry| entry.registration_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn periods_page_back_from_today() {
+        // 2026-10-16 is a Friday.
+        let today = date!(2026 - 10 - 16);
+        assert_eq!(
+            HistoryPeriod::Week.range(today, 1),
+            (date!(2026 - 10 - 05), date!(2026 - 10 - 11))
+        );
+        assert_eq!(
+            HistoryPeriod::Month.range(today, 2),
+            (date!(2026 - 08 - 01), date!(2026 - 08 - 31))
+        );
+        assert_eq!(
+            HistoryPeriod::Recent.range(today, 0),
+            (date!(2026 - 09 - 16), today)
+        );
+        assert_eq!(
+            HistoryPeriod::Recent.range(today, 1),
+            (date!(2026 - 08 - 16), date!(2026 - 09 - 15))
+        );
+        assert_eq!(HistoryPeriod::Week.label(today, 0), "this week");
+        assert_eq!(
+            HistoryPeriod::Week.label(today, 1),
+            "2026-10-05 – 2026-10-11"
+        );
+        assert_eq!(HistoryPeriod::Month.next(), HistoryPeriod::Recent);
+    }
+
+    #[test]
+    fn date_ranges_parse_one_or_two_days() {
+        assert_eq!(
+            parse_history_range(" 2026-09-01  2026-09-30 "),
+            Ok((date!(2026 - 09 - 01), date!(2026 - 09 - 30)))
+        );
+        assert_eq!(
+            parse_history_range("2026-09-01"),
+            Ok((date!(2026 - 09 - 01), date!(2026 - 09 - 01)))
+        );
+        assert!(parse_history_range("2026-09-30 2026-09-01").is_err());
+        assert!(parse_history_range("2026-13-01").is_err());
+        assert!(parse_history_range("").is_err());
+    }
+
+    #[test]
+    fn paging_past_the_loaded_history_asks_for_older_entries() {
+        let today = crate::time_utils::local_today();
+        let (recent_start, _) = HistoryPeriod::Recent.range(today, 0);
+        let old_day = recent_start - time::Duration::days(10);
+        let mut app = test_app();
+        app.merge_history(
+            vec![day_entry("1", "Toki", "Development", today, 1.0, Some(""))],
+            recent_start,
+            today,
+        );
+        assert_eq!(app.missing_history_range(), None);
+
+        app.page_history(true);
+        let (start, _) = app.history_filter.range(today);
+        assert_eq!(
+            app.missing_history_range(),
+            Some((start, recent_start - time::Duration::days(1)))
+        );
+        assert!(app.history_list().is_empty());
+
+        app.merge_history(
+            vec![day_entry(
+                "2",
+                "Toki",
+                "Development",
+                old_day,
+                1.0,
+                Some(""),
+            )],
+            start,
+            recent_start - time::Duration::days(1),
+        );
+        assert_eq!(app.missing_history_range(), None);
+        assert_eq!(listed_ids(&app), ["2"]);
+
+        // Refreshing the recent entries keeps the older page.
+        app.merge_history(Vec::new(), recent_start, today);
+        assert_eq!(app.time_entries.len(), 1);
+
+        app.page_history(false);
+        app.page_history(false);
+        assert_eq!(app.history_filter.page, 0);
+    }
+
+    #[test]
+    fn range_prompt_lists_the_typed_dates() {
+        let mut app = test_app();
+        app.open_history_range_prompt();
+        app.history_range_prompt.as_mut().unwrap().input.clear();
+        for c in "2026-09-01 2026-09-07x".chars() {
+            app.history_range_prompt_char(c);
+        }
+        assert!(app.apply_history_range_prompt());
+        assert_eq!(
+            app.history_filter.period,
+            HistoryPeriod::Custom {
+                start: date!(2026 - 09 - 01),
+                end: date!(2026 - 09 - 07)
+            }
+        );
+        assert!(app.history_range_prompt.is_none());
+
+        app.page_history(true);
+        assert_eq!(
+            app.history_filter.range(date!(2026 - 10 - 16)),
+            (date!(2026 - 08 - 25), date!(2026 - 08 - 31))
+        );
+
+        app.open_history_range_prompt();
+        app.history_range_prompt_backspace();
+        app.history_range_prompt_char('9');
+        app.history_range_prompt.as_mut().unwrap().input = "nope".to_string();
+        assert!(!app.apply_history_range_prompt());
+        assert!(app.history_range_prompt.as_ref().unwrap().error.is_some());
+    }
+
+    #[test]
+    fn search_fuzzy_matches_note_project_and_activity() {
+        let today = crate::time_utils::local_today();
+        let mut app = test_app();
+        app.update_history(vec![
+            day_entry("1", "Toki", "Development", today, 1.0, Some("Login page")),
+            day_entry("2", "Acme", "Meetings", today, 1.0, Some("Standup")),
+            day_entry("3", "Acme", "Development", today, 1.0, Some("Invoices")),
+        ]);
+        app.rebuild_history_list();
+
+        app.start_history_search();
+        for c in "acme dev".chars() {
+            app.history_search_char(c);
+        }
+        assert_eq!(listed_ids(&app), ["3"]);
+        assert_eq!(app.focused_history_index, Some(0));
+
+        app.clear_history_search();
+        for c in "stndp".chars() {
+            app.history_search_char(c);
+        }
+        assert_eq!(listed_ids(&app), ["2"]);
+        assert_eq!(app.history_filter.describe(today), "last 30 days · /stndp");
+
+        app.clear_history_search();
+        assert_eq!(listed_ids(&app).len(), 3);
+        assert!(!app.history_filter.is_active());
+    }
+
+    #[test]
+    fn quick_filters_narrow_by_period_and_project() {
+        let today = crate::time_utils::local_today();
+        let last_week = today - time::Duration::days(7);
+        let mut app = test_app();
+        app.update_history(vec![
+            day_entry("1", "Toki", "Development", today, 1.0, Some("")),
+            day_entry("2", "Acme", "Development", today, 1.0, Some("")),
+            day_entry("3", "Toki", "Development", last_week, 1.0, Some("")),
+        ]);
+        app.rebuild_history_list();
+
+        app.cycle_history_period();
+        assert_eq!(app.history_filter.period, HistoryPeriod::Week);
+        assert_eq!(listed_ids(&app).len(), 2);
+
+        app.cycle_history_period();
+        app.cycle_history_period();
+        app.focused_history_index = Some(0);
+        app.toggle_history_project_filter();
+        assert_eq!(app.history_filter.project.as_deref(), Some("Toki"));
+        assert_eq!(listed_ids(&app), ["1", "3"]);
+
+        app.toggle_history_project_filter();
+        assert_eq!(listed_ids(&app).len(), 3);
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+/// Time the timer ran while nobody was at the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdlePeriod {
+    pub since: OffsetDateTime,
+    pub until: OffsetDateTime,
+}
+
+impl IdlePeriod {
+    pub fn duration(&self) -> time::Duration {
+        self.until - self.since
+    }
+
+    pub fn minutes(&self) -> i64 {
+        self.duration().whole_minutes()
+    }
+}
+
+impl App {
+    /// Note a key press at `now`. Returns true when it is the first one after
+    /// being idle while the timer ran; the idle prompt is then open and the
+    /// key should not be handled further.
+    pub fn register_input(&mut self, now: OffsetDateTime) -> bool {
+        let last_input = std::mem::replace(&mut self.last_input_at, now);
+        self.open_idle_prompt_if_idle(last_input, now)
+    }
+
+    /// Called on every pass of the event loop. A long gap since the last
+    /// pass means the computer was asleep, which counts as idle too.
+    pub fn register_tick(&mut self, now: OffsetDateTime) {
+        let last_tick = std::mem::replace(&mut self.last_tick_at, now);
+        if self.open_idle_prompt_if_idle(last_tick, now) {
+            self.last_input_at = now;
+        }
+    }
+
+    fn open_idle_prompt_if_idle(&mut self, since: OffsetDateTime, now: OffsetDateTime) -> bool {
+        let Some(threshold) = self.idle_threshold else {
+            return false;
+        };
+        // A timer owned by another device is being kept by someone else.
+        if self.timer_state != TimerState::Running
+            || self.timer_running_elsewhere().is_some()
+            || self.current_view != View::Timer
+            || self.is_in_edit_mode()
+        {
+            return false;
+        }
+
+        // Only the part of the gap the timer was running for matters.
+        let since = match self.absolute_start {
+            Some(start) => since.max(start),
+            None => since,
+        };
+        if now - since < threshold {
+            return false;
+        }
+
+        self.idle_period = Some(IdlePeriod { since, until: now });
+        self.selected_idle_action = IdleAction::Keep;
+        self.navigate_to(View::IdlePrompt);
+        true
+    }
+
+    pub fn select_next_idle_action(&mut self) {
+        self.selected_idle_action = match self.selected_idle_action {
+            IdleAction::Keep => IdleAction::Discard,
+            IdleAction::Discard => IdleAction::Split,
+            IdleAction::Split => IdleAction::Keep,
+        };
+    }
+
+    pub fn select_previous_idle_action(&mut self) {
+        self.selected_idle_action = match self.selected_idle_action {
+            IdleAction::Keep => IdleAction::Split,
+            IdleAction::Discard => IdleAction::Keep,
+            IdleAction::Split => IdleAction::Discard,
+        };
+    }
+
+    pub fn select_idle_action_by_number(&mut self, num: u32) {
+        self.selected_idle_action = match num {
+            1 => IdleAction::Keep,
+            2 => IdleAction::Discard,
+            3 => IdleAction::Split,
+            _ => return,
+        };
+    }
+
+    /// Move the running timer's start forward by `by`, so it no longer counts
+    /// that time. Returns the timer's previous start.
+    pub fn shift_timer_start(&mut self, by: time::Duration) -> Option<OffsetDateTime> {
+        let previous = self.absolute_start?;
+        let new_start = (previous + by).min(OffsetDateTime::now_utc());
+        self.absolute_start = Some(new_start);
+        let elapsed = (OffsetDateTime::now_utc() - new_start)
+            .whole_seconds()
+            .max(0) as u64;
+        self.local_start = Some(Instant::now() - Duration::from_secs(elapsed));
+        Some(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn idle_app() -> App {
+        let mut app = test_app();
+        app.idle_threshold = Some(time::Duration::minutes(15));
+        app.start_timer(false);
+        app.absolute_start = Some(OffsetDateTime::now_utc() - time::Duration::hours(2));
+        app
+    }
+
+    #[test]
+    fn key_press_after_long_pause_opens_prompt() {
+        let mut app = idle_app();
+        let now = OffsetDateTime::now_utc();
+        app.last_input_at = now - time::Duration::minutes(47);
+
+        assert!(app.register_input(now));
+
+        assert_eq!(app.current_view, View::IdlePrompt);
+        assert_eq!(app.idle_period.map(|idle| idle.minutes()), Some(47));
+        assert!(!app.register_input(now));
+    }
+
+    #[test]
+    fn short_pauses_and_stopped_timers_are_not_idle() {
+        let mut app = idle_app();
+        let now = OffsetDateTime::now_utc();
+        app.last_input_at = now - time::Duration::minutes(5);
+        assert!(!app.register_input(now));
+
+        app.stop_timer(false);
+        app.last_input_at = now - time::Duration::hours(1);
+        assert!(!app.register_input(now));
+        assert_eq!(app.current_view, View::Timer);
+    }
+
+    #[test]
+    fn sleeping_counts_as_idle_from_the_last_tick() {
+        let mut app = idle_app();
+        let now = OffsetDateTime::now_utc();
+        app.last_tick_at = now - time::Duration::minutes(30);
+        app.last_input_at = now - time::Duration::minutes(31);
+
+        app.register_tick(now);
+
+        assert_eq!(app.idle_period.map(|idle| idle.minutes()), Some(30));
+        assert_eq!(app.last_input_at, now);
+    }
+
+    #[test]
+    fn idle_time_is_counted_from_the_timer_start() {
+        let mut app = idle_app();
+        let now = OffsetDateTime::now_utc();
+        app.absolute_start = Some(now - time::Duration::minutes(20));
+        app.last_input_at = now - time::Duration::hours(3);
+
+        assert!(app.register_input(now));
+        assert_eq!(app.idle_period.map(|idle| idle.minutes()), Some(20));
+    }
+}
@@ -1,22 +1,71 @@
 use crate::config::TokiConfig;
 use crate::time_utils::to_local_time;
-use crate::types::{Activity, Project, TimeEntry};
+use crate::types::{
+    Activity, BudgetStatus, CalendarSuggestion, Favorite, FlexSnapshot, Project, PullRequest,
+    PullRequestChange, SprintCapacity, TimeEntry, WorkItemTimeMapping,
+};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 use time::OffsetDateTime;
 
+mod auto_stop;
+mod board;
+mod calendar;
+mod calendar_suggestions;
+mod copy;
 mod edit;
+mod favorites;
+mod fill_workdays;
 mod history;
+mod history_filter;
+mod idle;
+mod mouse;
 mod navigation;
+mod overlaps;
+mod paused_timers;
+mod pomodoro;
+mod pull_requests;
+mod recovery;
+mod recurring;
+mod split;
+mod sprint_capacity;
 mod state;
+mod statistics;
+mod templates;
+mod undo;
+mod week_checklist;
+mod work_items;
+pub use board::BoardLane;
+pub use calendar::{first_of_month, last_of_month, shift_month, CalendarDay, DayFill};
+pub use calendar_suggestions::suggestion_label;
+pub use copy::{parse_copy_date, CopyPrompt};
+pub use favorites::FAVORITE_SHORTCUTS;
+pub use fill_workdays::{FillStep, FillWizard};
 pub use history::parse_date_str;
+pub use history_filter::{HistoryFilter, HistoryRangePrompt, RECENT_DAYS};
+pub use idle::IdlePeriod;
+pub use mouse::ClickTarget;
+pub use overlaps::{OverlapFix, OverlapResolution};
+pub use paused_timers::paused_timer_label;
+pub use pomodoro::{Pomodoro, PomodoroPhase, PomodoroSettings};
+pub use recovery::UnsavedState;
+pub use recurring::RecurringOccurrence;
+pub use split::{parse_split_time, SplitPrompt};
+pub use sprint_capacity::sprint_capacity_usage;
 pub use state::{
-    DailyProjectStat, DayStat, DeleteContext, DeleteOrigin, EntryEditField, EntryEditState,
-    FocusedBox, GitContext, ProjectStat, SaveAction, TaskEntry, TaskwarriorOverlay, TextInput,
-    TimerSize, TimerState, View,
+    BoardProject, DailyProjectStat, DayStat, DeleteContext, DeleteOrigin, EntryEditField,
+    EntryEditState, FocusedBox, GitContext, IdleAction, ProjectStat, SaveAction, TaskEntry,
+    TaskwarriorOverlay, TextInput, TimerSize, TimerState, View, WorkItemOverlay,
 };
+pub use statistics::{StatsPeriod, StatsSpan};
+pub use templates::template_name;
+pub use undo::{EntryChange, UndoRecord, UNDO_WINDOW};
+pub use week_checklist::ChecklistItem;
+
+/// Weeks of flex balance charted in the statistics view.
+pub const FLEX_TREND_WEEKS: i64 = 8;
 
 pub struct App {
     pub running: bool,
@@ -53,6 +102,13 @@ pub struct App {
     pub filtered_activities: Vec<Activity>,
     pub filtered_activity_index: usize,
 
+    /// Pinned project and activity combinations, quick-selected with 1-9.
+    pub favorites: Vec<Favorite>,
+
+    // Meetings from the calendar feed with nothing logged over them
+    pub calendar_suggestions: Vec<CalendarSuggestion>,
+    pub selected_suggestion_index: usize,
+
     // Whether focus is on the result list (vs the search input) in selection views
     pub selection_list_focused: bool,
 
@@ -65,6 +121,8 @@ pub struct App {
     pub description_is_default: bool,
     pub saved_timer_note: Option<String>, // Saved when editing entry note to restore later
     pub pending_edit_selection_restore: Option<(Option<Project>, Option<Activity>)>,
+    /// Timer selection to restore once marked history entries are reassigned.
+    pub reassigning_marked: Option<(Option<Project>, Option<Activity>)>,
 
     // Today box navigation (This Week view)
     pub focused_this_week_index: Option<usize>,
@@ -77,10 +135,35 @@ pub struct App {
     pub history_edit_state: Option<EntryEditState>,
     pub history_list_entries: Vec<usize>, // Indices into time_entries for entries (excludes date separators)
     pub history_view_height: usize, // Last-rendered inner height (updated by renderer each frame)
+    pub history_filter: HistoryFilter,
+    /// Oldest day loaded into time_entries, once history is loaded.
+    pub history_loaded_from: Option<time::Date>,
+    pub history_range_prompt: Option<HistoryRangePrompt>,
 
     // Delete confirmation
     pub delete_context: Option<DeleteContext>,
 
+    // Pull request review
+    pub pull_requests: Vec<PullRequest>,
+    pub selected_pull_request_index: usize,
+    pub pull_request_changes: Vec<PullRequestChange>,
+    pub pull_request_detail_scroll: u16,
+
+    // Board
+    /// Columns of the `[board]` team's taskboard with their items.
+    pub board_lanes: Vec<BoardLane>,
+    pub board_column_index: usize,
+    pub board_item_index: usize,
+    /// Column picked in the move prompt, `Some(_)` while it is open.
+    pub board_move_target: Option<usize>,
+    /// Project and activity timers started from the board are tracked on.
+    pub board_time_mapping: Option<WorkItemTimeMapping>,
+
+    // Calendar
+    /// First day of the month shown in the calendar view.
+    pub calendar_month: time::Date,
+    pub calendar_entries: Vec<TimeEntry>,
+
     // Git context for note editor
     pub git_context: GitContext,
     pub git_mode: bool,
@@ -88,6 +171,7 @@ pub struct App {
     pub cwd_input: Option<TextInput>, // Some(_) when changing directory
     pub cwd_completions: Vec<String>, // Tab completion candidates
     pub taskwarrior_overlay: Option<TaskwarriorOverlay>,
+    pub work_item_overlay: Option<WorkItemOverlay>,
 
     // Loading indicator
     pub is_loading: bool,
@@ -95,29 +179,104 @@ pub struct App {
 
     // Scheduled hours per week from the time tracking backend (defaults to 40.0 until fetched)
     pub scheduled_hours_per_week: f64,
+    // Absence (vacation, sick leave) this week, which counts towards scheduled hours
+    pub absence_hours_this_week: f64,
 
     // Activity cache: project_id -> fetched activities
     pub activity_cache: HashMap<String, Vec<Activity>>,
 
-    // Statistics cache — computed once per history update, used every render frame
-    pub weekly_stats_cache: Vec<ProjectStat>,
-    pub weekly_daily_stats_cache: Vec<DayStat>,
+    // Statistics cache — computed once per history or period update, used every render frame
+    pub statistics_period: StatsPeriod,
+    /// Entries of a statistics period outside the loaded history, `None` when
+    /// the loaded history covers it.
+    pub statistics_entries: Option<Vec<TimeEntry>>,
+    /// Project whose activities the statistics view breaks down, `None` for
+    /// the per-project overview.
+    pub statistics_project: Option<String>,
+    /// Selected slice in the statistics view, an index into project_stats_cache.
+    pub statistics_selected: usize,
+    pub project_stats_cache: Vec<ProjectStat>,
+    pub daily_stats_cache: Vec<DayStat>,
+    /// Project budgets set in toki, loaded when the statistics view opens.
+    pub budget_statuses: Vec<BudgetStatus>,
+    /// Recorded flex balances of the last [`FLEX_TREND_WEEKS`] weeks, loaded
+    /// when the statistics view opens.
+    pub flex_history: Vec<FlexSnapshot>,
+    /// Capacity against logged hours in the `[board]` team's recent sprints,
+    /// newest first, loaded when the statistics view opens.
+    pub sprint_capacities: Vec<SprintCapacity>,
 
     // Config values used at runtime
     pub task_filter: String,
     pub git_default_prefix: String,
     pub auto_resize_timer: bool,
+    /// First day of the week the attest checklist is shown, `None` when off.
+    pub attest_reminder_day: Option<time::Weekday>,
+    pub pomodoro_settings: PomodoroSettings,
+    /// Pomodoro cycle over the running timer, `None` when pomodoro mode is off.
+    pub pomodoro: Option<Pomodoro>,
+    /// Directory history exports are written to, `None` when none was found.
+    pub export_dir: Option<std::path::PathBuf>,
+    pub export_format: crate::export::ExportFormat,
+    /// Rounding of entry times, `None` when off.
+    pub rounding: Option<crate::rounding::Rounding>,
+    /// Azure DevOps project from `[board]`, `None` when not configured.
+    pub board_project: Option<BoardProject>,
+
+    // Idle detection
+    /// How long without input, or asleep, counts as idle. `None` when off.
+    pub idle_threshold: Option<time::Duration>,
+    pub last_input_at: OffsetDateTime,
+    pub last_tick_at: OffsetDateTime,
+    /// Idle time waiting for the user to decide what to do with it.
+    pub idle_period: Option<IdlePeriod>,
+    pub selected_idle_action: IdleAction,
+
+    /// Open while asking where to split the entry being edited.
+    pub split_prompt: Option<SplitPrompt>,
+    /// Open while asking which day to copy the entry being edited to.
+    pub copy_prompt: Option<CopyPrompt>,
+    /// Open while proposing fixes for the overlaps on one history day.
+    pub overlap_resolution: Option<OverlapResolution>,
+    /// Registration ids of history entries marked for merging, deleting or reassigning.
+    pub marked_entries: HashSet<String>,
+    /// Recent deletes and edits, latest last, reverted with `u`.
+    pub undo_stack: Vec<UndoRecord>,
+    /// Undone changes, latest last, redone with `U`.
+    pub redo_stack: Vec<UndoRecord>,
+
+    // Timer ownership
+    /// Name this TUI sends to the server, see `TokiConfig::device_name`.
+    pub device_name: String,
+    /// Device that owns the running timer, as last reported by the server.
+    pub timer_device: Option<String>,
+    /// Timers paused with `i`, the most recently paused first.
+    pub paused_timers: Vec<crate::types::PausedTimer>,
+
+    /// Announcement from the server, shown in the header.
+    pub status_banner: Option<crate::types::StatusBanner>,
+
+    /// Changes made while the server was unreachable, waiting to be replayed.
+    pub offline_queue: crate::offline_queue::OfflineQueue,
 
     // Templates
     pub templates: Vec<crate::config::TemplateConfig>,
     pub template_search_input: TextInput,
     pub filtered_templates: Vec<crate::config::TemplateConfig>,
     pub filtered_template_index: usize,
+    pub recurring_templates: Vec<crate::config::RecurringTemplateConfig>,
+    /// Default project for filling workdays with nothing logged.
+    pub fill_default: crate::config::FillConfig,
+    /// Missing workdays wizard, open in the fill workdays view.
+    pub fill_wizard: Option<FillWizard>,
 
     /// Set to true after leaving/re-entering the alternate screen (e.g. after spawning an editor).
     /// The event loop will call terminal.clear() to force a full redraw when this is true.
     pub needs_full_redraw: bool,
 
+    /// Clickable regions of the last rendered frame, registered by the renderer.
+    pub click_targets: Vec<(ratatui::layout::Rect, ClickTarget)>,
+
     /// When a log note is linked to the current description, this holds the 8-char hex ID.
     /// The `·log:<id>` tag is stripped from `description_input` while editing so the user
     /// sees (and edits) only the clean summary. The tag is re-appended when the editor
@@ -128,6 +287,10 @@ pub struct App {
     /// `description_log_id` changes. Used by the render path to avoid per-frame
     /// synchronous file I/O.
     pub cached_log_content: Option<String>,
+
+    /// Work item picked with Ctrl+W for the running timer. The entry is linked
+    /// to it when the timer is saved.
+    pub description_work_item_id: Option<String>,
 }
 
 impl App {
@@ -157,6 +320,9 @@ impl App {
             activity_search_input: TextInput::new(),
             filtered_activities: Vec::new(),
             filtered_activity_index: 0,
+            favorites: Vec::new(),
+            calendar_suggestions: Vec::new(),
+            selected_suggestion_index: 0,
             selection_list_focused: false,
             selected_save_action: SaveAction::SaveAndStop,
             description_input: TextInput::new(),
@@ -164,6 +330,7 @@ impl App {
             description_is_default: true,
             saved_timer_note: None,
             pending_edit_selection_restore: None,
+            reassigning_marked: None,
             focused_this_week_index: None,
             this_week_edit_state: None,
             this_week_scroll: 0,
@@ -172,7 +339,21 @@ impl App {
             history_edit_state: None,
             history_list_entries: Vec::new(),
             history_view_height: 0,
+            history_filter: HistoryFilter::default(),
+            history_loaded_from: None,
+            history_range_prompt: None,
             delete_context: None,
+            pull_requests: Vec::new(),
+            selected_pull_request_index: 0,
+            pull_request_changes: Vec::new(),
+            pull_request_detail_scroll: 0,
+            board_lanes: Vec::new(),
+            board_column_index: 0,
+            board_item_index: 0,
+            board_move_target: None,
+            board_time_mapping: None,
+            calendar_month: first_of_month(crate::time_utils::local_today()),
+            calendar_entries: Vec::new(),
             git_context: GitContext::from_cwd(
                 std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from(".")),
             ),
@@ -181,22 +362,62 @@ impl App {
             cwd_input: None,
             cwd_completions: Vec::new(),
             taskwarrior_overlay: None,
+            work_item_overlay: None,
             is_loading: false,
             throbber_state: throbber_widgets_tui::ThrobberState::default(),
             scheduled_hours_per_week: 40.0,
+            absence_hours_this_week: 0.0,
             activity_cache: HashMap::new(),
-            weekly_stats_cache: Vec::new(),
-            weekly_daily_stats_cache: Vec::new(),
+            statistics_period: StatsPeriod::containing(
+                StatsSpan::Week,
+                crate::time_utils::local_today(),
+            ),
+            statistics_entries: None,
+            statistics_project: None,
+            statistics_selected: 0,
+            project_stats_cache: Vec::new(),
+            daily_stats_cache: Vec::new(),
+            budget_statuses: Vec::new(),
+            flex_history: Vec::new(),
+            sprint_capacities: Vec::new(),
             task_filter: cfg.task_filter.clone(),
             git_default_prefix: cfg.git_default_prefix.clone(),
             auto_resize_timer: cfg.auto_resize_timer,
+            attest_reminder_day: cfg.attest_reminder_day(),
+            pomodoro_settings: cfg.pomodoro.settings(),
+            pomodoro: None,
+            export_dir: cfg.export_dir(),
+            export_format: cfg.export_format(),
+            rounding: cfg.rounding.rounding(),
+            board_project: cfg.board.board_project(),
+            idle_threshold: cfg.idle_threshold(),
+            last_input_at: OffsetDateTime::now_utc(),
+            last_tick_at: OffsetDateTime::now_utc(),
+            idle_period: None,
+            selected_idle_action: IdleAction::Keep,
+            split_prompt: None,
+            copy_prompt: None,
+            overlap_resolution: None,
+            marked_entries: HashSet::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            device_name: cfg.device_name(),
+            timer_device: None,
+            paused_timers: Vec::new(),
+            status_banner: None,
+            offline_queue: Default::default(),
             templates: cfg.template.clone(),
             template_search_input: TextInput::new(),
             filtered_templates: Vec::new(),
             filtered_template_index: 0,
+            recurring_templates: cfg.recurring.clone(),
+            fill_default: cfg.fill.clone(),
+            fill_wizard: None,
             needs_full_redraw: false,
+            click_targets: Vec::new(),
             description_log_id: None,
             cached_log_content: None,
+            description_work_item_id: None,
         }
     }
 
@@ -224,6 +445,7 @@ impl App {
         self.description_is_default = true;
         self.description_log_id = None;
         self.cached_log_content = None;
+        self.description_work_item_id = None;
         self.status_message = Some("Note cleared".to_string());
     }
 
@@ -238,6 +460,8 @@ impl App {
         self.description_is_default = true;
         self.description_log_id = None;
         self.cached_log_content = None;
+        self.description_work_item_id = None;
+        self.timer_device = None;
         self.status_message = Some("Timer cleared".to_string());
     }
 
@@ -265,7 +489,7 @@ impl App {
                     None => return,
                 };
                 DeleteContext {
-                    registration_id: e.registration_id.clone(),
+                    registration_ids: vec![e.registration_id.clone()],
                     display_label: format!("{} / {}", e.project_name, e.activity_name),
                     display_date: e.date.clone(),
                     display_hours: e.hours,
@@ -286,7 +510,7 @@ impl App {
                     None => return,
                 };
                 DeleteContext {
-                    registration_id: e.registration_id.clone(),
+                    registration_ids: vec![e.registration_id.clone()],
                     display_label: format!("{} / {}", e.project_name, e.activity_name),
                     display_date: e.date.clone(),
                     display_hours: e.hours,
@@ -303,6 +527,8 @@ impl App {
         self.timer_state = TimerState::Running;
         self.absolute_start = Some(OffsetDateTime::now_utc());
         self.local_start = Some(Instant::now());
+        self.timer_device = Some(self.device_name.clone());
+        self.reset_pomodoro();
         if auto_resize {
             self.timer_size = TimerSize::Large;
         }
@@ -320,6 +546,7 @@ impl App {
         }
         self.absolute_start = None;
         self.local_start = None;
+        self.timer_device = None;
         // Shift focus back: running timer row at index 0 is removed, pushing DB entries down by 1
         if let Some(idx) = self.focused_this_week_index {
             self.focused_this_week_index = if idx == 0 {
@@ -330,6 +557,17 @@ impl App {
         }
     }
 
+    /// The other device running the timer, when it isn't this one.
+    pub fn timer_running_elsewhere(&self) -> Option<&str> {
+        match self.timer_state {
+            TimerState::Running => self
+                .timer_device
+                .as_deref()
+                .filter(|device| *device != self.device_name),
+            TimerState::Stopped => None,
+        }
+    }
+
     pub fn set_status(&mut self, message: String) {
         self.status_message = Some(message);
     }
@@ -384,10 +622,7 @@ impl App {
         self.time_entries = entries;
         self.history_scroll = 0;
         self.compute_overlaps();
-        // Recompute statistics caches — these are expensive (multiple passes over history)
-        // and are called every render frame, so we compute once here and serve cached values.
-        self.weekly_stats_cache = self.weekly_project_stats();
-        self.weekly_daily_stats_cache = self.weekly_daily_stats();
+        self.refresh_statistics();
     }
 
     /// Load projects and activities derived from timer history (via HTTP API).
@@ -539,23 +774,42 @@ impl App {
                 }
             }
             View::SelectActivity => {
-                if let Some(activity) = self.filtered_activities.get(self.filtered_activity_index) {
-                    self.selected_activity = Some(activity.clone());
+                if let Some(activity) = self
+                    .filtered_activities
+                    .get(self.filtered_activity_index)
+                    .cloned()
+                {
                     self.set_status(format!("Selected activity: {}", activity.name));
-                    if self.description_is_default {
-                        self.navigate_to(View::EditDescription);
-                    } else {
-                        self.navigate_to(View::Timer);
-                        self.focused_box = FocusedBox::Description;
-                    }
+                    self.select_activity(activity);
                 }
             }
             _ => {}
         }
     }
 
+    /// Make `activity` the selected activity and move on to the note, or back
+    /// to the timer when the note is already set.
+    fn select_activity(&mut self, activity: Activity) {
+        self.selected_activity = Some(activity);
+        if self.reassigning_marked.is_some() {
+            return;
+        }
+        if self.description_is_default {
+            self.navigate_to(View::EditDescription);
+        } else {
+            self.navigate_to(View::Timer);
+            self.focused_box = FocusedBox::Description;
+        }
+    }
+
     /// Cancel current selection and return to timer view
     pub fn cancel_selection(&mut self) {
+        if let Some((restore_project, restore_activity)) = self.reassigning_marked.take() {
+            self.selected_project = restore_project;
+            self.selected_activity = restore_activity;
+            self.navigate_to(View::History);
+            return;
+        }
         if let Some((restore_project, restore_activity)) =
             self.pending_edit_selection_restore.take()
         {
@@ -573,6 +827,63 @@ impl App {
             .unwrap_or_else(|| "[None]".to_string())
     }
 
+    /// Warning to show when the selected project has used most of its budget.
+    pub fn selected_project_budget_warning(&self) -> Option<String> {
+        let selected = self.selected_project.as_ref()?;
+        let budget = self
+            .projects
+            .iter()
+            .find(|project| project.id == selected.id)?
+            .budget?;
+        if !budget.is_nearly_consumed() {
+            return None;
+        }
+        Some(format!(
+            "Warning: {} has used {:.0}% of its budget ({:.1}h left)",
+            selected.name,
+            budget.consumption() * 100.0,
+            budget.remaining_hours()
+        ))
+    }
+
+    /// Flex history as `(days before today, flex hours)` chart points,
+    /// where `today` is at 0 and older snapshots are negative.
+    pub fn flex_trend_points(&self, today: time::Date) -> Vec<(f64, f64)> {
+        self.flex_history
+            .iter()
+            .filter_map(|snapshot| {
+                let date = parse_date_str(&snapshot.date)?;
+                Some(((date - today).whole_days() as f64, snapshot.flex_hours))
+            })
+            .collect()
+    }
+
+    /// Warning naming the budgets that have used most of their hours this
+    /// period.
+    pub fn budget_statuses_warning(&self) -> Option<String> {
+        let nearly_used: Vec<String> = self
+            .budget_statuses
+            .iter()
+            .filter(|status| status.nearly_used)
+            .map(|status| {
+                format!(
+                    "{} {:.1}/{:.1}h this {}",
+                    status.budget.project_name,
+                    status.used_hours,
+                    status.budget.target_hours,
+                    status.budget.period
+                )
+            })
+            .collect();
+        if nearly_used.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "Warning: budget nearly used: {}",
+            nearly_used.join(", ")
+        ))
+    }
+
     /// Get current activity name for display
     pub fn current_activity_name(&self) -> String {
         self.selected_activity
@@ -714,6 +1025,17 @@ impl App {
         }
     }
 
+    /// Replace the note being edited with a suggested one, unless the user
+    /// changed it while the suggestion was loading. Returns whether it was applied.
+    pub fn apply_note_suggestion(&mut self, original: &str, suggestion: &str) -> bool {
+        if !self.editing_description || self.description_input.value != original {
+            return false;
+        }
+        self.description_input = TextInput::from_str(suggestion);
+        self.set_status(format!("Note improved (was: {original})"));
+        true
+    }
+
     /// Confirm description edit
     pub fn confirm_description(&mut self) {
         self.editing_description = false;
@@ -1298,6 +1620,79 @@ mod tests {
         assert_eq!(app.focused_this_week_index, Some(2));
     }
 
+    #[test]
+    fn budget_warning_only_for_nearly_consumed_projects() {
+        let mut app = test_app();
+        let mut nearly_used = project("proj-1", "Project One");
+        nearly_used.budget = Some(crate::types::ProjectBudget {
+            budget_hours: 100.0,
+            used_hours: 92.0,
+        });
+        let mut plenty_left = project("proj-2", "Project Two");
+        plenty_left.budget = Some(crate::types::ProjectBudget {
+            budget_hours: 100.0,
+            used_hours: 40.0,
+        });
+        app.projects = vec![nearly_used, plenty_left];
+
+        app.selected_project = Some(project("proj-1", "Project One"));
+        assert_eq!(
+            app.selected_project_budget_warning().as_deref(),
+            Some("Warning: Project One has used 92% of its budget (8.0h left)")
+        );
+
+        app.selected_project = Some(project("proj-2", "Project Two"));
+        assert_eq!(app.selected_project_budget_warning(), None);
+    }
+
+    #[test]
+    fn budget_statuses_warning_lists_nearly_used_budgets() {
+        let status = |name: &str, period: &str, used_hours: f64, nearly_used: bool| {
+            crate::types::BudgetStatus {
+                budget: toki_types::ProjectBudgetResponse {
+                    id: 1,
+                    project_id: name.to_lowercase(),
+                    project_name: name.to_string(),
+                    period: period.to_string(),
+                    target_hours: 10.0,
+                },
+                from: "2026-10-12".to_string(),
+                to: "2026-10-18".to_string(),
+                used_hours,
+                nearly_used,
+            }
+        };
+        let mut app = test_app();
+        app.budget_statuses = vec![status("Toki", "week", 4.0, false)];
+        assert_eq!(app.budget_statuses_warning(), None);
+
+        app.budget_statuses.push(status("Acme", "month", 9.5, true));
+        assert_eq!(
+            app.budget_statuses_warning().as_deref(),
+            Some("Warning: budget nearly used: Acme 9.5/10.0h this month")
+        );
+    }
+
+    #[test]
+    fn flex_trend_points_are_days_before_today() {
+        let snapshot = |date: &str, flex_hours: f64| crate::types::FlexSnapshot {
+            date: date.to_string(),
+            flex_hours,
+        };
+        let mut app = test_app();
+        app.flex_history = vec![
+            snapshot("2026-10-04", -1.5),
+            snapshot("not a date", 9.0),
+            snapshot("2026-10-16", 2.0),
+        ];
+
+        let today = time::Date::from_calendar_date(2026, time::Month::October, 16).unwrap();
+        assert_eq!(
+            app.flex_trend_points(today),
+            vec![(-12.0, -1.5), (0.0, 2.0)]
+        );
+    }
+
     #[test]
     fn clear_timer_resets_selected_fields_and_note() {
         let mut app = test_app();
@@ -1331,6 +1726,19 @@ mod tests {
         assert!(!app.description_is_default);
     }
 
+    #[test]
+    fn note_suggestion_is_dropped_if_the_note_changed_meanwhile() {
+        let mut app = test_app();
+        app.navigate_to(View::EditDescription);
+        app.description_input = TextInput::from_str("fix login");
+
+        assert!(!app.apply_note_suggestion("fix", "Fixed the login redirect"));
+        assert_eq!(app.description_input.value, "fix login");
+
+        assert!(app.apply_note_suggestion("fix login", "Fixed the login redirect"));
+        assert_eq!(app.description_input.value, "Fixed the login redirect");
+    }
+
     #[test]
     fn select_save_action_by_number_ignores_unknown_values() {
         let mut app = test_app();
@@ -1438,4 +1846,68 @@ mod tests {
             .collect();
         assert_eq!(ids, vec!["reg-2", "reg-1"]);
     }
+
+    #[test]
+    fn entry_marks_toggle_and_skip_locked_entries() {
+        let mut app = test_app();
+        let today = crate::time_utils::local_today().to_string();
+        let entry = |id: &str| {
+            time_entry(
+                id,
+                "proj-1",
+                "Project One",
+                "act-1",
+                "Activity One",
+                &today,
+                1.0,
+                None,
+                None,
+                None,
+            )
+        };
+        let mut locked = entry("reg-3");
+        locked.status = toki_types::TimeEntryStatus::Approved;
+        app.update_history(vec![entry("reg-1"), entry("reg-2"), locked]);
+        app.rebuild_history_list();
+
+        app.focused_history_index = Some(0);
+        app.toggle_entry_mark();
+        app.focused_history_index = Some(1);
+        app.toggle_entry_mark();
+        app.focused_history_index = Some(2);
+        app.toggle_entry_mark();
+        assert_eq!(app.marked_history_entries().len(), 2);
+
+        app.focused_history_index = Some(0);
+        app.toggle_entry_mark();
+        assert_eq!(app.marked_history_entries().len(), 1);
+    }
+
+    #[test]
+    fn reassigning_marked_entries_keeps_the_timer_selection() {
+        let mut app = test_app();
+        app.selected_project = Some(project("proj-1", "Project One"));
+        app.selected_activity = Some(activity("act-1", "proj-1", "Activity One"));
+        app.marked_entries.insert("reg-1".to_string());
+
+        app.start_reassigning_marked();
+        assert_eq!(app.current_view, View::SelectProject);
+        app.selected_project = Some(project("proj-2", "Project Two"));
+        app.select_activity(activity("act-2", "proj-2", "Activity Two"));
+        assert_eq!(app.current_view, View::SelectProject);
+
+        let (picked_project, picked_activity) = app.finish_reassigning_marked().unwrap();
+        assert_eq!(picked_project.id, "proj-2");
+        assert_eq!(picked_activity.id, "act-2");
+        assert_eq!(app.current_view, View::History);
+        assert_eq!(app.selected_project.as_ref().unwrap().id, "proj-1");
+        assert_eq!(app.selected_activity.as_ref().unwrap().id, "act-1");
+
+        app.selected_project = None;
+        app.start_reassigning_marked();
+        app.cancel_selection();
+        assert_eq!(app.current_view, View::History);
+        assert!(app.selected_project.is_none());
+        assert!(app.reassigning_marked.is_none());
+    }
 }
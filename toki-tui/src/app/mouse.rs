@@ -0,0 +1,119 @@
+use super::*;
+use ratatui::layout::{Position, Rect};
+
+/// What a mouse click on a region of the last rendered frame does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClickTarget {
+    /// A box in the timer view.
+    FocusBox(FocusedBox),
+    /// A row in the This Week box, by its `focused_this_week_index`.
+    ThisWeekEntry(usize),
+}
+
+impl App {
+    /// Forget the click regions of the previous frame. Called before each render.
+    pub fn clear_click_targets(&mut self) {
+        self.click_targets.clear();
+    }
+
+    /// Make `area` of the frame being rendered clickable. Regions registered
+    /// later are drawn on top and win over earlier ones.
+    pub fn register_click_target(&mut self, area: Rect, target: ClickTarget) {
+        self.click_targets.push((area, target));
+    }
+
+    /// The topmost click region under the cell at `column`, `row`.
+    pub fn click_target_at(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        self.click_targets
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(Position::new(column, row)))
+            .map(|(_, target)| *target)
+    }
+
+    /// Focus whatever was clicked at `column`, `row`. Ignored while editing an
+    /// entry so a stray click can't drop the changes.
+    pub fn click_at(&mut self, column: u16, row: u16) {
+        if self.is_in_edit_mode() {
+            return;
+        }
+        match self.click_target_at(column, row) {
+            Some(ClickTarget::FocusBox(FocusedBox::Today)) => {
+                self.focused_box = FocusedBox::Today;
+                if self.focused_this_week_index.is_none() {
+                    self.this_week_focus_down();
+                }
+            }
+            Some(ClickTarget::FocusBox(focused_box)) => {
+                self.focused_box = focused_box;
+                self.focused_this_week_index = None;
+            }
+            Some(ClickTarget::ThisWeekEntry(index)) => {
+                self.focused_box = FocusedBox::Today;
+                self.focused_this_week_index = Some(index);
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+
+    #[test]
+    fn clicks_focus_the_topmost_region() {
+        let mut app = test_app();
+        app.register_click_target(
+            Rect::new(0, 0, 40, 3),
+            ClickTarget::FocusBox(FocusedBox::Description),
+        );
+        app.register_click_target(
+            Rect::new(0, 10, 40, 6),
+            ClickTarget::FocusBox(FocusedBox::Today),
+        );
+        app.register_click_target(Rect::new(1, 12, 38, 1), ClickTarget::ThisWeekEntry(2));
+
+        app.click_at(5, 1);
+        assert_eq!(app.focused_box, FocusedBox::Description);
+
+        app.click_at(5, 12);
+        assert_eq!(app.focused_box, FocusedBox::Today);
+        assert_eq!(app.focused_this_week_index, Some(2));
+
+        app.click_at(50, 12);
+        assert_eq!(app.focused_this_week_index, Some(2));
+
+        app.clear_click_targets();
+        assert_eq!(app.click_target_at(5, 1), None);
+    }
+
+    #[test]
+    fn clicks_are_ignored_while_editing() {
+        let mut app = test_app();
+        app.time_entries = vec![time_entry(
+            "r1",
+            "p1",
+            "Toki",
+            "a1",
+            "Development",
+            &crate::time_utils::local_today().to_string(),
+            2.0,
+            None,
+            None,
+            None,
+        )];
+        app.register_click_target(
+            Rect::new(0, 0, 40, 3),
+            ClickTarget::FocusBox(FocusedBox::Timer),
+        );
+        app.focused_box = FocusedBox::Today;
+        app.focused_this_week_index = Some(0);
+        app.enter_this_week_edit_mode();
+        assert!(app.is_in_edit_mode());
+
+        app.click_at(5, 1);
+        assert_eq!(app.focused_box, FocusedBox::Today);
+    }
+}
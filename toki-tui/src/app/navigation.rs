@@ -6,7 +6,10 @@ impl App {
         self.focused_box = match self.focused_box {
             FocusedBox::Timer => FocusedBox::ProjectActivity,
             FocusedBox::ProjectActivity => FocusedBox::Description,
-            FocusedBox::Description => FocusedBox::Today,
+            FocusedBox::Description if !self.calendar_suggestions.is_empty() => {
+                FocusedBox::Suggestions
+            }
+            FocusedBox::Description | FocusedBox::Suggestions => FocusedBox::Today,
             FocusedBox::Today => FocusedBox::Timer,
         };
     }
@@ -17,14 +20,15 @@ impl App {
             FocusedBox::Timer => FocusedBox::Today,
             FocusedBox::ProjectActivity => FocusedBox::Timer,
             FocusedBox::Description => FocusedBox::ProjectActivity,
-            FocusedBox::Today => FocusedBox::Description,
+            FocusedBox::Today if !self.calendar_suggestions.is_empty() => FocusedBox::Suggestions,
+            FocusedBox::Suggestions | FocusedBox::Today => FocusedBox::Description,
         };
     }
 
     /// Handle Enter key on focused box
     pub fn activate_focused_box(&mut self) {
         match self.focused_box {
-            FocusedBox::Timer => {}
+            FocusedBox::Timer | FocusedBox::Suggestions => {}
             FocusedBox::ProjectActivity => {
                 self.navigate_to(View::SelectProject);
             }
@@ -77,14 +81,14 @@ impl App {
         };
         let visible_count = db_count + running_offset;
         if visible_count == 0 {
-            self.focused_box = FocusedBox::Description;
+            self.focus_previous();
             self.focused_this_week_index = None;
             return;
         }
 
         if let Some(idx) = self.focused_this_week_index {
             if idx == 0 {
-                self.focused_box = FocusedBox::Description;
+                self.focus_previous();
                 self.focused_this_week_index = None;
             } else {
                 self.focused_this_week_index = Some(idx - 1);
@@ -0,0 +1,321 @@
+use super::*;
+
+/// A proposed change that stops an entry from overlapping its neighbours.
+#[derive(Debug, Clone)]
+pub enum OverlapFix {
+    /// Move the entry to `start`–`end`, keeping everything else.
+    Trim {
+        entry: TimeEntry,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    },
+    /// Stretch `keep` over the entries in `remove`, all on the same project
+    /// and activity, and delete them.
+    Merge {
+        keep: TimeEntry,
+        remove: Vec<TimeEntry>,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        note: String,
+    },
+}
+
+impl OverlapFix {
+    /// "Trim Toki / Development to 08:00–09:30", for the resolve prompt.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Trim { entry, start, end } => format!(
+                "Trim {} / {} to {}–{}",
+                entry.project_name,
+                entry.activity_name,
+                hh_mm(*start),
+                hh_mm(*end)
+            ),
+            Self::Merge {
+                keep,
+                remove,
+                start,
+                end,
+                ..
+            } => format!(
+                "Merge {} {} / {} entries into {}–{}",
+                remove.len() + 1,
+                keep.project_name,
+                keep.activity_name,
+                hh_mm(*start),
+                hh_mm(*end)
+            ),
+        }
+    }
+}
+
+fn hh_mm(dt: OffsetDateTime) -> String {
+    let local = to_local_time(dt);
+    format!("{:02}:{:02}", local.hour(), local.minute())
+}
+
+/// Fixes proposed for the overlapping entries of one day, applied with
+/// `Enter` in the resolve prompt.
+#[derive(Debug, Clone)]
+pub struct OverlapResolution {
+    /// "YYYY-MM-DD"
+    pub date: String,
+    pub fixes: Vec<OverlapFix>,
+    /// Overlaps left to fix by hand, where both entries are attested.
+    pub unresolved: usize,
+}
+
+/// An entry as it would be after the proposed fixes.
+struct Slot<'a> {
+    entry: &'a TimeEntry,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    notes: Vec<String>,
+    merged: Vec<&'a TimeEntry>,
+}
+
+impl Slot<'_> {
+    fn is_open(&self) -> bool {
+        !self.entry.status.is_locked()
+    }
+}
+
+/// Propose fixes for the overlaps between `day_entries`, all entries of one
+/// day. Going through the day in order, an entry overlapping the one before
+/// it is merged into it when both are on the same project and activity.
+/// Otherwise the earlier entry is trimmed to end where the later one starts,
+/// or the later one to start where the earlier ends if the earlier can't
+/// be trimmed. Attested entries are never changed.
+pub fn propose_overlap_fixes(day_entries: &[&TimeEntry]) -> (Vec<OverlapFix>, usize) {
+    let mut timed: Vec<(&TimeEntry, OffsetDateTime, OffsetDateTime)> = day_entries
+        .iter()
+        .filter_map(|entry| Some((*entry, entry.start_time?, entry.end_time?)))
+        .collect();
+    timed.sort_by_key(|(_, start, end)| (*start, *end));
+
+    let mut slots: Vec<Slot> = Vec::with_capacity(timed.len());
+    let mut unresolved = 0;
+    for (entry, start, end) in timed {
+        let mut next = Slot {
+            entry,
+            start,
+            end,
+            notes: entry.note.iter().cloned().collect(),
+            merged: Vec::new(),
+        };
+        let Some(prev) = slots.last_mut() else {
+            slots.push(next);
+            continue;
+        };
+        if next.start >= prev.end {
+            slots.push(next);
+            continue;
+        }
+
+        let same_activity = prev.entry.project_id == entry.project_id
+            && prev.entry.activity_id == entry.activity_id;
+        if same_activity && prev.is_open() && next.is_open() {
+            prev.end = prev.end.max(next.end);
+            prev.notes.append(&mut next.notes);
+            prev.merged.push(entry);
+        } else if prev.is_open() && prev.start < next.start {
+            prev.end = next.start;
+            slots.push(next);
+        } else if next.is_open() && next.end > prev.end {
+            next.start = prev.end;
+            slots.push(next);
+        } else {
+            unresolved += 1;
+            slots.push(next);
+        }
+    }
+
+    let fixes = slots
+        .into_iter()
+        .filter_map(|slot| {
+            if !slot.merged.is_empty() {
+                let mut notes: Vec<&str> = Vec::new();
+                for note in &slot.notes {
+                    let note = note.trim();
+                    if !note.is_empty() && !notes.contains(&note) {
+                        notes.push(note);
+                    }
+                }
+                return Some(OverlapFix::Merge {
+                    keep: slot.entry.clone(),
+                    remove: slot.merged.into_iter().cloned().collect(),
+                    start: slot.start,
+                    end: slot.end,
+                    note: notes.join("; "),
+                });
+            }
+            let moved =
+                Some(slot.start) != slot.entry.start_time || Some(slot.end) != slot.entry.end_time;
+            moved.then(|| OverlapFix::Trim {
+                entry: slot.entry.clone(),
+                start: slot.start,
+                end: slot.end,
+            })
+        })
+        .collect();
+    (fixes, unresolved)
+}
+
+impl App {
+    /// Propose fixes for the overlaps on the focused history entry's day.
+    pub fn open_overlap_resolution(&mut self) {
+        let Some(date) = self
+            .focused_history_index
+            .and_then(|idx| self.history_list_entries.get(idx))
+            .and_then(|&idx| self.time_entries.get(idx))
+            .map(|entry| entry.date.clone())
+        else {
+            return;
+        };
+        self.open_overlap_resolution_on(date);
+    }
+
+    /// Propose fixes for the overlaps on `date` ("YYYY-MM-DD").
+    pub fn open_overlap_resolution_on(&mut self, date: String) {
+        let day_entries: Vec<&TimeEntry> = self
+            .time_entries
+            .iter()
+            .filter(|entry| entry.date == date)
+            .collect();
+        if !day_entries
+            .iter()
+            .any(|entry| self.is_entry_overlapping(&entry.registration_id))
+        {
+            self.set_status(format!("No overlapping entries on {}", date));
+            return;
+        }
+
+        let (fixes, unresolved) = propose_overlap_fixes(&day_entries);
+        if fixes.is_empty() {
+            self.set_status(format!(
+                "The overlaps on {} are between attested entries",
+                date
+            ));
+            return;
+        }
+        self.overlap_resolution = Some(OverlapResolution {
+            date,
+            fixes,
+            unresolved,
+        });
+    }
+
+    pub fn close_overlap_resolution(&mut self) {
+        self.overlap_resolution = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::week_checklist::format_date;
+    use super::*;
+    use crate::test_support::{test_app, timed_entry};
+    use crate::time_utils::{local_date_time, local_today};
+    use time::Date;
+
+    fn day() -> Date {
+        local_today() - time::Duration::days(2)
+    }
+
+    fn at(hour: u8, minute: u8) -> OffsetDateTime {
+        local_date_time(day(), time::Time::from_hms(hour, minute, 0).unwrap())
+    }
+
+    /// (registration id, removed ids, start, end) of each fix.
+    fn summary(fixes: &[OverlapFix]) -> Vec<(&str, Vec<&str>, OffsetDateTime, OffsetDateTime)> {
+        fixes
+            .iter()
+            .map(|fix| match fix {
+                OverlapFix::Trim { entry, start, end } => {
+                    (entry.registration_id.as_str(), Vec::new(), *start, *end)
+                }
+                OverlapFix::Merge {
+                    keep,
+                    remove,
+                    start,
+                    end,
+                    ..
+                } => (
+                    keep.registration_id.as_str(),
+                    remove.iter().map(|e| e.registration_id.as_str()).collect(),
+                    *start,
+                    *end,
+                ),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn trims_the_earlier_entry_and_merges_the_same_activity() {
+        let standup = timed_entry("1", "Meetings", at(8, 0), at(9, 0), Some("Standup"));
+        let coding = timed_entry("2", "Development", at(8, 45), at(11, 0), Some("Login"));
+        let more_coding = timed_entry("3", "Development", at(10, 30), at(12, 0), Some("Tests"));
+        let planning = timed_entry("4", "Meetings", at(13, 0), at(14, 0), Some("Planning"));
+
+        let (fixes, unresolved) =
+            propose_overlap_fixes(&[&more_coding, &standup, &planning, &coding]);
+
+        assert_eq!(unresolved, 0);
+        assert_eq!(
+            summary(&fixes),
+            vec![
+                ("1", vec![], at(8, 0), at(8, 45)),
+                ("2", vec!["3"], at(8, 45), at(12, 0)),
+            ]
+        );
+        let OverlapFix::Merge { note, .. } = &fixes[1] else {
+            panic!("expected a merge");
+        };
+        assert_eq!(note, "Login; Tests");
+        assert_eq!(fixes[0].describe(), "Trim Toki / Meetings to 08:00–08:45");
+    }
+
+    #[test]
+    fn attested_entries_are_left_as_they_are() {
+        let mut attested = timed_entry("1", "Meetings", at(8, 0), at(9, 0), Some("Standup"));
+        attested.status = toki_types::TimeEntryStatus::Approved;
+        let coding = timed_entry("2", "Development", at(8, 30), at(10, 0), Some("Login"));
+
+        let (fixes, _) = propose_overlap_fixes(&[&attested, &coding]);
+        assert_eq!(summary(&fixes), vec![("2", vec![], at(9, 0), at(10, 0))]);
+
+        let mut also_attested = coding.clone();
+        also_attested.status = toki_types::TimeEntryStatus::Approved;
+        let (fixes, unresolved) = propose_overlap_fixes(&[&attested, &also_attested]);
+        assert!(fixes.is_empty());
+        assert_eq!(unresolved, 1);
+    }
+
+    #[test]
+    fn resolution_opens_for_the_focused_entrys_day() {
+        let mut app = test_app();
+        app.update_history(vec![
+            timed_entry("1", "Meetings", at(8, 0), at(9, 0), Some("Standup")),
+            timed_entry("2", "Development", at(8, 30), at(10, 0), Some("Login")),
+        ]);
+        app.rebuild_history_list();
+        app.focused_history_index = Some(0);
+
+        app.open_overlap_resolution();
+        let resolution = app.overlap_resolution.as_ref().unwrap();
+        assert_eq!(resolution.date, format_date(day()));
+        assert_eq!(resolution.fixes.len(), 1);
+
+        app.close_overlap_resolution();
+        app.update_history(vec![timed_entry(
+            "1",
+            "Meetings",
+            at(8, 0),
+            at(9, 0),
+            Some("Standup"),
+        )]);
+        app.rebuild_history_list();
+        app.open_overlap_resolution();
+        assert!(app.overlap_resolution.is_none());
+    }
+}
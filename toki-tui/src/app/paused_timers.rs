@@ -0,0 +1,83 @@
+use super::*;
+use crate::types::PausedTimer;
+
+/// "Toki / Development", or the note when no project is set.
+pub fn paused_timer_label(paused: &PausedTimer) -> String {
+    match (&paused.project_name, &paused.activity_name) {
+        (Some(project), Some(activity)) => format!("{} / {}", project, activity),
+        (Some(project), None) => project.clone(),
+        _ if !paused.note.is_empty() => paused.note.clone(),
+        _ => "no project".to_string(),
+    }
+}
+
+impl App {
+    /// Put a timer paused on the server on top of the paused stack.
+    pub fn push_paused_timer(&mut self, paused: PausedTimer) {
+        self.paused_timers.retain(|timer| timer.id != paused.id);
+        self.paused_timers.insert(0, paused);
+    }
+
+    /// Drop a paused timer once the server has resumed it.
+    pub fn remove_paused_timer(&mut self, id: i32) {
+        self.paused_timers.retain(|timer| timer.id != id);
+    }
+
+    /// "Paused: Toki / Development (1h 30m) +2 more", for the timer view.
+    /// `None` when nothing is paused.
+    pub fn paused_timers_summary(&self) -> Option<String> {
+        let top = self.paused_timers.first()?;
+        let mut summary = format!(
+            "Paused: {} ({}h {:02}m)",
+            paused_timer_label(top),
+            top.tracked_minutes / 60,
+            top.tracked_minutes % 60
+        );
+        if self.paused_timers.len() > 1 {
+            summary.push_str(&format!(" +{} more", self.paused_timers.len() - 1));
+        }
+        Some(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn paused(id: i32, project: Option<&str>, tracked_minutes: i64) -> PausedTimer {
+        PausedTimer {
+            id,
+            project_id: project.map(|_| "p1".to_string()),
+            project_name: project.map(str::to_string),
+            activity_id: project.map(|_| "a1".to_string()),
+            activity_name: project.map(|_| "Development".to_string()),
+            note: "Hotfix".to_string(),
+            segments: Vec::new(),
+            paused_at: OffsetDateTime::now_utc(),
+            tracked_minutes,
+        }
+    }
+
+    #[test]
+    fn summary_shows_the_latest_paused_timer_first() {
+        let mut app = test_app();
+        assert_eq!(app.paused_timers_summary(), None);
+
+        app.push_paused_timer(paused(1, Some("Toki"), 90));
+        assert_eq!(
+            app.paused_timers_summary().as_deref(),
+            Some("Paused: Toki / Development (1h 30m)")
+        );
+
+        app.push_paused_timer(paused(2, None, 5));
+        assert_eq!(
+            app.paused_timers_summary().as_deref(),
+            Some("Paused: Hotfix (0h 05m) +1 more")
+        );
+
+        app.remove_paused_timer(2);
+        assert_eq!(app.paused_timers.len(), 1);
+        assert_eq!(app.paused_timers[0].id, 1);
+    }
+}
@@ -0,0 +1,259 @@
+use super::*;
+
+/// Work and break lengths for pomodoro mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PomodoroSettings {
+    pub work: Duration,
+    pub short_break: Duration,
+    /// Ring the terminal bell when an interval ends.
+    pub bell: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    Work,
+    Break,
+}
+
+/// Where the running timer is in the pomodoro cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PomodoroStatus {
+    pub phase: PomodoroPhase,
+    pub remaining: Duration,
+    pub completed: u32,
+}
+
+/// Pomodoro cycle laid over the running timer. Work and break intervals
+/// follow each other from `offset` into the timer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pomodoro {
+    /// Elapsed timer time when the cycle started.
+    offset: Duration,
+    /// Interval seen at the last tick, as (cycle, phase), to notice when one ends.
+    last_interval: (u32, PomodoroPhase),
+}
+
+impl Pomodoro {
+    fn starting_at(offset: Duration) -> Self {
+        Self {
+            offset,
+            last_interval: (0, PomodoroPhase::Work),
+        }
+    }
+
+    fn interval_at(&self, elapsed: Duration, settings: &PomodoroSettings) -> (u32, Duration) {
+        let cycle = (settings.work + settings.short_break).as_secs().max(1);
+        let since = elapsed.saturating_sub(self.offset).as_secs();
+        ((since / cycle) as u32, Duration::from_secs(since % cycle))
+    }
+
+    fn status_at(&self, elapsed: Duration, settings: &PomodoroSettings) -> PomodoroStatus {
+        let (cycle, position) = self.interval_at(elapsed, settings);
+        if position < settings.work {
+            PomodoroStatus {
+                phase: PomodoroPhase::Work,
+                remaining: settings.work - position,
+                completed: cycle,
+            }
+        } else {
+            PomodoroStatus {
+                phase: PomodoroPhase::Break,
+                remaining: settings.work + settings.short_break - position,
+                completed: cycle + 1,
+            }
+        }
+    }
+}
+
+impl App {
+    pub fn pomodoro_enabled(&self) -> bool {
+        self.pomodoro.is_some()
+    }
+
+    /// Turn pomodoro mode on or off. Turned on while the timer runs, the
+    /// first work interval starts now.
+    pub fn toggle_pomodoro(&mut self) {
+        if self.pomodoro.take().is_some() {
+            self.set_status("Pomodoro mode off".to_string());
+            return;
+        }
+
+        let offset = self.elapsed_duration();
+        self.pomodoro = Some(Pomodoro::starting_at(offset));
+        self.set_status(format!(
+            "Pomodoro mode on: {} min work, {} min break",
+            self.pomodoro_settings.work.as_secs() / 60,
+            self.pomodoro_settings.short_break.as_secs() / 60
+        ));
+    }
+
+    /// Start a new cycle, used when a new timer starts.
+    pub(super) fn reset_pomodoro(&mut self) {
+        if let Some(pomodoro) = &mut self.pomodoro {
+            *pomodoro = Pomodoro::starting_at(Duration::ZERO);
+        }
+    }
+
+    /// The current interval, when pomodoro mode is on and the timer runs.
+    pub fn pomodoro_status(&self) -> Option<PomodoroStatus> {
+        if self.timer_state != TimerState::Running {
+            return None;
+        }
+        let pomodoro = self.pomodoro.as_ref()?;
+        Some(pomodoro.status_at(self.elapsed_duration(), &self.pomodoro_settings))
+    }
+
+    /// Check whether an interval just ended. Announces it in the status box
+    /// and returns the phase that starts now.
+    pub fn tick_pomodoro(&mut self) -> Option<PomodoroPhase> {
+        if self.timer_state != TimerState::Running {
+            return None;
+        }
+        let elapsed = self.elapsed_duration();
+        let settings = self.pomodoro_settings;
+        let pomodoro = self.pomodoro.as_mut()?;
+
+        let status = pomodoro.status_at(elapsed, &settings);
+        let (cycle, _) = pomodoro.interval_at(elapsed, &settings);
+        let interval = (cycle, status.phase);
+        if interval == pomodoro.last_interval {
+            return None;
+        }
+        pomodoro.last_interval = interval;
+
+        let message = match status.phase {
+            PomodoroPhase::Break => format!(
+                "Pomodoro {} done, take a {} min break",
+                status.completed,
+                settings.short_break.as_secs() / 60
+            ),
+            PomodoroPhase::Work => "Break over, back to work".to_string(),
+        };
+        self.set_status(message);
+        Some(status.phase)
+    }
+
+    /// Pomodoros finished on the running timer, counting one whose break
+    /// is in progress.
+    pub fn completed_pomodoros(&self) -> u32 {
+        self.pomodoro_status()
+            .map(|status| status.completed)
+            .unwrap_or(0)
+    }
+
+    /// The note to save the running timer with: the full note, with the
+    /// completed pomodoros tagged before any log tag.
+    pub fn note_for_save(&self) -> String {
+        let summary = tag_pomodoros(&self.description_input.value, self.completed_pomodoros());
+        match &self.description_log_id {
+            Some(id) => crate::log_notes::append_tag(&summary, id),
+            None => summary,
+        }
+    }
+}
+
+/// Append `[N pomodoros]` to a note. A tag already at the end of the note,
+/// e.g. from a resumed entry, is added to rather than repeated.
+pub fn tag_pomodoros(note: &str, completed: u32) -> String {
+    if completed == 0 {
+        return note.to_string();
+    }
+
+    let (note, earlier) = split_pomodoro_tag(note.trim_end());
+    let total = earlier + completed;
+    let label = if total == 1 { "pomodoro" } else { "pomodoros" };
+    if note.is_empty() {
+        format!("[{} {}]", total, label)
+    } else {
+        format!("{} [{} {}]", note, total, label)
+    }
+}
+
+fn split_pomodoro_tag(note: &str) -> (&str, u32) {
+    let tagged = note.strip_suffix(']').and_then(|rest| {
+        let start = rest.rfind('[')?;
+        let (count, label) = rest[start + 1..].split_once(' ')?;
+        matches!(label, "pomodoro" | "pomodoros")
+            .then(|| count.parse().ok())
+            .flatten()
+            .map(|count| (rest[..start].trim_end(), count))
+    });
+    tagged.unwrap_or((note, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn settings() -> PomodoroSettings {
+        PomodoroSettings {
+            work: Duration::from_secs(25 * 60),
+            short_break: Duration::from_secs(5 * 60),
+            bell: false,
+        }
+    }
+
+    #[test]
+    fn status_follows_work_and_break_intervals() {
+        let pomodoro = Pomodoro::starting_at(Duration::from_secs(10 * 60));
+        let at = |minutes: u64| pomodoro.status_at(Duration::from_secs(minutes * 60), &settings());
+
+        assert_eq!(
+            at(10),
+            PomodoroStatus {
+                phase: PomodoroPhase::Work,
+                remaining: Duration::from_secs(25 * 60),
+                completed: 0,
+            }
+        );
+        assert_eq!(at(36).phase, PomodoroPhase::Break);
+        assert_eq!(at(36).completed, 1);
+        assert_eq!(at(36).remaining, Duration::from_secs(4 * 60));
+        assert_eq!(at(41).phase, PomodoroPhase::Work);
+        assert_eq!(at(41).completed, 1);
+    }
+
+    #[test]
+    fn tick_announces_each_interval_once() {
+        let mut app = test_app();
+        app.pomodoro_settings = settings();
+        app.start_timer(false);
+        app.toggle_pomodoro();
+        assert_eq!(app.tick_pomodoro(), None);
+
+        app.absolute_start = Some(OffsetDateTime::now_utc() - time::Duration::minutes(26));
+
+        assert_eq!(app.tick_pomodoro(), Some(PomodoroPhase::Break));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Pomodoro 1 done, take a 5 min break")
+        );
+        assert_eq!(app.tick_pomodoro(), None);
+        assert_eq!(app.completed_pomodoros(), 1);
+    }
+
+    #[test]
+    fn tags_completed_pomodoros_before_the_log_tag() {
+        assert_eq!(tag_pomodoros("Fix login", 0), "Fix login");
+        assert_eq!(tag_pomodoros("Fix login", 1), "Fix login [1 pomodoro]");
+        assert_eq!(
+            tag_pomodoros("Fix login [2 pomodoros]", 1),
+            "Fix login [3 pomodoros]"
+        );
+        assert_eq!(tag_pomodoros("", 2), "[2 pomodoros]");
+
+        let mut app = test_app();
+        app.pomodoro_settings = settings();
+        app.description_input = TextInput::from_str("Fix login");
+        app.description_log_id = Some("a3f8b2".to_string());
+        app.start_timer(false);
+        app.toggle_pomodoro();
+        app.absolute_start = Some(OffsetDateTime::now_utc() - time::Duration::minutes(61));
+
+        assert_eq!(
+            app.note_for_save(),
+            crate::log_notes::append_tag("Fix login [2 pomodoros]", "a3f8b2")
+        );
+    }
+}
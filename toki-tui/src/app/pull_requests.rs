@@ -0,0 +1,81 @@
+use super::*;
+use crate::types::{PullRequest, PullRequestChange};
+
+impl App {
+    /// Replace the pull request list, keeping the selection on the same PR when possible.
+    pub fn set_pull_requests(&mut self, pull_requests: Vec<PullRequest>) {
+        let selected_key = self
+            .selected_pull_request()
+            .map(|pr| (pr.repo_name.clone(), pr.id));
+        self.pull_requests = pull_requests;
+        self.selected_pull_request_index = selected_key
+            .and_then(|(repo_name, id)| {
+                self.pull_requests
+                    .iter()
+                    .position(|pr| pr.repo_name == repo_name && pr.id == id)
+            })
+            .unwrap_or(0);
+    }
+
+    pub fn selected_pull_request(&self) -> Option<&PullRequest> {
+        self.pull_requests.get(self.selected_pull_request_index)
+    }
+
+    pub fn pull_request_focus_down(&mut self) {
+        if self.selected_pull_request_index + 1 < self.pull_requests.len() {
+            self.selected_pull_request_index += 1;
+        }
+    }
+
+    pub fn pull_request_focus_up(&mut self) {
+        self.selected_pull_request_index = self.selected_pull_request_index.saturating_sub(1);
+    }
+
+    /// Show the detail view for the selected PR with its changed files.
+    pub fn open_pull_request_detail(&mut self, changes: Vec<PullRequestChange>) {
+        self.pull_request_changes = changes;
+        self.pull_request_detail_scroll = 0;
+        self.navigate_to(View::PullRequestDetail);
+    }
+
+    pub fn pull_request_detail_scroll_down(&mut self) {
+        self.pull_request_detail_scroll = self.pull_request_detail_scroll.saturating_add(1);
+    }
+
+    pub fn pull_request_detail_scroll_up(&mut self) {
+        self.pull_request_detail_scroll = self.pull_request_detail_scroll.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{pull_request, test_app};
+
+    #[test]
+    fn set_pull_requests_keeps_selection_on_same_pr() {
+        let mut app = test_app();
+        app.set_pull_requests(vec![pull_request("repo", 1), pull_request("repo", 2)]);
+        app.pull_request_focus_down();
+
+        app.set_pull_requests(vec![
+            pull_request("repo", 3),
+            pull_request("repo", 1),
+            pull_request("repo", 2),
+        ]);
+
+        assert_eq!(app.selected_pull_request().map(|pr| pr.id), Some(2));
+    }
+
+    #[test]
+    fn pull_request_focus_stays_within_list() {
+        let mut app = test_app();
+        app.set_pull_requests(vec![pull_request("repo", 1), pull_request("repo", 2)]);
+
+        app.pull_request_focus_up();
+        assert_eq!(app.selected_pull_request_index, 0);
+
+        app.pull_request_focus_down();
+        app.pull_request_focus_down();
+        assert_eq!(app.selected_pull_request_index, 1);
+    }
+}
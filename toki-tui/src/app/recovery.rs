@@ -0,0 +1,280 @@
+use super::*;
+use serde::{Deserialize, Serialize};
+
+/// Which list an entry edit was opened from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditOrigin {
+    ThisWeek,
+    History,
+}
+
+/// An entry edit in progress, with the raw text of every input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditSnapshot {
+    pub origin: EditOrigin,
+    pub registration_id: String, // "" = running timer sentinel
+    pub start_time_input: String,
+    pub end_time_input: String,
+    pub original_start_time: String,
+    pub original_end_time: String,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+}
+
+impl EditSnapshot {
+    fn from_edit_state(origin: EditOrigin, state: &EntryEditState, note: String) -> Self {
+        Self {
+            origin,
+            registration_id: state.registration_id.clone(),
+            start_time_input: state.start_time_input.clone(),
+            end_time_input: state.end_time_input.clone(),
+            original_start_time: state.original_start_time.clone(),
+            original_end_time: state.original_end_time.clone(),
+            project_id: state.project_id.clone(),
+            project_name: state.project_name.clone(),
+            activity_id: state.activity_id.clone(),
+            activity_name: state.activity_name.clone(),
+            note,
+        }
+    }
+
+    fn into_edit_state(self) -> EntryEditState {
+        EntryEditState {
+            registration_id: self.registration_id,
+            start_time_input: self.start_time_input,
+            end_time_input: self.end_time_input,
+            original_start_time: self.original_start_time,
+            original_end_time: self.original_end_time,
+            project_id: self.project_id,
+            project_name: self.project_name,
+            activity_id: self.activity_id,
+            activity_name: self.activity_name,
+            note: TextInput::from_str(&self.note),
+            focused_field: EntryEditField::Note,
+            validation_error: None,
+        }
+    }
+}
+
+/// State that only lives in memory until it is saved to the server: the
+/// timer's note and selection, and any entry edit in progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsavedState {
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub timer_started_at: Option<OffsetDateTime>,
+    pub project: Option<Project>,
+    pub activity: Option<Activity>,
+    /// Full timer note, including any log tag.
+    pub note: String,
+    /// The note editor was open for the timer note.
+    pub editing_note: bool,
+    pub edit: Option<EditSnapshot>,
+}
+
+impl UnsavedState {
+    /// One line per piece of recoverable state, for the restore prompt.
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(started_at) = self.timer_started_at {
+            let t = to_local_time(started_at).time();
+            lines.push(format!(
+                "Running timer started at {:02}:{:02}",
+                t.hour(),
+                t.minute()
+            ));
+        }
+        if !self.note.is_empty() {
+            lines.push(format!("Timer note: {}", self.note));
+        }
+        if let Some(edit) = &self.edit {
+            let label = match (&edit.project_name, &edit.activity_name) {
+                (Some(project), Some(activity)) => format!("{project}: {activity}"),
+                (Some(project), None) => project.clone(),
+                _ => "entry".to_string(),
+            };
+            lines.push(format!(
+                "Unsaved edit of {label} ({}-{})",
+                edit.start_time_input, edit.end_time_input
+            ));
+        }
+        lines
+    }
+}
+
+impl App {
+    /// Snapshot of the state that would be lost if the TUI died now, or
+    /// `None` when there is nothing worth restoring.
+    pub fn unsaved_state(&self) -> Option<UnsavedState> {
+        let editing_entry_note = self.editing_description && self.saved_timer_note.is_some();
+        // While an entry note is open in the editor, the timer note is parked in
+        // `saved_timer_note` and the editor holds the entry's note.
+        let note = match &self.saved_timer_note {
+            Some(saved) => saved.clone(),
+            None if self.description_is_default => String::new(),
+            None => self.full_note_value(),
+        };
+
+        let edit = [
+            (EditOrigin::ThisWeek, &self.this_week_edit_state),
+            (EditOrigin::History, &self.history_edit_state),
+        ]
+        .into_iter()
+        .find_map(|(origin, state)| state.as_ref().map(|state| (origin, state)))
+        .map(|(origin, state)| {
+            let note = if editing_entry_note {
+                self.full_note_value()
+            } else {
+                state.note.value.clone()
+            };
+            EditSnapshot::from_edit_state(origin, state, note)
+        });
+
+        // A running timer on its own is kept by the server.
+        if note.is_empty() && edit.is_none() {
+            return None;
+        }
+
+        Some(UnsavedState {
+            timer_started_at: match self.timer_state {
+                TimerState::Running => self.absolute_start,
+                TimerState::Stopped => None,
+            },
+            project: self.selected_project.clone(),
+            activity: self.selected_activity.clone(),
+            note,
+            editing_note: self.editing_description && !editing_entry_note,
+            edit,
+        })
+    }
+
+    /// Put recovered state back. Call after the server state is loaded, so the
+    /// running timer is already restored and edits can find their entries.
+    pub fn restore_unsaved_state(&mut self, state: UnsavedState) {
+        // The server owns the running timer's project and activity.
+        if self.timer_state == TimerState::Stopped {
+            if state.project.is_some() {
+                self.selected_project = state.project;
+                self.selected_activity = state.activity;
+            }
+            if state.timer_started_at.is_some() {
+                self.set_status(
+                    "Timer is no longer running on the server; restored its note".to_string(),
+                );
+            }
+        }
+
+        if !state.note.is_empty() {
+            self.set_note_from_raw(&state.note);
+            self.description_is_default = false;
+        }
+
+        if let Some(edit) = state.edit {
+            if !self.restore_edit(edit) {
+                self.set_status("Entry being edited no longer exists; edit discarded".to_string());
+            }
+        } else if state.editing_note {
+            self.navigate_to(View::EditDescription);
+        }
+    }
+
+    fn restore_edit(&mut self, edit: EditSnapshot) -> bool {
+        let running = self.timer_state == TimerState::Running;
+        match edit.origin {
+            EditOrigin::ThisWeek => {
+                let index = if edit.registration_id.is_empty() {
+                    running.then_some(0)
+                } else {
+                    self.this_week_history()
+                        .iter()
+                        .position(|e| e.registration_id == edit.registration_id)
+                        .map(|i| if running { i + 1 } else { i })
+                };
+                let Some(index) = index else {
+                    return false;
+                };
+                self.focused_this_week_index = Some(index);
+                self.focused_box = FocusedBox::Today;
+                self.this_week_edit_state = Some(edit.into_edit_state());
+            }
+            EditOrigin::History => {
+                let index = self
+                    .history_list_entries
+                    .iter()
+                    .position(|&i| self.time_entries[i].registration_id == edit.registration_id);
+                let Some(index) = index else {
+                    return false;
+                };
+                self.current_view = View::History;
+                self.focused_history_index = Some(index);
+                self.history_edit_state = Some(edit.into_edit_state());
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{project, test_app, time_entry};
+
+    #[test]
+    fn nothing_to_recover_for_a_fresh_app() {
+        assert_eq!(test_app().unsaved_state(), None);
+    }
+
+    #[test]
+    fn recovers_the_timer_note_being_written() {
+        let mut app = test_app();
+        app.start_timer(false);
+        app.selected_project = Some(project("p1", "Toki"));
+        app.navigate_to(View::EditDescription);
+        app.description_input = TextInput::from_str("Half-written note");
+
+        let state = app.unsaved_state().unwrap();
+        let mut restored = test_app();
+        restored.start_timer(false);
+        restored.restore_unsaved_state(state);
+
+        assert_eq!(restored.description_input.value, "Half-written note");
+        assert_eq!(restored.current_view, View::EditDescription);
+        // The running timer's project comes from the server, not the snapshot.
+        assert_eq!(restored.selected_project, None);
+    }
+
+    #[test]
+    fn recovers_a_history_edit_by_registration_id() {
+        let mut app = test_app();
+        app.time_entries = vec![time_entry(
+            "reg-1",
+            "p1",
+            "Toki",
+            "a1",
+            "Dev",
+            &crate::time_utils::local_today().to_string(),
+            1.0,
+            Some("old"),
+            None,
+            None,
+        )];
+        app.rebuild_history_list();
+        app.current_view = View::History;
+        app.focused_history_index = Some(0);
+        app.enter_history_edit_mode();
+        app.history_edit_state.as_mut().unwrap().note = TextInput::from_str("new note");
+
+        let state = app.unsaved_state().unwrap();
+        let mut restored = test_app();
+        restored.time_entries = app.time_entries.clone();
+        restored.rebuild_history_list();
+        restored.restore_unsaved_state(state);
+
+        assert_eq!(restored.current_view, View::History);
+        assert_eq!(restored.focused_history_index, Some(0));
+        assert_eq!(restored.history_edit_state.unwrap().note.value, "new note");
+    }
+}
@@ -0,0 +1,167 @@
+use super::week_checklist::{format_date, short_weekday};
+use super::*;
+use crate::config::RecurringTemplateConfig;
+use crate::time_utils::local_date_time;
+use time::{Date, Time};
+
+/// A day a recurring meeting is on that has nothing logged for it yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurringOccurrence {
+    pub template: RecurringTemplateConfig,
+    pub date: Date,
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+impl RecurringOccurrence {
+    pub fn label(&self) -> String {
+        let (start, end) = (to_local_time(self.start), to_local_time(self.end));
+        format!(
+            "{} {} {:02}:{:02}–{:02}:{:02} {} ({} / {})",
+            short_weekday(self.date.weekday()),
+            format_date(self.date),
+            start.hour(),
+            start.minute(),
+            end.hour(),
+            end.minute(),
+            self.template.description,
+            self.template.project,
+            self.template.activity
+        )
+    }
+}
+
+impl App {
+    /// Recurring meetings earlier this week, or earlier today, that have
+    /// nothing logged for them.
+    pub fn due_recurring_entries(&self) -> Vec<RecurringOccurrence> {
+        let now = to_local_time(OffsetDateTime::now_utc());
+        self.due_recurring_entries_at(now.date(), now.time())
+    }
+
+    pub(super) fn due_recurring_entries_at(
+        &self,
+        today: Date,
+        now: Time,
+    ) -> Vec<RecurringOccurrence> {
+        let monday = today - time::Duration::days(today.weekday().number_days_from_monday() as i64);
+        let mut due = Vec::new();
+
+        for template in &self.recurring_templates {
+            let Some((start, end)) = template.time_range() else {
+                continue;
+            };
+            let weekdays = template.weekdays();
+            let dates = (0..)
+                .map(|offset| monday + time::Duration::days(offset))
+                .take_while(|date| *date <= today)
+                .filter(|date| weekdays.contains(&date.weekday()))
+                .filter(|date| *date < today || start <= now);
+
+            for date in dates {
+                let occurrence = RecurringOccurrence {
+                    template: template.clone(),
+                    date,
+                    start: local_date_time(date, start),
+                    end: local_date_time(date, end),
+                };
+                if !self.is_occurrence_logged(&occurrence) {
+                    due.push(occurrence);
+                }
+            }
+        }
+
+        due.sort_by_key(|occurrence| occurrence.start);
+        due
+    }
+
+    /// An entry counts as the meeting when it is on the same project and
+    /// activity that day and either has the same note or overlaps the
+    /// meeting's time.
+    fn is_occurrence_logged(&self, occurrence: &RecurringOccurrence) -> bool {
+        let date = format_date(occurrence.date);
+        let template = &occurrence.template;
+        self.time_entries
+            .iter()
+            .filter(|entry| entry.date == date)
+            .filter(|entry| {
+                entry.project_name.eq_ignore_ascii_case(&template.project)
+                    && entry.activity_name.eq_ignore_ascii_case(&template.activity)
+            })
+            .any(|entry| {
+                let note = entry.note.as_deref().map(crate::log_notes::strip_tag);
+                let same_note = note.unwrap_or_default().trim() == template.note.trim();
+                let overlaps = match (entry.start_time, entry.end_time) {
+                    (Some(start), Some(end)) => start < occurrence.end && end > occurrence.start,
+                    _ => false,
+                };
+                same_note || overlaps
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+    use time::macros::{date, time};
+
+    fn standup() -> RecurringTemplateConfig {
+        RecurringTemplateConfig {
+            description: "Standup".to_string(),
+            project: "Toki".to_string(),
+            activity: "Meetings".to_string(),
+            note: "Daily standup".to_string(),
+            days: vec!["mon".to_string(), "wed".to_string(), "fri".to_string()],
+            start: "09:00".to_string(),
+            end: "09:15".to_string(),
+        }
+    }
+
+    #[test]
+    fn offers_matching_days_up_to_now() {
+        let mut app = test_app();
+        app.recurring_templates = vec![standup()];
+
+        let due = app.due_recurring_entries_at(date!(2026 - 03 - 06), time!(08:30));
+
+        let dates: Vec<Date> = due.iter().map(|occurrence| occurrence.date).collect();
+        assert_eq!(dates, vec![date!(2026 - 03 - 02), date!(2026 - 03 - 04)]);
+    }
+
+    #[test]
+    fn skips_days_already_logged() {
+        let mut app = test_app();
+        app.recurring_templates = vec![standup()];
+        app.update_history(vec![time_entry(
+            "1",
+            "p1",
+            "Toki",
+            "a1",
+            "meetings",
+            "2026-03-02",
+            0.25,
+            Some("Daily standup"),
+            None,
+            None,
+        )]);
+
+        let due = app.due_recurring_entries_at(date!(2026 - 03 - 06), time!(10:00));
+
+        let dates: Vec<Date> = due.iter().map(|occurrence| occurrence.date).collect();
+        assert_eq!(dates, vec![date!(2026 - 03 - 04), date!(2026 - 03 - 06)]);
+    }
+
+    #[test]
+    fn ignores_templates_with_invalid_times() {
+        let mut app = test_app();
+        app.recurring_templates = vec![RecurringTemplateConfig {
+            end: "08:00".to_string(),
+            ..standup()
+        }];
+
+        assert!(app
+            .due_recurring_entries_at(date!(2026 - 03 - 06), time!(12:00))
+            .is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use crate::time_utils::local_date_time;
+
+/// Prompt for the time to split a saved entry at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPrompt {
+    pub registration_id: String,
+    /// Split time as typed, `HH:MM` in local time.
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl App {
+    /// Open the split prompt for the entry being edited, prefilled with the
+    /// middle of the entry. Unsaved edits are not part of the split.
+    pub fn open_split_prompt(&mut self) {
+        let Some(registration_id) = self
+            .current_edit_state()
+            .map(|state| state.registration_id.clone())
+        else {
+            return;
+        };
+        if registration_id.is_empty() {
+            self.set_status("Only saved entries can be split".to_string());
+            return;
+        }
+        let Some(entry) = self
+            .time_entries
+            .iter()
+            .find(|entry| entry.registration_id == registration_id)
+        else {
+            self.set_status("Error: Entry not found in history".to_string());
+            return;
+        };
+        let (Some(start), Some(end)) = (entry.start_time, entry.end_time) else {
+            self.set_status("Entry has no start and end time to split".to_string());
+            return;
+        };
+
+        let middle = to_local_time(start + (end - start) / 2);
+        self.split_prompt = Some(SplitPrompt {
+            registration_id,
+            input: format!("{:02}:{:02}", middle.hour(), middle.minute()),
+            error: None,
+        });
+    }
+
+    pub fn close_split_prompt(&mut self) {
+        self.split_prompt = None;
+    }
+
+    pub fn split_prompt_input_char(&mut self, c: char) {
+        let Some(prompt) = &mut self.split_prompt else {
+            return;
+        };
+        if !c.is_ascii_digit() {
+            return;
+        }
+        if prompt.input.len() >= 5 {
+            prompt.input.clear();
+        }
+        prompt.input.push(c);
+        if prompt.input.len() == 2 {
+            prompt.input.push(':');
+        }
+        prompt.error = None;
+    }
+
+    pub fn split_prompt_backspace(&mut self) {
+        let Some(prompt) = &mut self.split_prompt else {
+            return;
+        };
+        prompt.input.pop();
+        if prompt.input.ends_with(':') {
+            prompt.input.pop();
+        }
+        prompt.error = None;
+    }
+}
+
+/// Parse `input` (`HH:MM`) as a time on `entry`'s date. The time has to fall
+/// strictly inside the entry.
+pub fn parse_split_time(input: &str, entry: &TimeEntry) -> Result<OffsetDateTime, String> {
+    let (Some(start), Some(end)) = (entry.start_time, entry.end_time) else {
+        return Err("Entry has no start and end time to split".to_string());
+    };
+    let date = parse_date_str(&entry.date)
+        .ok_or_else(|| format!("Unexpected date format: {}", entry.date))?;
+    let (hour, minute) = input
+        .split_once(':')
+        .and_then(|(h, m)| Some((h.parse::<u8>().ok()?, m.parse::<u8>().ok()?)))
+        .ok_or_else(|| "Enter the split time as HH:MM".to_string())?;
+    let time = time::Time::from_hms(hour, minute, 0).map_err(|_| "Invalid time".to_string())?;
+
+    let split_at = local_date_time(date, time);
+    if split_at <= start || split_at >= end {
+        return Err("Split time must be between the entry's start and end".to_string());
+    }
+    Ok(split_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, timed_entry};
+
+    fn entry() -> TimeEntry {
+        let date = time::Date::from_calendar_date(2026, time::Month::October, 16).unwrap();
+        timed_entry(
+            "reg-1",
+            "Development",
+            local_date_time(date, time::Time::from_hms(8, 0, 0).unwrap()),
+            local_date_time(date, time::Time::from_hms(11, 0, 0).unwrap()),
+            Some("Login"),
+        )
+    }
+
+    #[test]
+    fn prompt_defaults_to_the_middle_of_the_entry() {
+        let mut app = test_app();
+        app.time_entries = vec![entry()];
+        app.current_view = View::History;
+        app.history_edit_state = Some(EntryEditState {
+            registration_id: "reg-1".to_string(),
+            start_time_input: "08:00".to_string(),
+            end_time_input: "11:00".to_string(),
+            original_start_time: "08:00".to_string(),
+            original_end_time: "11:00".to_string(),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+            note: TextInput::new(),
+            focused_field: EntryEditField::StartTime,
+            validation_error: None,
+        });
+
+        app.open_split_prompt();
+
+        assert_eq!(
+            app.split_prompt
+                .as_ref()
+                .map(|prompt| prompt.input.as_str()),
+            Some("09:30")
+        );
+    }
+
+    #[test]
+    fn split_time_must_fall_inside_the_entry() {
+        let entry = entry();
+
+        let split_at = parse_split_time("09:15", &entry).unwrap();
+
+        assert_eq!(
+            to_local_time(split_at).time(),
+            time::Time::from_hms(9, 15, 0).unwrap()
+        );
+        assert!(parse_split_time("08:00", &entry).is_err());
+        assert!(parse_split_time("12:00", &entry).is_err());
+        assert!(parse_split_time("9", &entry).is_err());
+    }
+}
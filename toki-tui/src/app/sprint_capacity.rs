@@ -0,0 +1,57 @@
+use super::*;
+
+/// Logged share of a sprint's capacity, where 1.0 means all of it is logged.
+/// `None` when the user has no capacity in the sprint.
+pub fn sprint_capacity_usage(sprint: &SprintCapacity) -> Option<f64> {
+    let capacity = sprint.capacity_hours?;
+    if capacity <= 0.0 {
+        return None;
+    }
+    Some(sprint.logged_hours / capacity)
+}
+
+impl App {
+    /// Sprints the user has capacity in, newest first, for the statistics
+    /// view.
+    pub fn sprints_with_capacity(&self) -> impl Iterator<Item = &SprintCapacity> {
+        self.sprint_capacities
+            .iter()
+            .filter(|sprint| sprint_capacity_usage(sprint).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn sprint(name: &str, capacity_hours: Option<f64>, logged_hours: f64) -> SprintCapacity {
+        SprintCapacity {
+            iteration_name: name.to_string(),
+            iteration_path: format!("Toki\\{}", name),
+            start_date: "2026-10-05".to_string(),
+            finish_date: "2026-10-16".to_string(),
+            capacity_hours,
+            logged_hours,
+        }
+    }
+
+    #[test]
+    fn only_sprints_with_capacity_are_shown() {
+        let mut app = test_app();
+        app.sprint_capacities = vec![
+            sprint("Sprint 42", Some(42.0), 31.5),
+            sprint("Sprint 41", None, 20.0),
+            sprint("Sprint 40", Some(0.0), 3.0),
+            sprint("Sprint 39", Some(60.0), 66.0),
+        ];
+
+        let shown: Vec<&str> = app
+            .sprints_with_capacity()
+            .map(|sprint| sprint.iteration_name.as_str())
+            .collect();
+        assert_eq!(shown, vec!["Sprint 42", "Sprint 39"]);
+        assert_eq!(sprint_capacity_usage(&app.sprint_capacities[0]), Some(0.75));
+        assert_eq!(sprint_capacity_usage(&app.sprint_capacities[3]), Some(1.1));
+    }
+}
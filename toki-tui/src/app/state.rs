@@ -1,3 +1,5 @@
+use crate::types::WorkItem;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TimerState {
     Stopped,
@@ -14,7 +16,13 @@ pub enum View {
     EditDescription,
     SaveAction,
     Statistics,
+    Calendar,
+    IdlePrompt,
+    FillWorkdays,
     ConfirmDelete,
+    PullRequests,
+    PullRequestDetail,
+    Board,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -25,6 +33,14 @@ pub enum SaveAction {
     Cancel,
 }
 
+/// What to do with time the timer ran while the user was away.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdleAction {
+    Keep,
+    Discard,
+    Split,
+}
+
 /// Which view was active when delete was triggered — used to return after dismiss.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DeleteOrigin {
@@ -35,9 +51,10 @@ pub enum DeleteOrigin {
 /// Context for the delete-confirmation modal.
 #[derive(Debug, Clone)]
 pub struct DeleteContext {
-    pub registration_id: String,
-    pub display_label: String, // "Project / Activity"
-    pub display_date: String,  // "YYYY-MM-DD"
+    /// One entry, or the entries marked in the history view.
+    pub registration_ids: Vec<String>,
+    pub display_label: String, // "Project / Activity" or "3 marked entries"
+    pub display_date: String,  // "YYYY-MM-DD" or "YYYY-MM-DD – YYYY-MM-DD"
     pub display_hours: f64,
     pub origin: DeleteOrigin,
 }
@@ -47,6 +64,7 @@ pub enum FocusedBox {
     Timer,
     ProjectActivity,
     Description,
+    Suggestions,
     Today,
 }
 
@@ -56,20 +74,21 @@ pub enum TimerSize {
     Large,
 }
 
-/// Per-project/activity breakdown for the statistics view
+/// Per-project breakdown for the statistics view, or per-activity when
+/// drilled into a project
 #[derive(Debug, Clone)]
 pub struct ProjectStat {
-    pub label: String, // "Project: Activity"
+    pub label: String, // project name, or activity name in the drill-down
     pub hours: f64,
     pub percentage: f64, // 0.0–100.0 of total worked this week
 }
 
-/// One project/activity's contribution to a single day
+/// One project's (or activity's) contribution to a single day
 #[derive(Debug, Clone)]
 pub struct DailyProjectStat {
-    pub label: String, // "Project: Activity"
+    pub label: String, // same label as the matching ProjectStat
     pub hours: f64,
-    pub color_index: usize, // index into the shared PALETTE
+    pub color_index: usize, // index into the theme's chart colors
 }
 
 /// Hours breakdown for one weekday
@@ -154,6 +173,26 @@ pub struct TaskwarriorOverlay {
     pub error: Option<String>,
 }
 
+/// State for the Azure DevOps work item picker overlay.
+#[derive(Debug, Clone, Default)]
+pub struct WorkItemOverlay {
+    pub items: Vec<WorkItem>,
+    pub search: TextInput,
+    /// Indices into `items` matching the search, best match first.
+    pub filtered: Vec<usize>,
+    pub selected: Option<usize>,
+    pub error: Option<String>,
+}
+
+/// The Azure DevOps project set in `[board]`, for sprint capacity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardProject {
+    pub organization: String,
+    pub project: String,
+    /// `None` uses the project's board team.
+    pub team: Option<String>,
+}
+
 /// A text input with mid-string cursor support.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct TextInput {
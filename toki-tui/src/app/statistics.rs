@@ -0,0 +1,338 @@
+use super::calendar::{first_of_month, last_of_month, shift_month};
+use super::week_checklist::format_date;
+use super::*;
+use std::collections::HashMap;
+use time::Date;
+
+/// Length of the period the statistics view summarizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsSpan {
+    Week,
+    Month,
+}
+
+/// The week or month shown in the statistics view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsPeriod {
+    pub span: StatsSpan,
+    /// Monday of the week, or the first of the month.
+    pub start: Date,
+}
+
+impl StatsPeriod {
+    /// The period of `span` that `date` falls in.
+    pub fn containing(span: StatsSpan, date: Date) -> Self {
+        let start = match span {
+            StatsSpan::Week => {
+                date - time::Duration::days(date.weekday().number_days_from_monday() as i64)
+            }
+            StatsSpan::Month => first_of_month(date),
+        };
+        Self { span, start }
+    }
+
+    pub fn end(&self) -> Date {
+        match self.span {
+            StatsSpan::Week => self.start + time::Duration::days(6),
+            StatsSpan::Month => last_of_month(self.start),
+        }
+    }
+
+    /// The period `steps` weeks or months later, or earlier when negative.
+    pub fn shift(self, steps: i32) -> Self {
+        let start = match self.span {
+            StatsSpan::Week => self.start + time::Duration::weeks(steps as i64),
+            StatsSpan::Month => shift_month(self.start, steps),
+        };
+        Self { start, ..self }
+    }
+
+    /// Switch between week and month, staying around the same dates. A month
+    /// becomes its week containing `today`, or its last week.
+    pub fn toggle_span(self, today: Date) -> Self {
+        match self.span {
+            StatsSpan::Week => Self::containing(StatsSpan::Month, self.start),
+            StatsSpan::Month => Self::containing(StatsSpan::Week, self.end().min(today)),
+        }
+    }
+
+    /// "Week 42 (2026-10-12 – 2026-10-18)" or "October 2026".
+    pub fn label(&self) -> String {
+        match self.span {
+            StatsSpan::Week => format!(
+                "Week {} ({} – {})",
+                self.start.iso_week(),
+                format_date(self.start),
+                format_date(self.end())
+            ),
+            StatsSpan::Month => format!("{} {}", self.start.month(), self.start.year()),
+        }
+    }
+}
+
+impl App {
+    /// Show `period` in the statistics view. `entries` are the entries logged
+    /// during it, or `None` to take them from the loaded history.
+    pub fn set_statistics_period(&mut self, period: StatsPeriod, entries: Option<Vec<TimeEntry>>) {
+        self.statistics_period = period;
+        self.statistics_entries = entries;
+        self.refresh_statistics();
+    }
+
+    /// Recompute the statistics caches for the shown period. These are
+    /// expensive (multiple passes over history) and are used every render
+    /// frame, so they are computed once here and served cached.
+    pub(super) fn refresh_statistics(&mut self) {
+        self.project_stats_cache = self.project_stats();
+        self.daily_stats_cache = self.daily_stats();
+        self.statistics_selected = self
+            .statistics_selected
+            .min(self.project_stats_cache.len().saturating_sub(1));
+    }
+
+    pub fn select_next_statistics_slice(&mut self) {
+        if self.statistics_selected + 1 < self.project_stats_cache.len() {
+            self.statistics_selected += 1;
+        }
+    }
+
+    pub fn select_previous_statistics_slice(&mut self) {
+        self.statistics_selected = self.statistics_selected.saturating_sub(1);
+    }
+
+    /// Break the selected project down into its activities.
+    pub fn drill_into_statistics_project(&mut self) {
+        if self.statistics_project.is_some() {
+            return;
+        }
+        let Some(stat) = self.project_stats_cache.get(self.statistics_selected) else {
+            return;
+        };
+        self.statistics_project = Some(stat.label.clone());
+        self.statistics_selected = 0;
+        self.refresh_statistics();
+    }
+
+    /// Go back to the per-project overview with the drilled project selected.
+    /// Returns false if not drilled into a project.
+    pub fn leave_statistics_project(&mut self) -> bool {
+        let Some(project) = self.statistics_project.take() else {
+            return false;
+        };
+        self.refresh_statistics();
+        self.statistics_selected = self
+            .project_stats_cache
+            .iter()
+            .position(|stat| stat.label == project)
+            .unwrap_or(0);
+        true
+    }
+
+    /// Label a statistics entry by project, or by activity within the
+    /// drilled project. `None` for entries of other projects.
+    fn statistics_label(&self, entry: &TimeEntry) -> Option<String> {
+        match &self.statistics_project {
+            None => Some(entry.project_name.clone()),
+            Some(project) if entry.project_name == *project => Some(entry.activity_name.clone()),
+            Some(_) => None,
+        }
+    }
+
+    fn statistics_period_entries(&self) -> Vec<&TimeEntry> {
+        let start = format_date(self.statistics_period.start);
+        let end = format_date(self.statistics_period.end());
+        self.statistics_entries
+            .as_ref()
+            .unwrap_or(&self.time_entries)
+            .iter()
+            .filter(|e| e.date >= start && e.date <= end)
+            .collect()
+    }
+
+    /// Per-project (or per-activity of the drilled project) breakdown for
+    /// the statistics period (≥ 1% of total, sorted desc)
+    pub fn project_stats(&self) -> Vec<ProjectStat> {
+        let entries = self.statistics_period_entries();
+        let mut map: HashMap<String, f64> = HashMap::new();
+
+        for e in &entries {
+            if e.hours > 0.0 {
+                if let Some(key) = self.statistics_label(e) {
+                    *map.entry(key).or_insert(0.0) += e.hours;
+                }
+            }
+        }
+
+        let total: f64 = map.values().sum();
+        if total == 0.0 {
+            return Vec::new();
+        }
+
+        let mut stats: Vec<ProjectStat> = map
+            .into_iter()
+            .filter_map(|(label, hours)| {
+                let percentage = hours / total * 100.0;
+                if percentage >= 1.0 {
+                    Some(ProjectStat {
+                        label,
+                        hours,
+                        percentage,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        stats.sort_by(|a, b| {
+            b.hours
+                .partial_cmp(&a.hours)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.label.cmp(&b.label)) // stable tiebreaker: label alphabetical
+        });
+        stats
+    }
+
+    /// Per-weekday breakdown for the statistics period, Mon–Sun, each day
+    /// split like project_stats. A month sums all its Mondays, Tuesdays, …
+    /// Projects are colored by their global rank (same order as project_stats).
+    pub fn daily_stats(&self) -> Vec<DayStat> {
+        // Build the global project ordering (for consistent palette indices)
+        let global_stats = self.project_stats();
+        let color_index: HashMap<String, usize> = global_stats
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.label.clone(), i))
+            .collect();
+
+        // Build 7 slots Mon(0)…Sun(6)
+        let day_names = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        let mut slots: Vec<HashMap<String, f64>> = vec![HashMap::new(); 7];
+
+        for entry in self.statistics_period_entries() {
+            if entry.hours <= 0.0 {
+                continue;
+            }
+            let Some(key) = self.statistics_label(entry) else {
+                continue;
+            };
+            // Parse entry date to find which weekday slot
+            let Some(date) = parse_date_str(&entry.date) else {
+                continue;
+            };
+
+            let slot = date.weekday().number_days_from_monday() as usize;
+            *slots[slot].entry(key).or_insert(0.0) += entry.hours;
+        }
+
+        slots
+            .into_iter()
+            .enumerate()
+            .map(|(i, map)| {
+                let total_hours: f64 = map.values().sum();
+                let mut projects: Vec<DailyProjectStat> = map
+                    .into_iter()
+                    .map(|(label, hours)| {
+                        let ci = *color_index.get(&label).unwrap_or(&0);
+                        DailyProjectStat {
+                            label,
+                            hours,
+                            color_index: ci,
+                        }
+                    })
+                    .collect();
+                // Sort by global rank (color_index) so stacking order matches pie
+                projects.sort_by_key(|p| p.color_index);
+                DayStat {
+                    day_name: day_names[i].to_string(),
+                    total_hours,
+                    projects,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{day_entry, test_app};
+    use time::macros::date;
+
+    #[test]
+    fn periods_page_by_week_or_month() {
+        // 2026-10-16 is a Friday.
+        let week = StatsPeriod::containing(StatsSpan::Week, date!(2026 - 10 - 16));
+        assert_eq!(week.start, date!(2026 - 10 - 12));
+        assert_eq!(week.end(), date!(2026 - 10 - 18));
+        assert_eq!(week.shift(-2).start, date!(2026 - 09 - 28));
+
+        let month = StatsPeriod::containing(StatsSpan::Month, date!(2026 - 02 - 14));
+        assert_eq!(month.end(), date!(2026 - 02 - 28));
+        assert_eq!(month.shift(-2).start, date!(2025 - 12 - 01));
+        assert_eq!(month.label(), "February 2026");
+    }
+
+    #[test]
+    fn toggling_span_stays_around_the_same_dates() {
+        let today = date!(2026 - 10 - 16);
+        let week = StatsPeriod::containing(StatsSpan::Week, date!(2026 - 09 - 30));
+
+        let month = week.toggle_span(today);
+        assert_eq!(month.start, date!(2026 - 09 - 01));
+        assert_eq!(month.toggle_span(today).start, date!(2026 - 09 - 28));
+
+        let this_month = StatsPeriod::containing(StatsSpan::Month, today);
+        assert_eq!(this_month.toggle_span(today).start, date!(2026 - 10 - 12));
+    }
+
+    #[test]
+    fn month_stats_sum_hours_per_weekday() {
+        let mut app = test_app();
+        app.set_statistics_period(
+            StatsPeriod::containing(StatsSpan::Month, date!(2026 - 10 - 01)),
+            Some(vec![
+                day_entry("1", "Toki", "Development", date!(2026 - 10 - 05), 3.0, None),
+                day_entry("2", "Toki", "Development", date!(2026 - 10 - 12), 4.0, None),
+                day_entry("3", "Acme", "Development", date!(2026 - 10 - 13), 8.0, None),
+                day_entry("4", "Acme", "Development", date!(2026 - 09 - 29), 8.0, None),
+            ]),
+        );
+
+        assert_eq!(app.project_stats_cache.len(), 2);
+        assert_eq!(app.project_stats_cache[0].label, "Acme");
+        assert_eq!(app.project_stats_cache[0].hours, 8.0);
+        assert_eq!(app.daily_stats_cache[0].total_hours, 7.0);
+        assert_eq!(app.daily_stats_cache[1].total_hours, 8.0);
+        assert_eq!(app.daily_stats_cache[2].total_hours, 0.0);
+    }
+
+    #[test]
+    fn drilling_into_a_project_breaks_it_down_by_activity() {
+        let activity_entry = |id: &str, project: &str, activity: &str, hours: f64| {
+            day_entry(id, project, activity, date!(2026 - 10 - 13), hours, None)
+        };
+        let mut app = test_app();
+        app.set_statistics_period(
+            StatsPeriod::containing(StatsSpan::Week, date!(2026 - 10 - 13)),
+            Some(vec![
+                activity_entry("1", "Toki", "Development", 3.0),
+                activity_entry("2", "Toki", "Meetings", 1.0),
+                activity_entry("3", "Acme", "Development", 8.0),
+            ]),
+        );
+
+        app.select_next_statistics_slice();
+        app.drill_into_statistics_project();
+
+        assert_eq!(app.statistics_project.as_deref(), Some("Toki"));
+        let labels: Vec<_> = app.project_stats_cache.iter().map(|s| &s.label).collect();
+        assert_eq!(labels, ["Development", "Meetings"]);
+        assert_eq!(app.daily_stats_cache[1].total_hours, 4.0);
+
+        assert!(app.leave_statistics_project());
+        assert_eq!(app.statistics_selected, 1);
+        assert_eq!(app.project_stats_cache.len(), 2);
+        assert!(!app.leave_statistics_project());
+    }
+}
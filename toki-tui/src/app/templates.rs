@@ -0,0 +1,109 @@
+use super::*;
+use crate::config::TemplateConfig;
+use crate::log_notes;
+
+impl App {
+    /// Replace the templates saved on the server, keeping those from the
+    /// config file first.
+    pub fn set_server_templates(&mut self, templates: Vec<TemplateConfig>) {
+        self.templates
+            .retain(|template| template.server_id.is_none());
+        self.templates.extend(templates);
+        self.filter_templates();
+    }
+
+    /// Add a template saved on the server, replacing the one it was saved
+    /// over.
+    pub fn upsert_server_template(&mut self, template: TemplateConfig) {
+        self.templates.retain(|existing| {
+            existing.server_id.is_none() || existing.description != template.description
+        });
+        self.templates.push(template);
+        self.filter_templates();
+    }
+
+    pub fn remove_server_template(&mut self, id: i32) {
+        self.templates
+            .retain(|template| template.server_id != Some(id));
+        self.filter_templates();
+    }
+
+    pub fn highlighted_template(&self) -> Option<&TemplateConfig> {
+        self.filtered_templates.get(self.filtered_template_index)
+    }
+}
+
+/// Name of the template saved from `entry`: its note, or its project and
+/// activity when it has none.
+pub fn template_name(entry: &TimeEntry) -> String {
+    let note = entry
+        .note
+        .as_deref()
+        .map(log_notes::strip_tag)
+        .unwrap_or_default()
+        .trim();
+    if note.is_empty() {
+        format!("{}: {}", entry.project_name, entry.activity_name)
+    } else {
+        note.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+
+    fn server_template(id: i32, name: &str) -> TemplateConfig {
+        TemplateConfig {
+            description: name.to_string(),
+            project: "Toki".to_string(),
+            activity: "Meetings".to_string(),
+            note: name.to_string(),
+            duration_minutes: Some(15),
+            server_id: Some(id),
+        }
+    }
+
+    #[test]
+    fn server_templates_follow_config_templates() {
+        let mut app = test_app();
+        app.templates = vec![TemplateConfig {
+            description: "From config".to_string(),
+            ..TemplateConfig::default()
+        }];
+
+        app.set_server_templates(vec![server_template(1, "Standup")]);
+        app.upsert_server_template(server_template(2, "Standup"));
+        app.upsert_server_template(server_template(3, "Planning"));
+        app.remove_server_template(3);
+
+        let names: Vec<_> = app
+            .templates
+            .iter()
+            .map(|t| (t.description.as_str(), t.server_id))
+            .collect();
+        assert_eq!(names, vec![("From config", None), ("Standup", Some(2))]);
+        assert_eq!(app.filtered_templates.len(), 2);
+    }
+
+    #[test]
+    fn template_name_prefers_the_note() {
+        let mut entry = time_entry(
+            "1",
+            "p1",
+            "Toki",
+            "a1",
+            "Meetings",
+            "2026-10-16",
+            0.25,
+            Some("Standup  [log:a3f8b2]"),
+            None,
+            None,
+        );
+        assert_eq!(template_name(&entry), "Standup");
+
+        entry.note = None;
+        assert_eq!(template_name(&entry), "Toki: Meetings");
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+use crate::offline_queue::QueuedEntry;
+
+/// How long after a change it can still be undone or redone.
+pub const UNDO_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+/// Changes remembered for undo, older ones are forgotten.
+const UNDO_LIMIT: usize = 20;
+
+/// A change to saved entries that can be reverted through the API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryChange {
+    Created(QueuedEntry),
+    Deleted(QueuedEntry),
+    /// Saved entries are found by what they look like, since re-creating a
+    /// deleted entry gives it a new registration id.
+    Edited {
+        before: QueuedEntry,
+        after: QueuedEntry,
+    },
+}
+
+impl EntryChange {
+    /// The change that reverts this one.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Created(entry) => Self::Deleted(entry.clone()),
+            Self::Deleted(entry) => Self::Created(entry.clone()),
+            Self::Edited { before, after } => Self::Edited {
+                before: after.clone(),
+                after: before.clone(),
+            },
+        }
+    }
+
+    /// "delete of Toki / Development", for status messages.
+    pub fn describe(&self) -> String {
+        let (kind, entry) = match self {
+            Self::Created(entry) => ("new entry", entry),
+            Self::Deleted(entry) => ("delete", entry),
+            Self::Edited { before, .. } => ("edit", before),
+        };
+        format!(
+            "{} of {} / {}",
+            kind, entry.project_name, entry.activity_name
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UndoRecord {
+    pub change: EntryChange,
+    /// When the change was made, or undone for the redo stack.
+    pub at: Instant,
+}
+
+impl App {
+    /// Remember a change made by the user so `u` can revert it. Forgets
+    /// anything that was undone, as redoing it no longer makes sense.
+    pub fn record_change(&mut self, change: EntryChange) {
+        self.redo_stack.clear();
+        push_record(&mut self.undo_stack, change, Instant::now());
+    }
+
+    /// Take the latest change that can still be undone. Changes older than
+    /// [`UNDO_WINDOW`] are dropped.
+    pub fn take_undo(&mut self, now: Instant) -> Option<UndoRecord> {
+        take_recent(&mut self.undo_stack, now)
+    }
+
+    /// Take the latest undone change that can still be redone.
+    pub fn take_redo(&mut self, now: Instant) -> Option<UndoRecord> {
+        take_recent(&mut self.redo_stack, now)
+    }
+
+    /// Remember that `change` was undone, so `U` can redo it.
+    pub fn record_undone(&mut self, change: EntryChange) {
+        push_record(&mut self.redo_stack, change, Instant::now());
+    }
+
+    /// Remember that `change` was redone, so it can be undone again.
+    pub fn record_redone(&mut self, change: EntryChange) {
+        push_record(&mut self.undo_stack, change, Instant::now());
+    }
+
+    /// Put back a record whose undo or redo failed, so it can be retried.
+    pub fn restore_undo(&mut self, record: UndoRecord) {
+        self.undo_stack.push(record);
+    }
+
+    pub fn restore_redo(&mut self, record: UndoRecord) {
+        self.redo_stack.push(record);
+    }
+
+    /// Registration id of the loaded entry that looks like `entry`.
+    pub fn find_registration(&self, entry: &QueuedEntry) -> Option<String> {
+        self.time_entries
+            .iter()
+            .find(|e| entry.matches(e))
+            .map(|e| e.registration_id.clone())
+    }
+}
+
+fn push_record(stack: &mut Vec<UndoRecord>, change: EntryChange, at: Instant) {
+    stack.push(UndoRecord { change, at });
+    if stack.len() > UNDO_LIMIT {
+        stack.remove(0);
+    }
+}
+
+fn take_recent(stack: &mut Vec<UndoRecord>, now: Instant) -> Option<UndoRecord> {
+    stack.retain(|record| now.saturating_duration_since(record.at) < UNDO_WINDOW);
+    stack.pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{queued_entry, test_app};
+    use time::macros::datetime;
+
+    fn entry(note: &str) -> QueuedEntry {
+        queued_entry(
+            datetime!(2026-10-16 08:00 UTC),
+            datetime!(2026-10-16 09:00 UTC),
+            note,
+        )
+    }
+
+    #[test]
+    fn inverses_revert_changes() {
+        let edit = EntryChange::Edited {
+            before: entry("old"),
+            after: entry("new"),
+        };
+        assert_eq!(
+            edit.inverse(),
+            EntryChange::Edited {
+                before: entry("new"),
+                after: entry("old"),
+            }
+        );
+        assert_eq!(
+            EntryChange::Deleted(entry("x")).inverse(),
+            EntryChange::Created(entry("x"))
+        );
+        assert_eq!(
+            EntryChange::Deleted(entry("x")).describe(),
+            "delete of Toki / Development"
+        );
+    }
+
+    #[test]
+    fn changes_can_be_undone_within_the_window() {
+        let mut app = test_app();
+        app.record_change(EntryChange::Deleted(entry("first")));
+        app.record_change(EntryChange::Deleted(entry("second")));
+        let now = Instant::now();
+
+        let record = app.take_undo(now).unwrap();
+        assert_eq!(record.change, EntryChange::Deleted(entry("second")));
+        app.record_undone(record.change);
+        assert!(app.take_redo(now).is_some());
+
+        assert!(app.take_undo(now + UNDO_WINDOW).is_none());
+        assert!(app.undo_stack.is_empty());
+    }
+
+    #[test]
+    fn new_changes_forget_what_was_undone() {
+        let mut app = test_app();
+        app.record_undone(EntryChange::Deleted(entry("undone")));
+        app.record_change(EntryChange::Deleted(entry("new")));
+
+        assert!(app.take_redo(Instant::now()).is_none());
+    }
+}
@@ -0,0 +1,214 @@
+use super::*;
+use time::{Date, Weekday};
+
+/// Something to sort out before the week's time can be attested.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecklistItem {
+    /// Working days up to today with nothing logged.
+    UnfilledDays(Vec<Weekday>),
+    /// Hours still missing when no single day is empty.
+    HoursShort(f64),
+    /// Entries whose note is empty (ignoring any log tag).
+    MissingNotes(usize),
+    /// Entries that overlap another entry on the same day.
+    Overlaps(usize),
+}
+
+impl ChecklistItem {
+    pub fn label(&self) -> String {
+        match self {
+            Self::UnfilledDays(days) => {
+                let days: Vec<&str> = days.iter().map(|day| short_weekday(*day)).collect();
+                format!("Nothing logged on {}", days.join(", "))
+            }
+            Self::HoursShort(hours) => format!("{hours:.1}h left to reach scheduled hours"),
+            Self::MissingNotes(1) => "1 entry without a note".to_string(),
+            Self::MissingNotes(count) => format!("{count} entries without a note"),
+            Self::Overlaps(1) => "1 overlapping entry".to_string(),
+            Self::Overlaps(count) => format!("{count} overlapping entries"),
+        }
+    }
+}
+
+pub(super) fn short_weekday(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Monday => "Mon",
+        Weekday::Tuesday => "Tue",
+        Weekday::Wednesday => "Wed",
+        Weekday::Thursday => "Thu",
+        Weekday::Friday => "Fri",
+        Weekday::Saturday => "Sat",
+        Weekday::Sunday => "Sun",
+    }
+}
+
+impl App {
+    /// Checklist shown from the configured reminder day until the end of the
+    /// week. Empty on other days, when reminders are off, or when nothing
+    /// is missing.
+    pub fn week_checklist(&self) -> Vec<ChecklistItem> {
+        let today = crate::time_utils::local_today();
+        match self.attest_reminder_day {
+            Some(day)
+                if today.weekday().number_days_from_monday() >= day.number_days_from_monday() =>
+            {
+                self.week_checklist_on(today)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// What is missing in the week of `today`, counting working days up to
+    /// and including `today`.
+    pub(super) fn week_checklist_on(&self, today: Date) -> Vec<ChecklistItem> {
+        let monday = today - time::Duration::days(today.weekday().number_days_from_monday() as i64);
+        let sunday = monday + time::Duration::days(6);
+        let (from, to) = (format_date(monday), format_date(sunday));
+        let week: Vec<&TimeEntry> = self
+            .time_entries
+            .iter()
+            .filter(|entry| entry.date >= from && entry.date <= to)
+            .collect();
+
+        // Already attested weeks can't be changed anymore.
+        if !week.is_empty() && week.iter().all(|entry| entry.status.is_locked()) {
+            return Vec::new();
+        }
+
+        let mut items = Vec::new();
+
+        let logged_hours: f64 = week.iter().map(|entry| entry.hours).sum();
+        let missing_hours =
+            self.scheduled_hours_per_week - logged_hours - self.absence_hours_this_week;
+        // Absence is only known per week, so empty days are only worth
+        // pointing out while the week is short.
+        if missing_hours > 0.05 {
+            let last_working_day = today.min(monday + time::Duration::days(4));
+            let unfilled: Vec<Weekday> = (0..)
+                .map(|offset| monday + time::Duration::days(offset))
+                .take_while(|date| *date <= last_working_day)
+                .filter(|date| {
+                    let date = format_date(*date);
+                    !week.iter().any(|entry| entry.date == date)
+                })
+                .map(Date::weekday)
+                .collect();
+            if unfilled.is_empty() {
+                items.push(ChecklistItem::HoursShort(missing_hours));
+            } else {
+                items.push(ChecklistItem::UnfilledDays(unfilled));
+            }
+        }
+
+        let missing_notes = week
+            .iter()
+            .filter(|entry| {
+                entry
+                    .note
+                    .as_deref()
+                    .is_none_or(|note| crate::log_notes::strip_tag(note).trim().is_empty())
+            })
+            .count();
+        if missing_notes > 0 {
+            items.push(ChecklistItem::MissingNotes(missing_notes));
+        }
+
+        let overlaps = week
+            .iter()
+            .filter(|entry| self.is_entry_overlapping(&entry.registration_id))
+            .count();
+        if overlaps > 0 {
+            items.push(ChecklistItem::Overlaps(overlaps));
+        }
+
+        items
+    }
+}
+
+pub(super) fn format_date(date: Date) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.year(),
+        date.month() as u8,
+        date.day()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{day_entry, test_app, timed_entry};
+    use time::macros::{date, datetime};
+
+    fn entry(id: &str, date: Date, hours: f64, note: Option<&str>) -> TimeEntry {
+        day_entry(id, "Toki", "Dev", date, hours, note)
+    }
+
+    #[test]
+    fn lists_empty_days_notes_and_overlaps() {
+        let mut app = test_app();
+        app.update_history(vec![
+            entry("1", date!(2026 - 03 - 02), 8.0, Some("Planning")),
+            timed_entry(
+                "2",
+                "Dev",
+                datetime!(2026-03-04 09:00 UTC),
+                datetime!(2026-03-04 11:00 UTC),
+                None,
+            ),
+            timed_entry(
+                "3",
+                "Dev",
+                datetime!(2026-03-04 10:00 UTC),
+                datetime!(2026-03-04 12:00 UTC),
+                Some("Review"),
+            ),
+        ]);
+
+        let items = app.week_checklist_on(date!(2026 - 03 - 06));
+
+        assert_eq!(
+            items,
+            vec![
+                ChecklistItem::UnfilledDays(vec![
+                    Weekday::Tuesday,
+                    Weekday::Thursday,
+                    Weekday::Friday
+                ]),
+                ChecklistItem::MissingNotes(1),
+                ChecklistItem::Overlaps(2),
+            ]
+        );
+        assert_eq!(items[0].label(), "Nothing logged on Tue, Thu, Fri");
+    }
+
+    #[test]
+    fn absence_covers_empty_days() {
+        let mut app = test_app();
+        app.update_history(
+            [
+                date!(2026 - 03 - 02),
+                date!(2026 - 03 - 03),
+                date!(2026 - 03 - 04),
+                date!(2026 - 03 - 05),
+            ]
+            .iter()
+            .enumerate()
+            .map(|(i, date)| entry(&i.to_string(), *date, 8.0, Some("Dev")))
+            .collect(),
+        );
+        app.absence_hours_this_week = 8.0;
+
+        assert!(app.week_checklist_on(date!(2026 - 03 - 06)).is_empty());
+    }
+
+    #[test]
+    fn attested_weeks_have_nothing_to_do() {
+        let mut app = test_app();
+        let mut attested = entry("1", date!(2026 - 03 - 02), 4.0, None);
+        attested.status = toki_types::TimeEntryStatus::Approved;
+        app.update_history(vec![attested]);
+
+        assert!(app.week_checklist_on(date!(2026 - 03 - 06)).is_empty());
+    }
+}
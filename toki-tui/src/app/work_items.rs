@@ -0,0 +1,162 @@
+use super::*;
+use crate::types::WorkItem;
+
+impl App {
+    /// Show the work item picker with the user's assigned work items, or the
+    /// error loading them.
+    pub fn open_work_item_overlay(&mut self, items: Result<Vec<WorkItem>, String>) {
+        let mut overlay = match items {
+            Ok(items) => WorkItemOverlay {
+                items,
+                ..WorkItemOverlay::default()
+            },
+            Err(error) => WorkItemOverlay {
+                error: Some(error),
+                ..WorkItemOverlay::default()
+            },
+        };
+        filter_work_items(&mut overlay);
+        self.work_item_overlay = Some(overlay);
+    }
+
+    pub fn close_work_item_overlay(&mut self) {
+        self.work_item_overlay = None;
+    }
+
+    pub fn work_item_search_char(&mut self, c: char) {
+        if let Some(overlay) = &mut self.work_item_overlay {
+            overlay.search.insert(c);
+            filter_work_items(overlay);
+        }
+    }
+
+    pub fn work_item_search_backspace(&mut self) {
+        if let Some(overlay) = &mut self.work_item_overlay {
+            overlay.search.backspace();
+            filter_work_items(overlay);
+        }
+    }
+
+    pub fn work_item_move(&mut self, down: bool) {
+        if let Some(overlay) = &mut self.work_item_overlay {
+            let len = overlay.filtered.len();
+            if len == 0 {
+                return;
+            }
+            overlay.selected = Some(match overlay.selected {
+                None => 0,
+                Some(i) if down => (i + 1).min(len - 1),
+                Some(i) => i.saturating_sub(1),
+            });
+        }
+    }
+
+    /// Insert `#1234 Title` for the selected work item into the note. The
+    /// running timer is also linked to it, unless an entry is being edited.
+    pub fn work_item_confirm(&mut self) {
+        let item = self.work_item_overlay.as_ref().and_then(|overlay| {
+            let index = *overlay.filtered.get(overlay.selected?)?;
+            overlay.items.get(index).cloned()
+        });
+
+        self.work_item_overlay = None;
+
+        if let Some(item) = item {
+            if !self.is_in_edit_mode() {
+                self.description_work_item_id = Some(item.id.clone());
+            }
+            let reference = item.reference();
+            if !self.description_input.value.is_empty()
+                && !self.description_input.value.ends_with(' ')
+            {
+                self.description_input.insert(' ');
+            }
+            for c in reference.chars() {
+                self.description_input.insert(c);
+            }
+        }
+    }
+}
+
+/// Match the search against the work item's id and title, or keep the API's
+/// order when the search is empty.
+fn filter_work_items(overlay: &mut WorkItemOverlay) {
+    let query = overlay.search.value.trim().trim_start_matches('#');
+    overlay.filtered = if query.is_empty() {
+        (0..overlay.items.len()).collect()
+    } else {
+        let matcher = SkimMatcherV2::default();
+        let mut scored: Vec<(usize, i64)> = overlay
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                matcher
+                    .fuzzy_match(&format!("{} {}", item.id, item.title), query)
+                    .map(|score| (index, score))
+            })
+            .collect();
+        scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(index, _)| index).collect()
+    };
+    overlay.selected = if overlay.filtered.is_empty() {
+        None
+    } else {
+        Some(0)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_app;
+
+    fn work_item(id: &str, title: &str) -> WorkItem {
+        WorkItem {
+            organization: "org".to_string(),
+            project: "Toki".to_string(),
+            id: id.to_string(),
+            title: title.to_string(),
+            category: "task".to_string(),
+            state_name: "Active".to_string(),
+            url: format!("https://dev.azure.com/org/Toki/_workitems/edit/{id}"),
+        }
+    }
+
+    #[test]
+    fn search_matches_ids_and_titles() {
+        let mut app = test_app();
+        app.open_work_item_overlay(Ok(vec![
+            work_item("1201", "Login page"),
+            work_item("1234", "Timer sync"),
+        ]));
+
+        for c in "#1234".chars() {
+            app.work_item_search_char(c);
+        }
+        let overlay = app.work_item_overlay.as_ref().unwrap();
+        assert_eq!(overlay.filtered, vec![1]);
+
+        app.work_item_search_backspace();
+        app.work_item_search_backspace();
+        app.work_item_search_backspace();
+        for c in " timer".chars() {
+            app.work_item_search_char(c);
+        }
+        let overlay = app.work_item_overlay.as_ref().unwrap();
+        assert_eq!(overlay.filtered, vec![1]);
+    }
+
+    #[test]
+    fn confirm_appends_the_reference_to_the_note() {
+        let mut app = test_app();
+        app.description_input = TextInput::from_str("Fixing");
+        app.open_work_item_overlay(Ok(vec![work_item("1234", "Timer sync")]));
+
+        app.work_item_confirm();
+
+        assert_eq!(app.description_input.value, "Fixing #1234 Timer sync");
+        assert_eq!(app.description_work_item_id.as_deref(), Some("1234"));
+        assert!(app.work_item_overlay.is_none());
+    }
+}
@@ -1,21 +1,37 @@
 use crate::api::ApiClient;
-use crate::app::App;
+use crate::app::{App, RecurringOccurrence};
 use crate::runtime::restore_active_timer;
+use crate::time_utils;
+use anyhow::{Context, Result};
 
 pub async fn initialize_app_state(app: &mut App, client: &mut ApiClient) {
     app.is_loading = true;
 
-    let today = time::OffsetDateTime::now_utc().date();
-    let month_ago = today - time::Duration::days(30);
-
-    match client.get_time_entries(month_ago, today).await {
-        Ok(entries) => {
-            app.update_history(entries);
-            app.rebuild_history_list();
+    // Load the time zone first so every date below is the user's local date.
+    match client.get_time_zone().await {
+        Ok(Some(time_zone)) => {
+            if !time_utils::set_time_zone(&time_zone) {
+                eprintln!(
+                    "Warning: Unknown time zone '{}', using system time",
+                    time_zone
+                );
+            }
         }
-        Err(e) => eprintln!("Warning: Could not load history: {}", e),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Could not load time zone: {}", e),
+    }
+
+    match client.get_status().await {
+        Ok(status) => app.status_banner = status.banner,
+        Err(e) => eprintln!("Warning: Could not load server status: {}", e),
     }
 
+    if let Err(e) = load_history(app, client).await {
+        eprintln!("Warning: Could not load history: {}", e);
+    }
+
+    let today = time_utils::local_today();
+
     match client.get_projects().await {
         Ok(projects) => {
             app.set_projects_activities(projects, vec![]);
@@ -23,6 +39,21 @@ pub async fn initialize_app_state(app: &mut App, client: &mut ApiClient) {
         Err(e) => eprintln!("Warning: Could not load projects: {}", e),
     }
 
+    match client.get_templates().await {
+        Ok(templates) => app.set_server_templates(templates),
+        Err(e) => eprintln!("Warning: Could not load templates: {}", e),
+    }
+
+    match client.get_favorites().await {
+        Ok(favorites) => app.favorites = favorites,
+        Err(e) => eprintln!("Warning: Could not load favorites: {}", e),
+    }
+
+    match client.get_calendar_suggestions().await {
+        Ok(suggestions) => app.set_calendar_suggestions(suggestions),
+        Err(e) => eprintln!("Warning: Could not load calendar suggestions: {}", e),
+    }
+
     match client.get_active_timer().await {
         Ok(Some(timer)) => {
             restore_active_timer(app, timer);
@@ -32,19 +63,91 @@ pub async fn initialize_app_state(app: &mut App, client: &mut ApiClient) {
         Err(e) => eprintln!("Warning: Could not check active timer: {}", e),
     }
 
-    let local_today = time::OffsetDateTime::now_utc()
-        .to_offset(time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC))
-        .date();
-    let days_from_monday = local_today.weekday().number_days_from_monday() as i64;
-    let week_start = local_today - time::Duration::days(days_from_monday);
+    match client.get_paused_timers().await {
+        Ok(paused) => app.paused_timers = paused,
+        Err(e) => eprintln!("Warning: Could not load paused timers: {}", e),
+    }
+
+    match client.take_timer_auto_stop().await {
+        Ok(Some(stopped)) => app.show_timer_auto_stop(&stopped),
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Could not check timer auto-stop: {}", e),
+    }
+
+    let days_from_monday = today.weekday().number_days_from_monday() as i64;
+    let week_start = today - time::Duration::days(days_from_monday);
     let week_end = week_start + time::Duration::days(6);
 
     match client.get_time_info(week_start, week_end).await {
         Ok(time_info) => {
             app.scheduled_hours_per_week = time_info.scheduled_hours;
+            app.absence_hours_this_week = time_info.absence_hours;
         }
         Err(e) => eprintln!("Warning: Could not load time info: {}", e),
     }
 
     app.is_loading = false;
 }
+
+/// Load the last month of time entries into the history.
+pub async fn load_history(app: &mut App, client: &mut ApiClient) -> Result<()> {
+    let today = time_utils::local_today();
+    let month_ago = today - time::Duration::days(crate::app::RECENT_DAYS);
+
+    let entries = client.get_time_entries(month_ago, today).await?;
+    app.merge_history(entries, month_ago, today);
+    Ok(())
+}
+
+/// Create the time entry for a recurring meeting, looking up its project and
+/// activity by name.
+pub async fn log_recurring_entry(
+    app: &App,
+    client: &mut ApiClient,
+    occurrence: &RecurringOccurrence,
+) -> Result<()> {
+    let template = &occurrence.template;
+    log_named_entry(
+        app,
+        client,
+        (&template.project, &template.activity),
+        &template.note,
+        occurrence.start,
+        occurrence.end,
+    )
+    .await
+}
+
+/// Create a time entry on the project and activity with these names.
+pub async fn log_named_entry(
+    app: &App,
+    client: &mut ApiClient,
+    (project_name, activity_name): (&str, &str),
+    note: &str,
+    start: time::OffsetDateTime,
+    end: time::OffsetDateTime,
+) -> Result<()> {
+    let project = app
+        .projects
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(project_name))
+        .with_context(|| format!("Project '{}' not found", project_name))?;
+    let activity = client
+        .get_activities(&project.id)
+        .await?
+        .into_iter()
+        .find(|a| a.name.eq_ignore_ascii_case(activity_name))
+        .with_context(|| format!("Activity '{}' not found", activity_name))?;
+
+    client
+        .create_time_entry(
+            &project.id,
+            &project.name,
+            &activity.id,
+            &activity.name,
+            start,
+            end,
+            note,
+        )
+        .await
+}
@@ -26,4 +26,13 @@ pub enum Commands {
     Version,
     /// Show current login status
     Status,
+    /// Set the calendar feed (ICS or webcal URL) meetings are suggested from
+    CalendarFeed {
+        /// Feed URL, usually the "secret address in iCal format" of a calendar
+        #[arg(required_unless_present = "remove")]
+        url: Option<String>,
+        /// Stop suggesting meetings
+        #[arg(long, conflicts_with = "url")]
+        remove: bool,
+    },
 }
@@ -1,3 +1,6 @@
+use crate::export::ExportFormat;
+use crate::rounding::{Rounding, RoundingMode};
+use crate::ui::theme::ThemeName;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -8,6 +11,173 @@ pub struct TemplateConfig {
     pub project: String,
     pub activity: String,
     pub note: String,
+    /// Length of the entry logged with `Ctrl+L` in the template picker.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<u32>,
+    /// Id of a template saved on the server, `None` for templates from this
+    /// file.
+    #[serde(skip)]
+    pub server_id: Option<i32>,
+}
+
+/// A meeting on fixed weekdays and times, offered for logging on every
+/// matching day it hasn't been logged yet.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RecurringTemplateConfig {
+    pub description: String,
+    pub project: String,
+    pub activity: String,
+    #[serde(default)]
+    pub note: String,
+    /// Weekdays the meeting is on, e.g. `["mon", "wed", "fri"]`.
+    pub days: Vec<String>,
+    /// Local start time, `HH:MM`.
+    pub start: String,
+    /// Local end time, `HH:MM`.
+    pub end: String,
+}
+
+impl RecurringTemplateConfig {
+    /// The recognized weekdays in `days`.
+    pub fn weekdays(&self) -> Vec<time::Weekday> {
+        self.days
+            .iter()
+            .filter_map(|day| parse_weekday(day))
+            .collect()
+    }
+
+    /// Start and end time, or `None` when either doesn't parse or the
+    /// meeting ends before it starts.
+    pub fn time_range(&self) -> Option<(time::Time, time::Time)> {
+        let start = parse_hh_mm(&self.start)?;
+        let end = parse_hh_mm(&self.end)?;
+        (start < end).then_some((start, end))
+    }
+}
+
+/// Default project for the missing workdays wizard, opened with `F` in the
+/// timer view.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct FillConfig {
+    pub project: String,
+    pub activity: String,
+    pub note: String,
+    /// Local time filled days start at, `HH:MM`.
+    pub start: String,
+}
+
+impl Default for FillConfig {
+    fn default() -> Self {
+        Self {
+            project: String::new(),
+            activity: String::new(),
+            note: String::new(),
+            start: "08:00".to_string(),
+        }
+    }
+}
+
+impl FillConfig {
+    /// Whether a default project and activity are set.
+    pub fn is_set(&self) -> bool {
+        !self.project.trim().is_empty() && !self.activity.trim().is_empty()
+    }
+
+    /// The configured start time, 08:00 when it doesn't parse.
+    pub fn start_time(&self) -> time::Time {
+        parse_hh_mm(&self.start).unwrap_or(time::macros::time!(08:00))
+    }
+}
+
+/// The Azure DevOps project whose sprints the TUI follows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct BoardConfig {
+    pub organization: String,
+    pub project: String,
+    /// Team whose sprints to use. Empty uses the project's board team.
+    pub team: String,
+}
+
+impl BoardConfig {
+    /// The configured project, or `None` when organization or project is
+    /// missing.
+    pub fn board_project(&self) -> Option<crate::app::BoardProject> {
+        let organization = self.organization.trim();
+        let project = self.project.trim();
+        if organization.is_empty() || project.is_empty() {
+            return None;
+        }
+        let team = self.team.trim();
+        Some(crate::app::BoardProject {
+            organization: organization.to_string(),
+            project: project.to_string(),
+            team: (!team.is_empty()).then(|| team.to_string()),
+        })
+    }
+}
+
+/// Rounding of entry times to the nearest 5, 15 or 30 minutes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RoundingConfig {
+    /// 5, 15 or 30. 0 turns rounding off.
+    pub minutes: u16,
+    /// "save" to have entries rounded as they are saved, or "display" to
+    /// save them as tracked and only show them rounded.
+    pub mode: String,
+}
+
+impl Default for RoundingConfig {
+    fn default() -> Self {
+        Self {
+            minutes: 0,
+            mode: "save".to_string(),
+        }
+    }
+}
+
+impl RoundingConfig {
+    /// The configured rounding, or `None` when it is off or the minutes
+    /// aren't 5, 15 or 30. Unknown modes round on save.
+    pub fn rounding(&self) -> Option<Rounding> {
+        Rounding::new(
+            self.minutes,
+            RoundingMode::parse(&self.mode).unwrap_or_default(),
+        )
+    }
+}
+
+/// Interval lengths for pomodoro mode, toggled with `M` in the timer view.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PomodoroConfig {
+    pub work_minutes: u64,
+    pub break_minutes: u64,
+    /// Ring the terminal bell when an interval ends.
+    pub bell: bool,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            break_minutes: 5,
+            bell: true,
+        }
+    }
+}
+
+impl PomodoroConfig {
+    /// The configured intervals, each at least one minute long.
+    pub fn settings(&self) -> crate::app::PomodoroSettings {
+        crate::app::PomodoroSettings {
+            work: std::time::Duration::from_secs(self.work_minutes.max(1) * 60),
+            short_break: std::time::Duration::from_secs(self.break_minutes.max(1) * 60),
+            bell: self.bell,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,9 +197,48 @@ pub struct TokiConfig {
     /// and back to Normal when stopped. Default: true.
     #[serde(default = "default_auto_resize_timer")]
     pub auto_resize_timer: bool,
+    /// Weekday from which the end-of-week attest checklist is shown, or
+    /// "off". Default: "friday".
+    #[serde(default = "default_attest_reminder_day")]
+    pub attest_reminder_day: String,
+    /// Name shown to other clients when this TUI owns the running timer.
+    /// Defaults to `toki-tui@<hostname>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Work and break lengths for pomodoro mode.
+    #[serde(default)]
+    pub pomodoro: PomodoroConfig,
+    /// Minutes without a key press, or with the computer asleep, after which
+    /// the TUI asks what to do with the time a running timer counted. 0 turns
+    /// it off. Default: 15.
+    #[serde(default = "default_idle_minutes")]
+    pub idle_minutes: u64,
+    /// Directory `E` in the history view writes exports to. `~` expands to
+    /// the home directory. Defaults to the Downloads directory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_dir: Option<String>,
+    /// Format of history exports: "csv" or "markdown". Default: "csv".
+    #[serde(default = "default_export_format")]
+    pub export_format: String,
+    /// Color palette: "default", "light", "high-contrast" or "solarized".
+    /// Default: "default".
+    #[serde(default = "default_theme")]
+    pub theme: String,
     /// Named presets of (project, activity, note) applied via the template picker.
     #[serde(default)]
     pub template: Vec<TemplateConfig>,
+    /// Recurring meetings offered for logging on each matching day.
+    #[serde(default)]
+    pub recurring: Vec<RecurringTemplateConfig>,
+    /// Default project for filling workdays with nothing logged.
+    #[serde(default)]
+    pub fill: FillConfig,
+    /// Rounding of entry times to match invoicing rules. Off by default.
+    #[serde(default)]
+    pub rounding: RoundingConfig,
+    /// Azure DevOps project for sprint capacity. Unset by default.
+    #[serde(default)]
+    pub board: BoardConfig,
 }
 
 fn default_api_url() -> String {
@@ -44,6 +253,22 @@ fn default_auto_resize_timer() -> bool {
     true
 }
 
+fn default_idle_minutes() -> u64 {
+    15
+}
+
+fn default_export_format() -> String {
+    "csv".to_string()
+}
+
+fn default_theme() -> String {
+    "default".to_string()
+}
+
+fn default_attest_reminder_day() -> String {
+    "friday".to_string()
+}
+
 impl Default for TokiConfig {
     fn default() -> Self {
         Self {
@@ -51,12 +276,69 @@ impl Default for TokiConfig {
             task_filter: String::new(),
             git_default_prefix: default_git_prefix(),
             auto_resize_timer: default_auto_resize_timer(),
+            attest_reminder_day: default_attest_reminder_day(),
+            device_name: None,
+            pomodoro: PomodoroConfig::default(),
+            idle_minutes: default_idle_minutes(),
+            export_dir: None,
+            export_format: default_export_format(),
+            theme: default_theme(),
             template: Vec::new(),
+            recurring: Vec::new(),
+            fill: FillConfig::default(),
+            rounding: RoundingConfig::default(),
+            board: BoardConfig::default(),
         }
     }
 }
 
 impl TokiConfig {
+    /// The configured device name, or `toki-tui@<hostname>`.
+    pub fn device_name(&self) -> String {
+        if let Some(name) = self.device_name.as_deref().map(str::trim) {
+            if !name.is_empty() {
+                return name.to_string();
+            }
+        }
+        match hostname() {
+            Some(host) => format!("toki-tui@{host}"),
+            None => "toki-tui".to_string(),
+        }
+    }
+
+    /// The weekday the attest checklist starts showing, or `None` when it is
+    /// turned off (or the day isn't recognized).
+    pub fn attest_reminder_day(&self) -> Option<time::Weekday> {
+        parse_weekday(&self.attest_reminder_day)
+    }
+
+    /// How long counts as idle, or `None` when idle detection is off.
+    pub fn idle_threshold(&self) -> Option<time::Duration> {
+        (self.idle_minutes > 0).then(|| time::Duration::minutes(self.idle_minutes as i64))
+    }
+
+    /// Where history exports are written, if a directory can be found.
+    pub fn export_dir(&self) -> Option<PathBuf> {
+        match self.export_dir.as_deref().map(str::trim) {
+            Some(dir) if !dir.is_empty() => match dir.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+                None => Some(PathBuf::from(dir)),
+            },
+            _ => dirs::download_dir().or_else(dirs::home_dir),
+        }
+    }
+
+    /// The configured export format, falling back to CSV when unrecognized.
+    pub fn export_format(&self) -> ExportFormat {
+        ExportFormat::parse(&self.export_format).unwrap_or_default()
+    }
+
+    /// The configured theme, falling back to the default one when
+    /// unrecognized.
+    pub fn theme(&self) -> ThemeName {
+        ThemeName::parse(&self.theme).unwrap_or_default()
+    }
+
     pub fn config_path() -> Result<PathBuf> {
         Ok(dirs::config_dir()
             .context("Cannot determine config directory")?
@@ -90,6 +372,10 @@ impl TokiConfig {
             .set_default("task_filter", "")?
             .set_default("git_default_prefix", default_git_prefix())?
             .set_default("auto_resize_timer", default_auto_resize_timer())?
+            .set_default("attest_reminder_day", default_attest_reminder_day())?
+            .set_default("idle_minutes", default_idle_minutes())?
+            .set_default("export_format", default_export_format())?
+            .set_default("theme", default_theme())?
             .add_source(config::File::from(path.clone()).required(false))
             .add_source(
                 config::Environment::with_prefix("TOKI_TUI")
@@ -104,3 +390,30 @@ impl TokiConfig {
             .with_context(|| format!("Failed to parse config from {}", path.display()))
     }
 }
+
+fn parse_weekday(day: &str) -> Option<time::Weekday> {
+    use time::Weekday;
+    match day.trim().to_ascii_lowercase().as_str() {
+        "monday" | "mon" => Some(Weekday::Monday),
+        "tuesday" | "tue" => Some(Weekday::Tuesday),
+        "wednesday" | "wed" => Some(Weekday::Wednesday),
+        "thursday" | "thu" => Some(Weekday::Thursday),
+        "friday" | "fri" => Some(Weekday::Friday),
+        "saturday" | "sat" => Some(Weekday::Saturday),
+        "sunday" | "sun" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+fn parse_hh_mm(value: &str) -> Option<time::Time> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    time::Time::from_hms(hours.parse().ok()?, minutes.parse().ok()?, 0).ok()
+}
+
+fn hostname() -> Option<String> {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+}
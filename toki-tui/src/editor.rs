@@ -1,5 +1,6 @@
 use anyhow::Result;
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -19,7 +20,7 @@ pub async fn open_editor(path: &Path) -> Result<()> {
 
     // Leave TUI
     disable_raw_mode()?;
-    execute!(std::io::stdout(), LeaveAlternateScreen)?;
+    execute!(std::io::stdout(), DisableMouseCapture, LeaveAlternateScreen)?;
 
     // Spawn editor and wait; capture result so TUI is always restored below.
     let status_res = tokio::process::Command::new(program)
@@ -30,8 +31,8 @@ pub async fn open_editor(path: &Path) -> Result<()> {
 
     // Re-enter TUI — always attempt restoration even if the editor failed.
     // If restoration itself fails, combine with any prior editor error.
-    let restore_res =
-        enable_raw_mode().and_then(|_| execute!(std::io::stdout(), EnterAlternateScreen));
+    let restore_res = enable_raw_mode()
+        .and_then(|_| execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture));
 
     // Prefer the original editor error; surface restoration error only if no prior error.
     let status = match (status_res, restore_res) {
@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::time_utils::to_local_time;
+use crate::types::TimeEntry;
+
+/// File format for exported history entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "markdown" | "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "Date", "Start", "End", "Hours", "Project", "Activity", "Note",
+];
+
+/// Write `entries` to a new file in `dir`, named after the current local
+/// time. Returns the path written.
+pub fn write_entries(
+    dir: &Path,
+    entries: &[&TimeEntry],
+    format: ExportFormat,
+) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+
+    let now = to_local_time(time::OffsetDateTime::now_utc());
+    let name = format!(
+        "toki-entries-{}-{:02}{:02}{:02}.{}",
+        now.date(),
+        now.hour(),
+        now.minute(),
+        now.second(),
+        format.extension()
+    );
+    let path = dir.join(name);
+    std::fs::write(&path, render(entries, format))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Render entries as a table, oldest first, with a total row at the end.
+pub fn render(entries: &[&TimeEntry], format: ExportFormat) -> String {
+    let mut entries = entries.to_vec();
+    entries.sort_by(|a, b| a.date.cmp(&b.date).then(a.start_time.cmp(&b.start_time)));
+
+    let mut rows: Vec<[String; 7]> = entries.iter().map(|entry| row(entry)).collect();
+    let total: f64 = entries.iter().map(|entry| entry.hours).sum();
+    rows.push([
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        format!("{total:.2}"),
+        String::new(),
+        String::new(),
+        String::new(),
+    ]);
+
+    match format {
+        ExportFormat::Csv => render_csv(&rows),
+        ExportFormat::Markdown => render_markdown(&rows),
+    }
+}
+
+fn row(entry: &TimeEntry) -> [String; 7] {
+    let time = |instant: Option<time::OffsetDateTime>| {
+        instant
+            .map(|instant| {
+                let local = to_local_time(instant);
+                format!("{:02}:{:02}", local.hour(), local.minute())
+            })
+            .unwrap_or_default()
+    };
+    let note = entry.note.as_deref().map(crate::log_notes::strip_tag);
+    [
+        entry.date.clone(),
+        time(entry.start_time),
+        time(entry.end_time),
+        format!("{:.2}", entry.hours),
+        entry.project_name.clone(),
+        entry.activity_name.clone(),
+        note.unwrap_or_default().trim().to_string(),
+    ]
+}
+
+fn render_csv(rows: &[[String; 7]]) -> String {
+    let field = |text: &str| {
+        if text.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut csv = HEADER.join(",");
+    csv.push('\n');
+    for row in rows {
+        let line: Vec<String> = row.iter().map(|cell| field(cell)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn render_markdown(rows: &[[String; 7]]) -> String {
+    let cell = |text: &str| text.replace('|', "\\|").replace(['\n', '\r'], " ");
+
+    let mut markdown = format!("| {} |\n", HEADER.join(" | "));
+    markdown.push_str("|------|-------|-----|------:|---------|----------|------|\n");
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|text| cell(text)).collect();
+        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::time_entry;
+
+    fn entries() -> Vec<TimeEntry> {
+        vec![
+            time_entry(
+                "2",
+                "p1",
+                "Toki",
+                "a1",
+                "Development",
+                "2026-10-14",
+                1.5,
+                Some("Fix | pipes, \"quotes\"  [log:a3f8b2]"),
+                None,
+                None,
+            ),
+            time_entry(
+                "1",
+                "p1",
+                "Toki",
+                "a2",
+                "Support",
+                "2026-10-13",
+                6.0,
+                None,
+                None,
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn csv_lists_entries_oldest_first_with_a_total() {
+        let entries = entries();
+        let refs: Vec<&TimeEntry> = entries.iter().collect();
+
+        let csv = render(&refs, ExportFormat::Csv);
+
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            vec![
+                "Date,Start,End,Hours,Project,Activity,Note",
+                "2026-10-13,,,6.00,Toki,Support,",
+                "2026-10-14,,,1.50,Toki,Development,\"Fix | pipes, \"\"quotes\"\"\"",
+                "Total,,,7.50,,,",
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes() {
+        let entries = entries();
+        let refs: Vec<&TimeEntry> = entries.iter().collect();
+
+        let markdown = render(&refs, ExportFormat::Markdown);
+        let lines: Vec<_> = markdown.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "| Date | Start | End | Hours | Project | Activity | Note |"
+        );
+        assert_eq!(
+            lines[3],
+            "| 2026-10-14 |  |  | 1.50 | Toki | Development | Fix \\| pipes, \"quotes\" |"
+        );
+        assert_eq!(lines.len(), 5);
+    }
+}
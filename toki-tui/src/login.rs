@@ -95,7 +95,7 @@ async fn serve_one_page(port: u16, html: String) {
 }
 
 /// Open a URL in the system default browser.
-fn open_browser(url: &str) {
+pub(crate) fn open_browser(url: &str) {
     #[cfg(target_os = "linux")]
     let _ = std::process::Command::new("xdg-open").arg(url).spawn();
     #[cfg(target_os = "macos")]
@@ -4,9 +4,13 @@ mod bootstrap;
 mod cli;
 mod config;
 mod editor;
+mod export;
 mod git;
 mod log_notes;
 mod login;
+mod offline_queue;
+mod recovery_store;
+mod rounding;
 mod runtime;
 mod session_store;
 mod terminal;
@@ -55,6 +59,9 @@ async fn main() -> Result<()> {
             session_store::clear_session()?;
             println!("Logged out. Session cleared.");
         }
+        Commands::CalendarFeed { url, remove } => {
+            set_calendar_feed(url, remove).await?;
+        }
         Commands::Dev => {
             run_dev_mode().await?;
         }
@@ -68,15 +75,17 @@ async fn main() -> Result<()> {
 
 async fn run_dev_mode() -> Result<()> {
     let cfg = config::TokiConfig::load()?;
+    ui::theme::set_theme(cfg.theme());
     let mut client = ApiClient::dev()?;
     let me = client.me().await?;
 
     println!("Dev mode: logged in as {} ({})\n", me.full_name, me.email);
-    run_ui(App::new(me.id, &cfg), client).await
+    run_ui(App::new(me.id, &cfg), client, true).await
 }
 
 async fn run_real_mode() -> Result<()> {
     let cfg = config::TokiConfig::load()?;
+    ui::theme::set_theme(cfg.theme());
 
     let session_id = match session_store::load_session()? {
         Some(session_id) => session_id,
@@ -85,26 +94,142 @@ async fn run_real_mode() -> Result<()> {
         }
     };
 
-    let mut client = ApiClient::new(&cfg.api_url, &session_id)?;
+    let mut client = ApiClient::new(&cfg.api_url, &session_id, &cfg.device_name())?
+        .with_rounding(cfg.rounding.rounding());
 
     let me = client.me().await?;
     println!("Logged in as {} ({})\n", me.full_name, me.email);
 
-    run_ui(App::new(me.id, &cfg), client).await
+    run_ui(App::new(me.id, &cfg), client, false).await
+}
+
+async fn set_calendar_feed(url: Option<String>, remove: bool) -> Result<()> {
+    let cfg = config::TokiConfig::load()?;
+    let Some(session_id) = session_store::load_session()? else {
+        anyhow::bail!("Not logged in. Run `toki-tui login` to authenticate.");
+    };
+    let mut client = ApiClient::new(&cfg.api_url, &session_id, &cfg.device_name())?;
+
+    match url {
+        Some(url) if !remove => {
+            let url = client.set_calendar_feed(&url).await?;
+            println!("Suggesting meetings from {}", url);
+        }
+        _ => {
+            client.delete_calendar_feed().await?;
+            println!("Calendar feed removed.");
+        }
+    }
+    Ok(())
 }
 
-async fn run_ui(mut app: App, mut client: ApiClient) -> Result<()> {
+async fn run_ui(mut app: App, mut client: ApiClient, dev: bool) -> Result<()> {
     bootstrap::initialize_app_state(&mut app, &mut client).await;
 
+    let recovery_path = recovery_store::recovery_path(dev)
+        .inspect_err(|e| eprintln!("Warning: Crash recovery disabled: {}", e))
+        .ok();
+    if let Some(path) = &recovery_path {
+        offer_recovery(&mut app, path)?;
+    }
+    offer_recurring_entries(&mut app, &mut client).await?;
+
+    match offline_queue::queue_path(dev).and_then(|path| offline_queue::OfflineQueue::load(&path)) {
+        Ok(queue) => app.offline_queue = queue,
+        Err(e) => eprintln!("Warning: Offline queue disabled: {}", e),
+    }
+
     let result = {
         let mut terminal = terminal::TerminalGuard::new()?;
-        runtime::run_app(terminal.terminal_mut(), &mut app, &mut client).await
+        runtime::run_app(
+            terminal.terminal_mut(),
+            &mut app,
+            &mut client,
+            recovery_path.as_deref(),
+        )
+        .await
     };
 
-    if let Err(err) = result {
-        eprintln!("Error: {:?}", err);
+    match result {
+        // A clean exit means nothing needs recovering next time.
+        Ok(()) => {
+            if let Some(path) = &recovery_path {
+                recovery_store::clear_snapshot(path)?;
+            }
+        }
+        Err(err) => eprintln!("Error: {:?}", err),
     }
 
     println!("\nGoodbye!");
     Ok(())
 }
+
+/// Ask whether to log recurring meetings that have nothing logged yet this week.
+async fn offer_recurring_entries(app: &mut App, client: &mut ApiClient) -> Result<()> {
+    let due = app.due_recurring_entries();
+    if due.is_empty() {
+        return Ok(());
+    }
+
+    println!("Recurring meetings not logged yet:");
+    for occurrence in &due {
+        println!("  {}", occurrence.label());
+    }
+    print!("Log them? [Y/n] ");
+    std::io::Write::flush(&mut std::io::stdout())?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    if answer.trim().eq_ignore_ascii_case("n") {
+        return Ok(());
+    }
+
+    let mut logged = 0;
+    for occurrence in &due {
+        match bootstrap::log_recurring_entry(app, client, occurrence).await {
+            Ok(()) => logged += 1,
+            Err(e) => eprintln!("Warning: Could not log {}: {:#}", occurrence.label(), e),
+        }
+    }
+    if logged > 0 {
+        println!("Logged {} recurring meeting(s).", logged);
+        if let Err(e) = bootstrap::load_history(app, client).await {
+            eprintln!("Warning: Could not reload history: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Ask whether to restore state left behind by a crashed session.
+fn offer_recovery(app: &mut App, path: &std::path::Path) -> Result<()> {
+    let snapshot = match recovery_store::load_snapshot(path) {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            eprintln!("Warning: Ignoring recovery file: {:#}", e);
+            return recovery_store::clear_snapshot(path);
+        }
+    };
+
+    let saved_at = time_utils::to_local_time(snapshot.saved_at);
+    println!(
+        "Found unsaved work from {} {:02}:{:02}:",
+        saved_at.date(),
+        saved_at.hour(),
+        saved_at.minute()
+    );
+    for line in snapshot.state.summary() {
+        println!("  {}", line);
+    }
+    print!("Restore it? [Y/n] ");
+    std::io::Write::flush(&mut std::io::stdout())?;
+
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    if answer.trim().eq_ignore_ascii_case("n") {
+        recovery_store::clear_snapshot(path)?;
+    } else {
+        app.restore_unsaved_state(snapshot.state);
+    }
+    Ok(())
+}
@@ -0,0 +1,454 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use time::OffsetDateTime;
+
+use crate::types::TimeEntry;
+
+/// Timer fields to send to the server. `None` leaves a field unchanged when
+/// updating a running timer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueuedTimer {
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: Option<String>,
+}
+
+impl QueuedTimer {
+    /// Apply the fields an update sets on top of these ones.
+    fn merge(&mut self, update: QueuedTimer) {
+        let QueuedTimer {
+            project_id,
+            project_name,
+            activity_id,
+            activity_name,
+            note,
+        } = update;
+        self.project_id = project_id.or(self.project_id.take());
+        self.project_name = project_name.or(self.project_name.take());
+        self.activity_id = activity_id.or(self.activity_id.take());
+        self.activity_name = activity_name.or(self.activity_name.take());
+        self.note = note.or(self.note.take());
+    }
+}
+
+/// A saved entry, as it was sent to or last seen from the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueuedEntry {
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub start: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub end: OffsetDateTime,
+    pub note: String,
+}
+
+impl QueuedEntry {
+    /// The saved entry as it would be sent to re-create it. `None` for
+    /// entries without a start and end time.
+    pub fn from_entry(entry: &TimeEntry) -> Option<Self> {
+        Some(Self {
+            project_id: entry.project_id.clone(),
+            project_name: entry.project_name.clone(),
+            activity_id: entry.activity_id.clone(),
+            activity_name: entry.activity_name.clone(),
+            start: entry.start_time?,
+            end: entry.end_time?,
+            note: entry.note.clone().unwrap_or_default(),
+        })
+    }
+
+    /// Whether the server's copy of the entry still looks like this one.
+    pub fn matches(&self, entry: &TimeEntry) -> bool {
+        let same_time = |ours: OffsetDateTime, theirs: Option<OffsetDateTime>| {
+            theirs.is_none_or(|theirs| same_instant(ours, theirs))
+        };
+        entry.project_id == self.project_id
+            && entry.activity_id == self.activity_id
+            && entry.note.as_deref().unwrap_or_default().trim() == self.note.trim()
+            && same_time(self.start, entry.start_time)
+            && same_time(self.end, entry.end_time)
+    }
+
+    /// Whether the entry shares any time with `entry`.
+    pub fn overlaps(&self, entry: &TimeEntry) -> bool {
+        match (entry.start_time, entry.end_time) {
+            (Some(start), Some(end)) => start < self.end && end > self.start,
+            _ => false,
+        }
+    }
+}
+
+/// Timestamps the server has echoed back can lose precision, and a timer it
+/// started can be a moment off from when it was started locally.
+pub fn same_instant(a: OffsetDateTime, b: OffsetDateTime) -> bool {
+    (a - b).abs() < time::Duration::minutes(1)
+}
+
+/// A change made while toki-api or the time tracking provider couldn't be
+/// reached. Timers are identified by when they started.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueuedOperation {
+    /// Start a timer, backdated to when it was started locally.
+    StartTimer {
+        timer: QueuedTimer,
+        #[serde(with = "time::serde::rfc3339")]
+        started_at: OffsetDateTime,
+    },
+    /// Update the running timer, optionally moving its start.
+    UpdateTimer {
+        #[serde(with = "time::serde::rfc3339")]
+        timer_started_at: OffsetDateTime,
+        timer: QueuedTimer,
+        #[serde(with = "time::serde::rfc3339::option")]
+        new_start: Option<OffsetDateTime>,
+    },
+    /// Save a timer as an entry, then stop it on the server if it's running
+    /// there.
+    SaveTimer {
+        #[serde(with = "time::serde::rfc3339")]
+        timer_started_at: OffsetDateTime,
+        entry: QueuedEntry,
+        stop_server_timer: bool,
+    },
+    /// Stop a timer without saving it.
+    StopTimer {
+        #[serde(with = "time::serde::rfc3339")]
+        timer_started_at: OffsetDateTime,
+    },
+    /// Edit a saved entry, unless it changed on the server since `before`.
+    EditEntry {
+        registration_id: String,
+        before: QueuedEntry,
+        after: QueuedEntry,
+    },
+}
+
+impl QueuedOperation {
+    /// Short description for status messages.
+    pub fn describe(&self) -> String {
+        let hh_mm = |dt: OffsetDateTime| {
+            let local = crate::time_utils::to_local_time(dt);
+            format!("{:02}:{:02}", local.hour(), local.minute())
+        };
+        match self {
+            Self::StartTimer { started_at, .. } => format!("Start timer at {}", hh_mm(*started_at)),
+            Self::UpdateTimer { .. } => "Update timer".to_string(),
+            Self::SaveTimer { entry, .. } => format!(
+                "Save {}–{} {} / {}",
+                hh_mm(entry.start),
+                hh_mm(entry.end),
+                entry.project_name,
+                entry.activity_name
+            ),
+            Self::StopTimer { .. } => "Stop timer".to_string(),
+            Self::EditEntry { before, .. } => format!(
+                "Edit {} {}–{}",
+                crate::time_utils::to_local_time(before.start).date(),
+                hh_mm(before.start),
+                hh_mm(before.end)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingOperation {
+    #[serde(with = "time::serde::rfc3339")]
+    pub queued_at: OffsetDateTime,
+    pub operation: QueuedOperation,
+}
+
+/// Changes waiting to be sent to the server, in the order they were made.
+///
+/// Every change is written to the journal file right away, so queued work
+/// survives restarting the TUI.
+#[derive(Debug, Default)]
+pub struct OfflineQueue {
+    path: Option<PathBuf>,
+    pending: Vec<PendingOperation>,
+}
+
+/// Returns the journal path: ~/.local/share/toki-tui/offline-queue.json
+/// (`offline-queue-dev.json` in dev mode).
+pub fn queue_path(dev: bool) -> Result<PathBuf> {
+    let name = if dev {
+        "offline-queue-dev.json"
+    } else {
+        "offline-queue.json"
+    };
+    Ok(dirs::data_local_dir()
+        .context("Cannot determine local data directory")?
+        .join("toki-tui")
+        .join(name))
+}
+
+impl OfflineQueue {
+    pub fn load(path: &Path) -> Result<Self> {
+        let pending = if path.exists() {
+            let raw = std::fs::read_to_string(path).context("Failed to read offline queue")?;
+            serde_json::from_str(&raw).context("Failed to parse offline queue")?
+        } else {
+            vec![]
+        };
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            pending,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn front(&self) -> Option<&PendingOperation> {
+        self.pending.first()
+    }
+
+    /// Queue an operation, folding it into a timer start that is still
+    /// queued: the server never needs to hear about a timer that was started
+    /// and stopped while offline.
+    pub fn push(&mut self, operation: QueuedOperation) -> Result<()> {
+        let queued_start = match &operation {
+            QueuedOperation::UpdateTimer {
+                timer_started_at, ..
+            }
+            | QueuedOperation::SaveTimer {
+                timer_started_at, ..
+            }
+            | QueuedOperation::StopTimer { timer_started_at } => {
+                self.queued_start(*timer_started_at)
+            }
+            _ => None,
+        };
+
+        match (operation, queued_start) {
+            (
+                QueuedOperation::UpdateTimer {
+                    timer, new_start, ..
+                },
+                Some(index),
+            ) => {
+                if let QueuedOperation::StartTimer {
+                    timer: queued,
+                    started_at,
+                } = &mut self.pending[index].operation
+                {
+                    queued.merge(timer);
+                    *started_at = new_start.unwrap_or(*started_at);
+                }
+            }
+            (
+                QueuedOperation::SaveTimer {
+                    timer_started_at,
+                    entry,
+                    ..
+                },
+                Some(index),
+            ) => {
+                self.pending.remove(index);
+                self.append(QueuedOperation::SaveTimer {
+                    timer_started_at,
+                    entry,
+                    stop_server_timer: false,
+                });
+            }
+            (QueuedOperation::StopTimer { .. }, Some(index)) => {
+                self.pending.remove(index);
+            }
+            (operation, _) => self.append(operation),
+        }
+        self.save()
+    }
+
+    /// Drop the oldest operation once it has been replayed.
+    pub fn pop_front(&mut self) -> Result<()> {
+        if !self.pending.is_empty() {
+            self.pending.remove(0);
+        }
+        self.save()
+    }
+
+    fn append(&mut self, operation: QueuedOperation) {
+        self.pending.push(PendingOperation {
+            queued_at: OffsetDateTime::now_utc(),
+            operation,
+        });
+    }
+
+    fn queued_start(&self, timer_started_at: OffsetDateTime) -> Option<usize> {
+        self.pending.iter().position(|pending| {
+            matches!(
+                pending.operation,
+                QueuedOperation::StartTimer { started_at, .. } if started_at == timer_started_at
+            )
+        })
+    }
+
+    /// Write the journal next to its file and rename it into place, or
+    /// remove it once nothing is queued.
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        if self.pending.is_empty() {
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+            return Ok(());
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string(&self.pending)?)?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::queued_entry;
+    use time::macros::datetime;
+
+    fn timer(note: &str) -> QueuedTimer {
+        QueuedTimer {
+            project_id: Some("p1".to_string()),
+            project_name: Some("Toki".to_string()),
+            activity_id: Some("a1".to_string()),
+            activity_name: Some("Development".to_string()),
+            note: Some(note.to_string()),
+        }
+    }
+
+    fn entry() -> QueuedEntry {
+        queued_entry(
+            datetime!(2026-03-02 08:00 UTC),
+            datetime!(2026-03-02 09:30 UTC),
+            "Offline work",
+        )
+    }
+
+    fn operations(queue: &OfflineQueue) -> Vec<&QueuedOperation> {
+        queue.pending.iter().map(|p| &p.operation).collect()
+    }
+
+    #[test]
+    fn updates_fold_into_a_queued_start() {
+        let started_at = datetime!(2026-03-02 08:00 UTC);
+        let moved_to = datetime!(2026-03-02 07:45 UTC);
+        let mut queue = OfflineQueue::default();
+        queue
+            .push(QueuedOperation::StartTimer {
+                timer: timer("Draft"),
+                started_at,
+            })
+            .unwrap();
+
+        queue
+            .push(QueuedOperation::UpdateTimer {
+                timer_started_at: started_at,
+                timer: QueuedTimer {
+                    note: Some("Final".to_string()),
+                    ..Default::default()
+                },
+                new_start: Some(moved_to),
+            })
+            .unwrap();
+
+        assert_eq!(
+            operations(&queue),
+            vec![&QueuedOperation::StartTimer {
+                timer: timer("Final"),
+                started_at: moved_to,
+            }]
+        );
+    }
+
+    #[test]
+    fn saving_a_queued_timer_skips_the_server_timer() {
+        let started_at = datetime!(2026-03-02 08:00 UTC);
+        let mut queue = OfflineQueue::default();
+        queue
+            .push(QueuedOperation::StartTimer {
+                timer: timer("Offline work"),
+                started_at,
+            })
+            .unwrap();
+
+        queue
+            .push(QueuedOperation::SaveTimer {
+                timer_started_at: started_at,
+                entry: entry(),
+                stop_server_timer: true,
+            })
+            .unwrap();
+
+        assert_eq!(
+            operations(&queue),
+            vec![&QueuedOperation::SaveTimer {
+                timer_started_at: started_at,
+                entry: entry(),
+                stop_server_timer: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn stopping_a_queued_timer_leaves_nothing_to_send() {
+        let started_at = datetime!(2026-03-02 08:00 UTC);
+        let mut queue = OfflineQueue::default();
+        queue
+            .push(QueuedOperation::StartTimer {
+                timer: timer("Oops"),
+                started_at,
+            })
+            .unwrap();
+
+        queue
+            .push(QueuedOperation::StopTimer {
+                timer_started_at: started_at,
+            })
+            .unwrap();
+
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn journal_round_trips_through_disk() {
+        let dir = std::env::temp_dir().join(format!("toki-offline-queue-{}", std::process::id()));
+        let path = dir.join("offline-queue.json");
+        let mut queue = OfflineQueue::load(&path).unwrap();
+        queue
+            .push(QueuedOperation::EditEntry {
+                registration_id: "42".to_string(),
+                before: entry(),
+                after: QueuedEntry {
+                    note: "Edited".to_string(),
+                    ..entry()
+                },
+            })
+            .unwrap();
+
+        let reloaded = OfflineQueue::load(&path).unwrap();
+        assert_eq!(reloaded.pending, queue.pending);
+
+        queue.pop_front().unwrap();
+        assert!(!path.exists());
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use time::OffsetDateTime;
+
+use crate::app::UnsavedState;
+
+/// Unsaved state written to disk while the TUI runs, so it survives a crash
+/// or a killed terminal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    #[serde(with = "time::serde::rfc3339")]
+    pub saved_at: OffsetDateTime,
+    pub state: UnsavedState,
+}
+
+/// Returns the recovery file path: ~/.local/share/toki-tui/recovery.json
+/// (`recovery-dev.json` in dev mode, so fake data never leaks into real runs).
+pub fn recovery_path(dev: bool) -> Result<PathBuf> {
+    let name = if dev {
+        "recovery-dev.json"
+    } else {
+        "recovery.json"
+    };
+    Ok(dirs::data_local_dir()
+        .context("Cannot determine local data directory")?
+        .join("toki-tui")
+        .join(name))
+}
+
+pub fn load_snapshot(path: &Path) -> Result<Option<RecoverySnapshot>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let raw = std::fs::read_to_string(path).context("Failed to read recovery file")?;
+    let snapshot = serde_json::from_str(&raw).context("Failed to parse recovery file")?;
+    Ok(Some(snapshot))
+}
+
+/// Write the snapshot next to the recovery file and rename it into place, so
+/// a crash mid-write never leaves a truncated file behind.
+pub fn save_snapshot(path: &Path, state: &UnsavedState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let snapshot = RecoverySnapshot {
+        saved_at: OffsetDateTime::now_utc(),
+        state: state.clone(),
+    };
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, serde_json::to_string(&snapshot)?)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+pub fn clear_snapshot(path: &Path) -> Result<()> {
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use time::OffsetDateTime;
+use toki_types::{round_entry_times, ROUNDING_INCREMENTS};
+
+use crate::time_utils::to_local_time;
+
+/// Where configured rounding is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// toki-api rounds entries as they are saved.
+    #[default]
+    Save,
+    /// Entries are saved as tracked and only shown rounded.
+    Display,
+}
+
+impl RoundingMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "save" => Some(Self::Save),
+            "display" => Some(Self::Display),
+            _ => None,
+        }
+    }
+}
+
+/// Rounding of entry times to the nearest 5, 15 or 30 minutes, matching how
+/// customers are invoiced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rounding {
+    pub minutes: u16,
+    pub mode: RoundingMode,
+}
+
+impl Rounding {
+    pub const INCREMENTS: [u16; 3] = ROUNDING_INCREMENTS;
+
+    /// `None` unless `minutes` is one of [`Self::INCREMENTS`].
+    pub fn new(minutes: u16, mode: RoundingMode) -> Option<Self> {
+        Self::INCREMENTS
+            .contains(&minutes)
+            .then_some(Self { minutes, mode })
+    }
+
+    /// Minutes to ask toki-api to round saves to.
+    pub fn save_minutes(self) -> Option<u16> {
+        (self.mode == RoundingMode::Save).then_some(self.minutes)
+    }
+
+    /// `start` and `end` rounded to the nearest increment on the user's
+    /// clock, the way toki-api rounds saved entries: halfway rounds up and
+    /// an entry never rounds away to nothing.
+    pub fn round(
+        self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> (OffsetDateTime, OffsetDateTime) {
+        round_entry_times(start, end, self.minutes, to_local_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{macros::datetime, Duration};
+
+    use super::*;
+
+    #[test]
+    fn rounds_to_the_nearest_increment() {
+        let rounding = Rounding::new(15, RoundingMode::Display).unwrap();
+        let start = to_local_time(datetime!(2026-10-14 08:00 UTC))
+            .replace_minute(7)
+            .unwrap();
+        let (rounded_start, rounded_end) = rounding.round(
+            start.replace_second(30).unwrap(),
+            start + Duration::minutes(6 * 60 + 37),
+        );
+        assert_eq!(rounded_start, start.replace_minute(15).unwrap());
+        assert_eq!(rounded_end - rounded_start, Duration::minutes(6 * 60 + 30));
+
+        let (short_start, short_end) = rounding.round(start, start + Duration::minutes(4));
+        assert_eq!(short_end - short_start, Duration::minutes(15));
+    }
+
+    #[test]
+    fn only_known_increments_round_and_display_mode_saves_as_tracked() {
+        assert_eq!(Rounding::new(10, RoundingMode::Save), None);
+        assert_eq!(
+            Rounding::new(30, RoundingMode::Save)
+                .unwrap()
+                .save_minutes(),
+            Some(30)
+        );
+        assert_eq!(
+            Rounding::new(5, RoundingMode::Display)
+                .unwrap()
+                .save_minutes(),
+            None
+        );
+        assert_eq!(
+            RoundingMode::parse(" Display "),
+            Some(RoundingMode::Display)
+        );
+        assert_eq!(RoundingMode::parse("invoice"), None);
+    }
+}
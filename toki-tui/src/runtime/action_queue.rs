@@ -1,4 +1,4 @@
-use crate::types::{Activity, Project, TimeEntry};
+use crate::types::{Activity, CalendarSuggestion, Favorite, LiveEvent, Project, TimeEntry};
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 #[derive(Debug, Clone)]
@@ -13,26 +13,104 @@ pub(super) enum Action {
         saved_selected_project: Option<Project>,
         saved_selected_activity: Option<Activity>,
     },
+    /// Select a pinned project and activity from the project list.
+    ApplyFavorite {
+        favorite: Favorite,
+        had_edit_state: bool,
+        saved_selected_project: Option<Project>,
+        saved_selected_activity: Option<Activity>,
+    },
+    /// Pin or unpin the highlighted activity of the selected project.
+    ToggleFavorite,
     OpenEditActivityPicker {
         project_id: String,
     },
     StartTimer,
     SaveTimer,
+    ResolveIdle,
     SyncRunningTimerNote {
         note: String,
     },
+    ImproveNote {
+        note: String,
+    },
+    TakeOverTimer,
+    /// Pause the running timer and start an empty one in its place.
+    PauseTimer,
+    /// Resume the most recently paused timer.
+    ResumePausedTimer,
     SaveHistoryEdit,
     SaveThisWeekEdit,
+    /// Split the entry in the split prompt at the time entered there.
+    SplitEntry,
+    /// Copy the entry in the copy prompt to the day entered there.
+    CopyEntry,
+    /// Merge the history entries selected for merging.
+    MergeEntries,
+    /// Apply the fixes proposed in the overlap prompt.
+    ResolveOverlaps,
     LoadHistoryAndOpen,
+    /// Load the entries between the dates into the history, for paging past
+    /// the loaded ones.
+    LoadHistoryRange {
+        from: time::Date,
+        to: time::Date,
+    },
+    /// Load the month containing the date into the calendar and show it.
+    LoadCalendarMonth(time::Date),
     ConfirmDelete,
+    /// Revert the latest delete or edit.
+    Undo,
+    /// Make the latest undone change again.
+    Redo,
     StopServerTimerAndClear,
     RefreshHistoryBackground,
+    /// A timer change pushed by the server, possibly made on another device.
+    ApplyLiveEvent(LiveEvent),
+    ReplayOfflineQueue,
     ResumeEntry(TimeEntry),
     ApplyTemplate {
         template: crate::config::TemplateConfig,
     },
+    /// Log the template as an entry of its default length, ending now.
+    LogTemplate(crate::config::TemplateConfig),
+    /// Delete a template saved on the server.
+    DeleteTemplate(i32),
+    /// Save a history entry as a template on the server.
+    SaveEntryAsTemplate(TimeEntry),
+    /// Log a meeting from the calendar as a time entry.
+    AcceptCalendarSuggestion(CalendarSuggestion),
+    /// Stop suggesting a meeting from the calendar.
+    DeclineCalendarSuggestion(CalendarSuggestion),
     OpenLogNote,
+    /// Load the user's assigned work items into the note editor's picker.
+    OpenWorkItemPicker,
     OpenEntryLogNote(String),
+    /// Write the entries listed in the history view to a file.
+    ExportHistory,
+    /// Look up this month's workdays with nothing logged and open the fill
+    /// wizard on them.
+    LoadMissingWorkdays,
+    /// Log the entries picked in the fill wizard.
+    FillWorkdays,
+    /// Load project budget progress and show the statistics view.
+    LoadStatisticsAndOpen,
+    /// Load the entries of an earlier week or month into the statistics view.
+    LoadStatisticsPeriod(crate::app::StatsPeriod),
+    LoadPullRequestsAndOpen,
+    OpenPullRequestDetail,
+    /// Load the `[board]` team's taskboard and show the board view.
+    LoadBoardAndOpen,
+    /// Move a work item on the board to another column.
+    MoveBoardItem {
+        work_item_id: String,
+        target_column_name: String,
+    },
+    /// Start a timer on the selected board item.
+    StartBoardItemTimer,
+    /// Track time on the board's work items with the timer's project and
+    /// activity.
+    LinkBoardTimeMapping,
 }
 
 pub(super) type ActionTx = UnboundedSender<Action>;
@@ -1,10 +1,15 @@
-use crate::api::{ApiClient, SaveTimerRequest};
+use crate::api::{self, ApiClient};
 use crate::app::{self, App};
 use crate::types;
 use anyhow::{Context, Result};
 use std::time::{Duration, Instant};
 
 use super::action_queue::{Action, ActionTx};
+use super::offline::{self, Delivery};
+use crate::offline_queue::{QueuedEntry, QueuedOperation, QueuedTimer};
+
+const OFFLINE_TIMER_STARTED: &str =
+    "Timer started offline, it will be synced once the server is reachable";
 
 /// Apply an active timer fetched from the server into App state.
 pub(crate) fn restore_active_timer(app: &mut App, timer: crate::types::ActiveTimerState) {
@@ -16,7 +21,11 @@ pub(crate) fn restore_active_timer(app: &mut App, timer: crate::types::ActiveTim
         app.timer_size = app::TimerSize::Large;
     }
     if let (Some(id), Some(name)) = (timer.project_id, timer.project_name) {
-        app.selected_project = Some(crate::types::Project { id, name });
+        app.selected_project = Some(crate::types::Project {
+            id,
+            name,
+            budget: None,
+        });
     }
     if let (Some(id), Some(name)) = (timer.activity_id, timer.activity_name) {
         app.selected_activity = Some(crate::types::Activity {
@@ -33,6 +42,7 @@ pub(crate) fn restore_active_timer(app: &mut App, timer: crate::types::ActiveTim
         app.set_note_from_raw(&timer.note);
         app.description_is_default = false;
     }
+    app.timer_device = timer.device;
 }
 
 pub(super) async fn run_action(
@@ -69,6 +79,31 @@ pub(super) async fn run_action(
             )
             .await;
         }
+        Action::ApplyFavorite {
+            favorite,
+            had_edit_state,
+            saved_selected_project,
+            saved_selected_activity,
+        } => {
+            app.select_favorite(&favorite);
+            if had_edit_state {
+                app.update_edit_state_project(
+                    favorite.project_id.clone(),
+                    favorite.project_name.clone(),
+                );
+            }
+            handle_activity_selection_enter(
+                app,
+                client,
+                had_edit_state,
+                saved_selected_project,
+                saved_selected_activity,
+            )
+            .await;
+        }
+        Action::ToggleFavorite => {
+            toggle_favorite(app, client).await;
+        }
         Action::OpenEditActivityPicker { project_id } => {
             app.pending_edit_selection_restore.get_or_insert_with(|| {
                 (app.selected_project.clone(), app.selected_activity.clone())
@@ -82,6 +117,7 @@ pub(super) async fn run_action(
             app.selected_project = Some(types::Project {
                 id: project_id.clone(),
                 name: project_name,
+                budget: None,
             });
             app.selected_activity = None;
 
@@ -94,33 +130,115 @@ pub(super) async fn run_action(
         Action::SaveTimer => {
             handle_save_timer_with_action(app, client).await?;
         }
+        Action::ResolveIdle => {
+            resolve_idle(app, client).await;
+        }
         Action::SyncRunningTimerNote { note } => {
             sync_running_timer_note(note, app, client).await;
         }
+        Action::ImproveNote { note } => {
+            improve_note(note, app, client).await;
+        }
+        Action::TakeOverTimer => {
+            take_over_timer(app, client).await;
+        }
+        Action::PauseTimer => {
+            pause_timer(app, client).await;
+        }
+        Action::ResumePausedTimer => {
+            resume_paused_timer(app, client).await;
+        }
         Action::SaveHistoryEdit => {
             handle_history_edit_save(app, client).await?;
         }
         Action::SaveThisWeekEdit => {
             handle_this_week_edit_save(app, client).await?;
         }
+        Action::SplitEntry => {
+            handle_split_entry(app, client).await;
+        }
+        Action::CopyEntry => {
+            handle_copy_entry(app, client).await;
+        }
+        Action::LoadHistoryRange { from, to } => {
+            load_history_range(from, to, app, client).await;
+        }
+        Action::MergeEntries => {
+            handle_merge_entries(app, client).await;
+        }
+        Action::ResolveOverlaps => {
+            handle_resolve_overlaps(app, client).await;
+        }
         Action::LoadHistoryAndOpen => {
             load_history_and_open(app, client).await;
         }
+        Action::LoadCalendarMonth(month) => {
+            load_calendar_month(month, app, client).await;
+        }
         Action::ConfirmDelete => {
             handle_confirm_delete(app, client).await;
         }
+        Action::Undo => {
+            undo_change(app, client).await;
+        }
+        Action::Redo => {
+            redo_change(app, client).await;
+        }
         Action::StopServerTimerAndClear => {
             stop_server_timer_and_clear(app, client).await;
         }
         Action::RefreshHistoryBackground => {
             refresh_history_background(app, client).await;
         }
+        Action::ApplyLiveEvent(event) => {
+            apply_live_event(event, app, client).await;
+        }
+        Action::ReplayOfflineQueue => {
+            offline::replay_offline_queue(app, client).await;
+        }
         Action::ResumeEntry(entry) => {
             resume_entry(entry, app, client).await;
         }
         Action::ApplyTemplate { template } => {
             handle_apply_template(template, app, client).await?;
         }
+        Action::LogTemplate(template) => {
+            log_template(template, app, client).await;
+        }
+        Action::DeleteTemplate(id) => match client.delete_template(id).await {
+            Ok(()) => {
+                app.remove_server_template(id);
+                app.set_status("Template deleted".to_string());
+            }
+            Err(e) => app.set_status(format!("Error deleting template: {}", e)),
+        },
+        Action::AcceptCalendarSuggestion(suggestion) => {
+            accept_calendar_suggestion(suggestion, app, client).await;
+        }
+        Action::DeclineCalendarSuggestion(suggestion) => {
+            match client.dismiss_calendar_suggestion(&suggestion).await {
+                Ok(()) => {
+                    app.remove_calendar_suggestion(&suggestion);
+                    app.set_status(format!("Declined {}", suggestion.title));
+                }
+                Err(e) => app.set_status(format!("Error declining suggestion: {}", e)),
+            }
+        }
+        Action::SaveEntryAsTemplate(entry) => {
+            let name = app::template_name(&entry);
+            match client.save_template(&name, &entry).await {
+                Ok(template) => {
+                    app.upsert_server_template(template);
+                    app.set_status(format!("Saved template '{}'", name));
+                }
+                Err(e) => app.set_status(format!("Error saving template: {}", e)),
+            }
+        }
+        Action::OpenWorkItemPicker => {
+            let items = client.list_assigned_work_items().await;
+            app.open_work_item_overlay(items.map_err(|e| e.to_string()));
+            app.clear_status();
+        }
         Action::OpenLogNote => {
             if let Err(e) = handle_open_log_note(app, client).await {
                 app.set_status(format!("Log note error: {}", e));
@@ -129,6 +247,42 @@ pub(super) async fn run_action(
         Action::OpenEntryLogNote(id) => {
             handle_open_entry_log_note(&id, app).await;
         }
+        Action::ExportHistory => {
+            export_history(app);
+        }
+        Action::LoadMissingWorkdays => {
+            load_missing_workdays(app, client).await;
+        }
+        Action::FillWorkdays => {
+            fill_workdays(app, client).await;
+        }
+        Action::LoadStatisticsAndOpen => {
+            load_statistics_and_open(app, client).await;
+        }
+        Action::LoadStatisticsPeriod(period) => {
+            load_statistics_period(period, app, client).await;
+        }
+        Action::LoadPullRequestsAndOpen => {
+            load_pull_requests_and_open(app, client).await;
+        }
+        Action::OpenPullRequestDetail => {
+            open_pull_request_detail(app, client).await;
+        }
+        Action::LoadBoardAndOpen => {
+            load_board_and_open(app, client).await;
+        }
+        Action::MoveBoardItem {
+            work_item_id,
+            target_column_name,
+        } => {
+            move_board_item(&work_item_id, &target_column_name, app, client).await;
+        }
+        Action::StartBoardItemTimer => {
+            start_board_item_timer(app, client).await;
+        }
+        Action::LinkBoardTimeMapping => {
+            link_board_time_mapping(app, client).await;
+        }
     }
     Ok(())
 }
@@ -136,28 +290,15 @@ pub(super) async fn run_action(
 pub(super) async fn handle_start_timer(app: &mut App, client: &mut ApiClient) -> Result<()> {
     match app.timer_state {
         app::TimerState::Stopped => {
-            let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-            let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-            let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-            let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-            let note = {
-                let full = app.full_note_value();
-                if full.is_empty() {
-                    None
-                } else {
-                    Some(full)
-                }
-            };
-            if let Err(e) = client
-                .start_timer(project_id, project_name, activity_id, activity_name, note)
-                .await
-            {
-                app.set_status(format!("Error starting timer: {}", e));
-                return Ok(());
+            let timer = offline::current_timer(app);
+            match offline::start_timer(app, client, timer).await {
+                Ok(Delivery::Sent) => match app.selected_project_budget_warning() {
+                    Some(warning) => app.set_status(warning),
+                    None => app.clear_status(),
+                },
+                Ok(Delivery::Queued) => app.set_status(OFFLINE_TIMER_STARTED.to_string()),
+                Err(e) => app.set_status(format!("Error starting timer: {}", e)),
             }
-            let auto_resize = app.auto_resize_timer;
-            app.start_timer(auto_resize);
-            app.clear_status();
         }
         app::TimerState::Running => {
             app.set_status("Timer already running (Ctrl+S to save)".to_string());
@@ -225,6 +366,13 @@ async fn handle_activity_selection_enter(
     saved_selected_project: Option<types::Project>,
     saved_selected_activity: Option<types::Activity>,
 ) {
+    if app.reassigning_marked.is_some() {
+        if let Some((project, activity)) = app.finish_reassigning_marked() {
+            reassign_marked_entries(app, client, &project, &activity).await;
+        }
+        return;
+    }
+
     if was_in_edit_mode {
         if let Some(activity) = app.selected_activity.clone() {
             app.update_edit_state_activity(activity.id.clone(), activity.name.clone());
@@ -245,50 +393,253 @@ async fn handle_activity_selection_enter(
     app.pending_edit_selection_restore = None;
 
     if app.timer_state == app::TimerState::Running {
-        let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-        let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-        let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-        let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-        if let Err(e) = client
-            .update_active_timer(
-                project_id,
-                project_name,
-                activity_id,
-                activity_name,
-                None,
-                None,
-            )
-            .await
-        {
+        let timer = QueuedTimer {
+            note: None,
+            ..offline::current_timer(app)
+        };
+        if let Err(e) = offline::update_timer(app, client, timer).await {
             app.set_status(format!("Warning: Could not sync project to server: {}", e));
         }
     }
 }
 
-fn apply_recent_history(app: &mut App, entries: Vec<types::TimeEntry>) {
-    app.update_history(entries);
-    app.rebuild_history_list();
+async fn log_template(
+    template: crate::config::TemplateConfig,
+    app: &mut App,
+    client: &mut ApiClient,
+) {
+    let Some(minutes) = template.duration_minutes else {
+        app.set_status(format!(
+            "Template '{}' has no duration, press Enter to use it for the timer",
+            template.description
+        ));
+        return;
+    };
+    let end = time::OffsetDateTime::now_utc();
+    let start = end - time::Duration::minutes(i64::from(minutes));
+
+    let result = crate::bootstrap::log_named_entry(
+        app,
+        client,
+        (&template.project, &template.activity),
+        &template.note,
+        start,
+        end,
+    )
+    .await;
+    match result {
+        Ok(()) => {
+            if let Ok(entries) = fetch_recent_history(client).await {
+                apply_recent_history(app, entries);
+            }
+            app.navigate_to(app::View::Timer);
+            app.set_status(format!("Logged {} ({} min)", template.description, minutes));
+        }
+        Err(e) => app.set_status(format!("Error logging template: {}", e)),
+    }
+}
+
+async fn accept_calendar_suggestion(
+    suggestion: types::CalendarSuggestion,
+    app: &mut App,
+    client: &mut ApiClient,
+) {
+    let Some(target) = app.suggestion_target(&suggestion) else {
+        app.set_status(format!(
+            "Select a project and activity (press P) to log {}",
+            suggestion.title
+        ));
+        return;
+    };
+
+    let result = client
+        .create_time_entry(
+            &target.project_id,
+            &target.project_name,
+            &target.activity_id,
+            &target.activity_name,
+            suggestion.start_time,
+            suggestion.end_time,
+            &suggestion.title,
+        )
+        .await;
+    match result {
+        Ok(()) => {
+            app.remove_calendar_suggestion(&suggestion);
+            if let Ok(entries) = fetch_recent_history(client).await {
+                apply_recent_history(app, entries);
+            }
+            app.set_status(format!(
+                "Logged {} on {} / {}",
+                suggestion.title, target.project_name, target.activity_name
+            ));
+        }
+        Err(e) => app.set_status(format!("Error logging suggestion: {}", e)),
+    }
+}
+
+async fn toggle_favorite(app: &mut App, client: &mut ApiClient) {
+    let Some(project) = app.selected_project.clone() else {
+        return;
+    };
+    let Some(activity) = app
+        .filtered_activities
+        .get(app.filtered_activity_index)
+        .cloned()
+    else {
+        return;
+    };
+    let label = format!("{} / {}", project.name, activity.name);
+
+    if let Some(id) = app
+        .favorite_for(&project.id, &activity.id)
+        .map(|favorite| favorite.id)
+    {
+        match client.delete_favorite(id).await {
+            Ok(()) => {
+                app.favorites.retain(|favorite| favorite.id != id);
+                app.set_status(format!("Unpinned {}", label));
+            }
+            Err(e) => app.set_status(format!("Error unpinning favorite: {}", e)),
+        }
+        return;
+    }
+
+    match client.add_favorite(&project, &activity).await {
+        Ok(favorite) => {
+            app.favorites.push(favorite);
+            app.set_status(if app.favorites.len() <= app::FAVORITE_SHORTCUTS {
+                format!("Pinned {} as {}", label, app.favorites.len())
+            } else {
+                format!("Pinned {}", label)
+            });
+        }
+        Err(e) => app.set_status(format!("Error pinning favorite: {}", e)),
+    }
+}
+
+/// Replace the recent entries with `entries`, keeping older pages the
+/// history view loaded.
+pub(super) fn apply_recent_history(app: &mut App, entries: Vec<types::TimeEntry>) {
+    let today = crate::time_utils::local_today();
+    let month_ago = today - time::Duration::days(app::RECENT_DAYS);
+    app.merge_history(entries, month_ago, today);
 }
 
-async fn fetch_recent_history(client: &mut ApiClient) -> Result<Vec<types::TimeEntry>> {
-    let today = time::OffsetDateTime::now_utc().date();
-    let month_ago = today - time::Duration::days(30);
+pub(super) async fn fetch_recent_history(client: &mut ApiClient) -> Result<Vec<types::TimeEntry>> {
+    let today = crate::time_utils::local_today();
+    let month_ago = today - time::Duration::days(app::RECENT_DAYS);
     client.get_time_entries(month_ago, today).await
 }
 
+/// Load entries older than the loaded history for the history view.
+async fn load_history_range(
+    from: time::Date,
+    to: time::Date,
+    app: &mut App,
+    client: &mut ApiClient,
+) {
+    app.is_loading = true;
+    let result = client.get_time_entries(from, to).await;
+    app.is_loading = false;
+    match result {
+        Ok(entries) => app.merge_history(entries, from, to),
+        Err(e) => app.set_status(format!("Error loading older entries: {}", e)),
+    }
+}
+
 async fn sync_running_timer_note(note: String, app: &mut App, client: &mut ApiClient) {
     if app.timer_state != app::TimerState::Running {
         return;
     }
 
-    if let Err(e) = client
-        .update_active_timer(None, None, None, None, Some(note), None)
-        .await
-    {
+    let timer = QueuedTimer {
+        note: Some(note),
+        ..Default::default()
+    };
+    if let Err(e) = offline::update_timer(app, client, timer).await {
         app.set_status(format!("Warning: Could not sync note to server: {}", e));
     }
 }
 
+async fn improve_note(note: String, app: &mut App, client: &mut ApiClient) {
+    match client.improve_note(&note).await {
+        Ok(suggestion) => {
+            if !app.apply_note_suggestion(&note, &suggestion) {
+                app.set_status("Note changed while loading; suggestion discarded".to_string());
+            }
+        }
+        Err(e) => app.set_status(format!("Could not improve note: {}", e)),
+    }
+}
+
+async fn take_over_timer(app: &mut App, client: &mut ApiClient) {
+    let previous = app.timer_device.clone();
+    match client.take_over_timer().await {
+        Ok(Some(timer)) => {
+            // The other device may have changed the timer since we last looked.
+            restore_active_timer(app, timer);
+            app.set_status(match previous {
+                Some(device) => format!("Took over the timer from {}", device),
+                None => "Took over the timer".to_string(),
+            });
+        }
+        Ok(None) => app.set_status("Timer is no longer running on the server".to_string()),
+        Err(e) => app.set_status(format!("Could not take over timer: {}", e)),
+    }
+}
+
+async fn pause_timer(app: &mut App, client: &mut ApiClient) {
+    if app.timer_state != app::TimerState::Running {
+        app.set_status("No running timer to pause".to_string());
+        return;
+    }
+
+    // The server pauses the timer as it has it, so send local changes first
+    let timer = offline::current_timer(app);
+    if let Err(e) = offline::update_timer(app, client, timer).await {
+        app.set_status(format!("Could not pause timer: {}", e));
+        return;
+    }
+
+    match client.pause_timer().await {
+        Ok((paused, timer)) => {
+            app.clear_timer();
+            if let Some(timer) = timer {
+                restore_active_timer(app, timer);
+            }
+            app.push_paused_timer(paused);
+            if let Some(summary) = app.paused_timers_summary() {
+                app.set_status(format!("{} — I to resume", summary));
+            }
+        }
+        Err(e) => app.set_status(format!("Could not pause timer: {}", e)),
+    }
+}
+
+async fn resume_paused_timer(app: &mut App, client: &mut ApiClient) {
+    let Some(paused) = app.paused_timers.first().cloned() else {
+        app.set_status("No paused timers".to_string());
+        return;
+    };
+    if app.timer_state == app::TimerState::Running {
+        app.set_status("Save or stop the running timer to resume a paused one".to_string());
+        return;
+    }
+
+    match client.resume_timer(paused.id).await {
+        Ok(timer) => {
+            app.remove_paused_timer(paused.id);
+            app.clear_timer();
+            if let Some(timer) = timer {
+                restore_active_timer(app, timer);
+            }
+            app.set_status(format!("Resumed: {}", app::paused_timer_label(&paused)));
+        }
+        Err(e) => app.set_status(format!("Could not resume timer: {}", e)),
+    }
+}
+
 async fn handle_apply_template(
     template: crate::config::TemplateConfig,
     app: &mut App,
@@ -341,22 +692,11 @@ async fn handle_apply_template(
 
     // If timer is running, sync to server
     if app.timer_state == app::TimerState::Running {
-        let note = app.full_note_value();
-        let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-        let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-        let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-        let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-        if let Err(e) = client
-            .update_active_timer(
-                project_id,
-                project_name,
-                activity_id,
-                activity_name,
-                Some(note),
-                None,
-            )
-            .await
-        {
+        let timer = QueuedTimer {
+            note: Some(app.full_note_value()),
+            ..offline::current_timer(app)
+        };
+        if let Err(e) = offline::update_timer(app, client, timer).await {
             app.set_status(format!("Warning: Could not sync template to server: {}", e));
         }
     }
@@ -376,151 +716,578 @@ async fn load_history_and_open(app: &mut App, client: &mut ApiClient) {
     }
 }
 
-async fn handle_confirm_delete(app: &mut App, client: &mut ApiClient) {
-    if let Some(ctx) = app.delete_context.take() {
-        let origin = ctx.origin;
-        match client.delete_time_entry(&ctx.registration_id).await {
-            Ok(()) => {
-                app.time_entries
-                    .retain(|e| e.registration_id != ctx.registration_id);
-                app.rebuild_history_list();
-                app.set_status("Entry deleted".to_string());
-            }
-            Err(e) => {
-                app.set_status(format!("Delete failed: {}", e));
+async fn load_calendar_month(month: time::Date, app: &mut App, client: &mut ApiClient) {
+    let first = app::first_of_month(month);
+    match client
+        .get_time_entries(first, app::last_of_month(first))
+        .await
+    {
+        Ok(entries) => {
+            app.set_calendar_month(first, entries);
+            if app.current_view != app::View::Calendar {
+                app.navigate_to(app::View::Calendar);
             }
         }
-        match origin {
-            app::DeleteOrigin::Timer => app.navigate_to(app::View::Timer),
-            app::DeleteOrigin::History => app.navigate_to(app::View::History),
+        Err(e) => {
+            app.set_status(format!("Error loading calendar: {}", e));
         }
     }
 }
 
-async fn stop_server_timer_and_clear(app: &mut App, client: &mut ApiClient) {
-    if app.timer_state == app::TimerState::Running {
-        if let Err(e) = client.stop_timer().await {
-            app.set_status(format!("Warning: Could not stop server timer: {}", e));
-        }
+async fn load_missing_workdays(app: &mut App, client: &mut ApiClient) {
+    let today = crate::time_utils::local_today();
+    match client
+        .get_missing_workdays(app::first_of_month(today), today)
+        .await
+    {
+        Ok(missing) => app.open_fill_wizard(missing),
+        Err(e) => app.set_status(format!("Error loading missing workdays: {}", e)),
     }
-    app.clear_timer();
 }
 
-async fn refresh_history_background(app: &mut App, client: &mut ApiClient) {
-    if let Ok(entries) = fetch_recent_history(client).await {
-        apply_recent_history(app, entries);
+/// Log one entry per picked day. Days that fail are left for another try.
+async fn fill_workdays(app: &mut App, client: &mut ApiClient) {
+    let entries = app.fill_wizard_entries();
+    app.close_fill_wizard();
+
+    let mut filled = 0;
+    let mut last_error = None;
+    for entry in &entries {
+        match crate::bootstrap::log_named_entry(
+            app,
+            client,
+            (&entry.project, &entry.activity),
+            &entry.note,
+            entry.start,
+            entry.end,
+        )
+        .await
+        {
+            Ok(()) => filled += 1,
+            Err(e) => last_error = Some(e),
+        }
     }
-}
 
-async fn resume_entry(entry: types::TimeEntry, app: &mut App, client: &mut ApiClient) {
-    if app.timer_state == app::TimerState::Running {
-        // Timer already running — copy fields and sync to server (yank behaviour)
-        app.copy_entry_fields(&entry);
+    if let Ok(history) = fetch_recent_history(client).await {
+        apply_recent_history(app, history);
+    }
+    match last_error {
+        None => app.set_status(format!("Filled {} day(s)", filled)),
+        Some(e) => app.set_status(format!(
+            "Filled {} of {} day(s), error: {}",
+            filled,
+            entries.len(),
+            e
+        )),
+    }
+}
 
-        let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-        let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-        let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-        let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-        let note = {
-            let full = app.full_note_value();
-            if full.is_empty() {
-                None
-            } else {
-                Some(full)
+async fn load_statistics_and_open(app: &mut App, client: &mut ApiClient) {
+    let today = crate::time_utils::local_today();
+    app.statistics_project = None;
+    app.set_statistics_period(
+        app::StatsPeriod::containing(app::StatsSpan::Week, today),
+        None,
+    );
+    app.navigate_to(app::View::Statistics);
+    match client.get_budget_statuses().await {
+        Ok(statuses) => {
+            app.budget_statuses = statuses;
+            if let Some(warning) = app.budget_statuses_warning() {
+                app.set_status(warning);
             }
-        };
-        if let Err(e) = client
-            .update_active_timer(
-                project_id,
-                project_name,
-                activity_id,
-                activity_name,
-                note,
-                None,
-            )
-            .await
-        {
-            app.set_status(format!(
-                "Warning: Could not sync copied entry to server: {}",
-                e
-            ));
-        } else {
-            app.set_status(format!(
-                "Copied: {}: {}",
-                entry.project_name, entry.activity_name
-            ));
         }
-        return;
+        // The charts only need history that is already loaded.
+        Err(e) => app.set_status(format!("Could not load budgets: {}", e)),
     }
 
-    // Build server arguments from the entry directly (not from app state)
-    let project_id = Some(entry.project_id.clone());
-    let project_name = Some(entry.project_name.clone());
-    let activity_id = Some(entry.activity_id.clone());
-    let activity_name = Some(entry.activity_name.clone());
-    let note = entry.note.clone().filter(|n| !n.is_empty());
+    let from = today - time::Duration::weeks(app::FLEX_TREND_WEEKS);
+    match client.get_flex_history(from, today).await {
+        Ok(history) => app.flex_history = history,
+        Err(e) => app.set_status(format!("Could not load flex history: {}", e)),
+    }
 
-    match client
-        .start_timer(project_id, project_name, activity_id, activity_name, note)
-        .await
-    {
-        Ok(()) => {
-            // Only mutate local state after confirmed server success
-            app.copy_entry_fields(&entry);
-            let auto_resize = app.auto_resize_timer;
-            app.start_timer(auto_resize); // sets TimerState::Running + TimerSize::Large + local_start
-            app.set_status(format!(
-                "Resumed: {}: {}",
-                entry.project_name, entry.activity_name
-            ));
-        }
-        Err(e) => {
-            app.set_status(format!("Error resuming entry: {}", e));
+    if let Some(board) = app.board_project.clone() {
+        match client.get_sprint_capacity(&board).await {
+            Ok(sprints) => app.sprint_capacities = sprints,
+            Err(e) => app.set_status(format!("Could not load sprint capacity: {}", e)),
         }
     }
 }
 
-pub(super) async fn handle_save_timer_with_action(
-    app: &mut App,
-    client: &mut ApiClient,
-) -> Result<()> {
-    // Handle Cancel first
-    if app.selected_save_action == app::SaveAction::Cancel {
-        app.navigate_to(app::View::Timer);
-        return Ok(());
+async fn load_statistics_period(period: app::StatsPeriod, app: &mut App, client: &mut ApiClient) {
+    match client.get_time_entries(period.start, period.end()).await {
+        Ok(entries) => app.set_statistics_period(period, Some(entries)),
+        Err(e) => app.set_status(format!("Error loading statistics: {}", e)),
     }
+}
 
-    let duration = app.elapsed_duration();
-    let note = {
-        let full = app.full_note_value();
-        if full.is_empty() {
-            None
-        } else {
+async fn load_pull_requests_and_open(app: &mut App, client: &mut ApiClient) {
+    match client.list_pull_requests().await {
+        Ok(pull_requests) => {
+            app.set_pull_requests(pull_requests);
+            app.navigate_to(app::View::PullRequests);
+            if app.pull_requests.is_empty() {
+                app.set_status("No open pull requests in followed repositories".to_string());
+            }
+        }
+        Err(e) => {
+            app.set_status(format!("Error loading pull requests: {}", e));
+        }
+    }
+}
+
+async fn open_pull_request_detail(app: &mut App, client: &mut ApiClient) {
+    let Some(pr) = app.selected_pull_request().cloned() else {
+        return;
+    };
+    match client.get_pull_request_changes(&pr).await {
+        Ok(changes) => app.open_pull_request_detail(changes),
+        Err(e) => {
+            // The description and threads are already loaded; show them anyway.
+            app.open_pull_request_detail(Vec::new());
+            app.set_status(format!("Could not load changed files: {}", e));
+        }
+    }
+}
+
+async fn load_board_and_open(app: &mut App, client: &mut ApiClient) {
+    let Some(board) = app.board_project.clone() else {
+        app.set_status("Set organization and project under [board] in the config".to_string());
+        return;
+    };
+    match client.get_board(&board).await {
+        Ok(data) => {
+            app.set_board(data);
+            app.navigate_to(app::View::Board);
+            if app.board_lanes.is_empty() {
+                app.set_status("The board has no columns".to_string());
+            }
+        }
+        Err(e) => {
+            app.set_status(format!("Error loading board: {}", e));
+            return;
+        }
+    }
+
+    match client.get_work_item_time_mapping(&board).await {
+        Ok(mapping) => app.board_time_mapping = mapping,
+        Err(e) => app.set_status(format!("Could not load the linked project: {}", e)),
+    }
+}
+
+async fn start_board_item_timer(app: &mut App, client: &mut ApiClient) {
+    if app.timer_state == app::TimerState::Running {
+        app.set_status("Timer already running (Ctrl+S to save)".to_string());
+        return;
+    }
+    let Some(note) = app.prefill_timer_from_board_item() else {
+        return;
+    };
+
+    let timer = offline::current_timer(app);
+    match offline::start_timer(app, client, timer).await {
+        Ok(delivery) => {
+            app.navigate_to(app::View::Timer);
+            app.set_status(match (delivery, &app.board_time_mapping) {
+                (Delivery::Queued, _) => OFFLINE_TIMER_STARTED.to_string(),
+                (Delivery::Sent, Some(mapping)) => format!(
+                    "Started {} on {}: {}",
+                    note, mapping.project_name, mapping.activity_name
+                ),
+                (Delivery::Sent, None) => format!(
+                    "Started {}; no project is linked to the board (P on the board links the timer's)",
+                    note
+                ),
+            });
+        }
+        Err(e) => app.set_status(format!("Error starting timer: {}", e)),
+    }
+}
+
+async fn link_board_time_mapping(app: &mut App, client: &mut ApiClient) {
+    let Some(board) = app.board_project.clone() else {
+        return;
+    };
+    let (Some(project), Some(activity)) =
+        (app.selected_project.clone(), app.selected_activity.clone())
+    else {
+        app.set_status("Select a project and activity on the timer first".to_string());
+        return;
+    };
+
+    match client
+        .set_work_item_time_mapping(&board, &project, &activity)
+        .await
+    {
+        Ok(mapping) => {
+            app.set_status(format!(
+                "Timers started from the board now track {}: {}",
+                mapping.project_name, mapping.activity_name
+            ));
+            app.board_time_mapping = Some(mapping);
+        }
+        Err(e) => app.set_status(format!("Error linking project: {}", e)),
+    }
+}
+
+/// The item was already moved locally; reload the board if the server
+/// refused the move so it shows where the item really is.
+async fn move_board_item(
+    work_item_id: &str,
+    target_column_name: &str,
+    app: &mut App,
+    client: &mut ApiClient,
+) {
+    let Some(board) = app.board_project.clone() else {
+        return;
+    };
+    match client
+        .move_work_item(&board, work_item_id, target_column_name)
+        .await
+    {
+        Ok(None) => app.set_status(format!("Moved #{} to {}", work_item_id, target_column_name)),
+        Ok(Some(warning)) => app.set_status(format!(
+            "Moved #{} to {}, which is over its WIP limit ({}/{})",
+            work_item_id, warning.column_name, warning.item_count, warning.limit
+        )),
+        Err(e) => {
+            if let Ok(data) = client.get_board(&board).await {
+                app.set_board(data);
+            }
+            app.set_status(format!("Error moving #{}: {}", work_item_id, e));
+        }
+    }
+}
+
+async fn handle_confirm_delete(app: &mut App, client: &mut ApiClient) {
+    if let Some(ctx) = app.delete_context.take() {
+        let origin = ctx.origin;
+        let entries: Vec<types::TimeEntry> = app
+            .time_entries
+            .iter()
+            .filter(|e| ctx.registration_ids.contains(&e.registration_id))
+            .cloned()
+            .collect();
+        let result = match ctx.registration_ids.as_slice() {
+            [registration_id] => client.delete_time_entry(registration_id).await,
+            _ => {
+                client
+                    .delete_time_entries(&entries.iter().collect::<Vec<_>>())
+                    .await
+            }
+        };
+        match result {
+            Ok(()) => {
+                for entry in entries.iter().filter_map(QueuedEntry::from_entry) {
+                    app.record_change(app::EntryChange::Deleted(entry));
+                }
+                app.time_entries
+                    .retain(|e| !ctx.registration_ids.contains(&e.registration_id));
+                app.rebuild_history_list();
+                app.marked_entries
+                    .retain(|id| !ctx.registration_ids.contains(id));
+                if ctx.registration_ids.len() > 1 {
+                    app.set_status(format!("Deleted {} entries", ctx.registration_ids.len()));
+                } else {
+                    app.set_status("Entry deleted".to_string());
+                }
+            }
+            Err(e) => {
+                app.set_status(format!("Delete failed: {}", e));
+            }
+        }
+        match origin {
+            app::DeleteOrigin::Timer => app.navigate_to(app::View::Timer),
+            app::DeleteOrigin::History => app.navigate_to(app::View::History),
+        }
+    }
+}
+
+/// Revert the latest delete or edit through the API.
+async fn undo_change(app: &mut App, client: &mut ApiClient) {
+    let Some(record) = app.take_undo(Instant::now()) else {
+        app.set_status(format!(
+            "Nothing to undo from the last {} min",
+            app::UNDO_WINDOW.as_secs() / 60
+        ));
+        return;
+    };
+    match apply_entry_change(&record.change.inverse(), app, client).await {
+        Ok(()) => {
+            app.set_status(format!("Undid {}", record.change.describe()));
+            app.record_undone(record.change);
+        }
+        Err(e) => {
+            app.set_status(format!("Undo failed: {}", e));
+            app.restore_undo(record);
+        }
+    }
+}
+
+/// Make the latest undone change again.
+async fn redo_change(app: &mut App, client: &mut ApiClient) {
+    let Some(record) = app.take_redo(Instant::now()) else {
+        app.set_status("Nothing to redo".to_string());
+        return;
+    };
+    match apply_entry_change(&record.change, app, client).await {
+        Ok(()) => {
+            app.set_status(format!("Redid {}", record.change.describe()));
+            app.record_redone(record.change);
+        }
+        Err(e) => {
+            app.set_status(format!("Redo failed: {}", e));
+            app.restore_redo(record);
+        }
+    }
+}
+
+/// Make `change` on the server, then reload history so later undos find
+/// the entries as they are now.
+async fn apply_entry_change(
+    change: &app::EntryChange,
+    app: &mut App,
+    client: &mut ApiClient,
+) -> Result<()> {
+    let not_found = || anyhow::anyhow!("the entry is no longer in history");
+    match change {
+        app::EntryChange::Created(entry) => offline::create_entry(client, entry).await?,
+        app::EntryChange::Deleted(entry) => {
+            let registration_id = app.find_registration(entry).ok_or_else(not_found)?;
+            client.delete_time_entry(&registration_id).await?;
+            app.time_entries
+                .retain(|e| e.registration_id != registration_id);
+            app.rebuild_history_list();
+        }
+        app::EntryChange::Edited { before, after } => {
+            let registration_id = app.find_registration(before).ok_or_else(not_found)?;
+            client
+                .edit_time_entry(
+                    &registration_id,
+                    &after.project_id,
+                    &after.project_name,
+                    &after.activity_id,
+                    &after.activity_name,
+                    after.start,
+                    after.end,
+                    &after.note,
+                )
+                .await?;
+        }
+    }
+
+    if let Ok(entries) = fetch_recent_history(client).await {
+        apply_recent_history(app, entries);
+    }
+    Ok(())
+}
+
+async fn stop_server_timer_and_clear(app: &mut App, client: &mut ApiClient) {
+    let mut delivery = None;
+    if app.timer_state == app::TimerState::Running {
+        let timer_started_at = app
+            .absolute_start
+            .unwrap_or_else(time::OffsetDateTime::now_utc);
+        match offline::deliver(app, client, QueuedOperation::StopTimer { timer_started_at }).await {
+            Ok(sent) => delivery = Some(sent),
+            Err(e) => app.set_status(format!("Warning: Could not stop server timer: {}", e)),
+        }
+    }
+    app.clear_timer();
+    if delivery == Some(Delivery::Queued) {
+        app.set_status(
+            "Timer cleared, the server timer is stopped once it's reachable".to_string(),
+        );
+    }
+}
+
+async fn refresh_history_background(app: &mut App, client: &mut ApiClient) {
+    if let Ok(entries) = fetch_recent_history(client).await {
+        apply_recent_history(app, entries);
+    }
+    if let Ok(status) = client.get_status().await {
+        app.status_banner = status.banner;
+    }
+    // Pick up another device taking over the timer.
+    if app.timer_state == app::TimerState::Running {
+        if let Ok(Some(timer)) = client.get_active_timer().await {
+            app.timer_device = timer.device;
+        }
+    }
+}
+
+/// Bring the timer and history in line with a change pushed by the server.
+/// Changes made by this TUI come back as well and leave it as it is.
+async fn apply_live_event(event: types::LiveEvent, app: &mut App, client: &mut ApiClient) {
+    let device_name = app.device_name.clone();
+    let own_device = |device: &Option<String>| device.as_deref() == Some(device_name.as_str());
+    match event {
+        types::LiveEvent::TimerStarted(timer) => {
+            if own_device(&timer.device) {
+                return;
+            }
+            let message = match &timer.device {
+                Some(device) => format!("Timer started on {}", device),
+                None => "Timer started on another device".to_string(),
+            };
+            restore_active_timer(app, timer);
+            app.set_status(message);
+        }
+        types::LiveEvent::TimerUpdated(timer) => {
+            if app.timer_state != app::TimerState::Running {
+                return;
+            }
+            let current = offline::current_timer(app);
+            let unchanged = app.absolute_start == Some(timer.start_time)
+                && current.project_id == timer.project_id
+                && current.activity_id == timer.activity_id
+                && current.note.unwrap_or_default() == timer.note;
+            if unchanged {
+                // Edits made here come back as they were; only pick up a
+                // new owner.
+                app.timer_device = timer.device;
+                return;
+            }
+            restore_active_timer(app, timer);
+            app.set_status("Timer edited on another device".to_string());
+        }
+        types::LiveEvent::TimerStopped(_) | types::LiveEvent::EntrySaved(_) => {
+            let saved = matches!(event, types::LiveEvent::EntrySaved(_));
+            if saved {
+                if let Ok(entries) = fetch_recent_history(client).await {
+                    apply_recent_history(app, entries);
+                }
+            }
+            if app.timer_state != app::TimerState::Running {
+                return;
+            }
+            // This TUI may have started a new timer since; ask the server
+            // what is running now rather than trusting the event's order.
+            match client.get_active_timer().await {
+                Ok(None) => {
+                    app.clear_timer();
+                    app.set_status(if saved {
+                        "Timer saved on another device".to_string()
+                    } else {
+                        "Timer stopped on another device".to_string()
+                    });
+                }
+                Ok(Some(timer)) if !own_device(&timer.device) => {
+                    restore_active_timer(app, timer);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+async fn resume_entry(entry: types::TimeEntry, app: &mut App, client: &mut ApiClient) {
+    if app.timer_state == app::TimerState::Running {
+        // Timer already running — copy fields and sync to server (yank behaviour)
+        app.copy_entry_fields(&entry);
+
+        let timer = offline::current_timer(app);
+        if let Err(e) = offline::update_timer(app, client, timer).await {
+            app.set_status(format!(
+                "Warning: Could not sync copied entry to server: {}",
+                e
+            ));
+        } else {
+            app.set_status(format!(
+                "Copied: {}: {}",
+                entry.project_name, entry.activity_name
+            ));
+        }
+        return;
+    }
+
+    // Build server arguments from the entry directly (not from app state)
+    let timer = QueuedTimer {
+        project_id: Some(entry.project_id.clone()),
+        project_name: Some(entry.project_name.clone()),
+        activity_id: Some(entry.activity_id.clone()),
+        activity_name: Some(entry.activity_name.clone()),
+        note: entry.note.clone().filter(|n| !n.is_empty()),
+    };
+
+    match offline::start_timer(app, client, timer).await {
+        Ok(delivery) => {
+            // Only mutate local state after the start was sent or queued
+            app.copy_entry_fields(&entry);
+            app.set_status(match delivery {
+                Delivery::Sent => {
+                    format!("Resumed: {}: {}", entry.project_name, entry.activity_name)
+                }
+                Delivery::Queued => OFFLINE_TIMER_STARTED.to_string(),
+            });
+        }
+        Err(e) => {
+            app.set_status(format!("Error resuming entry: {}", e));
+        }
+    }
+}
+
+pub(super) async fn handle_save_timer_with_action(
+    app: &mut App,
+    client: &mut ApiClient,
+) -> Result<()> {
+    // Handle Cancel first
+    if app.selected_save_action == app::SaveAction::Cancel {
+        app.navigate_to(app::View::Timer);
+        return Ok(());
+    }
+
+    let duration = app.elapsed_duration();
+    let note = {
+        let full = app.note_for_save();
+        if full.is_empty() {
+            None
+        } else {
             Some(full)
         }
     };
 
     let project_display = app.current_project_name();
     let activity_display = app.current_activity_name();
-    let save_request = SaveTimerRequest {
+    let save_request = toki_types::SaveTimerPayload {
         user_note: note,
-        project_id: app.selected_project.as_ref().map(|p| p.id.clone()),
-        project_name: app.selected_project.as_ref().map(|p| p.name.clone()),
-        activity_id: app.selected_activity.as_ref().map(|a| a.id.clone()),
-        activity_name: app.selected_activity.as_ref().map(|a| a.name.clone()),
+        work_item_id: app.description_work_item_id.clone(),
+        restart_timer: None,
+        round_to_minutes: None,
     };
 
-    // Save the active timer to the time tracking backend
-    match client.save_timer(save_request).await {
-        Ok(()) => {
+    // Save the active timer to the time tracking backend, or queue it as an
+    // entry while the server can't be reached
+    let result = if app.offline_queue.is_empty() {
+        match client.save_timer(save_request).await {
+            Ok(lunch_deduction_minutes) => Ok((Delivery::Sent, lunch_deduction_minutes)),
+            Err(e) if api::is_unreachable(&e) => Ok(queue_timer_save(app)),
+            Err(e) => Err(e),
+        }
+    } else {
+        Ok(queue_timer_save(app))
+    };
+
+    match result {
+        Ok((delivery, lunch_deduction_minutes)) => {
+            app.description_work_item_id = None;
             let hours = duration.as_secs() / 3600;
             let minutes = (duration.as_secs() % 3600) / 60;
             let seconds = duration.as_secs() % 60;
-            let duration_str = format!("{:02}:{:02}:{:02}", hours, minutes, seconds);
+            let mut duration_str = format!("{:02}:{:02}:{:02}", hours, minutes, seconds);
+            if let Some(lunch) = lunch_deduction_minutes {
+                duration_str.push_str(&format!(" ({} min lunch deducted)", lunch));
+            }
 
-            // Refresh history
-            if let Ok(entries) = fetch_recent_history(client).await {
-                apply_recent_history(app, entries);
+            match delivery {
+                // Refresh history
+                Delivery::Sent => {
+                    if let Ok(entries) = fetch_recent_history(client).await {
+                        apply_recent_history(app, entries);
+                    }
+                }
+                Delivery::Queued => duration_str.push_str(" (offline)"),
             }
 
             match app.selected_save_action {
@@ -528,18 +1295,13 @@ pub(super) async fn handle_save_timer_with_action(
                     app.description_input.clear();
                     app.description_is_default = true;
                     // Start a new server-side timer with same project/activity
-                    let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-                    let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-                    let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-                    let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-                    if let Err(e) = client
-                        .start_timer(project_id, project_name, activity_id, activity_name, None)
-                        .await
-                    {
+                    let timer = QueuedTimer {
+                        note: None,
+                        ..offline::current_timer(app)
+                    };
+                    if let Err(e) = offline::start_timer(app, client, timer).await {
                         app.set_status(format!("Saved but could not restart timer: {}", e));
                     } else {
-                        let auto_resize = app.auto_resize_timer;
-                        app.start_timer(auto_resize);
                         app.set_status(format!(
                             "Saved {} to {} / {}",
                             duration_str, project_display, activity_display
@@ -552,11 +1314,10 @@ pub(super) async fn handle_save_timer_with_action(
                     app.description_input.clear();
                     app.description_is_default = true;
                     // Start new timer with no project yet
-                    if let Err(e) = client.start_timer(None, None, None, None, None).await {
+                    if let Err(e) = offline::start_timer(app, client, QueuedTimer::default()).await
+                    {
                         app.set_status(format!("Saved but could not restart timer: {}", e));
                     } else {
-                        let auto_resize = app.auto_resize_timer;
-                        app.start_timer(auto_resize);
                         app.set_status(format!(
                             "Saved {}. Timer started. Press P to select project.",
                             duration_str
@@ -584,6 +1345,126 @@ pub(super) async fn handle_save_timer_with_action(
     Ok(())
 }
 
+/// Queue the running timer to be saved as an entry ending now.
+fn queue_timer_save(app: &mut App) -> (Delivery, Option<i64>) {
+    let end = time::OffsetDateTime::now_utc();
+    let timer_started_at = app.absolute_start.unwrap_or(end);
+    let timer = offline::current_timer(app);
+    let entry = QueuedEntry {
+        project_id: timer.project_id.unwrap_or_default(),
+        project_name: timer.project_name.unwrap_or_default(),
+        activity_id: timer.activity_id.unwrap_or_default(),
+        activity_name: timer.activity_name.unwrap_or_default(),
+        start: timer_started_at,
+        end,
+        note: app.note_for_save(),
+    };
+    offline::queue(
+        app,
+        QueuedOperation::SaveTimer {
+            timer_started_at,
+            entry,
+            stop_server_timer: true,
+        },
+    );
+    (Delivery::Queued, None)
+}
+
+/// Apply the choice made in the idle prompt to the running timer.
+async fn resolve_idle(app: &mut App, client: &mut ApiClient) {
+    app.navigate_to(app::View::Timer);
+    let Some(idle) = app.idle_period.take() else {
+        return;
+    };
+    let minutes = idle.minutes();
+
+    match app.selected_idle_action {
+        app::IdleAction::Keep => {
+            app.set_status(format!("Kept {} idle min on the timer", minutes));
+        }
+        app::IdleAction::Discard => discard_idle_time(idle, app, client).await,
+        app::IdleAction::Split => {
+            let Some(timer_started_at) = app.absolute_start else {
+                return;
+            };
+            if !app.has_project_activity() {
+                app.set_status(
+                    "Cannot split: Please select Project / Activity first (press P)".to_string(),
+                );
+                return;
+            }
+            if idle.since - timer_started_at < time::Duration::minutes(1) {
+                // Nothing worth saving before the idle time.
+                discard_idle_time(idle, app, client).await;
+                return;
+            }
+
+            let timer = offline::current_timer(app);
+            let entry = QueuedEntry {
+                project_id: timer.project_id.clone().unwrap_or_default(),
+                project_name: timer.project_name.clone().unwrap_or_default(),
+                activity_id: timer.activity_id.clone().unwrap_or_default(),
+                activity_name: timer.activity_name.clone().unwrap_or_default(),
+                start: timer_started_at,
+                end: idle.since,
+                note: app.note_for_save(),
+            };
+            let worked = (idle.since - timer_started_at).whole_minutes();
+            let save = QueuedOperation::SaveTimer {
+                timer_started_at,
+                entry,
+                stop_server_timer: true,
+            };
+            let delivery = match offline::deliver(app, client, save).await {
+                Ok(delivery) => delivery,
+                Err(e) => {
+                    app.set_status(format!("Error saving timer: {}", e));
+                    return;
+                }
+            };
+
+            app.stop_timer(false);
+            if let Err(e) = offline::start_timer(app, client, timer).await {
+                app.set_status(format!("Saved but could not restart timer: {}", e));
+                return;
+            }
+            if delivery == Delivery::Sent {
+                if let Ok(entries) = fetch_recent_history(client).await {
+                    apply_recent_history(app, entries);
+                }
+            }
+            app.set_status(format!(
+                "Saved {} min before going idle, timer restarted{}",
+                worked,
+                if delivery == Delivery::Queued {
+                    " (offline)"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
+}
+
+/// Move the running timer's start forward so it doesn't count the idle time.
+async fn discard_idle_time(idle: app::IdlePeriod, app: &mut App, client: &mut ApiClient) {
+    let Some(timer_started_at) = app.shift_timer_start(idle.duration()) else {
+        return;
+    };
+    let update = QueuedOperation::UpdateTimer {
+        timer_started_at,
+        timer: QueuedTimer::default(),
+        new_start: app.absolute_start,
+    };
+    match offline::deliver(app, client, update).await {
+        Ok(_) => app.set_status(format!(
+            "Discarded {} idle min from the timer",
+            idle.minutes()
+        )),
+        Err(e) => app.set_status(format!("Warning: Could not sync timer to server: {}", e)),
+    }
+}
+
 // Helper functions for edit mode
 
 enum EditEnterAction {
@@ -695,15 +1576,11 @@ async fn handle_running_timer_edit_save(app: &mut App, client: &mut ApiClient) {
     };
 
     // Build new absolute_start: today's local date + typed HH:MM, converted to UTC
-    let local_offset = time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC);
-    let today = time::OffsetDateTime::now_utc()
-        .to_offset(local_offset)
-        .date();
     let Ok(new_time) = time::Time::from_hms(start_hours, start_mins, 0) else {
         app.set_status("Error: Invalid start time".to_string());
         return;
     };
-    let new_start = time::OffsetDateTime::new_in_offset(today, new_time, local_offset);
+    let new_start = crate::time_utils::local_date_time(crate::time_utils::local_today(), new_time);
 
     // Reject if new start is in the future
     if new_start > time::OffsetDateTime::now_utc() {
@@ -713,7 +1590,10 @@ async fn handle_running_timer_edit_save(app: &mut App, client: &mut ApiClient) {
         return;
     }
 
-    // Write back to App fields
+    // Write back to App fields, remembering which timer is being edited
+    let timer_started_at = app
+        .absolute_start
+        .unwrap_or_else(time::OffsetDateTime::now_utc);
     app.absolute_start = Some(new_start.to_offset(time::UtcOffset::UTC));
 
     // Recalculate local_start so elapsed_duration() reflects the new start time
@@ -724,10 +1604,15 @@ async fn handle_running_timer_edit_save(app: &mut App, client: &mut ApiClient) {
     app.local_start =
         Some(std::time::Instant::now() - std::time::Duration::from_secs(elapsed_secs));
 
-    app.selected_project = state
-        .project_id
-        .zip(state.project_name)
-        .map(|(id, name)| types::Project { id, name });
+    app.selected_project =
+        state
+            .project_id
+            .zip(state.project_name)
+            .map(|(id, name)| types::Project {
+                id,
+                name,
+                budget: None,
+            });
     app.selected_activity = state
         .activity_id
         .zip(state.activity_name)
@@ -741,27 +1626,12 @@ async fn handle_running_timer_edit_save(app: &mut App, client: &mut ApiClient) {
     app.set_status("Running timer updated".to_string());
 
     // Sync updated start time / project / activity / note to server
-    let project_id = app.selected_project.as_ref().map(|p| p.id.clone());
-    let project_name = app.selected_project.as_ref().map(|p| p.name.clone());
-    let activity_id = app.selected_activity.as_ref().map(|a| a.id.clone());
-    let activity_name = app.selected_activity.as_ref().map(|a| a.name.clone());
-    let full_note = app.full_note_value();
-    let note = if full_note.is_empty() {
-        None
-    } else {
-        Some(full_note)
+    let update = QueuedOperation::UpdateTimer {
+        timer_started_at,
+        timer: offline::current_timer(app),
+        new_start: app.absolute_start,
     };
-    if let Err(e) = client
-        .update_active_timer(
-            project_id,
-            project_name,
-            activity_id,
-            activity_name,
-            note,
-            app.absolute_start,
-        )
-        .await
-    {
+    if let Err(e) = offline::deliver(app, client, update).await {
         app.set_status(format!("Warning: Could not sync timer to server: {}", e));
     }
 }
@@ -772,122 +1642,398 @@ pub(super) async fn handle_history_edit_save(app: &mut App, client: &mut ApiClie
         return Ok(());
     };
     app.exit_history_edit_mode();
-    if let Err(e) = handle_saved_entry_edit_save(state, app, client).await {
-        app.set_status(format!("Error saving entry: {}", e));
+    if let Err(e) = handle_saved_entry_edit_save(state, app, client).await {
+        app.set_status(format!("Error saving entry: {}", e));
+    }
+    Ok(())
+}
+
+/// Shared helper: save edits to a completed (non-running) timer history entry via the API.
+async fn handle_saved_entry_edit_save(
+    state: app::EntryEditState,
+    app: &mut App,
+    client: &mut ApiClient,
+) -> Result<()> {
+    // Look up the original entry from history
+    let entry = match app
+        .time_entries
+        .iter()
+        .find(|e| e.registration_id == state.registration_id)
+    {
+        Some(e) => e.clone(),
+        None => {
+            app.set_status("Error: Entry not found in history".to_string());
+            return Ok(());
+        }
+    };
+
+    // registration_id is always present on TimeEntry
+    let registration_id = entry.registration_id.clone();
+
+    // Parse start / end times (HH:MM) on the entry's original local date
+    // Parse entry.date ("YYYY-MM-DD") to get the calendar date
+    let entry_date = app::parse_date_str(&entry.date)
+        .ok_or_else(|| anyhow::anyhow!("Unexpected date format: {}", entry.date))?;
+
+    let parse_hhmm = |s: &str| -> Result<time::Time> {
+        let parts: Vec<&str> = s.split(':').collect();
+        anyhow::ensure!(parts.len() == 2, "Expected HH:MM format, got {:?}", s);
+        let h: u8 = parts[0].parse().context("Invalid hour")?;
+        let m: u8 = parts[1].parse().context("Invalid minute")?;
+        time::Time::from_hms(h, m, 0).map_err(|e| anyhow::anyhow!("Invalid time: {}", e))
+    };
+
+    let start_local =
+        crate::time_utils::local_date_time(entry_date, parse_hhmm(&state.start_time_input)?);
+    let end_local =
+        crate::time_utils::local_date_time(entry_date, parse_hhmm(&state.end_time_input)?);
+
+    anyhow::ensure!(end_local > start_local, "End time must be after start time");
+
+    let start = start_local.to_offset(time::UtcOffset::UTC);
+    let end = end_local.to_offset(time::UtcOffset::UTC);
+    // What the entry looked like, so a queued edit can tell whether it was
+    // changed on the server in the meantime.
+    let before = QueuedEntry {
+        project_id: entry.project_id.clone(),
+        project_name: entry.project_name.clone(),
+        activity_id: entry.activity_id.clone(),
+        activity_name: entry.activity_name.clone(),
+        start: entry.start_time.unwrap_or(start),
+        end: entry.end_time.unwrap_or(end),
+        note: entry.note.clone().unwrap_or_default(),
+    };
+    let after = QueuedEntry {
+        project_id: state.project_id.unwrap_or_default(),
+        project_name: state.project_name.unwrap_or_default(),
+        activity_id: state.activity_id.unwrap_or_default(),
+        activity_name: state.activity_name.unwrap_or_default(),
+        start,
+        end,
+        note: state.note.value,
+    };
+
+    let edit = QueuedOperation::EditEntry {
+        registration_id: registration_id.clone(),
+        before: before.clone(),
+        after: after.clone(),
+    };
+    if offline::deliver(app, client, edit).await? == Delivery::Queued {
+        offline::apply_queued_edit(app, &registration_id, &after);
+        app.set_status(
+            "Entry updated offline, it will be synced once the server is reachable".to_string(),
+        );
+        return Ok(());
+    }
+    if before != after {
+        app.record_change(app::EntryChange::Edited { before, after });
+    }
+
+    // Reload history to reflect the changes
+    match fetch_recent_history(client).await {
+        Ok(entries) => {
+            apply_recent_history(app, entries);
+        }
+        Err(e) => {
+            app.set_status(format!(
+                "Entry updated (warning: could not reload history: {})",
+                e
+            ));
+            return Ok(());
+        }
+    }
+
+    app.set_status("Entry updated".to_string());
+    Ok(())
+}
+
+/// Split the entry in the split prompt. Leaves edit mode without saving
+/// other edits, since the split works on the entry as saved.
+async fn handle_split_entry(app: &mut App, client: &mut ApiClient) {
+    let Some(prompt) = app.split_prompt.clone() else {
+        return;
+    };
+    let Some(entry) = app
+        .time_entries
+        .iter()
+        .find(|e| e.registration_id == prompt.registration_id)
+        .cloned()
+    else {
+        app.close_split_prompt();
+        app.set_status("Error: Entry not found in history".to_string());
+        return;
+    };
+    let split_at = match app::parse_split_time(&prompt.input, &entry) {
+        Ok(split_at) => split_at,
+        Err(error) => {
+            if let Some(prompt) = &mut app.split_prompt {
+                prompt.error = Some(error);
+            }
+            return;
+        }
+    };
+
+    app.close_split_prompt();
+    app.exit_history_edit_mode();
+    app.exit_this_week_edit_mode();
+    if let Err(e) = client.split_time_entry(&entry, split_at).await {
+        app.set_status(format!("Error splitting entry: {}", e));
+        return;
+    }
+
+    match fetch_recent_history(client).await {
+        Ok(entries) => {
+            apply_recent_history(app, entries);
+            app.set_status("Entry split in two".to_string());
+        }
+        Err(e) => app.set_status(format!(
+            "Entry split (warning: could not reload history: {})",
+            e
+        )),
+    }
+}
+
+async fn handle_copy_entry(app: &mut App, client: &mut ApiClient) {
+    let Some(prompt) = app.copy_prompt.clone() else {
+        return;
+    };
+    let Some(entry) = app
+        .time_entries
+        .iter()
+        .find(|e| e.registration_id == prompt.registration_id)
+        .cloned()
+    else {
+        app.close_copy_prompt();
+        app.set_status("Error: Entry not found in history".to_string());
+        return;
+    };
+    let to_date = match app::parse_copy_date(&prompt.input).and_then(|to_date| {
+        if app.is_day_locked(&to_date.to_string()) {
+            Err(format!("{} is attested and locked", to_date))
+        } else {
+            Ok(to_date)
+        }
+    }) {
+        Ok(to_date) => to_date,
+        Err(error) => {
+            if let Some(prompt) = &mut app.copy_prompt {
+                prompt.error = Some(error);
+            }
+            return;
+        }
+    };
+
+    app.close_copy_prompt();
+    app.exit_history_edit_mode();
+    app.exit_this_week_edit_mode();
+    let copy = match client.copy_time_entry(&entry, to_date).await {
+        Ok(copy) => copy,
+        Err(e) => {
+            app.set_status(format!("Error copying entry: {}", e));
+            return;
+        }
+    };
+    if let Some(copy) = QueuedEntry::from_entry(&copy) {
+        app.record_change(app::EntryChange::Created(copy));
     }
-    Ok(())
-}
 
-/// Shared helper: save edits to a completed (non-running) timer history entry via the API.
-async fn handle_saved_entry_edit_save(
-    state: app::EntryEditState,
-    app: &mut App,
-    client: &mut ApiClient,
-) -> Result<()> {
-    // Look up the original entry from history
-    let entry = match app
-        .time_entries
-        .iter()
-        .find(|e| e.registration_id == state.registration_id)
-    {
-        Some(e) => e.clone(),
-        None => {
-            app.set_status("Error: Entry not found in history".to_string());
-            return Ok(());
+    match fetch_recent_history(client).await {
+        Ok(entries) => {
+            apply_recent_history(app, entries);
+            app.set_status(format!("Entry copied to {}", prompt.input.trim()));
         }
-    };
+        Err(e) => app.set_status(format!(
+            "Entry copied (warning: could not reload history: {})",
+            e
+        )),
+    }
+}
 
-    // registration_id is always present on TimeEntry
-    let registration_id = entry.registration_id.clone();
+/// Merge the entries selected in the History view into one.
+async fn handle_merge_entries(app: &mut App, client: &mut ApiClient) {
+    let entries: Vec<types::TimeEntry> =
+        app.marked_history_entries().into_iter().cloned().collect();
+    if entries.len() < 2 {
+        app.set_status("Select at least two entries with Space to merge".to_string());
+        return;
+    }
+    if entries.iter().any(|entry| entry.date != entries[0].date) {
+        app.set_status("Only entries on the same day can be merged".to_string());
+        return;
+    }
 
-    // Parse start / end times (HH:MM) on the entry's original local date
-    let local_offset = time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC);
+    let refs: Vec<&types::TimeEntry> = entries.iter().collect();
+    if let Err(e) = client.merge_time_entries(&refs).await {
+        app.set_status(format!("Error merging entries: {}", e));
+        return;
+    }
+    app.marked_entries.clear();
 
-    // Parse entry.date ("YYYY-MM-DD") to get the calendar date
-    let entry_date = app::parse_date_str(&entry.date)
-        .ok_or_else(|| anyhow::anyhow!("Unexpected date format: {}", entry.date))?;
+    match fetch_recent_history(client).await {
+        Ok(history) => {
+            apply_recent_history(app, history);
+            app.set_status(format!("Merged {} entries", entries.len()));
+        }
+        Err(e) => app.set_status(format!(
+            "Entries merged (warning: could not reload history: {})",
+            e
+        )),
+    }
+}
 
-    let parse_hhmm = |s: &str| -> Result<time::Time> {
-        let parts: Vec<&str> = s.split(':').collect();
-        anyhow::ensure!(parts.len() == 2, "Expected HH:MM format, got {:?}", s);
-        let h: u8 = parts[0].parse().context("Invalid hour")?;
-        let m: u8 = parts[1].parse().context("Invalid minute")?;
-        time::Time::from_hms(h, m, 0).map_err(|e| anyhow::anyhow!("Invalid time: {}", e))
+/// Apply the fixes proposed for the overlaps on one day, stopping at the
+/// first that fails. Each applied change can be undone on its own.
+async fn handle_resolve_overlaps(app: &mut App, client: &mut ApiClient) {
+    let Some(resolution) = app.overlap_resolution.take() else {
+        return;
     };
 
-    let start_local = time::OffsetDateTime::new_in_offset(
-        entry_date,
-        parse_hhmm(&state.start_time_input)?,
-        local_offset,
-    );
-    let end_local = time::OffsetDateTime::new_in_offset(
-        entry_date,
-        parse_hhmm(&state.end_time_input)?,
-        local_offset,
-    );
+    let mut applied = 0;
+    let mut failure = None;
+    for fix in &resolution.fixes {
+        match apply_overlap_fix(app, client, fix).await {
+            Ok(()) => applied += 1,
+            Err(e) => {
+                failure = Some(e);
+                break;
+            }
+        }
+    }
 
-    anyhow::ensure!(end_local > start_local, "End time must be after start time");
+    if let Some(date) = app::parse_date_str(&resolution.date) {
+        match client.get_time_entries(date, date).await {
+            Ok(entries) => app.merge_history(entries, date, date),
+            Err(e) if failure.is_none() => {
+                app.set_status(format!(
+                    "Overlaps resolved (warning: could not reload history: {})",
+                    e
+                ));
+                return;
+            }
+            Err(_) => {}
+        }
+    }
 
-    // Compute reg_day and week_number from the entry date
-    let reg_day = format!(
-        "{:04}-{:02}-{:02}",
-        entry_date.year(),
-        entry_date.month() as u8,
-        entry_date.day()
-    );
-    let week_number = entry_date.iso_week() as i32;
+    match failure {
+        Some(e) => app.set_status(format!(
+            "Error resolving overlaps after {} of {} fixes: {}",
+            applied,
+            resolution.fixes.len(),
+            e
+        )),
+        None if resolution.unresolved > 0 => app.set_status(format!(
+            "Resolved the overlaps on {}, {} left between attested entries",
+            resolution.date, resolution.unresolved
+        )),
+        None => app.set_status(format!("Resolved the overlaps on {}", resolution.date)),
+    }
+}
 
-    // Determine delta fields (only set if project/activity changed)
-    let original_project_id = if state.project_id.as_deref() != Some(entry.project_id.as_str()) {
-        Some(entry.project_id.as_str())
-    } else {
-        None
-    };
-    let original_activity_id = if state.activity_id.as_deref() != Some(entry.activity_id.as_str()) {
-        Some(entry.activity_id.as_str())
-    } else {
-        None
+async fn apply_overlap_fix(
+    app: &mut App,
+    client: &mut ApiClient,
+    fix: &app::OverlapFix,
+) -> Result<()> {
+    let (entry, start, end, note, remove) = match fix {
+        app::OverlapFix::Trim { entry, start, end } => (
+            entry,
+            *start,
+            *end,
+            entry.note.clone().unwrap_or_default(),
+            &[][..],
+        ),
+        app::OverlapFix::Merge {
+            keep,
+            remove,
+            start,
+            end,
+            note,
+        } => (keep, *start, *end, note.clone(), remove.as_slice()),
     };
 
-    let project_id = state.project_id.as_deref().unwrap_or("");
-    let project_name = state.project_name.as_deref().unwrap_or("");
-    let activity_id = state.activity_id.as_deref().unwrap_or("");
-    let activity_name = state.activity_name.as_deref().unwrap_or("");
-    let user_note = &state.note.value;
-
     client
         .edit_time_entry(
-            &registration_id,
-            project_id,
-            project_name,
-            activity_id,
-            activity_name,
-            start_local.to_offset(time::UtcOffset::UTC),
-            end_local.to_offset(time::UtcOffset::UTC),
-            &reg_day,
-            week_number,
-            user_note,
-            original_project_id,
-            original_activity_id,
+            &entry.registration_id,
+            &entry.project_id,
+            &entry.project_name,
+            &entry.activity_id,
+            &entry.activity_name,
+            start,
+            end,
+            &note,
         )
         .await?;
+    if let Some(before) = QueuedEntry::from_entry(entry) {
+        let after = QueuedEntry {
+            start,
+            end,
+            note,
+            ..before.clone()
+        };
+        app.record_change(app::EntryChange::Edited { before, after });
+    }
 
-    // Reload history to reflect the changes
-    match fetch_recent_history(client).await {
-        Ok(entries) => {
-            apply_recent_history(app, entries);
+    for entry in remove {
+        client.delete_time_entry(&entry.registration_id).await?;
+        if let Some(deleted) = QueuedEntry::from_entry(entry) {
+            app.record_change(app::EntryChange::Deleted(deleted));
         }
-        Err(e) => {
+    }
+    Ok(())
+}
+
+/// Move the entries marked in the History view to `project` / `activity`.
+async fn reassign_marked_entries(
+    app: &mut App,
+    client: &mut ApiClient,
+    project: &types::Project,
+    activity: &types::Activity,
+) {
+    let entries: Vec<types::TimeEntry> =
+        app.marked_history_entries().into_iter().cloned().collect();
+    let refs: Vec<&types::TimeEntry> = entries.iter().collect();
+    if let Err(e) = client.reassign_time_entries(&refs, project, activity).await {
+        app.set_status(format!("Error reassigning entries: {}", e));
+        return;
+    }
+    app.marked_entries.clear();
+
+    match fetch_recent_history(client).await {
+        Ok(history) => {
+            apply_recent_history(app, history);
             app.set_status(format!(
-                "Entry updated (warning: could not reload history: {})",
-                e
+                "Moved {} entries to {} / {}",
+                entries.len(),
+                project.name,
+                activity.name
             ));
-            return Ok(());
         }
+        Err(e) => app.set_status(format!(
+            "Entries reassigned (warning: could not reload history: {})",
+            e
+        )),
+    }
+}
+
+/// Write the entries shown in the History view to a file in the export dir.
+fn export_history(app: &mut App) {
+    let Some(dir) = app.export_dir.clone() else {
+        app.set_status(
+            "Export failed: no export directory, set export_dir in the config".to_string(),
+        );
+        return;
+    };
+    let entries = app.history_list();
+    if entries.is_empty() {
+        app.set_status("No entries to export".to_string());
+        return;
     }
 
-    app.set_status("Entry updated".to_string());
-    Ok(())
+    let count = entries.len();
+    match crate::export::write_entries(&dir, &entries, app.export_format) {
+        Ok(path) => app.set_status(format!("Exported {} entries to {}", count, path.display())),
+        Err(e) => app.set_status(format!("Export failed: {:#}", e)),
+    }
 }
 
 /// Open an existing log file for a history/today entry.
@@ -938,7 +2084,7 @@ async fn handle_open_log_note(app: &mut App, client: &mut ApiClient) -> anyhow::
         .unwrap_or_else(log_notes::generate_id);
 
     // Date string
-    let today = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+    let today = crate::time_utils::to_local_time(OffsetDateTime::now_utc());
     let date = format!(
         "{:04}-{:02}-{:02}",
         today.year(),
@@ -1003,6 +2149,7 @@ mod tests {
             hours: 1,
             minutes: 2,
             seconds: 3,
+            device: Some("web".to_string()),
         };
 
         restore_active_timer(&mut app, timer);
@@ -1020,6 +2167,168 @@ mod tests {
         assert!(!app.description_is_default);
         assert_eq!(app.absolute_start, Some(datetime!(2026-03-06 09:15 UTC)));
         assert!(app.local_start.is_some());
+        assert_eq!(app.timer_running_elsewhere(), Some("web"));
+    }
+
+    #[tokio::test]
+    async fn live_events_follow_timer_changes_on_other_devices() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let timer = ActiveTimerState {
+            start_time: datetime!(2026-03-06 09:15 UTC),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+            note: "Review".to_string(),
+            hours: 0,
+            minutes: 5,
+            seconds: 0,
+            device: Some("web".to_string()),
+        };
+
+        apply_live_event(types::LiveEvent::TimerStarted(timer), &mut app, &mut client).await;
+        assert_eq!(app.timer_state, app::TimerState::Running);
+        assert_eq!(app.timer_running_elsewhere(), Some("web"));
+
+        // The dev backend has no server timer, as after a stop elsewhere.
+        apply_live_event(types::LiveEvent::TimerStopped(None), &mut app, &mut client).await;
+        assert_eq!(app.timer_state, app::TimerState::Stopped);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Timer stopped on another device")
+        );
+    }
+
+    #[tokio::test]
+    async fn live_events_follow_timer_edits_and_take_overs() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let timer = ActiveTimerState {
+            start_time: datetime!(2026-03-06 09:15 UTC),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+            note: "Review".to_string(),
+            hours: 0,
+            minutes: 5,
+            seconds: 0,
+            device: Some(app.device_name.clone()),
+        };
+        restore_active_timer(&mut app, timer.clone());
+
+        // Taken over by another device, nothing else changed
+        let taken_over = ActiveTimerState {
+            device: Some("web".to_string()),
+            ..timer.clone()
+        };
+        apply_live_event(
+            types::LiveEvent::TimerUpdated(taken_over.clone()),
+            &mut app,
+            &mut client,
+        )
+        .await;
+        assert_eq!(app.timer_running_elsewhere(), Some("web"));
+        assert_eq!(app.status_message, None);
+
+        let edited = ActiveTimerState {
+            start_time: datetime!(2026-03-06 09:00 UTC),
+            note: "Review and merge".to_string(),
+            ..taken_over
+        };
+        apply_live_event(
+            types::LiveEvent::TimerUpdated(edited),
+            &mut app,
+            &mut client,
+        )
+        .await;
+        assert_eq!(app.absolute_start, Some(datetime!(2026-03-06 09:00 UTC)));
+        assert_eq!(app.description_input.value, "Review and merge");
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Timer edited on another device")
+        );
+    }
+
+    #[tokio::test]
+    async fn own_live_events_leave_the_timer_alone() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        app.start_timer(false);
+        let started_at = app.absolute_start;
+        let timer = ActiveTimerState {
+            start_time: datetime!(2026-03-06 09:15 UTC),
+            project_id: None,
+            project_name: None,
+            activity_id: None,
+            activity_name: None,
+            note: String::new(),
+            hours: 0,
+            minutes: 0,
+            seconds: 1,
+            device: Some(app.device_name.clone()),
+        };
+
+        apply_live_event(types::LiveEvent::TimerStarted(timer), &mut app, &mut client).await;
+
+        assert_eq!(app.absolute_start, started_at);
+    }
+
+    #[tokio::test]
+    async fn toggle_favorite_pins_and_unpins_the_highlighted_activity() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        app.selected_project = Some(types::Project {
+            id: "proj_2".to_string(),
+            name: "Azure DevOps Integration".to_string(),
+            budget: None,
+        });
+        app.filtered_activities = client.get_activities("proj_2").await.unwrap();
+        app.filtered_activity_index = 1;
+
+        toggle_favorite(&mut app, &mut client).await;
+
+        assert_eq!(app.favorites.len(), 1);
+        assert_eq!(app.favorites[0].activity_id, app.filtered_activities[1].id);
+        assert_eq!(
+            client.get_favorites().await.unwrap().len(),
+            2,
+            "the dev backend starts with one favorite"
+        );
+
+        toggle_favorite(&mut app, &mut client).await;
+
+        assert!(app.favorites.is_empty());
+        assert_eq!(client.get_favorites().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn saved_templates_log_an_entry_ending_now() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        app.set_projects_activities(client.get_projects().await.unwrap(), vec![]);
+        let standup = types::TimeEntry {
+            hours: 0.25,
+            note: Some("Standup".to_string()),
+            ..fetch_recent_history(&mut client).await.unwrap().remove(0)
+        };
+
+        run_action(Action::SaveEntryAsTemplate(standup), &mut app, &mut client)
+            .await
+            .unwrap();
+        let template = app.templates.last().cloned().expect("saved template");
+        assert_eq!(template.duration_minutes, Some(15));
+        let before = fetch_recent_history(&mut client).await.unwrap().len();
+
+        log_template(template, &mut app, &mut client).await;
+
+        let entries = fetch_recent_history(&mut client).await.unwrap();
+        assert_eq!(entries.len(), before + 1);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Logged Standup (15 min)")
+        );
     }
 
     #[tokio::test]
@@ -1037,6 +2346,27 @@ mod tests {
         assert!(app.status_message.is_none());
     }
 
+    #[tokio::test]
+    async fn pausing_keeps_the_timer_running_when_the_server_cant_pause_it() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        handle_start_timer(&mut app, &mut client)
+            .await
+            .expect("start timer should succeed");
+
+        pause_timer(&mut app, &mut client).await;
+
+        assert_eq!(app.timer_state, app::TimerState::Running);
+        assert!(app.paused_timers.is_empty());
+        assert!(app
+            .status_message
+            .as_deref()
+            .is_some_and(|status| status.starts_with("Could not pause timer")));
+
+        resume_paused_timer(&mut app, &mut client).await;
+        assert_eq!(app.status_message.as_deref(), Some("No paused timers"));
+    }
+
     #[tokio::test]
     async fn handle_save_timer_cancel_returns_to_timer_without_saving() {
         let mut app = test_app();
@@ -1100,7 +2430,7 @@ mod tests {
         app.rebuild_history_list();
         app.current_view = View::ConfirmDelete;
         app.delete_context = Some(DeleteContext {
-            registration_id: entry.registration_id.clone(),
+            registration_ids: vec![entry.registration_id.clone()],
             display_label: format!("{} / {}", entry.project_name, entry.activity_name),
             display_date: entry.date.clone(),
             display_hours: entry.hours,
@@ -1117,4 +2447,173 @@ mod tests {
             .all(|item| item.registration_id != entry.registration_id));
         assert!(app.delete_context.is_none());
     }
+
+    #[tokio::test]
+    async fn handle_confirm_delete_deletes_all_marked_entries() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let entries = fetch_recent_history(&mut client)
+            .await
+            .expect("history should load");
+        let count = entries.len();
+        apply_recent_history(&mut app, entries);
+        app.focused_history_index = Some(0);
+        app.toggle_entry_mark();
+        app.focused_history_index = Some(1);
+        app.toggle_entry_mark();
+        let marked: Vec<String> = app.marked_entries.iter().cloned().collect();
+
+        app.enter_marked_delete_confirm();
+        assert_eq!(app.current_view, View::ConfirmDelete);
+        handle_confirm_delete(&mut app, &mut client).await;
+
+        assert_eq!(app.current_view, View::History);
+        assert_eq!(app.time_entries.len(), count - 2);
+        assert!(app
+            .time_entries
+            .iter()
+            .all(|entry| !marked.contains(&entry.registration_id)));
+        assert!(app.marked_entries.is_empty());
+        assert_eq!(app.undo_stack.len(), 2);
+        let remaining = fetch_recent_history(&mut client).await.unwrap();
+        assert_eq!(remaining.len(), count - 2);
+    }
+
+    #[tokio::test]
+    async fn undo_recreates_a_deleted_entry_and_redo_deletes_it_again() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let entries = fetch_recent_history(&mut client)
+            .await
+            .expect("history should load");
+        let entry = entries
+            .iter()
+            .find(|e| e.start_time.is_some() && e.end_time.is_some())
+            .expect("seeded entry with times")
+            .clone();
+        let count = entries.len();
+        apply_recent_history(&mut app, entries);
+        app.delete_context = Some(DeleteContext {
+            registration_ids: vec![entry.registration_id.clone()],
+            display_label: format!("{} / {}", entry.project_name, entry.activity_name),
+            display_date: entry.date.clone(),
+            display_hours: entry.hours,
+            origin: DeleteOrigin::History,
+        });
+
+        handle_confirm_delete(&mut app, &mut client).await;
+        assert_eq!(app.time_entries.len(), count - 1);
+
+        undo_change(&mut app, &mut client).await;
+        assert_eq!(app.time_entries.len(), count);
+        let queued = QueuedEntry::from_entry(&entry).unwrap();
+        assert!(app.find_registration(&queued).is_some());
+        assert!(app.undo_stack.is_empty());
+
+        redo_change(&mut app, &mut client).await;
+        assert_eq!(app.time_entries.len(), count - 1);
+        assert!(app.find_registration(&queued).is_none());
+        assert_eq!(app.undo_stack.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_copy_entry_copies_the_entry_to_the_given_day() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let entries = fetch_recent_history(&mut client)
+            .await
+            .expect("history should load");
+        let entry = entries
+            .iter()
+            .find(|e| e.start_time.is_some() && e.end_time.is_some())
+            .expect("seeded entry with times")
+            .clone();
+        let count = entries.len();
+        apply_recent_history(&mut app, entries);
+        let today = crate::time_utils::local_today().to_string();
+        app.copy_prompt = Some(app::CopyPrompt {
+            registration_id: entry.registration_id.clone(),
+            input: "2026-13-01".to_string(),
+            error: None,
+        });
+
+        handle_copy_entry(&mut app, &mut client).await;
+        assert!(app.copy_prompt.as_ref().unwrap().error.is_some());
+        assert_eq!(app.time_entries.len(), count);
+
+        app.copy_prompt.as_mut().unwrap().input = today.clone();
+        handle_copy_entry(&mut app, &mut client).await;
+
+        assert!(app.copy_prompt.is_none());
+        assert_eq!(app.time_entries.len(), count + 1);
+        assert!(app.time_entries.iter().any(|e| e.date == today
+            && e.registration_id != entry.registration_id
+            && e.project_id == entry.project_id
+            && e.note == entry.note
+            && e.hours == entry.hours));
+        assert_eq!(app.undo_stack.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn handle_copy_entry_refuses_attested_days() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let mut entries = fetch_recent_history(&mut client)
+            .await
+            .expect("history should load");
+        let count = entries.len();
+        entries[0].status = toki_types::TimeEntryStatus::Approved;
+        let locked_date = entries[0].date.clone();
+        let registration_id = entries[1].registration_id.clone();
+        apply_recent_history(&mut app, entries);
+        app.copy_prompt = Some(app::CopyPrompt {
+            registration_id,
+            input: locked_date.clone(),
+            error: None,
+        });
+
+        handle_copy_entry(&mut app, &mut client).await;
+
+        assert_eq!(
+            app.copy_prompt.as_ref().unwrap().error.as_deref(),
+            Some(format!("{} is attested and locked", locked_date).as_str())
+        );
+        assert_eq!(
+            fetch_recent_history(&mut client).await.unwrap().len(),
+            count
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_resolve_overlaps_trims_through_the_edit_api() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().expect("dev client");
+        let day = crate::time_utils::local_today() - time::Duration::days(45);
+        let at = |hour| {
+            crate::time_utils::local_date_time(day, time::Time::from_hms(hour, 0, 0).unwrap())
+        };
+        for (activity, start, end) in [("a1", 8, 10), ("a2", 9, 11)] {
+            client
+                .create_time_entry("p1", "Toki", activity, activity, at(start), at(end), "")
+                .await
+                .expect("entry should be created");
+        }
+        let entries = client.get_time_entries(day, day).await.unwrap();
+        let date = entries[0].date.clone();
+        app.merge_history(entries, day, day);
+        app.open_overlap_resolution_on(date);
+        assert_eq!(app.overlap_resolution.as_ref().unwrap().fixes.len(), 1);
+
+        handle_resolve_overlaps(&mut app, &mut client).await;
+
+        assert!(app.overlap_resolution.is_none());
+        let entries = client.get_time_entries(day, day).await.unwrap();
+        let trimmed = entries.iter().find(|e| e.activity_id == "a1").unwrap();
+        assert_eq!(trimmed.end_time, Some(at(9)));
+        assert_eq!(app.undo_stack.len(), 1);
+        assert!(app
+            .time_entries
+            .iter()
+            .any(|e| e.activity_id == "a1" && e.end_time == Some(at(9))));
+    }
 }
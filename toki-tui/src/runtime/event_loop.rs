@@ -1,20 +1,24 @@
 use crate::api::ApiClient;
 use crate::app::App;
+use crate::recovery_store;
 use crate::ui;
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyEventKind};
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::path::Path;
 use std::time::{Duration, Instant};
+use time::OffsetDateTime;
 
 use super::action_queue::{channel, Action};
 use super::actions::run_action;
-use super::views::handle_view_key;
+use super::views::{handle_view_key, handle_view_mouse};
 
 pub async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     client: &mut ApiClient,
+    recovery_path: Option<&Path>,
 ) -> Result<()> {
     // Show throbber for at least 3 seconds on startup.
     app.is_loading = true;
@@ -24,8 +28,20 @@ pub async fn run_app(
     let mut last_history_refresh = Instant::now();
     const HISTORY_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
 
+    // Offline queue: retry queued changes now and then while any are left.
+    let mut last_offline_replay: Option<Instant> = None;
+    const OFFLINE_REPLAY_INTERVAL: Duration = Duration::from_secs(30);
+
+    // Crash recovery: persist unsaved state every few seconds when it changes.
+    let mut last_snapshot = Instant::now();
+    let mut saved_state = None;
+    const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(5);
+
     let (action_tx, mut action_rx) = channel();
 
+    // Timer changes pushed by the server as they happen on any device.
+    let mut live_events = client.live_events();
+
     loop {
         // Clear before drawing to avoid a flash when the screen needs a full repaint
         // (e.g. after returning from an external editor or waking from sleep).
@@ -49,7 +65,14 @@ pub async fn run_app(
                     if key.kind != KeyEventKind::Press {
                         continue;
                     }
-                    handle_view_key(key, app, &action_tx);
+                    // The first key after being idle only opens the idle prompt.
+                    if !app.register_input(OffsetDateTime::now_utc()) {
+                        handle_view_key(key, app, &action_tx);
+                    }
+                }
+                // Like keys, the first mouse event after being idle only opens the prompt.
+                Event::Mouse(mouse) if !app.register_input(OffsetDateTime::now_utc()) => {
+                    handle_view_mouse(mouse, app, &action_tx);
                 }
                 // Force a full redraw when the terminal regains focus (e.g. after sleep/wake)
                 Event::FocusGained => {
@@ -59,11 +82,47 @@ pub async fn run_app(
             }
         }
 
+        app.register_tick(OffsetDateTime::now_utc());
+
+        if app.tick_pomodoro().is_some() && app.pomodoro_settings.bell {
+            ring_bell();
+        }
+
         if last_history_refresh.elapsed() >= HISTORY_REFRESH_INTERVAL && !app.is_in_edit_mode() {
             let _ = action_tx.send(Action::RefreshHistoryBackground);
             last_history_refresh = Instant::now();
         }
 
+        if let Some(events) = &mut live_events {
+            while let Ok(event) = events.try_recv() {
+                let _ = action_tx.send(Action::ApplyLiveEvent(event));
+            }
+        }
+
+        if !app.offline_queue.is_empty()
+            && last_offline_replay.is_none_or(|last| last.elapsed() >= OFFLINE_REPLAY_INTERVAL)
+        {
+            let _ = action_tx.send(Action::ReplayOfflineQueue);
+            last_offline_replay = Some(Instant::now());
+        }
+
+        if let Some(path) = recovery_path {
+            if last_snapshot.elapsed() >= SNAPSHOT_INTERVAL {
+                let state = app.unsaved_state();
+                if state != saved_state {
+                    let result = match &state {
+                        Some(state) => recovery_store::save_snapshot(path, state),
+                        None => recovery_store::clear_snapshot(path),
+                    };
+                    match result {
+                        Ok(()) => saved_state = state,
+                        Err(e) => app.set_status(format!("Could not write recovery file: {}", e)),
+                    }
+                }
+                last_snapshot = Instant::now();
+            }
+        }
+
         while let Ok(action) = action_rx.try_recv() {
             run_action(action, app, client).await?;
         }
@@ -75,3 +134,11 @@ pub async fn run_app(
 
     Ok(())
 }
+
+/// Ring the terminal bell. Failing to is harmless, the status box still
+/// announces the interval.
+fn ring_bell() {
+    use std::io::Write;
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07").and_then(|()| stdout.flush());
+}
@@ -1,6 +1,7 @@
 mod action_queue;
 mod actions;
 mod event_loop;
+mod offline;
 mod views;
 
 pub(crate) use actions::restore_active_timer;
@@ -0,0 +1,420 @@
+use crate::api::{self, ApiClient};
+use crate::app::App;
+use crate::offline_queue::{same_instant, QueuedEntry, QueuedOperation, QueuedTimer};
+use crate::time_utils::to_local_time;
+use anyhow::Result;
+use time::OffsetDateTime;
+
+use super::actions::{apply_recent_history, fetch_recent_history};
+
+/// Where a change ended up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Delivery {
+    Sent,
+    /// Queued until the server is reachable again.
+    Queued,
+}
+
+/// Outcome of replaying one queued change.
+enum Replay {
+    Applied,
+    /// Skipped because the server changed in the meantime.
+    Conflict(String),
+}
+
+/// Send a change to the server, or queue it when the server can't be reached.
+/// Changes made while earlier ones are still queued are queued behind them,
+/// so the server sees them in the order they were made.
+pub(super) async fn deliver(
+    app: &mut App,
+    client: &mut ApiClient,
+    operation: QueuedOperation,
+) -> Result<Delivery> {
+    if !app.offline_queue.is_empty() {
+        queue(app, operation);
+        return Ok(Delivery::Queued);
+    }
+
+    match send(client, &operation).await {
+        Ok(()) => Ok(Delivery::Sent),
+        Err(e) if api::is_unreachable(&e) => {
+            queue(app, operation);
+            Ok(Delivery::Queued)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Queue a change for replay. It stays queued in memory even if the journal
+/// can't be written.
+pub(super) fn queue(app: &mut App, operation: QueuedOperation) {
+    if let Err(e) = app.offline_queue.push(operation) {
+        app.set_status(format!("Warning: Could not write offline queue: {}", e));
+    }
+}
+
+/// Update the running timer, queued while offline.
+pub(super) async fn update_timer(
+    app: &mut App,
+    client: &mut ApiClient,
+    timer: QueuedTimer,
+) -> Result<Delivery> {
+    let timer_started_at = app.absolute_start.unwrap_or_else(OffsetDateTime::now_utc);
+    deliver(
+        app,
+        client,
+        QueuedOperation::UpdateTimer {
+            timer_started_at,
+            timer,
+            new_start: None,
+        },
+    )
+    .await
+}
+
+/// Start a timer on the server and locally. While offline it only runs
+/// locally until the queued start is replayed.
+pub(super) async fn start_timer(
+    app: &mut App,
+    client: &mut ApiClient,
+    timer: QueuedTimer,
+) -> Result<Delivery> {
+    let started_at = OffsetDateTime::now_utc();
+    let delivery = deliver(
+        app,
+        client,
+        QueuedOperation::StartTimer { timer, started_at },
+    )
+    .await?;
+
+    let auto_resize = app.auto_resize_timer;
+    app.start_timer(auto_resize);
+    // Later changes to this timer refer to it by its start.
+    app.absolute_start = Some(started_at);
+    Ok(delivery)
+}
+
+/// The selected project and activity and the current note.
+pub(super) fn current_timer(app: &App) -> QueuedTimer {
+    let note = app.full_note_value();
+    QueuedTimer {
+        project_id: app.selected_project.as_ref().map(|p| p.id.clone()),
+        project_name: app.selected_project.as_ref().map(|p| p.name.clone()),
+        activity_id: app.selected_activity.as_ref().map(|a| a.id.clone()),
+        activity_name: app.selected_activity.as_ref().map(|a| a.name.clone()),
+        note: (!note.is_empty()).then_some(note),
+    }
+}
+
+/// Replay queued changes in order until the queue is empty or the server is
+/// unreachable again. Changes that conflict with what's on the server, or
+/// that the server rejects, are dropped and reported.
+pub(super) async fn replay_offline_queue(app: &mut App, client: &mut ApiClient) {
+    let mut synced = 0;
+    let mut skipped = vec![];
+
+    while let Some(pending) = app.offline_queue.front() {
+        let operation = pending.operation.clone();
+        match replay(client, &operation).await {
+            Ok(Replay::Applied) => synced += 1,
+            Ok(Replay::Conflict(reason)) => {
+                skipped.push(format!("{} ({})", operation.describe(), reason))
+            }
+            Err(e) if api::is_unreachable(&e) => break,
+            Err(e) => skipped.push(format!("{} ({})", operation.describe(), e)),
+        }
+        if let Err(e) = app.offline_queue.pop_front() {
+            app.set_status(format!("Warning: Could not write offline queue: {}", e));
+        }
+    }
+
+    if synced == 0 && skipped.is_empty() {
+        return;
+    }
+
+    if synced > 0 {
+        if let Ok(entries) = fetch_recent_history(client).await {
+            apply_recent_history(app, entries);
+        }
+    }
+
+    if skipped.is_empty() {
+        app.set_status(format!("Synced {} offline change(s)", synced));
+    } else {
+        app.set_status(format!(
+            "Warning: Synced {} offline change(s), skipped {}: {}",
+            synced,
+            skipped.len(),
+            skipped.join("; ")
+        ));
+    }
+}
+
+/// Send a change as-is, without checking what's on the server.
+async fn send(client: &mut ApiClient, operation: &QueuedOperation) -> Result<()> {
+    match operation {
+        QueuedOperation::StartTimer { timer, .. } => {
+            let timer = timer.clone();
+            client
+                .start_timer(
+                    timer.project_id,
+                    timer.project_name,
+                    timer.activity_id,
+                    timer.activity_name,
+                    timer.note,
+                )
+                .await
+        }
+        QueuedOperation::UpdateTimer {
+            timer, new_start, ..
+        } => {
+            let timer = timer.clone();
+            client
+                .update_active_timer(
+                    timer.project_id,
+                    timer.project_name,
+                    timer.activity_id,
+                    timer.activity_name,
+                    timer.note,
+                    *new_start,
+                )
+                .await
+        }
+        QueuedOperation::SaveTimer {
+            entry,
+            stop_server_timer,
+            ..
+        } => {
+            create_entry(client, entry).await?;
+            if *stop_server_timer {
+                client.stop_timer().await?;
+            }
+            Ok(())
+        }
+        QueuedOperation::StopTimer { .. } => client.stop_timer().await,
+        QueuedOperation::EditEntry {
+            registration_id,
+            after,
+            ..
+        } => {
+            client
+                .edit_time_entry(
+                    registration_id,
+                    &after.project_id,
+                    &after.project_name,
+                    &after.activity_id,
+                    &after.activity_name,
+                    after.start,
+                    after.end,
+                    &after.note,
+                )
+                .await
+        }
+    }
+}
+
+pub(super) async fn create_entry(client: &mut ApiClient, entry: &QueuedEntry) -> Result<()> {
+    client
+        .create_time_entry(
+            &entry.project_id,
+            &entry.project_name,
+            &entry.activity_id,
+            &entry.activity_name,
+            entry.start,
+            entry.end,
+            &entry.note,
+        )
+        .await
+}
+
+/// Send a queued change, unless the server changed in a way that makes it
+/// no longer apply.
+async fn replay(client: &mut ApiClient, operation: &QueuedOperation) -> Result<Replay> {
+    match operation {
+        QueuedOperation::StartTimer { started_at, .. } => {
+            match client.get_active_timer().await? {
+                // The start reached the server before the connection dropped.
+                Some(active) if same_instant(active.start_time, *started_at) => {
+                    return Ok(Replay::Applied)
+                }
+                Some(active) => {
+                    return Ok(Replay::Conflict(format!(
+                        "a timer started at {} is already running",
+                        hh_mm(active.start_time)
+                    )))
+                }
+                None => {}
+            }
+            send(client, operation).await?;
+            client
+                .update_active_timer(None, None, None, None, None, Some(*started_at))
+                .await?;
+        }
+        QueuedOperation::UpdateTimer {
+            timer_started_at,
+            new_start,
+            ..
+        } => {
+            let active = client.get_active_timer().await?;
+            let same_timer = active.is_some_and(|active| {
+                same_instant(active.start_time, *timer_started_at)
+                    || new_start.is_some_and(|start| same_instant(active.start_time, start))
+            });
+            if !same_timer {
+                return Ok(Replay::Conflict(
+                    "the timer is no longer running on the server".to_string(),
+                ));
+            }
+            send(client, operation).await?;
+        }
+        QueuedOperation::SaveTimer {
+            timer_started_at,
+            entry,
+            stop_server_timer,
+        } => {
+            let date = to_local_time(entry.start).date();
+            let entries = client.get_time_entries(date, date).await?;
+            if let Some(existing) = entries.iter().find(|existing| entry.overlaps(existing)) {
+                return Ok(Replay::Conflict(format!(
+                    "overlaps {} / {} on the server",
+                    existing.project_name, existing.activity_name
+                )));
+            }
+            create_entry(client, entry).await?;
+
+            if *stop_server_timer {
+                let active = client.get_active_timer().await?;
+                if active.is_some_and(|active| same_instant(active.start_time, *timer_started_at)) {
+                    client.stop_timer().await?;
+                }
+            }
+        }
+        QueuedOperation::StopTimer { timer_started_at } => match client.get_active_timer().await? {
+            None => {}
+            Some(active) if same_instant(active.start_time, *timer_started_at) => {
+                send(client, operation).await?;
+            }
+            Some(_) => {
+                return Ok(Replay::Conflict(
+                    "a different timer is running on the server".to_string(),
+                ))
+            }
+        },
+        QueuedOperation::EditEntry {
+            registration_id,
+            before,
+            ..
+        } => {
+            let date = to_local_time(before.start).date();
+            let entries = client.get_time_entries(date, date).await?;
+            match entries
+                .iter()
+                .find(|entry| &entry.registration_id == registration_id)
+            {
+                None => return Ok(Replay::Conflict("it was deleted on the server".to_string())),
+                Some(entry) if !before.matches(entry) => {
+                    return Ok(Replay::Conflict("it was changed on the server".to_string()))
+                }
+                Some(_) => send(client, operation).await?,
+            }
+        }
+    }
+    Ok(Replay::Applied)
+}
+
+fn hh_mm(dt: OffsetDateTime) -> String {
+    let local = to_local_time(dt);
+    format!("{:02}:{:02}", local.hour(), local.minute())
+}
+
+/// Apply a queued entry edit to the local history so it shows up before it
+/// reaches the server.
+pub(super) fn apply_queued_edit(app: &mut App, registration_id: &str, after: &QueuedEntry) {
+    let mut entries = app.time_entries.clone();
+    let Some(entry) = entries
+        .iter_mut()
+        .find(|entry| entry.registration_id == registration_id)
+    else {
+        return;
+    };
+
+    entry.project_id = after.project_id.clone();
+    entry.project_name = after.project_name.clone();
+    entry.activity_id = after.activity_id.clone();
+    entry.activity_name = after.activity_name.clone();
+    entry.note = Some(after.note.clone()).filter(|note| !note.is_empty());
+    entry.start_time = Some(after.start);
+    entry.end_time = Some(after.end);
+    entry.hours = (after.end - after.start).as_seconds_f64() / 3600.0;
+    apply_recent_history(app, entries);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_app, time_entry};
+    use time::macros::datetime;
+
+    #[test]
+    fn queued_edit_updates_local_history() {
+        let mut app = test_app();
+        app.update_history(vec![time_entry(
+            "42",
+            "p1",
+            "Toki",
+            "a1",
+            "Development",
+            "2026-03-02",
+            1.0,
+            Some("Before"),
+            Some(datetime!(2026-03-02 08:00 UTC)),
+            Some(datetime!(2026-03-02 09:00 UTC)),
+        )]);
+
+        apply_queued_edit(
+            &mut app,
+            "42",
+            &QueuedEntry {
+                project_id: "p1".to_string(),
+                project_name: "Toki".to_string(),
+                activity_id: "a2".to_string(),
+                activity_name: "Meetings".to_string(),
+                start: datetime!(2026-03-02 08:00 UTC),
+                end: datetime!(2026-03-02 09:30 UTC),
+                note: "After".to_string(),
+            },
+        );
+
+        let entry = &app.time_entries[0];
+        assert_eq!(entry.activity_name, "Meetings");
+        assert_eq!(entry.note.as_deref(), Some("After"));
+        assert_eq!(entry.hours, 1.5);
+    }
+
+    #[tokio::test]
+    async fn deliver_queues_behind_pending_changes() {
+        let mut app = test_app();
+        let mut client = ApiClient::dev().unwrap();
+        queue(
+            &mut app,
+            QueuedOperation::StopTimer {
+                timer_started_at: datetime!(2026-03-02 08:00 UTC),
+            },
+        );
+
+        let delivery = deliver(
+            &mut app,
+            &mut client,
+            QueuedOperation::StartTimer {
+                timer: QueuedTimer::default(),
+                started_at: datetime!(2026-03-02 09:00 UTC),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(delivery, Delivery::Queued);
+        assert_eq!(app.offline_queue.len(), 2);
+        assert_eq!(app.timer_state, crate::app::TimerState::Stopped);
+    }
+}
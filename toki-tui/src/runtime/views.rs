@@ -1,13 +1,22 @@
 use crate::app::{self, App};
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 use super::action_queue::{Action, ActionTx};
 
+mod board;
+mod calendar;
 mod confirm_delete;
+mod copy_prompt;
 mod edit_description;
+mod fill_workdays;
 mod history;
+mod history_range_prompt;
+mod idle_prompt;
+mod overlap_prompt;
+mod pull_requests;
 mod save_action;
 mod selection;
+mod split_prompt;
 mod statistics;
 mod template_selection;
 mod timer;
@@ -17,6 +26,23 @@ fn enqueue_action(action_tx: &ActionTx, action: Action) {
 }
 
 pub(super) fn handle_view_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    if app.split_prompt.is_some() {
+        split_prompt::handle_split_prompt_key(key, app, action_tx);
+        return;
+    }
+    if app.copy_prompt.is_some() {
+        copy_prompt::handle_copy_prompt_key(key, app, action_tx);
+        return;
+    }
+    if app.history_range_prompt.is_some() {
+        history_range_prompt::handle_history_range_prompt_key(key, app, action_tx);
+        return;
+    }
+    if app.overlap_resolution.is_some() {
+        overlap_prompt::handle_overlap_prompt_key(key, app, action_tx);
+        return;
+    }
+
     match &app.current_view {
         app::View::SelectProject => selection::handle_select_project_key(key, app, action_tx),
         app::View::SelectActivity => selection::handle_select_activity_key(key, app, action_tx),
@@ -27,9 +53,62 @@ pub(super) fn handle_view_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx
             edit_description::handle_edit_description_key(key, app, action_tx)
         }
         app::View::SaveAction => save_action::handle_save_action_key(key, app, action_tx),
+        app::View::IdlePrompt => idle_prompt::handle_idle_prompt_key(key, app, action_tx),
+        app::View::FillWorkdays => fill_workdays::handle_fill_workdays_key(key, app, action_tx),
         app::View::History => history::handle_history_key(key, app, action_tx),
-        app::View::Statistics => statistics::handle_statistics_key(key, app),
+        app::View::Statistics => statistics::handle_statistics_key(key, app, action_tx),
+        app::View::Calendar => calendar::handle_calendar_key(key, app, action_tx),
+        app::View::PullRequests => pull_requests::handle_pull_requests_key(key, app, action_tx),
+        app::View::PullRequestDetail => {
+            pull_requests::handle_pull_request_detail_key(key, app, action_tx)
+        }
+        app::View::Board => board::handle_board_key(key, app, action_tx),
         app::View::ConfirmDelete => confirm_delete::handle_confirm_delete_key(key, app, action_tx),
         app::View::Timer => timer::handle_timer_key(key, app, action_tx),
     }
 }
+
+pub(super) fn handle_view_mouse(mouse: MouseEvent, app: &mut App, action_tx: &ActionTx) {
+    if app.split_prompt.is_some()
+        || app.copy_prompt.is_some()
+        || app.history_range_prompt.is_some()
+        || app.overlap_resolution.is_some()
+    {
+        return;
+    }
+
+    let scrolls_list = match app.current_view {
+        app::View::History => app.history_edit_state.is_none(),
+        app::View::SelectProject
+        | app::View::SelectActivity
+        | app::View::SelectTemplate
+        | app::View::FillWorkdays
+        | app::View::PullRequests
+        | app::View::Board => true,
+        _ => false,
+    };
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left)
+            if app.current_view == app::View::Timer && !app.zen_mode =>
+        {
+            app.click_at(mouse.column, mouse.row);
+        }
+        // The wheel moves the selection like the arrow keys do.
+        MouseEventKind::ScrollUp if scrolls_list => {
+            handle_view_key(
+                KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+                app,
+                action_tx,
+            );
+        }
+        MouseEventKind::ScrollDown if scrolls_list => {
+            handle_view_key(
+                KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+                app,
+                action_tx,
+            );
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,61 @@
+use crate::app::{self, App};
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_board_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    if app.board_move_target.is_some() {
+        handle_move_prompt_key(key, app, action_tx);
+        return;
+    }
+
+    match key.code {
+        KeyCode::Left | KeyCode::Char('h') => app.board_focus_left(),
+        KeyCode::Right | KeyCode::Char('l') => app.board_focus_right(),
+        KeyCode::Down | KeyCode::Char('j') => app.board_focus_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.board_focus_up(),
+        KeyCode::Char('m') | KeyCode::Char('M') => app.open_board_move_prompt(),
+        KeyCode::Char('t') | KeyCode::Char('T') if app.selected_board_item().is_some() => {
+            enqueue_action(action_tx, Action::StartBoardItemTimer);
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            enqueue_action(action_tx, Action::LinkBoardTimeMapping);
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            if let Some(item) = app.selected_board_item() {
+                let url = item.url.clone();
+                crate::login::open_browser(&url);
+                app.set_status(format!("Opened {} in browser", url));
+            }
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            enqueue_action(action_tx, Action::LoadBoardAndOpen);
+        }
+        KeyCode::Char('b') | KeyCode::Char('B') | KeyCode::Esc => {
+            app.navigate_to(app::View::Timer);
+        }
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+        _ => {}
+    }
+}
+
+fn handle_move_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Down | KeyCode::Char('j') => app.board_move_target_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.board_move_target_up(),
+        KeyCode::Enter => {
+            if let Some((work_item_id, target_column_name)) = app.confirm_board_move() {
+                enqueue_action(
+                    action_tx,
+                    Action::MoveBoardItem {
+                        work_item_id,
+                        target_column_name,
+                    },
+                );
+            }
+        }
+        KeyCode::Esc => app.cancel_board_move(),
+        _ => {}
+    }
+}
@@ -0,0 +1,29 @@
+use crate::app::{self, App};
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_calendar_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('H') => {
+            let month = app::shift_month(app.calendar_month, -1);
+            enqueue_action(action_tx, Action::LoadCalendarMonth(month));
+        }
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('L') => {
+            let month = app::shift_month(app.calendar_month, 1);
+            enqueue_action(action_tx, Action::LoadCalendarMonth(month));
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            enqueue_action(
+                action_tx,
+                Action::LoadCalendarMonth(crate::time_utils::local_today()),
+            );
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Esc => {
+            app.navigate_to(app::View::Timer);
+        }
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+        _ => {}
+    }
+}
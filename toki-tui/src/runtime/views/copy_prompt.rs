@@ -0,0 +1,15 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_copy_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Char(c) => app.copy_prompt_input_char(c),
+        KeyCode::Backspace => app.copy_prompt_backspace(),
+        KeyCode::Enter => enqueue_action(action_tx, Action::CopyEntry),
+        KeyCode::Esc => app.close_copy_prompt(),
+        _ => {}
+    }
+}
@@ -53,6 +53,23 @@ pub(super) fn handle_edit_description_key(key: KeyEvent, app: &mut App, action_t
             KeyCode::Enter => app.taskwarrior_confirm(),
             _ => {}
         }
+    } else if app.work_item_overlay.is_some() {
+        match key.code {
+            KeyCode::Esc => app.close_work_item_overlay(),
+            KeyCode::Char('w') | KeyCode::Char('W')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.close_work_item_overlay();
+            }
+            KeyCode::Down => app.work_item_move(true),
+            KeyCode::Up => app.work_item_move(false),
+            KeyCode::Enter => app.work_item_confirm(),
+            KeyCode::Backspace => app.work_item_search_backspace(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.work_item_search_char(c);
+            }
+            _ => {}
+        }
     } else if app.git_mode {
         // Second key of Ctrl+G sequence.
         match key.code {
@@ -86,6 +103,25 @@ pub(super) fn handle_edit_description_key(key: KeyEvent, app: &mut App, action_t
             {
                 app.open_taskwarrior_overlay();
             }
+            KeyCode::Char('w') | KeyCode::Char('W')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                app.set_status("Loading work items...".to_string());
+                enqueue_action(action_tx, Action::OpenWorkItemPicker);
+            }
+            KeyCode::Char('e') | KeyCode::Char('E')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                let note = app.description_input.value.clone();
+                if note.trim().is_empty() {
+                    app.set_status(
+                        "Write a note first, then press Ctrl+E to improve it".to_string(),
+                    );
+                } else {
+                    app.set_status("Improving note...".to_string());
+                    enqueue_action(action_tx, Action::ImproveNote { note });
+                }
+            }
             KeyCode::Char('r') | KeyCode::Char('R')
                 if key.modifiers.contains(KeyModifiers::CONTROL) =>
             {
@@ -0,0 +1,19 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_fill_workdays_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => app.fill_wizard_move(false),
+        KeyCode::Down | KeyCode::Char('j') => app.fill_wizard_move(true),
+        KeyCode::Char(' ') => app.toggle_fill_day(),
+        KeyCode::Enter if app.fill_wizard_next() => {
+            enqueue_action(action_tx, Action::FillWorkdays);
+        }
+        KeyCode::Esc => app.fill_wizard_back(),
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+        _ => {}
+    }
+}
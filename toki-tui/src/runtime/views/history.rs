@@ -119,11 +119,30 @@ pub(super) fn handle_history_key(key: KeyEvent, app: &mut App, action_tx: &Actio
             KeyCode::Char('p') | KeyCode::Char('P') => {
                 app.navigate_to(app::View::SelectProject);
             }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                app.open_split_prompt();
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                app.open_copy_prompt();
+            }
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 app.quit();
             }
             _ => {}
         }
+    } else if app.history_filter.searching {
+        // Typing a search; the list is filtered as it changes.
+        match key.code {
+            KeyCode::Up => app.select_previous(),
+            KeyCode::Down => app.select_next(),
+            KeyCode::Enter => app.finish_history_search(),
+            KeyCode::Esc => app.clear_history_search(),
+            KeyCode::Backspace => app.history_search_backspace(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.history_search_char(c);
+            }
+            _ => {}
+        }
     } else {
         // Not in edit mode.
         match key.code {
@@ -132,10 +151,62 @@ pub(super) fn handle_history_key(key: KeyEvent, app: &mut App, action_tx: &Actio
             KeyCode::Enter => {
                 app.enter_history_edit_mode();
             }
+            KeyCode::Esc if !app.marked_entries.is_empty() => {
+                app.marked_entries.clear();
+            }
+            KeyCode::Esc if app.history_filter.is_active() => {
+                app.clear_history_filters();
+            }
+            KeyCode::Char('/') => app.start_history_search(),
+            KeyCode::Char('f') => {
+                app.cycle_history_period();
+                load_missing_history(app, action_tx);
+            }
+            KeyCode::Char('[') => {
+                app.page_history(true);
+                load_missing_history(app, action_tx);
+            }
+            KeyCode::Char(']') => {
+                app.page_history(false);
+                load_missing_history(app, action_tx);
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => app.open_history_range_prompt(),
+            KeyCode::Char('F') => app.toggle_history_project_filter(),
+            KeyCode::Char('o') | KeyCode::Char('O') => app.open_overlap_resolution(),
             KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Esc => {
                 app.navigate_to(app::View::Timer);
             }
+            KeyCode::Char(' ') => app.toggle_entry_mark(),
+            KeyCode::Char('u') => enqueue_action(action_tx, Action::Undo),
+            KeyCode::Char('U') => enqueue_action(action_tx, Action::Redo),
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                enqueue_action(action_tx, Action::MergeEntries);
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') if !app.marked_entries.is_empty() => {
+                app.start_reassigning_marked();
+            }
             KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                enqueue_action(action_tx, Action::ExportHistory);
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                let entry = app
+                    .focused_history_index
+                    .and_then(|idx| app.history_list_entries.get(idx).copied())
+                    .and_then(|te_idx| app.time_entries.get(te_idx).cloned());
+                if let Some(entry) = entry {
+                    enqueue_action(action_tx, Action::SaveEntryAsTemplate(entry));
+                }
+            }
+            KeyCode::Delete | KeyCode::Backspace if !app.marked_entries.is_empty() => {
+                app.enter_marked_delete_confirm();
+            }
+            KeyCode::Char('x')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && !app.marked_entries.is_empty() =>
+            {
+                app.enter_marked_delete_confirm();
+            }
             KeyCode::Delete | KeyCode::Backspace if app.focused_history_index.is_some() => {
                 if app.focused_history_entry_is_locked() {
                     app.set_locked_delete_status();
@@ -188,3 +259,10 @@ pub(super) fn handle_history_key(key: KeyEvent, app: &mut App, action_tx: &Actio
         }
     }
 }
+
+/// Fetch the part of the listed period that is older than the loaded history.
+pub(super) fn load_missing_history(app: &App, action_tx: &ActionTx) {
+    if let Some((from, to)) = app.missing_history_range() {
+        enqueue_action(action_tx, Action::LoadHistoryRange { from, to });
+    }
+}
@@ -0,0 +1,17 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::ActionTx;
+use super::history::load_missing_history;
+
+pub(super) fn handle_history_range_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Char(c) => app.history_range_prompt_char(c),
+        KeyCode::Backspace => app.history_range_prompt_backspace(),
+        KeyCode::Enter if app.apply_history_range_prompt() => {
+            load_missing_history(app, action_tx);
+        }
+        KeyCode::Esc => app.close_history_range_prompt(),
+        _ => {}
+    }
+}
@@ -0,0 +1,24 @@
+use crate::app::{self, App};
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_idle_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Char(c @ '1'..='3') => {
+            app.select_idle_action_by_number(c.to_digit(10).unwrap_or_default());
+            enqueue_action(action_tx, Action::ResolveIdle);
+        }
+        KeyCode::Esc => {
+            app.selected_idle_action = app::IdleAction::Keep;
+            enqueue_action(action_tx, Action::ResolveIdle);
+        }
+        KeyCode::Up | KeyCode::Char('k') => app.select_previous_idle_action(),
+        KeyCode::Down | KeyCode::Char('j') => app.select_next_idle_action(),
+        KeyCode::Enter => {
+            enqueue_action(action_tx, Action::ResolveIdle);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,13 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_overlap_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Enter => enqueue_action(action_tx, Action::ResolveOverlaps),
+        KeyCode::Esc | KeyCode::Char('q') => app.close_overlap_resolution(),
+        _ => {}
+    }
+}
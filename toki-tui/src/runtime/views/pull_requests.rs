@@ -0,0 +1,48 @@
+use crate::app::{self, App};
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_pull_requests_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Down | KeyCode::Char('j') => app.pull_request_focus_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.pull_request_focus_up(),
+        KeyCode::Enter if app.selected_pull_request().is_some() => {
+            enqueue_action(action_tx, Action::OpenPullRequestDetail);
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => open_selected_in_browser(app),
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            enqueue_action(action_tx, Action::LoadPullRequestsAndOpen);
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') | KeyCode::Esc => {
+            app.navigate_to(app::View::Timer);
+        }
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+        _ => {}
+    }
+}
+
+pub(super) fn handle_pull_request_detail_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Down | KeyCode::Char('j') => app.pull_request_detail_scroll_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.pull_request_detail_scroll_up(),
+        KeyCode::Char('o') | KeyCode::Char('O') => open_selected_in_browser(app),
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            enqueue_action(action_tx, Action::OpenPullRequestDetail);
+        }
+        KeyCode::Esc | KeyCode::Backspace => app.navigate_to(app::View::PullRequests),
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
+        _ => {}
+    }
+}
+
+/// Votes and replies happen in Azure DevOps itself, where they are attributed
+/// to the signed-in user rather than the repository's access token.
+fn open_selected_in_browser(app: &mut App) {
+    if let Some(pr) = app.selected_pull_request() {
+        let url = pr.url.clone();
+        crate::login::open_browser(&url);
+        app.set_status(format!("Opened {} in browser", url));
+    }
+}
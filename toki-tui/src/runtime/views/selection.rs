@@ -10,6 +10,26 @@ pub(super) fn handle_select_project_key(key: KeyEvent, app: &mut App, action_tx:
     let saved_selected_project = app.selected_project.clone();
     let saved_selected_activity = app.selected_activity.clone();
 
+    // Number keys pick a favorite until a search is typed.
+    if let KeyCode::Char(c) = key.code {
+        if !key.modifiers.contains(KeyModifiers::CONTROL)
+            && app.project_search_input.value.is_empty()
+        {
+            if let Some(favorite) = app.favorite_for_key(c).cloned() {
+                enqueue_action(
+                    action_tx,
+                    Action::ApplyFavorite {
+                        favorite,
+                        had_edit_state,
+                        saved_selected_project,
+                        saved_selected_activity,
+                    },
+                );
+                return;
+            }
+        }
+    }
+
     if handle_selection_input_key(
         key,
         app,
@@ -53,6 +73,11 @@ pub(super) fn handle_select_activity_key(key: KeyEvent, app: &mut App, action_tx
     let saved_selected_project = app.selected_project.clone();
     let saved_selected_activity = app.selected_activity.clone();
 
+    if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        enqueue_action(action_tx, Action::ToggleFavorite);
+        return;
+    }
+
     if handle_selection_input_key(
         key,
         app,
@@ -0,0 +1,15 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_split_prompt_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    match key.code {
+        KeyCode::Char(c) if c.is_ascii_digit() => app.split_prompt_input_char(c),
+        KeyCode::Backspace => app.split_prompt_backspace(),
+        KeyCode::Enter => enqueue_action(action_tx, Action::SplitEntry),
+        KeyCode::Esc => app.close_split_prompt(),
+        _ => {}
+    }
+}
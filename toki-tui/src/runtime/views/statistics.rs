@@ -1,8 +1,36 @@
-use crate::app::{self, App};
+use crate::app::{self, App, StatsPeriod};
 use crossterm::event::{KeyCode, KeyEvent};
 
-pub(super) fn handle_statistics_key(key: KeyEvent, app: &mut App) {
+use super::super::action_queue::{Action, ActionTx};
+use super::enqueue_action;
+
+pub(super) fn handle_statistics_key(key: KeyEvent, app: &mut App, action_tx: &ActionTx) {
+    let today = crate::time_utils::local_today();
     match key.code {
+        KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('H') => {
+            show_period(app.statistics_period.shift(-1), app, action_tx);
+        }
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('L') => {
+            let next = app.statistics_period.shift(1);
+            if next.start <= today {
+                show_period(next, app, action_tx);
+            }
+        }
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            show_period(app.statistics_period.toggle_span(today), app, action_tx);
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            app.select_previous_statistics_slice();
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            app.select_next_statistics_slice();
+        }
+        KeyCode::Enter => app.drill_into_statistics_project(),
+        KeyCode::Backspace => {
+            app.leave_statistics_project();
+        }
+        // Esc backs out of a drill-down first.
+        KeyCode::Esc if app.leave_statistics_project() => {}
         KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Esc => {
             app.navigate_to(app::View::Timer);
         }
@@ -10,3 +38,14 @@ pub(super) fn handle_statistics_key(key: KeyEvent, app: &mut App) {
         _ => {}
     }
 }
+
+/// The loaded history covers the current week and month; earlier periods
+/// are fetched.
+fn show_period(period: StatsPeriod, app: &mut App, action_tx: &ActionTx) {
+    let today = crate::time_utils::local_today();
+    if period == StatsPeriod::containing(period.span, today) {
+        app.set_statistics_period(period, None);
+    } else {
+        enqueue_action(action_tx, Action::LoadStatisticsPeriod(period));
+    }
+}
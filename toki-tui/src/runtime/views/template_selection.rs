@@ -21,6 +21,22 @@ pub(super) fn handle_select_template_key(key: KeyEvent, app: &mut App, action_tx
                 app.navigate_to(crate::app::View::Timer);
             }
         }
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(template) = app.highlighted_template().cloned() {
+                enqueue_action(action_tx, Action::LogTemplate(template));
+            }
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match app
+                .highlighted_template()
+                .map(|template| template.server_id)
+            {
+                Some(Some(id)) => enqueue_action(action_tx, Action::DeleteTemplate(id)),
+                Some(None) => app
+                    .set_status("Templates from config.toml can only be removed there".to_string()),
+                None => {}
+            }
+        }
         KeyCode::Esc => app.navigate_to(crate::app::View::Timer),
         KeyCode::Char('q') | KeyCode::Char('Q') => app.quit(),
         _ => {}
@@ -44,6 +44,8 @@ pub(super) fn handle_timer_key(key: KeyEvent, app: &mut App, action_tx: &ActionT
                 app.entry_edit_next_field();
             } else if app.focused_box == app::FocusedBox::Today {
                 app.this_week_focus_down();
+            } else if app.focused_box == app::FocusedBox::Suggestions {
+                app.suggestion_focus_down();
             } else {
                 app.focus_next();
             }
@@ -53,6 +55,8 @@ pub(super) fn handle_timer_key(key: KeyEvent, app: &mut App, action_tx: &ActionT
                 app.entry_edit_prev_field();
             } else if app.focused_box == app::FocusedBox::Today {
                 app.this_week_focus_up();
+            } else if app.focused_box == app::FocusedBox::Suggestions {
+                app.suggestion_focus_up();
             } else {
                 app.focus_previous();
             }
@@ -91,6 +95,13 @@ pub(super) fn handle_timer_key(key: KeyEvent, app: &mut App, action_tx: &ActionT
         {
             app.entry_edit_delete_word_back();
         }
+        KeyCode::Backspace | KeyCode::Delete | KeyCode::Char('d') | KeyCode::Char('D')
+            if app.focused_box == app::FocusedBox::Suggestions =>
+        {
+            if let Some(suggestion) = app.selected_calendar_suggestion().cloned() {
+                enqueue_action(action_tx, Action::DeclineCalendarSuggestion(suggestion));
+            }
+        }
         KeyCode::Backspace => {
             if is_editing_this_week(app) {
                 if !is_note_focused_in_this_week_edit(app) {
@@ -129,11 +140,38 @@ pub(super) fn handle_timer_key(key: KeyEvent, app: &mut App, action_tx: &ActionT
         {
             app.toggle_timer_size();
         }
+        // S in edit mode: split the entry
+        KeyCode::Char('s') | KeyCode::Char('S')
+            if !key.modifiers.contains(KeyModifiers::CONTROL) && is_editing_this_week(app) =>
+        {
+            app.open_split_prompt();
+        }
+        // C in edit mode: copy the entry to another day
+        KeyCode::Char('c') | KeyCode::Char('C')
+            if !key.modifiers.contains(KeyModifiers::CONTROL) && is_editing_this_week(app) =>
+        {
+            app.open_copy_prompt();
+        }
         // S: Open Stats view (unmodified only - Ctrl+S is save)
         KeyCode::Char('s') | KeyCode::Char('S')
             if !key.modifiers.contains(KeyModifiers::CONTROL) =>
         {
-            app.navigate_to(app::View::Statistics);
+            enqueue_action(action_tx, Action::LoadStatisticsAndOpen);
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') if !is_editing_this_week(app) => {
+            enqueue_action(
+                action_tx,
+                Action::LoadCalendarMonth(crate::time_utils::local_today()),
+            );
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::LoadMissingWorkdays);
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::LoadPullRequestsAndOpen);
+        }
+        KeyCode::Char('b') | KeyCode::Char('B') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::LoadBoardAndOpen);
         }
         KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             handle_ctrl_x_key(app, action_tx);
@@ -145,7 +183,33 @@ pub(super) fn handle_timer_key(key: KeyEvent, app: &mut App, action_tx: &ActionT
                 app.enter_delete_confirm(app::DeleteOrigin::Timer);
             }
         }
+        KeyCode::Char('u') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::Undo);
+        }
+        KeyCode::Char('U') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::Redo);
+        }
         KeyCode::Char('z') | KeyCode::Char('Z') => app.toggle_zen_mode(),
+        KeyCode::Char('m') | KeyCode::Char('M') if !is_editing_this_week(app) => {
+            app.toggle_pomodoro();
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') if !is_editing_this_week(app) => {
+            if app.timer_running_elsewhere().is_some() {
+                enqueue_action(action_tx, Action::TakeOverTimer);
+            } else {
+                app.set_status("Timer is not running on another device".to_string());
+            }
+        }
+        KeyCode::Char('i') if !is_editing_this_week(app) => {
+            if app.timer_state == app::TimerState::Running {
+                enqueue_action(action_tx, Action::PauseTimer);
+            } else {
+                app.set_status("No running timer to pause".to_string());
+            }
+        }
+        KeyCode::Char('I') if !is_editing_this_week(app) => {
+            enqueue_action(action_tx, Action::ResumePausedTimer);
+        }
         KeyCode::Char('r') | KeyCode::Char('R')
             if !is_editing_this_week(app)
                 && key.modifiers.contains(KeyModifiers::CONTROL)
@@ -231,6 +295,11 @@ fn handle_enter_key(app: &mut App, action_tx: &ActionTx) {
         app::FocusedBox::Timer => {
             enqueue_action(action_tx, Action::StartTimer);
         }
+        app::FocusedBox::Suggestions => {
+            if let Some(suggestion) = app.selected_calendar_suggestion().cloned() {
+                enqueue_action(action_tx, Action::AcceptCalendarSuggestion(suggestion));
+            }
+        }
         app::FocusedBox::Today => {
             if app.focused_this_week_index.is_none() && !app.this_week_history().is_empty() {
                 app.focused_this_week_index = Some(0);
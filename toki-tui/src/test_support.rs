@@ -1,10 +1,17 @@
 use crate::app::App;
 use crate::config::TokiConfig;
-use crate::types::{Activity, Project, TimeEntry, TimeEntryStatus};
-use time::OffsetDateTime;
+use crate::offline_queue::QueuedEntry;
+use crate::time_utils::to_local_time;
+use crate::types::{Activity, Project, PullRequest, TimeEntry};
+use time::{Date, OffsetDateTime};
+use toki_types::TimeEntryStatus;
 
 pub fn test_config() -> TokiConfig {
-    TokiConfig::default()
+    TokiConfig {
+        // Keep renders independent of the weekday tests run on.
+        attest_reminder_day: "off".to_string(),
+        ..TokiConfig::default()
+    }
 }
 
 pub fn test_app() -> App {
@@ -16,6 +23,7 @@ pub fn project(id: &str, name: &str) -> Project {
     Project {
         id: id.to_string(),
         name: name.to_string(),
+        budget: None,
     }
 }
 
@@ -55,9 +63,111 @@ pub fn time_entry(
         end_time,
         week_number: 1,
         status: TimeEntryStatus::Open,
+        work_item_id: None,
     }
 }
 
+/// An entry without start and end times. Project and activity names are
+/// the same as their ids.
+#[allow(dead_code)]
+pub fn day_entry(
+    registration_id: &str,
+    project: &str,
+    activity: &str,
+    date: Date,
+    hours: f64,
+    note: Option<&str>,
+) -> TimeEntry {
+    time_entry(
+        registration_id,
+        project,
+        project,
+        activity,
+        activity,
+        &date.to_string(),
+        hours,
+        note,
+        None,
+        None,
+    )
+}
+
+/// An entry on project `Toki` from `start` to `end`, dated by the local day
+/// it starts on. The activity name is the same as its id.
+#[allow(dead_code)]
+pub fn timed_entry(
+    registration_id: &str,
+    activity: &str,
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    note: Option<&str>,
+) -> TimeEntry {
+    time_entry(
+        registration_id,
+        "Toki",
+        "Toki",
+        activity,
+        activity,
+        &to_local_time(start).date().to_string(),
+        (end - start).as_seconds_f64() / 3600.0,
+        note,
+        Some(start),
+        Some(end),
+    )
+}
+
+/// A queued entry on Toki / Development.
+#[allow(dead_code)]
+pub fn queued_entry(start: OffsetDateTime, end: OffsetDateTime, note: &str) -> QueuedEntry {
+    QueuedEntry {
+        project_id: "p1".to_string(),
+        project_name: "Toki".to_string(),
+        activity_id: "a1".to_string(),
+        activity_name: "Development".to_string(),
+        start,
+        end,
+        note: note.to_string(),
+    }
+}
+
+#[allow(dead_code)]
+pub fn pull_request(repo_name: &str, id: i32) -> PullRequest {
+    PullRequest {
+        organization: "org".to_string(),
+        project: "project".to_string(),
+        repo_name: repo_name.to_string(),
+        url: format!("https://dev.azure.com/org/project/_git/{repo_name}/pullrequest/{id}"),
+        id,
+        title: format!("PR {id}"),
+        description: None,
+        created_by: toki_client::pull_request::Identity {
+            display_name: "Author".to_string(),
+            unique_name: "author@example.com".to_string(),
+        },
+        created_at: OffsetDateTime::UNIX_EPOCH,
+        source_branch: "refs/heads/feature".to_string(),
+        target_branch: "refs/heads/main".to_string(),
+        is_draft: false,
+        merge_status: None,
+        threads: Vec::new(),
+        reviewers: Vec::new(),
+        blocked_by: Vec::new(),
+        approved_by: Vec::new(),
+        waiting_for_user_review: false,
+        review_required: false,
+    }
+}
+
+#[test]
+fn day_entries_are_dated_like_the_api_dates_them() {
+    let date = time::macros::date!(2026 - 03 - 02);
+
+    assert_eq!(
+        day_entry("1", "p1", "a1", date, 1.0, None).date,
+        "2026-03-02"
+    );
+}
+
 #[test]
 fn app_defaults_to_timer_view() {
     let app = test_app();
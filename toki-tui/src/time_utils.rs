@@ -1,9 +1,88 @@
-use time::UtcOffset;
+use std::sync::OnceLock;
 
-pub fn to_local_time(dt: time::OffsetDateTime) -> time::OffsetDateTime {
-    if let Ok(local_offset) = UtcOffset::current_local_offset() {
-        dt.to_offset(local_offset)
-    } else {
-        dt
+use chrono::{Offset, TimeZone};
+use chrono_tz::Tz;
+use time::{Date, OffsetDateTime, Time, UtcOffset};
+
+/// The user's time zone preference from toki-api. Until it is set (or in dev
+/// mode) the system's current offset is used instead.
+static TIME_ZONE: OnceLock<Tz> = OnceLock::new();
+
+/// Use the IANA zone `name` for all local dates and times. Returns false if
+/// the name is unknown or a zone was already set.
+pub fn set_time_zone(name: &str) -> bool {
+    name.parse::<Tz>()
+        .ok()
+        .is_some_and(|tz| TIME_ZONE.set(tz).is_ok())
+}
+
+/// The user's UTC offset at `dt`.
+pub fn offset_at(dt: OffsetDateTime) -> UtcOffset {
+    offset_in(TIME_ZONE.get(), dt)
+}
+
+fn offset_in(time_zone: Option<&Tz>, dt: OffsetDateTime) -> UtcOffset {
+    match time_zone {
+        Some(tz) => {
+            let utc = chrono::DateTime::from_timestamp(dt.unix_timestamp(), 0).unwrap_or_default();
+            let seconds = tz
+                .offset_from_utc_datetime(&utc.naive_utc())
+                .fix()
+                .local_minus_utc();
+            UtcOffset::from_whole_seconds(seconds).unwrap_or(UtcOffset::UTC)
+        }
+        None => UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC),
+    }
+}
+
+pub fn to_local_time(dt: OffsetDateTime) -> OffsetDateTime {
+    dt.to_offset(offset_at(dt))
+}
+
+/// Today's date in the user's time zone.
+pub fn local_today() -> Date {
+    to_local_time(OffsetDateTime::now_utc()).date()
+}
+
+/// `time` on `date` in the user's time zone.
+pub fn local_date_time(date: Date, time: Time) -> OffsetDateTime {
+    local_date_time_in(TIME_ZONE.get(), date, time)
+}
+
+fn local_date_time_in(time_zone: Option<&Tz>, date: Date, time: Time) -> OffsetDateTime {
+    let naive = date.with_time(time);
+    // Look up the offset twice: the first guess can be on the wrong side of
+    // a DST switch when the user is far from UTC.
+    let guess = offset_in(time_zone, naive.assume_utc());
+    naive.assume_offset(offset_in(time_zone, naive.assume_offset(guess)))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    #[test]
+    fn local_date_time_uses_the_offset_of_that_day() {
+        let stockholm = Some(&chrono_tz::Europe::Stockholm);
+        let summer = Date::from_calendar_date(2026, Month::October, 16).unwrap();
+        let winter = Date::from_calendar_date(2026, Month::November, 16).unwrap();
+        let nine = Time::from_hms(9, 0, 0).unwrap();
+
+        assert_eq!(
+            local_date_time_in(stockholm, summer, nine),
+            summer.with_hms(7, 0, 0).unwrap().assume_utc()
+        );
+        assert_eq!(
+            local_date_time_in(stockholm, winter, nine),
+            winter.with_hms(8, 0, 0).unwrap().assume_utc()
+        );
+        // 23:30 UTC is already the next day in Stockholm.
+        let late = summer.with_hms(23, 30, 0).unwrap().assume_utc();
+        assert_eq!(
+            late.to_offset(offset_in(stockholm, late)).date(),
+            summer.next_day().unwrap()
+        );
     }
 }
@@ -1,96 +1,61 @@
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+
+/// API wire types, re-exported under the names the TUI uses internally.
+pub use toki_client::{
+    AssignedWorkItem as WorkItem, Board, BoardColumn, BoardWorkItem,
+    ListPullRequest as PullRequest, Me, PullRequestChange, WipLimitWarning,
+};
+pub use toki_types::{
+    AutoStoppedTimerResponse as AutoStoppedTimer, BannerSeverity,
+    CalendarSuggestionResponse as CalendarSuggestion, FavoriteResponse as Favorite,
+    FlexSnapshotResponse as FlexSnapshot, LiveEvent, MissingWorkdayResponse as MissingWorkday,
+    PausedTimerResponse as PausedTimer, ProjectBudgetStatusResponse as BudgetStatus,
+    SprintCapacityResponse as SprintCapacity, StatusBanner, StatusResponse,
+    TimeEntryResponse as TimeEntry, TimerResponse as ActiveTimerState,
+    WeeklyStatsResponse as TimeInfo, WorkItemTimeMappingResponse as WorkItemTimeMapping,
+};
 
 /// A project available for time tracking, derived from timer history.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Project {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub budget: Option<ProjectBudget>,
 }
 
-/// An activity belonging to a project.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-pub struct Activity {
-    pub id: String,
-    pub name: String,
-    pub project_id: String,
-}
+/// Share of a project budget used before the TUI warns about it.
+pub const BUDGET_WARNING_RATIO: f64 = 0.9;
 
-/// Lock status for a time entry.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum TimeEntryStatus {
-    #[default]
-    Open,
-    Approved,
-    Certified,
+/// Hours budgeted for a project and how many of them are used.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct ProjectBudget {
+    pub budget_hours: f64,
+    pub used_hours: f64,
 }
 
-impl TimeEntryStatus {
-    pub fn is_locked(self) -> bool {
-        self != TimeEntryStatus::Open
+impl ProjectBudget {
+    pub fn remaining_hours(&self) -> f64 {
+        self.budget_hours - self.used_hours
     }
-}
 
-/// A completed time entry from GET /time-tracking/time-entries.
-/// start_time / end_time are optional and present only when local timer history exists.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimeEntry {
-    pub registration_id: String,
-    pub project_id: String,
-    pub project_name: String,
-    pub activity_id: String,
-    pub activity_name: String,
-    /// Date in YYYY-MM-DD format, e.g. "2026-02-24"
-    pub date: String,
-    pub hours: f64,
-    pub note: Option<String>,
-    #[serde(with = "time::serde::rfc3339::option")]
-    pub start_time: Option<OffsetDateTime>,
-    #[serde(with = "time::serde::rfc3339::option")]
-    pub end_time: Option<OffsetDateTime>,
-    #[allow(dead_code)]
-    pub week_number: u8,
-    #[serde(default)]
-    pub status: TimeEntryStatus,
-}
-
-/// The current user, as returned by GET /me.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Me {
-    pub id: i32,
-    pub email: String,
-    pub full_name: String,
-}
-
-/// Active timer as returned by GET /time-tracking/timer.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ActiveTimerState {
-    #[serde(with = "time::serde::rfc3339")]
-    pub start_time: OffsetDateTime,
-    pub project_id: Option<String>,
-    pub project_name: Option<String>,
-    pub activity_id: Option<String>,
-    pub activity_name: Option<String>,
-    pub note: String,
-    pub hours: i64,
-    pub minutes: i64,
-    pub seconds: i64,
-}
+    /// Used share of the budget, where 1.0 means fully consumed.
+    pub fn consumption(&self) -> f64 {
+        if self.budget_hours <= 0.0 {
+            return 1.0;
+        }
+        self.used_hours / self.budget_hours
+    }
 
-/// Wrapper returned by GET /time-tracking/timer.
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GetTimerResponse {
-    pub timer: Option<ActiveTimerState>,
+    pub fn is_nearly_consumed(&self) -> bool {
+        self.consumption() >= BUDGET_WARNING_RATIO
+    }
 }
 
-/// Time info returned by GET /time-tracking/time-info.
-#[derive(Debug, Clone, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TimeInfo {
-    pub scheduled_hours: f64,
+/// An activity belonging to a project.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Activity {
+    pub id: String,
+    pub name: String,
+    pub project_id: String,
 }
@@ -0,0 +1,174 @@
+use super::pull_requests_view::{render_controls, truncate};
+use super::utils::centered_rect;
+use super::*;
+use crate::app::BoardLane;
+use crate::types::BoardWorkItem;
+
+pub fn render_board_view(frame: &mut Frame, app: &App, body: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(body);
+
+    let title = match &app.board_project {
+        Some(board) => match &board.team {
+            Some(team) => format!(" Board: {} / {} ", board.project, team),
+            None => format!(" Board: {} ", board.project),
+        },
+        None => " Board ".to_string(),
+    };
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(title, Style::default().fg(theme().text)));
+    if let Some(mapping) = &app.board_time_mapping {
+        block = block.title(
+            Line::from(Span::styled(
+                format!(
+                    " Timers: {}: {} ",
+                    mapping.project_name, mapping.activity_name
+                ),
+                Style::default().fg(theme().muted),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
+    if let Some(status) = &app.status_message {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", status),
+            Style::default().fg(theme().muted),
+        ));
+    }
+    let inner = block.inner(chunks[0]);
+    frame.render_widget(block, chunks[0]);
+
+    if app.board_lanes.is_empty() {
+        let empty_msg = Paragraph::new("No columns on the board").alignment(Alignment::Center);
+        frame.render_widget(empty_msg, inner);
+    } else {
+        let lane_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                app.board_lanes
+                    .iter()
+                    .map(|_| Constraint::Ratio(1, app.board_lanes.len() as u32)),
+            )
+            .split(inner);
+        for (i, (lane, area)) in app.board_lanes.iter().zip(lane_areas.iter()).enumerate() {
+            render_lane(frame, app, lane, i == app.board_column_index, *area);
+        }
+    }
+
+    render_controls(
+        frame,
+        chunks[1],
+        &[
+            ("←↓↑→", "Navigate"),
+            ("M", "Move"),
+            ("T", "Timer"),
+            ("P", "Link project"),
+            ("O", "Open"),
+            ("R", "Refresh"),
+            ("Esc", "Back"),
+            ("Q", "Quit"),
+        ],
+    );
+
+    if app.board_move_target.is_some() {
+        render_move_prompt(frame, app);
+    }
+}
+
+fn render_lane(frame: &mut Frame, app: &App, lane: &BoardLane, focused: bool, area: Rect) {
+    let border_color = if focused {
+        theme().accent
+    } else {
+        theme().muted
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .title(Span::styled(
+            format!(" {} ({}) ", lane.column.name, lane.items.len()),
+            Style::default().fg(border_color),
+        ));
+
+    let width = block.inner(area).width as usize;
+    let items: Vec<ListItem> = lane
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| board_item_row(item, focused && i == app.board_item_index, width))
+        .collect();
+    let selected = focused.then_some(app.board_item_index);
+    let mut state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(List::new(items).block(block), area, &mut state);
+}
+
+/// `#4711 Title` with the assignee underneath.
+fn board_item_row(item: &BoardWorkItem, selected: bool, width: usize) -> ListItem<'static> {
+    let title_style = if selected {
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme().text)
+    };
+    let id = format!("#{} ", item.id);
+    let title = truncate(item.title.trim(), width.saturating_sub(id.chars().count()));
+    let assignee = item
+        .assigned_to
+        .as_ref()
+        .map_or("Unassigned", |person| person.display_name.as_str());
+
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::styled(id, Style::default().fg(theme().info)),
+            Span::styled(title, title_style),
+        ]),
+        Line::from(Span::styled(
+            truncate(assignee, width),
+            Style::default().fg(theme().muted),
+        )),
+    ])
+}
+
+/// Dialog over the board listing the columns the selected item can move to.
+fn render_move_prompt(frame: &mut Frame, app: &App) {
+    let (Some(target), Some(item)) = (app.board_move_target, app.selected_board_item()) else {
+        return;
+    };
+
+    let area = centered_rect(50, app.board_lanes.len() as u16 + 4, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![Line::from("")];
+    lines.extend(app.board_lanes.iter().enumerate().map(|(i, lane)| {
+        let marker = if i == target { "› " } else { "  " };
+        let style = if i == target {
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().text)
+        };
+        Line::from(Span::styled(format!("{marker}{}", lane.column.name), style))
+    }));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Move #{} to ", item.id))
+            .title_bottom(
+                Line::from(Span::styled(
+                    " Enter: move  Esc: cancel ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(paragraph, area);
+}
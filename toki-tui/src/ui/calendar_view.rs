@@ -0,0 +1,145 @@
+use super::utils::format_hours_hm;
+use super::*;
+use crate::app::{CalendarDay, DayFill};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const CELL_WIDTH: usize = 11;
+
+pub fn render_calendar_view(frame: &mut Frame, app: &App, body: Rect) {
+    let outer = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([Constraint::Min(10), Constraint::Length(3)])
+        .split(body);
+
+    let month = app.calendar_month;
+    let days = app.calendar_days();
+    let total: f64 = days.iter().map(|day| day.hours).sum();
+
+    let calendar_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(
+            format!(" Calendar · {} {} ", month.month(), month.year()),
+            Style::default().fg(theme().text),
+        ))
+        .title_bottom(
+            Line::from(Span::styled(
+                format!(" Logged {} ", format_hours_hm(total)),
+                Style::default().fg(theme().muted),
+            ))
+            .alignment(Alignment::Right),
+        );
+    let inner = calendar_block.inner(outer[0]);
+    frame.render_widget(calendar_block, outer[0]);
+
+    let today = crate::time_utils::local_today();
+    let full_day_hours = app.full_day_hours();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(
+            WEEKDAYS
+                .iter()
+                .map(|day| {
+                    Span::styled(
+                        format!("{:^width$} ", day, width = CELL_WIDTH - 1),
+                        Style::default().fg(theme().muted),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(""),
+    ];
+
+    // Pad the first week so the 1st lands on its weekday.
+    let leading = month.weekday().number_days_from_monday() as usize;
+    let mut cells: Vec<Option<&CalendarDay>> = vec![None; leading];
+    cells.extend(days.iter().map(Some));
+    // Leave a blank line between weeks when there's room for it and the legend.
+    let weeks = cells.len().div_ceil(7);
+    let spaced = inner.height as usize > lines.len() + weeks * 3;
+    for week in cells.chunks(7) {
+        let mut date_row = Vec::with_capacity(7);
+        let mut hours_row = Vec::with_capacity(7);
+        for column in 0..7 {
+            let (date_text, hours_text, style) = match week.get(column).copied().flatten() {
+                Some(day) => {
+                    let mut style = fill_style(day.fill(today, full_day_hours));
+                    if day.date == today {
+                        style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                    }
+                    let hours = if day.hours > 0.0 {
+                        format_hours_hm(day.hours)
+                    } else {
+                        String::new()
+                    };
+                    (day.date.day().to_string(), hours, style)
+                }
+                None => (String::new(), String::new(), Style::default()),
+            };
+            date_row.extend(cell(date_text, style));
+            hours_row.extend(cell(hours_text, style));
+        }
+        lines.push(Line::from(date_row));
+        lines.push(Line::from(hours_row));
+        if spaced {
+            lines.push(Line::from(""));
+        }
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("■ ", Style::default().fg(theme().success)),
+        Span::styled("Full day  ", Style::default().fg(theme().muted)),
+        Span::styled("■ ", Style::default().fg(theme().accent)),
+        Span::styled("Partial  ", Style::default().fg(theme().muted)),
+        Span::styled("■ ", Style::default().fg(theme().error)),
+        Span::styled("Nothing logged", Style::default().fg(theme().muted)),
+    ]));
+
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), inner);
+
+    let controls = vec![
+        Span::styled("← / H", Style::default().fg(theme().accent)),
+        Span::raw(": Previous month  "),
+        Span::styled("→ / L", Style::default().fg(theme().accent)),
+        Span::raw(": Next month  "),
+        Span::styled("T", Style::default().fg(theme().accent)),
+        Span::raw(": This month  "),
+        Span::styled("C / Esc", Style::default().fg(theme().accent)),
+        Span::raw(": Back to timer  "),
+        Span::styled("Q", Style::default().fg(theme().accent)),
+        Span::raw(": Quit"),
+    ];
+    let controls = Paragraph::new(Line::from(controls))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme().muted))
+                .title(Span::styled(
+                    " Controls ",
+                    Style::default().fg(theme().muted),
+                ))
+                .padding(Padding::horizontal(1)),
+        );
+    frame.render_widget(controls, outer[1]);
+}
+
+/// A fixed-width calendar cell followed by a one column gap, so colored
+/// days don't run into each other.
+fn cell(text: String, style: Style) -> [Span<'static>; 2] {
+    [
+        Span::styled(format!("{:^width$}", text, width = CELL_WIDTH - 1), style),
+        Span::raw(" "),
+    ]
+}
+
+fn fill_style(fill: DayFill) -> Style {
+    match fill {
+        DayFill::Idle => Style::default().fg(theme().muted),
+        DayFill::Missing => Style::default().fg(theme().text).bg(theme().error),
+        DayFill::Partial => Style::default().fg(theme().inverse).bg(theme().warning),
+        DayFill::Full => Style::default().fg(theme().inverse).bg(theme().success),
+    }
+}
@@ -4,12 +4,21 @@ use crate::app::DeleteOrigin;
 
 pub fn render_delete_confirm_dialog(frame: &mut Frame, app: &mut App, body: Rect) {
     // Extract owned values before borrowing `app` mutably for background render
-    let (origin, label, detail) = if let Some(ctx) = &app.delete_context {
+    let (origin, label, detail, title) = if let Some(ctx) = &app.delete_context {
         let h = format!("{:.2}h", ctx.display_hours);
         let detail = format!("{}  ·  {}", ctx.display_date, h);
-        (Some(ctx.origin), ctx.display_label.clone(), detail)
+        let title = match ctx.registration_ids.len() {
+            1 => " Delete Entry? ".to_string(),
+            n => format!(" Delete {} Entries? ", n),
+        };
+        (Some(ctx.origin), ctx.display_label.clone(), detail, title)
     } else {
-        (None, String::new(), String::new())
+        (
+            None,
+            String::new(),
+            String::new(),
+            " Delete Entry? ".to_string(),
+        )
     };
 
     // Render the originating view in the background
@@ -25,13 +34,13 @@ pub fn render_delete_confirm_dialog(frame: &mut Frame, app: &mut App, body: Rect
 
     let text = vec![
         Line::from(""),
-        Line::from(Span::styled(label, Style::default().fg(Color::White))),
-        Line::from(Span::styled(detail, Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled(label, Style::default().fg(theme().text))),
+        Line::from(Span::styled(detail, Style::default().fg(theme().muted))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("[y] Yes", Style::default().fg(Color::Red)),
+            Span::styled("[y] Yes", Style::default().fg(theme().error)),
             Span::raw("    "),
-            Span::styled("[n] No", Style::default().fg(Color::White)),
+            Span::styled("[n] No", Style::default().fg(theme().text)),
         ]),
     ];
 
@@ -39,7 +48,7 @@ pub fn render_delete_confirm_dialog(frame: &mut Frame, app: &mut App, body: Rect
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Delete Entry? ")
+                .title(title)
                 .padding(Padding::horizontal(1)),
         )
         .alignment(Alignment::Center);
@@ -27,11 +27,11 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
         let (before, after) = cwd_input.split_at_cursor();
         let input_text = format!("{}{}{}", before, after, completions_hint);
         let input = Paragraph::new(input_text)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(theme().accent))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::DarkGray))
+                    .border_style(Style::default().fg(theme().muted))
                     .title(" Change Directory ")
                     .padding(Padding::horizontal(1)),
             );
@@ -50,12 +50,16 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
         let before: String = stripped.chars().take(cursor).collect();
         let after: String = stripped.chars().skip(cursor).collect();
         let input_text = format!("{}{}", before, after);
+        let title = match &app.description_work_item_id {
+            Some(id) => format!(" Note · linked to #{id} "),
+            None => " Note ".to_string(),
+        };
         let input = Paragraph::new(input_text)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme().text))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Note ")
+                    .title(title)
                     .padding(Padding::horizontal(1)),
             );
         frame.render_widget(input, chunks[0]);
@@ -67,12 +71,8 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
 
     // Info panel
     let has_git = app.git_context.branch.is_some();
-    let git_color = if has_git {
-        Color::White
-    } else {
-        Color::DarkGray
-    };
-    let muted = Color::DarkGray;
+    let git_color = if has_git { theme().text } else { theme().muted };
+    let muted = theme().muted;
 
     let cwd_str = app.git_context.cwd.to_string_lossy().to_string();
     let branch_str = app.git_context.branch.as_deref().unwrap_or("(no git repo)");
@@ -90,7 +90,7 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
                 };
                 Line::from(vec![
                     Span::styled("Log file:          ", Style::default().fg(muted)),
-                    Span::styled(display, Style::default().fg(Color::Cyan)),
+                    Span::styled(display, Style::default().fg(theme().info)),
                 ])
             }
             Err(_) => Line::from(vec![Span::styled(
@@ -108,7 +108,7 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
     let git_lines = vec![
         Line::from(vec![
             Span::styled("Current directory: ", Style::default().fg(muted)),
-            Span::styled(cwd_str, Style::default().fg(Color::Cyan)),
+            Span::styled(cwd_str, Style::default().fg(theme().info)),
         ]),
         Line::from(vec![
             Span::styled("Current branch:    ", Style::default().fg(muted)),
@@ -124,8 +124,8 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
     let git_panel = Paragraph::new(git_lines).block(
         Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::DarkGray))
-            .title(Span::styled(" Info ", Style::default().fg(Color::DarkGray)))
+            .border_style(Style::default().fg(theme().muted))
+            .title(Span::styled(" Info ", Style::default().fg(theme().muted)))
             .padding(Padding::horizontal(1)),
     );
     frame.render_widget(git_panel, chunks[1]);
@@ -139,13 +139,13 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
             .to_string();
 
         let log_paragraph = Paragraph::new(log_content)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(Style::default().fg(theme().muted))
             .wrap(ratatui::widgets::Wrap { trim: false })
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::DarkGray))
-                    .title(Span::styled(" Log ", Style::default().fg(Color::DarkGray)))
+                    .border_style(Style::default().fg(theme().muted))
+                    .title(Span::styled(" Log ", Style::default().fg(theme().muted)))
                     .padding(Padding::horizontal(1)),
             );
         frame.render_widget(log_paragraph, chunks[2]);
@@ -154,20 +154,20 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
     // Controls (context-sensitive)
     let controls_text: Vec<Span> = if app.cwd_input.is_some() {
         vec![
-            Span::styled("Type", Style::default().fg(Color::Yellow)),
+            Span::styled("Type", Style::default().fg(theme().accent)),
             Span::raw(": Path  "),
-            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::styled("Tab", Style::default().fg(theme().accent)),
             Span::raw(": Complete  "),
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled("Enter", Style::default().fg(theme().accent)),
             Span::raw(": Confirm  "),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled("Esc", Style::default().fg(theme().accent)),
             Span::raw(": Cancel"),
         ]
     } else if app.git_mode {
         let git_key_style = if has_git {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme().accent)
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(theme().muted)
         };
         vec![
             Span::styled("B", git_key_style),
@@ -176,45 +176,45 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
             Span::raw(": Parse & paste branch  "),
             Span::styled("C", git_key_style),
             Span::raw(": Copy/paste last commit  "),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled("Esc", Style::default().fg(theme().accent)),
             Span::raw(": Cancel"),
         ]
     } else {
         let git_key_style = if has_git {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme().accent)
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(theme().muted)
         };
         let mut spans = vec![
-            Span::styled("Type", Style::default().fg(Color::Yellow)),
+            Span::styled("Type", Style::default().fg(theme().accent)),
             Span::raw(": Edit  "),
-            Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+            Span::styled("Ctrl+X", Style::default().fg(theme().accent)),
             Span::raw(": Clear  "),
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled("Enter", Style::default().fg(theme().accent)),
             Span::raw(": Confirm  "),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled("Esc", Style::default().fg(theme().accent)),
             Span::raw(": Cancel  "),
-            Span::styled("Ctrl+L", Style::default().fg(Color::Yellow)),
+            Span::styled("Ctrl+L", Style::default().fg(theme().accent)),
             Span::raw(": Add/edit log  "),
         ];
         if has_log {
-            spans.push(Span::styled("Ctrl+R", Style::default().fg(Color::Yellow)));
+            spans.push(Span::styled("Ctrl+R", Style::default().fg(theme().accent)));
             spans.push(Span::raw(": Remove log  "));
         }
         spans.extend([
-            Span::styled("Ctrl+D", Style::default().fg(Color::Yellow)),
+            Span::styled("Ctrl+D", Style::default().fg(theme().accent)),
             Span::raw(": Change directory  "),
             Span::styled("Ctrl+G", git_key_style),
             Span::styled(
                 ": Git  ",
-                Style::default().fg(if has_git {
-                    Color::Reset
-                } else {
-                    Color::DarkGray
-                }),
+                Style::default().fg(if has_git { Color::Reset } else { theme().muted }),
             ),
-            Span::styled("Ctrl+T", Style::default().fg(Color::Yellow)),
-            Span::raw(": Taskwarrior"),
+            Span::styled("Ctrl+T", Style::default().fg(theme().accent)),
+            Span::raw(": Taskwarrior  "),
+            Span::styled("Ctrl+W", Style::default().fg(theme().accent)),
+            Span::raw(": Work item  "),
+            Span::styled("Ctrl+E", Style::default().fg(theme().accent)),
+            Span::raw(": Improve"),
         ]);
         spans
     };
@@ -224,10 +224,10 @@ pub fn render_description_editor(frame: &mut Frame, app: &App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
@@ -252,14 +252,14 @@ pub fn render_taskwarrior_overlay(frame: &mut Frame, app: &App, body: Rect) {
 
     if let Some(err) = &overlay.error {
         let paragraph = Paragraph::new(err.as_str())
-            .style(Style::default().fg(Color::Red))
+            .style(Style::default().fg(theme().error))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Yellow))
+                    .border_style(Style::default().fg(theme().accent))
                     .title(Span::styled(
                         " Taskwarrior — error ",
-                        Style::default().fg(Color::Yellow),
+                        Style::default().fg(theme().accent),
                     ))
                     .padding(Padding::horizontal(1)),
             );
@@ -272,7 +272,7 @@ pub fn render_taskwarrior_overlay(frame: &mut Frame, app: &App, body: Rect) {
         .iter()
         .map(|t| {
             ListItem::new(format!("[{}] {}", t.id, t.description))
-                .style(Style::default().fg(Color::White))
+                .style(Style::default().fg(theme().text))
         })
         .collect();
 
@@ -283,19 +283,113 @@ pub fn render_taskwarrior_overlay(frame: &mut Frame, app: &App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(theme().accent))
                 .title(Span::styled(
                     " Taskwarrior Tasks ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme().accent),
                 ))
                 .padding(Padding::horizontal(1)),
         )
         .highlight_style(
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::White)
+                .fg(theme().inverse)
+                .bg(theme().text)
                 .add_modifier(Modifier::BOLD),
         );
 
     frame.render_stateful_widget(list, area, &mut list_state);
 }
+
+pub fn render_work_item_overlay(frame: &mut Frame, app: &App, body: Rect) {
+    // Render description editor in the background
+    render_description_editor(frame, app, body);
+
+    let overlay = match &app.work_item_overlay {
+        Some(o) => o,
+        None => return,
+    };
+
+    let width = (frame.area().width as f32 * 0.70) as u16;
+    let height = 20_u16;
+    let area = centered_rect(width, height, frame.area());
+
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().accent))
+        .title(Span::styled(
+            " Assigned Work Items ",
+            Style::default().fg(theme().accent),
+        ))
+        .padding(Padding::horizontal(1));
+
+    if let Some(err) = &overlay.error {
+        let paragraph = Paragraph::new(err.as_str())
+            .style(Style::default().fg(theme().error))
+            .block(block.title(Span::styled(
+                " Work items — error ",
+                Style::default().fg(theme().accent),
+            )));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+
+    let search = Line::from(vec![
+        Span::styled("Search: ", Style::default().fg(theme().muted)),
+        Span::raw(overlay.search.value.as_str()),
+        Span::styled("█", Style::default().fg(theme().text)),
+    ]);
+    frame.render_widget(Paragraph::new(search), chunks[0]);
+
+    if overlay.filtered.is_empty() {
+        let message = if overlay.items.is_empty() {
+            "No open work items are assigned to you"
+        } else {
+            "No matching work items"
+        };
+        frame.render_widget(
+            Paragraph::new(message).style(Style::default().fg(theme().muted)),
+            chunks[1],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = overlay
+        .filtered
+        .iter()
+        .filter_map(|&i| overlay.items.get(i))
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("#{} ", item.id),
+                    Style::default().fg(theme().accent),
+                ),
+                Span::styled(item.title.clone(), Style::default().fg(theme().text)),
+                Span::styled(
+                    format!("  [{}] {}", item.state_name, item.project),
+                    Style::default().fg(theme().muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut list_state = ListState::default();
+    list_state.select(overlay.selected);
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(theme().inverse)
+            .bg(theme().text)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
+}
@@ -0,0 +1,74 @@
+use super::utils::centered_rect;
+use super::*;
+use crate::app::FillStep;
+
+/// Longest list shown before the dialog stops growing.
+const MAX_ROWS: u16 = 14;
+
+pub fn render_fill_workdays(frame: &mut Frame, app: &mut App, body: Rect) {
+    // Render the normal timer view in the background
+    super::timer_view::render_timer_view(frame, app, body);
+
+    let Some(wizard) = &app.fill_wizard else {
+        return;
+    };
+
+    let (title, hint, rows): (String, &str, Vec<(String, bool)>) = match wizard.step {
+        FillStep::Days => {
+            let selected = wizard.days.iter().filter(|day| day.selected).count();
+            (
+                format!(" Workdays without time ({selected} selected) "),
+                " Space: toggle  Enter: next  Esc: cancel ",
+                wizard
+                    .days
+                    .iter()
+                    .map(|day| {
+                        let mark = if day.selected { "[x]" } else { "[ ]" };
+                        (format!("{} {}", mark, day.label()), day.selected)
+                    })
+                    .collect(),
+            )
+        }
+        FillStep::Source => (
+            " Fill with ".to_string(),
+            " Enter: fill  Esc: back ",
+            wizard
+                .sources
+                .iter()
+                .map(|source| (source.label.clone(), true))
+                .collect(),
+        ),
+    };
+
+    let visible = MAX_ROWS as usize;
+    let offset = wizard.focused.saturating_sub(visible - 1);
+    let area = centered_rect(64, (rows.len() as u16).min(MAX_ROWS) + 4, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut items = vec![ListItem::new("")];
+    items.extend(rows.into_iter().enumerate().skip(offset).take(visible).map(
+        |(i, (text, active))| {
+            let style = if i == wizard.focused {
+                Style::default().fg(theme().accent)
+            } else if active {
+                Style::default().fg(theme().text)
+            } else {
+                Style::default().fg(theme().muted)
+            };
+            ListItem::new(text).style(style)
+        },
+    ));
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_bottom(
+                Line::from(Span::styled(hint, Style::default().fg(theme().muted)))
+                    .alignment(Alignment::Right),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(list, area);
+}
@@ -12,7 +12,7 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
 
     // Border style depends on focus
     let border_style = if is_today_focused {
-        Style::default().fg(Color::Magenta)
+        Style::default().fg(theme().highlight)
     } else {
         Style::default()
     };
@@ -43,9 +43,7 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
     let max_rows = inner_area.height as usize;
     app.this_week_view_height = max_rows;
 
-    let today = time::OffsetDateTime::now_utc()
-        .to_offset(time::UtcOffset::current_local_offset().unwrap_or(time::UtcOffset::UTC))
-        .date();
+    let today = crate::time_utils::local_today();
     let yesterday = today - time::Duration::days(1);
 
     // Format today/yesterday as YYYY-MM-DD strings for comparison with entry.date
@@ -157,6 +155,7 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
 
     let mut row_y = inner_area.y;
     let mut row_count = 0;
+    let mut clickable_rows = Vec::new();
 
     for (logical_idx, row) in logical_rows.iter().enumerate() {
         if logical_idx < scroll_offset {
@@ -172,7 +171,7 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
                 frame.render_widget(
                     Paragraph::new(Line::from(Span::styled(
                         "── Running ──",
-                        Style::default().fg(Color::Green),
+                        Style::default().fg(theme().success),
                     ))),
                     sep_rect,
                 );
@@ -191,16 +190,17 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
                 };
                 let row_rect = Rect::new(inner_area.x, row_y, content_width, 1);
                 frame.render_widget(
-                    Paragraph::new(line).style(Style::default().fg(Color::White)),
+                    Paragraph::new(line).style(Style::default().fg(theme().text)),
                     row_rect,
                 );
+                clickable_rows.push((row_rect, 0));
             }
             ThisWeekRow::Separator(label) => {
                 let sep_rect = Rect::new(inner_area.x, row_y, content_width, 1);
                 frame.render_widget(
                     Paragraph::new(Line::from(Span::styled(
                         label.as_str(),
-                        Style::default().fg(Color::Cyan),
+                        Style::default().fg(theme().info),
                     ))),
                     sep_rect,
                 );
@@ -220,13 +220,20 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
                         is_focused,
                     )
                 } else {
-                    build_display_row(entry, is_focused, is_overlapping, content_width)
+                    build_display_row(
+                        entry,
+                        is_focused,
+                        is_overlapping,
+                        content_width,
+                        app.display_rounding(),
+                    )
                 };
                 let row_rect = Rect::new(inner_area.x, row_y, content_width, 1);
                 frame.render_widget(
-                    Paragraph::new(line).style(Style::default().fg(Color::White)),
+                    Paragraph::new(line).style(Style::default().fg(theme().text)),
                     row_rect,
                 );
+                clickable_rows.push((row_rect, *visible_entry_idx));
             }
         }
 
@@ -234,6 +241,10 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
         row_count += 1;
     }
 
+    for (row_rect, index) in clickable_rows {
+        app.register_click_target(row_rect, crate::app::ClickTarget::ThisWeekEntry(index));
+    }
+
     // --- Render scrollbar ---
     if total_rows > max_rows {
         let mut scrollbar_state = ScrollbarState::new(total_rows)
@@ -241,7 +252,7 @@ pub fn render_this_week_history(frame: &mut Frame, area: ratatui::layout::Rect,
             .viewport_content_length(max_rows);
         frame.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .style(Style::default().fg(Color::DarkGray)),
+                .style(Style::default().fg(theme().muted)),
             inner_area,
             &mut scrollbar_state,
         );
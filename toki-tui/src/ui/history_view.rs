@@ -11,38 +11,50 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
         ])
         .split(body);
 
-    let month_ago = (time::OffsetDateTime::now_utc() - time::Duration::days(30)).date();
-    let month_ago_str = format!(
-        "{:04}-{:02}-{:02}",
-        month_ago.year(),
-        month_ago.month() as u8,
-        month_ago.day()
-    );
     let entries: Vec<(usize, &crate::types::TimeEntry)> = app
-        .time_entries
+        .history_list_entries
         .iter()
         .enumerate()
-        .filter(|(_, entry)| entry.date >= month_ago_str)
+        .filter_map(|(list_idx, &idx)| app.time_entries.get(idx).map(|entry| (list_idx, entry)))
         .collect();
+    let filter_label = if app.history_filter.is_active() {
+        format!(
+            " · {}",
+            app.history_filter
+                .describe(crate::time_utils::local_today())
+        )
+    } else {
+        String::new()
+    };
 
     if entries.is_empty() {
-        let empty_msg = Paragraph::new("No entries in the last 30 days")
+        let empty_text = if app.is_loading {
+            "Loading older entries…"
+        } else if app.history_filter.is_active() {
+            "No entries match the filter"
+        } else {
+            "No entries in the last 30 days"
+        };
+        let empty_msg = Paragraph::new(empty_text)
             .alignment(Alignment::Center)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::White))
-                    .title(Span::styled(" History ", Style::default().fg(Color::White)))
+                    .border_style(Style::default().fg(theme().text))
+                    .title(Span::styled(
+                        format!(" History{} ", filter_label),
+                        Style::default().fg(theme().text),
+                    ))
                     .padding(ratatui::widgets::Padding::horizontal(1)),
             );
         frame.render_widget(empty_msg, chunks[0]);
     } else {
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().text))
             .title(Span::styled(
-                format!(" History ({} entries) ", entries.len()),
-                Style::default().fg(Color::White),
+                format!(" History ({} entries{}) ", entries.len(), filter_label),
+                Style::default().fg(theme().text),
             ))
             .padding(ratatui::widgets::Padding::horizontal(1));
 
@@ -56,12 +68,12 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
         enum HistoryRow<'a> {
             Separator(String),
             Entry {
-                list_idx: Option<usize>,
+                list_idx: usize,
                 entry: &'a crate::types::TimeEntry,
             },
         }
 
-        let today = time::OffsetDateTime::now_utc().date();
+        let today = crate::time_utils::local_today();
         let yesterday = today - time::Duration::days(1);
         let today_str = format!(
             "{:04}-{:02}-{:02}",
@@ -85,7 +97,7 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
             *date_totals.entry(entry.date.as_str()).or_insert(0.0) += entry.hours;
         }
 
-        for (history_idx, entry) in &entries {
+        for (list_idx, entry) in &entries {
             if last_date.as_deref() != Some(&entry.date) {
                 let total = date_totals.get(entry.date.as_str()).copied().unwrap_or(0.0);
                 let total_str = super::utils::format_hours_hm(total);
@@ -100,11 +112,10 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
                 logical_rows.push(HistoryRow::Separator(label));
                 last_date = Some(entry.date.clone());
             }
-            let list_idx = app
-                .history_list_entries
-                .iter()
-                .position(|&idx| idx == *history_idx);
-            logical_rows.push(HistoryRow::Entry { list_idx, entry });
+            logical_rows.push(HistoryRow::Entry {
+                list_idx: *list_idx,
+                entry,
+            });
         }
 
         let total_rows = logical_rows.len();
@@ -113,7 +124,7 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
         let focused_logical_row: Option<usize> = app.focused_history_index.and_then(|fi| {
             logical_rows.iter().position(|r| {
                 if let HistoryRow::Entry { list_idx, .. } = r {
-                    *list_idx == Some(fi)
+                    *list_idx == fi
                 } else {
                     false
                 }
@@ -170,7 +181,7 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
                     frame.render_widget(
                         Paragraph::new(Line::from(Span::styled(
                             label.as_str(),
-                            Style::default().fg(Color::Cyan),
+                            Style::default().fg(theme().info),
                         ))),
                         sep_rect,
                     );
@@ -178,19 +189,38 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
                 HistoryRow::Entry {
                     list_idx, entry, ..
                 } => {
-                    let is_focused = app.focused_history_index == *list_idx;
+                    let is_focused = app.focused_history_index == Some(*list_idx);
                     let is_editing = editing_reg_id == Some(entry.registration_id.as_str());
                     let is_overlapping = app.is_entry_overlapping(&entry.registration_id);
 
+                    let is_selected = app.marked_entries.contains(&entry.registration_id);
+
                     let line = if is_editing {
                         build_edit_row(entry, app.history_edit_state.as_ref().unwrap(), is_focused)
+                    } else if is_selected {
+                        let mut line = build_display_row(
+                            entry,
+                            is_focused,
+                            is_overlapping,
+                            content_width.saturating_sub(2),
+                            app.display_rounding(),
+                        );
+                        line.spans
+                            .insert(0, Span::styled("+ ", Style::default().fg(theme().success)));
+                        line
                     } else {
-                        build_display_row(entry, is_focused, is_overlapping, content_width)
+                        build_display_row(
+                            entry,
+                            is_focused,
+                            is_overlapping,
+                            content_width,
+                            app.display_rounding(),
+                        )
                     };
 
                     let row_rect = Rect::new(inner_area.x, row_y, content_width, 1);
                     frame.render_widget(
-                        Paragraph::new(line).style(Style::default().fg(Color::White)),
+                        Paragraph::new(line).style(Style::default().fg(theme().text)),
                         row_rect,
                     );
                 }
@@ -207,7 +237,7 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
                 .viewport_content_length(max_rows);
             frame.render_stateful_widget(
                 Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                    .style(Style::default().fg(Color::DarkGray)),
+                    .style(Style::default().fg(theme().muted)),
                 inner_area,
                 &mut scrollbar_state,
             );
@@ -215,30 +245,64 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
     }
 
     // Controls
-    let controls_text = if app.history_edit_state.is_some() {
+    let controls_text = if app.history_filter.searching {
+        vec![
+            Span::styled("/", Style::default().fg(theme().accent)),
+            Span::styled(
+                format!("{}█  ", app.history_filter.search.value),
+                Style::default().fg(theme().text),
+            ),
+            Span::styled("Enter", Style::default().fg(theme().accent)),
+            Span::raw(": Keep filter  "),
+            Span::styled("Esc", Style::default().fg(theme().accent)),
+            Span::raw(": Clear search"),
+        ]
+    } else if app.history_edit_state.is_some() {
         vec![
-            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::styled("Tab", Style::default().fg(theme().accent)),
             Span::raw(": Next field  "),
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled("Enter", Style::default().fg(theme().accent)),
             Span::raw(": Edit field  "),
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::styled("Esc", Style::default().fg(theme().accent)),
             Span::raw(": Save & exit  "),
-            Span::styled("P/A", Style::default().fg(Color::Yellow)),
+            Span::styled("S", Style::default().fg(theme().accent)),
+            Span::raw(": Split  "),
+            Span::styled("C", Style::default().fg(theme().accent)),
+            Span::raw(": Copy to day  "),
+            Span::styled("P/A", Style::default().fg(theme().accent)),
             Span::raw(": Change Project/Activity"),
         ]
     } else {
         vec![
-            Span::styled("↑↓", Style::default().fg(Color::Yellow)),
+            Span::styled("↑↓", Style::default().fg(theme().accent)),
             Span::raw(": Navigate  "),
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::styled("Enter", Style::default().fg(theme().accent)),
             Span::raw(": Edit  "),
-            Span::styled("Ctrl+R", Style::default().fg(Color::Yellow)),
+            Span::styled("Ctrl+R", Style::default().fg(theme().accent)),
             Span::raw(": Resume  "),
-            Span::styled("Ctrl+L", Style::default().fg(Color::Yellow)),
+            Span::styled("Ctrl+L", Style::default().fg(theme().accent)),
             Span::raw(": Open log  "),
-            Span::styled("H / Esc", Style::default().fg(Color::Yellow)),
+            Span::styled("Space", Style::default().fg(theme().accent)),
+            Span::raw(": Mark  "),
+            Span::styled("M", Style::default().fg(theme().accent)),
+            Span::raw(": Merge  "),
+            Span::styled("O", Style::default().fg(theme().accent)),
+            Span::raw(": Resolve overlaps  "),
+            Span::styled("P", Style::default().fg(theme().accent)),
+            Span::raw(": Reassign marked  "),
+            Span::styled("/", Style::default().fg(theme().accent)),
+            Span::raw(": Search  "),
+            Span::styled("f/F", Style::default().fg(theme().accent)),
+            Span::raw(": Period/Project filter  "),
+            Span::styled("[/]", Style::default().fg(theme().accent)),
+            Span::raw(": Older/Newer  "),
+            Span::styled("D", Style::default().fg(theme().accent)),
+            Span::raw(": Dates  "),
+            Span::styled("E", Style::default().fg(theme().accent)),
+            Span::raw(": Export  "),
+            Span::styled("H / Esc", Style::default().fg(theme().accent)),
             Span::raw(": Back to timer  "),
-            Span::styled("Q", Style::default().fg(Color::Yellow)),
+            Span::styled("Q", Style::default().fg(theme().accent)),
             Span::raw(": Quit"),
         ]
     };
@@ -248,10 +312,10 @@ pub fn render_history_view(frame: &mut Frame, app: &mut App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
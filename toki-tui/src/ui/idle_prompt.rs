@@ -0,0 +1,61 @@
+use super::utils::{centered_rect, to_local_time};
+use super::*;
+use crate::app::IdleAction;
+
+pub fn render_idle_prompt(frame: &mut Frame, app: &mut App, body: Rect) {
+    // Render the normal timer view in the background
+    super::timer_view::render_timer_view(frame, app, body);
+
+    let Some(idle) = app.idle_period else {
+        return;
+    };
+
+    let area = centered_rect(62, 9, frame.area());
+    frame.render_widget(Clear, area);
+
+    let since = to_local_time(idle.since);
+    let title = format!(
+        " You were idle {} min (since {:02}:{:02}) ",
+        idle.minutes(),
+        since.hour(),
+        since.minute()
+    );
+
+    let options = [
+        (IdleAction::Keep, "1. Keep — count it on the timer"),
+        (
+            IdleAction::Discard,
+            "2. Discard — move the timer's start forward",
+        ),
+        (
+            IdleAction::Split,
+            "3. Split — save the time before, restart now",
+        ),
+    ];
+
+    let mut items = vec![ListItem::new("")];
+    items.extend(options.iter().map(|(action, text)| {
+        let style = if *action == app.selected_idle_action {
+            Style::default().fg(theme().accent)
+        } else {
+            Style::default().fg(theme().text)
+        };
+        ListItem::new(*text).style(style)
+    }));
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_bottom(
+                Line::from(Span::styled(
+                    " Enter: confirm  Esc: keep ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(list, area);
+}
@@ -9,21 +9,34 @@ use ratatui::{
     },
     Frame,
 };
+use theme::theme;
 
+mod board_view;
+mod calendar_view;
+mod copy_prompt;
 mod delete_dialog;
 mod description_editor;
+mod fill_workdays;
 mod history_panel;
+mod history_range_prompt;
 mod history_view;
+mod idle_prompt;
+mod overlap_prompt;
+mod pull_requests_view;
 mod save_dialog;
 mod selection_views;
+mod split_prompt;
 mod statistics_view;
 mod template_selection_view;
+pub mod theme;
 mod timer_view;
 pub(super) mod utils;
 pub(super) mod widgets;
 mod zen_view;
 
 pub fn render(frame: &mut Frame, app: &mut App) {
+    app.clear_click_targets();
+
     // Zen mode: full-screen, no stats bar, no other UI
     if app.current_view == View::Timer && app.zen_mode {
         zen_view::render_zen_view(frame, app);
@@ -49,13 +62,36 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         View::EditDescription => {
             if app.taskwarrior_overlay.is_some() {
                 description_editor::render_taskwarrior_overlay(frame, app, body);
+            } else if app.work_item_overlay.is_some() {
+                description_editor::render_work_item_overlay(frame, app, body);
             } else {
                 description_editor::render_description_editor(frame, app, body);
             }
         }
         View::SaveAction => save_dialog::render_save_action_dialog(frame, app, body),
+        View::IdlePrompt => idle_prompt::render_idle_prompt(frame, app, body),
+        View::FillWorkdays => fill_workdays::render_fill_workdays(frame, app, body),
         View::Statistics => statistics_view::render_statistics_view(frame, app, body),
+        View::Calendar => calendar_view::render_calendar_view(frame, app, body),
         View::ConfirmDelete => delete_dialog::render_delete_confirm_dialog(frame, app, body),
+        View::PullRequests => pull_requests_view::render_pull_requests_view(frame, app, body),
+        View::PullRequestDetail => {
+            pull_requests_view::render_pull_request_detail_view(frame, app, body)
+        }
+        View::Board => board_view::render_board_view(frame, app, body),
+    }
+
+    if app.split_prompt.is_some() {
+        split_prompt::render_split_prompt(frame, app);
+    }
+    if app.copy_prompt.is_some() {
+        copy_prompt::render_copy_prompt(frame, app);
+    }
+    if app.history_range_prompt.is_some() {
+        history_range_prompt::render_history_range_prompt(frame, app);
+    }
+    if app.overlap_resolution.is_some() {
+        overlap_prompt::render_overlap_prompt(frame, app);
     }
 }
 
@@ -63,7 +99,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
 mod tests {
     use super::*;
     use crate::app::{FocusedBox, TimerState};
-    use crate::test_support::{activity, project, test_app};
+    use crate::test_support::{activity, project, pull_request, test_app, time_entry};
     use ratatui::{backend::TestBackend, Terminal};
     use time::macros::datetime;
 
@@ -109,6 +145,225 @@ mod tests {
         assert!(text.contains("Investigate tests"));
     }
 
+    #[test]
+    fn render_shows_timer_running_on_another_device() {
+        let mut app = test_app();
+        app.start_timer(false);
+        assert!(!rendered_text(&mut app).contains("Timer running on"));
+
+        app.timer_device = Some("Web".to_string());
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("Timer running on Web"));
+        assert!(text.contains("O: Take over"));
+    }
+
+    #[test]
+    fn render_shows_paused_timers_below_the_timer() {
+        let mut app = test_app();
+        app.push_paused_timer(crate::types::PausedTimer {
+            id: 1,
+            project_id: Some("proj-1".to_string()),
+            project_name: Some("Project One".to_string()),
+            activity_id: Some("act-1".to_string()),
+            activity_name: Some("Activity One".to_string()),
+            note: String::new(),
+            segments: Vec::new(),
+            paused_at: time::OffsetDateTime::now_utc(),
+            tracked_minutes: 75,
+        });
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("Paused: Project One / Activity One (1h 15m)"));
+        assert!(text.contains("Shift+I: Resume paused"));
+    }
+
+    #[test]
+    fn render_shows_sprint_capacity_in_statistics() {
+        let mut app = test_app();
+        app.sprint_capacities = vec![crate::types::SprintCapacity {
+            iteration_name: "Sprint 42".to_string(),
+            iteration_path: "Toki\\Sprint 42".to_string(),
+            start_date: "2026-10-05".to_string(),
+            finish_date: "2026-10-16".to_string(),
+            capacity_hours: Some(42.0),
+            logged_hours: 31.5,
+        }];
+        app.navigate_to(View::Statistics);
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("Sprint capacity"));
+        assert!(text.contains("Sprint 42"));
+        assert!(text.contains("31.5/42.0h (75%)"));
+    }
+
+    #[test]
+    fn render_shows_board_columns_and_move_prompt() {
+        use crate::types::{Board, BoardColumn, BoardWorkItem};
+
+        let mut app = test_app();
+        let column = |id: &str, name: &str, order| BoardColumn {
+            id: id.to_string(),
+            name: name.to_string(),
+            order,
+        };
+        app.set_board(Board {
+            columns: vec![column("todo", "To Do", 0), column("doing", "Doing", 1)],
+            items: vec![BoardWorkItem {
+                id: "4711".to_string(),
+                title: "Scrollable lists".to_string(),
+                board_state: "inProgress".to_string(),
+                board_column_id: Some("doing".to_string()),
+                board_column_name: None,
+                category: "task".to_string(),
+                state_name: "Active".to_string(),
+                priority: None,
+                assigned_to: None,
+                url: "https://dev.azure.com/org/Toki/_workitems/edit/4711".to_string(),
+            }],
+        });
+        app.navigate_to(View::Board);
+
+        let text = rendered_text(&mut app);
+        assert!(text.contains("To Do (0)"));
+        assert!(text.contains("Doing (1)"));
+        assert!(text.contains("#4711 Scrollable lists"));
+        assert!(text.contains("Unassigned"));
+        assert!(text.contains("T: Timer"));
+        assert!(text.contains("Q: Quit"));
+
+        app.board_time_mapping = Some(crate::types::WorkItemTimeMapping {
+            id: 1,
+            organization: "org".to_string(),
+            project: "Toki".to_string(),
+            project_id: "proj-1".to_string(),
+            project_name: "Project One".to_string(),
+            activity_id: "act-1".to_string(),
+            activity_name: "Development".to_string(),
+        });
+        assert!(rendered_text(&mut app).contains("Timers: Project One: Development"));
+
+        app.board_focus_right();
+        app.open_board_move_prompt();
+        let text = rendered_text(&mut app);
+        assert!(text.contains("Move #4711 to"));
+        assert!(text.contains("› Doing"));
+    }
+
+    #[test]
+    fn render_lists_pinned_favorites_above_projects() {
+        let mut app = test_app();
+        app.set_projects_activities(vec![project("proj-1", "Project One")], vec![]);
+        app.favorites = vec![crate::types::Favorite {
+            id: 1,
+            project_id: "proj-1".to_string(),
+            project_name: "Project One".to_string(),
+            activity_id: "act-1".to_string(),
+            activity_name: "Activity One".to_string(),
+        }];
+        app.navigate_to(View::SelectProject);
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("1  Project One / Activity One"));
+        assert!(text.contains("1-1: Pinned"));
+    }
+
+    #[test]
+    fn render_shows_status_banner_in_header() {
+        let mut app = test_app();
+        app.status_banner = Some(crate::types::StatusBanner {
+            message: "Kleer upgrade 18:00-20:00".to_string(),
+            severity: crate::types::BannerSeverity::Warning,
+            starts_at: None,
+            ends_at: None,
+            maintenance: true,
+        });
+
+        let first_line = render_lines(&mut app).remove(0);
+
+        assert!(first_line.contains("Maintenance: Kleer upgrade 18:00-20:00"));
+    }
+
+    #[test]
+    fn render_shows_attest_checklist_from_reminder_day() {
+        let mut app = test_app();
+        assert!(!rendered_text(&mut app).contains("Before attesting"));
+
+        app.attest_reminder_day = Some(time::Weekday::Monday);
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("Before attesting this week"));
+        assert!(text.contains("Nothing logged on Mon"));
+    }
+
+    #[test]
+    fn render_calendar_shows_month_and_logged_hours() {
+        let mut app = test_app();
+        app.set_calendar_month(
+            time::macros::date!(2026 - 02 - 01),
+            vec![time_entry(
+                "1",
+                "p1",
+                "Toki",
+                "a1",
+                "Development",
+                "2026-02-02",
+                7.5,
+                None,
+                None,
+                None,
+            )],
+        );
+        app.navigate_to(View::Calendar);
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("Calendar · February 2026"));
+        assert!(text.contains("07h:30m"));
+        assert!(text.contains("Logged 07h:30m"));
+    }
+
+    #[test]
+    fn render_idle_prompt_shows_idle_minutes_and_choices() {
+        let mut app = test_app();
+        app.idle_threshold = Some(time::Duration::minutes(15));
+        app.start_timer(false);
+        let now = time::OffsetDateTime::now_utc();
+        app.absolute_start = Some(now - time::Duration::hours(2));
+        app.last_input_at = now - time::Duration::minutes(47);
+        assert!(app.register_input(now));
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("You were idle 47 min"));
+        assert!(text.contains("1. Keep"));
+        assert!(text.contains("2. Discard"));
+        assert!(text.contains("3. Split"));
+    }
+
+    #[test]
+    fn render_pull_request_detail_shows_changes_and_threads() {
+        let mut app = test_app();
+        let mut pr = pull_request("toki2", 42);
+        pr.description = Some("Adds the review view".to_string());
+        app.set_pull_requests(vec![pr]);
+        app.open_pull_request_detail(vec![toki_client::PullRequestChange {
+            path: "/src/main.rs".to_string(),
+            change_type: "edit".to_string(),
+            original_path: None,
+        }]);
+
+        let text = rendered_text(&mut app);
+
+        assert!(text.contains("toki2 !42"));
+        assert!(text.contains("Adds the review view"));
+        assert!(text.contains("Changed files (1)"));
+        assert!(text.contains("M /src/main.rs"));
+    }
+
     #[test]
     fn render_status_shows_error_copy() {
         let mut app = test_app();
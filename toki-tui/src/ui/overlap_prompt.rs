@@ -0,0 +1,48 @@
+use super::utils::centered_rect;
+use super::*;
+
+/// Dialog over the history listing the fixes proposed for one day's
+/// overlapping entries.
+pub fn render_overlap_prompt(frame: &mut Frame, app: &App) {
+    let Some(resolution) = &app.overlap_resolution else {
+        return;
+    };
+
+    let extra = usize::from(resolution.unresolved > 0);
+    let height = (resolution.fixes.len() + extra) as u16 + 4;
+    let area = centered_rect(60, height, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![Line::from("")];
+    lines.extend(resolution.fixes.iter().map(|fix| {
+        Line::from(vec![
+            Span::styled("• ", Style::default().fg(theme().muted)),
+            Span::styled(fix.describe(), Style::default().fg(theme().accent)),
+        ])
+    }));
+    if resolution.unresolved > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} overlap(s) between attested entries stay as they are",
+                resolution.unresolved
+            ),
+            Style::default().fg(theme().muted),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Resolve overlaps on {} ", resolution.date))
+            .title_bottom(
+                Line::from(Span::styled(
+                    " Enter: apply  Esc: cancel ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,327 @@
+use super::*;
+use crate::types::PullRequest;
+use ratatui::widgets::Wrap;
+use toki_client::pull_request::Vote;
+
+pub fn render_pull_requests_view(frame: &mut Frame, app: &App, body: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(body);
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(
+            format!(" Pull Requests ({}) ", app.pull_requests.len()),
+            Style::default().fg(theme().text),
+        ))
+        .padding(Padding::horizontal(1));
+    if let Some(status) = &app.status_message {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", status),
+            Style::default().fg(theme().muted),
+        ));
+    }
+
+    if app.pull_requests.is_empty() {
+        let empty_msg = Paragraph::new("No open pull requests in followed repositories")
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty_msg, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = app
+            .pull_requests
+            .iter()
+            .enumerate()
+            .map(|(i, pr)| pull_request_row(pr, i == app.selected_pull_request_index))
+            .collect();
+        let mut state = ListState::default().with_selected(Some(app.selected_pull_request_index));
+        frame.render_stateful_widget(List::new(items).block(block), chunks[0], &mut state);
+    }
+
+    render_controls(
+        frame,
+        chunks[1],
+        &[
+            ("↑↓ / j/k", "Navigate"),
+            ("Enter", "Details"),
+            ("O", "Open in browser"),
+            ("R", "Refresh"),
+            ("V / Esc", "Back to timer"),
+            ("Q", "Quit"),
+        ],
+    );
+}
+
+pub fn render_pull_request_detail_view(frame: &mut Frame, app: &App, body: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(body);
+
+    let Some(pr) = app.selected_pull_request() else {
+        return;
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(
+            format!(" {} !{} ", pr.repo_name, pr.id),
+            Style::default().fg(theme().text),
+        ))
+        .padding(Padding::horizontal(1));
+    if let Some(status) = &app.status_message {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", status),
+            Style::default().fg(theme().muted),
+        ));
+    }
+
+    let detail = Paragraph::new(pull_request_detail_lines(pr, app))
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.pull_request_detail_scroll, 0));
+    frame.render_widget(detail, chunks[0]);
+
+    render_controls(
+        frame,
+        chunks[1],
+        &[
+            ("↑↓ / j/k", "Scroll"),
+            ("O", "Open to vote/reply"),
+            ("R", "Reload files"),
+            ("Esc", "Back to list"),
+            ("Q", "Quit"),
+        ],
+    );
+}
+
+fn pull_request_row(pr: &PullRequest, selected: bool) -> ListItem<'static> {
+    let title_style = if selected {
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme().text)
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{:<20} ", truncate(&pr.repo_name, 20)),
+            Style::default().fg(theme().muted),
+        ),
+        Span::styled(format!("!{:<6} ", pr.id), Style::default().fg(theme().info)),
+        Span::styled(pr.title.clone(), title_style),
+    ];
+    if pr.is_draft {
+        spans.push(Span::styled(" [draft]", Style::default().fg(theme().muted)));
+    }
+    if pr.waiting_for_user_review {
+        spans.push(Span::styled(
+            " • review requested",
+            Style::default().fg(theme().highlight),
+        ));
+    }
+    if !pr.approved_by.is_empty() {
+        spans.push(Span::styled(
+            format!(" ✓{}", pr.approved_by.len()),
+            Style::default().fg(theme().success),
+        ));
+    }
+    if !pr.blocked_by.is_empty() {
+        spans.push(Span::styled(
+            format!(" ✗{}", pr.blocked_by.len()),
+            Style::default().fg(theme().error),
+        ));
+    }
+    let active_threads = pr.active_thread_count();
+    if active_threads > 0 {
+        spans.push(Span::styled(
+            format!(" 💬{}", active_threads),
+            Style::default().fg(theme().accent),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+fn pull_request_detail_lines(pr: &PullRequest, app: &App) -> Vec<Line<'static>> {
+    let heading = Style::default()
+        .fg(theme().accent)
+        .add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(theme().muted);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            pr.title.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "{} → {}  by {}{}",
+                branch_name(&pr.source_branch),
+                branch_name(&pr.target_branch),
+                pr.created_by.display_name,
+                if pr.is_draft { "  (draft)" } else { "" }
+            ),
+            muted,
+        )),
+        Line::raw(""),
+        Line::from(Span::styled("Reviewers", heading)),
+    ];
+
+    if pr.reviewers.is_empty() {
+        lines.push(Line::from(Span::styled("  none", muted)));
+    }
+    for reviewer in &pr.reviewers {
+        let (label, color) = vote_label(reviewer.vote);
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", reviewer.identity.display_name)),
+            Span::styled(label, Style::default().fg(color)),
+            Span::styled(
+                if reviewer.is_required.unwrap_or(false) {
+                    " (required)"
+                } else {
+                    ""
+                },
+                muted,
+            ),
+        ]));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled("Description", heading)));
+    match pr.description.as_deref().map(str::trim) {
+        Some(description) if !description.is_empty() => {
+            lines.extend(
+                description
+                    .lines()
+                    .map(|line| Line::raw(format!("  {line}"))),
+            );
+        }
+        _ => lines.push(Line::from(Span::styled("  No description", muted))),
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        format!("Changed files ({})", app.pull_request_changes.len()),
+        heading,
+    )));
+    for change in &app.pull_request_changes {
+        let (marker, color) = change_marker(&change.change_type);
+        let mut spans = vec![
+            Span::styled(format!("  {marker} "), Style::default().fg(color)),
+            Span::raw(change.path.clone()),
+        ];
+        if let Some(original_path) = &change.original_path {
+            spans.push(Span::styled(format!(" (from {original_path})"), muted));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let threads: Vec<_> = pr.discussion_threads().collect();
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "Threads ({} active / {})",
+            pr.active_thread_count(),
+            threads.len()
+        ),
+        heading,
+    )));
+    for thread in threads {
+        let status = thread.status.as_deref().unwrap_or("unknown");
+        let status_color = if status == "active" {
+            theme().warning
+        } else {
+            theme().success
+        };
+        lines.push(Line::from(Span::styled(
+            format!("  [{status}]"),
+            Style::default().fg(status_color),
+        )));
+        for comment in thread
+            .comments
+            .iter()
+            .filter(|comment| !comment.is_deleted.unwrap_or(false))
+        {
+            let content = comment.content.as_deref().unwrap_or("").trim();
+            let mut content_lines = content.lines();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("    {}: ", comment.author.display_name),
+                    Style::default().fg(theme().info),
+                ),
+                Span::raw(content_lines.next().unwrap_or("").to_string()),
+            ]));
+            lines.extend(content_lines.map(|line| Line::raw(format!("      {line}"))));
+        }
+    }
+
+    lines
+}
+
+pub(super) fn render_controls(frame: &mut Frame, area: Rect, controls: &[(&str, &str)]) {
+    let mut spans = Vec::new();
+    for (i, (key, label)) in controls.iter().enumerate() {
+        spans.push(Span::styled(
+            key.to_string(),
+            Style::default().fg(theme().accent),
+        ));
+        let separator = if i + 1 < controls.len() { "  " } else { "" };
+        spans.push(Span::raw(format!(": {label}{separator}")));
+    }
+
+    let controls = Paragraph::new(Line::from(spans))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme().muted))
+                .title(Span::styled(
+                    " Controls ",
+                    Style::default().fg(theme().muted),
+                ))
+                .padding(Padding::horizontal(1)),
+        );
+    frame.render_widget(controls, area);
+}
+
+fn vote_label(vote: Option<Vote>) -> (&'static str, Color) {
+    match vote {
+        Some(Vote::Approved) => ("approved", theme().success),
+        Some(Vote::ApprovedWithSuggestions) => ("approved with suggestions", theme().success),
+        Some(Vote::WaitingForAuthor) => ("waiting for author", theme().warning),
+        Some(Vote::Rejected) => ("rejected", theme().error),
+        Some(Vote::NoResponse) | None => ("no vote", theme().muted),
+    }
+}
+
+fn change_marker(change_type: &str) -> (&'static str, Color) {
+    if change_type.contains("add") {
+        ("A", theme().success)
+    } else if change_type.contains("delete") {
+        ("D", theme().error)
+    } else if change_type.contains("rename") {
+        ("R", theme().info)
+    } else {
+        ("M", theme().warning)
+    }
+}
+
+fn branch_name(git_ref: &str) -> &str {
+    git_ref.strip_prefix("refs/heads/").unwrap_or(git_ref)
+}
+
+pub(super) fn truncate(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        value.to_string()
+    } else {
+        let truncated: String = value.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{truncated}…")
+    }
+}
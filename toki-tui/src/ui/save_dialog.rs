@@ -32,9 +32,9 @@ pub fn render_save_action_dialog(frame: &mut Frame, app: &mut App, body: Rect) {
             };
 
             let style = if action == app.selected_save_action {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme().accent)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().text)
             };
 
             ListItem::new(*text).style(style)
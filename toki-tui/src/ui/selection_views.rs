@@ -1,11 +1,18 @@
 use super::*;
 
 pub fn render_project_selection(frame: &mut Frame, app: &App, body: Rect) {
+    // Favorites are listed above the projects until a search is typed.
+    let pinned_rows = if app.project_search_input.value.is_empty() {
+        app.favorites.len().min(crate::app::FAVORITE_SHORTCUTS) as u16
+    } else {
+        0
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
             Constraint::Length(3), // Search input
+            Constraint::Length(if pinned_rows > 0 { pinned_rows + 2 } else { 0 }), // Pinned
             Constraint::Min(0),    // Project list
             Constraint::Length(3), // Controls
         ])
@@ -26,12 +33,12 @@ pub fn render_project_selection(frame: &mut Frame, app: &App, body: Rect) {
         (format!("{}{}", before, after), Some(col))
     };
     let search_border = if app.selection_list_focused {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     };
     let search_box = Paragraph::new(search_text)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().text))
         .alignment(Alignment::Left)
         .block(
             Block::default()
@@ -45,21 +52,62 @@ pub fn render_project_selection(frame: &mut Frame, app: &App, body: Rect) {
         frame.set_cursor_position((chunks[0].x + 2 + col, chunks[0].y + 1));
     }
 
+    if pinned_rows > 0 {
+        let items: Vec<ListItem> = app
+            .favorites
+            .iter()
+            .take(crate::app::FAVORITE_SHORTCUTS)
+            .enumerate()
+            .map(|(i, favorite)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}  ", i + 1), Style::default().fg(theme().accent)),
+                    Span::raw(favorite.project_name.clone()),
+                    Span::styled(
+                        format!(" / {}", favorite.activity_name),
+                        Style::default().fg(theme().muted),
+                    ),
+                ]))
+            })
+            .collect();
+        let pinned = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme().muted))
+                .title(" Pinned ")
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(pinned, chunks[1]);
+    }
+
     // Project list
     let items: Vec<ListItem> = app
         .filtered_projects
         .iter()
         .enumerate()
         .map(|(i, project)| {
-            let text = project.name.clone();
+            let mut spans = vec![Span::raw(project.name.clone())];
+            if let Some(budget) = project.budget {
+                let remaining = budget.remaining_hours();
+                let text = if remaining < 0.0 {
+                    format!("  {:.1}h over budget", -remaining)
+                } else {
+                    format!("  {:.1}h left", remaining)
+                };
+                let color = if budget.is_nearly_consumed() {
+                    theme().error
+                } else {
+                    theme().muted
+                };
+                spans.push(Span::styled(text, Style::default().fg(color)));
+            }
 
             let style = if i == app.filtered_project_index {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme().accent)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().text)
             };
 
-            ListItem::new(text).style(style)
+            ListItem::new(Line::from(spans)).style(style)
         })
         .collect();
 
@@ -75,9 +123,9 @@ pub fn render_project_selection(frame: &mut Frame, app: &App, body: Rect) {
     };
 
     let list_border = if app.selection_list_focused {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     };
     let list = List::new(items)
         .block(
@@ -89,38 +137,50 @@ pub fn render_project_selection(frame: &mut Frame, app: &App, body: Rect) {
         )
         .style(Style::default());
 
-    frame.render_widget(list, chunks[1]);
+    frame.render_widget(list, chunks[2]);
 
     // Controls
-    let controls_text = vec![
-        Span::styled("Type", Style::default().fg(Color::Yellow)),
+    let mut controls_text = vec![
+        Span::styled("Type", Style::default().fg(theme().accent)),
         Span::raw(": Filter  "),
-        Span::styled("Tab", Style::default().fg(Color::Yellow)),
+        Span::styled("Tab", Style::default().fg(theme().accent)),
         Span::raw(": Focus list  "),
-        Span::styled("↑↓/j/k", Style::default().fg(Color::Yellow)),
+        Span::styled("↑↓/j/k", Style::default().fg(theme().accent)),
         Span::raw(": Navigate  "),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled("Enter", Style::default().fg(theme().accent)),
         Span::raw(": Select  "),
-        Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+X", Style::default().fg(theme().accent)),
         Span::raw(": Clear  "),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled("Esc", Style::default().fg(theme().accent)),
         Span::raw(": Cancel"),
     ];
+    if pinned_rows > 0 {
+        controls_text.splice(
+            2..2,
+            [
+                Span::styled(
+                    format!("1-{}", pinned_rows),
+                    Style::default().fg(theme().accent),
+                ),
+                Span::raw(": Pinned  "),
+            ],
+        );
+    }
 
     let controls = Paragraph::new(Line::from(controls_text))
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
 
-    frame.render_widget(controls, chunks[2]);
+    frame.render_widget(controls, chunks[3]);
 }
 
 pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
@@ -149,12 +209,12 @@ pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
         (format!("{}{}", before, after), Some(col))
     };
     let search_border = if app.selection_list_focused {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     };
     let search_box = Paragraph::new(search_text)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().text))
         .alignment(Alignment::Left)
         .block(
             Block::default()
@@ -175,12 +235,21 @@ pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
         .enumerate()
         .map(|(i, activity)| {
             let style = if i == app.filtered_activity_index {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme().accent)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().text)
             };
 
-            ListItem::new(activity.name.clone()).style(style)
+            let mut spans = vec![Span::raw(activity.name.clone())];
+            let pinned = app
+                .selected_project
+                .as_ref()
+                .is_some_and(|project| app.favorite_for(&project.id, &activity.id).is_some());
+            if pinned {
+                spans.push(Span::styled("  pinned", Style::default().fg(theme().muted)));
+            }
+
+            ListItem::new(Line::from(spans)).style(style)
         })
         .collect();
 
@@ -196,9 +265,9 @@ pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
     };
 
     let list_border = if app.selection_list_focused {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     };
     let list = List::new(items)
         .block(
@@ -214,17 +283,19 @@ pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
 
     // Controls
     let controls_text = vec![
-        Span::styled("Type", Style::default().fg(Color::Yellow)),
+        Span::styled("Type", Style::default().fg(theme().accent)),
         Span::raw(": Filter  "),
-        Span::styled("Tab", Style::default().fg(Color::Yellow)),
+        Span::styled("Tab", Style::default().fg(theme().accent)),
         Span::raw(": Focus list  "),
-        Span::styled("↑↓/j/k", Style::default().fg(Color::Yellow)),
+        Span::styled("↑↓/j/k", Style::default().fg(theme().accent)),
         Span::raw(": Navigate  "),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled("Enter", Style::default().fg(theme().accent)),
         Span::raw(": Select  "),
-        Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+P", Style::default().fg(theme().accent)),
+        Span::raw(": Pin  "),
+        Span::styled("Ctrl+X", Style::default().fg(theme().accent)),
         Span::raw(": Clear  "),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled("Esc", Style::default().fg(theme().accent)),
         Span::raw(": Cancel"),
     ];
 
@@ -233,10 +304,10 @@ pub fn render_activity_selection(frame: &mut Frame, app: &App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
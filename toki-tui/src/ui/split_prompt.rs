@@ -0,0 +1,47 @@
+use super::utils::centered_rect;
+use super::*;
+
+/// Small dialog over the current view asking where to split the entry.
+pub fn render_split_prompt(frame: &mut Frame, app: &App) {
+    let Some(prompt) = &app.split_prompt else {
+        return;
+    };
+
+    let area = centered_rect(44, 7, frame.area());
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Split at: "),
+            Span::styled(
+                prompt.input.clone(),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    if let Some(error) = &prompt.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme().error),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Split entry ")
+            .title_bottom(
+                Line::from(Span::styled(
+                    " Enter: split  Esc: cancel ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_widget(paragraph, area);
+}
@@ -1,37 +1,45 @@
 use super::*;
+use crate::app::{StatsSpan, FLEX_TREND_WEEKS};
+use ratatui::{
+    symbols::Marker,
+    widgets::{Axis, Chart, Dataset, GraphType},
+};
 use tui_piechart::{PieChart, PieSlice};
 
-/// Shared color palette — same order for pie slices and daily bars
-pub const PALETTE: [Color; 12] = [
-    Color::Blue,
-    Color::Green,
-    Color::Yellow,
-    Color::Magenta,
-    Color::Cyan,
-    Color::Red,
-    Color::LightBlue,
-    Color::LightGreen,
-    Color::LightYellow,
-    Color::LightMagenta,
-    Color::LightCyan,
-    Color::LightRed,
-];
-
 pub fn render_statistics_view(frame: &mut Frame, app: &App, body: Rect) {
-    // Outer vertical split: chart area + controls bar
+    // Outer vertical split: chart area + flex trend (when recorded) + budgets
+    // (when set) + sprint capacity (when planned) + controls bar
+    let flex_height = if app.flex_history.is_empty() { 0 } else { 10 };
+    let budget_rows = app.budget_statuses.len() as u16;
+    let budgets_height = if budget_rows == 0 { 0 } else { budget_rows + 2 };
+    let sprint_rows = app.sprints_with_capacity().count() as u16;
+    let sprints_height = if sprint_rows == 0 { 0 } else { sprint_rows + 2 };
     let outer = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
-        .constraints([Constraint::Min(10), Constraint::Length(3)])
+        .constraints([
+            Constraint::Min(10),
+            Constraint::Length(flex_height),
+            Constraint::Length(budgets_height),
+            Constraint::Length(sprints_height),
+            Constraint::Length(3),
+        ])
         .split(body);
 
     // Outer "Statistics" box
     let stats_block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::White))
+        .border_style(Style::default().fg(theme().text))
         .title(Span::styled(
-            " Statistics ",
-            Style::default().fg(Color::White),
+            match &app.statistics_project {
+                Some(project) => format!(
+                    " Statistics — {} — {} ",
+                    app.statistics_period.label(),
+                    project
+                ),
+                None => format!(" Statistics — {} ", app.statistics_period.label()),
+            },
+            Style::default().fg(theme().text),
         ));
     let stats_inner = stats_block.inner(outer[0]);
     frame.render_widget(stats_block, outer[0]);
@@ -53,11 +61,37 @@ pub fn render_statistics_view(frame: &mut Frame, app: &App, body: Rect) {
     render_pie_panel(frame, app, pad(panels[0]));
     render_daily_panel(frame, app, pad(panels[1]));
 
+    if flex_height > 0 {
+        render_flex_panel(frame, app, outer[1]);
+    }
+    if budget_rows > 0 {
+        render_budgets_panel(frame, app, outer[2]);
+    }
+    if sprint_rows > 0 {
+        render_sprints_panel(frame, app, outer[3]);
+    }
+
     // Controls bar
+    let span_toggle = match app.statistics_period.span {
+        StatsSpan::Week => ": Month  ",
+        StatsSpan::Month => ": Week  ",
+    };
+    let (drill_key, drill_label) = match app.statistics_project {
+        Some(_) => ("Esc", ": Projects  "),
+        None => ("Enter", ": Activities  "),
+    };
     let stats_controls = vec![
-        Span::styled("S / Esc", Style::default().fg(Color::Yellow)),
+        Span::styled("← / →", Style::default().fg(theme().accent)),
+        Span::raw(": Earlier/later  "),
+        Span::styled("M", Style::default().fg(theme().accent)),
+        Span::raw(span_toggle),
+        Span::styled("↑↓", Style::default().fg(theme().accent)),
+        Span::raw(": Select  "),
+        Span::styled(drill_key, Style::default().fg(theme().accent)),
+        Span::raw(drill_label),
+        Span::styled("S", Style::default().fg(theme().accent)),
         Span::raw(": Back to timer  "),
-        Span::styled("Q", Style::default().fg(Color::Yellow)),
+        Span::styled("Q", Style::default().fg(theme().accent)),
         Span::raw(": Quit"),
     ];
     let controls = Paragraph::new(Line::from(stats_controls))
@@ -65,23 +99,23 @@ pub fn render_statistics_view(frame: &mut Frame, app: &App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
-    frame.render_widget(controls, outer[1]);
+    frame.render_widget(controls, outer[4]);
 }
 
 fn render_pie_panel(frame: &mut Frame, app: &App, area: Rect) {
-    let stats = &app.weekly_stats_cache;
+    let stats = &app.project_stats_cache;
 
     if stats.is_empty() {
         let empty = Paragraph::new("No data")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(theme().muted));
         frame.render_widget(empty, area);
         return;
     }
@@ -99,7 +133,7 @@ fn render_pie_panel(frame: &mut Frame, app: &App, area: Rect) {
         .iter()
         .enumerate()
         .map(|(i, label)| {
-            let color = PALETTE[i % PALETTE.len()];
+            let color = theme().chart[i % theme().chart.len()];
             PieSlice::new(label.as_str(), stats[i].percentage, color)
         })
         .collect();
@@ -126,7 +160,7 @@ fn render_pie_panel(frame: &mut Frame, app: &App, area: Rect) {
     let total_hours: f64 = stats.iter().map(|s| s.hours).sum();
     let mut legend_lines: Vec<Line> = Vec::new();
     for (i, s) in stats.iter().enumerate() {
-        let color = PALETTE[i % PALETTE.len()];
+        let color = theme().chart[i % theme().chart.len()];
         let pct = if total_hours > 0.0 {
             s.hours / total_hours * 100.0
         } else {
@@ -134,11 +168,18 @@ fn render_pie_panel(frame: &mut Frame, app: &App, area: Rect) {
         };
         let h = s.hours.floor() as u64;
         let m = ((s.hours - h as f64) * 60.0).round() as u64;
+        let label_style = if i == app.statistics_selected {
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().muted)
+        };
         legend_lines.push(Line::from(vec![
             Span::styled("■ ", Style::default().fg(color)),
             Span::styled(
                 format!("{} — {:02}h:{:02}m ({:.0}%)", s.label, h, m, pct),
-                Style::default().fg(Color::DarkGray),
+                label_style,
             ),
         ]));
     }
@@ -149,7 +190,7 @@ fn render_pie_panel(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
-    let day_stats = &app.weekly_daily_stats_cache;
+    let day_stats = &app.daily_stats_cache;
 
     // Find max daily hours for bar scaling
     let max_hours = day_stats
@@ -170,13 +211,13 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
         // Day name (4 chars + space)
         spans.push(Span::styled(
             format!("{:<4} ", day.day_name),
-            Style::default().fg(Color::White),
+            Style::default().fg(theme().text),
         ));
 
         if day.total_hours <= 0.0 || max_hours <= 0.0 {
             spans.push(Span::styled(
                 "─".repeat(bar_cols),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().muted),
             ));
             spans.push(Span::raw("         "));
         } else {
@@ -196,7 +237,7 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
                     cols.min(remaining_fill)
                 };
                 if proj_filled > 0 {
-                    let color = PALETTE[proj.color_index % PALETTE.len()];
+                    let color = theme().chart[proj.color_index % theme().chart.len()];
                     spans.push(Span::styled(
                         "█".repeat(proj_filled),
                         Style::default().fg(color),
@@ -208,7 +249,7 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
             if total_filled < bar_cols {
                 spans.push(Span::styled(
                     "░".repeat(bar_cols - total_filled),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ));
             }
 
@@ -216,7 +257,7 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
             let m = ((day.total_hours - h as f64) * 60.0).round() as u64;
             spans.push(Span::styled(
                 format!("  {:>2}h:{:02}m", h, m),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme().text),
             ));
         }
 
@@ -227,7 +268,7 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
 
         // --- One label row per project ---
         for proj in &day.projects {
-            let color = PALETTE[proj.color_index % PALETTE.len()];
+            let color = theme().chart[proj.color_index % theme().chart.len()];
             let h = proj.hours.floor() as u64;
             let m = ((proj.hours - h as f64) * 60.0).round() as u64;
             lines.push(Line::from(vec![
@@ -235,7 +276,7 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("■ ", Style::default().fg(color)),
                 Span::styled(
                     format!("{} ({}h:{:02}m)", proj.label, h, m),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ),
             ]));
         }
@@ -253,3 +294,195 @@ fn render_daily_panel(frame: &mut Frame, app: &App, area: Rect) {
         .block(Block::default().padding(ratatui::widgets::Padding::new(0, 0, 4, 0)));
     frame.render_widget(paragraph, area);
 }
+
+/// Line chart of the flex balance over the last weeks, around a zero line.
+fn render_flex_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let points = app.flex_trend_points(crate::time_utils::local_today());
+    let days = (FLEX_TREND_WEEKS * 7) as f64;
+    // Symmetric bounds keep the zero line in the middle.
+    let max_hours = points
+        .iter()
+        .map(|(_, hours)| hours.abs())
+        .fold(1.0_f64, f64::max)
+        .ceil();
+    let latest = points.last().map(|(_, hours)| *hours).unwrap_or_default();
+    let color = if latest < 0.0 {
+        theme().error
+    } else {
+        theme().success
+    };
+    let zero_line = [(-days, 0.0), (0.0, 0.0)];
+
+    let datasets = vec![
+        Dataset::default()
+            .graph_type(GraphType::Line)
+            .marker(Marker::Braille)
+            .style(Style::default().fg(theme().muted))
+            .data(&zero_line),
+        Dataset::default()
+            .graph_type(GraphType::Line)
+            .marker(Marker::Braille)
+            .style(Style::default().fg(color))
+            .data(&points),
+    ];
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme().text))
+                .title(Span::styled(
+                    format!(" Flex, last {FLEX_TREND_WEEKS} weeks ({latest:+.1}h) "),
+                    Style::default().fg(theme().text),
+                )),
+        )
+        .x_axis(
+            Axis::default()
+                .bounds([-days, 0.0])
+                .labels([
+                    format!("-{FLEX_TREND_WEEKS}w"),
+                    format!("-{}w", FLEX_TREND_WEEKS / 2),
+                    "today".to_string(),
+                ])
+                .style(Style::default().fg(theme().muted)),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([-max_hours, max_hours])
+                .labels([
+                    format!("{:+.0}h", -max_hours),
+                    "0h".to_string(),
+                    format!("{max_hours:+.0}h"),
+                ])
+                .style(Style::default().fg(theme().muted)),
+        );
+    frame.render_widget(chart, area);
+}
+
+/// One progress bar per project budget: green while on track, yellow once
+/// nearly used and red when exceeded.
+fn render_budgets_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(" Budgets ", Style::default().fg(theme().text)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let label_width = app
+        .budget_statuses
+        .iter()
+        .map(|status| status.budget.project_name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(30);
+    // bar_cols = width - 1 (left margin) - label - 2 (gap) - 24 ("  XX.X/XX.Xh (XXX%) month")
+    let bar_cols = (inner.width as i32 - 1 - label_width as i32 - 2 - 24).max(1) as usize;
+
+    let lines: Vec<Line> = app
+        .budget_statuses
+        .iter()
+        .map(|status| {
+            let consumption = if status.budget.target_hours > 0.0 {
+                status.used_hours / status.budget.target_hours
+            } else {
+                1.0
+            };
+            let color = if consumption > 1.0 {
+                theme().error
+            } else if status.nearly_used {
+                theme().warning
+            } else {
+                theme().success
+            };
+            let filled = ((consumption * bar_cols as f64).round() as usize).min(bar_cols);
+            let name: String = status
+                .budget
+                .project_name
+                .chars()
+                .take(label_width)
+                .collect();
+
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", name, width = label_width),
+                    Style::default().fg(theme().text),
+                ),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled(
+                    "░".repeat(bar_cols - filled),
+                    Style::default().fg(theme().muted),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:.1}/{:.1}h ({:.0}%) {}",
+                        status.used_hours,
+                        status.budget.target_hours,
+                        consumption * 100.0,
+                        status.budget.period
+                    ),
+                    Style::default().fg(color),
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Hours logged in each recent sprint against the capacity planned for the
+/// user in Azure DevOps: green up to the capacity, yellow beyond it.
+fn render_sprints_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().text))
+        .title(Span::styled(
+            " Sprint capacity ",
+            Style::default().fg(theme().text),
+        ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let label_width = app
+        .sprints_with_capacity()
+        .map(|sprint| sprint.iteration_name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(30);
+    // bar_cols = width - 1 (left margin) - label - 2 (gap) - 20 ("  XX.X/XX.Xh (XXX%)")
+    let bar_cols = (inner.width as i32 - 1 - label_width as i32 - 2 - 20).max(1) as usize;
+
+    let lines: Vec<Line> = app
+        .sprints_with_capacity()
+        .map(|sprint| {
+            let usage = crate::app::sprint_capacity_usage(sprint).unwrap_or_default();
+            let color = if usage > 1.0 {
+                theme().warning
+            } else {
+                theme().success
+            };
+            let filled = ((usage * bar_cols as f64).round() as usize).min(bar_cols);
+            let name: String = sprint.iteration_name.chars().take(label_width).collect();
+
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", name, width = label_width),
+                    Style::default().fg(theme().text),
+                ),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled(
+                    "░".repeat(bar_cols - filled),
+                    Style::default().fg(theme().muted),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:.1}/{:.1}h ({:.0}%)",
+                        sprint.logged_hours,
+                        sprint.capacity_hours.unwrap_or_default(),
+                        usage * 100.0
+                    ),
+                    Style::default().fg(color),
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -26,12 +26,12 @@ pub fn render_template_selection(frame: &mut Frame, app: &App, body: Rect) {
         (format!("{}{}", before, after), Some(col))
     };
     let search_border = if app.selection_list_focused {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     };
     let search_box = Paragraph::new(search_text)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().text))
         .alignment(Alignment::Left)
         .block(
             Block::default()
@@ -53,17 +53,21 @@ pub fn render_template_selection(frame: &mut Frame, app: &App, body: Rect) {
         .map(|(i, template)| {
             let selected = i == app.filtered_template_index;
             let desc_style = if selected {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme().accent)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().text)
             };
-            let sub_style = Style::default().fg(Color::DarkGray);
+            let sub_style = Style::default().fg(theme().muted);
 
             let line1 = Line::from(Span::styled(template.description.clone(), desc_style));
-            let line2 = Line::from(Span::styled(
-                format!("{}: {}", template.project, template.activity),
-                sub_style,
-            ));
+            let mut details = format!("{}: {}", template.project, template.activity);
+            if let Some(minutes) = template.duration_minutes {
+                details.push_str(&format!(" · {} min", minutes));
+            }
+            if template.server_id.is_some() {
+                details.push_str(" · saved");
+            }
+            let line2 = Line::from(Span::styled(details, sub_style));
 
             ListItem::new(vec![line1, line2])
         })
@@ -81,9 +85,9 @@ pub fn render_template_selection(frame: &mut Frame, app: &App, body: Rect) {
     };
 
     let list_border = if app.selection_list_focused {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     } else {
-        Style::default().fg(Color::DarkGray)
+        Style::default().fg(theme().muted)
     };
     let list = List::new(items)
         .block(
@@ -99,17 +103,21 @@ pub fn render_template_selection(frame: &mut Frame, app: &App, body: Rect) {
 
     // Controls
     let controls_text = vec![
-        Span::styled("Type", Style::default().fg(Color::Yellow)),
+        Span::styled("Type", Style::default().fg(theme().accent)),
         Span::raw(": Filter  "),
-        Span::styled("Tab", Style::default().fg(Color::Yellow)),
+        Span::styled("Tab", Style::default().fg(theme().accent)),
         Span::raw(": Focus list  "),
-        Span::styled("↑↓/j/k", Style::default().fg(Color::Yellow)),
+        Span::styled("↑↓/j/k", Style::default().fg(theme().accent)),
         Span::raw(": Navigate  "),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled("Enter", Style::default().fg(theme().accent)),
         Span::raw(": Apply  "),
-        Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+L", Style::default().fg(theme().accent)),
+        Span::raw(": Log now  "),
+        Span::styled("Ctrl+D", Style::default().fg(theme().accent)),
+        Span::raw(": Delete  "),
+        Span::styled("Ctrl+X", Style::default().fg(theme().accent)),
         Span::raw(": Clear  "),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled("Esc", Style::default().fg(theme().accent)),
         Span::raw(": Cancel"),
     ];
 
@@ -118,10 +126,10 @@ pub fn render_template_selection(frame: &mut Frame, app: &App, body: Rect) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 ))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
@@ -0,0 +1,214 @@
+use ratatui::style::Color;
+use std::sync::OnceLock;
+
+/// Color palette the UI is drawn with, chosen by `theme` in the config file.
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// The built-in palettes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeName {
+    #[default]
+    Default,
+    Light,
+    HighContrast,
+    Solarized,
+}
+
+impl ThemeName {
+    pub const ALL: [Self; 4] = [
+        Self::Default,
+        Self::Light,
+        Self::HighContrast,
+        Self::Solarized,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Light => "light",
+            Self::HighContrast => "high-contrast",
+            Self::Solarized => "solarized",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        Self::ALL.into_iter().find(|name| name.as_str() == value)
+    }
+}
+
+/// Colors by what they are used for rather than by hue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// Key hints, titles of focused boxes and other highlights.
+    pub accent: Color,
+    /// Regular text and borders.
+    pub text: Color,
+    /// De-emphasized text, hints and unfocused borders.
+    pub muted: Color,
+    /// Secondary text such as notes.
+    pub secondary: Color,
+    /// Errors, overlaps and destructive actions.
+    pub error: Color,
+    /// Saved, approved and on-track states.
+    pub success: Color,
+    /// States that need attention but are not errors.
+    pub warning: Color,
+    /// Projects, activities and other informational labels.
+    pub info: Color,
+    /// Durations, the running timer and selections.
+    pub highlight: Color,
+    /// Text drawn on top of an accent or state color background.
+    pub inverse: Color,
+    /// Colors of pie slices and bars in the statistics view, in order.
+    pub chart: [Color; 12],
+}
+
+impl Theme {
+    pub fn named(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Default => Self {
+                accent: Color::Yellow,
+                text: Color::White,
+                muted: Color::DarkGray,
+                secondary: Color::Gray,
+                error: Color::Red,
+                success: Color::Green,
+                warning: Color::Yellow,
+                info: Color::Cyan,
+                highlight: Color::Magenta,
+                inverse: Color::Black,
+                chart: [
+                    Color::Blue,
+                    Color::Green,
+                    Color::Yellow,
+                    Color::Magenta,
+                    Color::Cyan,
+                    Color::Red,
+                    Color::LightBlue,
+                    Color::LightGreen,
+                    Color::LightYellow,
+                    Color::LightMagenta,
+                    Color::LightCyan,
+                    Color::LightRed,
+                ],
+            },
+            // For terminals with a light background, where yellow and white
+            // text is unreadable.
+            ThemeName::Light => Self {
+                accent: Color::Blue,
+                text: Color::Black,
+                muted: Color::DarkGray,
+                secondary: Color::DarkGray,
+                error: Color::Red,
+                success: Color::Rgb(0, 128, 0),
+                warning: Color::Rgb(175, 95, 0),
+                info: Color::Rgb(0, 95, 135),
+                highlight: Color::Magenta,
+                inverse: Color::White,
+                chart: [
+                    Color::Blue,
+                    Color::Rgb(0, 128, 0),
+                    Color::Rgb(175, 95, 0),
+                    Color::Magenta,
+                    Color::Rgb(0, 95, 135),
+                    Color::Red,
+                    Color::Rgb(95, 95, 175),
+                    Color::Rgb(95, 135, 0),
+                    Color::Rgb(135, 95, 0),
+                    Color::Rgb(135, 0, 135),
+                    Color::Rgb(0, 135, 135),
+                    Color::Rgb(175, 0, 0),
+                ],
+            },
+            ThemeName::HighContrast => Self {
+                accent: Color::LightYellow,
+                text: Color::White,
+                muted: Color::Gray,
+                secondary: Color::White,
+                error: Color::LightRed,
+                success: Color::LightGreen,
+                warning: Color::LightYellow,
+                info: Color::LightCyan,
+                highlight: Color::LightMagenta,
+                inverse: Color::Black,
+                chart: [
+                    Color::LightBlue,
+                    Color::LightGreen,
+                    Color::LightYellow,
+                    Color::LightMagenta,
+                    Color::LightCyan,
+                    Color::LightRed,
+                    Color::White,
+                    Color::Blue,
+                    Color::Green,
+                    Color::Yellow,
+                    Color::Magenta,
+                    Color::Cyan,
+                ],
+            },
+            ThemeName::Solarized => {
+                const BASE03: Color = Color::Rgb(0, 43, 54);
+                const BASE01: Color = Color::Rgb(88, 110, 117);
+                const BASE0: Color = Color::Rgb(131, 148, 150);
+                const BASE1: Color = Color::Rgb(147, 161, 161);
+                const BASE2: Color = Color::Rgb(238, 232, 213);
+                const YELLOW: Color = Color::Rgb(181, 137, 0);
+                const ORANGE: Color = Color::Rgb(203, 75, 22);
+                const RED: Color = Color::Rgb(220, 50, 47);
+                const MAGENTA: Color = Color::Rgb(211, 54, 130);
+                const VIOLET: Color = Color::Rgb(108, 113, 196);
+                const BLUE: Color = Color::Rgb(38, 139, 210);
+                const CYAN: Color = Color::Rgb(42, 161, 152);
+                const GREEN: Color = Color::Rgb(133, 153, 0);
+                Self {
+                    accent: YELLOW,
+                    text: BASE1,
+                    muted: BASE01,
+                    secondary: BASE0,
+                    error: RED,
+                    success: GREEN,
+                    warning: ORANGE,
+                    info: CYAN,
+                    highlight: MAGENTA,
+                    inverse: BASE03,
+                    chart: [
+                        BLUE, GREEN, YELLOW, MAGENTA, CYAN, RED, VIOLET, ORANGE, BASE1, BASE0,
+                        BASE2, BASE01,
+                    ],
+                }
+            }
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::named(ThemeName::Default)
+    }
+}
+
+/// Draw the UI with the `name` palette. Returns false if a theme was
+/// already set.
+pub fn set_theme(name: ThemeName) -> bool {
+    THEME.set(Theme::named(name)).is_ok()
+}
+
+/// The palette in use, the default one until [`set_theme`] is called.
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        for name in ThemeName::ALL {
+            assert_eq!(ThemeName::parse(name.as_str()), Some(name));
+        }
+        assert_eq!(ThemeName::parse(" Solarized "), Some(ThemeName::Solarized));
+        assert_eq!(ThemeName::parse("dracula"), None);
+    }
+}
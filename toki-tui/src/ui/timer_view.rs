@@ -1,5 +1,6 @@
 use super::*;
-use crate::app::TimerSize;
+use crate::app::{ClickTarget, FocusedBox, TimerSize};
+use crate::types::BannerSeverity;
 
 pub fn render_timer_view(frame: &mut Frame, app: &mut App, body: Rect) {
     // Timer box height depends on timer size
@@ -8,25 +9,142 @@ pub fn render_timer_view(frame: &mut Frame, app: &mut App, body: Rect) {
         crate::app::TimerSize::Large => 11,
     };
 
+    let checklist = app.week_checklist();
+    let checklist_height = if checklist.is_empty() {
+        0
+    } else {
+        checklist.len() as u16 + 2
+    };
+
+    let suggestions_height = if app.calendar_suggestions.is_empty() {
+        0
+    } else {
+        app.calendar_suggestions.len().min(MAX_SUGGESTION_ROWS) as u16 + 2
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
-            Constraint::Length(timer_height), // Timer display (dynamic)
-            Constraint::Length(3),            // Project info
-            Constraint::Length(3),            // Description
-            Constraint::Min(5),               // Today's history
-            Constraint::Length(3),            // Status
-            Constraint::Length(4),            // Controls (2 rows)
+            Constraint::Length(timer_height),       // Timer display (dynamic)
+            Constraint::Length(3),                  // Project info
+            Constraint::Length(3),                  // Description
+            Constraint::Length(checklist_height),   // Attest checklist (end of week only)
+            Constraint::Length(suggestions_height), // Calendar suggestions (when any)
+            Constraint::Min(5),                     // Today's history
+            Constraint::Length(3),                  // Status
+            Constraint::Length(4),                  // Controls (2 rows)
         ])
         .split(body);
 
+    app.register_click_target(chunks[0], ClickTarget::FocusBox(FocusedBox::Timer));
+    app.register_click_target(
+        chunks[1],
+        ClickTarget::FocusBox(FocusedBox::ProjectActivity),
+    );
+    app.register_click_target(chunks[2], ClickTarget::FocusBox(FocusedBox::Description));
+    if !app.calendar_suggestions.is_empty() {
+        app.register_click_target(chunks[4], ClickTarget::FocusBox(FocusedBox::Suggestions));
+    }
+    app.register_click_target(chunks[5], ClickTarget::FocusBox(FocusedBox::Today));
+
     render_timer(frame, chunks[0], app);
     render_project(frame, chunks[1], app);
     render_description(frame, chunks[2], app);
-    super::history_panel::render_this_week_history(frame, chunks[3], app);
-    render_status(frame, chunks[4], app);
-    render_controls(frame, chunks[5], app);
+    if !checklist.is_empty() {
+        render_week_checklist(frame, chunks[3], &checklist);
+    }
+    if !app.calendar_suggestions.is_empty() {
+        render_calendar_suggestions(frame, chunks[4], app);
+    }
+    super::history_panel::render_this_week_history(frame, chunks[5], app);
+    render_status(frame, chunks[6], app);
+    render_controls(frame, chunks[7], app);
+}
+
+/// Calendar suggestions listed at once; the list scrolls past this.
+const MAX_SUGGESTION_ROWS: usize = 4;
+
+fn render_calendar_suggestions(frame: &mut Frame, area: Rect, app: &App) {
+    let is_focused = app.focused_box == crate::app::FocusedBox::Suggestions;
+    let first = app
+        .selected_suggestion_index
+        .saturating_sub(MAX_SUGGESTION_ROWS - 1);
+
+    let lines: Vec<Line> = app
+        .calendar_suggestions
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(MAX_SUGGESTION_ROWS)
+        .map(|(index, suggestion)| {
+            let selected = is_focused && index == app.selected_suggestion_index;
+            let target = match (&suggestion.project_name, &suggestion.activity_name) {
+                (Some(project), Some(activity)) => format!("  {} / {}", project, activity),
+                _ => "  (uses the selected project)".to_string(),
+            };
+            let style = if selected {
+                Style::default().fg(theme().inverse).bg(theme().highlight)
+            } else {
+                Style::default().fg(theme().text)
+            };
+            Line::from(vec![
+                Span::styled(crate::app::suggestion_label(suggestion), style),
+                Span::styled(target, Style::default().fg(theme().muted)),
+            ])
+        })
+        .collect();
+
+    let widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Suggested from calendar ({}) ",
+                app.calendar_suggestions.len()
+            ))
+            .title(
+                Line::from(Span::styled(
+                    " Enter: log  D: decline ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .border_style(if is_focused {
+                Style::default().fg(theme().highlight)
+            } else {
+                Style::default().fg(theme().info)
+            })
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(widget, area);
+}
+
+fn render_week_checklist(frame: &mut Frame, area: Rect, items: &[crate::app::ChecklistItem]) {
+    let lines: Vec<Line> = items
+        .iter()
+        .map(|item| {
+            Line::from(vec![
+                Span::styled("• ", Style::default().fg(theme().accent)),
+                Span::styled(item.label(), Style::default().fg(theme().text)),
+            ])
+        })
+        .collect();
+
+    let widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Before attesting this week ")
+            .title(
+                Line::from(Span::styled(
+                    " H: review in history ",
+                    Style::default().fg(theme().muted),
+                ))
+                .alignment(Alignment::Right),
+            )
+            .border_style(Style::default().fg(theme().accent))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(widget, area);
 }
 
 fn render_timer(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
@@ -34,9 +152,9 @@ fn render_timer(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let is_focused = app.focused_box == crate::app::FocusedBox::Timer;
 
     let border_style = if is_focused {
-        Style::default().fg(Color::Magenta)
+        Style::default().fg(theme().highlight)
     } else if is_running {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     } else {
         Style::default()
     };
@@ -53,15 +171,19 @@ fn render_timer(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             };
 
             let timer = Paragraph::new(timer_text)
-                .style(Style::default().fg(Color::White))
+                .style(Style::default().fg(theme().text))
                 .alignment(Alignment::Left)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" Timer ")
-                        .border_style(border_style)
-                        .padding(ratatui::widgets::Padding::horizontal(1)),
-                );
+                .block(with_paused_title(
+                    with_pomodoro_title(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(" Timer ")
+                            .border_style(border_style)
+                            .padding(ratatui::widgets::Padding::horizontal(1)),
+                        app,
+                    ),
+                    app,
+                ));
 
             frame.render_widget(timer, area);
         }
@@ -87,25 +209,72 @@ fn render_timer(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
                 status,
-                Style::default().fg(Color::White),
+                Style::default().fg(theme().text),
             )]));
 
             // Add bottom padding
             lines.push(Line::from(""));
 
-            let timer = Paragraph::new(lines).alignment(Alignment::Center).block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Timer ")
-                    .border_style(border_style)
-                    .padding(ratatui::widgets::Padding::horizontal(1)),
-            );
+            let timer =
+                Paragraph::new(lines)
+                    .alignment(Alignment::Center)
+                    .block(with_paused_title(
+                        with_pomodoro_title(
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .title(" Timer ")
+                                .border_style(border_style)
+                                .padding(ratatui::widgets::Padding::horizontal(1)),
+                            app,
+                        ),
+                        app,
+                    ));
 
             frame.render_widget(timer, area);
         }
     }
 }
 
+/// Show the paused timers below the timer box when any are paused.
+fn with_paused_title<'a>(block: Block<'a>, app: &App) -> Block<'a> {
+    match app.paused_timers_summary() {
+        Some(summary) => block.title_bottom(Span::styled(
+            format!(" ⏸ {} ", summary),
+            Style::default().fg(theme().muted),
+        )),
+        None => block,
+    }
+}
+
+/// Show the pomodoro interval in the timer box's title when pomodoro mode is on.
+fn with_pomodoro_title<'a>(block: Block<'a>, app: &App) -> Block<'a> {
+    if !app.pomodoro_enabled() {
+        return block;
+    }
+
+    let title = match app.pomodoro_status() {
+        Some(status) => {
+            let (label, color) = match status.phase {
+                crate::app::PomodoroPhase::Work => ("Work", theme().error),
+                crate::app::PomodoroPhase::Break => ("Break", theme().success),
+            };
+            let remaining = status.remaining.as_secs();
+            Span::styled(
+                format!(
+                    " 🍅 {} {:02}:{:02} left · {} done ",
+                    label,
+                    remaining / 60,
+                    remaining % 60,
+                    status.completed
+                ),
+                Style::default().fg(color),
+            )
+        }
+        None => Span::styled(" 🍅 Pomodoro ", Style::default().fg(theme().muted)),
+    };
+    block.title(Line::from(title).alignment(Alignment::Right))
+}
+
 fn render_project(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let project = app.current_project_name();
     let activity = app.current_activity_name();
@@ -116,13 +285,13 @@ fn render_project(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
 
     let (border_style, text_color) = if is_focused {
         // Magenta border and white text when focused (takes priority)
-        (Style::default().fg(Color::Magenta), Color::White)
+        (Style::default().fg(theme().highlight), theme().text)
     } else if !is_empty {
         // White border when project/activity selected and not focused
-        (Style::default().fg(Color::White), Color::White)
+        (Style::default().fg(theme().text), theme().text)
     } else {
         // Default border when empty and not focused
-        (Style::default(), Color::White)
+        (Style::default(), theme().text)
     };
 
     // Title with underlined P
@@ -153,10 +322,10 @@ fn render_description(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
 
     let is_focused = app.focused_box == crate::app::FocusedBox::Description;
     let border_style = if is_focused {
-        Style::default().fg(Color::Magenta)
+        Style::default().fg(theme().highlight)
     } else if !is_empty || has_log {
         // White border when note has content (or a log is attached) and not focused
-        Style::default().fg(Color::White)
+        Style::default().fg(theme().text)
     } else {
         // Default when empty and not focused
         Style::default()
@@ -170,11 +339,11 @@ fn render_description(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
     ];
 
     // Build the paragraph content: summary text + optional muted "[…]" log indicator
-    let mut spans: Vec<Span> = vec![Span::styled(description, Style::default().fg(Color::White))];
+    let mut spans: Vec<Span> = vec![Span::styled(description, Style::default().fg(theme().text))];
     if has_log {
         spans.push(Span::styled(
             " [\u{2026}]",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().muted),
         ));
     }
 
@@ -214,11 +383,16 @@ pub fn render_status(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
         || status_lower.contains("resumed");
 
     let (border_style, text_color) = if is_error {
-        (Style::default().fg(Color::Red), Color::Red)
+        (Style::default().fg(theme().error), theme().error)
     } else if is_success {
-        (Style::default().fg(Color::Green), Color::Green)
+        (Style::default().fg(theme().success), theme().success)
     } else {
-        (Style::default().fg(Color::White), Color::White)
+        (Style::default().fg(theme().text), theme().text)
+    };
+
+    let title = match app.offline_queue.len() {
+        0 => " Status ".to_string(),
+        queued => format!(" Status · {} change(s) queued offline ", queued),
     };
 
     let status = Paragraph::new(status_text)
@@ -227,7 +401,7 @@ pub fn render_status(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Status ")
+                .title(title)
                 .border_style(border_style)
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
@@ -237,46 +411,65 @@ pub fn render_status(frame: &mut Frame, area: ratatui::layout::Rect, app: &App)
 
 fn render_controls(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let line1 = vec![
-        Span::styled("Space", Style::default().fg(Color::Yellow)),
+        Span::styled("Space", Style::default().fg(theme().accent)),
         Span::raw(": Start/Stop  "),
-        Span::styled("Ctrl+S", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+S", Style::default().fg(theme().accent)),
         Span::raw(": Save  "),
-        Span::styled("Ctrl+R", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+R", Style::default().fg(theme().accent)),
         Span::raw(": Resume  "),
-        Span::styled("Ctrl+L", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+L", Style::default().fg(theme().accent)),
         Span::raw(": Open log  "),
-        Span::styled("Ctrl+X", Style::default().fg(Color::Yellow)),
+        Span::styled("Ctrl+X", Style::default().fg(theme().accent)),
         Span::raw(": Clear  "),
-        Span::styled("Tab / ↑↓ / j/k", Style::default().fg(Color::Yellow)),
+        Span::styled("Tab / ↑↓ / j/k", Style::default().fg(theme().accent)),
         Span::raw(": Navigate  "),
-        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::styled("Enter", Style::default().fg(theme().accent)),
         Span::raw(": Edit"),
     ];
 
     let mut line2 = vec![
-        Span::styled("P", Style::default().fg(Color::Yellow)),
+        Span::styled("P", Style::default().fg(theme().accent)),
         Span::raw(": Project  "),
-        Span::styled("N", Style::default().fg(Color::Yellow)),
+        Span::styled("N", Style::default().fg(theme().accent)),
         Span::raw(": Note  "),
     ];
 
     if !app.templates.is_empty() {
-        line2.push(Span::styled("T", Style::default().fg(Color::Yellow)));
+        line2.push(Span::styled("T", Style::default().fg(theme().accent)));
         line2.push(Span::raw(": Template  "));
     }
+    if app.timer_running_elsewhere().is_some() {
+        line2.push(Span::styled("O", Style::default().fg(theme().accent)));
+        line2.push(Span::raw(": Take over  "));
+    }
+    if app.timer_state == crate::app::TimerState::Running {
+        line2.push(Span::styled("I", Style::default().fg(theme().accent)));
+        line2.push(Span::raw(": Pause  "));
+    } else if !app.paused_timers.is_empty() {
+        line2.push(Span::styled("Shift+I", Style::default().fg(theme().accent)));
+        line2.push(Span::raw(": Resume paused  "));
+    }
 
     line2.extend([
-        Span::styled("H", Style::default().fg(Color::Yellow)),
+        Span::styled("H", Style::default().fg(theme().accent)),
         Span::raw(": History  "),
-        Span::styled("S", Style::default().fg(Color::Yellow)),
+        Span::styled("S", Style::default().fg(theme().accent)),
         Span::raw(": Statistics  "),
-        Span::styled("X", Style::default().fg(Color::Yellow)),
+        Span::styled("C", Style::default().fg(theme().accent)),
+        Span::raw(": Calendar  "),
+        Span::styled("V", Style::default().fg(theme().accent)),
+        Span::raw(": Reviews  "),
+        Span::styled("F", Style::default().fg(theme().accent)),
+        Span::raw(": Fill days  "),
+        Span::styled("X", Style::default().fg(theme().accent)),
         Span::raw(": Toggle size  "),
-        Span::styled("Z", Style::default().fg(Color::Yellow)),
+        Span::styled("Z", Style::default().fg(theme().accent)),
         Span::raw(": Zen mode  "),
-        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::styled("M", Style::default().fg(theme().accent)),
+        Span::raw(": Pomodoro  "),
+        Span::styled("Esc", Style::default().fg(theme().accent)),
         Span::raw(": Exit edit  "),
-        Span::styled("Q", Style::default().fg(Color::Yellow)),
+        Span::styled("Q", Style::default().fg(theme().accent)),
         Span::raw(": Quit"),
     ]);
 
@@ -287,9 +480,9 @@ fn render_controls(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 .borders(Borders::ALL)
                 .title(Line::from(vec![Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme().muted),
                 )]))
-                .border_style(Style::default().fg(Color::DarkGray))
+                .border_style(Style::default().fg(theme().muted))
                 .padding(ratatui::widgets::Padding::horizontal(1)),
         );
 
@@ -408,12 +601,12 @@ fn get_digit_pattern(digit: char) -> &'static [u8; DIGIT_SIZE * DIGIT_SIZE] {
 
 /// Render time string as large block digits
 fn render_large_time(time_str: &str) -> Vec<Line<'_>> {
-    render_large_time_colored(time_str, Color::White)
+    render_large_time_colored(time_str, theme().text)
 }
 
 /// Like `render_large_time` but rendered in DarkGray (for zen mode).
 pub(super) fn render_large_time_muted(time_str: &str) -> Vec<Line<'_>> {
-    render_large_time_colored(time_str, Color::DarkGray)
+    render_large_time_colored(time_str, theme().muted)
 }
 
 fn render_large_time_colored(time_str: &str, color: Color) -> Vec<Line<'_>> {
@@ -498,9 +691,9 @@ pub fn render_compact_stats(frame: &mut Frame, area: Rect, app: &mut App) {
     let rem_h = remaining_hours.floor() as u64;
     let rem_m = ((remaining_hours - rem_h as f64) * 60.0).round() as u64;
 
-    let muted = Style::default().fg(Color::DarkGray);
-    let white = Style::default().fg(Color::White);
-    let yellow = Style::default().fg(Color::Yellow);
+    let muted = Style::default().fg(theme().muted);
+    let white = Style::default().fg(theme().text);
+    let yellow = Style::default().fg(theme().accent);
     let stats_text = Line::from(vec![
         Span::raw("   "),
         Span::styled("This week:", yellow),
@@ -542,8 +735,8 @@ pub fn render_compact_stats(frame: &mut Frame, area: Rect, app: &mut App) {
         height: 1,
     };
     let throbber = throbber_widgets_tui::Throbber::default()
-        .style(Style::default().fg(Color::Yellow))
-        .throbber_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().fg(theme().accent))
+        .throbber_style(Style::default().fg(theme().accent))
         .throbber_set(throbber_widgets_tui::BRAILLE_SIX)
         .use_type(if app.is_loading {
             throbber_widgets_tui::WhichUse::Spin
@@ -552,7 +745,7 @@ pub fn render_compact_stats(frame: &mut Frame, area: Rect, app: &mut App) {
         });
     frame.render_stateful_widget(throbber, throbber_area, &mut app.throbber_state);
     frame.render_widget(
-        Paragraph::new(Span::styled(LABEL, Style::default().fg(Color::Yellow))),
+        Paragraph::new(Span::styled(LABEL, Style::default().fg(theme().accent))),
         label_area,
     );
     let (gauge_col, stats_col) = (cols[1], cols[2]);
@@ -564,10 +757,53 @@ pub fn render_compact_stats(frame: &mut Frame, area: Rect, app: &mut App) {
         .label("")
         .filled_symbol(ratatui::symbols::line::THICK_HORIZONTAL)
         .unfilled_symbol("╌")
-        .filled_style(Style::default().fg(Color::Cyan))
-        .unfilled_style(Style::default().fg(Color::DarkGray));
+        .filled_style(Style::default().fg(theme().info))
+        .unfilled_style(Style::default().fg(theme().muted));
     frame.render_widget(gauge, gauge_col);
 
     // --- Stats labels (right of gauge) ---
     frame.render_widget(Paragraph::new(stats_text), stats_col);
+
+    // --- Notices in the padding row: server banner left, timer owner right ---
+    let notice_row = Rect {
+        y: rows[0].y,
+        ..area
+    };
+    let owner = app.timer_running_elsewhere().map(|device| {
+        Line::from(vec![
+            Span::styled("● ", yellow),
+            Span::styled(format!("Timer running on {}", device), white),
+            Span::styled(" — press O to take over ", muted),
+        ])
+    });
+    let owner_width = owner.as_ref().map_or(0, |line| line.width() as u16);
+    let notice_cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(owner_width)])
+        .split(notice_row);
+
+    if let Some(banner) = &app.status_banner {
+        let color = match banner.severity {
+            BannerSeverity::Info => theme().info,
+            BannerSeverity::Warning => theme().warning,
+            BannerSeverity::Critical => theme().error,
+        };
+        let label = if banner.maintenance {
+            " Maintenance: "
+        } else {
+            " "
+        };
+        let notice = Line::from(vec![
+            Span::styled(" ! ", Style::default().fg(theme().inverse).bg(color)),
+            Span::styled(label, Style::default().fg(color)),
+            Span::styled(banner.message.clone(), white),
+        ]);
+        frame.render_widget(Paragraph::new(notice), notice_cols[0]);
+    }
+    if let Some(owner) = owner {
+        frame.render_widget(
+            Paragraph::new(owner).alignment(Alignment::Right),
+            notice_cols[1],
+        );
+    }
 }
@@ -1,9 +1,11 @@
+use super::theme::theme;
 use super::utils::to_local_time;
 use crate::app::{EntryEditField, EntryEditState};
 use crate::log_notes;
+use crate::rounding::Rounding;
 use crate::types::TimeEntry;
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
 };
 
@@ -73,33 +75,36 @@ pub fn build_display_row(
     is_focused: bool,
     is_overlapping: bool,
     available_width: u16,
+    rounding: Option<Rounding>,
 ) -> Line<'_> {
     let is_locked = entry.status.is_locked();
 
-    // Base colors - red for overlapping, normal otherwise (locked entries keep normal colors)
-    let time_color = if is_overlapping {
-        Color::Red
-    } else {
-        Color::Yellow
-    };
-    let duration_color = if is_overlapping {
-        Color::Red
-    } else {
-        Color::Magenta
+    // Shown rounded when rounding is display-only
+    let (start_time, end_time) = match (rounding, entry.start_time, entry.end_time) {
+        (Some(rounding), Some(start), Some(end)) => {
+            let (start, end) = rounding.round(start, end);
+            (Some(start), Some(end))
+        }
+        _ => (entry.start_time, entry.end_time),
     };
-    let project_color = if is_overlapping {
-        Color::Red
-    } else {
-        Color::Cyan
-    };
-    let note_color = if is_overlapping {
-        Color::Red
-    } else {
-        Color::Gray
+
+    // Base colors - greyed out for locked, red for overlapping, normal otherwise
+    let base_color = |normal| {
+        if is_locked {
+            theme().muted
+        } else if is_overlapping {
+            theme().error
+        } else {
+            normal
+        }
     };
+    let time_color = base_color(theme().accent);
+    let duration_color = base_color(theme().highlight);
+    let project_color = base_color(theme().info);
+    let note_color = base_color(theme().secondary);
 
     // Calculate duration in [00h:05m] format
-    let duration_display = if let (Some(start), Some(end)) = (entry.start_time, entry.end_time) {
+    let duration_display = if let (Some(start), Some(end)) = (start_time, end_time) {
         let total_minutes = (end - start).whole_minutes();
         let hours = total_minutes / 60;
         let minutes = total_minutes % 60;
@@ -116,8 +121,7 @@ pub fn build_display_row(
     let has_log = log_notes::extract_id(note_raw).is_some();
 
     // Start time
-    let start_str = entry
-        .start_time
+    let start_str = start_time
         .map(|t| {
             let local = to_local_time(t).time();
             format!("{:02}:{:02}", local.hour(), local.minute())
@@ -125,7 +129,7 @@ pub fn build_display_row(
         .unwrap_or_else(|| "XX:XX".to_string());
 
     // End time
-    let end_time_str = if let Some(end_time) = entry.end_time {
+    let end_time_str = if let Some(end_time) = end_time {
         let t = to_local_time(end_time).time();
         format!("{:02}:{:02}", t.hour(), t.minute())
     } else {
@@ -148,34 +152,30 @@ pub fn build_display_row(
     // Locked takes visual precedence over overlap — attested entries cannot be
     // edited regardless of overlap, so the lock indicator is more actionable.
     if is_locked {
-        spans.push(Span::styled("⊘ ", Style::default().fg(Color::Red)));
+        spans.push(Span::styled("⊘ ", Style::default().fg(theme().error)));
     } else if is_overlapping {
-        spans.push(Span::styled("⚠ ", Style::default().fg(Color::Red)));
+        spans.push(Span::styled("⚠ ", Style::default().fg(theme().error)));
     }
 
     // Show time range — use real times if available, otherwise a dimmed placeholder
     let has_times = entry.start_time.is_some() || entry.end_time.is_some();
     spans.push(Span::styled(
         format!("{} - {} ", start_str, end_time_str),
-        Style::default().fg(if has_times {
-            time_color
-        } else {
-            Color::DarkGray
-        }),
+        Style::default().fg(if has_times { time_color } else { theme().muted }),
     ));
 
     spans.extend(vec![
         // Duration
         Span::styled(duration_display, Style::default().fg(duration_color)),
         // Pipe separator
-        Span::styled(" | ", Style::default().fg(Color::DarkGray)),
+        Span::styled(" | ", Style::default().fg(theme().muted)),
         // Project - Activity (possibly truncated)
         Span::styled(proj_act_display, Style::default().fg(project_color)),
     ]);
 
     // Add annotation if present
     if !note_display.is_empty() {
-        spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(" | ", Style::default().fg(theme().muted)));
         spans.push(Span::styled(note_display, Style::default().fg(note_color)));
     }
 
@@ -183,15 +183,15 @@ pub fn build_display_row(
     if has_log {
         spans.push(Span::styled(
             " [\u{2026}]",
-            Style::default().fg(Color::White),
+            Style::default().fg(theme().text),
         ));
     }
 
     // Apply focus styling: white background with black text
     if is_focused {
         let focused_style = Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD);
         return Line::from(vec![Span::styled(
             spans.iter().map(|s| s.content.as_ref()).collect::<String>(),
@@ -263,31 +263,34 @@ pub fn build_running_timer_display_row(
         return Line::from(Span::styled(
             text,
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::White)
+                .fg(theme().inverse)
+                .bg(theme().text)
                 .add_modifier(Modifier::BOLD),
         ));
     }
 
     // Non-focused: color each part
     let mut spans: Vec<Span<'static>> = vec![
-        Span::styled("▶ ", Style::default().fg(Color::Green)),
+        Span::styled("▶ ", Style::default().fg(theme().success)),
         Span::styled(
             format!("{} - HH:MM ", start_str),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme().accent),
         ),
-        Span::styled(duration_str, Style::default().fg(Color::Magenta)),
-        Span::styled(" | ", Style::default().fg(Color::DarkGray)),
-        Span::styled(proj_act_display, Style::default().fg(Color::Cyan)),
+        Span::styled(duration_str, Style::default().fg(theme().highlight)),
+        Span::styled(" | ", Style::default().fg(theme().muted)),
+        Span::styled(proj_act_display, Style::default().fg(theme().info)),
     ];
     if !note_display.is_empty() {
-        spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
-        spans.push(Span::styled(note_display, Style::default().fg(Color::Gray)));
+        spans.push(Span::styled(" | ", Style::default().fg(theme().muted)));
+        spans.push(Span::styled(
+            note_display,
+            Style::default().fg(theme().secondary),
+        ));
     }
     if has_log {
         spans.push(Span::styled(
             " [\u{2026}]",
-            Style::default().fg(Color::White),
+            Style::default().fg(theme().text),
         ));
     }
     Line::from(spans)
@@ -297,37 +300,37 @@ pub fn build_running_timer_edit_row(edit_state: &EntryEditState) -> Line<'_> {
     let mut spans = vec![];
 
     // ▶ prefix before start time (no space)
-    spans.push(Span::styled("▶ ", Style::default().fg(Color::Green)));
+    spans.push(Span::styled("▶ ", Style::default().fg(theme().success)));
 
     // Start time field
     let start_value = time_input_display(&edit_state.start_time_input);
     let start_style = match edit_state.focused_field {
         EntryEditField::StartTime => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(start_value, start_style));
 
     // Separator + HH:MM placeholder for end time
     spans.push(Span::styled(
         " - HH:MM | ",
-        Style::default().fg(Color::White),
+        Style::default().fg(theme().text),
     ));
 
     // Project field
     let project_value = format!("[{}]", edit_state.project_name.as_deref().unwrap_or("None"));
     let project_style = match edit_state.focused_field {
         EntryEditField::Project => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(project_value, project_style));
 
-    spans.push(Span::styled(": ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(": ", Style::default().fg(theme().text)));
 
     // Activity field
     let activity_value = format!(
@@ -336,22 +339,22 @@ pub fn build_running_timer_edit_row(edit_state: &EntryEditState) -> Line<'_> {
     );
     let activity_style = match edit_state.focused_field {
         EntryEditField::Activity => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(activity_value, activity_style));
 
-    spans.push(Span::styled(" | ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(" | ", Style::default().fg(theme().text)));
 
     // Note field — display only (editing opens the full-screen Notes overlay via Enter)
     let note_style = match edit_state.focused_field {
         EntryEditField::Note => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     let display = log_notes::strip_tag(&edit_state.note.value);
     let note_value = format!("[{}]", if display.is_empty() { "Empty" } else { display });
@@ -371,43 +374,43 @@ pub fn build_edit_row<'a>(
     let start_value = time_input_display(&edit_state.start_time_input);
     let start_style = match edit_state.focused_field {
         EntryEditField::StartTime => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(start_value, start_style));
 
     // Separator
-    spans.push(Span::styled(" - ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(" - ", Style::default().fg(theme().text)));
 
     // End time field
     let end_value = time_input_display(&edit_state.end_time_input);
     let end_style = match edit_state.focused_field {
         EntryEditField::EndTime => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(end_value, end_style));
 
     // Separator
-    spans.push(Span::styled(" | ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(" | ", Style::default().fg(theme().text)));
 
     // Project field
     let project_value = format!("[{}]", edit_state.project_name.as_deref().unwrap_or("None"));
     let project_style = match edit_state.focused_field {
         EntryEditField::Project => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(project_value, project_style));
 
     // Separator
-    spans.push(Span::styled(": ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(": ", Style::default().fg(theme().text)));
 
     // Activity field
     let activity_value = format!(
@@ -416,23 +419,23 @@ pub fn build_edit_row<'a>(
     );
     let activity_style = match edit_state.focused_field {
         EntryEditField::Activity => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     spans.push(Span::styled(activity_value, activity_style));
 
     // Separator
-    spans.push(Span::styled(" | ", Style::default().fg(Color::White)));
+    spans.push(Span::styled(" | ", Style::default().fg(theme().text)));
 
     // Note field — display only (editing opens the full-screen Notes overlay via Enter)
     let note_style = match edit_state.focused_field {
         EntryEditField::Note => Style::default()
-            .fg(Color::Black)
-            .bg(Color::White)
+            .fg(theme().inverse)
+            .bg(theme().text)
             .add_modifier(Modifier::BOLD),
-        _ => Style::default().fg(Color::White),
+        _ => Style::default().fg(theme().text),
     };
     let display = log_notes::strip_tag(&edit_state.note.value);
     let note_value = format!("[{}]", if display.is_empty() { "Empty" } else { display });
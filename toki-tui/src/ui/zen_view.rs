@@ -5,7 +5,7 @@ const CLOCK_ROWS: u16 = 5;
 
 pub fn render_zen_view(frame: &mut Frame, app: &App) {
     let area = frame.area();
-    let muted = Style::default().fg(Color::DarkGray);
+    let muted = Style::default().fg(theme().muted);
 
     let is_running = matches!(app.timer_state, crate::app::TimerState::Running);
 
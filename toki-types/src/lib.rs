@@ -0,0 +1,17 @@
+//! Wire types shared between toki-api and its clients.
+//!
+//! Every type here derives both `Serialize` and `Deserialize` so the server
+//! and the TUI encode and decode the exact same JSON shapes. Rules both sides
+//! must apply the same way, like entry time rounding, live here too.
+
+pub mod rounding;
+pub mod status;
+pub mod time_tracking;
+pub mod users;
+pub mod work_items;
+
+pub use rounding::*;
+pub use status::*;
+pub use time_tracking::*;
+pub use users::*;
+pub use work_items::*;
@@ -0,0 +1,82 @@
+//! Rounding of entry times, applied by toki-api when saving and by clients
+//! when showing entries rounded, so both arrive at the same times.
+
+use time::{Duration, OffsetDateTime};
+
+/// Minutes entry times can be rounded to.
+pub const ROUNDING_INCREMENTS: [u16; 3] = [5, 15, 30];
+
+/// `start` and `end` rounded to the nearest `increment_minutes` on the
+/// user's clock, halfway rounding up. An entry that would round away to
+/// nothing lasts one increment.
+///
+/// `to_local` gives the user's local time of an instant.
+pub fn round_entry_times(
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    increment_minutes: u16,
+    to_local: impl Fn(OffsetDateTime) -> OffsetDateTime,
+) -> (OffsetDateTime, OffsetDateTime) {
+    let increment = Duration::minutes(i64::from(increment_minutes));
+    let start = round_instant(start, increment, &to_local);
+    let end = round_instant(end, increment, &to_local).max(start + increment);
+    (start, end)
+}
+
+fn round_instant(
+    instant: OffsetDateTime,
+    increment: Duration,
+    to_local: impl Fn(OffsetDateTime) -> OffsetDateTime,
+) -> OffsetDateTime {
+    let instant = instant.replace_nanosecond(0).unwrap_or(instant);
+    let local = to_local(instant).time();
+    let since_midnight =
+        i64::from(local.hour()) * 3600 + i64::from(local.minute()) * 60 + i64::from(local.second());
+    let step = increment.whole_seconds();
+    let past = since_midnight % step;
+    let shift = if past * 2 >= step { step - past } else { -past };
+    instant + Duration::seconds(shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, UtcOffset};
+
+    use super::*;
+
+    fn at(hour: u8, minute: u8, second: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, 14)
+            .unwrap()
+            .with_hms(hour, minute, second)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn utc(instant: OffsetDateTime) -> OffsetDateTime {
+        instant
+    }
+
+    #[test]
+    fn rounds_to_the_nearest_increment_halfway_up() {
+        let (start, end) = round_entry_times(at(8, 7, 30), at(16, 52, 29), 15, utc);
+        assert_eq!(start, at(8, 15, 0));
+        assert_eq!(end, at(16, 45, 0));
+    }
+
+    #[test]
+    fn short_entries_keep_one_increment() {
+        let (start, end) = round_entry_times(at(8, 1, 0), at(8, 9, 0), 30, utc);
+        assert_eq!(start, at(8, 0, 0));
+        assert_eq!(end, at(8, 30, 0));
+    }
+
+    #[test]
+    fn rounds_on_the_users_clock() {
+        // 08:45 at UTC+5:45 rounds to 09:00 there, not to 03:00 UTC.
+        let kathmandu = UtcOffset::from_hms(5, 45, 0).unwrap();
+        let (start, _) = round_entry_times(at(3, 0, 0), at(5, 0, 0), 30, |instant| {
+            instant.to_offset(kathmandu)
+        });
+        assert_eq!(start, at(3, 15, 0));
+    }
+}
@@ -0,0 +1,40 @@
+//! Types for the public `/status` endpoint.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Response for `GET /status`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusResponse {
+    /// Banner to show right now, if any.
+    pub banner: Option<StatusBanner>,
+    /// Whether time tracking is paused for maintenance right now.
+    pub maintenance: bool,
+}
+
+/// Announcement shown at the top of every client. Also the body admins
+/// send to `PUT /status/banner`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusBanner {
+    pub message: String,
+    pub severity: BannerSeverity,
+    /// When the banner is first shown. `None` means right away.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub starts_at: Option<OffsetDateTime>,
+    /// When the banner goes away. `None` means when it is cleared.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub ends_at: Option<OffsetDateTime>,
+    /// Time tracking is unavailable while the banner is shown.
+    #[serde(default)]
+    pub maintenance: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BannerSeverity {
+    Info,
+    Warning,
+    Critical,
+}
@@ -0,0 +1,739 @@
+//! Request and response types for the `/time-tracking` endpoints.
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Header clients send to name the device they run on, e.g. `toki-tui@desk`.
+///
+/// The device that starts or takes over the timer is reported as its owner.
+pub const DEVICE_HEADER: &str = "X-Toki-Device";
+
+// ============================================================================
+// Responses
+// ============================================================================
+
+/// Response for the get timer endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTimerResponse {
+    pub timer: Option<TimerResponse>,
+}
+
+/// Response for saving the active timer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveTimerResponse {
+    pub entry: TimeEntryResponse,
+    pub timer: Option<TimerResponse>,
+    /// Minutes taken off the entry by the user's lunch rule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lunch_deduction_minutes: Option<i64>,
+    /// Entries saved for the segments a resumed timer ran in before it was
+    /// paused, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segment_entries: Vec<TimeEntryResponse>,
+}
+
+/// Active timer response - all timers are standalone now.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerResponse {
+    /// When the timer was started (ISO 8601).
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+    /// Project ID (if set).
+    pub project_id: Option<String>,
+    /// Project name (if set).
+    pub project_name: Option<String>,
+    /// Activity ID/code (if set).
+    pub activity_id: Option<String>,
+    /// Activity name (if set).
+    pub activity_name: Option<String>,
+    /// User note.
+    pub note: String,
+    /// Elapsed hours.
+    pub hours: i64,
+    /// Elapsed minutes (within current hour).
+    pub minutes: i64,
+    /// Elapsed seconds (within current minute).
+    pub seconds: i64,
+    /// Name of the device running the timer, if the client that started it
+    /// sent one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+}
+
+/// Response for `GET /time-tracking/timer/auto-stop`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTimerAutoStopResponse {
+    /// The last timer stopped by the user's auto-stop rule, until it is
+    /// acknowledged with `DELETE`.
+    pub auto_stop: Option<AutoStoppedTimerResponse>,
+}
+
+/// A timer stopped by the user's auto-stop rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoStoppedTimerResponse {
+    /// `save` or `discard`.
+    pub action: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+    /// When the rule stopped the timer, and the saved entry's end before any
+    /// lunch deduction.
+    #[serde(with = "time::serde::rfc3339")]
+    pub stop_time: OffsetDateTime,
+    pub project_name: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    /// The saved entry, when the timer was saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_id: Option<String>,
+}
+
+/// Response for `POST /time-tracking/timer/pause`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseTimerResponse {
+    pub paused: PausedTimerResponse,
+    /// The timer started in place of the paused one, if any.
+    pub timer: Option<TimerResponse>,
+}
+
+/// A timer put aside to track an interruption, resumed later to continue
+/// tracking it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PausedTimerResponse {
+    pub id: i32,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: String,
+    /// Stretches of time the timer ran in, oldest first.
+    pub segments: Vec<TimerSegmentResponse>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub paused_at: OffsetDateTime,
+    /// Minutes tracked over all segments.
+    pub tracked_minutes: i64,
+}
+
+/// A stretch of time a paused timer ran in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerSegmentResponse {
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub end_time: OffsetDateTime,
+}
+
+/// Project response - simplified for frontend use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectResponse {
+    pub project_id: String,
+    pub project_name: String,
+    /// Budgeted hours, if the provider tracks a budget for the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_hours: Option<f64>,
+    /// Hours registered against the budget so far.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_hours: Option<f64>,
+}
+
+/// Activity response - simplified for frontend use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityResponse {
+    /// Activity code (used in API calls).
+    pub activity: String,
+    /// Activity display name.
+    pub activity_name: String,
+}
+
+/// Attestation level of a time entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeEntryStatus {
+    #[default]
+    Open,
+    Approved,
+    Certified,
+}
+
+impl TimeEntryStatus {
+    /// Approved and certified entries can no longer be edited or deleted.
+    pub fn is_locked(self) -> bool {
+        self != TimeEntryStatus::Open
+    }
+}
+
+/// Time entry response - completed time registration.
+///
+/// `start_time` / `end_time` are only present when local timer history exists.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryResponse {
+    pub registration_id: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    pub hours: f64,
+    pub note: Option<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub start_time: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_time: Option<OffsetDateTime>,
+    pub week_number: u8,
+    #[serde(default)]
+    pub status: TimeEntryStatus,
+    /// Work item the entry was linked to when its timer was saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_item_id: Option<String>,
+}
+
+/// Date-level time entry status response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryDayStatusResponse {
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    pub status: TimeEntryStatus,
+}
+
+/// Timer history entry response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerHistoryEntryResponse {
+    pub id: i32,
+    pub registration_id: Option<String>,
+    pub user_id: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub end_time: Option<OffsetDateTime>,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_item_id: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// Weekly stats response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyStatsResponse {
+    pub worked_hours: f64,
+    pub scheduled_hours: f64,
+    pub remaining_hours: f64,
+    pub absence_hours: f64,
+    pub covered_hours: f64,
+    pub period_flex_hours: f64,
+}
+
+/// Flex balance at the end of a day, as returned by
+/// `GET /time-tracking/flex-history`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexSnapshotResponse {
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    /// Flex accumulated since the start of that year.
+    pub flex_hours: f64,
+}
+
+/// A workday with scheduled hours and nothing logged, as returned by
+/// `GET /time-tracking/missing-workdays`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingWorkdayResponse {
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    /// Scheduled hours left after absence.
+    pub hours: f64,
+}
+
+/// Response for `GET /time-tracking/invoice-report`.
+///
+/// Entries grouped project → activity → day. Totals are sums of the rounded
+/// day lines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceReportResponse {
+    /// Date in YYYY-MM-DD format.
+    pub from: String,
+    /// Date in YYYY-MM-DD format.
+    pub to: String,
+    pub projects: Vec<InvoiceProjectResponse>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceProjectResponse {
+    pub project_id: String,
+    pub project_name: String,
+    pub activities: Vec<InvoiceActivityResponse>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceActivityResponse {
+    pub activity_id: String,
+    pub activity_name: String,
+    pub days: Vec<InvoiceDayResponse>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDayResponse {
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    /// Rounded hours.
+    pub hours: f64,
+    /// Hours as registered, before rounding.
+    pub registered_hours: f64,
+    /// Notes of the day's entries joined into one line.
+    pub notes: String,
+}
+
+/// A suspicious time entry pattern, as returned by
+/// `GET /time-tracking/anomalies`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryAnomalyResponse {
+    /// `long_day`, `outside_working_hours`, `repeated_note` or `missing_note`.
+    pub kind: String,
+    /// Date in YYYY-MM-DD format.
+    pub date: String,
+    pub registration_ids: Vec<String>,
+    pub message: String,
+}
+
+/// Response for `POST /time-tracking/time-entries/import`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryImportResponse {
+    pub created: usize,
+    pub failed: usize,
+    pub rows: Vec<TimeEntryImportRowResponse>,
+}
+
+/// Outcome of one CSV row. Exactly one of `entry` and `error` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryImportRowResponse {
+    /// 1-based line number in the uploaded CSV.
+    pub line: usize,
+    pub entry: Option<TimeEntryResponse>,
+    pub error: Option<String>,
+}
+
+/// Response for `POST /time-tracking/time-entries/split`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitTimeEntryResponse {
+    /// The original entry, now ending at the split time.
+    pub first: TimeEntryResponse,
+    /// The new entry starting at the split time.
+    pub second: TimeEntryResponse,
+}
+
+/// Response for `POST /time-tracking/time-entries/batch`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchTimeEntriesResponse {
+    /// The reassigned entries, empty when deleting.
+    pub updated: Vec<TimeEntryResponse>,
+    /// Registration ids of the deleted entries.
+    pub deleted: Vec<String>,
+}
+
+/// Target hours a user set for a project, see `/time-tracking/budgets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBudgetResponse {
+    pub id: i32,
+    pub project_id: String,
+    pub project_name: String,
+    /// `week` or `month`.
+    pub period: String,
+    pub target_hours: f64,
+}
+
+/// Progress of a budget in its current period, as returned by
+/// `GET /time-tracking/budgets/status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBudgetStatusResponse {
+    pub budget: ProjectBudgetResponse,
+    /// First day of the period, in YYYY-MM-DD format.
+    pub from: String,
+    /// Last day of the period, in YYYY-MM-DD format.
+    pub to: String,
+    pub used_hours: f64,
+    /// Set once most of the budget is used.
+    pub nearly_used: bool,
+}
+
+/// A project and activity the user pinned, see `/time-tracking/favorites`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteResponse {
+    pub id: i32,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+}
+
+/// A saved time entry template, see `/time-tracking/templates`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTemplateResponse {
+    pub id: i32,
+    pub name: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    pub note: String,
+    /// Length of the entry when the template is logged directly.
+    pub duration_minutes: Option<u32>,
+}
+
+/// A meeting from the user's calendar feed that hasn't been logged, see
+/// `/time-tracking/calendar-suggestions`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarSuggestionResponse {
+    pub uid: String,
+    pub title: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub end_time: OffsetDateTime,
+    /// Project and activity last logged for a meeting with the same title.
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+}
+
+/// The user's calendar feed, see `/time-tracking/calendar-feed`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeedResponse {
+    pub url: String,
+}
+
+/// A change to the user's timer or entries, pushed over `GET /ws` as it
+/// happens on any device.
+///
+/// Messages carry the same `event` and `data` fields as event webhook
+/// deliveries; other fields are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", content = "data")]
+pub enum LiveEvent {
+    #[serde(rename = "timer.started")]
+    TimerStarted(TimerResponse),
+    /// The running timer was edited or taken over by another device.
+    #[serde(rename = "timer.updated")]
+    TimerUpdated(TimerResponse),
+    /// The timer was stopped without saving. Holds the stopped timer, if
+    /// there was one.
+    #[serde(rename = "timer.stopped")]
+    TimerStopped(Option<TimerResponse>),
+    #[serde(rename = "entry.saved")]
+    EntrySaved(TimeEntryResponse),
+}
+
+// ============================================================================
+// Request payloads
+// ============================================================================
+
+/// Body for `POST /time-tracking/timer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTimerPayload {
+    pub user_note: Option<String>,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+}
+
+/// Body for `PUT /time-tracking/timer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveTimerPayload {
+    pub user_note: Option<String>,
+    /// Work item to link the saved entry to, e.g. `1234`. A leading `#` is
+    /// ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_item_id: Option<String>,
+    /// Start a new timer right after saving the current one.
+    pub restart_timer: Option<RestartTimerPayload>,
+    /// Round the saved start and end times to the nearest 5, 15 or 30
+    /// minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_to_minutes: Option<u16>,
+}
+
+/// Timer to start after a save, see [`SaveTimerPayload`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestartTimerPayload {
+    pub user_note: String,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+}
+
+/// Body for `POST /time-tracking/timer/pause`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseTimerPayload {
+    /// Timer to start in place of the paused one.
+    pub start_timer: Option<StartTimerPayload>,
+}
+
+/// Body for `POST /time-tracking/timer/resume`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeTimerPayload {
+    /// Paused timer to resume. The most recently paused one when omitted.
+    pub id: Option<i32>,
+}
+
+/// Body for `PUT /time-tracking/update-timer`. Omitted fields keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTimerPayload {
+    pub user_note: Option<String>,
+    pub project_id: Option<String>,
+    pub project_name: Option<String>,
+    pub activity_id: Option<String>,
+    pub activity_name: Option<String>,
+    /// RFC 3339 start time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+}
+
+/// Body for `POST /time-tracking/time-entries`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectRegistrationPayload {
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    /// RFC 3339 start time.
+    pub start_time: String,
+    /// RFC 3339 end time.
+    pub end_time: String,
+    pub user_note: String,
+    /// Round the start and end times to the nearest 5, 15 or 30 minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_to_minutes: Option<u16>,
+}
+
+/// Body for `PUT /time-tracking/time-entries`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditProjectRegistrationPayload {
+    pub project_registration_id: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    /// RFC 3339 start time.
+    pub start_time: String,
+    /// RFC 3339 end time.
+    pub end_time: String,
+    pub user_note: String,
+    /// Round the start and end times to the nearest 5, 15 or 30 minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_to_minutes: Option<u16>,
+}
+
+/// Body for `POST /time-tracking/time-entries/split`.
+///
+/// Describes the entry as it is now; it is shortened to end at `split_time`
+/// and a new entry with the same project, activity and note covers the rest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitProjectRegistrationPayload {
+    pub project_registration_id: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    /// RFC 3339 start time.
+    pub start_time: String,
+    /// RFC 3339 end time.
+    pub end_time: String,
+    pub user_note: String,
+    /// RFC 3339 time to split at, strictly between start and end.
+    pub split_time: String,
+}
+
+/// Body for `POST /time-tracking/time-entries/merge`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeProjectRegistrationsPayload {
+    /// Day of the entries, YYYY-MM-DD.
+    pub date: String,
+    /// At least two consecutive entries on the same project and activity.
+    pub project_registration_ids: Vec<String>,
+}
+
+/// Body for `POST /time-tracking/time-entries/copy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyProjectRegistrationPayload {
+    /// Day of the entry, YYYY-MM-DD.
+    pub date: String,
+    pub project_registration_id: String,
+    /// Day to copy the entry to, YYYY-MM-DD. The copy keeps the entry's
+    /// start and end time of day.
+    pub to_date: String,
+}
+
+/// Body for `POST /time-tracking/time-entries/batch`.
+///
+/// Entries are looked up between `from` and `to`. If any of them is missing
+/// or attested, nothing is changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchProjectRegistrationsPayload {
+    /// First day of the entries, YYYY-MM-DD.
+    pub from: String,
+    /// Last day of the entries, YYYY-MM-DD.
+    pub to: String,
+    pub project_registration_ids: Vec<String>,
+    #[serde(flatten)]
+    pub action: BatchRegistrationAction,
+}
+
+/// What a batch does with each of its entries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "action",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum BatchRegistrationAction {
+    Delete,
+    /// Move the entries to another project and activity, keeping their
+    /// times and notes.
+    Reassign {
+        project_id: String,
+        project_name: String,
+        activity_id: String,
+        activity_name: String,
+    },
+}
+
+/// Body for `DELETE /time-tracking/time-entries`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteProjectRegistrationPayload {
+    pub project_registration_id: String,
+}
+
+/// Body for `POST /time-tracking/improve-note`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImproveNotePayload {
+    pub note: String,
+    /// Work item the note is about, given to the model as context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_item: Option<NoteWorkItemRef>,
+}
+
+/// Azure DevOps work item referenced by an [`ImproveNotePayload`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteWorkItemRef {
+    pub organization: String,
+    pub project: String,
+    pub work_item_id: String,
+}
+
+/// Response of `POST /time-tracking/improve-note`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImproveNoteResponse {
+    pub suggestion: String,
+}
+
+/// Body for `PUT /time-tracking/budgets`. Replaces the target of an existing
+/// budget for the same project and period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectBudgetPayload {
+    pub project_id: String,
+    pub project_name: String,
+    /// `week` or `month`.
+    pub period: String,
+    pub target_hours: f64,
+}
+
+/// Body for `POST /time-tracking/favorites`. Pinning a combination that is
+/// already a favorite returns the existing favorite.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFavoritePayload {
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+}
+
+/// Body for `POST /time-tracking/templates`. Replaces the user's existing
+/// template with the same name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveEntryTemplatePayload {
+    pub name: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub duration_minutes: Option<u32>,
+}
+
+/// Body of `PUT /time-tracking/calendar-feed`. `webcal://` links are
+/// accepted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCalendarFeedPayload {
+    pub url: String,
+}
+
+/// Body of `POST /time-tracking/calendar-suggestions/dismiss`: the meeting
+/// occurrence to stop suggesting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DismissCalendarSuggestionPayload {
+    pub uid: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub start_time: OffsetDateTime,
+}
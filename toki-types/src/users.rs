@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// Body and response for `GET`/`PUT /users/me/time-zone`.
+///
+/// Entry dates, week numbers and "today" are counted in this zone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZonePreference {
+    /// IANA time zone name, e.g. `Europe/Stockholm`.
+    pub time_zone: String,
+}
+
+/// Body and response for `GET`/`PUT /users/me/lunch-rule`.
+///
+/// When enabled, a saved timer covering the whole lunch window has
+/// `deduction_minutes` taken off its end unless its note contains `#nolunch`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunchRulePreference {
+    pub enabled: bool,
+    /// Start of the lunch window as `HH:MM` in the user's time zone.
+    pub window_start: String,
+    /// End of the lunch window as `HH:MM` in the user's time zone.
+    pub window_end: String,
+    pub deduction_minutes: u16,
+}
+
+/// Body and response for `GET`/`PUT /users/me/timer-auto-stop`.
+///
+/// When enabled, a timer still running at `stop_at` is saved as an entry
+/// ending then, or discarded, depending on `action`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerAutoStopPreference {
+    pub enabled: bool,
+    /// Time of day as `HH:MM` in the user's time zone.
+    pub stop_at: String,
+    /// `save` or `discard`.
+    pub action: String,
+}
+
+/// Body and response for `GET`/`PUT /users/me/work-item-hour-sync`.
+///
+/// When enabled, saving a timer linked to an Azure DevOps work item adds its
+/// hours to the work item's Completed Work and takes them off Remaining Work.
+/// Only saving a timer syncs hours: editing, splitting, merging or deleting
+/// the entries afterwards leaves the work item's fields as they are.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkItemHourSyncPreference {
+    pub enabled: bool,
+}
+
+/// Body and response for `GET`/`PUT /users/me/digest`.
+///
+/// Subscribed users get a summary email of logged hours, flex balance,
+/// missing registrations and pull requests waiting for their review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestPreference {
+    /// `off`, `daily` (every weekday) or `weekly` (Fridays).
+    pub frequency: String,
+}
@@ -0,0 +1,46 @@
+//! Work item types shared with the TUI.
+
+use serde::{Deserialize, Serialize};
+
+/// A user's capacity in a sprint against the hours they logged in it, as
+/// returned by `GET /work-items/sprint-capacity`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SprintCapacityResponse {
+    pub iteration_name: String,
+    pub iteration_path: String,
+    /// First day of the sprint, in YYYY-MM-DD format.
+    pub start_date: String,
+    /// Last day of the sprint, in YYYY-MM-DD format.
+    pub finish_date: String,
+    /// Hours planned for the user, `None` when they have no capacity set.
+    pub capacity_hours: Option<f64>,
+    pub logged_hours: f64,
+}
+
+/// The project and activity the user tracks time on for an Azure DevOps
+/// project's work items, see `/work-items/time-mappings`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkItemTimeMappingResponse {
+    pub id: i32,
+    pub organization: String,
+    pub project: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+}
+
+/// Body for `PUT /work-items/time-mappings`. Replaces the user's existing
+/// mapping for the Azure DevOps project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWorkItemTimeMappingPayload {
+    pub organization: String,
+    pub project: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub activity_id: String,
+    pub activity_name: String,
+}